@@ -31,6 +31,10 @@ pub struct AuditEvent {
     pub source_ip: Option<String>,
     /// Backend-assigned unique event ID.
     pub event_id: String,
+    /// Correlation id of the request that caused the event, when the backend
+    /// records one. For `xv`'s own Azure calls this is the id printed by
+    /// `--debug` and in error output.
+    pub correlation_id: Option<String>,
 }
 
 /// Audit log operations for backends that support them.
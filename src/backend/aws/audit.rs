@@ -254,6 +254,7 @@ fn map_event(event: &Event, vault: &str, secret_filter: Option<&str>) -> Option<
         status,
         source_ip,
         event_id,
+        correlation_id: None,
     })
 }
 
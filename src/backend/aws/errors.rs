@@ -525,6 +525,14 @@ pub fn from_s3_get_object_tagging(
     handle_s3("GetObjectTagging", Some(name), e)
 }
 
+#[cfg(feature = "file-ops")]
+pub fn from_s3_copy_object(
+    name: &str,
+    e: SdkError<aws_sdk_s3::operation::copy_object::CopyObjectError, Response>,
+) -> BackendError {
+    handle_s3("CopyObject", Some(name), e)
+}
+
 #[cfg(all(test, feature = "aws"))]
 mod tests {
     use super::*;
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use aws_sdk_s3::operation::head_object::HeadObjectOutput;
 use aws_sdk_s3::primitives::ByteStream;
 use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
 use aws_sdk_s3::Client as S3Client;
@@ -23,11 +24,17 @@ use chrono::Utc;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use crate::backend::error::BackendError;
-use crate::backend::file::FileBackend;
-use crate::blob::models::{BlobListItem, FileInfo, FileListRequest, FileUploadRequest};
+use crate::backend::file::{destination_exists, FileBackend};
+use crate::blob::models::{
+    BlobListItem, FileCopyRequest, FileDetails, FileInfo, FileListPage, FileListRequest,
+    FilePropertiesUpdate, FileReadUrl, FileUploadRequest, FileUploadSpec,
+};
 use crate::config::settings::AwsConfig;
 use crate::utils::format::format_size;
 use crate::utils::progress::{NoopReporter, ProgressReporter};
+use crate::utils::retry::{
+    describe_failed_ranges, retry_with_backoff_if, FailedRange, RetryOptions,
+};
 
 use super::errors;
 
@@ -206,6 +213,16 @@ pub fn part_count(file_size: u64, part_size: u64) -> u64 {
     file_size.div_ceil(part_size.max(1))
 }
 
+/// Whether a failed part upload or body stream may succeed on another
+/// attempt. Refusals (permissions, missing bucket, ...) fail every attempt
+/// alike and are returned at once.
+fn is_retryable_part_error(error: &BackendError) -> bool {
+    matches!(
+        error,
+        BackendError::Network(_) | BackendError::RateLimited { .. }
+    )
+}
+
 /// Reject downloads whose object size exceeds `max_bytes`.
 fn validate_download_size(content_length: u64, max_bytes: u64) -> Result<(), BackendError> {
     if content_length > max_bytes {
@@ -246,6 +263,37 @@ fn encode_tagging(tags: &HashMap<String, String>) -> Result<Option<String>, Back
     Ok(Some(serializer.finish()))
 }
 
+/// The `CopySource` value for an object: `bucket/key`, percent-encoded
+/// except for the path separators.
+fn copy_source(bucket: &str, key: &str) -> String {
+    let mut source = String::with_capacity(bucket.len() + key.len() + 1);
+    for byte in format!("{bucket}/{key}").bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~') {
+            source.push(byte as char);
+        } else {
+            source.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    source
+}
+
+/// Content type, object metadata and URL-encoded tagging for an upload.
+type ObjectProperties = (String, HashMap<String, String>, Option<String>);
+
+/// Content type, object metadata and tagging for an upload, mirroring the
+/// Azure blob manager's conventions.
+fn object_properties(spec: &FileUploadSpec) -> Result<ObjectProperties, BackendError> {
+    let content_type =
+        crate::blob::content_type::resolve(spec.content_type.as_deref(), &spec.name, &[]);
+    let mut metadata = spec.metadata.clone();
+    if !spec.groups.is_empty() {
+        metadata.insert(METADATA_KEY_GROUPS.to_string(), spec.groups.join(","));
+    }
+    metadata.insert("uploaded_by".to_string(), "crosstache".to_string());
+    metadata.insert("uploaded_at".to_string(), Utc::now().to_rfc3339());
+    Ok((content_type, metadata, encode_tagging(&spec.tags)?))
+}
+
 /// Convert an SDK timestamp to `chrono::DateTime<Utc>`.
 fn to_chrono(dt: Option<&aws_sdk_s3::primitives::DateTime>) -> chrono::DateTime<Utc> {
     dt.and_then(|d| chrono::DateTime::from_timestamp(d.secs(), d.subsec_nanos()))
@@ -254,7 +302,7 @@ fn to_chrono(dt: Option<&aws_sdk_s3::primitives::DateTime>) -> chrono::DateTime<
 
 /// Read up to `chunk_size` bytes from `reader` (fewer at EOF, empty when
 /// already at EOF). Same contract as the Azure blob manager's chunker.
-async fn read_chunk<R: AsyncRead + Unpin>(
+async fn read_chunk<R: AsyncRead + Unpin + ?Sized>(
     reader: &mut R,
     chunk_size: usize,
 ) -> std::io::Result<Vec<u8>> {
@@ -286,32 +334,6 @@ fn sort_list_items(items: &mut [BlobListItem]) {
     });
 }
 
-// ---------------------------------------------------------------------------
-// Upload spec
-// ---------------------------------------------------------------------------
-
-/// Metadata for a streaming upload (everything in [`FileUploadRequest`]
-/// except the content, which arrives via the reader).
-pub struct FileUploadSpec {
-    pub name: String,
-    pub content_type: Option<String>,
-    pub groups: Vec<String>,
-    pub metadata: HashMap<String, String>,
-    pub tags: HashMap<String, String>,
-}
-
-impl From<&FileUploadRequest> for FileUploadSpec {
-    fn from(request: &FileUploadRequest) -> Self {
-        Self {
-            name: request.name.clone(),
-            content_type: request.content_type.clone(),
-            groups: request.groups.clone(),
-            metadata: request.metadata.clone(),
-            tags: request.tags.clone(),
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // AwsFileBackend
 // ---------------------------------------------------------------------------
@@ -323,6 +345,8 @@ pub struct AwsFileBackend {
     bucket: String,
     chunk_size_mb: usize,
     max_concurrent_uploads: usize,
+    /// Further attempts for each failed multipart part or download stream.
+    transfer_retries: usize,
 }
 
 impl AwsFileBackend {
@@ -333,6 +357,7 @@ impl AwsFileBackend {
             bucket,
             chunk_size_mb: 8,
             max_concurrent_uploads: 3,
+            transfer_retries: 3,
         }
     }
 
@@ -345,6 +370,24 @@ impl AwsFileBackend {
         self
     }
 
+    /// Override how often a failed part upload or interrupted download is
+    /// retried (builder style).
+    pub fn with_transfer_retries(mut self, retries: usize) -> Self {
+        self.transfer_retries = retries;
+        self
+    }
+
+    /// The same backend pointed at another bucket (`xv file cp --container`).
+    fn in_bucket(&self, bucket: &str) -> Self {
+        Self {
+            client: self.client.clone(),
+            bucket: bucket.to_string(),
+            chunk_size_mb: self.chunk_size_mb,
+            max_concurrent_uploads: self.max_concurrent_uploads,
+            transfer_retries: self.transfer_retries,
+        }
+    }
+
     /// Upload a file from any async reader, streaming chunk-by-chunk.
     ///
     /// Files larger than the effective part size go through S3 multipart
@@ -360,22 +403,7 @@ impl AwsFileBackend {
         reporter: &dyn ProgressReporter,
     ) -> Result<FileInfo, BackendError> {
         let key = validated_key(vault, &spec.name)?;
-
-        let content_type = spec.content_type.clone().unwrap_or_else(|| {
-            mime_guess::from_path(&spec.name)
-                .first_or_octet_stream()
-                .to_string()
-        });
-
-        // Object metadata, mirroring the Azure blob manager's conventions.
-        let mut metadata = spec.metadata.clone();
-        if !spec.groups.is_empty() {
-            metadata.insert(METADATA_KEY_GROUPS.to_string(), spec.groups.join(","));
-        }
-        metadata.insert("uploaded_by".to_string(), "crosstache".to_string());
-        metadata.insert("uploaded_at".to_string(), Utc::now().to_rfc3339());
-
-        let tagging = encode_tagging(&spec.tags)?;
+        let (content_type, metadata, tagging) = object_properties(&spec)?;
 
         reporter.set_total(file_size);
 
@@ -432,6 +460,54 @@ impl AwsFileBackend {
         })
     }
 
+    /// Upload a stream of unknown length. A stream shorter than one part
+    /// goes up as a single `PutObject`; anything longer is sent as multipart
+    /// parts while it is read, so at most `max_concurrent_uploads + 1` parts
+    /// are buffered. The part count cap bounds the stream at 10,000 parts.
+    pub async fn upload_stream<R: AsyncRead + Unpin + ?Sized>(
+        &self,
+        vault: &str,
+        spec: FileUploadSpec,
+        reader: &mut R,
+        reporter: &dyn ProgressReporter,
+    ) -> Result<FileInfo, BackendError> {
+        let key = validated_key(vault, &spec.name)?;
+        let part_size = multipart_part_size(0, chunk_bytes(self.chunk_size_mb));
+        let first = read_chunk(reader, part_size as usize)
+            .await
+            .map_err(|e| BackendError::Internal(format!("read upload stream: {e}")))?;
+        let mut spec = spec;
+        spec.content_type = Some(crate::blob::content_type::resolve(
+            spec.content_type.as_deref(),
+            &spec.name,
+            &first,
+        ));
+        if (first.len() as u64) < part_size {
+            let size = first.len() as u64;
+            let mut first = std::io::Cursor::new(first);
+            return self
+                .upload_file_streaming(vault, spec, &mut first, size, reporter)
+                .await;
+        }
+
+        let (content_type, metadata, tagging) = object_properties(&spec)?;
+        let mut rest = std::io::Cursor::new(first).chain(reader);
+        self.multipart_upload(
+            &spec.name,
+            &key,
+            &mut rest,
+            part_size,
+            &content_type,
+            &metadata,
+            &tagging,
+            reporter,
+        )
+        .await?;
+        reporter.finish_clear();
+        // The length is only known now; read it back with the final ETag.
+        self.get_file_info(vault, &spec.name).await
+    }
+
     /// Multipart upload: create, upload parts concurrently, complete.
     /// Aborts the multipart upload (best effort) on any failure so no
     /// orphaned parts accrue storage charges.
@@ -530,9 +606,11 @@ impl AwsFileBackend {
         use tokio::sync::Semaphore;
 
         let semaphore = Arc::new(Semaphore::new(self.max_concurrent_uploads));
+        let retry = RetryOptions::transfer(self.transfer_retries);
         type PartHandle = tokio::task::JoinHandle<Result<CompletedPart, BackendError>>;
-        let mut handles: Vec<(PartHandle, u64)> = Vec::new();
+        let mut handles: Vec<(PartHandle, u64, u64)> = Vec::new();
         let mut part_number: i32 = 0;
+        let mut offset: u64 = 0;
 
         loop {
             let chunk = read_chunk(reader, part_size as usize)
@@ -558,37 +636,69 @@ impl AwsFileBackend {
             let key = key.to_string();
             let upload_id = upload_id.to_string();
             let name = name.to_string();
+            let retry = retry.clone();
             let len = chunk.len() as u64;
 
             handles.push((
                 tokio::spawn(async move {
                     let _permit = permit; // held for the duration of the upload
-                    let out = client
-                        .upload_part()
-                        .bucket(bucket)
-                        .key(key)
-                        .upload_id(upload_id)
-                        .part_number(part_number)
-                        .body(ByteStream::from(chunk))
-                        .send()
-                        .await
-                        .map_err(|e| errors::from_s3_upload_part(&name, e))?;
+                    let out = retry_with_backoff_if(
+                        || async {
+                            client
+                                .upload_part()
+                                .bucket(&bucket)
+                                .key(&key)
+                                .upload_id(&upload_id)
+                                .part_number(part_number)
+                                .body(ByteStream::from(chunk.clone()))
+                                .send()
+                                .await
+                                .map_err(|e| errors::from_s3_upload_part(&name, e))
+                        },
+                        retry,
+                        is_retryable_part_error,
+                    )
+                    .await?;
                     Ok(CompletedPart::builder()
                         .part_number(part_number)
                         .set_e_tag(out.e_tag().map(str::to_string))
                         .build())
                 }),
+                offset,
                 len,
             ));
+            offset += len;
         }
 
-        let mut parts = Vec::with_capacity(handles.len());
-        for (handle, len) in handles {
-            let part = handle
+        // Every part is awaited so the error names all the ranges that
+        // failed, not just the first.
+        let total_parts = handles.len();
+        let mut parts = Vec::with_capacity(total_parts);
+        let mut failures = Vec::new();
+        for (index, (handle, offset, len)) in handles.into_iter().enumerate() {
+            match handle
                 .await
-                .map_err(|e| BackendError::Internal(format!("upload task panicked: {e}")))??;
-            parts.push(part);
-            reporter.advance(len);
+                .map_err(|e| BackendError::Internal(format!("upload task panicked: {e}")))?
+            {
+                Ok(part) => {
+                    parts.push(part);
+                    reporter.advance(len);
+                }
+                // A refusal fails every part alike; surface it as is.
+                Err(e) if !is_retryable_part_error(&e) => return Err(e),
+                Err(e) => failures.push(FailedRange {
+                    label: format!("part {}", index + 1),
+                    offset,
+                    len,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        if !failures.is_empty() {
+            return Err(BackendError::Network(format!(
+                "upload of '{name}' failed: {}",
+                describe_failed_ranges("parts", total_parts, self.transfer_retries + 1, &failures)
+            )));
         }
         Ok(parts)
     }
@@ -596,7 +706,7 @@ impl AwsFileBackend {
     /// Stream a file's contents to `writer`, enforcing the 5 GiB download
     /// cap. At most one body frame is buffered at a time. Returns the
     /// object's size in bytes.
-    pub async fn download_file_to_writer<W: AsyncWrite + Unpin>(
+    pub async fn download_file_to_writer<W: AsyncWrite + Unpin + ?Sized>(
         &self,
         vault: &str,
         name: &str,
@@ -617,28 +727,61 @@ impl AwsFileBackend {
         validate_download_size(content_length, MAX_DOWNLOAD_SIZE_BYTES)?;
         reporter.set_total(content_length);
 
-        let out = self
-            .client
-            .get_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .send()
-            .await
-            .map_err(|e| errors::from_s3_get_object(name, e))?;
-
-        let mut body = out.body;
+        // A body stream that drops mid-transfer resumes with a ranged GET
+        // from the first byte not yet written, pinned to the ETag so the
+        // pieces come from the same object version.
+        let retry = RetryOptions::transfer(self.transfer_retries);
         let mut written: u64 = 0;
-        while let Some(bytes) = body
-            .try_next()
-            .await
-            .map_err(|e| BackendError::Network(format!("aws GetObject body stream: {e}")))?
-        {
-            writer
-                .write_all(&bytes)
-                .await
-                .map_err(|e| BackendError::Internal(format!("write downloaded data: {e}")))?;
-            written += bytes.len() as u64;
-            reporter.advance(bytes.len() as u64);
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.get_object().bucket(&self.bucket).key(&key);
+            if written > 0 {
+                request = request
+                    .range(format!("bytes={written}-"))
+                    .set_if_match(head.e_tag().map(str::to_string));
+            }
+            let streamed: Result<(), BackendError> =
+                async {
+                    let out = request
+                        .send()
+                        .await
+                        .map_err(|e| errors::from_s3_get_object(name, e))?;
+                    let mut body = out.body;
+                    while let Some(bytes) = body.try_next().await.map_err(|e| {
+                        BackendError::Network(format!("aws GetObject body stream: {e}"))
+                    })? {
+                        writer.write_all(&bytes).await.map_err(|e| {
+                            BackendError::Internal(format!("write downloaded data: {e}"))
+                        })?;
+                        written += bytes.len() as u64;
+                        reporter.advance(bytes.len() as u64);
+                    }
+                    Ok(())
+                }
+                .await;
+            match streamed {
+                Ok(()) => break,
+                Err(e) if written >= content_length || !is_retryable_part_error(&e) => {
+                    return Err(e)
+                }
+                Err(e) if attempt >= retry.max_retries => {
+                    let failure = FailedRange {
+                        label: "range".to_string(),
+                        offset: written,
+                        len: content_length - written,
+                        error: e.to_string(),
+                    };
+                    return Err(BackendError::Network(format!(
+                        "download of '{name}' failed: {}",
+                        describe_failed_ranges("ranges", 1, attempt + 1, &[failure])
+                    )));
+                }
+                Err(e) => {
+                    attempt += 1;
+                    tracing::debug!(name, written, attempt, error = %e, "resuming download");
+                    tokio::time::sleep(retry.initial_interval * attempt as u32).await;
+                }
+            }
         }
         writer
             .flush()
@@ -754,6 +897,73 @@ impl AwsFileBackend {
         Ok(items)
     }
 
+    /// `HeadObject` (plus tagging) as a [`FileInfo`], alongside the raw
+    /// output for callers that report more than `FileInfo` carries.
+    async fn head_file(
+        &self,
+        vault: &str,
+        name: &str,
+    ) -> Result<(FileInfo, HeadObjectOutput), BackendError> {
+        let key = validated_key(vault, name)?;
+
+        let head = self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .send()
+            .await
+            .map_err(|e| errors::from_s3_head_object(name, e))?;
+
+        let metadata: HashMap<String, String> = head.metadata().cloned().unwrap_or_default();
+        let groups: Vec<String> = metadata
+            .get(METADATA_KEY_GROUPS)
+            .map(|g| g.split(',').map(|s| s.trim().to_string()).collect())
+            .unwrap_or_default();
+
+        // Tags need a separate call; degrade gracefully when the credentials
+        // lack s3:GetObjectTagging (mirrors the Azure 403 fallback).
+        let tags: HashMap<String, String> = match self
+            .client
+            .get_object_tagging()
+            .bucket(&self.bucket)
+            .key(&key)
+            .send()
+            .await
+        {
+            Ok(out) => out
+                .tag_set()
+                .iter()
+                .map(|t| (t.key().to_string(), t.value().to_string()))
+                .collect(),
+            Err(e) => {
+                match errors::from_s3_get_object_tagging(name, e) {
+                    BackendError::PermissionDenied(_) => tracing::debug!(
+                        "Tag read for '{name}' was denied; tags will be empty. \
+                         Grant s3:GetObjectTagging to include them."
+                    ),
+                    other => tracing::warn!("Failed to fetch tags for '{name}': {other}"),
+                }
+                HashMap::new()
+            }
+        };
+
+        let info = FileInfo {
+            name: name.to_string(),
+            size: head.content_length().unwrap_or(0).max(0) as u64,
+            content_type: head
+                .content_type()
+                .unwrap_or("application/octet-stream")
+                .to_string(),
+            last_modified: to_chrono(head.last_modified()),
+            etag: head.e_tag().unwrap_or_default().to_string(),
+            groups,
+            metadata,
+            tags,
+        };
+        Ok((info, head))
+    }
+
     /// Convert one S3 listing entry to a [`FileInfo`].
     ///
     /// S3 listings carry no user metadata or content type, so `content_type`
@@ -814,13 +1024,30 @@ impl FileBackend for AwsFileBackend {
     ) -> Result<FileInfo, BackendError> {
         let null = NoopReporter;
         let reporter = reporter.unwrap_or(&null);
-        let spec = FileUploadSpec::from(&request);
+        let mut spec = FileUploadSpec::from(&request);
+        spec.content_type = Some(crate::blob::content_type::resolve(
+            spec.content_type.as_deref(),
+            &spec.name,
+            &request.content,
+        ));
         let file_size = request.content.len() as u64;
         let mut reader = std::io::Cursor::new(request.content);
         self.upload_file_streaming(vault, spec, &mut reader, file_size, reporter)
             .await
     }
 
+    async fn upload_file_from_reader(
+        &self,
+        vault: &str,
+        spec: FileUploadSpec,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<FileInfo, BackendError> {
+        let null = NoopReporter;
+        let reporter = reporter.unwrap_or(&null);
+        self.upload_stream(vault, spec, reader, reporter).await
+    }
+
     async fn download_file(
         &self,
         vault: &str,
@@ -835,6 +1062,18 @@ impl FileBackend for AwsFileBackend {
         Ok(buf)
     }
 
+    async fn download_file_to_writer(
+        &self,
+        vault: &str,
+        name: &str,
+        writer: &mut (dyn AsyncWrite + Unpin + Send),
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<u64, BackendError> {
+        let null = NoopReporter;
+        let reporter = reporter.unwrap_or(&null);
+        AwsFileBackend::download_file_to_writer(self, vault, name, writer, reporter).await
+    }
+
     async fn list_files(
         &self,
         vault: &str,
@@ -894,6 +1133,60 @@ impl FileBackend for AwsFileBackend {
         Ok(results)
     }
 
+    /// One `ListObjectsV2` call: `request.limit` becomes `MaxKeys` and the
+    /// marker is S3's continuation token.
+    async fn list_files_page(
+        &self,
+        vault: &str,
+        request: FileListRequest,
+        marker: Option<&str>,
+    ) -> Result<FileListPage, BackendError> {
+        validate_vault_for_files(vault)?;
+
+        let base = files_prefix(vault);
+        let user_prefix = normalize_user_prefix(request.prefix.as_deref());
+        let full_prefix = format!("{base}{user_prefix}");
+
+        let page = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(&full_prefix)
+            .set_max_keys(
+                request
+                    .limit
+                    .map(|limit| i32::try_from(limit).unwrap_or(i32::MAX)),
+            )
+            .set_continuation_token(marker.filter(|m| !m.is_empty()).map(str::to_string))
+            .send()
+            .await
+            .map_err(errors::from_s3_list_objects)?;
+
+        let mut files = Vec::new();
+        for obj in page.contents() {
+            let Some(info) = self.object_to_file_info(vault, obj) else {
+                continue;
+            };
+            // Group filtering needs per-object metadata; see hierarchical note.
+            match &request.groups {
+                Some(filter_groups) => {
+                    let full = self.get_file_info(vault, &info.name).await?;
+                    if filter_groups.iter().any(|fg| full.groups.contains(fg)) {
+                        files.push(full);
+                    }
+                }
+                None => files.push(info),
+            }
+        }
+
+        let next_marker = if page.is_truncated() == Some(true) {
+            page.next_continuation_token().map(str::to_string)
+        } else {
+            None
+        };
+        Ok(FileListPage { files, next_marker })
+    }
+
     async fn delete_file(&self, vault: &str, name: &str) -> Result<(), BackendError> {
         let key = validated_key(vault, name)?;
 
@@ -918,62 +1211,146 @@ impl FileBackend for AwsFileBackend {
     }
 
     async fn get_file_info(&self, vault: &str, name: &str) -> Result<FileInfo, BackendError> {
+        self.head_file(vault, name).await.map(|(info, _)| info)
+    }
+
+    /// S3 has no leases; the storage class stands in for the tier. The ETag
+    /// is the content MD5 only for single-part uploads without SSE-KMS, so
+    /// the MD5 is left out otherwise.
+    async fn get_file_details(&self, vault: &str, name: &str) -> Result<FileDetails, BackendError> {
+        use aws_sdk_s3::types::ServerSideEncryption;
+
+        let (info, head) = self.head_file(vault, name).await?;
+        let sse = head.server_side_encryption();
+        let kms = matches!(
+            sse,
+            Some(ServerSideEncryption::AwsKms | ServerSideEncryption::AwsKmsDsse)
+        );
+        let content_md5 = head
+            .e_tag()
+            .map(|etag| etag.trim_matches('"'))
+            .filter(|etag| !kms && etag.len() == 32 && etag.bytes().all(|b| b.is_ascii_hexdigit()))
+            .map(str::to_ascii_lowercase);
+        Ok(FileDetails {
+            info,
+            // HeadObject omits the class for STANDARD objects.
+            tier: Some(
+                head.storage_class()
+                    .map_or("STANDARD", |class| class.as_str())
+                    .to_string(),
+            ),
+            lease_state: None,
+            version_id: head.version_id().map(str::to_string),
+            server_encrypted: Some(sse.is_some()),
+            content_md5,
+        })
+    }
+
+    /// S3 metadata is immutable, so this copies the object onto itself with
+    /// the new metadata (and tags, when given), keeping its content type,
+    /// storage class and encryption. `CopyObject` is limited to 5 GiB.
+    async fn update_file_properties(
+        &self,
+        vault: &str,
+        name: &str,
+        update: FilePropertiesUpdate,
+    ) -> Result<FileInfo, BackendError> {
+        use aws_sdk_s3::types::{MetadataDirective, TaggingDirective};
+
         let key = validated_key(vault, name)?;
+        let (_, head) = self.head_file(vault, name).await?;
 
-        let head = self
+        let mut metadata = update.metadata;
+        if !update.groups.is_empty() {
+            metadata.insert(METADATA_KEY_GROUPS.to_string(), update.groups.join(","));
+        }
+        let mut copy = self
             .client
-            .head_object()
+            .copy_object()
             .bucket(&self.bucket)
             .key(&key)
+            .copy_source(copy_source(&self.bucket, &key))
+            .metadata_directive(MetadataDirective::Replace)
+            .set_metadata(Some(metadata))
+            .set_content_type(head.content_type().map(str::to_string))
+            .set_storage_class(head.storage_class().cloned())
+            .set_server_side_encryption(head.server_side_encryption().cloned())
+            .set_ssekms_key_id(head.ssekms_key_id().map(str::to_string));
+        if let Some(tags) = &update.tags {
+            copy = copy
+                .tagging_directive(TaggingDirective::Replace)
+                .set_tagging(encode_tagging(tags)?);
+        }
+        copy.send()
+            .await
+            .map_err(|e| errors::from_s3_copy_object(name, e))?;
+
+        self.get_file_info(vault, name).await
+    }
+
+    /// `CopyObject` copies metadata and tags by default; the storage class
+    /// and KMS key are carried over explicitly. Limited to 5 GiB.
+    async fn copy_file(
+        &self,
+        vault: &str,
+        request: FileCopyRequest,
+    ) -> Result<FileInfo, BackendError> {
+        let destination = match &request.destination_container {
+            Some(bucket) => self.in_bucket(bucket),
+            None => self.in_bucket(&self.bucket),
+        };
+        let source_key = validated_key(vault, &request.source)?;
+        let destination_key = validated_key(vault, &request.destination)?;
+        let (_, head) = self.head_file(vault, &request.source).await?;
+        if !request.overwrite {
+            match destination.get_file_info(vault, &request.destination).await {
+                Ok(_) => return Err(destination_exists(&request.destination)),
+                Err(BackendError::NotFound { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.client
+            .copy_object()
+            .bucket(&destination.bucket)
+            .key(&destination_key)
+            .copy_source(copy_source(&self.bucket, &source_key))
+            .set_storage_class(head.storage_class().cloned())
+            .set_server_side_encryption(head.server_side_encryption().cloned())
+            .set_ssekms_key_id(head.ssekms_key_id().map(str::to_string))
             .send()
             .await
-            .map_err(|e| errors::from_s3_head_object(name, e))?;
+            .map_err(|e| errors::from_s3_copy_object(&request.source, e))?;
 
-        let metadata: HashMap<String, String> = head.metadata().cloned().unwrap_or_default();
-        let groups: Vec<String> = metadata
-            .get(METADATA_KEY_GROUPS)
-            .map(|g| g.split(',').map(|s| s.trim().to_string()).collect())
-            .unwrap_or_default();
+        destination.get_file_info(vault, &request.destination).await
+    }
 
-        // Tags need a separate call; degrade gracefully when the credentials
-        // lack s3:GetObjectTagging (mirrors the Azure 403 fallback).
-        let tags: HashMap<String, String> = match self
+    /// A presigned `GetObject` URL. S3 caps presigned URLs at seven days.
+    async fn read_url(
+        &self,
+        vault: &str,
+        name: &str,
+        ttl: std::time::Duration,
+    ) -> Result<FileReadUrl, BackendError> {
+        use aws_sdk_s3::presigning::PresigningConfig;
+
+        let key = validated_key(vault, name)?;
+        let config = PresigningConfig::expires_in(ttl)
+            .map_err(|e| BackendError::InvalidArgument(format!("invalid URL lifetime: {e}")))?;
+        let expires_on = Utc::now()
+            + chrono::Duration::from_std(ttl)
+                .map_err(|_| BackendError::InvalidArgument("URL lifetime is too long".into()))?;
+        let presigned = self
             .client
-            .get_object_tagging()
+            .get_object()
             .bucket(&self.bucket)
             .key(&key)
-            .send()
+            .presigned(config)
             .await
-        {
-            Ok(out) => out
-                .tag_set()
-                .iter()
-                .map(|t| (t.key().to_string(), t.value().to_string()))
-                .collect(),
-            Err(e) => {
-                match errors::from_s3_get_object_tagging(name, e) {
-                    BackendError::PermissionDenied(_) => tracing::debug!(
-                        "Tag read for '{name}' was denied; tags will be empty. \
-                         Grant s3:GetObjectTagging to include them."
-                    ),
-                    other => tracing::warn!("Failed to fetch tags for '{name}': {other}"),
-                }
-                HashMap::new()
-            }
-        };
-
-        Ok(FileInfo {
-            name: name.to_string(),
-            size: head.content_length().unwrap_or(0).max(0) as u64,
-            content_type: head
-                .content_type()
-                .unwrap_or("application/octet-stream")
-                .to_string(),
-            last_modified: to_chrono(head.last_modified()),
-            etag: head.e_tag().unwrap_or_default().to_string(),
-            groups,
-            metadata,
-            tags,
+            .map_err(|e| BackendError::Internal(format!("failed to presign '{name}': {e}")))?;
+        Ok(FileReadUrl {
+            url: presigned.uri().to_string(),
+            expires_on,
         })
     }
 
@@ -1198,6 +1575,16 @@ mod tests {
         ));
     }
 
+    // ── copy source ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn copy_source_encodes_everything_but_separators() {
+        assert_eq!(
+            copy_source("bucket", "kv/files/q1 report+final.pdf"),
+            "bucket/kv/files/q1%20report%2Bfinal.pdf"
+        );
+    }
+
     // ── misc helpers ─────────────────────────────────────────────────────────
 
     #[test]
@@ -26,14 +26,16 @@ use aws_sdk_secretsmanager::Client as SecretsManagerClient;
 #[cfg(feature = "file-ops")]
 use crate::backend::FileBackend;
 
-/// Blob transfer settings threaded into S3 file storage (chunk size and upload
-/// concurrency). Sourced from the global `[blob]` config so `xv file` on AWS
-/// honors `BLOB_CHUNK_SIZE_MB` / `BLOB_MAX_CONCURRENT_UPLOADS`; named AWS
-/// entries fall back to [`TransferConfig::default`].
+/// Blob transfer settings threaded into S3 file storage (chunk size, upload
+/// concurrency and per-part retries). Sourced from the global `[blob]` config
+/// so `xv file` on AWS honors `BLOB_CHUNK_SIZE_MB` /
+/// `BLOB_MAX_CONCURRENT_UPLOADS` / `BLOB_TRANSFER_RETRIES`; named AWS entries
+/// fall back to [`TransferConfig::default`].
 #[derive(Debug, Clone, Copy)]
 pub struct TransferConfig {
     pub chunk_size_mb: usize,
     pub max_concurrent_uploads: usize,
+    pub retries: usize,
 }
 
 impl Default for TransferConfig {
@@ -41,6 +43,7 @@ impl Default for TransferConfig {
         Self {
             chunk_size_mb: 4,
             max_concurrent_uploads: 3,
+            retries: 3,
         }
     }
 }
@@ -74,13 +77,18 @@ impl AwsBackend {
             let s3_client = auth::build_s3_client(aws_cfg, &sdk_config);
             Arc::new(
                 files::AwsFileBackend::new(s3_client, bucket)
-                    .with_transfer_config(transfer.chunk_size_mb, transfer.max_concurrent_uploads),
+                    .with_transfer_config(transfer.chunk_size_mb, transfer.max_concurrent_uploads)
+                    .with_transfer_retries(transfer.retries),
             )
         });
-        // Without file storage there is nothing to transfer; touch both fields
+        // Without file storage there is nothing to transfer; touch the fields
         // so they don't read as dead when the `file-ops` reader is compiled out.
         #[cfg(not(feature = "file-ops"))]
-        let _ = (transfer.chunk_size_mb, transfer.max_concurrent_uploads);
+        let _ = (
+            transfer.chunk_size_mb,
+            transfer.max_concurrent_uploads,
+            transfer.retries,
+        );
 
         Ok(Self {
             secrets_impl: Arc::new(secrets::AwsSecretBackend::new(client.clone())),
@@ -175,6 +175,7 @@ impl AwsSecretBackend {
             not_before: request.not_before,
             tags: request.tags.clone().unwrap_or_default(),
             content_type: request.content_type.clone().unwrap_or_default(),
+            id: put_out.arn().map(str::to_string),
             recovery_level: None,
         })
     }
@@ -237,6 +238,7 @@ impl AwsSecretBackend {
                 not_before: None,
                 tags,
                 content_type: String::new(),
+                id: out.arn().map(str::to_string),
                 recovery_level: None,
             });
         }
@@ -360,6 +362,7 @@ impl AwsSecretBackend {
             not_before: None,
             tags: user_tags,
             content_type: content_type.unwrap_or_default(),
+            id: describe.arn().map(str::to_string),
             recovery_level: None,
         }
     }
@@ -427,8 +430,11 @@ impl AwsSecretBackend {
         }
         let create_result = create_builder.send().await;
 
-        let version_id = match create_result {
-            Ok(out) => out.version_id().unwrap_or("").to_string(),
+        let (version_id, arn) = match create_result {
+            Ok(out) => (
+                out.version_id().unwrap_or("").to_string(),
+                out.arn().map(str::to_string),
+            ),
             Err(e) => {
                 match update_after_create_conflict(update_existing, super::errors::from_create(e)) {
                     Ok(true) => {
@@ -456,6 +462,7 @@ impl AwsSecretBackend {
             not_before: request.not_before,
             tags: request.tags.unwrap_or_default(),
             content_type: request.content_type.unwrap_or_default(),
+            id: arn,
             recovery_level: None,
         })
     }
@@ -582,6 +589,7 @@ impl SecretBackend for AwsSecretBackend {
             not_before: None,
             tags,
             content_type: String::new(),
+            id: out.arn().map(str::to_string),
             recovery_level: None,
         })
     }
@@ -600,7 +608,9 @@ impl SecretBackend for AwsSecretBackend {
         group_filter: Option<&str>,
     ) -> Result<Vec<SecretSummary>, BackendError> {
         use crate::backend::aws::encoding::{is_marker, strip_prefix};
-        use crate::backend::aws::metadata::{TAG_FOLDER, TAG_GROUPS, TAG_ORIGINAL_NAME};
+        use crate::backend::aws::metadata::{
+            TAG_CONTENT_TYPE, TAG_EXPIRES_AT, TAG_FOLDER, TAG_GROUPS, TAG_ORIGINAL_NAME,
+        };
         use aws_sdk_secretsmanager::types::{Filter, FilterNameStringType};
 
         let prefix = format!("{vault}/");
@@ -676,6 +686,20 @@ impl SecretBackend for AwsSecretBackend {
                     .description()
                     .map(String::from)
                     .filter(|n| !n.is_empty());
+                let content_type_val = entry
+                    .tags()
+                    .iter()
+                    .find(|t| t.key() == Some(TAG_CONTENT_TYPE))
+                    .and_then(|t| t.value())
+                    .map(String::from)
+                    .unwrap_or_default();
+                let expires_val = entry
+                    .tags()
+                    .iter()
+                    .find(|t| t.key() == Some(TAG_EXPIRES_AT))
+                    .and_then(|t| t.value())
+                    .and_then(|v| chrono::DateTime::parse_from_rfc3339(v).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc));
 
                 // Full tag map (record-types plan Task 10): xv-type/f.*
                 // fields ride as plain user tags (see `request.tags` in
@@ -696,8 +720,8 @@ impl SecretBackend for AwsSecretBackend {
                     groups: groups_val,
                     updated_on: String::new(),
                     enabled: true,
-                    expires_on: None,
-                    content_type: String::new(),
+                    expires_on: expires_val,
+                    content_type: content_type_val,
                     tags: tags_val,
                 });
             }
@@ -1000,7 +1024,9 @@ impl SecretBackend for AwsSecretBackend {
         vault: &str,
     ) -> Result<Vec<DeletedSecretSummary>, BackendError> {
         use crate::backend::aws::encoding::{is_marker, strip_prefix};
-        use crate::backend::aws::metadata::TAG_ORIGINAL_NAME;
+        use crate::backend::aws::metadata::{
+            decode_groups, TAG_FOLDER, TAG_GROUPS, TAG_ORIGINAL_NAME,
+        };
         use aws_sdk_secretsmanager::types::{Filter, FilterNameStringType};
 
         let prefix = format!("{vault}/");
@@ -1045,6 +1071,26 @@ impl SecretBackend for AwsSecretBackend {
                     .map(String::from)
                     .filter(|n| !n.is_empty())
                     .unwrap_or_else(|| secret_name.clone());
+                // Groups and folder under their canonical keys, as
+                // `props_from_describe` exposes them, for bulk filters.
+                let mut tags = HashMap::new();
+                for tag in entry.tags() {
+                    match (tag.key(), tag.value()) {
+                        (Some(TAG_GROUPS), Some(v)) => {
+                            let groups = decode_groups(v);
+                            if !groups.is_empty() {
+                                tags.insert("groups".to_string(), groups.join(","));
+                            }
+                        }
+                        (Some(TAG_FOLDER), Some(v)) if !v.is_empty() => {
+                            tags.insert("folder".to_string(), v.to_string());
+                        }
+                        (Some(k), Some(v)) if !k.starts_with("xv:") => {
+                            tags.insert(k.to_string(), v.to_string());
+                        }
+                        _ => {}
+                    }
+                }
                 summaries.push(DeletedSecretSummary {
                     name: secret_name,
                     original_name: original_name_val,
@@ -1055,6 +1101,8 @@ impl SecretBackend for AwsSecretBackend {
                     // ListSecrets doesn't expose the recovery window, so the
                     // purge time (DeletedDate + window) is unknowable here.
                     scheduled_purge_on: None,
+                    recovery_level: None,
+                    tags,
                 });
             }
 
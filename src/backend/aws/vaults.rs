@@ -93,6 +93,8 @@ impl VaultBackend for AwsVaultBackend {
             created_at: now,
             tags: request.tags.clone().unwrap_or_default(),
             enable_rbac_authorization: Some(false),
+            public_network_access: None,
+            network_acls: None,
         })
     }
 
@@ -155,6 +157,8 @@ impl VaultBackend for AwsVaultBackend {
             created_at,
             tags,
             enable_rbac_authorization: Some(false),
+            public_network_access: None,
+            network_acls: None,
         })
     }
 
@@ -198,8 +202,10 @@ impl VaultBackend for AwsVaultBackend {
                         name: vault.to_string(),
                         location: "aws".to_string(),
                         resource_group: "default".to_string(),
+                        subscription: None,
                         status: "Active".to_string(),
                         created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string(),
+                        secret_count: None,
                     });
                 }
             }
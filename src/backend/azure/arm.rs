@@ -0,0 +1,263 @@
+//! Typed Azure Resource Manager client
+//!
+//! Vault management, deleted-vault lookups, storage lifecycle policies, App
+//! Service reads and role assignments all go through ARM. [`ArmClient`] is
+//! the one place that talks to it: it attaches a management-plane bearer
+//! token, retries reads that fail transiently, classifies network and HTTP
+//! errors, bounds response sizes and follows `value`/`nextLink` paging.
+//! Callers build resource paths and deal in decoded bodies.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::auth::provider::AzureAuthProvider;
+use crate::backend::azure::kv_rest::Page;
+use crate::error::{CrosstacheError, Result};
+use crate::utils::correlation::with_client_request_id;
+use crate::utils::network::{
+    classify_http_response, classify_network_error, create_http_client, NetworkConfig,
+};
+use crate::utils::retry::{retry_with_backoff, RetryOptions};
+
+/// Azure Resource Manager in the public cloud.
+pub const ARM_ENDPOINT: &str = "https://management.azure.com";
+
+/// OAuth scope for Resource Manager tokens.
+pub const ARM_SCOPE: &str = "https://management.azure.com/.default";
+
+/// Retries for throttling, 5xx and transport errors. Only GETs are retried:
+/// a PUT, POST or DELETE that timed out may still have been applied, and
+/// sending it again could repeat its effect.
+fn retry_options() -> RetryOptions {
+    RetryOptions {
+        max_retries: 3,
+        initial_interval: Duration::from_secs(1),
+        max_interval: Duration::from_secs(10),
+        multiplier: 2.0,
+    }
+}
+
+/// A Resource Manager client shared by everything that manages Azure
+/// resources.
+pub struct ArmClient {
+    auth_provider: Arc<dyn AzureAuthProvider>,
+    http_client: reqwest::Client,
+}
+
+impl ArmClient {
+    pub fn new(auth_provider: Arc<dyn AzureAuthProvider>) -> Result<Self> {
+        Ok(Self {
+            auth_provider,
+            http_client: create_http_client(&NetworkConfig::default())?,
+        })
+    }
+
+    /// `https://management.azure.com{path}`; `path` starts with `/` and
+    /// carries its own `api-version`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{ARM_ENDPOINT}{path}")
+    }
+
+    /// The underlying HTTP client, for the Microsoft Graph calls that sit
+    /// next to ARM ones and should share its connection pool.
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.http_client
+    }
+
+    /// `GET url`, decoded; `None` on 404.
+    pub async fn get<T: DeserializeOwned>(&self, url: &str, context: &str) -> Result<Option<T>> {
+        self.execute::<()>(reqwest::Method::GET, url, None, context, true)
+            .await?
+            .map(|body| decode(&body, context))
+            .transpose()
+    }
+
+    /// `PUT url` with a JSON body, decoded.
+    pub async fn put<B, T>(&self, url: &str, body: &B, context: &str) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let response = self
+            .execute(reqwest::Method::PUT, url, Some(body), context, false)
+            .await?;
+        decode(&response.unwrap_or_default(), context)
+    }
+
+    /// `POST url`, with a JSON body when given, decoded. Actions that answer
+    /// `202 Accepted` without a body decode as JSON `null`, so ask for a
+    /// `serde_json::Value` or an `Option` there.
+    pub async fn post<B, T>(&self, url: &str, body: Option<&B>, context: &str) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let response = self
+            .execute(reqwest::Method::POST, url, body, context, false)
+            .await?;
+        decode(&response.unwrap_or_default(), context)
+    }
+
+    /// `DELETE url`; `false` on 404.
+    pub async fn delete(&self, url: &str, context: &str) -> Result<bool> {
+        Ok(self
+            .execute::<()>(reqwest::Method::DELETE, url, None, context, true)
+            .await?
+            .is_some())
+    }
+
+    /// Every item of a paged list, following `nextLink` up to
+    /// [`crate::utils::MAX_PAGES`] pages.
+    pub async fn list<T: DeserializeOwned>(&self, url: String, context: &str) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        self.find_in_pages(url, context, |page: Vec<T>| {
+            items.extend(page);
+            None::<()>
+        })
+        .await?;
+        Ok(items)
+    }
+
+    /// Hand each page of a paged list to `visit` until it returns `Some`,
+    /// which stops the paging and is returned; `None` when no page matched.
+    pub async fn find_in_pages<T, R>(
+        &self,
+        url: String,
+        context: &str,
+        mut visit: impl FnMut(Vec<T>) -> Option<R>,
+    ) -> Result<Option<R>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut next_url = Some(url);
+        let mut page_count: usize = 0;
+        while let Some(current_url) = next_url.take() {
+            page_count += 1;
+            if page_count > crate::utils::MAX_PAGES {
+                return Err(CrosstacheError::azure_api(format!(
+                    "Pagination exceeded maximum of {} pages",
+                    crate::utils::MAX_PAGES
+                )));
+            }
+            let body = self
+                .execute::<()>(reqwest::Method::GET, &current_url, None, context, false)
+                .await?
+                .unwrap_or_default();
+            let mut page: Page<T> = decode(&body, context)?;
+            next_url = page.take_next_link();
+            if let Some(found) = visit(page.value) {
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Send one request, retrying transient failures of GETs, and return its
+    /// body. A 404 is `None` when `missing_ok`, a classified error otherwise.
+    async fn execute<B: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&B>,
+        context: &str,
+        missing_ok: bool,
+    ) -> Result<Option<Vec<u8>>> {
+        let attempt = || async {
+            let token = self.auth_provider.get_token(&[ARM_SCOPE]).await?;
+            let mut request = with_client_request_id(self.http_client.request(method.clone(), url))
+                .bearer_auth(token.token.secret());
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| classify_network_error(&e, url))?;
+            if missing_ok && response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            if !response.status().is_success() {
+                return Err(classify_http_response(response, context).await);
+            }
+            read_bounded(response).await.map(Some)
+        };
+        if method == reqwest::Method::GET {
+            retry_with_backoff(attempt, retry_options()).await
+        } else {
+            attempt().await
+        }
+    }
+}
+
+/// Buffer a response body, enforcing [`crate::utils::MAX_RESPONSE_BYTES`].
+async fn read_bounded(response: reqwest::Response) -> Result<Vec<u8>> {
+    let max_bytes = crate::utils::MAX_RESPONSE_BYTES;
+    let too_large = |len: u64| {
+        CrosstacheError::azure_api(format!(
+            "Response body too large: {len} bytes (max: {max_bytes} bytes)"
+        ))
+    };
+    if let Some(content_length) = response.content_length() {
+        if content_length > max_bytes as u64 {
+            return Err(too_large(content_length));
+        }
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| CrosstacheError::azure_api(format!("Failed to read response body: {e}")))?;
+    if bytes.len() > max_bytes {
+        return Err(too_large(bytes.len() as u64));
+    }
+    Ok(bytes.to_vec())
+}
+
+/// Decode a JSON body; an empty body decodes as `null`.
+fn decode<T: DeserializeOwned>(body: &[u8], context: &str) -> Result<T> {
+    let body = if body.iter().all(u8::is_ascii_whitespace) {
+        b"null".as_slice()
+    } else {
+        body
+    };
+    serde_json::from_slice(body)
+        .map_err(|e| CrosstacheError::serialization(format!("{context}: unexpected response: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn empty_bodies_decode_as_null() {
+        assert_eq!(decode::<Value>(b"", "ctx").unwrap(), Value::Null);
+        assert_eq!(decode::<Option<Value>>(b" \n", "ctx").unwrap(), None);
+        assert_eq!(
+            decode::<Value>(br#"{"name":"kv"}"#, "ctx").unwrap(),
+            json!({"name": "kv"})
+        );
+        let err = decode::<Page<Value>>(b"<html>", "Failed to list vaults").unwrap_err();
+        assert!(err.to_string().contains("Failed to list vaults"), "{err}");
+    }
+
+    #[test]
+    fn pages_follow_next_link_and_tolerate_missing_value() {
+        let mut page: Page<Value> = decode(
+            br#"{"value":[{"id":"a"}],"nextLink":"https://management.azure.com/next"}"#,
+            "ctx",
+        )
+        .unwrap();
+        assert_eq!(page.value.len(), 1);
+        assert_eq!(
+            page.take_next_link().as_deref(),
+            Some("https://management.azure.com/next")
+        );
+
+        let mut last: Page<Value> = decode(b"{}", "ctx").unwrap();
+        assert!(last.value.is_empty());
+        assert_eq!(last.take_next_link(), None);
+    }
+}
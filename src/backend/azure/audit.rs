@@ -10,6 +10,7 @@ use crate::backend::audit::{AuditBackend, AuditEvent};
 use crate::backend::error::BackendError;
 use crate::error::{CrosstacheError, Result};
 
+use super::arm::ArmClient;
 use super::map_error;
 
 /// Azure Activity Log audit adapter.
@@ -60,46 +61,21 @@ impl AzureAuditBackend {
         let start_time_str = start_time.format("%Y-%m-%dT%H:%M:%S.%3fZ");
         let end_time_str = end_time.format("%Y-%m-%dT%H:%M:%S.%3fZ");
 
-        let activity_url = format!(
-            "https://management.azure.com/subscriptions/{}/providers/microsoft.insights/eventtypes/management/values?api-version=2015-04-01&$filter=eventTimestamp ge '{}' and eventTimestamp le '{}' and resourceUri eq '/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}'",
+        // The Activity Log pages its results; `list` follows `nextLink` so a
+        // busy vault's events past the first page are not dropped.
+        let arm = ArmClient::new(self.auth_provider.clone())?;
+        let activity_url = arm.url(&format!(
+            "/subscriptions/{}/providers/microsoft.insights/eventtypes/management/values?api-version=2015-04-01&$filter=eventTimestamp ge '{}' and eventTimestamp le '{}' and resourceUri eq '/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}'",
             self.subscription_id,
             start_time_str,
             end_time_str,
             self.subscription_id,
             resource_group,
             vault_name
-        );
+        ));
 
-        let token = self
-            .auth_provider
-            .get_token(&["https://management.azure.com/.default"])
-            .await?;
-
-        let response = reqwest::Client::new()
-            .get(&activity_url)
-            .header("Authorization", format!("Bearer {}", token.token.secret()))
-            .header("Content-Type", "application/json")
-            .send()
+        arm.list(activity_url, "Activity Log API request failed")
             .await
-            .map_err(|e| CrosstacheError::network(format!("Failed to fetch activity logs: {e}")))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(CrosstacheError::azure_api(format!(
-                "Activity Log API returned {status}: {error_text}"
-            )));
-        }
-
-        let activity_response: Value = response.json().await.map_err(|e| {
-            CrosstacheError::serialization(format!("Failed to parse activity logs: {e}"))
-        })?;
-
-        Ok(activity_response
-            .get("value")
-            .and_then(|v| v.as_array())
-            .cloned()
-            .unwrap_or_default())
     }
 
     async fn get_vault_audit_logs(
@@ -239,6 +215,12 @@ fn parse_activity_log_entry(event: &Value) -> Result<AuditEvent> {
         .unwrap_or("")
         .to_string();
 
+    let correlation_id = event
+        .get("correlationId")
+        .and_then(|v| v.as_str())
+        .filter(|id| !id.is_empty())
+        .map(|id| id.to_string());
+
     Ok(AuditEvent {
         timestamp,
         operation,
@@ -247,5 +229,6 @@ fn parse_activity_log_entry(event: &Value) -> Result<AuditEvent> {
         status,
         source_ip: None,
         event_id,
+        correlation_id,
     })
 }
@@ -68,8 +68,13 @@ For detailed troubleshooting, see: https://docs.microsoft.com/en-us/azure/develo
     CrosstacheError::authentication(format!("{error}\n\n{help_message}"))
 }
 
-/// Creates a user-friendly error message for token acquisition failures
-fn create_user_friendly_token_error(error: azure_core::Error) -> CrosstacheError {
+/// Creates a user-friendly error message for token acquisition failures,
+/// listing why each credential-chain source failed when `chain_report` is
+/// given.
+fn create_user_friendly_token_error(
+    error: azure_core::Error,
+    chain_report: Option<&str>,
+) -> CrosstacheError {
     let error_str = error.to_string().to_lowercase();
 
     let help_message = if error_str.contains("403") || error_str.contains("forbidden") {
@@ -106,7 +111,147 @@ fn create_user_friendly_token_error(error: azure_core::Error) -> CrosstacheError
 4. Ensure the Azure service is available"
     };
 
-    CrosstacheError::authentication(format!("{error}\n\n{help_message}"))
+    match chain_report {
+        Some(report) => CrosstacheError::authentication(format!(
+            "{error}\n\nCredential chain:\n{report}\n\n{help_message}"
+        )),
+        None => CrosstacheError::authentication(format!("{error}\n\n{help_message}")),
+    }
+}
+
+/// How long each credential-chain source gets during diagnosis. Off Azure
+/// the managed-identity endpoint does not answer at all, so without a bound
+/// its probe would hang.
+const DIAGNOSIS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// One source of the default credential chain and whether it produced a
+/// token, or why not.
+pub struct ChainAttempt {
+    pub source: &'static str,
+    pub outcome: std::result::Result<(), String>,
+}
+
+/// Try each source of the default credential chain on its own, in chain
+/// order and concurrently, so a failure can say what each one lacked: the
+/// service-principal variables, a managed-identity endpoint, an `az login`.
+pub async fn diagnose_credential_chain(scopes: &[&str]) -> Vec<ChainAttempt> {
+    use crate::config::settings::AzureCredentialType;
+
+    let environment = async {
+        let missing = missing_environment_variables();
+        if !missing.is_empty() {
+            return Err(format!("{} not set", missing.join(", ")));
+        }
+        let credential = EnvironmentCredential::create(TokenCredentialOptions::default())
+            .map_err(|e| summarize_credential_error(&e))?;
+        probe_credential(&credential, scopes).await
+    };
+    let managed_identity = async {
+        let credential = DefaultAzureCredentialProvider::create_prioritized_credential(
+            AzureCredentialType::ManagedIdentity,
+        )
+        .map_err(|e| e.to_string())?;
+        probe_credential(credential.as_ref(), scopes)
+            .await
+            .map_err(|reason| {
+                if reason == TIMED_OUT && std::env::var_os("IDENTITY_ENDPOINT").is_none() {
+                    "no managed-identity endpoint (IMDS) answered; not running on Azure?"
+                        .to_string()
+                } else {
+                    reason
+                }
+            })
+    };
+    let azure_cli = async {
+        if !crate::utils::environment::current().az_cli {
+            return Err("az CLI not installed".to_string());
+        }
+        probe_credential(&AzureCliCredential::new(), scopes)
+            .await
+            .map_err(|reason| {
+                if reason.contains("az login") || reason.to_lowercase().contains("not logged in") {
+                    "not signed in (run `az login`)".to_string()
+                } else {
+                    reason
+                }
+            })
+    };
+
+    let (environment, managed_identity, azure_cli) =
+        futures::join!(environment, managed_identity, azure_cli);
+    vec![
+        ChainAttempt {
+            source: "Environment",
+            outcome: environment,
+        },
+        ChainAttempt {
+            source: "Managed identity",
+            outcome: managed_identity,
+        },
+        ChainAttempt {
+            source: "Azure CLI",
+            outcome: azure_cli,
+        },
+    ]
+}
+
+/// One line per source, for an error message.
+pub fn format_chain_report(attempts: &[ChainAttempt]) -> String {
+    attempts
+        .iter()
+        .map(|attempt| match &attempt.outcome {
+            Ok(()) => format!("  - {}: token acquired", attempt.source),
+            Err(reason) => format!("  - {}: {reason}", attempt.source),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+const TIMED_OUT: &str = "timed out";
+
+async fn probe_credential(
+    credential: &dyn TokenCredential,
+    scopes: &[&str],
+) -> std::result::Result<(), String> {
+    match tokio::time::timeout(DIAGNOSIS_TIMEOUT, credential.get_token(scopes)).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(summarize_credential_error(&e)),
+        Err(_) => Err(TIMED_OUT.to_string()),
+    }
+}
+
+/// The service-principal variables `EnvironmentCredential` needs that are
+/// unset: tenant and client id, plus one of a secret or a certificate.
+fn missing_environment_variables() -> Vec<&'static str> {
+    let set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+    let mut missing: Vec<&'static str> = ["AZURE_TENANT_ID", "AZURE_CLIENT_ID"]
+        .into_iter()
+        .filter(|name| !set(name))
+        .collect();
+    if !set("AZURE_CLIENT_SECRET") && !set("AZURE_CLIENT_CERTIFICATE_PATH") {
+        missing.push("AZURE_CLIENT_SECRET (or AZURE_CLIENT_CERTIFICATE_PATH)");
+    }
+    missing
+}
+
+/// First line of a credential error, capped so one verbose source cannot
+/// bury the others.
+fn summarize_credential_error(error: &azure_core::Error) -> String {
+    summarize_reason(&error.to_string())
+}
+
+fn summarize_reason(message: &str) -> String {
+    const MAX: usize = 160;
+    let line = message
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("failed");
+    if line.chars().count() > MAX {
+        format!("{}...", line.chars().take(MAX).collect::<String>())
+    } else {
+        line.to_string()
+    }
 }
 
 /// Maximum time to wait for an `az` CLI invocation before giving up.
@@ -230,6 +375,10 @@ pub struct DefaultAzureCredentialProvider {
     credential: Arc<dyn TokenCredential>,
     http_client: Client,
     tenant_id: Option<String>,
+    /// The credential is (or may fall back to) the default chain, whose
+    /// own error does not say which sources were tried; a failed token
+    /// request then diagnoses each source.
+    diagnose_chain: bool,
 }
 
 impl DefaultAzureCredentialProvider {
@@ -247,6 +396,11 @@ impl DefaultAzureCredentialProvider {
         // wedge construction; failure is soft (tenant_id stays None).
         let tenant_id = az_account_tenant_id();
 
+        let diagnose_chain = matches!(
+            priority,
+            crate::config::settings::AzureCredentialType::Default
+                | crate::config::settings::AzureCredentialType::Environment
+        );
         let credential = Self::create_prioritized_credential(priority)?;
         let network_config = NetworkConfig::default();
         let http_client = create_http_client(&network_config)?;
@@ -255,6 +409,7 @@ impl DefaultAzureCredentialProvider {
             credential,
             http_client,
             tenant_id,
+            diagnose_chain,
         })
     }
 
@@ -306,7 +461,26 @@ impl DefaultAzureCredentialProvider {
                     }
                 }
             }
+            AzureCredentialType::DeviceCode => {
+                let http_client = create_http_client(&NetworkConfig::default())?;
+                Ok(
+                    Arc::new(super::device_code::DeviceCodeCredential::new(http_client))
+                        as Arc<dyn TokenCredential>,
+                )
+            }
             AzureCredentialType::Default => {
+                // In WSL or a container with no browser and no `az`, the
+                // default chain has nothing to sign in with; adapt instead.
+                let environment = crate::utils::environment::current();
+                if let Some(adapted) = environment
+                    .adapted_credential(crate::utils::environment::has_env_azure_credentials())
+                {
+                    tracing::debug!(
+                        "{} environment: using {adapted} credentials instead of the default chain",
+                        environment.describe()
+                    );
+                    return Self::create_prioritized_credential(adapted);
+                }
                 // Use the default credential chain
                 Ok(Arc::new(
                     DefaultAzureCredential::create(TokenCredentialOptions::default())
@@ -331,6 +505,7 @@ impl DefaultAzureCredentialProvider {
             credential,
             http_client,
             tenant_id: Some(tenant_id),
+            diagnose_chain: true,
         })
     }
 
@@ -463,13 +638,17 @@ fn tenant_id_from_jwt(token: &str) -> Result<String> {
 #[async_trait]
 impl AzureAuthProvider for DefaultAzureCredentialProvider {
     async fn get_token(&self, scopes: &[&str]) -> Result<AccessToken> {
-        let token_response = self
-            .credential
-            .get_token(scopes)
-            .await
-            .map_err(create_user_friendly_token_error)?;
-
-        Ok(token_response)
+        match self.credential.get_token(scopes).await {
+            Ok(token) => Ok(token),
+            Err(error) if self.diagnose_chain => {
+                let attempts = diagnose_credential_chain(scopes).await;
+                Err(create_user_friendly_token_error(
+                    error,
+                    Some(&format_chain_report(&attempts)),
+                ))
+            }
+            Err(error) => Err(create_user_friendly_token_error(error, None)),
+        }
     }
 
     async fn get_tenant_id(&self) -> Result<String> {
@@ -635,4 +814,28 @@ mod tests {
         assert!(tenant_id_from_jwt("only.two").is_err());
         assert!(tenant_id_from_jwt("a.b.c.d").is_err());
     }
+
+    #[test]
+    fn chain_report_lists_each_source_with_a_short_reason() {
+        let attempts = vec![
+            ChainAttempt {
+                source: "Environment",
+                outcome: Err("AZURE_CLIENT_ID not set".to_string()),
+            },
+            ChainAttempt {
+                source: "Azure CLI",
+                outcome: Ok(()),
+            },
+        ];
+        assert_eq!(
+            format_chain_report(&attempts),
+            "  - Environment: AZURE_CLIENT_ID not set\n  - Azure CLI: token acquired"
+        );
+
+        assert_eq!(
+            summarize_reason("\nERROR: Please run 'az login' to setup account.\ndetails"),
+            "ERROR: Please run 'az login' to setup account."
+        );
+        assert_eq!(summarize_reason(&"x".repeat(200)).len(), 163);
+    }
 }
@@ -5,7 +5,11 @@
 
 use crate::error::{CrosstacheError, Result};
 use serde_json::Value;
-use std::process::Command;
+use std::process::{Command, Output};
+use std::time::Duration;
+
+/// Upper bound for `az` calls that reach the network during detection.
+const AZ_NETWORK_TIMEOUT: Duration = Duration::from_secs(20);
 
 /// Azure environment detection information
 #[derive(Debug, Clone)]
@@ -220,6 +224,25 @@ impl AzureDetector {
         }
     }
 
+    /// Run an `az` command that talks to Azure, bounded by
+    /// [`AZ_NETWORK_TIMEOUT`]. The child inherits `HTTPS_PROXY`/`NO_PROXY`,
+    /// so it takes the same network path as xv's own requests; behind a
+    /// broken proxy it would otherwise retry for minutes.
+    async fn run_networked(args: &[&str]) -> Result<Output> {
+        let mut command = tokio::process::Command::new("az");
+        command.args(args).kill_on_drop(true);
+        match tokio::time::timeout(AZ_NETWORK_TIMEOUT, command.output()).await {
+            Ok(output) => output
+                .map_err(|e| CrosstacheError::config(format!("Failed to execute Azure CLI: {e}"))),
+            Err(_) => Err(CrosstacheError::connection_timeout(format!(
+                "`az {}` did not finish within {}s. Check your network connection; \
+                 behind a proxy, set HTTPS_PROXY and run `xv doctor` to test each endpoint.",
+                args.iter().take(2).copied().collect::<Vec<_>>().join(" "),
+                AZ_NETWORK_TIMEOUT.as_secs()
+            ))),
+        }
+    }
+
     /// Get detailed tenant information
     async fn get_tenant_details(tenant_id: &str) -> Option<AzureTenant> {
         // Validate tenant_id is a UUID before embedding in OData filter
@@ -230,19 +253,18 @@ impl AzureDetector {
             );
             return None;
         }
-        let output = Command::new("az")
-            .args([
-                "rest",
-                "--method",
-                "GET",
-                "--url",
-                &format!(
-                    "https://graph.microsoft.com/v1.0/organization?$filter={}",
-                    crate::utils::url_helpers::odata_eq("id", tenant_id)
-                ),
-            ])
-            .output()
-            .ok()?;
+        let output = Self::run_networked(&[
+            "rest",
+            "--method",
+            "GET",
+            "--url",
+            &format!(
+                "https://graph.microsoft.com/v1.0/organization?$filter={}",
+                crate::utils::url_helpers::odata_eq("id", tenant_id)
+            ),
+        ])
+        .await
+        .ok()?;
 
         if !output.status.success() {
             return None;
@@ -282,19 +304,17 @@ impl AzureDetector {
 
     /// Get available resource groups for a subscription
     pub async fn get_resource_groups(subscription_id: &str) -> Result<Vec<String>> {
-        let output = Command::new("az")
-            .args([
-                "group",
-                "list",
-                "--subscription",
-                subscription_id,
-                "--query",
-                "[].name",
-                "--output",
-                "json",
-            ])
-            .output()
-            .map_err(|e| CrosstacheError::config(format!("Failed to execute Azure CLI: {e}")))?;
+        let output = Self::run_networked(&[
+            "group",
+            "list",
+            "--subscription",
+            subscription_id,
+            "--query",
+            "[].name",
+            "--output",
+            "json",
+        ])
+        .await?;
 
         if !output.status.success() {
             let error_msg = String::from_utf8_lossy(&output.stderr);
@@ -323,19 +343,17 @@ impl AzureDetector {
 
     /// Get available locations for a subscription
     pub async fn get_locations(subscription_id: &str) -> Result<Vec<String>> {
-        let output = Command::new("az")
-            .args([
-                "account",
-                "list-locations",
-                "--subscription",
-                subscription_id,
-                "--query",
-                "[].name",
-                "--output",
-                "json",
-            ])
-            .output()
-            .map_err(|e| CrosstacheError::config(format!("Failed to execute Azure CLI: {e}")))?;
+        let output = Self::run_networked(&[
+            "account",
+            "list-locations",
+            "--subscription",
+            subscription_id,
+            "--query",
+            "[].name",
+            "--output",
+            "json",
+        ])
+        .await?;
 
         if !output.status.success() {
             let error_msg = String::from_utf8_lossy(&output.stderr);
@@ -367,17 +385,15 @@ impl AzureDetector {
         subscription_id: &str,
         resource_group: &str,
     ) -> Result<bool> {
-        let output = Command::new("az")
-            .args([
-                "group",
-                "exists",
-                "--subscription",
-                subscription_id,
-                "--name",
-                resource_group,
-            ])
-            .output()
-            .map_err(|e| CrosstacheError::config(format!("Failed to execute Azure CLI: {e}")))?;
+        let output = Self::run_networked(&[
+            "group",
+            "exists",
+            "--subscription",
+            subscription_id,
+            "--name",
+            resource_group,
+        ])
+        .await?;
 
         if !output.status.success() {
             return Ok(false);
@@ -466,7 +482,6 @@ impl AzureDetector {
     }
 
     /// Check if a storage account exists
-    #[allow(dead_code)]
     pub async fn storage_account_exists(
         subscription_id: &str,
         storage_account: &str,
@@ -0,0 +1,262 @@
+//! Device-code sign-in for shells without a browser
+//!
+//! The OAuth 2.0 device authorization grant against Microsoft Entra ID: xv
+//! prints a code and a URL, the user signs in on any other device, and xv
+//! polls until the sign-in completes. Used when `azure_credential_priority`
+//! is `device_code`, or automatically in WSL and containers where neither a
+//! browser nor the `az` CLI is available (see [`crate::utils::environment`]).
+//!
+//! The first token is requested with `offline_access`, and its refresh token
+//! (kept in memory only) redeems tokens for the other resources xv talks to,
+//! so one invocation prompts at most once. Nothing is persisted: the next
+//! invocation prompts again.
+
+use azure_core::auth::{AccessToken, TokenCredential};
+use azure_core::error::ErrorKind;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use zeroize::Zeroizing;
+
+/// Public client id of the Azure CLI, which every tenant has consented to
+/// for Key Vault, Resource Manager and Graph scopes.
+const AZURE_CLI_CLIENT_ID: &str = "04b07795-8ddb-461a-bbee-02f9e1bf7b46";
+
+/// Seconds added to the polling interval when Entra answers `slow_down`.
+const SLOW_DOWN_STEP: u64 = 5;
+
+/// Refresh a cached token this long before it expires.
+const EXPIRY_MARGIN: time::Duration = time::Duration::minutes(2);
+
+#[derive(Deserialize)]
+struct DeviceCodeResponse {
+    device_code: String,
+    message: String,
+    expires_in: u64,
+    #[serde(default = "default_interval")]
+    interval: u64,
+}
+
+fn default_interval() -> u64 {
+    5
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: i64,
+    refresh_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenError {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Default)]
+struct State {
+    refresh_token: Option<Zeroizing<String>>,
+    tokens: HashMap<String, AccessToken>,
+}
+
+/// A [`TokenCredential`] that signs in with the device-code flow.
+pub struct DeviceCodeCredential {
+    http_client: reqwest::Client,
+    authority: String,
+    tenant: String,
+    // A tokio mutex, held across the whole sign-in, so concurrent token
+    // requests wait for the one prompt instead of each starting their own.
+    state: Mutex<State>,
+}
+
+impl std::fmt::Debug for DeviceCodeCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeviceCodeCredential")
+            .field("authority", &self.authority)
+            .field("tenant", &self.tenant)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DeviceCodeCredential {
+    /// A credential for `AZURE_TENANT_ID` (any work or school account when
+    /// unset) at `AZURE_AUTHORITY_HOST` (public cloud when unset).
+    pub fn new(http_client: reqwest::Client) -> Self {
+        let non_empty = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        Self {
+            http_client,
+            authority: non_empty("AZURE_AUTHORITY_HOST")
+                .unwrap_or_else(|| "https://login.microsoftonline.com".to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            tenant: non_empty("AZURE_TENANT_ID").unwrap_or_else(|| "organizations".to_string()),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}/{}/oauth2/v2.0/{path}", self.authority, self.tenant)
+    }
+
+    async fn post(&self, path: &str, form: &[(&str, &str)]) -> azure_core::Result<(bool, String)> {
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(form)
+            .finish();
+        let response = self
+            .http_client
+            .post(self.endpoint(path))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| credential_error(format!("device-code sign-in request failed: {e}")))?;
+        let success = response.status().is_success();
+        let text = response.text().await.map_err(|e| {
+            credential_error(format!("device-code sign-in response unreadable: {e}"))
+        })?;
+        Ok((success, text))
+    }
+
+    /// Prompt the user and poll until they finish signing in.
+    async fn sign_in(&self, scope: &str) -> azure_core::Result<TokenResponse> {
+        let scope = format!("{scope} offline_access");
+        let (success, body) = self
+            .post(
+                "devicecode",
+                &[("client_id", AZURE_CLI_CLIENT_ID), ("scope", &scope)],
+            )
+            .await?;
+        if !success {
+            return Err(token_error("device-code sign-in", &body));
+        }
+        let device: DeviceCodeResponse = serde_json::from_str(&body)
+            .map_err(|e| credential_error(format!("unexpected device-code response: {e}")))?;
+        eprintln!("{}", device.message);
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(device.expires_in);
+        let mut interval = device.interval;
+        loop {
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+            if std::time::Instant::now() >= deadline {
+                return Err(credential_error(
+                    "the device code expired before sign-in completed; run the command again",
+                ));
+            }
+            let (success, body) = self
+                .post(
+                    "token",
+                    &[
+                        ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                        ("client_id", AZURE_CLI_CLIENT_ID),
+                        ("device_code", &device.device_code),
+                    ],
+                )
+                .await?;
+            if success {
+                return parse_token(&body);
+            }
+            match serde_json::from_str::<TokenError>(&body) {
+                Ok(e) if e.error == "authorization_pending" => {}
+                Ok(e) if e.error == "slow_down" => interval += SLOW_DOWN_STEP,
+                _ => return Err(token_error("device-code sign-in", &body)),
+            }
+        }
+    }
+
+    async fn redeem(&self, refresh_token: &str, scope: &str) -> azure_core::Result<TokenResponse> {
+        let (success, body) = self
+            .post(
+                "token",
+                &[
+                    ("grant_type", "refresh_token"),
+                    ("client_id", AZURE_CLI_CLIENT_ID),
+                    ("refresh_token", refresh_token),
+                    ("scope", scope),
+                ],
+            )
+            .await?;
+        if success {
+            parse_token(&body)
+        } else {
+            Err(token_error("token refresh", &body))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for DeviceCodeCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let scope = scopes.join(" ");
+        let mut state = self.state.lock().await;
+        if let Some(token) = state.tokens.get(&scope) {
+            if token.expires_on > time::OffsetDateTime::now_utc() + EXPIRY_MARGIN {
+                return Ok(token.clone());
+            }
+        }
+
+        let refreshed = match state.refresh_token.clone() {
+            Some(refresh_token) => self.redeem(&refresh_token, &scope).await.ok(),
+            None => None,
+        };
+        let response = match refreshed {
+            Some(response) => response,
+            None => self.sign_in(&scope).await?,
+        };
+
+        if let Some(refresh_token) = response.refresh_token {
+            state.refresh_token = Some(Zeroizing::new(refresh_token));
+        }
+        let token = AccessToken::new(
+            response.access_token,
+            time::OffsetDateTime::now_utc() + time::Duration::seconds(response.expires_in),
+        );
+        state.tokens.insert(scope, token.clone());
+        Ok(token)
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        *self.state.lock().await = State::default();
+        Ok(())
+    }
+}
+
+fn parse_token(body: &str) -> azure_core::Result<TokenResponse> {
+    serde_json::from_str(body)
+        .map_err(|e| credential_error(format!("unexpected token response: {e}")))
+}
+
+fn credential_error(message: impl Into<String>) -> azure_core::Error {
+    azure_core::Error::message(ErrorKind::Credential, message.into())
+}
+
+/// An Entra error body as a credential error, keeping its description
+/// (which carries the AADSTS code) and never the raw body.
+fn token_error(context: &str, body: &str) -> azure_core::Error {
+    let detail = match serde_json::from_str::<TokenError>(body) {
+        Ok(TokenError {
+            error_description: Some(description),
+            ..
+        }) => description.lines().next().unwrap_or_default().to_string(),
+        Ok(TokenError { error, .. }) => error,
+        Err(_) => "unexpected response from Microsoft Entra ID".to_string(),
+    };
+    credential_error(format!("{context} failed: {detail}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_errors_keep_only_the_first_description_line() {
+        let body = r#"{"error":"expired_token","error_description":"AADSTS70020: The provided value for the input parameter 'device_code' is not valid.\r\nTrace ID: 1"}"#;
+        let message = token_error("device-code sign-in", body).to_string();
+        assert!(message.contains("AADSTS70020"));
+        assert!(!message.contains("Trace ID"));
+
+        let message = token_error("token refresh", "<html>").to_string();
+        assert!(message.contains("unexpected response"));
+    }
+}
@@ -11,7 +11,10 @@ use async_trait::async_trait;
 use crate::backend::error::BackendError;
 use crate::backend::file::FileBackend;
 use crate::blob::manager::BlobManager;
-use crate::blob::models::{FileDownloadRequest, FileInfo, FileListRequest, FileUploadRequest};
+use crate::blob::models::{
+    FileCopyRequest, FileDetails, FileDownloadRequest, FileInfo, FileListPage, FileListRequest,
+    FilePropertiesUpdate, FileReadUrl, FileUploadRequest, FileUploadSpec,
+};
 use crate::utils::progress::{NoopReporter, ProgressReporter};
 
 use super::map_error;
@@ -28,6 +31,17 @@ impl AzureFileBackend {
     pub(crate) fn new(inner: Arc<BlobManager>) -> Self {
         Self { inner }
     }
+
+    /// Create the wrapped container (for scratch containers; the configured
+    /// one is created by `xv init`).
+    pub(crate) async fn create_container(&self) -> Result<(), BackendError> {
+        self.inner.create_container().await.map_err(map_error)
+    }
+
+    /// Delete the wrapped container with all its files.
+    pub(crate) async fn delete_container(&self) -> Result<(), BackendError> {
+        self.inner.delete_container().await.map_err(map_error)
+    }
 }
 
 /// Azure blob file storage is scoped to one container per storage account,
@@ -48,6 +62,22 @@ impl FileBackend for AzureFileBackend {
             .map_err(map_error)
     }
 
+    /// Staged block upload: blocks are sent as they are read.
+    async fn upload_file_from_reader(
+        &self,
+        _vault: &str,
+        spec: FileUploadSpec,
+        reader: &mut (dyn tokio::io::AsyncRead + Unpin + Send),
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<FileInfo, BackendError> {
+        let null = NoopReporter;
+        let reporter: &dyn ProgressReporter = reporter.unwrap_or(&null);
+        self.inner
+            .upload_large_file(spec, reader, None, reporter)
+            .await
+            .map_err(map_error)
+    }
+
     async fn download_file(
         &self,
         _vault: &str,
@@ -65,6 +95,22 @@ impl FileBackend for AzureFileBackend {
             .map_err(map_error)
     }
 
+    /// Ranged GETs: one chunk is held in memory at a time.
+    async fn download_file_to_writer(
+        &self,
+        _vault: &str,
+        name: &str,
+        writer: &mut (dyn tokio::io::AsyncWrite + Unpin + Send),
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<u64, BackendError> {
+        let null = NoopReporter;
+        let reporter: &dyn ProgressReporter = reporter.unwrap_or(&null);
+        self.inner
+            .download_file_stream(name, writer, reporter)
+            .await
+            .map_err(map_error)
+    }
+
     async fn list_files(
         &self,
         _vault: &str,
@@ -73,6 +119,18 @@ impl FileBackend for AzureFileBackend {
         self.inner.list_files(request).await.map_err(map_error)
     }
 
+    async fn list_files_page(
+        &self,
+        _vault: &str,
+        request: FileListRequest,
+        marker: Option<&str>,
+    ) -> Result<FileListPage, BackendError> {
+        self.inner
+            .list_files_page(request, marker)
+            .await
+            .map_err(map_error)
+    }
+
     async fn delete_file(&self, _vault: &str, name: &str) -> Result<(), BackendError> {
         self.inner.delete_file(name).await.map_err(map_error)
     }
@@ -81,6 +139,43 @@ impl FileBackend for AzureFileBackend {
         self.inner.get_file_info(name).await.map_err(map_error)
     }
 
+    async fn get_file_details(
+        &self,
+        _vault: &str,
+        name: &str,
+    ) -> Result<FileDetails, BackendError> {
+        self.inner.get_file_details(name).await.map_err(map_error)
+    }
+
+    async fn update_file_properties(
+        &self,
+        _vault: &str,
+        name: &str,
+        update: FilePropertiesUpdate,
+    ) -> Result<FileInfo, BackendError> {
+        self.inner
+            .update_file_properties(name, update)
+            .await
+            .map_err(map_error)
+    }
+
+    async fn copy_file(
+        &self,
+        _vault: &str,
+        request: FileCopyRequest,
+    ) -> Result<FileInfo, BackendError> {
+        self.inner.copy_file(request).await.map_err(map_error)
+    }
+
+    async fn read_url(
+        &self,
+        _vault: &str,
+        name: &str,
+        ttl: std::time::Duration,
+    ) -> Result<FileReadUrl, BackendError> {
+        self.inner.read_url(name, ttl).await.map_err(map_error)
+    }
+
     async fn list_files_hierarchical(
         &self,
         _vault: &str,
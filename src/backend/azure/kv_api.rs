@@ -0,0 +1,239 @@
+//! Key Vault data-plane endpoints
+//!
+//! Every Key Vault REST call xv makes builds its URL here, so the
+//! `api-version` it speaks is one setting (`key_vault_api_version`, default
+//! [`DEFAULT_API_VERSION`]) rather than a literal per request. Operations
+//! that only exist from some API version on ask [`KeyVaultApi::require`]
+//! first, so pinning an older version fails with a clear message instead of
+//! a 400 from the service — and moving to a newer version is a config
+//! change plus, for new fields, a new [`Capability`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::backend::azure::types::AzureVaultName;
+use crate::error::{CrosstacheError, Result};
+
+/// The version xv is tested against. 7.4 is stable and covers every
+/// operation xv uses; it stays explicit so SDK crate bumps do not silently
+/// change the wire contract.
+pub const DEFAULT_API_VERSION: &str = "7.4";
+
+/// OAuth scope for Key Vault data-plane tokens.
+pub const KEY_VAULT_SCOPE: &str = "https://vault.azure.net/.default";
+
+/// A Key Vault `api-version`, ordered by release.
+///
+/// The service has used three schemes: dated versions up to 2016-10-01,
+/// then `7.0` through `7.6`, then dated versions again. Variant order
+/// follows that history, so the derived ordering is chronological.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ApiVersion {
+    /// `2015-06-01`, `2016-10-01`.
+    Legacy { year: u16, month: u8, day: u8 },
+    /// `7.0` ... `7.6`.
+    Numbered { major: u8, minor: u8 },
+    /// `2025-07-01` and later.
+    Dated { year: u16, month: u8, day: u8 },
+}
+
+/// First year of the second dated scheme; earlier dates are pre-7.0.
+const NUMBERED_ERA_END: u16 = 2018;
+
+impl FromStr for ApiVersion {
+    type Err = CrosstacheError;
+
+    /// Accepts `7.4`, `2025-07-01`, and either with a `-preview[...]` suffix.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            CrosstacheError::config(format!(
+                "Invalid Key Vault API version '{s}' (expected e.g. {DEFAULT_API_VERSION} or 2025-07-01)"
+            ))
+        };
+        let release = s.split("-preview").next().unwrap_or_default();
+
+        if let Some((major, minor)) = release.split_once('.') {
+            let major = major.parse::<u8>().map_err(|_| invalid())?;
+            let minor = minor.parse::<u8>().map_err(|_| invalid())?;
+            if major != 7 {
+                return Err(invalid());
+            }
+            return Ok(Self::Numbered { major, minor });
+        }
+
+        let mut parts = release.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let year = year.parse::<u16>().map_err(|_| invalid())?;
+        let month = month.parse::<u8>().map_err(|_| invalid())?;
+        let day = day.parse::<u8>().map_err(|_| invalid())?;
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || year < 2015 {
+            return Err(invalid());
+        }
+        Ok(if year < NUMBERED_ERA_END {
+            Self::Legacy { year, month, day }
+        } else {
+            Self::Dated { year, month, day }
+        })
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numbered { major, minor } => write!(f, "{major}.{minor}"),
+            Self::Legacy { year, month, day } | Self::Dated { year, month, day } => {
+                write!(f, "{year:04}-{month:02}-{day:02}")
+            }
+        }
+    }
+}
+
+/// A Key Vault feature xv uses that not every API version has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// `secrets/{name}/backup` and `secrets/restore`.
+    Backup,
+    /// The soft-delete endpoints under `deletedsecrets` (list, show,
+    /// recover, purge).
+    DeletedSecrets,
+}
+
+impl Capability {
+    /// The first API version with this feature.
+    pub fn since(self) -> ApiVersion {
+        match self {
+            Self::Backup => ApiVersion::Legacy {
+                year: 2016,
+                month: 10,
+                day: 1,
+            },
+            Self::DeletedSecrets => ApiVersion::Numbered { major: 7, minor: 0 },
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Backup => "secret backup and restore",
+            Self::DeletedSecrets => "soft-deleted secrets",
+        }
+    }
+}
+
+/// URL construction and capability checks for one Key Vault `api-version`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyVaultApi {
+    /// Sent verbatim as `api-version`, preview suffix included.
+    raw: String,
+    version: ApiVersion,
+}
+
+impl Default for KeyVaultApi {
+    fn default() -> Self {
+        Self::new(DEFAULT_API_VERSION).expect("default Key Vault API version parses")
+    }
+}
+
+impl KeyVaultApi {
+    /// Validate `version` (the `key_vault_api_version` setting).
+    pub fn new(version: &str) -> Result<Self> {
+        let raw = version.trim().to_string();
+        let version = raw.parse()?;
+        Ok(Self { raw, version })
+    }
+
+    /// Whether this API version has `capability`.
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.version >= capability.since()
+    }
+
+    /// Fail with a configuration error naming the version to switch to when
+    /// this API version lacks `capability`.
+    pub fn require(&self, capability: Capability) -> Result<()> {
+        if self.supports(capability) {
+            return Ok(());
+        }
+        Err(CrosstacheError::config(format!(
+            "Key Vault API version {} has no {}; it needs {} or later (set key_vault_api_version)",
+            self.raw,
+            capability.describe(),
+            capability.since()
+        )))
+    }
+
+    /// `https://{vault}.vault.azure.net/{segments...}?api-version={version}`,
+    /// with each segment percent-encoded.
+    pub fn url(&self, vault_name: &AzureVaultName, path_segments: &[&str]) -> Result<String> {
+        let mut url = vault_name.key_vault_url()?;
+        {
+            let mut segments = url.path_segments_mut().map_err(|_| {
+                CrosstacheError::invalid_url(format!(
+                    "Cannot build Key Vault URL for vault '{}'",
+                    vault_name.as_str()
+                ))
+            })?;
+            segments.clear();
+            segments.extend(path_segments.iter().copied());
+        }
+        url.query_pairs_mut().append_pair("api-version", &self.raw);
+        Ok(url.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault() -> AzureVaultName {
+        AzureVaultName::try_from("myvault").unwrap()
+    }
+
+    #[test]
+    fn versions_parse_and_order_chronologically() {
+        let v = |s: &str| s.parse::<ApiVersion>().unwrap();
+        assert!(v("2016-10-01") < v("7.0"));
+        assert!(v("7.0") < v("7.4"));
+        assert!(v("7.4") < v("7.6-preview.2"));
+        assert!(v("7.6") < v("2025-07-01"));
+        assert_eq!(v("7.5-preview.1"), v("7.5"));
+        assert_eq!(v("2025-07-01").to_string(), "2025-07-01");
+
+        for bad in ["", "7", "8.0", "7.x", "2025-13-01", "2014-01-01", "latest"] {
+            assert!(bad.parse::<ApiVersion>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn urls_carry_the_configured_version_verbatim() {
+        assert_eq!(
+            KeyVaultApi::default()
+                .url(&vault(), &["secrets", "my secret", "versions"])
+                .unwrap(),
+            "https://myvault.vault.azure.net/secrets/my%20secret/versions?api-version=7.4"
+        );
+        assert_eq!(
+            KeyVaultApi::new("7.6-preview.2")
+                .unwrap()
+                .url(&vault(), &["deletedsecrets"])
+                .unwrap(),
+            "https://myvault.vault.azure.net/deletedsecrets?api-version=7.6-preview.2"
+        );
+    }
+
+    #[test]
+    fn capabilities_follow_the_version() {
+        let api = KeyVaultApi::default();
+        assert!(api.supports(Capability::Backup));
+        assert!(api.supports(Capability::DeletedSecrets));
+
+        let legacy = KeyVaultApi::new("2016-10-01").unwrap();
+        assert!(legacy.require(Capability::Backup).is_ok());
+        let err = legacy.require(Capability::DeletedSecrets).unwrap_err();
+        assert!(err.to_string().contains("needs 7.0 or later"), "{err}");
+
+        let oldest = KeyVaultApi::new("2015-06-01").unwrap();
+        assert!(!oldest.supports(Capability::Backup));
+    }
+}
@@ -0,0 +1,444 @@
+//! Typed Key Vault secrets REST client
+//!
+//! The azure_security_keyvault crate omits tags and other fields xv needs,
+//! so secret operations talk to the REST API directly. This module holds
+//! the one place that does it: [`KvRestClient`] attaches the bearer token,
+//! builds URLs through [`KeyVaultApi`], classifies network and HTTP
+//! failures, bounds response sizes and follows `nextLink` paging, and the
+//! serde models below are the request and response shapes from the Key
+//! Vault secrets API. Callers deal in [`SecretBundle`]s, not JSON.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use zeroize::Zeroizing;
+
+use crate::auth::provider::AzureAuthProvider;
+use crate::backend::azure::kv_api::{KeyVaultApi, KEY_VAULT_SCOPE};
+use crate::backend::azure::types::AzureVaultName;
+use crate::error::{CrosstacheError, Result};
+use crate::utils::correlation::with_client_request_id;
+use crate::utils::network::{
+    classify_http_response, classify_network_error, create_http_client, NetworkConfig,
+};
+
+/// `SecretAttributes`: the timestamps are epoch seconds. Only `enabled`,
+/// `nbf` and `exp` are writable; the rest are skipped when serializing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretAttributes {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(default, skip_serializing)]
+    pub created: Option<i64>,
+    #[serde(default, skip_serializing)]
+    pub updated: Option<i64>,
+    #[serde(default, skip_serializing)]
+    pub recovery_level: Option<String>,
+}
+
+impl SecretAttributes {
+    /// No writable attribute is set, so a request can omit the object.
+    pub fn is_unset(&self) -> bool {
+        self.enabled.is_none() && self.nbf.is_none() && self.exp.is_none()
+    }
+}
+
+/// `SecretBundle` (get, set, update, recover, restore) and `SecretItem`
+/// (list pages), which is the same shape without `value`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretBundle {
+    /// `https://{vault}.vault.azure.net/secrets/{name}[/{version}]`.
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub value: Option<Zeroizing<String>>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub attributes: SecretAttributes,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub tags: HashMap<String, String>,
+}
+
+impl SecretBundle {
+    /// The name and version segments of a secret `id`, either empty when
+    /// absent.
+    pub fn name_and_version(&self) -> (&str, &str) {
+        let mut segments = self.id.as_deref().unwrap_or_default().rsplit('/');
+        let last = segments.next().unwrap_or_default();
+        match segments.next() {
+            // `.../secrets/{name}` on list items: no version segment.
+            Some("secrets") | None => (last, ""),
+            Some(name) => (name, last),
+        }
+    }
+}
+
+/// `DeletedSecretBundle` / `DeletedSecretItem`: a bundle plus its deletion
+/// dates (epoch seconds). The `id` is the deleted-secret URL, so its last
+/// segment is the name.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedSecretBundle {
+    #[serde(flatten)]
+    pub secret: SecretBundle,
+    #[serde(default)]
+    pub deleted_date: Option<i64>,
+    #[serde(default)]
+    pub scheduled_purge_date: Option<i64>,
+}
+
+/// One page of a list response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    // `Vec::new` rather than plain `default`, which would make serde
+    // require `T: Default`.
+    #[serde(default = "Vec::new")]
+    pub value: Vec<T>,
+    #[serde(default)]
+    pub next_link: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// `nextLink`, treating an empty string like an absent one.
+    pub fn take_next_link(&mut self) -> Option<String> {
+        self.next_link.take().filter(|link| !link.is_empty())
+    }
+}
+
+/// `SecretSetParameters`: the body of `PUT secrets/{name}`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretSetParameters<'a> {
+    pub value: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "SecretAttributes::is_unset")]
+    pub attributes: SecretAttributes,
+}
+
+/// `SecretUpdateParameters`: the body of `PATCH secrets/{name}`. Omitted
+/// fields are left unchanged by the service.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretUpdateParameters<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "SecretAttributes::is_unset")]
+    pub attributes: SecretAttributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<&'a HashMap<String, String>>,
+}
+
+/// `BackupSecretResult` and `SecretRestoreParameters`: a base64url
+/// protected backup blob.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BackupBlob {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+fn null_as_default<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Authenticated calls to one Key Vault API version.
+pub struct KvRestClient {
+    auth_provider: Arc<dyn AzureAuthProvider>,
+    api: KeyVaultApi,
+    // Built on first use and shared by every call, so the concurrent
+    // per-secret reads in a listing reuse pooled connections.
+    http: OnceLock<reqwest::Client>,
+}
+
+impl KvRestClient {
+    pub fn new(auth_provider: Arc<dyn AzureAuthProvider>) -> Self {
+        Self {
+            auth_provider,
+            api: KeyVaultApi::default(),
+            http: OnceLock::new(),
+        }
+    }
+
+    /// Call `api` (`key_vault_api_version` in config) instead of the default.
+    pub fn with_api(mut self, api: KeyVaultApi) -> Self {
+        self.api = api;
+        self
+    }
+
+    pub fn api(&self) -> &KeyVaultApi {
+        &self.api
+    }
+
+    /// The URL of `path_segments` under `vault_name` at this API version.
+    pub fn url(&self, vault_name: &AzureVaultName, path_segments: &[&str]) -> Result<String> {
+        self.api.url(vault_name, path_segments)
+    }
+
+    fn http(&self) -> Result<&reqwest::Client> {
+        if let Some(client) = self.http.get() {
+            return Ok(client);
+        }
+        let client = create_http_client(&NetworkConfig::default())?;
+        Ok(self.http.get_or_init(|| client))
+    }
+
+    /// Send one request with a fresh bearer token. Network failures are
+    /// classified; the HTTP status is left to the caller.
+    pub async fn send<B: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<reqwest::Response> {
+        let token = self.auth_provider.get_token(&[KEY_VAULT_SCOPE]).await?;
+        let mut request = with_client_request_id(self.http()?.request(method, url))
+            .bearer_auth(token.token.secret());
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        request
+            .send()
+            .await
+            .map_err(|e| classify_network_error(&e, url))
+    }
+
+    /// Send a request and decode its JSON response: `None` on 404, a
+    /// classified error (`context` leads its message) on any other failure.
+    pub async fn call<B, T>(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&B>,
+        context: &str,
+    ) -> Result<Option<T>>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let response = self.send(method, url, body).await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(classify_http_response(response, context).await);
+        }
+        read_json(response).await.map(Some)
+    }
+
+    /// `GET url`, decoded; `None` on 404.
+    pub async fn get<T: DeserializeOwned>(&self, url: &str, context: &str) -> Result<Option<T>> {
+        self.call::<(), T>(reqwest::Method::GET, url, None, context)
+            .await
+    }
+
+    /// `POST url` with a JSON body (Key Vault wants `{}` even for actions
+    /// that take no parameters), decoded; `None` on 404.
+    pub async fn post<B, T>(&self, url: &str, body: &B, context: &str) -> Result<Option<T>>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.call(reqwest::Method::POST, url, Some(body), context)
+            .await
+    }
+
+    /// `DELETE url`; `false` on 404.
+    pub async fn delete(&self, url: &str, context: &str) -> Result<bool> {
+        let response = self.send::<()>(reqwest::Method::DELETE, url, None).await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        if !response.status().is_success() {
+            return Err(classify_http_response(response, context).await);
+        }
+        Ok(true)
+    }
+
+    /// Every item of a paged list, following `nextLink` up to
+    /// [`crate::utils::MAX_PAGES`] pages. A 404 is an error here: list
+    /// endpoints only return it when the vault itself is missing.
+    pub async fn list<T: DeserializeOwned>(&self, url: String, context: &str) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        self.for_each_page(url, context, |page: Page<T>| items.extend(page.value))
+            .await?;
+        Ok(items)
+    }
+
+    /// Hand each page of a paged list to `visit`, following `nextLink`.
+    pub async fn for_each_page<T: DeserializeOwned>(
+        &self,
+        url: String,
+        context: &str,
+        mut visit: impl FnMut(Page<T>),
+    ) -> Result<()> {
+        let mut next_url = Some(url);
+        let mut page_count: usize = 0;
+        while let Some(current_url) = next_url.take() {
+            page_count += 1;
+            if page_count > crate::utils::MAX_PAGES {
+                return Err(CrosstacheError::azure_api(format!(
+                    "Pagination exceeded maximum of {} pages",
+                    crate::utils::MAX_PAGES
+                )));
+            }
+            let response = self
+                .send::<()>(reqwest::Method::GET, &current_url, None)
+                .await?;
+            if !response.status().is_success() {
+                return Err(classify_http_response(response, context).await);
+            }
+            let mut page: Page<T> = read_json(response).await?;
+            next_url = page.take_next_link();
+            visit(page);
+        }
+        Ok(())
+    }
+}
+
+/// Deserialize a JSON response body while enforcing
+/// [`crate::utils::MAX_RESPONSE_BYTES`].
+///
+/// Checks the `Content-Length` header first (fast path) and then verifies
+/// the actual byte count after buffering, to guard against oversized responses.
+pub async fn read_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+    let max_bytes = crate::utils::MAX_RESPONSE_BYTES;
+    if let Some(content_length) = response.content_length() {
+        if content_length > max_bytes as u64 {
+            return Err(CrosstacheError::azure_api(format!(
+                "Response body too large: {} bytes (max: {} bytes)",
+                content_length, max_bytes
+            )));
+        }
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| CrosstacheError::azure_api(format!("Failed to read response body: {e}")))?;
+    if bytes.len() > max_bytes {
+        return Err(CrosstacheError::azure_api(format!(
+            "Response body too large: {} bytes (max: {} bytes)",
+            bytes.len(),
+            max_bytes
+        )));
+    }
+    serde_json::from_slice(&bytes)
+        .map_err(|e| CrosstacheError::serialization(format!("Failed to parse JSON response: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundles_deserialize_and_split_their_id() {
+        let bundle: SecretBundle = serde_json::from_value(serde_json::json!({
+            "id": "https://myvault.vault.azure.net/secrets/my-secret/abc123",
+            "value": "s3cret",
+            "contentType": "text/plain",
+            "attributes": { "enabled": false, "created": 1_700_000_000, "recoveryLevel": "Recoverable" },
+            "tags": { "original_name": "My Secret" }
+        }))
+        .unwrap();
+        assert_eq!(bundle.name_and_version(), ("my-secret", "abc123"));
+        assert_eq!(bundle.value.as_deref().map(String::as_str), Some("s3cret"));
+        assert_eq!(bundle.attributes.enabled, Some(false));
+        assert_eq!(
+            bundle.attributes.recovery_level.as_deref(),
+            Some("Recoverable")
+        );
+
+        let item: SecretBundle = serde_json::from_value(serde_json::json!({
+            "id": "https://myvault.vault.azure.net/secrets/listed",
+            "attributes": null,
+            "tags": null
+        }))
+        .unwrap();
+        assert_eq!(item.name_and_version(), ("listed", ""));
+        assert!(item.tags.is_empty());
+        assert_eq!(SecretBundle::default().name_and_version(), ("", ""));
+    }
+
+    #[test]
+    fn deleted_bundles_and_pages_deserialize() {
+        let mut page: Page<DeletedSecretBundle> = serde_json::from_value(serde_json::json!({
+            "value": [{
+                "id": "https://myvault.vault.azure.net/deletedsecrets/gone",
+                "recoveryId": "https://myvault.vault.azure.net/deletedsecrets/gone",
+                "deletedDate": 1_707_000_000,
+                "scheduledPurgeDate": 1_714_776_000,
+                "attributes": { "recoveryLevel": "Recoverable+Purgeable" }
+            }],
+            "nextLink": ""
+        }))
+        .unwrap();
+        assert_eq!(page.value[0].deleted_date, Some(1_707_000_000));
+        assert_eq!(
+            page.value[0].secret.id.as_deref(),
+            Some("https://myvault.vault.azure.net/deletedsecrets/gone")
+        );
+        assert_eq!(page.take_next_link(), None);
+    }
+
+    #[test]
+    fn pages_decode_items_without_default() {
+        #[derive(Debug, Deserialize)]
+        struct Item {
+            id: String,
+        }
+        let page: Page<Item> =
+            serde_json::from_value(serde_json::json!({ "value": [{ "id": "a" }] })).unwrap();
+        assert_eq!(page.value[0].id, "a");
+        let empty: Page<Item> = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(empty.value.is_empty());
+    }
+
+    #[test]
+    fn request_bodies_omit_unset_fields() {
+        let set = SecretSetParameters {
+            value: "v",
+            tags: None,
+            content_type: None,
+            attributes: SecretAttributes {
+                created: Some(1),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&set).unwrap(),
+            serde_json::json!({ "value": "v" })
+        );
+
+        let update = SecretUpdateParameters {
+            content_type: Some("text/plain"),
+            attributes: SecretAttributes {
+                enabled: Some(false),
+                exp: Some(1_800_000_000),
+                ..Default::default()
+            },
+            tags: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            serde_json::json!({
+                "contentType": "text/plain",
+                "attributes": { "enabled": false, "exp": 1_800_000_000 }
+            })
+        );
+    }
+}
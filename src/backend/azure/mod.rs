@@ -7,9 +7,13 @@
 //!
 //! This is a *thin adapter layer* — no business logic is duplicated.
 
+pub mod arm;
 pub mod audit;
 pub mod auth;
 pub mod detect;
+pub mod device_code;
+pub mod kv_api;
+pub mod kv_rest;
 #[allow(clippy::module_inception)]
 pub mod secrets;
 pub mod types;
@@ -50,10 +54,20 @@ pub fn map_error(err: CrosstacheError) -> BackendError {
         }
         CrosstacheError::AuthenticationError(msg) => BackendError::AuthenticationFailed(msg),
         CrosstacheError::PermissionDenied(msg) => BackendError::PermissionDenied(msg),
-        CrosstacheError::Conflict(msg) => BackendError::Conflict(msg),
-        CrosstacheError::RateLimited(_msg) => BackendError::RateLimited {
-            retry_after_secs: None,
+        CrosstacheError::Forbidden {
+            message,
+            required_permission,
+        } => BackendError::Forbidden {
+            message,
+            required_permission,
         },
+        CrosstacheError::NotFound { resource, message } => {
+            BackendError::ResourceNotFound { resource, message }
+        }
+        CrosstacheError::Conflict(msg) => BackendError::Conflict(msg),
+        CrosstacheError::RateLimited {
+            retry_after_secs, ..
+        } => BackendError::RateLimited { retry_after_secs },
         CrosstacheError::NetworkError(msg) => BackendError::Network(msg),
         CrosstacheError::DnsResolutionError {
             vault_name,
@@ -79,7 +93,7 @@ use self::files::AzureFileBackend;
 #[cfg(feature = "file-ops")]
 use super::FileBackend;
 #[cfg(feature = "file-ops")]
-use crate::blob::manager::BlobManager;
+use crate::blob::manager::{BlobManager, StorageKeySecret};
 
 /// Azure Key Vault backend — wraps all existing Azure implementations
 /// behind the new [`Backend`] trait.
@@ -107,8 +121,8 @@ impl AzureBackend {
         }
 
         // Secret backend
-        let secret_ops = Arc::new(AzureSecretOperations::new(auth_provider.clone()));
-        let secret_backend = AzureSecretBackend::new(secret_ops);
+        let secret_ops = secret_operations(config, &auth_provider)?;
+        let secret_backend = AzureSecretBackend::new(secret_ops.clone());
 
         // Vault backend
         let vault_ops = Arc::new(
@@ -131,16 +145,12 @@ impl AzureBackend {
         let file_backend = {
             let blob_config = config.get_blob_config();
             if !blob_config.storage_account.is_empty() {
-                let blob_manager = BlobManager::new(
-                    auth_provider.clone(),
-                    blob_config.storage_account.clone(),
-                    blob_config.container_name.clone(),
-                )
-                .map_err(|e| BackendError::Internal(e.to_string()))?
-                .with_blob_config(
-                    blob_config.chunk_size_mb,
-                    blob_config.max_concurrent_uploads,
-                );
+                let blob_manager = blob_manager(
+                    config,
+                    &auth_provider,
+                    secret_ops.clone(),
+                    &blob_config.container_name,
+                )?;
                 Some(AzureFileBackend::new(Arc::new(blob_manager)))
             } else {
                 None
@@ -168,6 +178,79 @@ impl AzureBackend {
     }
 }
 
+fn secret_operations(
+    config: &Config,
+    auth_provider: &Arc<dyn AzureAuthProvider>,
+) -> Result<Arc<AzureSecretOperations>, BackendError> {
+    Ok(Arc::new(
+        AzureSecretOperations::new(auth_provider.clone())
+            .with_detail_concurrency(config.fetch_concurrency)
+            .with_api(kv_api::KeyVaultApi::new(&config.key_vault_api_version).map_err(map_error)?),
+    ))
+}
+
+/// A blob manager for `container` of the configured storage account, with
+/// the configured transfer settings, endpoint and credentials.
+#[cfg(feature = "file-ops")]
+fn blob_manager(
+    config: &Config,
+    auth_provider: &Arc<dyn AzureAuthProvider>,
+    secret_ops: Arc<AzureSecretOperations>,
+    container: &str,
+) -> Result<BlobManager, BackendError> {
+    let blob_config = config.get_blob_config();
+    let mut blob_manager = BlobManager::new(
+        auth_provider.clone(),
+        blob_config.storage_account.clone(),
+        container.to_string(),
+    )
+    .map_err(|e| BackendError::Internal(e.to_string()))?
+    .with_blob_config(
+        blob_config.chunk_size_mb,
+        blob_config.max_concurrent_uploads,
+    )
+    .with_transfer_retries(blob_config.transfer_retries)
+    .with_endpoint(blob_config.endpoint.clone());
+    if let Some(reference) = &blob_config.key_secret {
+        // `<vault>/<secret>`, or a bare name in the default vault.
+        let (vault, name) = reference
+            .split_once('/')
+            .unwrap_or((config.default_vault.as_str(), reference.as_str()));
+        if vault.is_empty() {
+            return Err(BackendError::InvalidArgument(format!(
+                "storage_key_secret '{reference}' needs a vault: set default_vault \
+                 or use '<vault>/<secret>'"
+            )));
+        }
+        blob_manager = blob_manager.with_key_secret(StorageKeySecret {
+            secrets: secret_ops,
+            vault: vault.to_string(),
+            name: name.to_string(),
+        });
+    }
+    Ok(blob_manager)
+}
+
+/// A file backend on another container of the configured storage account,
+/// signed like the backend's own. `xv selftest` uses it for a scratch
+/// container it creates and deletes.
+#[cfg(feature = "file-ops")]
+pub(crate) fn file_backend_for_container(
+    config: &Config,
+    auth_provider: Arc<dyn AzureAuthProvider>,
+    container: &str,
+) -> Result<AzureFileBackend, BackendError> {
+    if config.get_blob_config().storage_account.is_empty() {
+        return Err(BackendError::InvalidArgument(
+            "no storage account is configured (run 'xv init --blob-only' or set storage_account)"
+                .into(),
+        ));
+    }
+    let secret_ops = secret_operations(config, &auth_provider)?;
+    let manager = blob_manager(config, &auth_provider, secret_ops, container)?;
+    Ok(AzureFileBackend::new(Arc::new(manager)))
+}
+
 #[async_trait]
 impl Backend for AzureBackend {
     fn name(&self) -> &'static str {
@@ -236,7 +319,7 @@ impl Backend for AzureBackend {
     async fn health_check(&self) -> Result<(), BackendError> {
         // Verify we can obtain an Azure token (cheap connectivity check).
         self.auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
+            .get_token(&[kv_api::KEY_VAULT_SCOPE])
             .await
             .map_err(|e| BackendError::AuthenticationFailed(e.to_string()))?;
         Ok(())
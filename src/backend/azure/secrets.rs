@@ -33,33 +33,37 @@ impl AzureSecretBackend {
         Self { inner }
     }
 
-    /// Fetch the current tags of a secret, tolerating disabled secrets.
+    /// Fetch the current tags of a secret without reading its value.
     ///
-    /// `GET {vault}/secrets/{name}` returns HTTP 403 `SecretDisabled` for a
-    /// disabled secret, but the versions list (`GET .../versions`) still
-    /// exposes attributes and tags, so use it as the second source.
+    /// The versions list (`GET .../versions`) carries each version's
+    /// attributes and tags but never a value, and still answers for a
+    /// disabled secret, so the newest entry is the primary source. `GET
+    /// {vault}/secrets/{name}` ships the value over the wire (and returns
+    /// HTTP 403 `SecretDisabled` for a disabled secret); it is only the
+    /// fallback for callers that may get but not list.
     async fn current_tags(
         &self,
         vault: &str,
         name: &str,
     ) -> Result<HashMap<String, String>, BackendError> {
-        let get_err = match self.inner.get_secret(vault, name, false).await {
-            Ok(current) => return Ok(current.tags),
+        let list_err = match self.inner.get_secret_versions(vault, name).await {
+            Ok(mut versions) => {
+                versions.sort_by_key(|v| v.created_timestamp);
+                return versions
+                    .pop()
+                    .map(|v| v.tags)
+                    .ok_or_else(|| BackendError::NotFound {
+                        name: name.to_string(),
+                        suggestion: None,
+                    });
+            }
             Err(e) => e,
         };
-        let mut versions = self
-            .inner
-            .get_secret_versions(vault, name)
+        self.inner
+            .get_secret(vault, name, false)
             .await
-            .map_err(|_| map_error(get_err))?;
-        versions.sort_by_key(|v| v.created_timestamp);
-        versions
-            .pop()
-            .map(|v| v.tags)
-            .ok_or_else(|| BackendError::NotFound {
-                name: name.to_string(),
-                suggestion: None,
-            })
+            .map(|current| current.tags)
+            .map_err(|_| map_error(list_err))
     }
 
     /// Resolve a user-friendly version identifier ("v6" / "6") to the Azure
@@ -248,6 +252,10 @@ impl SecretBackend for AzureSecretBackend {
             .map_err(map_error)
     }
 
+    async fn count_secrets(&self, vault: &str) -> Result<usize, BackendError> {
+        self.inner.count_secrets(vault).await.map_err(map_error)
+    }
+
     async fn delete_secret(&self, vault: &str, name: &str) -> Result<(), BackendError> {
         self.inner
             .delete_secret(vault, name)
@@ -261,13 +269,13 @@ impl SecretBackend for AzureSecretBackend {
         name: &str,
         request: SecretUpdateRequest,
     ) -> Result<SecretProperties, BackendError> {
-        // Attributes/tags-only updates (no value change) go
-        // through `PATCH {vault}/secrets/{name}` instead of the full-write
-        // path below: the full write must read the current value first and
-        // confirm afterwards, both of which return HTTP 403 `SecretDisabled`
-        // on a disabled secret — making `xv update --enabled true` (re-enable)
-        // impossible. PATCH touches only attributes/tags, works on disabled
-        // secrets, and creates no new version.
+        // Attributes/tags-only updates (no value change) go through
+        // `PATCH {vault}/secrets/{name}` instead of the full-write path
+        // below: the full write must read the current value first, which
+        // returns HTTP 403 `SecretDisabled` on a disabled secret — making
+        // `xv update --enabled true` (re-enable) impossible. PATCH touches
+        // only attributes/tags, works on disabled secrets, and creates no
+        // new version.
         //
         // Clearing exp/nbf still needs the full write: omitted PATCH
         // attribute fields are left unchanged, so a clear cannot be expressed.
@@ -469,6 +477,17 @@ impl SecretBackend for AzureSecretBackend {
             .map_err(map_error)
     }
 
+    async fn get_deleted_secret(
+        &self,
+        vault: &str,
+        name: &str,
+    ) -> Result<Option<DeletedSecretSummary>, BackendError> {
+        self.inner
+            .get_deleted_secret(vault, name)
+            .await
+            .map_err(map_error)
+    }
+
     async fn backup_secret(&self, vault: &str, name: &str) -> Result<Vec<u8>, BackendError> {
         self.inner
             .backup_secret(vault, name)
@@ -686,6 +705,7 @@ mod atomic_conversion_update_tests {
     struct AtomicUpdateMock {
         reads: AtomicUsize,
         updates: Mutex<Vec<SecretRequest>>,
+        patches: Mutex<Vec<SecretAttributesUpdate>>,
     }
 
     impl AtomicUpdateMock {
@@ -693,6 +713,7 @@ mod atomic_conversion_update_tests {
             Self {
                 reads: AtomicUsize::new(0),
                 updates: Mutex::new(Vec::new()),
+                patches: Mutex::new(Vec::new()),
             }
         }
     }
@@ -712,6 +733,7 @@ mod atomic_conversion_update_tests {
             not_before: None,
             tags: HashMap::new(),
             content_type: String::new(),
+            id: None,
             recovery_level: None,
         }
     }
@@ -738,6 +760,16 @@ mod atomic_conversion_update_tests {
             Ok(properties())
         }
 
+        async fn update_secret_attributes(
+            &self,
+            _vault: &str,
+            _name: &str,
+            update: &SecretAttributesUpdate,
+        ) -> Result<SecretProperties> {
+            self.patches.lock().unwrap().push(update.clone());
+            Ok(properties())
+        }
+
         async fn set_secret(&self, _v: &str, _r: &SecretRequest) -> Result<SecretProperties> {
             unimplemented!()
         }
@@ -769,7 +801,12 @@ mod atomic_conversion_update_tests {
             unimplemented!()
         }
         async fn get_secret_versions(&self, _v: &str, _n: &str) -> Result<Vec<SecretProperties>> {
-            unimplemented!()
+            let mut older = properties();
+            older.tags = HashMap::from([("custom".into(), "stale".into())]);
+            let mut newest = properties();
+            newest.created_timestamp = 10;
+            newest.tags = HashMap::from([("custom".into(), "keep".into())]);
+            Ok(vec![newest, older])
         }
         async fn rollback_secret(
             &self,
@@ -831,6 +868,76 @@ mod atomic_conversion_update_tests {
         assert_eq!(updates[0].note.as_deref(), Some("preserved"));
         assert_eq!(updates[0].folder, None);
     }
+
+    #[tokio::test]
+    async fn attribute_only_update_patches_without_writing_a_version() {
+        let inner = Arc::new(AtomicUpdateMock::new());
+        let backend = AzureSecretBackend::new(inner.clone());
+        let expires = Utc.with_ymd_and_hms(2030, 1, 2, 0, 0, 0).unwrap();
+        let request = SecretUpdateRequest {
+            name: "secret".into(),
+            expected_revision: None,
+            value: None,
+            content_type: None,
+            enabled: Some(false),
+            expires_on: FieldUpdate::Set(expires),
+            not_before: FieldUpdate::Unchanged,
+            tags: None,
+            groups: None,
+            note: FieldUpdate::Unchanged,
+            folder: FieldUpdate::Unchanged,
+            replace_tags: false,
+            replace_groups: false,
+        };
+
+        backend
+            .update_secret("vault", "secret", request)
+            .await
+            .unwrap();
+
+        // No tag change: nothing to merge, so no read at all.
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 0);
+        assert!(inner.updates.lock().unwrap().is_empty());
+        let patches = inner.patches.lock().unwrap();
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].enabled, Some(false));
+        assert_eq!(patches[0].expires_on, Some(expires));
+        assert_eq!(patches[0].not_before, None);
+        assert!(patches[0].tags.is_none());
+    }
+
+    #[tokio::test]
+    async fn tag_only_update_merges_over_newest_version_tags_without_a_value_read() {
+        let inner = Arc::new(AtomicUpdateMock::new());
+        let backend = AzureSecretBackend::new(inner.clone());
+        let request = SecretUpdateRequest {
+            name: "secret".into(),
+            expected_revision: None,
+            value: None,
+            content_type: None,
+            enabled: None,
+            expires_on: FieldUpdate::Unchanged,
+            not_before: FieldUpdate::Unchanged,
+            tags: None,
+            groups: None,
+            note: FieldUpdate::Set("new note".into()),
+            folder: FieldUpdate::Unchanged,
+            replace_tags: false,
+            replace_groups: false,
+        };
+
+        backend
+            .update_secret("vault", "secret", request)
+            .await
+            .unwrap();
+
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 0);
+        assert!(inner.updates.lock().unwrap().is_empty());
+        let patches = inner.patches.lock().unwrap();
+        let tags = patches[0].tags.as_ref().expect("tag change sends tags");
+        assert_eq!(tags.get("custom").map(String::as_str), Some("keep"));
+        assert_eq!(tags.get("note").map(String::as_str), Some("new note"));
+    }
 }
 
 #[cfg(test)]
@@ -861,6 +968,7 @@ mod rollback_version_resolver_tests {
             not_before: None,
             tags: HashMap::new(),
             content_type: String::new(),
+            id: None,
             recovery_level: None,
         }
     }
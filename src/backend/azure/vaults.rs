@@ -4,6 +4,7 @@
 //! [`VaultOperations`]) behind the new [`VaultBackend`] trait.
 
 #[allow(unused_imports)]
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -12,12 +13,16 @@ use crate::backend::error::BackendError;
 use crate::backend::vault::VaultBackend;
 use crate::config::settings::Config;
 use crate::vault::models::{
-    AccessLevel, VaultCreateRequest, VaultProperties, VaultRole, VaultSummary, VaultUpdateRequest,
+    AccessLevel, DeletedVaultSummary, GuestInvitation, PolicyFinding, VaultCreateRequest,
+    VaultNameStatus, VaultPermission, VaultProperties, VaultRole, VaultSummary, VaultUpdateRequest,
 };
 use crate::vault::operations::VaultOperations;
 
 use super::map_error;
 
+/// Upper bound on concurrent per-subscription ARM listings.
+const SUBSCRIPTION_LIST_CONCURRENCY: usize = 4;
+
 /// Adapter that implements [`VaultBackend`] by delegating to an existing
 /// [`VaultOperations`] implementation (i.e. `AzureVaultOperations`).
 ///
@@ -95,6 +100,53 @@ impl VaultBackend for AzureVaultBackend {
             .map_err(map_error)
     }
 
+    /// Lists up to [`SUBSCRIPTION_LIST_CONCURRENCY`] subscriptions at once.
+    /// A subscription that refuses the listing (commonly: no Reader role) is
+    /// skipped with a warning rather than failing the others; only when every
+    /// subscription fails is the first error returned.
+    async fn list_vaults_all_subscriptions(&self) -> Result<Vec<VaultSummary>, BackendError> {
+        use futures::stream::{self, StreamExt};
+
+        let subscriptions = self.inner.list_subscriptions().await.map_err(map_error)?;
+        let results: Vec<_> = stream::iter(subscriptions)
+            .map(|sub| async move {
+                let listed = self.inner.list_vaults(Some(&sub.id), None).await;
+                (sub, listed)
+            })
+            .buffer_unordered(SUBSCRIPTION_LIST_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut vaults = Vec::new();
+        let mut listed_any = false;
+        let mut first_error = None;
+        for (sub, listed) in results {
+            match listed {
+                Ok(found) => {
+                    listed_any = true;
+                    vaults.extend(found.into_iter().map(|mut vault| {
+                        vault.subscription = Some(sub.display_name.clone());
+                        vault
+                    }));
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "skipping subscription '{}' ({}): {e}",
+                        sub.display_name,
+                        sub.id
+                    );
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        if let Some(e) = first_error.filter(|_| !listed_any) {
+            return Err(map_error(e));
+        }
+
+        vaults.sort_by(|a, b| (&a.subscription, &a.name).cmp(&(&b.subscription, &b.name)));
+        Ok(vaults)
+    }
+
     async fn delete_vault(
         &self,
         name: &str,
@@ -201,6 +253,118 @@ impl VaultBackend for AzureVaultBackend {
         Ok(props.enable_rbac_authorization == Some(true))
     }
 
+    async fn get_vault_tags(
+        &self,
+        name: &str,
+        resource_group: Option<&str>,
+    ) -> Result<HashMap<String, String>, BackendError> {
+        // Read the ARM document directly: get_vault would also resolve every
+        // access-policy principal through Graph, which tags don't need.
+        let resource = self.get_vault_resource(name, resource_group).await?;
+        Ok(resource
+            .get("tags")
+            .and_then(|tags| tags.as_object())
+            .map(|tags| {
+                tags.iter()
+                    .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn get_vault_resource(
+        &self,
+        name: &str,
+        resource_group: Option<&str>,
+    ) -> Result<serde_json::Value, BackendError> {
+        let resource_group = resource_group.unwrap_or(&self.default_resource_group);
+        self.inner
+            .get_vault_resource(name, resource_group)
+            .await
+            .map_err(map_error)
+    }
+
+    async fn check_vault_name(&self, name: &str) -> Result<VaultNameStatus, BackendError> {
+        match self
+            .inner
+            .check_name_availability(name)
+            .await
+            .map_err(map_error)?
+        {
+            // checkNameAvailability reports a soft-deleted reservation the
+            // same way as a live vault; tell the two apart when we can see it.
+            VaultNameStatus::Taken { message } => match self.inner.find_deleted_vault(name).await {
+                Ok(Some(deleted)) => Ok(VaultNameStatus::SoftDeleted(deleted)),
+                _ => Ok(VaultNameStatus::Taken { message }),
+            },
+            status => Ok(status),
+        }
+    }
+
+    async fn find_deleted_vault(
+        &self,
+        name: &str,
+    ) -> Result<Option<DeletedVaultSummary>, BackendError> {
+        self.inner.find_deleted_vault(name).await.map_err(map_error)
+    }
+
+    async fn diagnostics_enabled(
+        &self,
+        vault: &str,
+        resource_group: Option<&str>,
+    ) -> Result<bool, BackendError> {
+        let resource_group = resource_group.unwrap_or(&self.default_resource_group);
+        self.inner
+            .diagnostics_enabled(vault, resource_group)
+            .await
+            .map_err(map_error)
+    }
+
+    async fn missing_permissions(
+        &self,
+        vault: &str,
+        resource_group: Option<&str>,
+        needed: &[VaultPermission],
+    ) -> Result<Vec<VaultPermission>, BackendError> {
+        // Access policies aren't role assignments, so the permissions API
+        // would report nothing granted; only RBAC-mode vaults can be checked.
+        let resource = self.get_vault_resource(vault, resource_group).await?;
+        let uses_rbac = resource
+            .pointer("/properties/enableRbacAuthorization")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        if !uses_rbac {
+            return Err(BackendError::Unsupported(
+                "permission preflight for access-policy vaults".into(),
+            ));
+        }
+        let resource_group = resource_group.unwrap_or(&self.default_resource_group);
+        let grants = self
+            .inner
+            .caller_data_actions(vault, resource_group)
+            .await
+            .map_err(map_error)?;
+        Ok(needed
+            .iter()
+            .copied()
+            .filter(|permission| {
+                !grants
+                    .iter()
+                    .any(|grant| grant.permits(permission.azure_data_action()))
+            })
+            .collect())
+    }
+
+    async fn check_create_policy(
+        &self,
+        request: &VaultCreateRequest,
+    ) -> Result<Vec<PolicyFinding>, BackendError> {
+        self.inner
+            .check_create_policy(request)
+            .await
+            .map_err(map_error)
+    }
+
     // ------------------------------------------------------------------
     // Secret-scoped RBAC
     // ------------------------------------------------------------------
@@ -264,4 +428,37 @@ impl VaultBackend for AzureVaultBackend {
     ) -> std::collections::HashMap<String, (String, String)> {
         self.inner.resolve_principal_ids(principal_ids).await
     }
+
+    async fn resolve_group(&self, group: &str) -> Result<String, BackendError> {
+        self.inner
+            .resolve_group_to_object_id(group)
+            .await
+            .map_err(map_error)
+    }
+
+    async fn invite_guest(
+        &self,
+        email: &str,
+        redirect_url: &str,
+        send_email: bool,
+    ) -> Result<GuestInvitation, BackendError> {
+        self.inner
+            .invite_guest(email, redirect_url, send_email)
+            .await
+            .map_err(map_error)
+    }
+
+    async fn principal_exists(&self, object_id: &str) -> Result<bool, BackendError> {
+        self.inner
+            .principal_exists(object_id)
+            .await
+            .map_err(map_error)
+    }
+
+    async fn group_member_counts(
+        &self,
+        group_ids: &[String],
+    ) -> std::collections::HashMap<String, usize> {
+        self.inner.group_member_counts(group_ids).await
+    }
 }
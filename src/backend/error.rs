@@ -35,6 +35,18 @@ pub enum BackendError {
     #[error("permission denied: {0}")]
     PermissionDenied(String),
 
+    /// The service refused the request, possibly naming the permission it
+    /// wanted.
+    #[error("forbidden: {message}")]
+    Forbidden {
+        message: String,
+        required_permission: Option<String>,
+    },
+
+    /// Something other than a secret or a vault was not found.
+    #[error("not found: {resource}: {message}")]
+    ResourceNotFound { resource: String, message: String },
+
     /// The operation is not supported by this backend.
     ///
     /// The `String` payload describes the unsupported feature
@@ -101,6 +113,16 @@ impl From<BackendError> for CrosstacheError {
             }
             BackendError::AuthenticationFailed(msg) => CrosstacheError::AuthenticationError(msg),
             BackendError::PermissionDenied(msg) => CrosstacheError::PermissionDenied(msg),
+            BackendError::Forbidden {
+                message,
+                required_permission,
+            } => CrosstacheError::Forbidden {
+                message,
+                required_permission,
+            },
+            BackendError::ResourceNotFound { resource, message } => {
+                CrosstacheError::NotFound { resource, message }
+            }
             BackendError::Unsupported(feature) => {
                 CrosstacheError::InvalidArgument(format!("operation not supported: {feature}"))
             }
@@ -120,7 +142,10 @@ impl From<BackendError> for CrosstacheError {
                     Some(secs) => format!("rate limited — retry after {secs}s"),
                     None => "rate limited".to_string(),
                 };
-                CrosstacheError::RateLimited(detail)
+                CrosstacheError::RateLimited {
+                    message: detail,
+                    retry_after_secs,
+                }
             }
             BackendError::Network(msg) => CrosstacheError::NetworkError(msg),
             BackendError::RenameIncomplete {
@@ -225,7 +250,8 @@ mod tests {
             retry_after_secs: Some(30),
         };
         let ce: CrosstacheError = be.into();
-        assert!(matches!(ce, CrosstacheError::RateLimited(_)));
+        assert!(matches!(ce, CrosstacheError::RateLimited { .. }));
+        assert_eq!(ce.retry_after_secs(), Some(30));
     }
 
     #[test]
@@ -234,6 +260,29 @@ mod tests {
             retry_after_secs: None,
         };
         let ce: CrosstacheError = be.into();
-        assert!(matches!(ce, CrosstacheError::RateLimited(ref s) if s == "rate limited"));
+        assert!(
+            matches!(ce, CrosstacheError::RateLimited { ref message, retry_after_secs: None } if message == "rate limited")
+        );
+    }
+
+    #[test]
+    fn forbidden_round_trips_through_the_azure_mapping() {
+        let ce = CrosstacheError::from_http_status(
+            403,
+            None,
+            "kv",
+            "Action: 'Microsoft.KeyVault/vaults/secrets/getSecret/action'".into(),
+        );
+        let back: CrosstacheError = crate::backend::azure::map_error(ce).into();
+        assert_eq!(
+            back.required_permission(),
+            Some("Microsoft.KeyVault/vaults/secrets/getSecret/action")
+        );
+
+        let missing = CrosstacheError::from_http_status(404, None, "acct/files", "gone".into());
+        let back: CrosstacheError = crate::backend::azure::map_error(missing).into();
+        assert!(
+            matches!(back, CrosstacheError::NotFound { ref resource, .. } if resource == "acct/files")
+        );
     }
 }
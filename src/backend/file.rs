@@ -5,7 +5,10 @@
 
 use async_trait::async_trait;
 
-use crate::blob::models::{BlobListItem, FileInfo, FileListRequest, FileUploadRequest};
+use crate::blob::models::{
+    BlobListItem, FileCopyRequest, FileDetails, FileInfo, FileListPage, FileListRequest,
+    FilePropertiesUpdate, FileReadUrl, FileUploadRequest, FileUploadSpec,
+};
 use crate::utils::progress::ProgressReporter;
 
 use super::error::BackendError;
@@ -45,6 +48,38 @@ pub trait FileBackend: Send + Sync {
         reporter: Option<&dyn ProgressReporter>,
     ) -> Result<FileInfo, BackendError>;
 
+    /// Upload a stream of unknown length, such as stdin (`xv upload -`).
+    ///
+    /// The default reads the whole stream and calls [`upload_file`]. Azure
+    /// and AWS override it to send blocks/parts as the bytes arrive, so
+    /// memory stays bounded by a few chunks however long the stream is.
+    ///
+    /// [`upload_file`]: FileBackend::upload_file
+    async fn upload_file_from_reader(
+        &self,
+        vault: &str,
+        spec: FileUploadSpec,
+        reader: &mut (dyn tokio::io::AsyncRead + Unpin + Send),
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<FileInfo, BackendError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(|e| BackendError::Internal(format!("read upload stream: {e}")))?;
+        let request = FileUploadRequest {
+            name: spec.name,
+            content,
+            content_type: spec.content_type,
+            groups: spec.groups,
+            metadata: spec.metadata,
+            tags: spec.tags,
+        };
+        self.upload_file(vault, request, reporter).await
+    }
+
     /// Create a file only when the destination is absent at the backend's
     /// commit point. Implementations must never replace an existing file.
     ///
@@ -70,6 +105,34 @@ pub trait FileBackend: Send + Sync {
         reporter: Option<&dyn ProgressReporter>,
     ) -> Result<Vec<u8>, BackendError>;
 
+    /// Stream a file's contents to `writer` and return its size in bytes.
+    ///
+    /// The default buffers the whole file through [`download_file`];
+    /// backends that can read a blob in pieces (Azure ranged GETs, the S3
+    /// body stream) override this so memory stays bounded by one chunk.
+    ///
+    /// [`download_file`]: FileBackend::download_file
+    async fn download_file_to_writer(
+        &self,
+        vault: &str,
+        name: &str,
+        writer: &mut (dyn tokio::io::AsyncWrite + Unpin + Send),
+        reporter: Option<&dyn ProgressReporter>,
+    ) -> Result<u64, BackendError> {
+        use tokio::io::AsyncWriteExt;
+
+        let content = self.download_file(vault, name, reporter).await?;
+        writer
+            .write_all(&content)
+            .await
+            .map_err(|e| BackendError::Internal(format!("write downloaded data: {e}")))?;
+        writer
+            .flush()
+            .await
+            .map_err(|e| BackendError::Internal(format!("flush downloaded data: {e}")))?;
+        Ok(content.len() as u64)
+    }
+
     /// List files matching the request criteria.
     async fn list_files(
         &self,
@@ -77,12 +140,130 @@ pub trait FileBackend: Send + Sync {
         request: FileListRequest,
     ) -> Result<Vec<FileInfo>, BackendError>;
 
+    /// One page of the flat listing: at most `request.limit` files (all of
+    /// them when `None`), resuming after `marker`, the `next_marker` of the
+    /// previous page.
+    ///
+    /// The default pages through the full [`list_files`] result with a
+    /// numeric offset marker. Backends with native continuation (Azure
+    /// `NextMarker`, S3 continuation tokens) override this so a page costs one
+    /// request instead of a full enumeration. Markers are opaque to callers
+    /// and only valid for the backend that issued them.
+    ///
+    /// [`list_files`]: FileBackend::list_files
+    async fn list_files_page(
+        &self,
+        vault: &str,
+        request: FileListRequest,
+        marker: Option<&str>,
+    ) -> Result<FileListPage, BackendError> {
+        let offset = match marker {
+            Some(marker) => marker.parse::<usize>().map_err(|_| {
+                BackendError::InvalidArgument(format!("invalid continuation token '{marker}'"))
+            })?,
+            None => 0,
+        };
+        let page_size = request.limit;
+        let files = self
+            .list_files(
+                vault,
+                FileListRequest {
+                    limit: None,
+                    ..request
+                },
+            )
+            .await?;
+
+        let end = page_size.map_or(files.len(), |n| offset.saturating_add(n).min(files.len()));
+        let next_marker = (end < files.len()).then(|| end.to_string());
+        let files = files
+            .into_iter()
+            .skip(offset)
+            .take(end.saturating_sub(offset))
+            .collect();
+        Ok(FileListPage { files, next_marker })
+    }
+
     /// Delete a file by name.
     async fn delete_file(&self, vault: &str, name: &str) -> Result<(), BackendError>;
 
     /// Get metadata about a file without downloading it.
     async fn get_file_info(&self, vault: &str, name: &str) -> Result<FileInfo, BackendError>;
 
+    /// [`get_file_info`] plus the storage properties `xv file info` shows
+    /// (tier, lease state, version id, encryption, MD5). The default reports
+    /// none of them.
+    ///
+    /// [`get_file_info`]: FileBackend::get_file_info
+    async fn get_file_details(&self, vault: &str, name: &str) -> Result<FileDetails, BackendError> {
+        self.get_file_info(vault, name).await.map(FileDetails::from)
+    }
+
+    /// Replace a file's groups, metadata and (when `update.tags` is set) tags
+    /// in place, returning the updated file. The content is not rewritten.
+    async fn update_file_properties(
+        &self,
+        _vault: &str,
+        _name: &str,
+        _update: FilePropertiesUpdate,
+    ) -> Result<FileInfo, BackendError> {
+        Err(BackendError::Unsupported("updating file properties".into()))
+    }
+
+    /// Copy a stored file to `request.destination`, keeping its content
+    /// type, groups, metadata and tags. Fails with [`BackendError::Conflict`]
+    /// when the destination exists and `request.overwrite` is not set.
+    ///
+    /// The default reads the file and uploads it again under the new name.
+    /// Backends with a server-side copy (Azure Copy Blob, S3 `CopyObject`)
+    /// override this so the content never leaves the service; only they
+    /// support `destination_container`.
+    async fn copy_file(
+        &self,
+        vault: &str,
+        request: FileCopyRequest,
+    ) -> Result<FileInfo, BackendError> {
+        if request.destination_container.is_some() {
+            return Err(BackendError::Unsupported(
+                "copying files to another container".into(),
+            ));
+        }
+        let source = self.get_file_info(vault, &request.source).await?;
+        if !request.overwrite {
+            match self.get_file_info(vault, &request.destination).await {
+                Ok(_) => return Err(destination_exists(&request.destination)),
+                Err(BackendError::NotFound { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let content = self.download_file(vault, &request.source, None).await?;
+        self.upload_file(
+            vault,
+            FileUploadRequest {
+                name: request.destination,
+                content,
+                content_type: Some(source.content_type),
+                groups: source.groups,
+                metadata: source.metadata,
+                tags: source.tags,
+            },
+            None,
+        )
+        .await
+    }
+
+    /// A presigned URL that grants read access to one file for `ttl`, for
+    /// handing a file to someone without storage credentials. Backends
+    /// without presigning reject the operation.
+    async fn read_url(
+        &self,
+        _vault: &str,
+        _name: &str,
+        _ttl: std::time::Duration,
+    ) -> Result<FileReadUrl, BackendError> {
+        Err(BackendError::Unsupported("presigned read URLs".into()))
+    }
+
     /// List files one level deep, collapsing everything below the next
     /// `request.delimiter` boundary into [`BlobListItem::Directory`] entries
     /// (the `xv file ls` non-recursive view).
@@ -142,12 +323,25 @@ pub trait FileBackend: Send + Sync {
     }
 }
 
+/// The [`FileBackend::copy_file`] error for an existing destination.
+pub(crate) fn destination_exists(name: &str) -> BackendError {
+    BackendError::Conflict(format!(
+        "file '{name}' already exists (use --force to overwrite)"
+    ))
+}
+
+/// Hex MD5 of `content`, in the form [`FileDetails::content_md5`] reports.
+pub(crate) fn content_md5(content: &[u8]) -> String {
+    use md5::Digest;
+    hex::encode(md5::Md5::digest(content))
+}
+
 #[cfg(test)]
 mod default_hierarchical_tests {
     //! Behavior lock for the default `list_files_hierarchical` derivation
-    //! (the impl local backends inherit). A flat-list stub feeds canned
-    //! `FileInfo`s through the default, asserting one-level dir/file collapse
-    //! and folder-prefix semantics.
+    //! (the impl local backends inherit), plus the default offset paging. A
+    //! flat-list stub feeds canned `FileInfo`s through the default, asserting
+    //! one-level dir/file collapse and folder-prefix semantics.
 
     use super::*;
     use crate::blob::models::BlobListItem;
@@ -275,6 +469,37 @@ mod default_hierarchical_tests {
         assert_eq!(files, vec!["docs/x.txt", "docs/y.txt"]);
     }
 
+    #[tokio::test]
+    async fn default_page_resumes_from_offset_marker() {
+        let mut request = req(None, None);
+        request.limit = Some(4);
+        let first = stub()
+            .list_files_page("v", request.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(first.files.len(), 4);
+        assert_eq!(first.next_marker.as_deref(), Some("4"));
+
+        let last = stub()
+            .list_files_page("v", request.clone(), first.next_marker.as_deref())
+            .await
+            .unwrap();
+        let (_, files) = names(
+            &last
+                .files
+                .into_iter()
+                .map(BlobListItem::File)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(files, vec!["docs-extra/e.txt", "img/p.png"]);
+        assert!(last.next_marker.is_none());
+
+        assert!(stub()
+            .list_files_page("v", request, Some("bogus"))
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     async fn no_delimiter_is_flat_files_only() {
         let items = stub()
@@ -18,7 +18,9 @@ use sha2::{Digest, Sha256};
 
 use crate::backend::error::BackendError;
 use crate::backend::file::FileBackend;
-use crate::blob::models::{FileInfo, FileListRequest, FileUploadRequest};
+use crate::blob::models::{
+    FileDetails, FileInfo, FileListRequest, FilePropertiesUpdate, FileUploadRequest,
+};
 use crate::utils::progress::ProgressReporter;
 
 use super::{crypto, paths};
@@ -1111,9 +1113,11 @@ impl LocalFileBackend {
         let info = FileInfo {
             name: request.name.clone(),
             size: original_size,
-            content_type: request
-                .content_type
-                .unwrap_or_else(|| "application/octet-stream".into()),
+            content_type: crate::blob::content_type::resolve(
+                request.content_type.as_deref(),
+                &request.name,
+                &request.content,
+            ),
             last_modified: now,
             etag: format!("\"{}\"", uuid::Uuid::new_v4()),
             groups: request.groups,
@@ -1408,6 +1412,68 @@ impl FileBackend for LocalFileBackend {
         serde_json::from_slice(&metadata)
             .map_err(|error| BackendError::Internal(format!("parse file metadata: {error}")))
     }
+
+    /// The store keeps no digest, so the MD5 is computed from the decrypted
+    /// content.
+    async fn get_file_details(&self, vault: &str, name: &str) -> Result<FileDetails, BackendError> {
+        let info = self.get_file_info(vault, name).await?;
+        let content = self.download_file(vault, name, None).await?;
+        Ok(FileDetails {
+            content_md5: Some(crate::backend::file::content_md5(&content)),
+            ..FileDetails::from(info)
+        })
+    }
+
+    /// Rewrites only the plaintext `.meta.json`: the new copy is staged in
+    /// the file's transaction directory and renamed over the active one, so
+    /// a crash leaves either the old or the new metadata. A transaction
+    /// directory without a journal is discarded by recovery.
+    async fn update_file_properties(
+        &self,
+        vault: &str,
+        name: &str,
+        update: FilePropertiesUpdate,
+    ) -> Result<FileInfo, BackendError> {
+        self.validate_file_name(name)?;
+        let not_found = || BackendError::NotFound {
+            name: name.to_string(),
+            suggestion: None,
+        };
+        let Some(chain) = existing_local_file_chain(&self.store_path, vault)? else {
+            return Err(not_found());
+        };
+        let _lock = lock_local_file_chain(&self.store_path, vault, &chain)?;
+        self.recover_all_locked(&chain)?;
+        let stem = storage_stem(name)?;
+        let active_meta = format!("{stem}.meta.json");
+        let Some(metadata) = chain.files.read_file(&active_meta)? else {
+            return Err(not_found());
+        };
+        let mut info: FileInfo = serde_json::from_slice(&metadata)
+            .map_err(|error| BackendError::Internal(format!("parse file metadata: {error}")))?;
+
+        info.groups = update.groups;
+        info.metadata = update.metadata;
+        if let Some(tags) = update.tags {
+            info.tags = tags;
+        }
+        info.last_modified = Utc::now();
+        info.etag = format!("\"{}\"", uuid::Uuid::new_v4());
+        let metadata = serde_json::to_vec_pretty(&info)
+            .map_err(|e| BackendError::Internal(format!("serialize file meta: {e}")))?;
+
+        let transaction_root = chain.files.open_or_create_private_dir(".transactions")?;
+        let transaction = transaction_root.open_or_create_private_dir(&stem)?;
+        let replaced = (|| {
+            transaction.create_private_file("new.meta.json", &metadata)?;
+            transaction.sync()?;
+            transaction.rename_to("new.meta.json", &chain.files, &active_meta)?;
+            chain.files.sync()
+        })();
+        Self::remove_transaction_dir(&transaction_root, &transaction, &stem)?;
+        replaced?;
+        Ok(info)
+    }
 }
 
 #[cfg(test)]
@@ -1656,6 +1722,50 @@ mod tests {
         assert_eq!(bytes, b"-----BEGIN CERTIFICATE-----\nMIIBxTCCA...");
     }
 
+    #[tokio::test]
+    async fn update_file_properties_rewrites_metadata_only() {
+        let (backend, tmp) = test_file_backend();
+        let request = FileUploadRequest {
+            name: "notes.txt".into(),
+            content: b"unchanged".to_vec(),
+            content_type: None,
+            groups: vec!["old".into()],
+            metadata: HashMap::new(),
+            tags: HashMap::from([("env".into(), "dev".into())]),
+        };
+        backend.upload_file("default", request, None).await.unwrap();
+        let ciphertext = fs::read(file_age_path(tmp.path(), "default", "notes.txt").unwrap());
+
+        let update = FilePropertiesUpdate {
+            groups: vec!["new".into()],
+            metadata: HashMap::from([("owner".into(), "ops".into())]),
+            tags: None,
+        };
+        let info = backend
+            .update_file_properties("default", "notes.txt", update)
+            .await
+            .unwrap();
+        assert_eq!(info.groups, vec!["new".to_string()]);
+        assert_eq!(info.metadata.get("owner").map(String::as_str), Some("ops"));
+        assert_eq!(info.tags.get("env").map(String::as_str), Some("dev"));
+
+        let stored = backend.get_file_info("default", "notes.txt").await.unwrap();
+        assert_eq!(stored.groups, info.groups);
+        assert_eq!(
+            fs::read(file_age_path(tmp.path(), "default", "notes.txt").unwrap()).unwrap(),
+            ciphertext.unwrap(),
+            "the content must not be rewritten"
+        );
+        assert!(
+            !files_dir(tmp.path(), "default")
+                .unwrap()
+                .join(".transactions")
+                .join(storage_stem("notes.txt").unwrap())
+                .exists(),
+            "the staging directory must be removed"
+        );
+    }
+
     #[tokio::test]
     async fn rejects_traversal_vault_name_for_file_writes() {
         let (backend, tmp) = test_file_backend();
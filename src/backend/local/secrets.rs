@@ -416,6 +416,7 @@ fn meta_to_properties(meta: &SecretMeta, value: Option<Zeroizing<String>>) -> Se
         not_before: meta.not_before,
         tags,
         content_type: meta.content_type.clone(),
+        id: None,
         recovery_level: None,
     }
 }
@@ -3101,6 +3102,8 @@ impl SecretBackend for LocalSecretBackend {
                                 // Local trash persists until an explicit
                                 // `xv purge` — no schedule to report.
                                 scheduled_purge_on: None,
+                                recovery_level: None,
+                                tags: meta_to_properties(&meta, None).tags,
                             });
                         }
                     }
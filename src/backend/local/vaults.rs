@@ -88,6 +88,8 @@ impl LocalVaultBackend {
             created_at: meta.created_at,
             tags: meta.tags.clone(),
             enable_rbac_authorization: Some(false),
+            public_network_access: None,
+            network_acls: None,
         })
     }
 }
@@ -166,8 +168,10 @@ impl VaultBackend for LocalVaultBackend {
                         name: meta.name.clone(),
                         location: "local".to_string(),
                         resource_group: String::new(),
+                        subscription: None,
                         status: "Active".to_string(),
                         created_at: meta.created_at.format("%Y-%m-%d %H:%M").to_string(),
+                        secret_count: None,
                     });
                 }
                 Err(_) => continue,
@@ -12,14 +12,15 @@ use super::{Backend, BackendKind};
 use crate::config::settings::Config;
 
 /// Blob transfer settings for AWS S3 file storage, read from the global
-/// `[blob]` config so `xv file` on AWS honors the same chunk-size and
-/// concurrency knobs as Azure.
+/// `[blob]` config so `xv file` on AWS honors the same chunk-size,
+/// concurrency and retry knobs as Azure.
 #[cfg(feature = "aws")]
 fn aws_transfer_config(config: &Config) -> super::aws::TransferConfig {
     let blob = config.get_blob_config();
     super::aws::TransferConfig {
         chunk_size_mb: blob.chunk_size_mb,
         max_concurrent_uploads: blob.max_concurrent_uploads,
+        retries: blob.transfer_retries,
     }
 }
 
@@ -32,6 +32,10 @@ pub struct SecretSnapshot {
 #[async_trait]
 pub trait SecretBackend: Send + Sync {
     /// Create or update a secret. Returns the new version's properties.
+    ///
+    /// Implementations build the result from the write response itself; a
+    /// follow-up read doubles latency for bulk writes and can race
+    /// replication, reporting the previous version.
     async fn set_secret(
         &self,
         vault: &str,
@@ -62,6 +66,12 @@ pub trait SecretBackend: Send + Sync {
         group_filter: Option<&str>,
     ) -> Result<Vec<SecretSummary>, BackendError>;
 
+    /// Count the secrets in a vault. Backends whose list call is cheaper than
+    /// a full [`list_secrets`](Self::list_secrets) should override this.
+    async fn count_secrets(&self, vault: &str) -> Result<usize, BackendError> {
+        Ok(self.list_secrets(vault, None).await?.len())
+    }
+
     /// Delete a secret (soft-delete if the backend supports it).
     async fn delete_secret(&self, vault: &str, name: &str) -> Result<(), BackendError>;
 
@@ -229,6 +239,20 @@ pub trait SecretBackend: Send + Sync {
         Err(BackendError::Unsupported("list deleted secrets".into()))
     }
 
+    /// Look up one deleted secret by name (default: scan
+    /// `list_deleted_secrets`). `None` when nothing by that name is deleted.
+    async fn get_deleted_secret(
+        &self,
+        vault: &str,
+        name: &str,
+    ) -> Result<Option<DeletedSecretSummary>, BackendError> {
+        Ok(self
+            .list_deleted_secrets(vault)
+            .await?
+            .into_iter()
+            .find(|s| s.name == name || s.original_name == name))
+    }
+
     /// Backup a secret to portable bytes.
     async fn backup_secret(&self, _vault: &str, _name: &str) -> Result<Vec<u8>, BackendError> {
         Err(BackendError::Unsupported("backup".into()))
@@ -377,6 +401,7 @@ mod tests {
             not_before: req.not_before,
             tags,
             content_type: req.content_type.clone().unwrap_or_default(),
+            id: None,
             recovery_level: None,
         }
     }
@@ -541,6 +566,7 @@ mod tests {
             not_before: None,
             tags,
             content_type: "text/plain".to_string(),
+            id: None,
             recovery_level: None,
         };
 
@@ -574,6 +600,7 @@ mod tests {
             not_before: None,
             tags: HashMap::new(),
             content_type: String::new(),
+            id: None,
             recovery_level: None,
         };
         let err = rename_request_from_properties("new", &props).unwrap_err();
@@ -8,7 +8,8 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 
 use crate::vault::models::{
-    AccessLevel, VaultCreateRequest, VaultProperties, VaultRole, VaultSummary, VaultUpdateRequest,
+    AccessLevel, DeletedVaultSummary, GuestInvitation, PolicyFinding, VaultCreateRequest,
+    VaultNameStatus, VaultPermission, VaultProperties, VaultRole, VaultSummary, VaultUpdateRequest,
 };
 
 use super::error::BackendError;
@@ -60,6 +61,14 @@ pub trait VaultBackend: Send + Sync {
     // Optional
     // -----------------------------------------------------------------------
 
+    /// List vaults in every subscription the credential can see, with
+    /// [`VaultSummary::subscription`] set. Only Azure has subscriptions.
+    async fn list_vaults_all_subscriptions(&self) -> Result<Vec<VaultSummary>, BackendError> {
+        Err(BackendError::Unsupported(
+            "listing vaults across subscriptions".into(),
+        ))
+    }
+
     /// Update vault properties. `resource_group` behaves as for
     /// [`get_vault`](Self::get_vault).
     async fn update_vault(
@@ -143,6 +152,75 @@ pub trait VaultBackend: Send + Sync {
         Err(BackendError::Unsupported("RBAC mode check".into()))
     }
 
+    /// Find a soft-deleted vault that still reserves `name`. Backends without
+    /// name-reserving soft delete return `Ok(None)`.
+    async fn find_deleted_vault(
+        &self,
+        _name: &str,
+    ) -> Result<Option<DeletedVaultSummary>, BackendError> {
+        Ok(None)
+    }
+
+    /// The vault's own tags. `resource_group` behaves as for
+    /// [`grant_access`](Self::grant_access).
+    async fn get_vault_tags(
+        &self,
+        name: &str,
+        resource_group: Option<&str>,
+    ) -> Result<HashMap<String, String>, BackendError> {
+        Ok(self.get_vault(name, resource_group).await?.tags)
+    }
+
+    /// The vault's provider-native resource definition (for Azure, the ARM
+    /// resource document), used for infrastructure-as-code export.
+    /// `resource_group` behaves as for [`grant_access`](Self::grant_access).
+    async fn get_vault_resource(
+        &self,
+        _name: &str,
+        _resource_group: Option<&str>,
+    ) -> Result<serde_json::Value, BackendError> {
+        Err(BackendError::Unsupported("vault template export".into()))
+    }
+
+    /// Whether `name` is free to create, taken, or reserved by a soft-deleted
+    /// vault.
+    async fn check_vault_name(&self, _name: &str) -> Result<VaultNameStatus, BackendError> {
+        Err(BackendError::Unsupported("vault name checks".into()))
+    }
+
+    /// Whether the vault ships diagnostic logs anywhere. `resource_group`
+    /// behaves as for [`grant_access`](Self::grant_access).
+    async fn diagnostics_enabled(
+        &self,
+        _vault: &str,
+        _resource_group: Option<&str>,
+    ) -> Result<bool, BackendError> {
+        Err(BackendError::Unsupported("diagnostic settings".into()))
+    }
+
+    /// Which of `needed` the current identity lacks on the vault, checked
+    /// with one cheap call before a bulk operation starts. `resource_group`
+    /// behaves as for [`grant_access`](Self::grant_access). Backends that
+    /// cannot tell (including Azure vaults in access-policy mode) return
+    /// [`BackendError::Unsupported`], and callers go ahead unchecked.
+    async fn missing_permissions(
+        &self,
+        _vault: &str,
+        _resource_group: Option<&str>,
+        _needed: &[VaultPermission],
+    ) -> Result<Vec<VaultPermission>, BackendError> {
+        Err(BackendError::Unsupported("permission preflight".into()))
+    }
+
+    /// Policy findings for the vault `request` would create, evaluated
+    /// before anything is created.
+    async fn check_create_policy(
+        &self,
+        _request: &VaultCreateRequest,
+    ) -> Result<Vec<PolicyFinding>, BackendError> {
+        Err(BackendError::Unsupported("policy pre-checks".into()))
+    }
+
     // -----------------------------------------------------------------------
     // Secret-scoped RBAC (optional — only if the backend supports assigning
     // access at the individual-secret granularity, e.g. Azure Key Vault RBAC
@@ -191,6 +269,35 @@ pub trait VaultBackend: Send + Sync {
         Err(BackendError::Unsupported("principal resolution".into()))
     }
 
+    /// Resolve a directory group (display name or object id) to its object
+    /// id, for granting access to everyone in it. Defaults to
+    /// [`BackendError::Unsupported`].
+    async fn resolve_group(&self, _group: &str) -> Result<String, BackendError> {
+        Err(BackendError::Unsupported("group principals".into()))
+    }
+
+    /// Invite `email` into the directory as a guest user and return the
+    /// invitation. Defaults to [`BackendError::Unsupported`].
+    async fn invite_guest(
+        &self,
+        _email: &str,
+        _redirect_url: &str,
+        _send_email: bool,
+    ) -> Result<GuestInvitation, BackendError> {
+        Err(BackendError::Unsupported("guest invitations".into()))
+    }
+
+    /// Whether the directory can see principal `object_id` yet.
+    async fn principal_exists(&self, _object_id: &str) -> Result<bool, BackendError> {
+        Err(BackendError::Unsupported("principal lookups".into()))
+    }
+
+    /// Member counts for group principals, keyed by object id. Groups that
+    /// can't be counted are absent; the default counts nothing.
+    async fn group_member_counts(&self, _group_ids: &[String]) -> HashMap<String, usize> {
+        HashMap::new()
+    }
+
     /// Resolve principal object ids to `(display_name, email)` pairs, for
     /// enriching access listings. Ids that can't be resolved are simply
     /// absent from the returned map; the default implementation resolves
@@ -0,0 +1,177 @@
+//! Archive bundles for `xv file archive`.
+//!
+//! Stored files are added one at a time to a zip or gzip-compressed tar
+//! written straight to disk, so the archive itself is never held in memory.
+
+use crate::error::{CrosstacheError, Result};
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use flate2::write::GzEncoder;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Archive container format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ArchiveFormat {
+    Zip,
+    #[value(name = "tar.gz", alias = "tgz")]
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// The format an output path's extension names (`.zip`, `.tar.gz`,
+    /// `.tgz`), if any.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+enum Inner {
+    Zip(zip::ZipWriter<File>),
+    TarGz(tar::Builder<GzEncoder<File>>),
+}
+
+/// Writes stored files into an archive file. Call [`finish`](Self::finish)
+/// to write the trailer; an unfinished archive is not readable.
+pub struct ArchiveWriter {
+    inner: Inner,
+}
+
+impl ArchiveWriter {
+    pub fn new(file: File, format: ArchiveFormat) -> Self {
+        let inner = match format {
+            ArchiveFormat::Zip => Inner::Zip(zip::ZipWriter::new(file)),
+            ArchiveFormat::TarGz => Inner::TarGz(tar::Builder::new(GzEncoder::new(
+                file,
+                flate2::Compression::default(),
+            ))),
+        };
+        Self { inner }
+    }
+
+    /// Add one file as `name`. Names come from the remote listing, so
+    /// absolute paths and `..` components are rejected before they reach an
+    /// archive someone else will extract.
+    pub fn append(&mut self, name: &str, content: &[u8], modified: DateTime<Utc>) -> Result<()> {
+        crate::utils::helpers::safe_join(Path::new(""), name)?;
+        match &mut self.inner {
+            Inner::Zip(zip) => {
+                let mut options = zip::write::SimpleFileOptions::default()
+                    .compression_method(zip::CompressionMethod::Deflated)
+                    .unix_permissions(0o644)
+                    .large_file(content.len() as u64 >= u32::MAX as u64);
+                // Zip timestamps start in 1980; older ones keep the default.
+                if let Ok(time) = zip::DateTime::from_date_and_time(
+                    u16::try_from(modified.year()).unwrap_or(0),
+                    modified.month() as u8,
+                    modified.day() as u8,
+                    modified.hour() as u8,
+                    modified.minute() as u8,
+                    modified.second() as u8,
+                ) {
+                    options = options.last_modified_time(time);
+                }
+                zip.start_file(name, options).map_err(archive_error)?;
+                zip.write_all(content)?;
+            }
+            Inner::TarGz(tar) => {
+                let mut header = tar::Header::new_gnu();
+                header.set_size(content.len() as u64);
+                header.set_mode(0o644);
+                header.set_mtime(modified.timestamp().max(0) as u64);
+                header.set_entry_type(tar::EntryType::Regular);
+                tar.append_data(&mut header, name, content)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the archive trailer and flush it to disk.
+    pub fn finish(self) -> Result<()> {
+        let file = match self.inner {
+            Inner::Zip(zip) => zip.finish().map_err(archive_error)?,
+            Inner::TarGz(tar) => tar.into_inner()?.finish()?,
+        };
+        file.sync_all()?;
+        Ok(())
+    }
+}
+
+fn archive_error(e: zip::result::ZipError) -> CrosstacheError {
+    CrosstacheError::unknown(format!("Failed to write archive: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn modified() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn format_follows_the_output_extension() {
+        let format = |p: &str| ArchiveFormat::from_path(Path::new(p));
+        assert_eq!(format("out/reports.ZIP"), Some(ArchiveFormat::Zip));
+        assert_eq!(format("reports.tar.gz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(format("reports.tgz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(format("reports.tar"), None);
+    }
+
+    #[test]
+    fn zip_round_trips_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.zip");
+        let mut writer = ArchiveWriter::new(File::create(&path).unwrap(), ArchiveFormat::Zip);
+        writer.append("reports/q1.csv", b"a,b", modified()).unwrap();
+        writer.append("reports/q2.csv", b"c,d", modified()).unwrap();
+        writer.finish().unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut content = String::new();
+        archive
+            .by_name("reports/q2.csv")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "c,d");
+    }
+
+    #[test]
+    fn tar_gz_round_trips_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.tar.gz");
+        let mut writer = ArchiveWriter::new(File::create(&path).unwrap(), ArchiveFormat::TarGz);
+        writer.append("reports/q1.csv", b"a,b", modified()).unwrap();
+        writer.finish().unwrap();
+
+        let mut archive =
+            tar::Archive::new(flate2::read::GzDecoder::new(File::open(&path).unwrap()));
+        let mut entries = archive.entries().unwrap();
+        let mut entry = entries.next().unwrap().unwrap();
+        assert_eq!(entry.path().unwrap().to_str(), Some("reports/q1.csv"));
+        assert_eq!(entry.header().mtime().unwrap(), 1_700_000_000);
+        let mut content = String::new();
+        entry.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "a,b");
+        assert!(entries.next().is_none());
+    }
+
+    #[test]
+    fn unsafe_entry_names_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = File::create(dir.path().join("a.zip")).unwrap();
+        let mut writer = ArchiveWriter::new(file, ArchiveFormat::Zip);
+        assert!(writer.append("../escape", b"x", modified()).is_err());
+        assert!(writer.append("/etc/passwd", b"x", modified()).is_err());
+    }
+}
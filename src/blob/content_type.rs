@@ -0,0 +1,62 @@
+//! Content-type detection for uploads without `--content-type`.
+//!
+//! The file name's extension wins when it is known; otherwise the leading
+//! bytes are sniffed for a magic number, so an extensionless PDF or PNG is
+//! still served with a type browsers can open from a shared URL.
+
+/// Fallback when neither the name nor the content identifies the type.
+pub const OCTET_STREAM: &str = "application/octet-stream";
+
+/// How many leading bytes [`detect`] looks at. Every signature `infer`
+/// knows fits well inside this.
+pub const SNIFF_LEN: usize = 8192;
+
+/// Guess the content type of `name` from its extension, then from `head`
+/// (the first bytes of the content; may be empty or shorter than
+/// [`SNIFF_LEN`]).
+pub fn detect(name: &str, head: &[u8]) -> String {
+    if let Some(mime) = mime_guess::from_path(name).first() {
+        return mime.to_string();
+    }
+    let head = &head[..head.len().min(SNIFF_LEN)];
+    infer::get(head)
+        .map(|kind| kind.mime_type().to_string())
+        .unwrap_or_else(|| OCTET_STREAM.to_string())
+}
+
+/// `explicit` when given, otherwise [`detect`].
+pub fn resolve(explicit: Option<&str>, name: &str, head: &[u8]) -> String {
+    explicit.map_or_else(|| detect(name, head), str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PDF: &[u8] = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n";
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    #[test]
+    fn extension_decides_when_known() {
+        assert_eq!(detect("report.html", PDF), "text/html");
+        assert_eq!(detect("data.json", b""), "application/json");
+    }
+
+    #[test]
+    fn magic_bytes_cover_missing_or_unknown_extensions() {
+        assert_eq!(detect("report", PDF), "application/pdf");
+        assert_eq!(detect("logo.blob-v2", PNG), "image/png");
+    }
+
+    #[test]
+    fn unrecognised_content_is_octet_stream() {
+        assert_eq!(detect("notes", b"just some text"), OCTET_STREAM);
+        assert_eq!(detect("empty", b""), OCTET_STREAM);
+    }
+
+    #[test]
+    fn explicit_type_overrides_detection() {
+        assert_eq!(resolve(Some("text/csv"), "report", PDF), "text/csv");
+        assert_eq!(resolve(None, "report", PDF), "application/pdf");
+    }
+}
@@ -0,0 +1,94 @@
+//! Storage failure diagnosis
+//!
+//! Azure Storage answers a missing data-plane role, a firewall block and a
+//! missing container with bare 403/404 statuses; only the `x-ms-error-code`
+//! header tells them apart. [`StorageDiagnosis`] maps that code to the cause
+//! and the command that fixes it.
+
+/// Known causes of a refused blob request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageDiagnosis {
+    /// Authenticated, but without a data-plane role such as Storage Blob Data
+    /// Contributor. Management roles (Owner, Contributor) do not grant blob
+    /// access.
+    MissingDataRole,
+    /// The account's network rules do not admit this client.
+    NetworkRulesBlocked,
+    /// The configured container does not exist.
+    ContainerMissing,
+}
+
+impl StorageDiagnosis {
+    /// Diagnose a storage response from its status and `x-ms-error-code`.
+    pub fn from_response(status: u16, error_code: Option<&str>) -> Option<Self> {
+        match (status, error_code?) {
+            (403, "AuthorizationPermissionMismatch") => Some(Self::MissingDataRole),
+            (403, "AuthorizationFailure" | "AuthorizationSourceIPMismatch") => {
+                Some(Self::NetworkRulesBlocked)
+            }
+            (404, "ContainerNotFound") => Some(Self::ContainerMissing),
+            _ => None,
+        }
+    }
+
+    /// User-facing explanation with the command to run.
+    pub fn explain(&self, account: &str, container: &str) -> String {
+        match self {
+            Self::MissingDataRole => format!(
+                "your identity has no data access to storage account '{account}'. Files need the \
+                 Storage Blob Data Contributor role (Owner and Contributor do not include it): \
+                 `az role assignment create --role \"Storage Blob Data Contributor\" \
+                 --assignee $(az ad signed-in-user show --query id -o tsv) \
+                 --scope $(az storage account show --name {account} --query id -o tsv)`. \
+                 New role assignments can take a few minutes to apply."
+            ),
+            Self::NetworkRulesBlocked => format!(
+                "storage account '{account}' firewall does not allow this network. Connect over \
+                 VPN, or ask an account admin to allow your address: \
+                 `az storage account network-rule add --account-name {account} --ip-address <your-ip>`"
+            ),
+            Self::ContainerMissing => format!(
+                "container '{container}' does not exist in storage account '{account}'. Create it \
+                 with `az storage container create --account-name {account} --name {container} \
+                 --auth-mode login`, or point xv at an existing one with \
+                 `xv config set storage_container <name>`."
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_map_to_causes() {
+        assert_eq!(
+            StorageDiagnosis::from_response(403, Some("AuthorizationPermissionMismatch")),
+            Some(StorageDiagnosis::MissingDataRole)
+        );
+        assert_eq!(
+            StorageDiagnosis::from_response(403, Some("AuthorizationFailure")),
+            Some(StorageDiagnosis::NetworkRulesBlocked)
+        );
+        assert_eq!(
+            StorageDiagnosis::from_response(404, Some("ContainerNotFound")),
+            Some(StorageDiagnosis::ContainerMissing)
+        );
+        assert_eq!(
+            StorageDiagnosis::from_response(404, Some("BlobNotFound")),
+            None
+        );
+        assert_eq!(StorageDiagnosis::from_response(403, None), None);
+    }
+
+    #[test]
+    fn explanations_name_the_fix() {
+        let role = StorageDiagnosis::MissingDataRole.explain("acct", "files");
+        assert!(role.contains("Storage Blob Data Contributor") && role.contains("--name acct"));
+        let missing = StorageDiagnosis::ContainerMissing.explain("acct", "files");
+        assert!(
+            missing.contains("--name files") && missing.contains("xv config set storage_container")
+        );
+    }
+}
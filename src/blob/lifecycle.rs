@@ -0,0 +1,237 @@
+//! Storage account lifecycle management policy
+//!
+//! `xv file lifecycle` keeps per-prefix retention rules in the storage
+//! account's management policy, an ARM resource rather than a blob-service
+//! call. Rules created here are named after the container and prefix, so
+//! running `set` again for the same prefix replaces that rule; rules added
+//! any other way (portal, Bicep) are preserved.
+
+use crate::auth::provider::AzureAuthProvider;
+use crate::backend::azure::arm::ArmClient;
+use crate::error::Result;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// A lifecycle rule for one blob prefix. Ages count days since the blob was
+/// last modified.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, tabled::Tabled)]
+pub struct LifecycleRule {
+    #[tabled(rename = "Rule")]
+    pub name: String,
+    /// `container/prefix`, as the policy's `prefixMatch` filter wants it.
+    #[tabled(rename = "Prefix")]
+    pub prefix: String,
+    #[tabled(rename = "Cool After (days)", display_with = "display_days")]
+    pub tier_cool_after_days: Option<u32>,
+    #[tabled(rename = "Delete After (days)", display_with = "display_days")]
+    pub delete_after_days: Option<u32>,
+    #[tabled(rename = "Enabled")]
+    pub enabled: bool,
+}
+
+fn display_days(days: &Option<u32>) -> String {
+    days.map_or_else(|| "-".to_string(), |d| d.to_string())
+}
+
+impl LifecycleRule {
+    /// The rule in management-policy JSON form.
+    pub fn to_json(&self) -> Value {
+        let mut base_blob = serde_json::Map::new();
+        if let Some(days) = self.tier_cool_after_days {
+            base_blob.insert(
+                "tierToCool".into(),
+                json!({ "daysAfterModificationGreaterThan": days }),
+            );
+        }
+        if let Some(days) = self.delete_after_days {
+            base_blob.insert(
+                "delete".into(),
+                json!({ "daysAfterModificationGreaterThan": days }),
+            );
+        }
+        json!({
+            "enabled": self.enabled,
+            "name": self.name,
+            "type": "Lifecycle",
+            "definition": {
+                "filters": {
+                    "blobTypes": ["blockBlob"],
+                    "prefixMatch": [self.prefix]
+                },
+                "actions": { "baseBlob": base_blob }
+            }
+        })
+    }
+
+    /// Read a rule back from management-policy JSON. Rules filtering on
+    /// several prefixes report the first.
+    pub fn from_json(rule: &Value) -> Option<Self> {
+        let days = |action: &str| {
+            rule.pointer(&format!(
+                "/definition/actions/baseBlob/{action}/daysAfterModificationGreaterThan"
+            ))
+            .and_then(Value::as_u64)
+            .and_then(|d| u32::try_from(d).ok())
+        };
+        Some(Self {
+            name: rule.get("name")?.as_str()?.to_string(),
+            prefix: rule
+                .pointer("/definition/filters/prefixMatch/0")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            tier_cool_after_days: days("tierToCool"),
+            delete_after_days: days("delete"),
+            enabled: rule.get("enabled").and_then(Value::as_bool).unwrap_or(true),
+        })
+    }
+}
+
+/// The `prefixMatch` value for `prefix` inside `container`. No prefix covers
+/// the whole container.
+pub fn blob_prefix(container: &str, prefix: Option<&str>) -> String {
+    let prefix = prefix.unwrap_or_default().trim_start_matches('/');
+    format!("{container}/{prefix}")
+}
+
+/// Stable rule name for a `container/prefix` filter. Policy rule names are
+/// alphanumeric only, so everything else is dropped.
+pub fn rule_name(blob_prefix: &str) -> String {
+    let stem: String = blob_prefix
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect();
+    format!("xv{stem}")
+}
+
+/// The rules of a management policy (`None` when the account has none yet).
+pub fn rules_in(policy: Option<&Value>) -> Vec<LifecycleRule> {
+    policy
+        .and_then(|p| p.pointer("/properties/policy/rules"))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(LifecycleRule::from_json)
+        .collect()
+}
+
+/// PUT body for `policy` with `rule` added, or replacing the rule of the same
+/// name. Every other rule is kept as-is.
+pub fn upsert_rule(policy: Option<&Value>, rule: &LifecycleRule) -> Value {
+    let mut rules: Vec<Value> = policy
+        .and_then(|p| p.pointer("/properties/policy/rules"))
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let replacement = rule.to_json();
+    match rules
+        .iter_mut()
+        .find(|existing| existing.get("name").and_then(Value::as_str) == Some(rule.name.as_str()))
+    {
+        Some(existing) => *existing = replacement,
+        None => rules.push(replacement),
+    }
+    json!({ "properties": { "policy": { "rules": rules } } })
+}
+
+/// ARM client for one storage account's `managementPolicies/default`.
+pub struct ManagementPolicyClient {
+    arm: ArmClient,
+    url: String,
+}
+
+impl ManagementPolicyClient {
+    pub fn new(
+        auth_provider: Arc<dyn AzureAuthProvider>,
+        subscription_id: &str,
+        resource_group: &str,
+        storage_account: &str,
+    ) -> Result<Self> {
+        let arm = ArmClient::new(auth_provider)?;
+        let url = arm.url(&format!(
+            "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Storage/storageAccounts/{}/managementPolicies/default?api-version=2023-01-01",
+            crate::vault::operations::enc(subscription_id),
+            crate::vault::operations::enc(resource_group),
+            crate::vault::operations::enc(storage_account)
+        ));
+        Ok(Self { arm, url })
+    }
+
+    /// The account's current policy, or `None` when it has never had one.
+    pub async fn get(&self) -> Result<Option<Value>> {
+        self.arm
+            .get(&self.url, "Failed to read lifecycle policy")
+            .await
+    }
+
+    /// Replace the account's policy with `body`.
+    pub async fn put(&self, body: &Value) -> Result<Value> {
+        self.arm
+            .put(&self.url, body, "Failed to update lifecycle policy")
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(name: &str, prefix: &str, cool: Option<u32>, delete: Option<u32>) -> LifecycleRule {
+        LifecycleRule {
+            name: name.to_string(),
+            prefix: prefix.to_string(),
+            tier_cool_after_days: cool,
+            delete_after_days: delete,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn prefix_and_rule_name_follow_the_container() {
+        let prefix = blob_prefix("crosstache-files", Some("/logs/"));
+        assert_eq!(prefix, "crosstache-files/logs/");
+        assert_eq!(rule_name(&prefix), "xvcrosstachefileslogs");
+        assert_eq!(blob_prefix("files", None), "files/");
+    }
+
+    #[test]
+    fn rule_round_trips_through_policy_json() {
+        let original = rule("xvfileslogs", "files/logs/", Some(30), Some(90));
+        let json = original.to_json();
+        assert_eq!(
+            json.pointer("/definition/actions/baseBlob/delete/daysAfterModificationGreaterThan"),
+            Some(&json!(90))
+        );
+        assert_eq!(LifecycleRule::from_json(&json), Some(original));
+
+        let delete_only = rule("xvfiles", "files/", None, Some(7)).to_json();
+        assert!(delete_only
+            .pointer("/definition/actions/baseBlob/tierToCool")
+            .is_none());
+    }
+
+    #[test]
+    fn upsert_replaces_same_name_and_keeps_other_rules() {
+        let existing = json!({
+            "properties": { "policy": { "rules": [
+                { "name": "portalRule", "enabled": true, "type": "Lifecycle",
+                  "definition": { "filters": { "blobTypes": ["blockBlob"] },
+                                  "actions": { "baseBlob": { "delete": { "daysAfterModificationGreaterThan": 365 } } } } },
+                rule("xvfileslogs", "files/logs/", None, Some(90)).to_json()
+            ] } }
+        });
+
+        let updated = upsert_rule(
+            Some(&existing),
+            &rule("xvfileslogs", "files/logs/", Some(30), Some(60)),
+        );
+        let rules = rules_in(Some(&updated));
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].name, "portalRule");
+        assert_eq!(rules[1].delete_after_days, Some(60));
+        assert_eq!(rules[1].tier_cool_after_days, Some(30));
+
+        let fresh = upsert_rule(None, &rule("xvfiles", "files/", None, Some(7)));
+        assert_eq!(rules_in(Some(&fresh)).len(), 1);
+    }
+}
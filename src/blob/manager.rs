@@ -7,9 +7,15 @@
 //! `azure_storage_blobs` crate, including block uploads for large files.
 
 use crate::auth::provider::AzureAuthProvider;
+use crate::blob::diagnostics::StorageDiagnosis;
 use crate::blob::models::*;
 use crate::error::{CrosstacheError, Result};
+use crate::utils::retry::{
+    describe_failed_ranges, is_retryable_transfer_error, retry_with_backoff_if, FailedRange,
+    RetryOptions,
+};
 use azure_core::request_options::Metadata;
+use azure_storage::{CloudLocation, ConnectionString, StorageCredentials};
 use azure_storage_blobs::prelude::*;
 // use azure_core::auth::TokenCredential; // Not needed for current implementation
 use chrono::Utc;
@@ -17,6 +23,7 @@ use futures::TryStreamExt;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::io::AsyncWrite;
+use tokio::sync::OnceCell;
 
 use crate::utils::progress::ProgressReporter;
 
@@ -26,11 +33,30 @@ use crate::utils::progress::ProgressReporter;
 pub(crate) struct BlobManager {
     storage_account: String,
     container_name: String,
+    /// Blob service URL overriding `https://<account>.blob.core.windows.net`,
+    /// e.g. `http://127.0.0.1:10000/devstoreaccount1` for Azurite.
+    endpoint: Option<String>,
     auth_provider: Arc<dyn AzureAuthProvider>,
     /// Chunk size for block-based large file uploads (megabytes).
     chunk_size_mb: usize,
     /// Maximum number of concurrent block uploads.
     max_concurrent_uploads: usize,
+    /// Further attempts for each failed block upload or download range.
+    transfer_retries: usize,
+    /// Vault secret holding an account key or connection string; `None`
+    /// signs requests with the caller's Entra ID token.
+    key_secret: Option<Arc<StorageKeySecret>>,
+    /// Credentials resolved on first use and shared with `in_container`
+    /// copies, so the key secret is read at most once per process.
+    credentials: Arc<OnceCell<StorageCredentials>>,
+}
+
+/// A vault secret whose value is a storage account key or a connection
+/// string, for accounts where data-plane RBAC can't be granted.
+pub(crate) struct StorageKeySecret {
+    pub secrets: Arc<dyn crate::secret::manager::SecretOperations>,
+    pub vault: String,
+    pub name: String,
 }
 
 impl BlobManager {
@@ -43,9 +69,13 @@ impl BlobManager {
         Ok(Self {
             storage_account,
             container_name,
+            endpoint: None,
             auth_provider,
             chunk_size_mb: 4,
             max_concurrent_uploads: 3,
+            transfer_retries: 3,
+            key_secret: None,
+            credentials: Arc::new(OnceCell::new()),
         })
     }
 
@@ -58,6 +88,94 @@ impl BlobManager {
         self
     }
 
+    /// Override how many times a failed block or range is retried.
+    pub fn with_transfer_retries(mut self, retries: usize) -> Self {
+        self.transfer_retries = retries;
+        self
+    }
+
+    /// Send requests to `endpoint` instead of the public Azure blob host.
+    /// A plain `http://` endpoint is taken to be the Azurite emulator.
+    pub fn with_endpoint(mut self, endpoint: Option<String>) -> Self {
+        self.endpoint = endpoint.filter(|e| !e.trim().is_empty());
+        self
+    }
+
+    /// Whether requests go to the storage emulator over plain HTTP.
+    fn is_emulator(&self) -> bool {
+        self.endpoint.as_deref().is_some_and(is_emulator_endpoint)
+    }
+
+    /// Whether requests are signed with an account key (a key secret or
+    /// the emulator's well-known key) rather than an Entra ID token.
+    fn uses_account_key(&self) -> bool {
+        self.key_secret.is_some() || self.is_emulator()
+    }
+
+    /// Authenticate with the account key or connection string stored in
+    /// `secret` instead of the caller's token. The secret is read when the
+    /// first storage request is made.
+    pub fn with_key_secret(mut self, secret: StorageKeySecret) -> Self {
+        self.key_secret = Some(Arc::new(secret));
+        self
+    }
+
+    /// A service client for the account, signed with the configured
+    /// credentials.
+    async fn blob_service(&self) -> Result<BlobServiceClient> {
+        let credentials = self
+            .credentials
+            .get_or_try_init(|| self.resolve_credentials())
+            .await?
+            .clone();
+        let builder = match &self.endpoint {
+            Some(uri) => ClientBuilder::with_location(
+                CloudLocation::Custom {
+                    account: self.storage_account.clone(),
+                    uri: uri.trim_end_matches('/').to_string(),
+                },
+                credentials,
+            ),
+            None => BlobServiceClient::builder(&self.storage_account, credentials),
+        };
+        Ok(builder
+            .client_options(crate::utils::correlation::sdk_client_options())
+            .blob_service_client())
+    }
+
+    async fn resolve_credentials(&self) -> Result<StorageCredentials> {
+        let Some(source) = &self.key_secret else {
+            // Bearer tokens are only accepted over HTTPS, so Azurite is
+            // always addressed with its well-known development key.
+            if self.is_emulator() {
+                return Ok(StorageCredentials::access_key(
+                    self.storage_account.clone(),
+                    AZURITE_ACCOUNT_KEY.to_string(),
+                ));
+            }
+            return Ok(StorageCredentials::token_credential(
+                self.auth_provider.get_token_credential(),
+            ));
+        };
+        let secret = source
+            .secrets
+            .get_secret(&source.vault, &source.name, true)
+            .await
+            .map_err(|e| {
+                CrosstacheError::config(format!(
+                    "Failed to read storage key secret '{}' from vault '{}': {e}",
+                    source.name, source.vault
+                ))
+            })?;
+        let value = secret.value.ok_or_else(|| {
+            CrosstacheError::config(format!(
+                "Storage key secret '{}' in vault '{}' has no value",
+                source.name, source.vault
+            ))
+        })?;
+        storage_credentials_from_secret(&self.storage_account, value.trim())
+    }
+
     /// Upload a file to blob storage
     pub async fn upload_file(
         &self,
@@ -65,11 +183,11 @@ impl BlobManager {
         reporter: &dyn ProgressReporter,
     ) -> Result<FileInfo> {
         // Determine content type
-        let content_type = request.content_type.unwrap_or_else(|| {
-            mime_guess::from_path(&request.name)
-                .first_or_octet_stream()
-                .to_string()
-        });
+        let content_type = crate::blob::content_type::resolve(
+            request.content_type.as_deref(),
+            &request.name,
+            &request.content,
+        );
 
         // Build metadata with groups
         let mut metadata = request.metadata.clone();
@@ -79,10 +197,7 @@ impl BlobManager {
         metadata.insert("uploaded_by".to_string(), "crosstache".to_string());
         metadata.insert("uploaded_at".to_string(), Utc::now().to_rfc3339());
 
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container client
         let container_client = blob_service.container_client(&self.container_name);
@@ -109,7 +224,7 @@ impl BlobManager {
             .content_type(&content_type)
             .metadata(sdk_metadata)
             .await
-            .map_err(|e| CrosstacheError::azure_api(format!("Failed to upload blob: {e}")))?;
+            .map_err(|e| self.storage_error(&e, "Failed to upload blob"))?;
         reporter.advance(content_length);
         reporter.finish_clear();
 
@@ -136,9 +251,7 @@ impl BlobManager {
 
     /// List files in the container
     pub async fn list_files(&self, request: FileListRequest) -> Result<Vec<FileInfo>> {
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container client
         let container_client = blob_service.container_client(&self.container_name);
@@ -163,64 +276,67 @@ impl BlobManager {
         while let Some(page) = stream
             .try_next()
             .await
-            .map_err(|e| CrosstacheError::azure_api(format!("Failed to list blobs: {e}")))?
+            .map_err(|e| self.storage_error(&e, "Failed to list blobs"))?
         {
-            // Process each blob in this page
-            for blob_item in page.blobs.blobs() {
-                // Extract blob information
-                let name = blob_item.name.clone();
-                let size = blob_item.properties.content_length;
-                let content_type = blob_item.properties.content_type.clone();
-
-                // Convert time::OffsetDateTime to chrono::DateTime<Utc>
-                let last_modified = {
-                    let timestamp = blob_item.properties.last_modified.unix_timestamp();
-                    chrono::DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now)
-                };
-
-                let etag = blob_item.properties.etag.to_string();
-
-                // Process metadata - handle Option<HashMap<String, String>>
-                let metadata = blob_item.metadata.clone().unwrap_or_default();
-
-                // Extract groups from metadata
-                let groups: Vec<String> = metadata
-                    .get("groups")
-                    .map(|g| g.split(',').map(|s| s.trim().to_string()).collect())
-                    .unwrap_or_default();
+            file_infos.extend(
+                page.blobs
+                    .blobs()
+                    .filter_map(|blob| blob_file_info(blob, request.groups.as_deref())),
+            );
+        }
 
-                // Apply group-based filtering if requested
-                if let Some(filter_groups) = &request.groups {
-                    let matches_group = filter_groups.iter().any(|fg| groups.contains(fg));
-                    if !matches_group {
-                        continue; // Skip this blob
-                    }
-                }
+        Ok(file_infos)
+    }
 
-                // Extract tags if they were requested and returned inline
-                let tags: HashMap<String, String> = blob_item
-                    .tags
-                    .clone()
-                    .map(HashMap::from)
-                    .unwrap_or_default();
-
-                // Build FileInfo struct
-                let file_info = FileInfo {
-                    name,
-                    size,
-                    content_type,
-                    last_modified,
-                    etag,
-                    groups,
-                    metadata,
-                    tags,
-                };
+    /// One page of the container listing, resuming after `marker` (the
+    /// service's `NextMarker`). `request.limit` caps the page at the service's
+    /// `maxresults`; group filtering applies within the page, so a filtered
+    /// page can be shorter than the limit while more remain.
+    pub async fn list_files_page(
+        &self,
+        request: FileListRequest,
+        marker: Option<&str>,
+    ) -> Result<FileListPage> {
+        let blob_service = self.blob_service().await?;
+        let container_client = blob_service.container_client(&self.container_name);
 
-                file_infos.push(file_info);
+        let mut list_builder = container_client.list_blobs().include_metadata(true);
+        if let Some(prefix) = request.prefix.clone() {
+            list_builder = list_builder.prefix(prefix);
+        }
+        if let Some(limit) = request.limit {
+            let limit = u32::try_from(limit).unwrap_or(u32::MAX);
+            if let Some(limit) = std::num::NonZeroU32::new(limit) {
+                list_builder = list_builder.max_results(limit);
             }
         }
+        if let Some(marker) = marker.filter(|m| !m.is_empty()) {
+            list_builder = list_builder.marker(marker.to_string());
+        }
 
-        Ok(file_infos)
+        let Some(page) = list_builder
+            .into_stream()
+            .try_next()
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to list blobs"))?
+        else {
+            return Ok(FileListPage {
+                files: Vec::new(),
+                next_marker: None,
+            });
+        };
+
+        Ok(FileListPage {
+            files: page
+                .blobs
+                .blobs()
+                .filter_map(|blob| blob_file_info(blob, request.groups.as_deref()))
+                .collect(),
+            next_marker: page
+                .next_marker
+                .map(|m| m.as_str().to_string())
+                .filter(|m| !m.is_empty()),
+        })
     }
 
     /// List files and directories hierarchically at a specific prefix level
@@ -230,9 +346,7 @@ impl BlobManager {
     ) -> Result<Vec<BlobListItem>> {
         use crate::blob::models::BlobListItem;
 
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container client
         let container_client = blob_service.container_client(&self.container_name);
@@ -262,7 +376,7 @@ impl BlobManager {
         while let Some(page) = stream
             .try_next()
             .await
-            .map_err(|e| CrosstacheError::azure_api(format!("Failed to list blobs: {e}")))?
+            .map_err(|e| self.storage_error(&e, "Failed to list blobs"))?
         {
             // Process blob prefixes (directories) first
             for prefix_item in page.blobs.prefixes() {
@@ -285,58 +399,12 @@ impl BlobManager {
             }
 
             // Process blobs (files)
-            for blob_item in page.blobs.blobs() {
-                // Extract blob information
-                let name = blob_item.name.clone();
-                let size = blob_item.properties.content_length;
-                let content_type = blob_item.properties.content_type.clone();
-
-                // Convert time::OffsetDateTime to chrono::DateTime<Utc>
-                let last_modified = {
-                    let timestamp = blob_item.properties.last_modified.unix_timestamp();
-                    chrono::DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now)
-                };
-
-                let etag = blob_item.properties.etag.to_string();
-
-                // Process metadata - handle Option<HashMap<String, String>>
-                let metadata = blob_item.metadata.clone().unwrap_or_default();
-
-                // Extract groups from metadata
-                let groups: Vec<String> = metadata
-                    .get("groups")
-                    .map(|g| g.split(',').map(|s| s.trim().to_string()).collect())
-                    .unwrap_or_default();
-
-                // Apply group-based filtering if requested
-                if let Some(filter_groups) = &request.groups {
-                    let matches_group = filter_groups.iter().any(|fg| groups.contains(fg));
-                    if !matches_group {
-                        continue; // Skip this blob
-                    }
-                }
-
-                // Extract tags if they were requested and returned inline
-                let tags: HashMap<String, String> = blob_item
-                    .tags
-                    .clone()
-                    .map(HashMap::from)
-                    .unwrap_or_default();
-
-                // Build FileInfo struct
-                let file_info = FileInfo {
-                    name,
-                    size,
-                    content_type,
-                    last_modified,
-                    etag,
-                    groups,
-                    metadata,
-                    tags,
-                };
-
-                items.push(BlobListItem::File(file_info));
-            }
+            items.extend(
+                page.blobs
+                    .blobs()
+                    .filter_map(|blob| blob_file_info(blob, request.groups.as_deref()))
+                    .map(BlobListItem::File),
+            );
         }
 
         // Sort items: directories first, then files (both alphabetically)
@@ -363,9 +431,7 @@ impl BlobManager {
             ));
         }
 
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container and blob clients
         let container_client = blob_service.container_client(&self.container_name);
@@ -373,11 +439,10 @@ impl BlobManager {
 
         // Check if blob exists and get its size before attempting download
         let properties = blob_client.get_properties().await.map_err(|e| {
-            let error_msg = e.to_string().to_lowercase();
-            if error_msg.contains("404") || error_msg.contains("not found") {
+            if is_missing_blob(&e) {
                 CrosstacheError::vault_not_found(format!("File '{}' not found", request.name))
             } else {
-                CrosstacheError::azure_api(format!("Failed to check if blob exists: {e}"))
+                self.storage_error(&e, "Failed to check if blob exists")
             }
         })?;
 
@@ -395,13 +460,35 @@ impl BlobManager {
         let blob_content = blob_client
             .get_content()
             .await
-            .map_err(|e| CrosstacheError::azure_api(format!("Failed to download blob: {e}")))?;
+            .map_err(|e| self.storage_error(&e, "Failed to download blob"))?;
         reporter.advance(content_length);
         reporter.finish_clear();
 
         Ok(blob_content)
     }
 
+    /// Create the manager's container.
+    pub async fn create_container(&self) -> Result<()> {
+        let blob_service = self.blob_service().await?;
+        blob_service
+            .container_client(&self.container_name)
+            .create()
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to create container"))?;
+        Ok(())
+    }
+
+    /// Delete the manager's container and every blob in it.
+    pub async fn delete_container(&self) -> Result<()> {
+        let blob_service = self.blob_service().await?;
+        blob_service
+            .container_client(&self.container_name)
+            .delete()
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to delete container"))?;
+        Ok(())
+    }
+
     /// Delete a file from blob storage
     pub async fn delete_file(&self, name: &str) -> Result<()> {
         // Validate file name parameter
@@ -411,9 +498,7 @@ impl BlobManager {
             ));
         }
 
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container and blob clients
         let container_client = blob_service.container_client(&self.container_name);
@@ -429,11 +514,10 @@ impl BlobManager {
 
         // Implement blob deletion
         blob_client.delete().await.map_err(|e| {
-            let error_msg = e.to_string().to_lowercase();
-            if error_msg.contains("404") || error_msg.contains("not found") {
+            if is_missing_blob(&e) {
                 CrosstacheError::vault_not_found(format!("File '{name}' not found"))
             } else {
-                CrosstacheError::azure_api(format!("Failed to delete blob: {e}"))
+                self.storage_error(&e, "Failed to delete blob")
             }
         })?;
 
@@ -443,6 +527,247 @@ impl BlobManager {
 
     /// Get file metadata without downloading content
     pub async fn get_file_info(&self, name: &str) -> Result<FileInfo> {
+        self.blob_properties(name).await.map(|(info, _)| info)
+    }
+
+    /// File metadata plus tier, lease state, encryption, MD5 and version id.
+    pub async fn get_file_details(&self, name: &str) -> Result<FileDetails> {
+        let (info, properties) = self.blob_properties(name).await?;
+        let version_id = self.current_version_id(name).await;
+        Ok(FileDetails {
+            info,
+            tier: properties.access_tier.map(|t| <&str>::from(t).to_string()),
+            lease_state: properties.lease_state.map(|l| <&str>::from(l).to_string()),
+            version_id,
+            server_encrypted: Some(properties.server_encrypted),
+            content_md5: properties
+                .content_md5
+                .map(|md5| hex::encode(md5.as_slice())),
+        })
+    }
+
+    /// The current version id of `name`, or `None` when versioning is off.
+    /// Get Blob Properties does not surface `x-ms-version-id` through the SDK,
+    /// so this reads it from a one-entry listing. Best-effort: a failure only
+    /// drops the field from `xv file info`.
+    async fn current_version_id(&self, name: &str) -> Option<String> {
+        let blob_service = self.blob_service().await.ok()?;
+        let container_client = blob_service.container_client(&self.container_name);
+
+        // The exact name sorts first among blobs sharing it as a prefix.
+        let listing = container_client
+            .list_blobs()
+            .prefix(name.to_string())
+            .max_results(std::num::NonZeroU32::MIN)
+            .into_stream()
+            .try_next()
+            .await;
+        match listing {
+            Ok(page) => page?
+                .blobs
+                .blobs()
+                .find(|blob| blob.name == name)
+                .and_then(|blob| blob.version_id.clone()),
+            Err(e) => {
+                tracing::debug!("Version id lookup for '{}' failed: {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// A read-only SAS URL for `name`, valid for `ttl`. With token auth it
+    /// is a user delegation SAS, signed with a delegation key from the
+    /// caller's Entra ID identity: no account key, but it needs
+    /// `generateUserDelegationKey` (included in Storage Blob Data
+    /// Contributor/Reader via Storage Blob Delegator). With a storage key
+    /// secret it is a service SAS signed with the account key.
+    pub async fn read_url(&self, name: &str, ttl: std::time::Duration) -> Result<FileReadUrl> {
+        use azure_storage::shared_access_signature::service_sas::BlobSasPermissions;
+
+        let expires_on = Utc::now()
+            + chrono::Duration::from_std(ttl)
+                .map_err(|_| CrosstacheError::invalid_argument("URL lifetime is too long"))?;
+        let expiry = time::OffsetDateTime::from_unix_timestamp(expires_on.timestamp())
+            .map_err(|e| CrosstacheError::invalid_argument(format!("Invalid URL expiry: {e}")))?;
+        // Start slightly in the past to tolerate clock skew with the service.
+        let start = time::OffsetDateTime::now_utc() - time::Duration::minutes(5);
+
+        let blob_service = self.blob_service().await?;
+        let blob_client = blob_service
+            .container_client(&self.container_name)
+            .blob_client(name);
+        let permissions = BlobSasPermissions {
+            read: true,
+            ..Default::default()
+        };
+        let sas = if self.uses_account_key() {
+            blob_client
+                .shared_access_signature(permissions, expiry)
+                .await
+        } else {
+            let delegation_key = blob_service
+                .get_user_deligation_key(start, expiry)
+                .await
+                .map_err(|e| self.storage_error(&e, "Failed to get a user delegation key"))?
+                .user_deligation_key;
+            blob_client
+                .user_delegation_shared_access_signature(permissions, &delegation_key)
+                .await
+        }
+        .map_err(|e| CrosstacheError::azure_api(format!("Failed to sign read URL: {e}")))?;
+        let url = blob_client
+            .generate_signed_blob_url(&sas)
+            .map_err(|e| CrosstacheError::azure_api(format!("Failed to build read URL: {e}")))?;
+
+        Ok(FileReadUrl {
+            url: url.to_string(),
+            expires_on,
+        })
+    }
+
+    /// Overwrite a blob's metadata (which carries its groups) and, when
+    /// `update.tags` is set, its index tags. The content is not rewritten.
+    pub async fn update_file_properties(
+        &self,
+        name: &str,
+        update: FilePropertiesUpdate,
+    ) -> Result<FileInfo> {
+        // Fails with "not found" before any write for a missing blob.
+        self.blob_properties(name).await?;
+
+        let blob_service = self.blob_service().await?;
+        let blob_client = blob_service
+            .container_client(&self.container_name)
+            .blob_client(name);
+
+        let mut sdk_metadata = Metadata::new();
+        for (k, v) in &update.metadata {
+            sdk_metadata.insert(k.clone(), v.clone());
+        }
+        if !update.groups.is_empty() {
+            sdk_metadata.insert("groups".to_string(), update.groups.join(","));
+        }
+        blob_client
+            .set_metadata()
+            .metadata(sdk_metadata)
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to update blob metadata"))?;
+
+        if let Some(tags) = update.tags {
+            blob_client
+                .set_tags(tags)
+                .await
+                .map_err(|e| self.storage_error(&e, "Failed to update blob tags"))?;
+        }
+
+        self.get_file_info(name).await
+    }
+
+    /// Server-side copy of `request.source` to `request.destination`, in
+    /// this container or `request.destination_container` of the same
+    /// account. Copy Blob carries the metadata (and so the groups) over but
+    /// not the index tags, which are set on the copy afterwards.
+    pub async fn copy_file(&self, request: FileCopyRequest) -> Result<FileInfo> {
+        use azure_storage_blobs::blob::CopyStatus;
+
+        let destination = match &request.destination_container {
+            Some(container) => self.in_container(container),
+            None => self.in_container(&self.container_name),
+        };
+        let source = self.get_file_info(&request.source).await?;
+        if !request.overwrite {
+            match destination.get_file_info(&request.destination).await {
+                Ok(_) => {
+                    return Err(CrosstacheError::conflict(format!(
+                        "file '{}' already exists (use --force to overwrite)",
+                        request.destination
+                    )))
+                }
+                Err(CrosstacheError::VaultNotFound { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // The source is read through a short-lived delegation SAS, so the
+        // copy works with Entra ID credentials alone.
+        let source_url = self.read_url(&request.source, COPY_TIMEOUT).await?.url;
+        let source_url = azure_core::Url::parse(&source_url)
+            .map_err(|e| CrosstacheError::invalid_url(format!("Invalid copy source URL: {e}")))?;
+
+        let blob_service = self.blob_service().await?;
+        let blob_client = blob_service
+            .container_client(&destination.container_name)
+            .blob_client(&request.destination);
+
+        let mut status = blob_client
+            .copy(source_url)
+            .await
+            .map_err(|e| destination.storage_error(&e, "Failed to copy blob"))?
+            .copy_status;
+        let deadline = tokio::time::Instant::now() + COPY_TIMEOUT;
+        let mut interval = COPY_POLL_INITIAL;
+        while status == CopyStatus::Pending {
+            if tokio::time::Instant::now() + interval > deadline {
+                return Err(CrosstacheError::connection_timeout(format!(
+                    "Copy of '{}' to '{}' still pending after {} minutes; it may finish \
+                     server-side, check with 'xv file info {}'",
+                    request.source,
+                    request.destination,
+                    COPY_TIMEOUT.as_secs() / 60,
+                    request.destination
+                )));
+            }
+            tokio::time::sleep(interval).await;
+            interval = next_copy_poll(interval);
+            let properties = blob_client
+                .get_properties()
+                .await
+                .map_err(|e| destination.storage_error(&e, "Failed to check copy status"))?;
+            status = properties
+                .blob
+                .properties
+                .copy_status
+                .unwrap_or(CopyStatus::Success);
+        }
+        if status != CopyStatus::Success {
+            return Err(CrosstacheError::azure_api(format!(
+                "Copy of '{}' to '{}' did not complete ({})",
+                request.source,
+                request.destination,
+                <&str>::from(status)
+            )));
+        }
+
+        if !source.tags.is_empty() {
+            blob_client
+                .set_tags(source.tags)
+                .await
+                .map_err(|e| destination.storage_error(&e, "Failed to copy blob tags"))?;
+        }
+        destination.get_file_info(&request.destination).await
+    }
+
+    /// A manager for another container of the same storage account.
+    fn in_container(&self, container: &str) -> Self {
+        Self {
+            storage_account: self.storage_account.clone(),
+            container_name: container.to_string(),
+            endpoint: self.endpoint.clone(),
+            auth_provider: Arc::clone(&self.auth_provider),
+            chunk_size_mb: self.chunk_size_mb,
+            max_concurrent_uploads: self.max_concurrent_uploads,
+            transfer_retries: self.transfer_retries,
+            key_secret: self.key_secret.clone(),
+            credentials: Arc::clone(&self.credentials),
+        }
+    }
+
+    /// A blob's [`FileInfo`] and its raw properties, for callers that report
+    /// more than `FileInfo` carries.
+    async fn blob_properties(
+        &self,
+        name: &str,
+    ) -> Result<(FileInfo, azure_storage_blobs::blob::BlobProperties)> {
         // Validate file name parameter
         if name.trim().is_empty() {
             return Err(CrosstacheError::config(
@@ -450,9 +775,7 @@ impl BlobManager {
             ));
         }
 
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container and blob clients
         let container_client = blob_service.container_client(&self.container_name);
@@ -460,11 +783,10 @@ impl BlobManager {
 
         // Get blob properties
         let properties = blob_client.get_properties().await.map_err(|e| {
-            let error_msg = e.to_string().to_lowercase();
-            if error_msg.contains("404") || error_msg.contains("not found") {
+            if is_missing_blob(&e) {
                 CrosstacheError::vault_not_found(format!("File '{name}' not found"))
             } else {
-                CrosstacheError::azure_api(format!("Failed to get blob properties: {e}"))
+                self.storage_error(&e, "Failed to get blob properties")
             }
         })?;
 
@@ -507,7 +829,7 @@ impl BlobManager {
         };
 
         // Build complete FileInfo with all available data
-        Ok(FileInfo {
+        let info = FileInfo {
             name: name.to_string(),
             size,
             content_type,
@@ -516,16 +838,18 @@ impl BlobManager {
             groups,
             metadata,
             tags,
-        })
+        };
+        Ok((info, properties.blob.properties))
     }
 
-    /// Stream download a large file
-    #[allow(dead_code)]
+    /// Stream a file's contents to `writer`, one chunk at a time. Returns
+    /// the blob's size in bytes.
     pub async fn download_file_stream<W: AsyncWrite + Unpin>(
         &self,
         name: &str,
         mut writer: W,
-    ) -> Result<()> {
+        reporter: &dyn ProgressReporter,
+    ) -> Result<u64> {
         // Validate file name
         if name.trim().is_empty() {
             return Err(CrosstacheError::config(
@@ -533,9 +857,7 @@ impl BlobManager {
             ));
         }
 
-        // Create BlobServiceClient using token credential
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
 
         // Get container and blob clients
         let container_client = blob_service.container_client(&self.container_name);
@@ -543,16 +865,16 @@ impl BlobManager {
 
         // Check if blob exists and get its size before attempting download
         let properties = blob_client.get_properties().await.map_err(|e| {
-            let error_msg = e.to_string().to_lowercase();
-            if error_msg.contains("404") || error_msg.contains("not found") {
+            if is_missing_blob(&e) {
                 CrosstacheError::vault_not_found(format!("File '{name}' not found"))
             } else {
-                CrosstacheError::azure_api(format!("Failed to check if blob exists: {e}"))
+                self.storage_error(&e, "Failed to check if blob exists")
             }
         })?;
 
         let content_length = properties.blob.properties.content_length;
         validate_download_size(content_length, MAX_DOWNLOAD_SIZE_BYTES)?;
+        reporter.set_total(content_length);
 
         use tokio::io::AsyncWriteExt;
 
@@ -564,26 +886,48 @@ impl BlobManager {
                 .flush()
                 .await
                 .map_err(|e| CrosstacheError::unknown(format!("Failed to flush data: {e}")))?;
-            return Ok(());
+            return Ok(0);
         }
 
-        // Stream the blob as a sequence of chunk-sized ranged GETs. Each page's
-        // body is itself a byte stream, so at most one chunk is buffered at a
-        // time instead of the entire blob.
+        // Stream the blob as a sequence of chunk-sized ranged GETs. Each range
+        // is fetched (and retried) whole before it is written, so at most one
+        // chunk is buffered and a retry never writes bytes twice.
         let chunk_size = download_chunk_size_bytes(self.chunk_size_mb);
-        let mut pages = blob_client.get().chunk_size(chunk_size).into_stream();
-        while let Some(page) = pages
-            .try_next()
+        let retry = RetryOptions::transfer(self.transfer_retries);
+        let mut written: u64 = 0;
+        while written < content_length {
+            let start = written;
+            let end = (start + chunk_size).min(content_length);
+            let bytes = retry_with_backoff_if(
+                || self.download_range(&blob_client, start, end),
+                retry.clone(),
+                is_retryable_transfer_error,
+            )
             .await
-            .map_err(|e| CrosstacheError::azure_api(format!("Failed to download blob: {e}")))?
-        {
-            let mut body = page.data;
-            while let Some(bytes) = body.try_next().await.map_err(|e| {
-                CrosstacheError::azure_api(format!("Failed to read blob data stream: {e}"))
-            })? {
-                writer.write_all(&bytes).await.map_err(|e| {
-                    CrosstacheError::unknown(format!("Failed to write blob data: {e}"))
-                })?;
+            .map_err(|e| {
+                if !is_retryable_transfer_error(&e) {
+                    return e;
+                }
+                let failure = FailedRange {
+                    label: format!("range {}", start / chunk_size),
+                    offset: start,
+                    len: end - start,
+                    error: e.to_string(),
+                };
+                let total = content_length.div_ceil(chunk_size) as usize;
+                CrosstacheError::network(format!(
+                    "Download of '{name}' failed: {}",
+                    describe_failed_ranges("ranges", total, self.transfer_retries + 1, &[failure])
+                ))
+            })?;
+            writer
+                .write_all(&bytes)
+                .await
+                .map_err(|e| CrosstacheError::unknown(format!("Failed to write blob data: {e}")))?;
+            written += bytes.len() as u64;
+            reporter.advance(bytes.len() as u64);
+            if bytes.is_empty() {
+                break;
             }
         }
 
@@ -592,8 +936,43 @@ impl BlobManager {
             .flush()
             .await
             .map_err(|e| CrosstacheError::unknown(format!("Failed to flush data: {e}")))?;
+        reporter.finish_clear();
 
-        Ok(())
+        if written != content_length {
+            return Err(CrosstacheError::network(format!(
+                "Download of '{name}' truncated: expected {content_length} bytes, wrote {written}"
+            )));
+        }
+        Ok(written)
+    }
+
+    /// Fetch bytes `start..end` of a blob in one ranged GET.
+    async fn download_range(
+        &self,
+        blob_client: &BlobClient,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<u8>> {
+        let mut pages = blob_client
+            .get()
+            .range(start..end)
+            .chunk_size(end - start)
+            .into_stream();
+        let page = pages
+            .try_next()
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to download blob"))?
+            .ok_or_else(|| CrosstacheError::network("Blob download returned no data"))?;
+        let mut body = page.data;
+        let mut bytes = Vec::with_capacity((end - start) as usize);
+        while let Some(chunk) = body
+            .try_next()
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to read blob data stream"))?
+        {
+            bytes.extend_from_slice(&chunk);
+        }
+        Ok(bytes)
     }
 
     /// Upload a large file to blob storage using Azure block blob chunked upload.
@@ -605,37 +984,58 @@ impl BlobManager {
     ///
     /// After the commit, blob properties are fetched so that the returned
     /// [`FileInfo`] contains the real server-side `size`, `etag`, and
-    /// `last_modified` values.
-    #[allow(dead_code)]
+    /// `last_modified` values. `file_size` only sizes the progress bar, so a
+    /// stream of unknown length (stdin) passes `None`. Metadata follows
+    /// [`upload_file`](Self::upload_file), including leaving tags unset.
     pub async fn upload_large_file<R: tokio::io::AsyncRead + Unpin>(
         &self,
-        name: &str,
+        spec: FileUploadSpec,
         mut reader: R,
-        file_size: u64,
-        metadata: HashMap<String, String>,
-        tags: HashMap<String, String>,
+        file_size: Option<u64>,
         reporter: &dyn ProgressReporter,
     ) -> Result<FileInfo> {
+        use tokio::io::AsyncReadExt;
         use tokio::sync::Semaphore;
 
-        let content_type = mime_guess::from_path(name)
-            .first_or_octet_stream()
-            .to_string();
+        let name = spec.name.as_str();
         let chunk_size = self.chunk_size_mb * 1024 * 1024;
-        reporter.set_total(file_size);
+        // Read the first block up front so its leading bytes can be sniffed.
+        let first = read_chunk(&mut reader, chunk_size)
+            .await
+            .map_err(|e| CrosstacheError::unknown(format!("Failed to read file data: {e}")))?;
+        let content_type =
+            crate::blob::content_type::resolve(spec.content_type.as_deref(), name, &first);
+        let mut reader = std::io::Cursor::new(first).chain(reader);
+        let mut metadata = spec.metadata.clone();
+        if !spec.groups.is_empty() {
+            metadata.insert("groups".to_string(), spec.groups.join(","));
+        }
+        metadata.insert("uploaded_by".to_string(), "crosstache".to_string());
+        metadata.insert("uploaded_at".to_string(), Utc::now().to_rfc3339());
+        let mut sdk_metadata = Metadata::new();
+        for (k, v) in &metadata {
+            sdk_metadata.insert(k.clone(), v.clone());
+        }
+
+        if let Some(file_size) = file_size {
+            reporter.set_total(file_size);
+        }
 
         // Build blob client.
-        let token_credential = self.auth_provider.get_token_credential();
-        let blob_service = BlobServiceClient::new(&self.storage_account, token_credential);
+        let blob_service = self.blob_service().await?;
         let container_client = blob_service.container_client(&self.container_name);
         let blob_client = container_client.blob_client(name);
 
         let semaphore = Arc::new(Semaphore::new(self.max_concurrent_uploads));
         let mut block_list = BlockList::default();
         let mut block_idx: u32 = 0;
-        let mut upload_tasks: Vec<(tokio::task::JoinHandle<Result<()>>, u64)> = Vec::new();
+        let retry = RetryOptions::transfer(self.transfer_retries);
+        let mut upload_tasks: Vec<(tokio::task::JoinHandle<Result<()>>, u64, u64)> = Vec::new();
+        let mut offset: u64 = 0;
 
-        // Read chunks and spawn concurrent block uploads.
+        // Read chunks and spawn concurrent block uploads. Each block is
+        // retried on its own, so one dropped connection costs a block rather
+        // than the whole upload.
         loop {
             let chunk = read_chunk(&mut reader, chunk_size)
                 .await
@@ -656,32 +1056,77 @@ impl BlobManager {
                 .await
                 .map_err(|e| CrosstacheError::unknown(format!("Semaphore error: {e}")))?;
             let blob_client = blob_client.clone();
+            let account = self.storage_account.clone();
+            let container = self.container_name.clone();
+            let retry = retry.clone();
             let actual_chunk_size = chunk.len() as u64;
-            let chunk_bytes = chunk; // Vec<u8> satisfies Into<azure_core::Body>
 
             upload_tasks.push((
                 tokio::spawn(async move {
                     let _permit = permit; // held for the duration of the upload
-                    blob_client
-                        .put_block(block_id, chunk_bytes)
-                        .await
-                        .map_err(|e| {
-                            CrosstacheError::azure_api(format!("Failed to upload block: {e}"))
-                        })?;
-                    Ok(())
+                    retry_with_backoff_if(
+                        || async {
+                            // Vec<u8> satisfies Into<azure_core::Body>
+                            blob_client
+                                .put_block(block_id.clone(), chunk.clone())
+                                .await
+                                .map(|_| ())
+                                .map_err(|e| {
+                                    storage_error(
+                                        &e,
+                                        "Failed to upload block",
+                                        &account,
+                                        &container,
+                                    )
+                                })
+                        },
+                        retry,
+                        is_retryable_transfer_error,
+                    )
+                    .await
                 }),
+                offset,
                 actual_chunk_size,
             ));
+            offset += actual_chunk_size;
         }
 
-        // Wait for all block uploads to finish and report progress.
-        for (task, bytes) in upload_tasks {
-            task.await
+        // Wait for all block uploads to finish and report progress. Every
+        // block is awaited so the error names all the ranges that failed.
+        let total_blocks = upload_tasks.len();
+        let mut failures = Vec::new();
+        for (index, (task, offset, bytes)) in upload_tasks.into_iter().enumerate() {
+            match task
+                .await
                 .map_err(|e| CrosstacheError::unknown(format!("Upload task panicked: {e}")))?
-                .map_err(|e: CrosstacheError| e)?;
-            reporter.advance(bytes);
+            {
+                Ok(()) => reporter.advance(bytes),
+                // A refusal (permissions, firewall, ...) fails every block
+                // alike; surface it as is rather than as a block summary.
+                Err(e) if !is_retryable_transfer_error(&e) => {
+                    reporter.finish_clear();
+                    return Err(e);
+                }
+                Err(e) => failures.push(FailedRange {
+                    label: format!("block {index}"),
+                    offset,
+                    len: bytes,
+                    error: e.to_string(),
+                }),
+            }
         }
         reporter.finish_clear();
+        if !failures.is_empty() {
+            return Err(CrosstacheError::network(format!(
+                "Upload of '{name}' failed: {}",
+                describe_failed_ranges(
+                    "blocks",
+                    total_blocks,
+                    self.transfer_retries + 1,
+                    &failures
+                )
+            )));
+        }
 
         if block_list.blocks.is_empty() {
             // Zero-byte file: fall back to a simple put_block_blob so the blob
@@ -689,25 +1134,24 @@ impl BlobManager {
             blob_client
                 .put_block_blob(vec![])
                 .content_type(&content_type)
+                .metadata(sdk_metadata)
                 .await
-                .map_err(|e| {
-                    CrosstacheError::azure_api(format!("Failed to upload empty blob: {e}"))
-                })?;
+                .map_err(|e| self.storage_error(&e, "Failed to upload empty blob"))?;
         } else {
             // Commit the staged blocks.
             blob_client
                 .put_block_list(block_list)
                 .content_type(&content_type)
+                .metadata(sdk_metadata)
                 .await
-                .map_err(|e| {
-                    CrosstacheError::azure_api(format!("Failed to commit block list: {e}"))
-                })?;
+                .map_err(|e| self.storage_error(&e, "Failed to commit block list"))?;
         }
 
         // Fetch the committed blob's server-side properties for an accurate FileInfo.
-        let properties = blob_client.get_properties().await.map_err(|e| {
-            CrosstacheError::azure_api(format!("Failed to get blob properties after upload: {e}"))
-        })?;
+        let properties = blob_client
+            .get_properties()
+            .await
+            .map_err(|e| self.storage_error(&e, "Failed to get blob properties after upload"))?;
 
         let size = properties.blob.properties.content_length;
         let last_modified = {
@@ -716,11 +1160,6 @@ impl BlobManager {
         };
         let etag = properties.blob.properties.etag.to_string();
 
-        let groups: Vec<String> = metadata
-            .get("groups")
-            .map(|g| g.split(',').map(|s| s.trim().to_string()).collect())
-            .unwrap_or_default();
-
         tracing::info!(
             name = name,
             blocks = block_idx,
@@ -734,9 +1173,9 @@ impl BlobManager {
             content_type,
             last_modified,
             etag,
-            groups,
+            groups: spec.groups,
             metadata,
-            tags,
+            tags: spec.tags,
         })
     }
 
@@ -746,6 +1185,11 @@ impl BlobManager {
         &self.container_name
     }
 
+    /// [`storage_error`] for this manager's account and container.
+    fn storage_error(&self, error: &azure_core::Error, context: &str) -> CrosstacheError {
+        storage_error(error, context, &self.storage_account, &self.container_name)
+    }
+
     /// Get the storage account name
     #[allow(dead_code)]
     pub fn storage_account(&self) -> &str {
@@ -753,6 +1197,143 @@ impl BlobManager {
     }
 }
 
+/// The account key every Azurite instance accepts for `devstoreaccount1`.
+/// It is published in the Azurite documentation and grants nothing outside
+/// a local emulator.
+const AZURITE_ACCOUNT_KEY: &str =
+    "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+/// Whether `endpoint` addresses a storage emulator: Azure Storage only
+/// serves HTTPS, so a plain `http://` URL can only be Azurite.
+fn is_emulator_endpoint(endpoint: &str) -> bool {
+    endpoint
+        .trim()
+        .get(..7)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("http://"))
+}
+
+/// Credentials from a storage key secret: a connection string (it has
+/// `;`-separated settings, which a base64 account key never contains) or a
+/// bare account key for `account`.
+fn storage_credentials_from_secret(account: &str, value: &str) -> Result<StorageCredentials> {
+    if value.is_empty() {
+        return Err(CrosstacheError::config("Storage key secret is empty"));
+    }
+    if !value.contains(';') {
+        return Ok(StorageCredentials::access_key(
+            account.to_string(),
+            value.to_string(),
+        ));
+    }
+    let connection = ConnectionString::new(value)
+        .map_err(|e| CrosstacheError::config(format!("Invalid storage connection string: {e}")))?;
+    if let Some(name) = connection.account_name {
+        if name != account {
+            return Err(CrosstacheError::config(format!(
+                "Storage connection string is for account '{name}', but storage_account is '{account}'"
+            )));
+        }
+    }
+    connection
+        .storage_credentials()
+        .map_err(|e| CrosstacheError::config(format!("Unusable storage connection string: {e}")))
+}
+
+/// Classify a storage SDK failure, keeping the HTTP status class when the
+/// service answered (403 becomes `Forbidden`, 404 `NotFound`, 429
+/// `RateLimited`, ...). Refusals with a recognizable cause (missing data
+/// role, firewall, missing container) carry that cause and its fix instead
+/// of the raw SDK error; a missing data role also names the role.
+fn storage_error(
+    error: &azure_core::Error,
+    context: &str,
+    account: &str,
+    container: &str,
+) -> CrosstacheError {
+    match error.kind() {
+        azure_core::error::ErrorKind::HttpResponse { status, error_code } => {
+            let status = u16::from(*status);
+            let diagnosis = StorageDiagnosis::from_response(status, error_code.as_deref());
+            let message = match diagnosis {
+                Some(diagnosis) => format!("{context}: {}", diagnosis.explain(account, container)),
+                None => format!("{context}: {error}"),
+            };
+            let error = CrosstacheError::from_http_status(
+                status,
+                None,
+                &format!("{account}/{container}"),
+                message,
+            );
+            match diagnosis {
+                Some(StorageDiagnosis::MissingDataRole) => error
+                    .with_required_permission(Some("Storage Blob Data Contributor".to_string())),
+                _ => error,
+            }
+        }
+        azure_core::error::ErrorKind::Io => CrosstacheError::network(format!("{context}: {error}")),
+        _ => CrosstacheError::azure_api(format!("{context}: {error}")),
+    }
+}
+
+/// Whether a failed blob request means the blob itself is absent, as opposed
+/// to its whole container.
+fn is_missing_blob(error: &azure_core::Error) -> bool {
+    if let azure_core::error::ErrorKind::HttpResponse { status, error_code } = error.kind() {
+        let status = u16::from(*status);
+        if status == 404 {
+            return StorageDiagnosis::from_response(status, error_code.as_deref())
+                != Some(StorageDiagnosis::ContainerMissing);
+        }
+    }
+    let error_msg = error.to_string().to_lowercase();
+    error_msg.contains("404") || error_msg.contains("not found")
+}
+
+/// A listed blob as a [`FileInfo`], or `None` when `groups` filters it out.
+fn blob_file_info(
+    blob_item: &azure_storage_blobs::blob::Blob,
+    groups: Option<&[String]>,
+) -> Option<FileInfo> {
+    // Convert time::OffsetDateTime to chrono::DateTime<Utc>
+    let last_modified = {
+        let timestamp = blob_item.properties.last_modified.unix_timestamp();
+        chrono::DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now)
+    };
+
+    let metadata = blob_item.metadata.clone().unwrap_or_default();
+
+    // Extract groups from metadata
+    let blob_groups: Vec<String> = metadata
+        .get("groups")
+        .map(|g| g.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default();
+
+    // Apply group-based filtering if requested
+    if let Some(filter_groups) = groups {
+        if !filter_groups.iter().any(|fg| blob_groups.contains(fg)) {
+            return None;
+        }
+    }
+
+    // Extract tags if they were requested and returned inline
+    let tags: HashMap<String, String> = blob_item
+        .tags
+        .clone()
+        .map(HashMap::from)
+        .unwrap_or_default();
+
+    Some(FileInfo {
+        name: blob_item.name.clone(),
+        size: blob_item.properties.content_length,
+        content_type: blob_item.properties.content_type.clone(),
+        last_modified,
+        etag: blob_item.properties.etag.to_string(),
+        groups: blob_groups,
+        metadata,
+        tags,
+    })
+}
+
 /// Normalize a prefix by ensuring it ends with '/' if non-empty
 fn normalize_prefix(prefix: Option<String>) -> Option<String> {
     prefix.and_then(|p| {
@@ -791,6 +1372,17 @@ fn sort_blob_items(items: &mut [BlobListItem]) {
     });
 }
 
+/// How long `copy_file` waits for a server-side copy. The source SAS lasts
+/// as long, so the service could not finish the copy after it anyway.
+const COPY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+/// First wait between copy status polls; doubles up to [`COPY_POLL_MAX`].
+const COPY_POLL_INITIAL: std::time::Duration = std::time::Duration::from_millis(500);
+const COPY_POLL_MAX: std::time::Duration = std::time::Duration::from_secs(10);
+
+fn next_copy_poll(interval: std::time::Duration) -> std::time::Duration {
+    (interval * 2).min(COPY_POLL_MAX)
+}
+
 /// Maximum blob size accepted by `download_file_stream`.
 ///
 /// There is currently no user-facing config field for a download size limit
@@ -852,6 +1444,114 @@ async fn read_chunk<R: tokio::io::AsyncRead + Unpin>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn copy_polls_back_off_to_a_cap_within_the_timeout() {
+        let mut interval = COPY_POLL_INITIAL;
+        let mut waited = std::time::Duration::ZERO;
+        for _ in 0..10 {
+            waited += interval;
+            interval = next_copy_poll(interval);
+        }
+        assert_eq!(interval, COPY_POLL_MAX);
+        assert!(waited < COPY_TIMEOUT);
+    }
+
+    // ── emulator endpoints ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_plain_http_endpoint_is_the_emulator() {
+        assert!(is_emulator_endpoint(
+            "http://127.0.0.1:10000/devstoreaccount1"
+        ));
+        assert!(is_emulator_endpoint(
+            "HTTP://azurite:10000/devstoreaccount1"
+        ));
+        assert!(!is_emulator_endpoint("https://acct.blob.core.windows.net"));
+        assert!(!is_emulator_endpoint("http"));
+    }
+
+    // ── storage_credentials_from_secret ─────────────────────────────────────
+
+    #[test]
+    fn test_storage_key_secret_accepts_key_or_matching_connection_string() {
+        assert!(storage_credentials_from_secret("acct", "a2V5a2V5a2V5==").is_ok());
+        assert!(storage_credentials_from_secret(
+            "acct",
+            "DefaultEndpointsProtocol=https;AccountName=acct;AccountKey=a2V5a2V5a2V5==;EndpointSuffix=core.windows.net",
+        )
+        .is_ok());
+        assert!(storage_credentials_from_secret(
+            "acct",
+            "DefaultEndpointsProtocol=https;AccountName=other;AccountKey=a2V5a2V5a2V5==",
+        )
+        .is_err());
+        assert!(storage_credentials_from_secret("acct", "").is_err());
+    }
+
+    // ── storage_error ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_storage_error_keeps_http_status_class() {
+        let forbidden = azure_core::Error::new(
+            azure_core::error::ErrorKind::http_response(azure_core::StatusCode::Forbidden, None),
+            "denied",
+        );
+        assert!(matches!(
+            storage_error(&forbidden, "Failed to list blobs", "acct", "files"),
+            CrosstacheError::Forbidden { ref message, required_permission: None }
+                if message.starts_with("Failed to list blobs: ")
+        ));
+
+        let io = azure_core::Error::message(azure_core::error::ErrorKind::Io, "reset");
+        assert!(matches!(
+            storage_error(&io, "Failed to download blob", "acct", "files"),
+            CrosstacheError::NetworkError(_)
+        ));
+    }
+
+    #[test]
+    fn test_storage_error_explains_known_refusals() {
+        let no_role = azure_core::Error::new(
+            azure_core::error::ErrorKind::http_response(
+                azure_core::StatusCode::Forbidden,
+                Some("AuthorizationPermissionMismatch".to_string()),
+            ),
+            "denied",
+        );
+        assert!(matches!(
+            storage_error(&no_role, "Failed to upload blob", "acct", "files"),
+            CrosstacheError::Forbidden { ref message, ref required_permission }
+                if message.contains("Storage Blob Data Contributor")
+                    && required_permission.as_deref() == Some("Storage Blob Data Contributor")
+        ));
+
+        let no_container = azure_core::Error::new(
+            azure_core::error::ErrorKind::http_response(
+                azure_core::StatusCode::NotFound,
+                Some("ContainerNotFound".to_string()),
+            ),
+            "The specified container does not exist.",
+        );
+        assert!(!is_missing_blob(&no_container));
+        let missing = storage_error(&no_container, "Failed to upload blob", "acct", "files");
+        assert!(matches!(
+            missing,
+            CrosstacheError::NotFound { ref resource, .. } if resource == "acct/files"
+        ));
+        assert!(missing
+            .to_string()
+            .contains("container 'files' does not exist"));
+
+        let no_blob = azure_core::Error::new(
+            azure_core::error::ErrorKind::http_response(
+                azure_core::StatusCode::NotFound,
+                Some("BlobNotFound".to_string()),
+            ),
+            "The specified blob does not exist.",
+        );
+        assert!(is_missing_blob(&no_blob));
+    }
+
     // ── generate_block_id ────────────────────────────────────────────────────
 
     #[test]
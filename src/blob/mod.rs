@@ -3,6 +3,10 @@
 //! This module provides functionality for storing and managing files
 //! in Azure Blob Storage, including upload, download, listing, and deletion.
 
+pub mod archive;
+pub mod content_type;
+pub mod diagnostics;
+pub mod lifecycle;
 pub mod manager;
 pub mod models;
 pub mod sync;
@@ -41,6 +41,29 @@ pub struct FileUploadRequest {
     pub tags: HashMap<String, String>,
 }
 
+/// Metadata for a streaming upload (everything in [`FileUploadRequest`]
+/// except the content, which arrives via a reader).
+#[derive(Debug, Clone)]
+pub struct FileUploadSpec {
+    pub name: String,
+    pub content_type: Option<String>,
+    pub groups: Vec<String>,
+    pub metadata: HashMap<String, String>,
+    pub tags: HashMap<String, String>,
+}
+
+impl From<&FileUploadRequest> for FileUploadSpec {
+    fn from(request: &FileUploadRequest) -> Self {
+        Self {
+            name: request.name.clone(),
+            content_type: request.content_type.clone(),
+            groups: request.groups.clone(),
+            metadata: request.metadata.clone(),
+            tags: request.tags.clone(),
+        }
+    }
+}
+
 /// Request for downloading a file
 #[derive(Debug, Clone)]
 pub struct FileDownloadRequest {
@@ -56,6 +79,79 @@ pub struct FileListRequest {
     pub delimiter: Option<String>,
 }
 
+/// One page of a flat file listing. `next_marker` is the opaque token that
+/// resumes the listing after the last file; `None` on the final page.
+#[derive(Debug, Clone)]
+pub struct FileListPage {
+    pub files: Vec<FileInfo>,
+    pub next_marker: Option<String>,
+}
+
+/// The groups, metadata and tags a stored file should end up with
+/// (`xv file update`). Backends write them without touching the content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilePropertiesUpdate {
+    pub groups: Vec<String>,
+    /// User metadata, without the reserved `groups` key.
+    pub metadata: HashMap<String, String>,
+    /// `None` leaves the stored tags as they are. Writing tags needs more
+    /// permission than writing metadata on Azure (Storage Blob Data Owner),
+    /// so an update that changes no tag does not ask for it.
+    pub tags: Option<HashMap<String, String>>,
+}
+
+/// A server-side copy of one stored file (`xv file cp` / `xv file mv`).
+/// The copy keeps the source's content type, groups, metadata and tags.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileCopyRequest {
+    pub source: String,
+    pub destination: String,
+    /// Container (Azure) or bucket (S3) to copy into; `None` copies within
+    /// the backend's own.
+    pub destination_container: Option<String>,
+    /// Replace an existing destination instead of failing.
+    pub overwrite: bool,
+}
+
+/// A file with the storage properties `xv file info` shows beyond
+/// [`FileInfo`]. A property is `None` when the backend has no equivalent or
+/// did not report it.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileDetails {
+    #[serde(flatten)]
+    pub info: FileInfo,
+    /// Access tier (Azure `Hot`/`Cool`/...) or S3 storage class.
+    pub tier: Option<String>,
+    /// Azure lease state (`available`, `leased`, ...).
+    pub lease_state: Option<String>,
+    /// Version id when blob versioning is enabled.
+    pub version_id: Option<String>,
+    /// Whether the service reports the content as encrypted at rest.
+    pub server_encrypted: Option<bool>,
+    /// Hex MD5 of the content.
+    pub content_md5: Option<String>,
+}
+
+impl From<FileInfo> for FileDetails {
+    fn from(info: FileInfo) -> Self {
+        Self {
+            info,
+            tier: None,
+            lease_state: None,
+            version_id: None,
+            server_encrypted: None,
+            content_md5: None,
+        }
+    }
+}
+
+/// A presigned, read-only URL for one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileReadUrl {
+    pub url: String,
+    pub expires_on: DateTime<Utc>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +195,19 @@ mod tests {
         assert_eq!(fi.metadata.get("uploaded_by").unwrap(), "xv-cli");
     }
 
+    // --- FileDetails serde ---
+
+    #[test]
+    fn test_file_details_flattens_file_info() {
+        let mut details = FileDetails::from(make_file_info("report.pdf", 10));
+        details.tier = Some("Cool".to_string());
+        let json = serde_json::to_value(&details).unwrap();
+        assert_eq!(json["name"], "report.pdf");
+        assert_eq!(json["size"], 10);
+        assert_eq!(json["tier"], "Cool");
+        assert!(json["version_id"].is_null());
+    }
+
     // --- BlobListItem serde ---
 
     #[test]
@@ -0,0 +1,159 @@
+//! Local search index over the cached secret metadata.
+//!
+//! Written next to each `secrets-list` cache entry whenever that entry is
+//! written, and removed with it, so it is exactly as fresh as the metadata
+//! cache. It holds the listing itself (so `xv search` can answer without a
+//! backend round-trip) plus an inverted index from lower-cased terms of
+//! names, notes, folders, groups and user tags to the secrets containing
+//! them, which backs prefix completion.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::debug;
+
+use crate::secret::manager::SecretSummary;
+use crate::utils::fuzzy::{CandidateItem, FuzzyField};
+
+/// On-disk filename, versioned like `SECRETS_LIST_FILENAME`: bump it on any
+/// change to the index shape so old files simply miss.
+pub(crate) const SEARCH_INDEX_FILENAME: &str = "search-index-v1.json";
+
+/// The index for one `(backend, vault)`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// Same header field as a cache entry, so `xv cache status` can date it.
+    pub created_at: DateTime<Utc>,
+    pub vault_name: String,
+    pub secrets: Vec<SecretSummary>,
+    /// Lower-cased term → indexes into `secrets`, ascending.
+    pub terms: BTreeMap<String, Vec<u32>>,
+}
+
+/// Split `text` into lower-cased alphanumeric terms.
+fn terms_of(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+}
+
+impl SearchIndex {
+    /// Index `secrets` of `vault_name`.
+    pub fn build(vault_name: &str, secrets: Vec<SecretSummary>) -> Self {
+        let mut terms: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+        for (i, secret) in secrets.iter().enumerate() {
+            let item = CandidateItem::from_secret_summary(secret);
+            for field in FuzzyField::ALL {
+                for value in item.field_values(field) {
+                    for term in terms_of(value) {
+                        let ids = terms.entry(term).or_default();
+                        if ids.last() != Some(&(i as u32)) {
+                            ids.push(i as u32);
+                        }
+                    }
+                }
+            }
+        }
+        Self {
+            created_at: Utc::now(),
+            vault_name: vault_name.to_string(),
+            secrets,
+            terms,
+        }
+    }
+
+    /// Read the index at `path`; `None` when missing or unreadable.
+    pub fn load(path: &Path) -> Option<Self> {
+        let raw = std::fs::read_to_string(path).ok()?;
+        match serde_json::from_str(&raw) {
+            Ok(index) => Some(index),
+            Err(e) => {
+                debug!("Search index parse error ({}): {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Write the index to `path` (atomic: temp-file → rename). Errors are
+    /// logged and ignored, like cache writes.
+    pub fn save(&self, path: &Path) {
+        let json = match serde_json::to_string(self) {
+            Ok(json) => json,
+            Err(e) => {
+                debug!("Search index serialisation error: {e}");
+                return;
+            }
+        };
+        let tmp_path = path.with_extension("tmp");
+        if let Err(e) = std::fs::write(&tmp_path, json) {
+            debug!("Search index write error ({}): {e}", tmp_path.display());
+            return;
+        }
+        if let Err(e) = std::fs::rename(&tmp_path, path) {
+            debug!("Search index rename error ({}): {e}", path.display());
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+    }
+
+    /// Indexed terms starting with `prefix` (case-insensitive), most
+    /// common first, then alphabetical.
+    pub fn complete(&self, prefix: &str) -> Vec<&str> {
+        let prefix = prefix.to_lowercase();
+        let mut found: Vec<(&str, usize)> = self
+            .terms
+            .range(prefix.clone()..)
+            .take_while(|(term, _)| term.starts_with(&prefix))
+            .map(|(term, ids)| (term.as_str(), ids.len()))
+            .collect();
+        found.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        found.into_iter().map(|(term, _)| term).collect()
+    }
+
+    /// Age in whole seconds.
+    pub fn age_secs(&self) -> u64 {
+        (Utc::now() - self.created_at).num_seconds().max(0) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(name: &str, note: Option<&str>, folder: Option<&str>) -> SecretSummary {
+        SecretSummary {
+            name: name.to_string(),
+            original_name: name.to_string(),
+            note: note.map(str::to_string),
+            folder: folder.map(str::to_string),
+            groups: Some("payments".to_string()),
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            content_type: String::new(),
+            tags: std::collections::HashMap::from([("env".to_string(), "prod".to_string())]),
+        }
+    }
+
+    #[test]
+    fn indexes_every_field_and_completes_prefixes() {
+        let index = SearchIndex::build(
+            "kv",
+            vec![
+                secret("db-password", Some("Primary Postgres"), Some("app/db")),
+                secret("api-key", None, Some("app")),
+            ],
+        );
+        assert_eq!(index.terms["db"], vec![0]);
+        assert_eq!(index.terms["postgres"], vec![0]);
+        assert_eq!(index.terms["app"], vec![0, 1]);
+        assert_eq!(index.terms["payments"], vec![0, 1]);
+        assert_eq!(index.terms["prod"], vec![0, 1]);
+
+        assert_eq!(
+            index.complete("P"),
+            vec!["payments", "prod", "password", "postgres", "primary"]
+        );
+        assert!(index.complete("zz").is_empty());
+    }
+}
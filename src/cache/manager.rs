@@ -9,6 +9,7 @@ use serde::{de::DeserializeOwned, Serialize};
 use std::path::{Path, PathBuf};
 use tracing::debug;
 
+use crate::cache::index::{SearchIndex, SEARCH_INDEX_FILENAME};
 use crate::cache::models::{
     validate_cache_vault_name, CacheEntry, CacheEntryInfo, CacheKey, CacheStatus,
 };
@@ -58,7 +59,7 @@ impl CacheManager {
     /// `xv`, else `/tmp/xv` as a last resort. A relative `XV_CACHE_DIR` is
     /// resolved against the process's current working directory, which can
     /// shift under `cd`/`chdir` — an absolute path is recommended.
-    fn resolve_cache_dir() -> PathBuf {
+    pub(crate) fn resolve_cache_dir() -> PathBuf {
         if let Ok(dir) = std::env::var("XV_CACHE_DIR") {
             if !dir.is_empty() {
                 return PathBuf::from(dir);
@@ -146,6 +147,27 @@ impl CacheManager {
         Some(entry.data)
     }
 
+    /// Read a cached value regardless of its age, with the time it was
+    /// cached. For showing the last known listing when the backend is
+    /// unreachable; never triggers a refresh. `None` when caching is
+    /// disabled or there is no readable entry.
+    pub fn get_stale<T: Serialize + DeserializeOwned>(
+        &self,
+        key: &CacheKey,
+    ) -> Option<(T, chrono::DateTime<Utc>)> {
+        if !self.enabled {
+            return None;
+        }
+        let raw = std::fs::read_to_string(key.to_path(&self.cache_dir)).ok()?;
+        match serde_json::from_str::<CacheEntry<T>>(&raw) {
+            Ok(entry) => Some((entry.data, entry.created_at)),
+            Err(e) => {
+                debug!("Cache parse error ({key}): {e} — no stale entry");
+                None
+            }
+        }
+    }
+
     /// Write a value to the cache (atomic: temp-file → rename).
     ///
     /// Errors are logged at debug level and silently ignored.
@@ -203,6 +225,37 @@ impl CacheManager {
         }
 
         debug!("Cache set ({key}): written to {}", path.display());
+
+        if let Some(index_path) = self.search_index_path(key) {
+            // Re-read the listing as summaries; `T` is only known to be
+            // serializable here.
+            match serde_json::to_value(data).and_then(serde_json::from_value) {
+                Ok(secrets) => {
+                    let vault_name = key.vault_name().unwrap_or_default();
+                    SearchIndex::build(vault_name, secrets).save(&index_path);
+                }
+                Err(e) => debug!("Cache set ({key}): not indexed: {e}"),
+            }
+        }
+    }
+
+    /// Path of the search index kept beside a `SecretsList` entry; `None`
+    /// for other keys.
+    pub fn search_index_path(&self, key: &CacheKey) -> Option<PathBuf> {
+        matches!(key, CacheKey::SecretsList { .. }).then(|| {
+            key.to_path(&self.cache_dir)
+                .with_file_name(SEARCH_INDEX_FILENAME)
+        })
+    }
+
+    /// The search index for a `SecretsList` key when caching is enabled and
+    /// the index is younger than the TTL.
+    pub fn search_index(&self, key: &CacheKey) -> Option<SearchIndex> {
+        if !self.enabled {
+            return None;
+        }
+        let index = SearchIndex::load(&self.search_index_path(key)?)?;
+        (index.age_secs() < self.ttl_secs).then_some(index)
     }
 
     /// Delete a single cache entry (and its lock file if present).
@@ -212,6 +265,10 @@ impl CacheManager {
 
         let lock_path = self.lock_path(key);
         remove_file_if_exists(&lock_path, "invalidate lock file");
+
+        if let Some(index_path) = self.search_index_path(key) {
+            remove_file_if_exists(&index_path, "invalidate search index");
+        }
     }
 
     /// Delete every cache entry scoped to `vault_name`, across both on-disk
@@ -678,6 +735,23 @@ mod tests {
         assert!(retrieved.is_none());
     }
 
+    #[test]
+    fn test_get_stale_ignores_ttl() {
+        let dir = tempdir().unwrap();
+        let mgr = make_manager(dir.path(), true, 0);
+        let key = CacheKey::VaultList;
+
+        let data = vec!["vault-a".to_string()];
+        mgr.set(&key, &data);
+
+        let (stale, cached_at): (Vec<String>, _) = mgr.get_stale(&key).unwrap();
+        assert_eq!(stale, data);
+        assert!(cached_at <= Utc::now());
+        assert!(make_manager(dir.path(), false, 0)
+            .get_stale::<Vec<String>>(&key)
+            .is_none());
+    }
+
     #[test]
     fn test_get_returns_none_when_disabled() {
         let dir = tempdir().unwrap();
@@ -708,6 +782,39 @@ mod tests {
         assert!(mgr.get::<Vec<String>>(&key).is_none());
     }
 
+    #[test]
+    fn test_secrets_list_entry_carries_a_search_index() {
+        let dir = tempdir().unwrap();
+        let mgr = make_manager(dir.path(), true, 300);
+        let key = CacheKey::SecretsList {
+            backend: "local".to_string(),
+            vault_name: "kv".to_string(),
+        };
+        let summary = crate::secret::manager::SecretSummary {
+            name: "db-password".to_string(),
+            original_name: "db-password".to_string(),
+            note: None,
+            folder: None,
+            groups: None,
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            content_type: String::new(),
+            tags: std::collections::HashMap::new(),
+        };
+
+        mgr.set(&key, &vec![summary]);
+        let index = mgr
+            .search_index(&key)
+            .expect("index written with the entry");
+        assert_eq!(index.secrets.len(), 1);
+        assert_eq!(index.complete("pass"), vec!["password"]);
+
+        mgr.invalidate(&key);
+        assert!(mgr.search_index(&key).is_none());
+        assert!(!mgr.search_index_path(&key).unwrap().exists());
+    }
+
     #[test]
     fn test_invalidate_vault_removes_all_entries() {
         let dir = tempdir().unwrap();
@@ -2,10 +2,14 @@
 //!
 //! Caches responses from `xv ls`, `xv vault list`, and `xv file list`
 //! as flat JSON files organized by vault. Supports configurable TTL,
-//! background refresh, and eager invalidation on writes.
+//! background refresh, and eager invalidation on writes. The break-glass
+//! offline cache (`offline`) is separate: it holds encrypted secret values.
+//! Each secrets listing also gets a search index (`index`) beside it.
 
+pub mod index;
 pub mod manager;
 pub mod models;
+pub mod offline;
 pub mod refresh;
 
 pub use manager::CacheManager;
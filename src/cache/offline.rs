@@ -0,0 +1,375 @@
+//! Break-glass offline cache behind `xv vault cache warm` and
+//! `xv get --offline`.
+//!
+//! Unlike the listing cache, this one holds secret values. Each warmed
+//! vault is one age-encrypted file under `<cache dir>/break-glass/<backend>/`,
+//! sealed with a passphrase or to the local age key, and carries its own
+//! expiry: an expired bundle is refused, never served. A plaintext sidecar
+//! beside it holds only what `xv vault cache status` shows. Every warm,
+//! offline read and refusal is appended to `break-glass/audit.jsonl`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+use crate::cache::models::validate_cache_vault_name;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::SecretProperties;
+use crate::utils::audit_log;
+pub use crate::utils::audit_log::{AuditEntry, AuditEvent};
+
+/// Directory under the cache root holding bundles and the audit log.
+pub const BREAK_GLASS_DIR: &str = "break-glass";
+/// Append-only JSON-lines audit log, one entry per access.
+pub const AUDIT_FILE: &str = "audit.jsonl";
+/// Environment variable read for the bundle passphrase before prompting.
+pub const PASSPHRASE_ENV: &str = "XV_BREAK_GLASS_PASSPHRASE";
+
+/// How a bundle is sealed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Protection {
+    /// age scrypt passphrase, from `XV_BREAK_GLASS_PASSPHRASE` or a prompt
+    Passphrase,
+    /// The local backend's age key (`~/.xv/key.txt` by default)
+    Key,
+}
+
+impl std::fmt::Display for Protection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Protection::Passphrase => "passphrase",
+            Protection::Key => "key",
+        })
+    }
+}
+
+/// Key material for sealing a bundle.
+pub enum Sealer {
+    Passphrase(Zeroizing<String>),
+    Recipients(Vec<age::x25519::Recipient>),
+}
+
+/// Key material for opening a bundle, chosen by [`open_bundle`] from the
+/// bundle's own header.
+pub enum Credential {
+    Passphrase(Zeroizing<String>),
+    Identity(age::x25519::Identity),
+}
+
+/// The encrypted payload: full secret properties, values included, so an
+/// offline `get` behaves like an online one (records, `--field`, ...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineBundle {
+    pub backend: String,
+    pub vault: String,
+    pub group: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub secrets: Vec<SecretProperties>,
+}
+
+impl OfflineBundle {
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Look a secret up by its sanitized or original name.
+    pub fn find(&self, name: &str) -> Option<&SecretProperties> {
+        self.secrets
+            .iter()
+            .find(|s| s.name == name || s.original_name == name)
+    }
+
+    pub fn summary(&self, protection: Protection) -> BundleSummary {
+        BundleSummary {
+            backend: self.backend.clone(),
+            vault: self.vault.clone(),
+            group: self.group.clone(),
+            secret_count: self.secrets.len(),
+            protection,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+/// Non-secret description of a bundle, stored in plaintext beside it. The
+/// expiry here is informational; the one inside the bundle is enforced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleSummary {
+    pub backend: String,
+    pub vault: String,
+    pub group: String,
+    pub secret_count: usize,
+    pub protection: Protection,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// `<cache root>/break-glass/<backend>/<vault>.age`. Rejects names that
+/// would escape the directory.
+pub fn bundle_path(cache_root: &Path, backend: &str, vault: &str) -> Result<PathBuf> {
+    for name in [backend, vault] {
+        validate_cache_vault_name(name).map_err(|e| {
+            CrosstacheError::invalid_argument(format!("Cannot cache '{name}' offline: {e}"))
+        })?;
+    }
+    Ok(cache_root
+        .join(BREAK_GLASS_DIR)
+        .join(backend)
+        .join(format!("{vault}.age")))
+}
+
+/// The plaintext sidecar for the bundle at `bundle`.
+pub fn summary_path(bundle: &Path) -> PathBuf {
+    bundle.with_extension("json")
+}
+
+/// Serialize and encrypt `bundle`.
+pub fn seal_bundle(bundle: &OfflineBundle, sealer: &Sealer) -> Result<Vec<u8>> {
+    let plaintext = Zeroizing::new(serde_json::to_vec(bundle).map_err(|e| {
+        CrosstacheError::serialization(format!("Failed to serialize offline cache: {e}"))
+    })?);
+    match sealer {
+        Sealer::Recipients(recipients) => {
+            crate::backend::local::crypto::encrypt_bytes(&plaintext, recipients)
+                .map_err(CrosstacheError::from)
+        }
+        Sealer::Passphrase(passphrase) => {
+            let encryptor = age::Encryptor::with_user_passphrase(age::secrecy::Secret::new(
+                passphrase.as_str().to_owned(),
+            ));
+            let mut out = Vec::new();
+            let mut writer = encryptor.wrap_output(&mut out).map_err(|e| {
+                CrosstacheError::unknown(format!("Failed to encrypt offline cache: {e}"))
+            })?;
+            writer.write_all(&plaintext)?;
+            writer.finish()?;
+            Ok(out)
+        }
+    }
+}
+
+/// Decrypt and parse a bundle. `unlock` is asked for the credential that
+/// matches the bundle's protection, so a key-sealed bundle never prompts
+/// for a passphrase.
+pub fn open_bundle(
+    data: &[u8],
+    unlock: impl FnOnce(Protection) -> Result<Credential>,
+) -> Result<OfflineBundle> {
+    let decrypt_failed = |e: age::DecryptError| {
+        CrosstacheError::authentication(format!("Cannot open offline cache: {e}"))
+    };
+    let mut reader: Box<dyn Read + '_> = match age::Decryptor::new_buffered(data)
+        .map_err(|e| CrosstacheError::unknown(format!("Offline cache is not an age file: {e}")))?
+    {
+        age::Decryptor::Recipients(d) => match unlock(Protection::Key)? {
+            Credential::Identity(identity) => Box::new(
+                d.decrypt(std::iter::once(&identity as &dyn age::Identity))
+                    .map_err(decrypt_failed)?,
+            ),
+            Credential::Passphrase(_) => {
+                return Err(CrosstacheError::invalid_argument(
+                    "Offline cache is sealed to an age key, not a passphrase",
+                ))
+            }
+        },
+        age::Decryptor::Passphrase(d) => match unlock(Protection::Passphrase)? {
+            Credential::Passphrase(passphrase) => Box::new(
+                d.decrypt(
+                    &age::secrecy::Secret::new(passphrase.as_str().to_owned()),
+                    None,
+                )
+                .map_err(decrypt_failed)?,
+            ),
+            Credential::Identity(_) => {
+                return Err(CrosstacheError::invalid_argument(
+                    "Offline cache is sealed with a passphrase, not an age key",
+                ))
+            }
+        },
+    };
+    let mut plaintext = Zeroizing::new(Vec::new());
+    reader.read_to_end(&mut plaintext)?;
+    serde_json::from_slice(&plaintext)
+        .map_err(|e| CrosstacheError::serialization(format!("Offline cache is corrupt: {e}")))
+}
+
+/// Append one entry to `<cache root>/break-glass/audit.jsonl` (owner-only
+/// on Unix). Callers treat a failure as fatal: an access that cannot be
+/// audited does not happen.
+pub fn append_audit(cache_root: &Path, entry: &AuditEntry) -> Result<()> {
+    audit_log::append(&cache_root.join(BREAK_GLASS_DIR).join(AUDIT_FILE), entry)
+}
+
+/// Every bundle summary under the cache root, sorted by backend then vault.
+pub fn list_summaries(cache_root: &Path) -> Vec<BundleSummary> {
+    let mut summaries = Vec::new();
+    let Ok(backends) = std::fs::read_dir(cache_root.join(BREAK_GLASS_DIR)) else {
+        return summaries;
+    };
+    for backend in backends.flatten() {
+        let Ok(files) = std::fs::read_dir(backend.path()) else {
+            continue;
+        };
+        for file in files.flatten() {
+            let path = file.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Some(summary) = std::fs::read(&path)
+                .ok()
+                .and_then(|data| serde_json::from_slice::<BundleSummary>(&data).ok())
+            {
+                summaries.push(summary);
+            }
+        }
+    }
+    summaries.sort_by(|a, b| (&a.backend, &a.vault).cmp(&(&b.backend, &b.vault)));
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn secret(name: &str, value: &str) -> SecretProperties {
+        SecretProperties {
+            name: name.to_string(),
+            original_name: name.replace('-', "_"),
+            value: Some(Zeroizing::new(value.to_string())),
+            version: "v1".to_string(),
+            id: None,
+            version_number: None,
+            created_timestamp: 0,
+            created_on: String::new(),
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            not_before: None,
+            tags: HashMap::new(),
+            content_type: String::new(),
+            recovery_level: None,
+        }
+    }
+
+    fn bundle(expires_at: DateTime<Utc>) -> OfflineBundle {
+        OfflineBundle {
+            backend: "azure".to_string(),
+            vault: "kv-prod".to_string(),
+            group: "break-glass".to_string(),
+            created_at: Utc::now(),
+            expires_at,
+            secrets: vec![secret("db-password", "hunter2")],
+        }
+    }
+
+    #[test]
+    fn passphrase_sealed_bundle_round_trips() {
+        let sealed = seal_bundle(
+            &bundle(Utc::now() + chrono::Duration::hours(1)),
+            &Sealer::Passphrase(Zeroizing::new("correct horse".to_string())),
+        )
+        .unwrap();
+        let opened = open_bundle(&sealed, |protection| {
+            assert_eq!(protection, Protection::Passphrase);
+            Ok(Credential::Passphrase(Zeroizing::new(
+                "correct horse".to_string(),
+            )))
+        })
+        .unwrap();
+        let found = opened.find("db_password").unwrap();
+        assert_eq!(found.value.as_deref().map(|v| v.as_str()), Some("hunter2"));
+    }
+
+    #[test]
+    fn key_sealed_bundle_round_trips_and_rejects_a_passphrase() {
+        let identity = age::x25519::Identity::generate();
+        let sealed = seal_bundle(
+            &bundle(Utc::now() + chrono::Duration::hours(1)),
+            &Sealer::Recipients(vec![identity.to_public()]),
+        )
+        .unwrap();
+        assert!(
+            open_bundle(&sealed, |_| Ok(Credential::Passphrase(Zeroizing::new(
+                "guess".to_string()
+            ))))
+            .is_err()
+        );
+        let opened = open_bundle(&sealed, |_| Ok(Credential::Identity(identity))).unwrap();
+        assert!(opened.find("db-password").is_some());
+    }
+
+    #[test]
+    fn wrong_passphrase_is_an_authentication_error() {
+        let sealed = seal_bundle(
+            &bundle(Utc::now() + chrono::Duration::hours(1)),
+            &Sealer::Passphrase(Zeroizing::new("right".to_string())),
+        )
+        .unwrap();
+        let err = open_bundle(&sealed, |_| {
+            Ok(Credential::Passphrase(Zeroizing::new("wrong".to_string())))
+        })
+        .unwrap_err();
+        assert!(matches!(err, CrosstacheError::AuthenticationError(_)));
+    }
+
+    #[test]
+    fn bundle_expires_at_its_deadline() {
+        let deadline = Utc::now();
+        let b = bundle(deadline);
+        assert!(!b.is_expired_at(deadline - chrono::Duration::seconds(1)));
+        assert!(b.is_expired_at(deadline));
+    }
+
+    #[test]
+    fn bundle_path_rejects_traversal() {
+        let root = Path::new("/cache");
+        assert_eq!(
+            bundle_path(root, "azure", "kv-prod").unwrap(),
+            PathBuf::from("/cache/break-glass/azure/kv-prod.age")
+        );
+        assert!(bundle_path(root, "azure", "../etc").is_err());
+    }
+
+    #[test]
+    fn audit_log_appends_json_lines_and_summaries_list() {
+        let dir = tempfile::tempdir().unwrap();
+        append_audit(
+            dir.path(),
+            &AuditEntry::new(AuditEvent::Warm, "azure", "kv-prod").detail("1 secret"),
+        )
+        .unwrap();
+        append_audit(
+            dir.path(),
+            &AuditEntry::new(AuditEvent::OfflineRead, "azure", "kv-prod").secret("db-password"),
+        )
+        .unwrap();
+        let log =
+            std::fs::read_to_string(dir.path().join(BREAK_GLASS_DIR).join(AUDIT_FILE)).unwrap();
+        let entries: Vec<AuditEntry> = log
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].event, AuditEvent::OfflineRead);
+        assert_eq!(entries[1].secret.as_deref(), Some("db-password"));
+        assert!(log.contains("\"offline-read\""));
+
+        let b = bundle(Utc::now() + chrono::Duration::hours(1));
+        let path = bundle_path(dir.path(), "azure", "kv-prod").unwrap();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(
+            summary_path(&path),
+            serde_json::to_vec(&b.summary(Protection::Key)).unwrap(),
+        )
+        .unwrap();
+        let summaries = list_summaries(dir.path());
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].secret_count, 1);
+    }
+}
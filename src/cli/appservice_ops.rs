@@ -0,0 +1,282 @@
+//! CLI executor for `xv appservice check`.
+//!
+//! Lists an App Service or Function app's settings that hold Key Vault
+//! references and checks each one the way the platform resolves it: the
+//! vault exists, the secret (or pinned version) exists and is usable, and
+//! the app's reference identity may read secrets from the vault.
+
+use crate::backend::{BackendKind, BackendRegistry};
+use crate::cli::commands::AppserviceCommands;
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+use crate::vault::appservice::{
+    access_policy_grants_get, parse_key_vault_reference, reference_identity, AppServiceClient,
+    KeyVaultReference, ReferenceCheck, ReferenceIdentity, ReferenceStatus,
+};
+use crate::vault::VaultPermission;
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub(crate) async fn execute_appservice_command(
+    command: AppserviceCommands,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        AppserviceCommands::Check {
+            app,
+            resource_group,
+            slot,
+        } => {
+            execute_appservice_check(&app, resource_group, slot.as_deref(), config, registry).await
+        }
+    }
+}
+
+/// Fails with `ReferenceCheckFailed` (exit 52) when any reference is broken.
+async fn execute_appservice_check(
+    app: &str,
+    resource_group: Option<String>,
+    slot: Option<&str>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+    if backend.kind() != BackendKind::Azure {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "`xv appservice check` resolves Azure Key Vault references; the {} backend has none.",
+            backend.name()
+        )));
+    }
+    let resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
+    let auth_provider = BackendRegistry::create_azure_auth_provider(config)
+        .map_err(|e| CrosstacheError::authentication(e.to_string()))?;
+    let client = AppServiceClient::new(
+        auth_provider,
+        &config.subscription_id,
+        &resource_group,
+        app,
+        slot,
+    )?;
+    let app_label = match slot {
+        Some(slot) => format!("{app}/{slot}"),
+        None => app.to_string(),
+    };
+
+    let identity = reference_identity(&client.site().await?);
+    let references: Vec<(String, std::result::Result<KeyVaultReference, String>)> = client
+        .settings()
+        .await?
+        .into_iter()
+        .filter_map(|(name, value)| parse_key_vault_reference(&value).map(|r| (name, r)))
+        .collect();
+    if references.is_empty() {
+        output::info(&format!(
+            "No Key Vault references in the settings of app '{app_label}'"
+        ));
+        return Ok(());
+    }
+
+    let vaults = client.vaults().await?;
+    // Identity access is per vault, not per reference.
+    let mut access_by_vault: HashMap<String, (ReferenceStatus, String)> = HashMap::new();
+    let mut rows = Vec::with_capacity(references.len());
+    for (setting, reference) in references {
+        let reference = match reference {
+            Ok(reference) => reference,
+            Err(reason) => {
+                rows.push(ReferenceCheck {
+                    setting,
+                    vault: "-".to_string(),
+                    secret: "-".to_string(),
+                    status: ReferenceStatus::Malformed,
+                    detail: reason,
+                });
+                continue;
+            }
+        };
+        let Some(vault) = vaults.iter().find(|v| {
+            v.get("name")
+                .and_then(Value::as_str)
+                .is_some_and(|n| n.eq_ignore_ascii_case(&reference.vault))
+        }) else {
+            rows.push(ReferenceCheck {
+                setting,
+                vault: reference.vault,
+                secret: reference.secret,
+                status: ReferenceStatus::VaultNotFound,
+                detail: format!(
+                    "no vault by that name in subscription {}",
+                    config.subscription_id
+                ),
+            });
+            continue;
+        };
+
+        let (secret_status, secret_detail) = check_secret(backend.secrets(), &reference).await;
+        let (status, detail) = if secret_status.is_broken() {
+            (secret_status, secret_detail)
+        } else {
+            let key = reference.vault.to_ascii_lowercase();
+            if !access_by_vault.contains_key(&key) {
+                let access = check_identity_access(&client, vault, &identity).await;
+                access_by_vault.insert(key.clone(), access);
+            }
+            let (access_status, access_detail) = access_by_vault[&key].clone();
+            match (access_status, secret_status) {
+                (ReferenceStatus::Ok, ReferenceStatus::Ok) => (ReferenceStatus::Ok, String::new()),
+                (ReferenceStatus::Ok, _) => (secret_status, secret_detail),
+                _ => (access_status, access_detail),
+            }
+        };
+        rows.push(ReferenceCheck {
+            setting,
+            vault: reference.vault,
+            secret: match reference.version {
+                Some(version) => format!("{}/{version}", reference.secret),
+                None => reference.secret,
+            },
+            status,
+            detail,
+        });
+    }
+
+    let broken = rows.iter().filter(|r| r.status.is_broken()).count();
+    let unverified = rows
+        .iter()
+        .filter(|r| r.status == ReferenceStatus::Unverified)
+        .count();
+    let formatter = crate::utils::format::TableFormatter::new(
+        config.runtime_output_format,
+        config.no_color,
+        config.template.clone(),
+        config.runtime_columns.clone(),
+    );
+    println!("{}", formatter.format_table(&rows)?);
+    if matches!(
+        config.runtime_output_format,
+        OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
+    ) {
+        if let ReferenceIdentity::Principal { label, .. } = &identity {
+            output::hint(&format!("References resolve as the {label} identity."));
+        }
+        let summary = format!(
+            "{} reference(s) in app '{app_label}': {} ok, {broken} broken, {unverified} unverified",
+            rows.len(),
+            rows.len() - broken - unverified
+        );
+        if broken > 0 {
+            output::warn(&summary);
+        } else {
+            output::success(&summary);
+        }
+    }
+    if broken > 0 {
+        return Err(CrosstacheError::reference_check_failed(broken, app_label));
+    }
+    Ok(())
+}
+
+/// Whether the referenced secret (or pinned version) can be served. Read
+/// with the caller's credentials, so a caller who may not read the vault
+/// gets `Unverified` rather than a false alarm.
+async fn check_secret(
+    secrets: &dyn crate::backend::SecretBackend,
+    reference: &KeyVaultReference,
+) -> (ReferenceStatus, String) {
+    let fetched = match &reference.version {
+        Some(version) => {
+            secrets
+                .get_secret_version(&reference.vault, &reference.secret, version, false)
+                .await
+        }
+        None => {
+            secrets
+                .get_secret(&reference.vault, &reference.secret, false)
+                .await
+        }
+    };
+    match fetched {
+        Ok(secret) if !secret.enabled => (
+            ReferenceStatus::SecretDisabled,
+            "re-enable it or point the setting at another version".to_string(),
+        ),
+        Ok(secret) if secret.expires_on.is_some_and(|at| at <= chrono::Utc::now()) => (
+            ReferenceStatus::SecretExpired,
+            format!(
+                "expired {}",
+                secret
+                    .expires_on
+                    .map(|at| at.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default()
+            ),
+        ),
+        Ok(_) => (ReferenceStatus::Ok, String::new()),
+        Err(crate::backend::BackendError::NotFound { .. }) => (
+            ReferenceStatus::SecretNotFound,
+            match &reference.version {
+                Some(version) => format!("version {version} does not exist"),
+                None => "renamed or deleted?".to_string(),
+            },
+        ),
+        Err(e) => (
+            ReferenceStatus::Unverified,
+            format!("could not read the secret: {e}"),
+        ),
+    }
+}
+
+/// Whether the app's reference identity may read secrets from `vault`,
+/// through RBAC or access policies depending on the vault's mode.
+async fn check_identity_access(
+    client: &AppServiceClient,
+    vault: &Value,
+    identity: &ReferenceIdentity,
+) -> (ReferenceStatus, String) {
+    let (principal_id, label) = match identity {
+        ReferenceIdentity::Principal {
+            principal_id,
+            label,
+        } => (principal_id, label),
+        ReferenceIdentity::Missing(reason) => return (ReferenceStatus::NoIdentity, reason.clone()),
+    };
+    let uses_rbac = vault
+        .pointer("/properties/enableRbacAuthorization")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if !uses_rbac {
+        return if access_policy_grants_get(vault, principal_id) {
+            (ReferenceStatus::Ok, String::new())
+        } else {
+            (
+                ReferenceStatus::AccessDenied,
+                format!("no access policy gives the {label} identity secret Get"),
+            )
+        };
+    }
+
+    let scope = vault.get("id").and_then(Value::as_str).unwrap_or_default();
+    match client.principal_grants(scope, principal_id).await {
+        Ok(grants)
+            if grants
+                .iter()
+                .any(|g| g.permits(VaultPermission::Read.azure_data_action())) =>
+        {
+            (ReferenceStatus::Ok, String::new())
+        }
+        Ok(_) => (
+            ReferenceStatus::AccessDenied,
+            format!(
+                "no role assigned to the {label} identity grants getSecret; assign '{}' \
+                 (roles granted through groups are not checked)",
+                VaultPermission::Read.azure_role()
+            ),
+        ),
+        Err(e) => (
+            ReferenceStatus::Unverified,
+            format!("could not list the identity's role assignments: {e}"),
+        ),
+    }
+}
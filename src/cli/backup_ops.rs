@@ -0,0 +1,797 @@
+//! Scheduled backup command handlers (`xv backup ...`).
+//!
+//! `xv backup vault` is `xv vault export --fmt json` packaged for cron: each
+//! run writes a new `<vault>-<UTC timestamp>.json` (or `.json.age`) into a
+//! directory, optionally prunes that vault's older archives, and reports a
+//! partial backup through its exit code instead of a log line nobody reads.
+//! `xv backup restore` is the way back: it decrypts an archive, checks its
+//! manifest, and writes the secrets into a vault with a conflict strategy.
+
+use crate::backend::BackendRegistry;
+use crate::cli::commands::{BackupCommands, OnConflict};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::utils::output;
+use crate::utils::report::{ItemStatus, ReportRecorder};
+use crate::vault::VaultPermission;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use zeroize::Zeroizing;
+
+/// UTC timestamp embedded in archive names. Sorts lexically by time.
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// `manifest.format` of every archive `xv backup vault` writes.
+const BACKUP_FORMAT: &str = "xv-backup";
+
+/// Newest archive layout this build can restore.
+const BACKUP_VERSION: u32 = 1;
+
+/// Describes an archive so a restore can tell it is complete and readable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupManifest {
+    format: String,
+    version: u32,
+    vault: String,
+    created_at: DateTime<Utc>,
+    secret_count: usize,
+    include_values: bool,
+}
+
+/// An archive as read back by `xv backup restore`: the `xv vault export`
+/// JSON plus its manifest.
+#[derive(Debug, Deserialize)]
+struct BackupArchive {
+    manifest: Option<BackupManifest>,
+    #[serde(default)]
+    secrets: Vec<ArchivedSecret>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArchivedSecret {
+    name: String,
+    #[serde(default)]
+    enabled: Option<bool>,
+    #[serde(default)]
+    content_type: String,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+    #[serde(default)]
+    value: Option<Zeroizing<String>>,
+    #[serde(default)]
+    expires_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    not_before: Option<DateTime<Utc>>,
+}
+
+/// `--only` selector for a restore.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RestoreFilter {
+    /// `group:<name>`
+    Group(String),
+    /// `folder:<path>` — the folder and everything under it
+    Folder(String),
+    /// Anything else: a glob on the secret name
+    Name(String),
+}
+
+impl RestoreFilter {
+    fn parse(raw: &str) -> Result<Self> {
+        let filter = if let Some(group) = raw.strip_prefix("group:") {
+            Self::Group(group.to_string())
+        } else if let Some(folder) = raw.strip_prefix("folder:") {
+            Self::Folder(folder.trim_end_matches('/').to_string())
+        } else {
+            crate::utils::helpers::compile_name_glob(raw)?;
+            Self::Name(raw.to_string())
+        };
+        match &filter {
+            Self::Group(v) | Self::Folder(v) if v.is_empty() => {
+                Err(CrosstacheError::invalid_argument(format!(
+                    "--only '{raw}' needs a value after the prefix"
+                )))
+            }
+            _ => Ok(filter),
+        }
+    }
+
+    fn matches(&self, secret: &ArchivedSecret) -> Result<bool> {
+        use crate::secret::models::SecretInfo;
+        Ok(match self {
+            Self::Group(group) => SecretInfo::extract_groups(&secret.tags).contains(group),
+            Self::Folder(path) => SecretInfo::extract_folder(&secret.tags)
+                .is_some_and(|f| crate::cli::ls_view::folder_in_scope(&f, path)),
+            Self::Name(pattern) => {
+                crate::utils::helpers::compile_name_glob(pattern)?.is_match(&secret.name)
+            }
+        })
+    }
+}
+
+pub(crate) async fn execute_backup_command(
+    command: BackupCommands,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        BackupCommands::Vault {
+            name,
+            output_dir,
+            encrypt,
+            recipients,
+            include_values,
+            keep,
+            group,
+        } => {
+            let recipients = if encrypt {
+                Some(resolve_recipients(&recipients, config)?)
+            } else {
+                None
+            };
+            execute_backup_vault(
+                &name,
+                &output_dir,
+                recipients,
+                include_values,
+                keep.map(|k| k as usize),
+                group,
+                config,
+                registry,
+            )
+            .await
+        }
+        BackupCommands::Restore {
+            archive,
+            to,
+            only,
+            on_conflict,
+            dry_run,
+            identity,
+            report,
+        } => {
+            let only = only.as_deref().map(RestoreFilter::parse).transpose()?;
+            let mut recorder = ReportRecorder::new("backup restore")
+                .with_endpoints(Some(archive.display().to_string()), to.clone());
+            let result = execute_backup_restore(
+                &archive,
+                to,
+                only,
+                on_conflict,
+                dry_run,
+                identity.as_deref(),
+                config,
+                registry,
+                &mut recorder,
+            )
+            .await;
+            match report {
+                Some(path) => recorder.write_for(Path::new(&path), result),
+                None => result,
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn execute_backup_vault(
+    name: &str,
+    output_dir: &Path,
+    recipients: Option<Vec<age::x25519::Recipient>>,
+    include_values: bool,
+    keep: Option<usize>,
+    group: Option<String>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+    if include_values {
+        crate::cli::helpers::preflight_permissions(
+            config,
+            &*backend,
+            name,
+            None,
+            &[VaultPermission::Read],
+            "back up secret values",
+        )
+        .await?;
+        if recipients.is_none() {
+            output::warn(
+                "Backing up secret values without --encrypt: the archive holds them in \
+                 plaintext (owner-only file permissions)",
+            );
+        }
+    }
+
+    std::fs::create_dir_all(output_dir).map_err(|e| {
+        CrosstacheError::unknown(format!(
+            "Failed to create backup directory '{}': {e}",
+            output_dir.display()
+        ))
+    })?;
+    let archive = output_dir.join(archive_file_name(name, Utc::now(), recipients.is_some()));
+
+    let secrets = backend
+        .secrets()
+        .list_secrets(name, group.as_deref())
+        .await
+        .map_err(CrosstacheError::from)?;
+    let mut recorder = ReportRecorder::new("backup vault")
+        .with_endpoints(Some(name.to_string()), Some(archive.display().to_string()));
+    let data = crate::cli::vault_ops::render_vault_export(
+        backend.secrets(),
+        name,
+        &secrets,
+        "json",
+        include_values,
+        config.fetch_concurrency,
+        &mut recorder,
+    )
+    .await?;
+    let data = with_manifest(
+        &data,
+        BackupManifest {
+            format: BACKUP_FORMAT.to_string(),
+            version: BACKUP_VERSION,
+            vault: name.to_string(),
+            created_at: Utc::now(),
+            secret_count: secrets.len(),
+            include_values,
+        },
+    )?;
+    // Plaintext archives hold secret values; wipe the copy on drop too.
+    let bytes: Zeroizing<Vec<u8>> = match &recipients {
+        Some(recipients) => Zeroizing::new(
+            crate::backend::local::crypto::encrypt_bytes(data.as_bytes(), recipients)
+                .map_err(CrosstacheError::from)?,
+        ),
+        None => Zeroizing::new(data.as_bytes().to_vec()),
+    };
+    crate::utils::helpers::write_sensitive_file(&archive, &bytes).map_err(|e| {
+        CrosstacheError::unknown(format!(
+            "Failed to write backup '{}': {e}",
+            archive.display()
+        ))
+    })?;
+    crate::cli::vault_ops::print_bulk_summary(&recorder, "backed up", config);
+
+    let failed = recorder.finish(None).summary.failed;
+    if failed > 0 {
+        // A partial archive must not rotate out the last complete one.
+        return Err(CrosstacheError::backup_incomplete(
+            failed,
+            archive.display().to_string(),
+        ));
+    }
+    output::success(&format!(
+        "Backed up {} secret(s) from '{name}' to {}",
+        secrets.len(),
+        archive.display()
+    ));
+
+    if let Some(keep) = keep {
+        let existing = std::fs::read_dir(output_dir)
+            .map_err(|e| {
+                CrosstacheError::unknown(format!(
+                    "Failed to read backup directory '{}': {e}",
+                    output_dir.display()
+                ))
+            })?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        let prune = backups_to_prune(&existing, name, keep);
+        for file_name in &prune {
+            let path = output_dir.join(file_name);
+            std::fs::remove_file(&path).map_err(|e| {
+                CrosstacheError::unknown(format!(
+                    "Failed to prune old backup '{}': {e}",
+                    path.display()
+                ))
+            })?;
+        }
+        if !prune.is_empty() {
+            output::info(&format!(
+                "Pruned {} old backup(s) of '{name}' (keeping {keep})",
+                prune.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Add `manifest` to the export JSON `data`.
+fn with_manifest(data: &str, manifest: BackupManifest) -> Result<Zeroizing<String>> {
+    let mut archive: serde_json::Value = serde_json::from_str(data)
+        .map_err(|e| CrosstacheError::serialization(format!("Failed to parse export: {e}")))?;
+    let manifest = serde_json::to_value(manifest)
+        .map_err(|e| CrosstacheError::serialization(format!("Failed to build manifest: {e}")))?;
+    if let serde_json::Value::Object(ref mut map) = archive {
+        map.insert("manifest".to_string(), manifest);
+    }
+    serde_json::to_string_pretty(&archive)
+        .map(Zeroizing::new)
+        .map_err(|e| CrosstacheError::serialization(format!("Failed to serialize backup: {e}")))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn execute_backup_restore(
+    archive_path: &Path,
+    to: Option<String>,
+    only: Option<RestoreFilter>,
+    on_conflict: OnConflict,
+    dry_run: bool,
+    identity: Option<&Path>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+    recorder: &mut ReportRecorder,
+) -> Result<()> {
+    use crate::secret::manager::SecretRequest;
+    use crate::secret::models::SecretInfo;
+
+    let (manifest, secrets) = read_archive(archive_path, identity, config)?;
+    let vault = to.unwrap_or_else(|| manifest.vault.clone());
+    output::info(&format!(
+        "Backup of '{}' taken {} ({} secret(s){})",
+        manifest.vault,
+        manifest.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        manifest.secret_count,
+        if manifest.include_values {
+            ""
+        } else {
+            ", names only"
+        }
+    ));
+
+    let mut selected = Vec::new();
+    for secret in secrets {
+        if match &only {
+            Some(filter) => filter.matches(&secret)?,
+            None => true,
+        } {
+            selected.push(secret);
+        }
+    }
+
+    let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+    if !dry_run {
+        crate::cli::helpers::preflight_permissions(
+            config,
+            &*backend,
+            &vault,
+            None,
+            &[VaultPermission::Write],
+            "restore secrets",
+        )
+        .await?;
+    }
+    let secrets_backend = backend.secrets();
+
+    // Resolve every conflict before the first write, so `--on-conflict fail`
+    // leaves the vault untouched and the dry run reports exactly what a
+    // real run would do.
+    let mut existing = HashSet::new();
+    for secret in &selected {
+        // A failed check (403, throttling, network) must not read as
+        // "missing", or the restore would overwrite a live secret.
+        if secrets_backend.secret_exists(&vault, &secret.name).await? {
+            existing.insert(secret.name.clone());
+        }
+    }
+    if on_conflict == OnConflict::Fail && !existing.is_empty() {
+        let mut names: Vec<&String> = existing.iter().collect();
+        names.sort();
+        return Err(CrosstacheError::conflict(format!(
+            "{} secret(s) in the backup already exist in vault '{vault}': {}. \
+             Use --on-conflict skip or replace.",
+            names.len(),
+            names
+                .iter()
+                .map(|n| n.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    let mut restored = 0usize;
+    let mut failed = 0usize;
+    for secret in selected {
+        let started = Instant::now();
+        let exists = existing.contains(&secret.name);
+        let skip_reason = if secret.name == crate::secret::attachments::ATTACHMENT_KEY_SECRET {
+            Some("reserved for attachment encryption")
+        } else if secret.value.is_none() {
+            Some("no value in backup (taken without --include-values)")
+        } else if exists && on_conflict == OnConflict::Skip {
+            Some("already exists")
+        } else {
+            None
+        };
+        if let Some(reason) = skip_reason {
+            output::hint(&format!("  skip  {} ({reason})", secret.name));
+            recorder.record(
+                &secret.name,
+                ItemStatus::Skipped,
+                started.elapsed(),
+                Some(reason.to_string()),
+            );
+            continue;
+        }
+        let action = if exists { "replace" } else { "create" };
+        if dry_run {
+            output::hint(&format!("  {action:<6}{}", secret.name));
+            recorder.record(
+                &secret.name,
+                ItemStatus::Skipped,
+                Duration::ZERO,
+                Some(format!("dry run: would {action}")),
+            );
+            continue;
+        }
+
+        let groups = SecretInfo::extract_groups(&secret.tags);
+        let request = SecretRequest {
+            name: secret.name.clone(),
+            value: secret.value.unwrap_or_default(),
+            content_type: (!secret.content_type.is_empty()).then_some(secret.content_type),
+            enabled: secret.enabled,
+            expires_on: secret.expires_on,
+            not_before: secret.not_before,
+            groups: (!groups.is_empty()).then_some(groups),
+            note: SecretInfo::extract_note(&secret.tags),
+            folder: SecretInfo::extract_folder(&secret.tags),
+            tags: Some(
+                secret
+                    .tags
+                    .into_iter()
+                    .filter(|(k, _)| {
+                        !crate::cli::helpers::RESERVED_SECRET_TAGS.contains(&k.as_str())
+                    })
+                    .collect(),
+            ),
+        };
+        match secrets_backend.set_secret(&vault, request).await {
+            Ok(_) => {
+                output::success(&format!("Restored '{}' ({action})", secret.name));
+                recorder.record(&secret.name, ItemStatus::Succeeded, started.elapsed(), None);
+                restored += 1;
+            }
+            Err(e) => {
+                output::error(&format!("Failed to restore '{}': {e}", secret.name));
+                recorder.record(
+                    &secret.name,
+                    ItemStatus::Failed,
+                    started.elapsed(),
+                    Some(e.to_string()),
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    if restored > 0 {
+        crate::cache::CacheManager::from_config(config).invalidate(
+            &crate::cache::CacheKey::SecretsList {
+                backend: config.effective_backend_name().to_string(),
+                vault_name: vault.clone(),
+            },
+        );
+    }
+    crate::cli::vault_ops::print_bulk_summary(
+        recorder,
+        if dry_run { "would restore" } else { "restored" },
+        config,
+    );
+    if failed > 0 {
+        return Err(CrosstacheError::unknown(format!(
+            "backup restore: {failed} secret(s) failed to restore into vault '{vault}'"
+        )));
+    }
+    Ok(())
+}
+
+/// Read, decrypt (when age-encrypted) and validate an archive.
+fn read_archive(
+    path: &Path,
+    identity: Option<&Path>,
+    config: &Config,
+) -> Result<(BackupManifest, Vec<ArchivedSecret>)> {
+    use crate::backend::local::crypto;
+
+    let bytes = std::fs::read(path).map_err(|e| {
+        CrosstacheError::unknown(format!("Failed to read backup '{}': {e}", path.display()))
+    })?;
+    let plaintext = if crypto::is_age_encrypted(&bytes) {
+        let key_file = match identity {
+            Some(path) => path.to_path_buf(),
+            None => {
+                crate::backend::local::config::ResolvedLocalConfig::from_raw(config.local.as_ref())
+                    .key_file
+            }
+        };
+        let identity = crypto::load_identity(&key_file).map_err(CrosstacheError::from)?;
+        crypto::decrypt_bytes(&bytes, &identity).map_err(|e| {
+            CrosstacheError::config(format!(
+                "Failed to decrypt backup '{}' with '{}': {e}",
+                path.display(),
+                key_file.display()
+            ))
+        })?
+    } else {
+        Zeroizing::new(bytes)
+    };
+    let archive = parse_archive(&plaintext, path)?;
+    let manifest = validate_archive(&archive)?;
+    Ok((manifest, archive.secrets))
+}
+
+/// Decode an archive. The embedded export is held to its schema first, so
+/// an archive whose `format_version` is newer than this build is refused.
+fn parse_archive(plaintext: &[u8], path: &Path) -> Result<BackupArchive> {
+    let unreadable = |e: &dyn std::fmt::Display| {
+        CrosstacheError::serialization(format!(
+            "'{}' is not a readable backup archive: {e}",
+            path.display()
+        ))
+    };
+    let text = std::str::from_utf8(plaintext).map_err(|e| unreadable(&e))?;
+    crate::vault::export_format::parse_export(text)?;
+    serde_json::from_str(text).map_err(|e| unreadable(&e))
+}
+
+/// Check the manifest against this build and the archive's own contents.
+fn validate_archive(archive: &BackupArchive) -> Result<BackupManifest> {
+    let manifest = archive.manifest.clone().ok_or_else(|| {
+        CrosstacheError::serialization(
+            "backup has no manifest; restore 'xv vault export' files with 'xv vault import'",
+        )
+    })?;
+    if manifest.format != BACKUP_FORMAT {
+        return Err(CrosstacheError::serialization(format!(
+            "unknown backup format '{}' (expected '{BACKUP_FORMAT}')",
+            manifest.format
+        )));
+    }
+    if manifest.version > BACKUP_VERSION {
+        return Err(CrosstacheError::serialization(format!(
+            "backup version {} is newer than this xv supports ({BACKUP_VERSION}); upgrade xv",
+            manifest.version
+        )));
+    }
+    if manifest.secret_count != archive.secrets.len() {
+        return Err(CrosstacheError::serialization(format!(
+            "backup is truncated or altered: manifest lists {} secret(s), archive holds {}",
+            manifest.secret_count,
+            archive.secrets.len()
+        )));
+    }
+    let mut seen = HashSet::new();
+    for secret in &archive.secrets {
+        if secret.name.is_empty() || !seen.insert(secret.name.as_str()) {
+            return Err(CrosstacheError::serialization(format!(
+                "backup has an empty or duplicate secret name '{}'",
+                secret.name
+            )));
+        }
+    }
+    Ok(manifest)
+}
+
+/// age recipients for `--encrypt`: the `--recipient` values when given,
+/// else the local backend's recipients file, else its key's public key.
+fn resolve_recipients(explicit: &[String], config: &Config) -> Result<Vec<age::x25519::Recipient>> {
+    use crate::backend::local::config::ResolvedLocalConfig;
+    use crate::backend::local::crypto;
+
+    if !explicit.is_empty() {
+        return explicit
+            .iter()
+            .map(|r| {
+                r.trim().parse::<age::x25519::Recipient>().map_err(|e| {
+                    CrosstacheError::invalid_argument(format!("Invalid age recipient '{r}': {e}"))
+                })
+            })
+            .collect();
+    }
+    let local = ResolvedLocalConfig::from_raw(config.local.as_ref());
+    if local.recipients_file.exists() {
+        return crypto::load_recipients(&local.recipients_file).map_err(CrosstacheError::from);
+    }
+    if local.key_file.exists() {
+        return crypto::load_identity(&local.key_file)
+            .map(|identity| vec![identity.to_public()])
+            .map_err(CrosstacheError::from);
+    }
+    Err(CrosstacheError::config(format!(
+        "No age recipient for --encrypt: pass --recipient age1... or create a key at '{}'",
+        local.key_file.display()
+    )))
+}
+
+/// `<vault>-<timestamp>.json`, with `.age` appended when encrypted.
+fn archive_file_name(vault: &str, at: DateTime<Utc>, encrypted: bool) -> String {
+    let mut file_name = format!("{vault}-{}.json", at.format(BACKUP_TIMESTAMP_FORMAT));
+    if encrypted {
+        file_name.push_str(".age");
+    }
+    file_name
+}
+
+/// Timestamp of a `vault` archive named by [`archive_file_name`]; `None`
+/// for any other file (including another vault's archives).
+fn archive_timestamp(file_name: &str, vault: &str) -> Option<NaiveDateTime> {
+    let rest = file_name.strip_prefix(vault)?.strip_prefix('-')?;
+    let stamp = rest
+        .strip_suffix(".json.age")
+        .or_else(|| rest.strip_suffix(".json"))?;
+    NaiveDateTime::parse_from_str(stamp, BACKUP_TIMESTAMP_FORMAT).ok()
+}
+
+/// `vault` archives in `file_names` beyond the newest `keep`, oldest last.
+/// Files that aren't this vault's archives are never selected.
+fn backups_to_prune(file_names: &[String], vault: &str, keep: usize) -> Vec<String> {
+    let mut archives: Vec<(NaiveDateTime, &String)> = file_names
+        .iter()
+        .filter_map(|f| archive_timestamp(f, vault).map(|ts| (ts, f)))
+        .collect();
+    archives.sort_by(|a, b| b.cmp(a));
+    archives
+        .into_iter()
+        .skip(keep)
+        .map(|(_, f)| f.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 17, h, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn archive_names_embed_a_sortable_utc_timestamp() {
+        assert_eq!(
+            archive_file_name("kv-app", at(3), false),
+            "kv-app-20261017T033000Z.json"
+        );
+        assert_eq!(
+            archive_file_name("kv-app", at(3), true),
+            "kv-app-20261017T033000Z.json.age"
+        );
+    }
+
+    #[test]
+    fn prune_keeps_the_newest_archives_of_that_vault_only() {
+        let files: Vec<String> = vec![
+            archive_file_name("kv", at(1), true),
+            archive_file_name("kv", at(3), false),
+            archive_file_name("kv", at(2), true),
+            // Another vault sharing the prefix, and unrelated files.
+            archive_file_name("kv-prod", at(0), true),
+            "kv-notes.txt".to_string(),
+            "kv-latest.json".to_string(),
+        ];
+        assert_eq!(
+            backups_to_prune(&files, "kv", 2),
+            vec![archive_file_name("kv", at(1), true)]
+        );
+        assert!(backups_to_prune(&files, "kv", 3).is_empty());
+        assert_eq!(backups_to_prune(&files, "kv-prod", 1), Vec::<String>::new());
+    }
+
+    fn archive_json(secret_count: usize, secrets: serde_json::Value) -> BackupArchive {
+        serde_json::from_value(serde_json::json!({
+            "vault": "kv",
+            "exported_at": "2026-10-17T03:30:00Z",
+            "manifest": {
+                "format": "xv-backup",
+                "version": 1,
+                "vault": "kv",
+                "created_at": "2026-10-17T03:30:00Z",
+                "secret_count": secret_count,
+                "include_values": true,
+            },
+            "secrets": secrets,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn manifest_round_trips_through_an_export() {
+        let export = r#"{"format_version":1,"vault":"kv","secrets":[{"name":"a","value":"x"}]}"#;
+        let data = with_manifest(
+            export,
+            BackupManifest {
+                format: BACKUP_FORMAT.to_string(),
+                version: BACKUP_VERSION,
+                vault: "kv".to_string(),
+                created_at: at(3),
+                secret_count: 1,
+                include_values: true,
+            },
+        )
+        .unwrap();
+        let archive = parse_archive(data.as_bytes(), Path::new("kv.json")).unwrap();
+        let manifest = validate_archive(&archive).unwrap();
+        assert_eq!(manifest.vault, "kv");
+        assert_eq!(
+            archive.secrets[0].value.as_deref().map(|v| v.as_str()),
+            Some("x")
+        );
+    }
+
+    #[test]
+    fn archives_from_a_newer_export_format_are_refused() {
+        let data = with_manifest(
+            r#"{"format_version":2,"vault":"kv","secrets":[]}"#,
+            BackupManifest {
+                format: BACKUP_FORMAT.to_string(),
+                version: BACKUP_VERSION,
+                vault: "kv".to_string(),
+                created_at: at(3),
+                secret_count: 0,
+                include_values: false,
+            },
+        )
+        .unwrap();
+        let err = parse_archive(data.as_bytes(), Path::new("kv.json")).unwrap_err();
+        assert!(err.to_string().contains("newer than this xv"));
+    }
+
+    #[test]
+    fn validation_rejects_truncated_duplicate_and_unmanifested_archives() {
+        let truncated = archive_json(2, serde_json::json!([{ "name": "a" }]));
+        assert!(validate_archive(&truncated)
+            .unwrap_err()
+            .to_string()
+            .contains("truncated"));
+
+        let duplicate = archive_json(2, serde_json::json!([{ "name": "a" }, { "name": "a" }]));
+        assert!(validate_archive(&duplicate).is_err());
+
+        let plain_export: BackupArchive =
+            serde_json::from_str(r#"{"vault":"kv","secrets":[]}"#).unwrap();
+        assert!(validate_archive(&plain_export)
+            .unwrap_err()
+            .to_string()
+            .contains("no manifest"));
+    }
+
+    #[test]
+    fn restore_filter_selects_by_group_folder_or_name() {
+        let archive = archive_json(
+            2,
+            serde_json::json!([
+                { "name": "db-password", "tags": { "groups": "db,prod", "folder": "prod/db" } },
+                { "name": "api-key", "tags": { "folder": "prod" } },
+            ]),
+        );
+        let pick = |raw: &str| -> Vec<&str> {
+            let filter = RestoreFilter::parse(raw).unwrap();
+            archive
+                .secrets
+                .iter()
+                .filter(|s| filter.matches(s).unwrap())
+                .map(|s| s.name.as_str())
+                .collect()
+        };
+        assert_eq!(pick("group:db"), vec!["db-password"]);
+        assert_eq!(pick("folder:prod"), vec!["db-password", "api-key"]);
+        assert_eq!(pick("folder:prod/db/"), vec!["db-password"]);
+        assert_eq!(pick("api-*"), vec!["api-key"]);
+        assert!(RestoreFilter::parse("group:").is_err());
+    }
+
+    #[test]
+    fn explicit_recipients_are_validated() {
+        let config = Config::default();
+        let identity = age::x25519::Identity::generate();
+        let recipient = identity.to_public().to_string();
+        assert_eq!(resolve_recipients(&[recipient], &config).unwrap().len(), 1);
+        assert!(resolve_recipients(&["not-a-recipient".to_string()], &config).is_err());
+    }
+}
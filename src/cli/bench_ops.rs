@@ -0,0 +1,293 @@
+//! CLI executor for `xv bench`.
+//!
+//! Writes a batch of throwaway secrets to the target vault, reads them back
+//! and lists the vault, timing every call, then reports throughput and
+//! latency percentiles per operation. The secrets are deleted afterwards,
+//! and purged where the backend allows it right away.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::stream::{self, StreamExt};
+use serde::Serialize;
+use tabled::Tabled;
+use zeroize::Zeroizing;
+
+use crate::backend::{Backend, BackendRegistry};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::SecretRequest;
+use crate::utils::format::{OutputFormat, TableFormatter};
+use crate::utils::output;
+
+/// Timings of one operation across the run.
+#[derive(Debug, Clone, Serialize, Tabled)]
+pub(crate) struct PhaseStats {
+    #[tabled(rename = "Operation")]
+    pub operation: &'static str,
+    #[tabled(rename = "Calls")]
+    pub calls: usize,
+    #[tabled(rename = "Errors")]
+    pub errors: usize,
+    #[tabled(rename = "Ops/s", display_with = "display_rate")]
+    pub ops_per_sec: f64,
+    #[tabled(rename = "p50 ms", display_with = "display_ms")]
+    pub p50_ms: f64,
+    #[tabled(rename = "p90 ms", display_with = "display_ms")]
+    pub p90_ms: f64,
+    #[tabled(rename = "p99 ms", display_with = "display_ms")]
+    pub p99_ms: f64,
+    #[tabled(rename = "Max ms", display_with = "display_ms")]
+    pub max_ms: f64,
+}
+
+fn display_rate(rate: &f64) -> String {
+    format!("{rate:.1}")
+}
+
+fn display_ms(ms: &f64) -> String {
+    format!("{ms:.1}")
+}
+
+/// The whole run, as printed with `--format json|yaml`.
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    backend: &'static str,
+    vault: String,
+    secrets: usize,
+    concurrency: usize,
+    phases: Vec<PhaseStats>,
+}
+
+impl PhaseStats {
+    /// Summarise successful call latencies (`errors` failed calls aside)
+    /// over a phase that took `wall` end to end.
+    fn from_samples(
+        operation: &'static str,
+        mut samples: Vec<Duration>,
+        errors: usize,
+        wall: Duration,
+    ) -> Self {
+        samples.sort();
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        let calls = samples.len() + errors;
+        Self {
+            operation,
+            calls,
+            errors,
+            ops_per_sec: if wall.is_zero() {
+                0.0
+            } else {
+                samples.len() as f64 / wall.as_secs_f64()
+            },
+            p50_ms: ms(percentile(&samples, 50.0)),
+            p90_ms: ms(percentile(&samples, 90.0)),
+            p99_ms: ms(percentile(&samples, 99.0)),
+            max_ms: ms(samples.last().copied().unwrap_or_default()),
+        }
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted`; zero when empty.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Run `op` for every item with at most `concurrency` in flight, timing each
+/// call. Returns the successful latencies, the error count, the first error
+/// and the wall time of the phase.
+async fn timed<I, F, Fut, T>(
+    items: Vec<I>,
+    concurrency: usize,
+    op: F,
+) -> (Vec<Duration>, usize, Option<String>, Duration)
+where
+    F: Fn(I) -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, crate::backend::BackendError>>,
+{
+    let started = Instant::now();
+    let results: Vec<_> = stream::iter(items)
+        .map(|item| {
+            let call = op(item);
+            async move {
+                let began = Instant::now();
+                call.await.map(|_| began.elapsed())
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+    let wall = started.elapsed();
+
+    let mut samples = Vec::with_capacity(results.len());
+    let mut errors = 0;
+    let mut first_error = None;
+    for result in results {
+        match result {
+            Ok(latency) => samples.push(latency),
+            Err(e) => {
+                errors += 1;
+                first_error.get_or_insert_with(|| e.to_string());
+            }
+        }
+    }
+    (samples, errors, first_error, wall)
+}
+
+/// Measure set/get/list throughput and latency against the target vault.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn execute_bench_command(
+    secrets: usize,
+    concurrency: usize,
+    list_runs: usize,
+    vault: Option<String>,
+    format: OutputFormat,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    if secrets == 0 {
+        return Err(CrosstacheError::invalid_argument(
+            "--secrets must be at least 1",
+        ));
+    }
+    if concurrency == 0 {
+        return Err(CrosstacheError::invalid_argument(
+            "--concurrency must be at least 1",
+        ));
+    }
+    let backend: Arc<dyn Backend> =
+        crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+    let vault_name = match vault {
+        Some(v) => v,
+        None => crate::cli::helpers::resolve_vault_for_trait(config, registry).await?,
+    };
+    let run_id = &uuid::Uuid::new_v4().simple().to_string()[..8];
+    let names: Vec<String> = (0..secrets)
+        .map(|i| format!("xv-bench-{run_id}-{i:05}"))
+        .collect();
+    let store = backend.secrets();
+
+    output::step(&format!(
+        "Benchmarking the {} backend, vault '{vault_name}': {secrets} secret(s), \
+         concurrency {concurrency}...",
+        backend.name()
+    ));
+
+    let mut phases = Vec::new();
+    let mut first_error = None;
+
+    let (samples, errors, error, wall) = timed(names.clone(), concurrency, |name| {
+        let request = SecretRequest {
+            name,
+            value: Zeroizing::new(uuid::Uuid::new_v4().to_string()),
+            content_type: None,
+            enabled: None,
+            expires_on: None,
+            not_before: None,
+            tags: None,
+            groups: Some(vec!["xv-bench".to_string()]),
+            note: None,
+            folder: None,
+        };
+        let vault = vault_name.as_str();
+        async move { store.set_secret(vault, request).await }
+    })
+    .await;
+    phases.push(PhaseStats::from_samples("set", samples, errors, wall));
+    first_error = first_error.or(error);
+
+    let (samples, errors, error, wall) = timed(names.clone(), concurrency, |name| {
+        let vault = vault_name.as_str();
+        async move { store.get_secret(vault, &name, true).await }
+    })
+    .await;
+    phases.push(PhaseStats::from_samples("get", samples, errors, wall));
+    first_error = first_error.or(error);
+
+    let (samples, errors, error, wall) = timed((0..list_runs).collect(), concurrency, |_| {
+        store.list_secrets(&vault_name, None)
+    })
+    .await;
+    phases.push(PhaseStats::from_samples("list", samples, errors, wall));
+    first_error = first_error.or(error);
+
+    // Clean up whatever was written; a failed delete is reported, not fatal.
+    let purge = backend.capabilities().has_purge;
+    let (_, leftovers, _, _) = timed(names, concurrency, |name| {
+        let vault = vault_name.as_str();
+        async move {
+            store.delete_secret(vault, &name).await?;
+            if purge {
+                // Best effort: Azure may still be finishing the soft delete.
+                let _ = store.purge_secret(vault, &name).await;
+            }
+            Ok::<_, crate::backend::BackendError>(())
+        }
+    })
+    .await;
+    crate::cli::secret_ops::invalidate_trait_secret_cache(
+        config,
+        config.effective_backend_name(),
+        &vault_name,
+    );
+    if leftovers > 0 {
+        output::warn(&format!(
+            "{leftovers} benchmark secret(s) could not be removed; delete them with \
+             'xv delete --group xv-bench --force'"
+        ));
+    }
+
+    let report = BenchReport {
+        backend: backend.name(),
+        vault: vault_name,
+        secrets,
+        concurrency,
+        phases,
+    };
+    match format.resolve_for_stdout() {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&report)?),
+        resolved => {
+            let formatter = TableFormatter::new(
+                resolved,
+                config.no_color,
+                config.template.clone(),
+                config.runtime_columns.clone(),
+            );
+            println!("{}", formatter.format_table(&report.phases)?);
+        }
+    }
+    if let Some(error) = first_error {
+        output::warn(&format!("Some calls failed; the first error was: {error}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&samples, 50.0), Duration::from_millis(50));
+        assert_eq!(percentile(&samples, 99.0), Duration::from_millis(99));
+        assert_eq!(percentile(&samples, 100.0), Duration::from_millis(100));
+        assert_eq!(percentile(&samples[..1], 90.0), Duration::from_millis(1));
+        assert_eq!(percentile(&[], 50.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn phase_stats_count_errors_and_rate() {
+        let samples = vec![Duration::from_millis(20), Duration::from_millis(10)];
+        let stats = PhaseStats::from_samples("get", samples, 1, Duration::from_secs(2));
+        assert_eq!((stats.calls, stats.errors), (3, 1));
+        assert_eq!(stats.ops_per_sec, 1.0);
+        assert_eq!(stats.p50_ms, 10.0);
+        assert_eq!(stats.max_ms, 20.0);
+    }
+}
@@ -0,0 +1,195 @@
+//! CLI executors for `xv bundle set` / `xv bundle get`.
+
+use std::collections::BTreeMap;
+
+use crate::backend::BackendError;
+use crate::cli::commands::BundleCommands;
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::bundle;
+use crate::secret::manager::{FieldUpdate, SecretRequest, SecretUpdateRequest};
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+
+pub(crate) async fn execute_bundle_command(
+    command: BundleCommands,
+    format: OutputFormat,
+    config: &Config,
+) -> Result<()> {
+    match command {
+        BundleCommands::Set {
+            name,
+            entries,
+            unset,
+            replace,
+        } => execute_bundle_set(&name, entries, unset, replace, config).await,
+        BundleCommands::Get { name, field } => {
+            execute_bundle_get(&name, field, format, config).await
+        }
+    }
+}
+
+/// Create bundle `name`, or merge `entries` into it (`replace` drops the
+/// keys not given) and remove the `unset` keys. Writes a new version.
+async fn execute_bundle_set(
+    name: &str,
+    entries: Vec<(String, String)>,
+    unset: Vec<String>,
+    replace: bool,
+    config: &Config,
+) -> Result<()> {
+    if !entries.is_empty() {
+        crate::cli::helpers::check_command_line_value("--kv", config)?;
+    }
+    for (key, _) in &entries {
+        bundle::validate_key(key)?;
+    }
+
+    let (backend, backend_name, vault_name, name) =
+        crate::cli::helpers::resolve_workspace_or_default(
+            name,
+            config,
+            crate::workspace::TargetMode::Write,
+        )
+        .await?;
+    let name = name.as_str();
+    let secrets = backend.secrets();
+
+    let existing = match secrets.get_secret(&vault_name, name, true).await {
+        Ok(secret) => Some(secret),
+        Err(BackendError::NotFound { .. }) => None,
+        Err(e) => return Err(e.into()),
+    };
+    let mut merged = match &existing {
+        Some(secret) if !bundle::is_bundle(&secret.content_type) => {
+            return Err(CrosstacheError::conflict(format!(
+                "secret '{name}' exists and is not a bundle; pick another name"
+            )));
+        }
+        Some(secret) if !replace => bundle::parse(
+            name,
+            secret.value.as_deref().map(|v| v.as_str()).unwrap_or(""),
+        )?,
+        _ => BTreeMap::new(),
+    };
+    for key in &unset {
+        if merged.remove(key).is_none() {
+            output::warn(&format!("Bundle '{name}' has no key '{key}'"));
+        }
+    }
+    let added = entries.len();
+    merged.extend(entries);
+    if merged.is_empty() {
+        return Err(CrosstacheError::invalid_argument(
+            "A bundle needs at least one key; use 'xv delete' to remove it",
+        ));
+    }
+    let value = bundle::encode(&merged)?;
+
+    let props = match existing {
+        None => {
+            secrets
+                .set_secret(
+                    &vault_name,
+                    SecretRequest {
+                        name: name.to_string(),
+                        value,
+                        content_type: Some(bundle::BUNDLE_CONTENT_TYPE.to_string()),
+                        enabled: None,
+                        expires_on: None,
+                        not_before: None,
+                        tags: None,
+                        groups: None,
+                        note: None,
+                        folder: None,
+                    },
+                )
+                .await?
+        }
+        Some(_) => {
+            secrets
+                .update_secret(
+                    &vault_name,
+                    name,
+                    SecretUpdateRequest {
+                        name: name.to_string(),
+                        expected_revision: None,
+                        value: Some(value),
+                        content_type: None,
+                        enabled: None,
+                        expires_on: FieldUpdate::Unchanged,
+                        not_before: FieldUpdate::Unchanged,
+                        tags: None,
+                        groups: None,
+                        note: FieldUpdate::Unchanged,
+                        folder: FieldUpdate::Unchanged,
+                        replace_tags: false,
+                        replace_groups: false,
+                    },
+                )
+                .await?
+        }
+    };
+    crate::cli::secret_ops::invalidate_trait_secret_cache(config, &backend_name, &vault_name);
+
+    output::success(&format!(
+        "Bundle '{name}' saved: {} key(s), {added} set, {} removed",
+        merged.len(),
+        unset.len()
+    ));
+    if !props.version.is_empty() {
+        println!("Version: {}", props.version);
+    }
+    Ok(())
+}
+
+/// Print bundle `name`: one key's value with `field`, otherwise every key
+/// as a JSON/YAML object or as `key=value` lines.
+async fn execute_bundle_get(
+    name: &str,
+    field: Option<String>,
+    format: OutputFormat,
+    config: &Config,
+) -> Result<()> {
+    let (backend, _backend_name, vault_name, name) =
+        crate::cli::helpers::resolve_workspace_or_default(
+            name,
+            config,
+            crate::workspace::TargetMode::Read,
+        )
+        .await?;
+    let name = name.as_str();
+    let secret = backend
+        .secrets()
+        .get_secret(&vault_name, name, true)
+        .await?;
+    if !bundle::is_bundle(&secret.content_type) {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "secret '{name}' is not a bundle; read it with 'xv get {name}'"
+        )));
+    }
+
+    if let Some(field) = field {
+        let value = crate::cli::secret_ops::record_field_value(name, &secret, Some(&field), &[])?;
+        print!("{}", value.as_str());
+        return Ok(());
+    }
+
+    let entries = bundle::parse(
+        name,
+        secret.value.as_deref().map(|v| v.as_str()).unwrap_or(""),
+    )?;
+    match format.resolve_for_stdout() {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&entries)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&entries)?),
+        _ => {
+            for (key, value) in &entries {
+                println!(
+                    "{key}={}",
+                    crate::cli::config_ops::quote_posix_shell_value(value)
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -41,6 +41,12 @@ impl PagerWhen {
     pub fn wants_pager(self) -> bool {
         !matches!(self, PagerWhen::Never)
     }
+
+    /// Whether a command pages its output: the global `--no-pager` wins,
+    /// then the command's `--pager [WHEN]`, then the `paging` config default.
+    pub fn resolve(flag: Option<PagerWhen>, config: &Config) -> bool {
+        !config.no_pager && flag.map_or(config.paging, PagerWhen::wants_pager)
+    }
 }
 
 /// Sort order for `xv ls`.
@@ -53,6 +59,41 @@ pub enum LsSort {
     Updated,
 }
 
+/// Which secrets `xv ls` shows by enabled state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnabledFilter {
+    /// Enabled secrets only (default).
+    #[default]
+    EnabledOnly,
+    /// Enabled and disabled secrets (`--all`).
+    All,
+    /// Disabled secrets only (`--disabled-only`).
+    DisabledOnly,
+}
+
+impl EnabledFilter {
+    /// Resolve the mutually exclusive `--all` / `--disabled-only` flags;
+    /// with neither, only enabled secrets are listed.
+    pub fn from_flags(all: bool, disabled_only: bool) -> Self {
+        if all {
+            Self::All
+        } else if disabled_only {
+            Self::DisabledOnly
+        } else {
+            Self::EnabledOnly
+        }
+    }
+
+    /// Whether a secret with the given enabled state passes this filter.
+    pub fn admits(self, enabled: bool) -> bool {
+        match self {
+            Self::EnabledOnly => enabled,
+            Self::All => true,
+            Self::DisabledOnly => !enabled,
+        }
+    }
+}
+
 #[derive(Debug, Clone, clap::ValueEnum, PartialEq, Eq, Default)]
 pub enum OnConflict {
     /// Skip secrets that already exist in the target (default)
@@ -64,6 +105,32 @@ pub enum OnConflict {
     Fail,
 }
 
+/// Shell syntax for the lines `xv env` and `xv env pull` print.
+#[derive(Debug, Clone, Copy, clap::ValueEnum, PartialEq, Eq, Default)]
+pub enum EnvShell {
+    /// `NAME=value` lines that POSIX shells can source; `xv env` prefixes
+    /// `export` (default)
+    #[default]
+    #[value(alias = "bash", alias = "zsh", alias = "sh")]
+    Posix,
+    /// `set -gx NAME 'value'` lines for fish
+    Fish,
+    /// `$env:NAME = 'value'` lines for PowerShell
+    Powershell,
+}
+
+/// How `xv find` matches its pattern.
+#[derive(Debug, Clone, Copy, clap::ValueEnum, PartialEq, Eq, Default)]
+pub enum FindMode {
+    /// Ranked fuzzy match (default)
+    #[default]
+    Fuzzy,
+    /// Case-insensitive substring
+    Substring,
+    /// Regular expression; prefix with (?i) to ignore case
+    Regex,
+}
+
 /// Determine if options should be hidden based on environment or command line
 fn should_hide_options() -> bool {
     // Check if --show-options is present in command line args
@@ -181,12 +248,12 @@ pub struct Cli {
     #[arg(long, global = true, hide = should_hide_options())]
     pub no_color: bool,
 
-    /// Azure credential type to use first (cli, managed_identity, environment, default)
+    /// Azure credential type to use first (cli, managed_identity, environment, device_code, default)
     #[arg(
         long,
         global = true,
         value_name = "TYPE",
-        help = "Azure credential type to use first (cli, managed_identity, environment, default)",
+        help = "Azure credential type to use first (cli, managed_identity, environment, device_code, default)",
         env = "AZURE_CREDENTIAL_PRIORITY",
         hide = should_hide_options()
     )]
@@ -200,6 +267,21 @@ pub struct Cli {
     #[arg(long, global = true, hide = should_hide_options())]
     pub region: Option<String>,
 
+    /// Abort the command if it has not finished within SECONDS; in-flight
+    /// requests are cancelled and xv exits with code 32
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECONDS",
+        value_parser = clap::value_parser!(u64).range(1..),
+        hide = should_hide_options()
+    )]
+    pub timeout: Option<u64>,
+
+    /// Never page output, overriding --pager and the `paging` config default
+    #[arg(long, global = true, hide = should_hide_options())]
+    pub no_pager: bool,
+
     /// Show global options in help output
     #[arg(long)]
     pub show_options: bool,
@@ -323,10 +405,10 @@ pub struct SecretWriteArgs {
     /// Folder path for the secret (e.g., 'app/database', 'config/dev')
     #[arg(long)]
     pub folder: Option<String>,
-    /// Set expiration date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+    /// Set expiration date (YYYY-MM-DD, RFC 3339, or a duration from now like 90d)
     #[arg(long)]
     pub expires: Option<String>,
-    /// Set not-before date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+    /// Set not-before date (YYYY-MM-DD, RFC 3339, or a duration from now like 7d)
     #[arg(long)]
     pub not_before: Option<String>,
     /// Custom tag in key=value format (repeatable; e.g. `--tag owner=team-data`)
@@ -439,27 +521,48 @@ pub enum Commands {
         #[command(flatten)]
         meta: SecretWriteArgs,
     },
-    /// Get a secret from the current vault context
+    /// Get a secret from the current vault context. Several names (or
+    /// --group) print all the values at once, as a JSON object with
+    /// `--format json` or dotenv lines otherwise.
     Get {
-        /// Secret name
-        name: String,
+        /// Secret name(s)
+        #[arg(required_unless_present = "group", num_args = 1..)]
+        names: Vec<String>,
+        /// Get every secret in this group (JSON object or dotenv lines)
+        #[arg(long, conflicts_with_all = ["names", "version", "field", "record", "qr", "offline", "clipboard"])]
+        group: Option<String>,
         /// Raw output (print value instead of copying to clipboard)
         #[arg(short, long)]
         raw: bool,
+        /// Copy the value to the clipboard, overriding a
+        /// `get_default_output` of `raw` or `masked`
+        #[arg(long, conflicts_with_all = ["raw", "record", "qr"])]
+        clipboard: bool,
         /// Get a specific version of the secret
         #[arg(long)]
         version: Option<String>,
-        /// Read a single field from a typed record (metadata or secret).
-        /// Errors if the secret is untyped. Mutually exclusive with --record.
+        /// Read a single field from a typed record (metadata or secret) or
+        /// one key of a bundle. Errors if the secret is neither. Mutually
+        /// exclusive with --record.
         #[arg(long, conflicts_with = "record")]
         field: Option<String>,
         /// Print the full record (all fields) in the requested --format.
         /// Errors if the secret is untyped. Mutually exclusive with --field.
         #[arg(long, conflicts_with = "field")]
         record: bool,
+        /// Show the value (or --field) as a QR code in the terminal, for
+        /// moving it to a phone or MFA app. The screen is cleared on Enter.
+        /// Needs `allow_qr_and_paste = true`; each use is audited.
+        #[arg(long, conflicts_with_all = ["raw", "record"])]
+        qr: bool,
+        /// Fall back to the break-glass cache (`xv vault cache warm`) when
+        /// the vault cannot be reached. Audited.
+        #[arg(long, conflicts_with = "version")]
+        offline: bool,
     },
-    /// Ranked fuzzy search over secrets (alias: search). Non-interactive;
-    /// pipe the output through fzf or similar for an interactive picker.
+    /// Search secrets by name and metadata (alias: search). Fuzzy-ranked
+    /// by default, or substring/regex with --mode. Non-interactive; pipe
+    /// the output through fzf or similar for an interactive picker.
     /// Default search field is the secret name; opt in to other fields
     /// via repeated `--in <field>`.
     #[command(alias = "search")]
@@ -468,8 +571,13 @@ pub enum Commands {
         /// secrets unranked (score 0); flags still apply.
         pattern: Option<String>,
 
+        /// How PATTERN matches: fuzzy (ranked), substring or regex.
+        /// Substring and regex results are sorted by name.
+        #[arg(long, value_enum, default_value_t = FindMode::Fuzzy)]
+        mode: FindMode,
+
         /// Search additional fields alongside the name. Repeatable.
-        /// Allowed: name, folder, groups, note, tags.
+        /// Allowed: name, folder, groups, note, tags, all.
         #[arg(long = "in", value_name = "FIELD", num_args = 1..)]
         in_fields: Vec<String>,
 
@@ -478,7 +586,7 @@ pub enum Commands {
         limit: usize,
 
         /// Drop matches scoring below this fraction of the top match
-        /// (0.0..=1.0). Default 0.3.
+        /// (0.0..=1.0). Default 0.3. Fuzzy mode only.
         #[arg(
             long,
             default_value_t = 0.3,
@@ -507,6 +615,11 @@ pub enum Commands {
         /// yields an unranked filtered list.
         #[arg(long, value_name = "GLOB")]
         filter: Option<String>,
+
+        /// Fetch fresh metadata instead of answering from the local search
+        /// index (see `xv index`).
+        #[arg(long)]
+        no_cache: bool,
     },
     /// List secrets in the current vault context (alias: ls). Use --format table for the classic table view
     #[command(alias = "ls")]
@@ -516,6 +629,9 @@ pub enum Commands {
         /// listings.
         #[arg(value_name = "FOLDER")]
         path: Option<String>,
+        /// Folder to list; the same as the FOLDER argument
+        #[arg(long = "folder", value_name = "FOLDER", conflicts_with = "path")]
+        folder: Option<String>,
         /// Long listing: name, updated date, groups, note
         #[arg(short = 'l', long)]
         long: bool,
@@ -526,8 +642,11 @@ pub enum Commands {
         #[arg(short, long)]
         group: Option<String>,
         /// Show all secrets including disabled ones
-        #[arg(long)]
+        #[arg(long, conflicts_with = "disabled_only")]
         all: bool,
+        /// Show only disabled secrets
+        #[arg(long)]
+        disabled_only: bool,
         /// Show secrets expiring within specified period (e.g., 30d, 7d, 1h)
         #[arg(long)]
         expiring: Option<String>,
@@ -558,7 +677,10 @@ pub enum Commands {
         /// secrets (backend must support soft delete)
         #[arg(
             long,
-            conflicts_with_all = ["path", "recursive", "group", "all", "expiring", "expired", "type_filter"]
+            conflicts_with_all = [
+                "path", "recursive", "group", "all", "disabled_only",
+                "expiring", "expired", "type_filter"
+            ]
         )]
         deleted: bool,
         /// Filter by record type (built-in or custom `[types.*]`, e.g. `login`).
@@ -569,6 +691,17 @@ pub enum Commands {
         /// Filter secrets by glob pattern on the name (e.g., "test-*", "api-*")
         #[arg(long, value_name = "GLOB")]
         filter: Option<String>,
+        /// Only secrets whose tag KEY equals VALUE (repeatable; all must match)
+        #[arg(long = "tag", value_name = "KEY=VALUE", value_parser = parse_key_val::<String, String>, conflicts_with = "deleted")]
+        tag: Vec<(String, String)>,
+        /// Only secrets that have tag KEY, whatever its value (repeatable)
+        #[arg(long, value_name = "KEY", conflicts_with = "deleted")]
+        tag_exists: Vec<String>,
+        /// Fetch each listed secret's value to report its size in bytes
+        /// (SIZE column, `value_size` in JSON) and flag values nearing the
+        /// backend's limit. One extra read per secret; implies --long.
+        #[arg(long, conflicts_with_all = ["deleted", "names_only"])]
+        size: bool,
     },
     /// Delete a secret from the current vault context (alias: rm)
     #[command(alias = "rm")]
@@ -634,7 +767,9 @@ pub enum Commands {
         #[arg(short, long)]
         force: bool,
     },
-    /// Rotate a secret with a new random value
+    /// Rotate a secret: write a new version from a generated value, a
+    /// script, a shell command or a webhook, tagged with the rotation time
+    /// and the version it replaced
     Rotate {
         /// Secret name
         name: String,
@@ -650,14 +785,23 @@ pub enum Commands {
         #[arg(long, value_enum, default_value = "alphanumeric")]
         charset: CharsetType,
         /// Custom generator script path (overrides charset and length)
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["command", "webhook"])]
         generator: Option<String>,
+        /// Shell command whose stdout is the new value; it sees the secret
+        /// in XV_SECRET_NAME, XV_VAULT and XV_SECRET_VERSION
+        #[arg(long, conflicts_with = "webhook")]
+        command: Option<String>,
+        /// HTTPS endpoint that provisions the new value: it receives a JSON
+        /// POST of {vault, name, current_version} and answers with
+        /// {"value": ...} or the value as plain text
+        #[arg(long)]
+        webhook: Option<String>,
         /// Use the backend's native rotation instead of generating a value
         /// locally. On AWS this calls RotateSecret, which invokes the
         /// secret's configured rotation Lambda; the rotation completes
         /// asynchronously. Errors on backends without native rotation.
         /// Generation flags (--length, --charset) are ignored.
-        #[arg(long, conflicts_with_all = ["generator", "show_value"])]
+        #[arg(long, conflicts_with_all = ["generator", "command", "webhook", "show_value"])]
         native: bool,
         /// Show the generated value (default: hidden for security)
         #[arg(long)]
@@ -666,14 +810,29 @@ pub enum Commands {
         #[arg(short, long)]
         force: bool,
     },
-    /// Generate a random password and copy it to the clipboard
+    /// Generate a random password, passphrase or byte string and copy it
+    /// to the clipboard (alias: generate)
+    #[command(alias = "generate")]
     Gen {
-        /// Password length — must be between 6 and 100 (default: 15)
-        #[arg(short, long, default_value = "15")]
-        length: usize,
+        /// Password length — must be between 6 and 100 (default: 15); with
+        /// --encoding, the number of random bytes (8 to 1024, default: 32)
+        #[arg(short, long, conflicts_with = "passphrase")]
+        length: Option<usize>,
         /// Character set to use (default: alphanumeric, or gen_default_charset config)
-        #[arg(short, long, value_enum)]
+        #[arg(short, long, value_enum, conflicts_with_all = ["passphrase", "encoding"])]
         charset: Option<CharsetType>,
+        /// Generate a passphrase of random words (10 bits of entropy each)
+        #[arg(long, conflicts_with = "encoding")]
+        passphrase: bool,
+        /// Number of words for --passphrase (3 to 64, default: 6)
+        #[arg(long, requires = "passphrase")]
+        words: Option<usize>,
+        /// Word separator for --passphrase (default: -)
+        #[arg(long, requires = "passphrase")]
+        separator: Option<String>,
+        /// Generate random bytes written as hex or base64
+        #[arg(long, value_enum)]
+        encoding: Option<crate::secret::generate::ByteEncoding>,
         /// Save the generated password as a secret in the vault
         #[arg(long)]
         save: Option<String>,
@@ -687,7 +846,36 @@ pub enum Commands {
         #[command(flatten)]
         meta: SecretWriteArgs,
     },
+    /// Create a standard set of secrets (host, port, user, password,
+    /// connection string, ...) from a scaffold template
+    Scaffold {
+        /// Built-in template (postgres, mysql, redis), a template in
+        /// <config dir>/xv/scaffolds/<name>.toml, or a path to a TOML file
+        template: String,
+        /// Folder for the new secrets; its segments also prefix their names
+        /// (e.g. app/db creates app-db-host, app-db-port, ...)
+        #[arg(long)]
+        prefix: String,
+        /// Target vault (overrides context/config default)
+        #[arg(long)]
+        vault: Option<String>,
+        /// Field value in field=value form (repeatable); skips the prompt or
+        /// generation for that field
+        #[arg(long = "set", value_name = "FIELD=VALUE", value_parser = parse_key_val::<String, String>)]
+        values: Vec<(String, String)>,
+        /// Use template defaults instead of prompting
+        #[arg(short, long)]
+        yes: bool,
+        /// Overwrite secrets that already exist
+        #[arg(long)]
+        force: bool,
+        /// Show the secrets that would be created without prompting or writing
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// Run a command with secrets injected as environment variables
+    /// (alias: exec)
+    #[command(visible_alias = "exec")]
     Run {
         /// Target vault (overrides context/config default). Matches an
         /// attached workspace alias when given; otherwise a literal vault on
@@ -697,6 +885,9 @@ pub enum Commands {
         /// Filter secrets by group (can be specified multiple times)
         #[arg(short, long)]
         group: Vec<String>,
+        /// Inject only secrets in this folder or below it (e.g. `prod/db`)
+        #[arg(long)]
+        folder: Option<String>,
         /// Inject only these secrets by name (repeatable). When given, the set
         /// of injected secrets is restricted to these names (still subject to
         /// --group). Names match either the original (user-facing) name shown by
@@ -743,11 +934,34 @@ pub enum Commands {
         #[arg(long)]
         best_effort: bool,
     },
+    /// Render a template's {{ secret:name }} placeholders with secret values
+    /// from one group or folder
+    Render {
+        /// Template file (`-` reads stdin)
+        template: String,
+        /// Write the result to this file (owner-only) instead of stdout
+        #[arg(short, long)]
+        out: Option<String>,
+        /// Target vault (overrides context/config default)
+        #[arg(long)]
+        vault: Option<String>,
+        /// Resolve placeholders only against secrets in this group (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Resolve placeholders only against secrets in this folder or below it
+        #[arg(long)]
+        folder: Option<String>,
+        /// Fail, writing nothing, when a placeholder has no matching secret
+        /// (default: leave it as written and warn)
+        #[arg(long)]
+        strict: bool,
+    },
     /// Update secret properties in the current vault context
     Update {
         /// Secret name
         name: String,
-        /// New value (if not provided, will prompt)
+        /// New value (if not provided, will prompt). Avoid: it appears in
+        /// shell history — prefer the prompt or --stdin
         value: Option<String>,
         /// Read value from stdin.
         /// Input bytes are preserved exactly: no trimming or newline stripping
@@ -777,10 +991,10 @@ pub enum Commands {
         /// Replace existing groups instead of merging
         #[arg(long)]
         replace_groups: bool,
-        /// Set expiration date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS; omit to leave unchanged; use --clear-expires to remove)
+        /// Set expiration date (YYYY-MM-DD, RFC 3339, or a duration like 90d; omit to leave unchanged; use --clear-expires to remove)
         #[arg(long)]
         expires: Option<String>,
-        /// Set not-before date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS; omit to leave unchanged; use --clear-not-before to remove)
+        /// Set not-before date (YYYY-MM-DD, RFC 3339, or a duration like 7d; omit to leave unchanged; use --clear-not-before to remove)
         #[arg(long)]
         not_before: Option<String>,
         /// Clear expiration date
@@ -857,6 +1071,17 @@ pub enum Commands {
         #[arg(long, short = 'y')]
         yes: bool,
     },
+    /// Edit a secret's value in $VISUAL/$EDITOR. The value is opened from a
+    /// private temp file that is zeroed afterwards, and a new version is
+    /// saved only if the text changed. Typed records edit their primary field.
+    Edit {
+        /// Secret name
+        name: String,
+        /// Temp file extension, for the editor's syntax highlighting
+        /// (default: json for JSON values, otherwise txt)
+        #[arg(long, value_name = "EXT")]
+        extension: Option<String>,
+    },
     /// Move or rename a secret, re-folder a whole folder (trailing / = folder),
     /// or bulk-move every secret matching a glob (--filter) into a folder
     #[command(
@@ -883,15 +1108,34 @@ pub enum Commands {
         #[arg(long, short = 'y')]
         yes: bool,
     },
-    /// Compare secrets between two vaults
+    /// Print the folder tree with secret counts (alias: folders), or
+    /// rename a folder
+    #[command(alias = "folders", args_conflicts_with_subcommands = true)]
+    Folder {
+        #[command(subcommand)]
+        command: Option<FolderCommands>,
+        /// Only show this folder's subtree
+        #[arg(value_name = "FOLDER")]
+        path: Option<String>,
+    },
+    /// Compare secrets between two vaults or JSON export files
     Diff {
-        /// First vault name
+        /// First vault (name or workspace alias), or an `xv vault export`
+        /// JSON file
         vault1: String,
-        /// Second vault name
+        /// Second vault (name or workspace alias), or an `xv vault export`
+        /// JSON file
         vault2: String,
         /// Show actual secret values in diff output
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["hash_values", "no_values"])]
         show_values: bool,
+        /// Show a SHA-256 fingerprint of differing values instead of the
+        /// values themselves
+        #[arg(long, conflicts_with = "no_values")]
+        hash_values: bool,
+        /// Compare names and metadata only, without reading values
+        #[arg(long)]
+        no_values: bool,
         /// Filter by group in both vaults
         #[arg(short, long)]
         group: Option<String>,
@@ -929,16 +1173,40 @@ pub enum Commands {
     },
     /// Permanently delete (purge) a secret from the current vault context
     Purge {
-        /// Secret name
-        name: String,
+        /// Secret name (mutually exclusive with --all)
+        #[arg(required_unless_present = "all")]
+        name: Option<String>,
+        /// Purge every deleted secret in the vault (requires --force)
+        #[arg(long, conflicts_with = "name", requires = "force")]
+        all: bool,
+        /// With --all, only deleted secrets in this group
+        #[arg(long, requires = "all")]
+        group: Option<String>,
+        /// With --all, only deleted secrets in this folder or below it
+        #[arg(long, requires = "all")]
+        folder: Option<String>,
         /// Force purge without confirmation
         #[arg(short, long)]
         force: bool,
+        /// If the secret was deleted moments ago and can't be purged yet,
+        /// retry for up to 5 minutes until it can
+        #[arg(long, conflicts_with = "all")]
+        when_eligible: bool,
     },
     /// Restore a deleted secret in the current vault context
     Restore {
-        /// Secret name
-        name: String,
+        /// Secret name (mutually exclusive with --all)
+        #[arg(required_unless_present = "all")]
+        name: Option<String>,
+        /// Restore every deleted secret in the vault
+        #[arg(long, conflicts_with = "name")]
+        all: bool,
+        /// With --all, only deleted secrets in this group
+        #[arg(long, requires = "all")]
+        group: Option<String>,
+        /// With --all, only deleted secrets in this folder or below it
+        #[arg(long, requires = "all")]
+        folder: Option<String>,
     },
     /// Parse connection strings (vault-independent utility)
     Parse {
@@ -982,15 +1250,61 @@ pub enum Commands {
     },
     /// Manage `.xv.toml` project environments (the `[env.<name>]` blocks).
     /// Activate via `--env <name>`, `XV_ENV=<name>`, or by setting `default_env`.
+    ///
+    /// Without a subcommand, print the current vault's secrets as shell
+    /// export statements: `eval "$(xv env --group api)"`.
+    #[command(args_conflicts_with_subcommands = true)]
     Env {
         #[command(subcommand)]
-        command: EnvCommands,
+        command: Option<EnvCommands>,
+        /// Only secrets in this group (repeatable; default: the env
+        /// profile's group, else every secret)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Only secrets in this folder or below it (e.g. `prod/db`)
+        #[arg(long)]
+        folder: Option<String>,
+        /// Shell syntax for the export statements
+        #[arg(long, value_enum, default_value_t = EnvShell::Posix)]
+        shell: EnvShell,
+    },
+    /// Sync a local `.env` file with the vault: `xv env pull`/`push` with
+    /// `.env` as the default file
+    Dotenv {
+        #[command(subcommand)]
+        command: DotenvCommands,
     },
-    /// Inspect secret groups in the current vault context
+    /// direnv integration: load the project's secrets on entering its directory
+    Direnv {
+        #[command(subcommand)]
+        command: DirenvCommands,
+    },
+    /// SSH keys kept as secrets: generate a pair, or load one into ssh-agent
+    Ssh {
+        #[command(subcommand)]
+        command: SshCommands,
+    },
+    /// Key/value bundles: several related settings stored as one secret
+    Bundle {
+        #[command(subcommand)]
+        command: BundleCommands,
+    },
+    /// List, inspect and edit secret groups in the current vault context
+    #[command(alias = "groups")]
     Group {
         #[command(subcommand)]
         command: GroupCommands,
     },
+    /// Manage the local search index behind `xv search`
+    Index {
+        #[command(subcommand)]
+        command: IndexCommands,
+    },
+    /// Report how secret names map to the names stored in the vault
+    Name {
+        #[command(subcommand)]
+        command: NameCommands,
+    },
     /// Show audit history for secrets or vaults
     Audit {
         /// Secret name to show audit history for (exclusive with --vault)
@@ -1008,8 +1322,48 @@ pub enum Commands {
         #[arg(long)]
         resource_group: Option<String>,
     },
-    /// Initialize default configuration
-    Init,
+    /// Initialize default configuration (interactive, or from flags with
+    /// --subscription, --resource-group, --location and --vault)
+    Init {
+        /// Azure subscription ID; giving any setup flag skips the prompts
+        #[arg(long)]
+        subscription: Option<String>,
+        /// Azure tenant ID (default: looked up from the Azure CLI)
+        #[arg(long)]
+        tenant: Option<String>,
+        /// Resource group, created when it does not exist
+        #[arg(long)]
+        resource_group: Option<String>,
+        /// Azure region, e.g. eastus
+        #[arg(long)]
+        location: Option<String>,
+        /// Default vault
+        #[arg(long)]
+        vault: Option<String>,
+        /// Create the vault instead of configuring an existing one
+        #[arg(long, requires = "vault")]
+        create_vault: bool,
+        /// Storage account for file operations, created when it does not exist
+        #[arg(long)]
+        storage_account: Option<String>,
+        /// Blob container in the storage account
+        #[arg(long, default_value = crate::config::init::DEFAULT_CONTAINER, requires = "storage_account")]
+        container: String,
+        /// Overwrite an existing configuration without asking
+        #[arg(short = 'y', long)]
+        yes: bool,
+        /// Only check that the environment is ready (Azure CLI, subscription,
+        /// resources, RBAC); writes no config and creates nothing
+        #[arg(long, conflicts_with = "yes")]
+        check: bool,
+        /// Only add blob storage (for 'xv file') to an existing Azure setup;
+        /// prompts for the account unless --storage-account is given
+        #[arg(
+            long,
+            conflicts_with_all = ["subscription", "tenant", "resource_group", "location", "vault", "yes", "check"]
+        )]
+        blob_only: bool,
+    },
     /// Show information about a resource (vault, secret, or file)
     Info {
         /// Resource identifier (vault name, secret name, or file name)
@@ -1033,7 +1387,50 @@ pub enum Commands {
         shell: Shell,
     },
     /// Show authenticated identity and context information
-    Whoami,
+    Whoami {
+        /// Also try each credential-chain source and show why each one
+        /// did or did not produce a token
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Check network connectivity (and proxy use) for each Azure endpoint
+    Doctor,
+    /// Measure set/get/list throughput and latency percentiles against a
+    /// vault, using throwaway secrets that are removed afterwards
+    Bench {
+        /// Number of secrets to write and read back
+        #[arg(long, default_value_t = 100)]
+        secrets: usize,
+        /// Calls in flight at once
+        #[arg(long, default_value_t = 8)]
+        concurrency: usize,
+        /// Number of full-vault list calls
+        #[arg(long, default_value_t = 10)]
+        list_runs: usize,
+        /// Target vault (overrides context/config default)
+        #[arg(long)]
+        vault: Option<String>,
+    },
+    /// Run an acceptance test: create a temporary vault (and blob container),
+    /// exercise set/get/list/update/rename/delete/restore/purge and file
+    /// upload/download against it, and report each step
+    Selftest {
+        /// Resource group for the temporary vault (defaults to config value)
+        #[arg(short, long, visible_alias = "rg")]
+        resource_group: Option<String>,
+        /// Region for the temporary vault (defaults to config value)
+        #[arg(short, long)]
+        location: Option<String>,
+        /// Delete and purge the temporary vault and container afterwards
+        #[arg(long)]
+        cleanup: bool,
+        /// Skip the file upload/download steps
+        #[arg(long)]
+        skip_files: bool,
+        /// Write a JSON transcript of per-step outcomes to this path
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+    },
     /// Check for and install new versions
     Upgrade {
         /// Only check if an update is available (exit code 0 = up-to-date, 1 = update available)
@@ -1048,6 +1445,16 @@ pub enum Commands {
         #[command(subcommand)]
         command: CacheCommands,
     },
+    /// Check App Service / Function app Key Vault references
+    Appservice {
+        #[command(subcommand)]
+        command: AppserviceCommands,
+    },
+    /// Scheduled backups of vault secrets (cron-friendly)
+    Backup {
+        #[command(subcommand)]
+        command: BackupCommands,
+    },
     /// Local backend maintenance commands (only relevant for `backend = "local"`)
     Local {
         #[command(subcommand)]
@@ -1063,7 +1470,7 @@ pub enum Commands {
     #[cfg(feature = "file-ops")]
     #[command(alias = "up")]
     Upload {
-        /// Local file path
+        /// Local file path (`-` streams stdin; requires --name)
         file_path: String,
         /// Remote name (optional, defaults to filename)
         #[arg(long)]
@@ -1081,7 +1488,8 @@ pub enum Commands {
     Download {
         /// Remote file name
         name: String,
-        /// Local output path (optional, defaults to current directory)
+        /// Local output path (optional, defaults to current directory; `-`
+        /// streams to stdout)
         #[arg(long)]
         output: Option<String>,
         /// Print full file path after download
@@ -1108,6 +1516,29 @@ pub enum Commands {
         #[command(subcommand)]
         command: Option<ScanCommands>,
     },
+    /// List secrets that have expired, expire soon, or have no expiration
+    /// date, for review or cron-driven alerting.
+    ExpiryReport {
+        /// Window for "expiring soon", in days
+        #[arg(long, default_value_t = 30)]
+        days: u32,
+        /// Report on every vault you can list
+        #[arg(long)]
+        all_vaults: bool,
+        /// Also flag secrets not rotated by 'xv rotate' within this many days
+        #[arg(long, value_name = "DAYS")]
+        max_age: Option<u32>,
+    },
+    /// Check that secrets referenced by project config files (appsettings.json,
+    /// .env.example, helm values) exist in the current vault.
+    Lint {
+        /// Project directory to check (default: current directory).
+        #[arg(default_value = ".")]
+        dir: std::path::PathBuf,
+        /// Also fail when the vault has secrets no config file references.
+        #[arg(long)]
+        fail_on_unused: bool,
+    },
     /// Migrate secrets between backends
     Migrate {
         /// Source backend (azure, local, aws)
@@ -1134,10 +1565,36 @@ pub enum Commands {
         /// Concurrent transfers (default 8)
         #[arg(long, default_value = "8")]
         concurrency: usize,
+        /// Write a JSON transcript of per-secret outcomes to this path
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+        /// Skip secrets already migrated by an interrupted or failed run
+        /// between the same source and target
+        #[arg(long)]
+        resume: bool,
     },
     /// Open the read-only terminal browser. Requires --features tui at build time.
     #[cfg(feature = "tui")]
     Tui,
+    /// Serve secrets to local dev servers over a localhost-only HTTP API
+    DevProxy {
+        /// Port to bind on 127.0.0.1
+        #[arg(long, default_value_t = 8200)]
+        port: u16,
+        /// Only serve secrets in this group (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Only serve secrets in this folder (and its subfolders)
+        #[arg(long)]
+        folder: Option<String>,
+        /// Seconds to cache values and the secret list (0 = always fetch)
+        #[arg(long, default_value_t = 300)]
+        ttl: u64,
+        /// Also append each request as a JSON line to this file (owner-only).
+        /// A value whose read cannot be logged is not served.
+        #[arg(long, value_name = "FILE")]
+        audit_log: Option<std::path::PathBuf>,
+    },
     /// Open the local web UI in a browser. Requires --features ui at build time.
     #[cfg(feature = "ui")]
     Ui {
@@ -1162,6 +1619,15 @@ pub enum VaultCommands {
         /// Location
         #[arg(short, long)]
         location: Option<String>,
+        /// Succeed without changes when the vault already exists in this
+        /// resource group instead of failing
+        #[arg(long)]
+        update_if_exists: bool,
+        /// Evaluate Azure Policy assignments on the resource group first:
+        /// warn about settings a policy would change and stop before
+        /// creating anything if one would deny the vault (Azure only)
+        #[arg(long)]
+        policy_check: bool,
     },
     /// List vaults (alias: ls)
     #[command(alias = "ls")]
@@ -1176,6 +1642,14 @@ pub enum VaultCommands {
         /// Bypass the local cache and fetch fresh data
         #[arg(long)]
         no_cache: bool,
+        /// Add a Secrets column by listing each vault's secrets (queried
+        /// concurrently; reuses and refreshes the per-vault list cache)
+        #[arg(long, conflicts_with = "names_only")]
+        with_counts: bool,
+        /// List vaults in every subscription the credential can see, with a
+        /// Subscription column (Azure only)
+        #[arg(long, conflicts_with = "resource_group")]
+        all_subscriptions: bool,
         /// Page number to display (requires --page-size)
         #[arg(long)]
         page: Option<usize>,
@@ -1206,6 +1680,11 @@ pub enum VaultCommands {
         #[arg(short, long)]
         resource_group: Option<String>,
     },
+    /// Check whether a vault name is free to create (Azure only)
+    CheckName {
+        /// Vault name
+        name: String,
+    },
     /// Restore a soft-deleted vault
     Restore {
         /// Vault name
@@ -1224,6 +1703,10 @@ pub enum VaultCommands {
         /// Force purge without confirmation
         #[arg(short, long)]
         force: bool,
+        /// If the vault was deleted moments ago and can't be purged yet,
+        /// retry for up to 5 minutes until it can
+        #[arg(long)]
+        when_eligible: bool,
     },
     /// Export vault secrets to a file
     Export {
@@ -1235,7 +1718,8 @@ pub enum VaultCommands {
         /// Output file path (default: stdout)
         #[arg(short, long)]
         output: Option<String>,
-        /// Export format (json, env, txt)
+        /// Export format (json, env, txt, or bicep/terraform for IaC stubs
+        /// with names and metadata only)
         #[arg(
             short = 'f',
             long = "fmt",
@@ -1249,6 +1733,23 @@ pub enum VaultCommands {
         /// Filter by secret group
         #[arg(short, long)]
         group: Option<String>,
+        /// Write a JSON transcript of per-secret outcomes to this path
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+    },
+    /// Export the vault's configuration (not its secrets) as an ARM or Bicep template
+    ExportTemplate {
+        /// Vault name
+        name: String,
+        /// Resource group
+        #[arg(short, long)]
+        resource_group: Option<String>,
+        /// Output file path (default: stdout). A `.bicep` extension selects Bicep.
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Template format (default: from the output extension, else arm)
+        #[arg(long, value_enum)]
+        template_format: Option<crate::vault::template::TemplateFormat>,
     },
     /// Import secrets from a file
     Import {
@@ -1274,6 +1775,36 @@ pub enum VaultCommands {
         /// Dry run (show what would be imported)
         #[arg(long)]
         dry_run: bool,
+        /// Write a JSON transcript of per-secret outcomes to this path
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+        /// Skip secrets already imported by an interrupted or failed run of
+        /// the same import (requires --input)
+        #[arg(long, requires = "input")]
+        resume: bool,
+    },
+    /// Mirror secrets from one vault into another: create missing secrets
+    /// and update changed ones (value and metadata, tags preserved)
+    Sync {
+        /// Source vault (name or workspace alias)
+        source: String,
+        /// Destination vault (name or workspace alias)
+        dest: String,
+        /// Show what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Also delete destination secrets that are not in the source
+        #[arg(long)]
+        delete: bool,
+        /// Only sync secrets in this group
+        #[arg(short, long)]
+        group: Option<String>,
+        /// Skip the confirmation prompt for --delete
+        #[arg(short = 'y', long)]
+        yes: bool,
+        /// Write a JSON transcript of per-secret outcomes to this path
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
     },
     /// Update vault properties and tags
     Update {
@@ -1306,6 +1837,43 @@ pub enum VaultCommands {
         #[command(subcommand)]
         command: VaultShareCommands,
     },
+    /// Break-glass offline cache of selected secrets
+    Cache {
+        #[command(subcommand)]
+        command: VaultCacheCommands,
+    },
+}
+
+/// Break-glass offline cache subcommands (`xv vault cache ...`).
+#[derive(Subcommand)]
+pub enum VaultCacheCommands {
+    /// Store a group's secrets, values included, in an encrypted local file
+    /// that `xv get --offline` falls back to when the vault is unreachable.
+    ///
+    /// Replaces any earlier cache of the same vault. Every warm and offline
+    /// read is recorded in the break-glass audit log.
+    Warm {
+        /// Vault to cache (defaults to the current context vault)
+        vault_name: Option<String>,
+        /// Group whose secrets are cached
+        #[arg(long)]
+        group: String,
+        /// When the cache stops being served: a duration (`72h`, `7d`) or a
+        /// date
+        #[arg(long, default_value = "72h")]
+        expires: String,
+        /// Seal with a passphrase (from XV_BREAK_GLASS_PASSPHRASE or a
+        /// prompt) or to the local age key
+        #[arg(long, value_enum, default_value = "passphrase")]
+        protect: crate::cache::offline::Protection,
+    },
+    /// List cached vaults with their expiry
+    Status,
+    /// Delete cached vaults
+    Clear {
+        /// Vault to clear (all cached vaults when omitted)
+        vault_name: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1314,7 +1882,8 @@ pub enum VaultShareCommands {
     Grant {
         /// Vault name
         vault_name: String,
-        /// User email or service principal ID
+        /// User email, service principal ID, or `group:<name>` for an Entra
+        /// ID security group
         user: String,
         /// Resource group
         #[arg(short, long)]
@@ -1327,12 +1896,42 @@ pub enum VaultShareCommands {
     Revoke {
         /// Vault name
         vault_name: String,
-        /// User email or service principal ID
+        /// User email, service principal ID, or `group:<name>`
         user: String,
         /// Resource group
         #[arg(short, long)]
         resource_group: Option<String>,
     },
+    /// Invite an external user as a guest and grant them vault access
+    ///
+    /// Creates a Microsoft Entra B2B invitation, waits for the guest's
+    /// directory object to become assignable, then assigns the role.
+    /// Inviting someone who is already a guest reuses their account.
+    Invite {
+        /// Vault name
+        vault_name: String,
+        /// External email address to invite
+        email: String,
+        /// Resource group
+        #[arg(short, long)]
+        resource_group: Option<String>,
+        /// Access level (reader, contributor, admin)
+        #[arg(short, long, default_value = "reader")]
+        level: String,
+        /// Page the guest lands on after accepting the invitation
+        #[arg(
+            long,
+            value_name = "URL",
+            default_value = "https://myapps.microsoft.com"
+        )]
+        redirect_url: String,
+        /// Don't email the invitation; print the redemption link instead
+        #[arg(long)]
+        no_email: bool,
+        /// Seconds to wait for the new guest to become assignable
+        #[arg(long, value_name = "SECS", default_value_t = 120)]
+        wait: u64,
+    },
     /// List vault access assignments (alias: ls)
     #[command(alias = "ls")]
     List {
@@ -1447,6 +2046,92 @@ pub enum CacheCommands {
     },
 }
 
+/// App Service subcommands (`xv appservice ...`).
+#[derive(Subcommand)]
+pub enum AppserviceCommands {
+    /// Check the app's `@Microsoft.KeyVault(...)` settings against their vaults.
+    ///
+    /// Lists every app setting and connection string holding a Key Vault
+    /// reference and flags references to missing, disabled or expired
+    /// secrets and vaults the app's identity may not read. Exits 52 when any
+    /// reference is broken.
+    Check {
+        /// App Service or Function app name
+        #[arg(long)]
+        app: String,
+        /// Resource group of the app (defaults to config value)
+        #[arg(short, long, visible_alias = "rg")]
+        resource_group: Option<String>,
+        /// Deployment slot to check instead of the production slot
+        #[arg(long)]
+        slot: Option<String>,
+    },
+}
+
+/// Backup subcommands (`xv backup ...`).
+#[derive(Subcommand)]
+pub enum BackupCommands {
+    /// Write a timestamped backup of a vault's secrets and prune old ones.
+    ///
+    /// The archive is `<vault>-<UTC timestamp>.json` in the output directory
+    /// (the `xv vault export` JSON shape plus a manifest), or `.json.age`
+    /// with --encrypt. Restore it with `xv backup restore`. Exits 0 on a complete backup and 44
+    /// when some secrets could not be read; older backups are only pruned
+    /// after a complete one.
+    Vault {
+        /// Vault name
+        name: String,
+        /// Directory to write backups into (created if missing)
+        #[arg(long, value_name = "DIR")]
+        output_dir: std::path::PathBuf,
+        /// Encrypt the archive with age
+        #[arg(long)]
+        encrypt: bool,
+        /// age recipient (`age1...`) to encrypt to; repeatable. Defaults to
+        /// the local backend's recipients (or its key's public key).
+        #[arg(long = "recipient", value_name = "RECIPIENT", requires = "encrypt")]
+        recipients: Vec<String>,
+        /// Include secret values (requires appropriate permissions)
+        #[arg(long)]
+        include_values: bool,
+        /// Keep only the newest N backups of this vault in the directory
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        keep: Option<u32>,
+        /// Only back up secrets in this group
+        #[arg(short, long)]
+        group: Option<String>,
+    },
+    /// Restore secrets from an archive written by `xv backup vault`.
+    ///
+    /// Decrypts `.age` archives with the local backend's key (or
+    /// --identity), checks the manifest, then writes the secrets into the
+    /// target vault. Archives taken without --include-values have nothing
+    /// to restore.
+    Restore {
+        /// Backup archive (`.json` or `.json.age`)
+        archive: std::path::PathBuf,
+        /// Vault to restore into (default: the vault the backup was taken from)
+        #[arg(long, value_name = "VAULT")]
+        to: Option<String>,
+        /// Restore only matching secrets: `group:<name>`, `folder:<path>`,
+        /// or a name glob
+        #[arg(long, value_name = "SELECTOR")]
+        only: Option<String>,
+        /// What to do when a secret already exists in the target vault
+        #[arg(long, value_enum, default_value_t = OnConflict::Skip)]
+        on_conflict: OnConflict,
+        /// Show what would be restored without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// age identity file to decrypt with (default: the local backend's key)
+        #[arg(long, value_name = "PATH")]
+        identity: Option<std::path::PathBuf>,
+        /// Write a JSON transcript of per-secret outcomes to this path
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+    },
+}
+
 /// Maintenance subcommands for the local age-encrypted backend.
 #[derive(Subcommand)]
 pub enum LocalCommands {
@@ -1630,6 +2315,9 @@ pub enum EnvCommands {
         /// Default folder prefix for this env
         #[arg(long)]
         folder: Option<String>,
+        /// Blob key prefix for `xv file` commands in this env (e.g. prod/)
+        #[arg(long)]
+        file_prefix: Option<String>,
         /// Also set this env as `default_env`
         #[arg(long)]
         default: bool,
@@ -1645,7 +2333,7 @@ pub enum EnvCommands {
         #[arg(short, long)]
         force: bool,
     },
-    /// Show the currently-active env (source, backend, vault, resource_group, group, folder)
+    /// Show the currently-active env (source, backend, vault, resource_group, group, folder, file_prefix)
     Show,
     /// Pull secrets to a file format
     Pull {
@@ -1655,14 +2343,33 @@ pub enum EnvCommands {
         /// Filter secrets by group (can be specified multiple times)
         #[arg(short, long)]
         group: Vec<String>,
+        /// Only secrets in this folder or below it (e.g. `dev/api`)
+        #[arg(long)]
+        folder: Option<String>,
+        /// Mapping file of `VARIABLE=secret-name` lines, for secrets whose
+        /// variable has another name
+        #[arg(long, value_name = "FILE")]
+        map: Option<std::path::PathBuf>,
         /// Output file path (writes to stdout if not specified)
         #[arg(short, long)]
         output: Option<String>,
+        /// Shell syntax for plain output: posix or powershell
+        #[arg(long, value_enum, default_value_t = EnvShell::Posix)]
+        shell: EnvShell,
     },
     /// Push .env file to vault as secrets
     Push {
         /// Input .env file path (reads from stdin if not specified)
         file: Option<String>,
+        /// Folder to place the pushed secrets in
+        #[arg(long)]
+        folder: Option<String>,
+        /// Add pushed secrets to this group (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Mapping file of `VARIABLE=secret-name` lines
+        #[arg(long, value_name = "FILE")]
+        map: Option<std::path::PathBuf>,
         /// Overwrite existing secrets
         #[arg(long)]
         overwrite: bool,
@@ -1670,16 +2377,231 @@ pub enum EnvCommands {
 }
 
 #[derive(Subcommand)]
-pub enum GroupCommands {
-    /// List groups and member counts derived from secret metadata (alias: ls)
-    #[command(alias = "ls")]
-    List {
-        /// Bypass the local cache and fetch fresh data
+pub enum DotenvCommands {
+    /// Write secrets to a dotenv file (owner-only permissions)
+    Pull {
+        /// File to write, replacing it; `-` for stdout
+        #[arg(short, long, default_value = ".env")]
+        output: String,
+        /// Only secrets in this folder or below it (e.g. `dev/api`)
         #[arg(long)]
-        no_cache: bool,
+        folder: Option<String>,
+        /// Only secrets in this group (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Mapping file of `VARIABLE=secret-name` lines, for secrets whose
+        /// variable has another name
+        #[arg(long, value_name = "FILE")]
+        map: Option<std::path::PathBuf>,
     },
-}
-
+    /// Store the variables in a dotenv file as secrets
+    Push {
+        /// File to read; `-` for stdin
+        #[arg(short, long, default_value = ".env")]
+        input: String,
+        /// Folder to place the pushed secrets in
+        #[arg(long)]
+        folder: Option<String>,
+        /// Add pushed secrets to this group (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Mapping file of `VARIABLE=secret-name` lines
+        #[arg(long, value_name = "FILE")]
+        map: Option<std::path::PathBuf>,
+        /// Overwrite existing secrets
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum FolderCommands {
+    /// Move every secret under OLD (and its subfolders) to NEW, keeping the
+    /// names; the same as `xv mv OLD/ NEW/`
+    Rename {
+        /// Folder to rename, e.g. app/database
+        old: String,
+        /// New folder path, or '/' for the vault root
+        new: String,
+        /// Print the full move plan without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DirenvCommands {
+    /// Print the `.envrc` snippet that runs `xv direnv export`
+    Init {
+        /// Only secrets in this group (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Only secrets in this folder or below it
+        #[arg(long)]
+        folder: Option<String>,
+        /// Append the snippet to `.envrc` in the current directory instead
+        #[arg(long)]
+        write: bool,
+    },
+    /// Print `export NAME=value` lines for direnv to evaluate
+    Export {
+        /// Only secrets in this group (repeatable; default: the env
+        /// profile's group, else every secret)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Only secrets in this folder or below it
+        #[arg(long)]
+        folder: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SshCommands {
+    /// Generate an ed25519 key pair, store the private key as secret NAME
+    /// and print the public key
+    Keygen {
+        /// Secret name for the private key
+        name: String,
+        /// Key comment (default: `<name>@xv`)
+        #[arg(short = 'C', long)]
+        comment: Option<String>,
+        /// Replace an existing secret with a new key
+        #[arg(long)]
+        force: bool,
+    },
+    /// Add the private key in secret NAME to the running ssh-agent; the key
+    /// is piped to `ssh-add`, never written to disk
+    Load {
+        /// Secret name holding the private key
+        name: String,
+        /// Remove the key from the agent after this long (`ssh-add -t`,
+        /// e.g. `3600` or `1h`)
+        #[arg(short = 't', long)]
+        lifetime: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BundleCommands {
+    /// Create a bundle, or add/change/remove keys in an existing one
+    Set {
+        /// Bundle (secret) name
+        name: String,
+        /// Key and value, `key=value` (repeatable). Avoid for secret values:
+        /// they appear in shell history
+        #[arg(long = "kv", value_name = "KEY=VALUE", value_parser = parse_key_val::<String, String>, required_unless_present = "unset")]
+        entries: Vec<(String, String)>,
+        /// Remove a key (repeatable)
+        #[arg(long, value_name = "KEY")]
+        unset: Vec<String>,
+        /// Drop the keys not given with --kv instead of keeping them
+        #[arg(long, conflicts_with = "unset")]
+        replace: bool,
+    },
+    /// Print a bundle as JSON/YAML or `key=value` lines, or one key's value
+    Get {
+        /// Bundle (secret) name
+        name: String,
+        /// Print only this key's value
+        #[arg(long, value_name = "KEY")]
+        field: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GroupCommands {
+    /// List groups and member counts derived from secret metadata (alias: ls)
+    #[command(alias = "ls")]
+    List {
+        /// Bypass the local cache and fetch fresh data
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// List the secrets in a group
+    Show {
+        /// Group name
+        group: String,
+    },
+    /// Rename a group on every secret in it
+    Rename {
+        /// Current group name
+        old: String,
+        /// New group name
+        new: String,
+        /// Print the secrets that would change without updating them
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+    /// Remove a group from every secret in it (the secrets are kept)
+    Delete {
+        /// Group name
+        group: String,
+        /// Print the secrets that would change without updating them
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+    /// Add secrets to a group
+    Add {
+        /// Group name
+        group: String,
+        /// Secrets to add (name or folder/name)
+        #[arg(required_unless_present = "filter")]
+        secrets: Vec<String>,
+        /// Also add every secret whose name matches this glob
+        #[arg(long, value_name = "GLOB")]
+        filter: Option<String>,
+        /// Print the secrets that would change without updating them
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip the confirmation prompt shown for --filter
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+    /// Remove secrets from a group
+    Remove {
+        /// Group name
+        group: String,
+        /// Secrets to remove (name or folder/name)
+        #[arg(required_unless_present = "filter")]
+        secrets: Vec<String>,
+        /// Also remove every secret whose name matches this glob
+        #[arg(long, value_name = "GLOB")]
+        filter: Option<String>,
+        /// Print the secrets that would change without updating them
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip the confirmation prompt shown for --filter
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+/// `xv index` subcommands.
+#[derive(Subcommand)]
+pub enum IndexCommands {
+    /// Re-list the current vault and rebuild its search index
+    Rebuild,
+    /// Show when the current vault's search index was built and its size
+    Status,
+}
+
+/// `xv name` subcommands.
+#[derive(Subcommand)]
+pub enum NameCommands {
+    /// List secrets whose stored name differs from their name (sanitized or
+    /// hashed). Use `--format csv` for a spreadsheet-ready mapping.
+    Map,
+}
+
 #[derive(Subcommand)]
 pub enum ScanCommands {
     /// Install a pre-commit hook that runs `xv scan --staged --hook`.
@@ -1691,15 +2613,111 @@ pub enum ScanCommands {
     Uninstall,
 }
 
+/// Keys accepted in the config `[output]` table, one per command that
+/// renders formatted output (see [`Commands::output_key`]).
+pub const OUTPUT_DEFAULT_KEYS: &[&str] = &[
+    "list",
+    "find",
+    "get",
+    "history",
+    "audit",
+    "scan",
+    "lint",
+    "expiry_report",
+    "share_list",
+    "group_list",
+    "name_map",
+    "type_list",
+    "type_show",
+    "context_list",
+    "env_list",
+    "config_show",
+    "vault_list",
+    "vault_info",
+    "vault_share_list",
+    "file_list",
+];
+
+impl Commands {
+    /// The `[output]` config key whose format applies to this command when
+    /// `--format` is not given, or `None` for commands without formatted
+    /// output.
+    pub fn output_key(&self) -> Option<&'static str> {
+        let key = match self {
+            Commands::List { .. } => "list",
+            Commands::Find { .. } => "find",
+            Commands::Get { .. } => "get",
+            Commands::History { .. } => "history",
+            Commands::Audit { .. } => "audit",
+            Commands::Scan { .. } => "scan",
+            Commands::Lint { .. } => "lint",
+            Commands::ExpiryReport { .. } => "expiry_report",
+            Commands::Share {
+                command: ShareCommands::List { .. },
+            } => "share_list",
+            Commands::Group {
+                command: GroupCommands::List { .. },
+            } => "group_list",
+            Commands::Name {
+                command: NameCommands::Map,
+            } => "name_map",
+            Commands::Type {
+                command: TypeCommands::List,
+            } => "type_list",
+            Commands::Type {
+                command: TypeCommands::Show { .. },
+            } => "type_show",
+            Commands::Context {
+                command: ContextCommands::List | ContextCommands::Ls,
+            } => "context_list",
+            Commands::Env {
+                command: Some(EnvCommands::List),
+                ..
+            } => "env_list",
+            Commands::Config {
+                command: ConfigCommands::Show { .. },
+            } => "config_show",
+            Commands::Vault {
+                command: VaultCommands::List { .. },
+            } => "vault_list",
+            Commands::Vault {
+                command: VaultCommands::Info { .. },
+            } => "vault_info",
+            Commands::Vault {
+                command:
+                    VaultCommands::Share {
+                        command: VaultShareCommands::List { .. },
+                    },
+            } => "vault_share_list",
+            #[cfg(feature = "file-ops")]
+            Commands::File {
+                command: crate::cli::file::FileCommands::List { .. },
+            } => "file_list",
+            _ => return None,
+        };
+        Some(key)
+    }
+}
+
 impl Cli {
     pub async fn execute(
         self,
         mut config: Config,
         registry: Option<&crate::backend::BackendRegistry>,
     ) -> Result<()> {
-        let resolved = self.format.resolve_for_stdout();
+        // An explicit --format wins; otherwise the command's `[output]`
+        // default from config applies, and counts as explicit.
+        let format = match self.format {
+            OutputFormat::Auto => self
+                .command
+                .output_key()
+                .and_then(|key| config.output_default(key))
+                .unwrap_or(OutputFormat::Auto),
+            explicit => explicit,
+        };
+        let resolved = format.resolve_for_stdout();
         config.runtime_output_format = resolved;
-        config.format_explicit = !matches!(self.format, OutputFormat::Auto);
+        config.format_explicit = !matches!(format, OutputFormat::Auto);
         config.output_json = matches!(resolved, OutputFormat::Json);
 
         // Wire template string
@@ -1725,6 +2743,8 @@ impl Cli {
             config.no_color = true;
             crate::utils::output::disable_color();
         }
+        config.no_pager = self.no_pager;
+        crate::utils::pager::set_command(config.pager.clone());
 
         // Warn if --template given without --format template
         if config.template.is_some() && resolved != OutputFormat::Template {
@@ -1790,26 +2810,45 @@ impl Cli {
                 .await
             }
             Commands::Get {
-                name,
+                names,
+                group,
                 raw,
+                clipboard,
                 version,
                 field,
                 record,
+                qr,
+                offline,
             } => {
+                if names.len() > 1 || group.is_some() {
+                    if version.is_some() || field.is_some() || record || qr || offline || clipboard
+                    {
+                        return Err(CrosstacheError::invalid_argument(
+                            "--version, --field, --record, --qr, --offline and --clipboard \
+                             apply to a single secret",
+                        ));
+                    }
+                    return crate::cli::secret_ops::execute_secret_get_many(
+                        names, group, format, &config, registry,
+                    )
+                    .await;
+                }
+                let name = names.into_iter().next().unwrap_or_default();
+                let output = if raw || matches!(format, OutputFormat::Raw) {
+                    Some(crate::config::GetOutput::Raw)
+                } else if clipboard {
+                    Some(crate::config::GetOutput::Clipboard)
+                } else {
+                    None
+                };
                 crate::cli::secret_ops::execute_secret_get_direct(
-                    &name,
-                    raw,
-                    version,
-                    field,
-                    record,
-                    self.format,
-                    config,
-                    registry,
+                    &name, output, qr, offline, version, field, record, format, config, registry,
                 )
                 .await
             }
             Commands::Find {
                 pattern,
+                mode,
                 in_fields,
                 limit,
                 min_score,
@@ -1817,28 +2856,22 @@ impl Cli {
                 all_vaults,
                 names_only,
                 filter,
+                no_cache,
             } => {
                 crate::cli::secret_ops::execute_secret_find_direct(
-                    pattern,
-                    in_fields,
-                    limit,
-                    min_score,
-                    folder,
-                    all_vaults,
-                    names_only,
-                    self.format,
-                    filter,
-                    config,
-                    registry,
+                    pattern, mode, in_fields, limit, min_score, folder, all_vaults, names_only,
+                    format, filter, no_cache, config, registry,
                 )
                 .await
             }
             Commands::List {
                 path,
+                folder,
                 long,
                 recursive,
                 group,
                 all,
+                disabled_only,
                 expiring,
                 expired,
                 no_cache,
@@ -1850,16 +2883,19 @@ impl Cli {
                 deleted,
                 type_filter,
                 filter,
+                tag,
+                tag_exists,
+                size,
             } => {
                 let pagination = crate::utils::pagination::Pagination::from_args(page, page_size)?;
-                let pager = pager.map(PagerWhen::wants_pager).unwrap_or(false);
+                let pager = PagerWhen::resolve(pager, &config);
                 if deleted {
                     crate::cli::secret_ops::execute_deleted_secret_list(
                         pagination, pager, names_only, long, sort, filter, config, registry,
                     )
                     .await
                 } else {
-                    let path = match path {
+                    let path = match path.or(folder) {
                         Some(raw) => {
                             let trimmed = raw.trim_end_matches('/').to_string();
                             if !trimmed.is_empty() {
@@ -1872,18 +2908,23 @@ impl Cli {
                     crate::cli::secret_ops::execute_secret_list_direct(
                         path,
                         group,
-                        all,
+                        EnabledFilter::from_flags(all, disabled_only),
                         expiring,
                         expired,
                         no_cache,
                         pagination,
                         pager,
                         names_only,
-                        long,
+                        long || size,
                         recursive,
                         sort,
                         type_filter,
                         filter,
+                        crate::cli::secret_ops::TagQuery {
+                            equals: tag,
+                            exists: tag_exists,
+                        },
+                        size,
                         config,
                         registry,
                     )
@@ -1931,32 +2972,76 @@ impl Cli {
                 length,
                 charset,
                 generator,
+                command,
+                webhook,
                 native,
                 show_value,
                 force,
             } => {
+                use crate::secret::rotate::{
+                    CommandRotator, GenerateRotator, Rotator, ScriptRotator, WebhookRotator,
+                };
+                let rotator: Box<dyn Rotator> = if let Some(url) = webhook {
+                    Box::new(WebhookRotator::new(url)?)
+                } else if let Some(command) = command {
+                    Box::new(CommandRotator { command })
+                } else if let Some(path) = generator {
+                    Box::new(ScriptRotator { path, length })
+                } else {
+                    Box::new(GenerateRotator { length, charset })
+                };
                 crate::cli::secret_ops::execute_secret_rotate_direct(
-                    &name, vault, length, charset, generator, native, show_value, force, config,
-                    registry,
+                    &name, vault, rotator, native, show_value, force, config, registry,
                 )
                 .await
             }
             Commands::Gen {
                 length,
                 charset,
+                passphrase,
+                words,
+                separator,
+                encoding,
                 save,
                 vault,
                 raw,
                 meta,
             } => {
+                let separator = separator.unwrap_or_else(|| "-".to_string());
+                let generator = if passphrase {
+                    Some(crate::secret::generate::Generator::Passphrase {
+                        words: words.unwrap_or(6),
+                        separator: &separator,
+                    })
+                } else {
+                    encoding.map(|encoding| crate::secret::generate::Generator::Bytes {
+                        count: length.unwrap_or(32),
+                        encoding,
+                    })
+                };
                 crate::cli::system_ops::execute_gen_command(
-                    length, charset, save, vault, raw, meta, config, registry,
+                    length, charset, generator, save, vault, raw, meta, config, registry,
+                )
+                .await
+            }
+            Commands::Scaffold {
+                template,
+                prefix,
+                vault,
+                values,
+                yes,
+                force,
+                dry_run,
+            } => {
+                crate::cli::scaffold_ops::execute_scaffold(
+                    template, prefix, vault, values, yes, force, dry_run, config, registry,
                 )
                 .await
             }
             Commands::Run {
                 vault,
                 group,
+                folder,
                 include,
                 exclude,
                 no_masking,
@@ -1967,6 +3052,7 @@ impl Cli {
                 crate::cli::secret_ops::execute_secret_run_direct(
                     vault,
                     group,
+                    folder,
                     include,
                     exclude,
                     no_masking,
@@ -1978,6 +3064,19 @@ impl Cli {
                 )
                 .await
             }
+            Commands::Render {
+                template,
+                out,
+                vault,
+                group,
+                folder,
+                strict,
+            } => {
+                crate::cli::render_ops::execute_render(
+                    &template, out, vault, group, folder, strict, &config, registry,
+                )
+                .await
+            }
             Commands::Inject {
                 vault,
                 template,
@@ -2050,6 +3149,9 @@ impl Cli {
                 )
                 .await
             }
+            Commands::Edit { name, extension } => {
+                crate::cli::edit_ops::execute_edit_command(&name, extension, &config).await
+            }
             Commands::Mv {
                 operands,
                 filter,
@@ -2077,19 +3179,30 @@ impl Cli {
                 crate::cli::mv_ops::execute_mv(source, dest, filter, dry_run, yes, config, registry)
                     .await
             }
+            Commands::Folder { command, path } => {
+                crate::cli::folder_ops::execute_folder_command(command, path, config, registry)
+                    .await
+            }
             Commands::Diff {
                 vault1,
                 vault2,
                 show_values,
+                hash_values,
+                no_values,
                 group,
             } => {
+                use crate::cli::secret_ops::DiffValues;
+                let values = if show_values {
+                    DiffValues::Show
+                } else if hash_values {
+                    DiffValues::Hash
+                } else if no_values {
+                    DiffValues::Skip
+                } else {
+                    DiffValues::Compare
+                };
                 crate::cli::secret_ops::execute_diff_command(
-                    &vault1,
-                    &vault2,
-                    show_values,
-                    group,
-                    config,
-                    registry,
+                    &vault1, &vault2, values, group, config, registry,
                 )
                 .await
             }
@@ -2116,13 +3229,56 @@ impl Cli {
                 )
                 .await
             }
-            Commands::Purge { name, force } => {
-                crate::cli::secret_ops::execute_secret_purge_direct(&name, force, config, registry)
+            Commands::Purge {
+                name,
+                all,
+                group,
+                folder,
+                force,
+                when_eligible,
+            } => match name {
+                Some(name) if !all => {
+                    crate::cli::secret_ops::execute_secret_purge_direct(
+                        &name,
+                        force,
+                        when_eligible,
+                        config,
+                        registry,
+                    )
                     .await
-            }
-            Commands::Restore { name } => {
-                crate::cli::secret_ops::execute_secret_restore_direct(&name, config, registry).await
-            }
+                }
+                _ => {
+                    crate::cli::secret_ops::execute_deleted_bulk(
+                        crate::cli::secret_ops::DeletedBulkAction::Purge,
+                        group,
+                        folder,
+                        config,
+                        registry,
+                    )
+                    .await
+                }
+            },
+            Commands::Restore {
+                name,
+                all,
+                group,
+                folder,
+            } => match name {
+                Some(name) if !all => {
+                    crate::cli::secret_ops::execute_secret_restore_direct(&name, config, registry)
+                        .await
+                }
+                _ => {
+                    crate::cli::secret_ops::execute_deleted_bulk(
+                        crate::cli::secret_ops::DeletedBulkAction::Restore,
+                        group,
+                        folder,
+                        config,
+                        registry,
+                    )
+                    .await
+                }
+            },
             Commands::Parse {
                 connection_string,
                 format,
@@ -2151,11 +3307,39 @@ impl Cli {
             Commands::Context { command } => {
                 crate::cli::config_ops::execute_context_command(command, config).await
             }
-            Commands::Env { command } => {
-                crate::cli::config_ops::execute_env_command(command, config, registry).await
+            Commands::Env {
+                command: Some(command),
+                ..
+            } => crate::cli::config_ops::execute_env_command(command, config, registry).await,
+            Commands::Env {
+                command: None,
+                group,
+                folder,
+                shell,
+            } => {
+                crate::cli::config_ops::execute_env_export(group, folder, shell, &config, registry)
+                    .await
+            }
+            Commands::Dotenv { command } => {
+                crate::cli::config_ops::execute_dotenv_command(command, &config, registry).await
+            }
+            Commands::Direnv { command } => {
+                crate::cli::direnv_ops::execute_direnv_command(command, &config, registry).await
+            }
+            Commands::Ssh { command } => {
+                crate::cli::ssh_ops::execute_ssh_command(command, &config).await
+            }
+            Commands::Bundle { command } => {
+                crate::cli::bundle_ops::execute_bundle_command(command, format, &config).await
             }
             Commands::Group { command } => {
-                crate::cli::secret_ops::execute_group_command(command, config, registry).await
+                crate::cli::group_ops::execute_group_command(command, config, registry).await
+            }
+            Commands::Index { command } => {
+                crate::cli::index_ops::execute_index_command(command, &config, registry).await
+            }
+            Commands::Name { command } => {
+                crate::cli::name_ops::execute_name_command(command, &config, registry).await
             }
             Commands::Audit {
                 name,
@@ -2175,7 +3359,45 @@ impl Cli {
                 )
                 .await
             }
-            Commands::Init => crate::cli::system_ops::execute_init_command(config).await,
+            Commands::Init {
+                subscription,
+                tenant,
+                resource_group,
+                location,
+                vault,
+                create_vault,
+                storage_account,
+                container,
+                yes,
+                check,
+                blob_only,
+            } => {
+                if blob_only {
+                    let storage = storage_account.map(|account| (account, container));
+                    return crate::cli::system_ops::execute_init_blob_only(storage, config).await;
+                }
+                let flags_given = subscription.is_some()
+                    || tenant.is_some()
+                    || resource_group.is_some()
+                    || location.is_some()
+                    || vault.is_some()
+                    || storage_account.is_some();
+                let unattended = flags_given.then(|| crate::config::init::UnattendedInit {
+                    subscription_id: subscription.unwrap_or_default(),
+                    tenant_id: tenant,
+                    resource_group: resource_group.unwrap_or_default(),
+                    location: location.unwrap_or_default(),
+                    vault: vault.unwrap_or_default(),
+                    create_vault,
+                    storage_account,
+                    container,
+                });
+                if check {
+                    crate::cli::system_ops::execute_init_check_command(unattended, config).await
+                } else {
+                    crate::cli::system_ops::execute_init_command(unattended, yes, config).await
+                }
+            }
             Commands::Info {
                 resource,
                 resource_type,
@@ -2196,8 +3418,44 @@ impl Cli {
             Commands::Completion { shell } => {
                 crate::cli::system_ops::execute_completion_command(shell).await
             }
-            Commands::Whoami => {
-                crate::cli::system_ops::execute_whoami_command(config, registry).await
+            Commands::Whoami { verbose } => {
+                crate::cli::system_ops::execute_whoami_command(verbose, config, registry).await
+            }
+            Commands::Doctor => crate::cli::system_ops::execute_doctor_command(config).await,
+            Commands::Bench {
+                secrets,
+                concurrency,
+                list_runs,
+                vault,
+            } => {
+                crate::cli::bench_ops::execute_bench_command(
+                    secrets,
+                    concurrency,
+                    list_runs,
+                    vault,
+                    format,
+                    &config,
+                    registry,
+                )
+                .await
+            }
+            Commands::Selftest {
+                resource_group,
+                location,
+                cleanup,
+                skip_files,
+                report,
+            } => {
+                crate::cli::selftest_ops::execute_selftest_command(
+                    resource_group,
+                    location,
+                    cleanup,
+                    skip_files,
+                    report,
+                    &config,
+                    registry,
+                )
+                .await
             }
             // Upgrade does not need Azure config — only talks to GitHub API
             Commands::Upgrade { check, force } => {
@@ -2206,6 +3464,13 @@ impl Cli {
             Commands::Cache { command } => {
                 crate::cli::config_ops::execute_cache_command(command, config).await
             }
+            Commands::Appservice { command } => {
+                crate::cli::appservice_ops::execute_appservice_command(command, &config, registry)
+                    .await
+            }
+            Commands::Backup { command } => {
+                crate::cli::backup_ops::execute_backup_command(command, &config, registry).await
+            }
             Commands::Local { command } => {
                 crate::cli::local_ops::execute_local_command(command, config).await
             }
@@ -2238,14 +3503,29 @@ impl Cli {
                 command,
             } => {
                 crate::cli::scan_ops::execute_scan_command(
-                    paths,
-                    staged,
-                    all,
-                    hook,
-                    all_vaults,
-                    command,
-                    self.format,
-                    config,
+                    paths, staged, all, hook, all_vaults, command, format, config, registry,
+                )
+                .await
+            }
+            Commands::ExpiryReport {
+                days,
+                all_vaults,
+                max_age,
+            } => {
+                crate::cli::expiry_ops::execute_expiry_report_command(
+                    days, all_vaults, max_age, format, &config, registry,
+                )
+                .await
+            }
+            Commands::Lint {
+                dir,
+                fail_on_unused,
+            } => {
+                crate::cli::lint_ops::execute_lint_command(
+                    dir,
+                    fail_on_unused,
+                    format,
+                    &config,
                     registry,
                 )
                 .await
@@ -2259,6 +3539,8 @@ impl Cli {
                 on_conflict,
                 force_replace,
                 concurrency,
+                report,
+                resume,
             } => {
                 crate::cli::migrate_ops::execute_migrate(
                     from,
@@ -2269,12 +3551,30 @@ impl Cli {
                     on_conflict,
                     force_replace,
                     concurrency,
+                    report,
+                    resume,
                     config,
                 )
                 .await
             }
             #[cfg(feature = "tui")]
             Commands::Tui => crate::tui::run_tui(config, registry).await,
+            Commands::DevProxy {
+                port,
+                group,
+                folder,
+                ttl,
+                audit_log,
+            } => {
+                let options = crate::cli::dev_proxy_ops::DevProxyOptions {
+                    port,
+                    groups: group,
+                    folder,
+                    ttl: std::time::Duration::from_secs(ttl),
+                    audit_log,
+                };
+                crate::cli::dev_proxy_ops::execute_dev_proxy(options, &config, registry).await
+            }
             #[cfg(feature = "ui")]
             Commands::Ui { port, no_open } => {
                 crate::web::run_web(config, registry, port, no_open).await
@@ -2288,6 +3588,27 @@ mod tests {
     use super::*;
     use crate::cli::helpers::generate_random_value;
 
+    #[test]
+    fn test_output_key_maps_commands_to_config_keys() {
+        let key = |args: &[&str]| Cli::try_parse_from(args).unwrap().command.output_key();
+        assert_eq!(key(&["xv", "list"]), Some("list"));
+        assert_eq!(key(&["xv", "get", "db-password"]), Some("get"));
+        assert_eq!(key(&["xv", "vault", "list"]), Some("vault_list"));
+        assert_eq!(key(&["xv", "config", "show"]), Some("config_show"));
+        assert_eq!(key(&["xv", "config", "path"]), None);
+        for args in [
+            &["xv", "find", "x"][..],
+            &["xv", "vault", "info", "kv"],
+            &["xv", "scan"],
+            &["xv", "lint"],
+            &["xv", "expiry-report", "--days", "7"],
+            &["xv", "name", "map"],
+        ] {
+            let k = key(args).unwrap();
+            assert!(OUTPUT_DEFAULT_KEYS.contains(&k), "{k}");
+        }
+    }
+
     #[test]
     fn test_charset_default_is_alphanumeric() {
         assert_eq!(CharsetType::default(), CharsetType::Alphanumeric);
@@ -2428,7 +3749,697 @@ mod tests {
     }
 
     #[test]
-    fn test_scan_staged_and_all_conflict() {
+    fn test_no_pager_overrides_flag_and_config() {
+        let cli = Cli::try_parse_from(["xv", "--no-pager", "list", "--pager"]).unwrap();
+        assert!(cli.no_pager);
+
+        let mut config = Config {
+            paging: true,
+            ..Config::default()
+        };
+        assert!(PagerWhen::resolve(None, &config));
+        assert!(!PagerWhen::resolve(Some(PagerWhen::Never), &config));
+        config.paging = false;
+        assert!(PagerWhen::resolve(Some(PagerWhen::Auto), &config));
+        config.no_pager = true;
+        assert!(!PagerWhen::resolve(Some(PagerWhen::Always), &config));
+    }
+
+    #[test]
+    fn test_secret_list_enabled_filters_are_exclusive() {
+        assert!(Cli::try_parse_from(["xv", "list", "--all", "--disabled-only"]).is_err());
+        // Enabled-only is the default listing, so there is no flag for it.
+        assert!(Cli::try_parse_from(["xv", "list", "--enabled-only"]).is_err());
+
+        let cli = Cli::try_parse_from(["xv", "list", "--disabled-only"]).unwrap();
+        match cli.command {
+            Commands::List {
+                all, disabled_only, ..
+            } => assert_eq!(
+                EnabledFilter::from_flags(all, disabled_only),
+                EnabledFilter::DisabledOnly
+            ),
+            _ => panic!("Expected List command"),
+        }
+        assert!(!EnabledFilter::DisabledOnly.admits(true));
+        assert!(EnabledFilter::EnabledOnly.admits(true));
+        assert!(EnabledFilter::All.admits(false));
+    }
+
+    #[test]
+    fn test_init_accepts_unattended_flags() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "init",
+            "--subscription",
+            "00000000-0000-0000-0000-000000000000",
+            "--resource-group",
+            "rg",
+            "--location",
+            "eastus",
+            "--vault",
+            "kv-dev",
+            "--storage-account",
+            "stdev01",
+            "--yes",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Init {
+                vault,
+                storage_account,
+                container,
+                create_vault,
+                yes,
+                ..
+            } => {
+                assert_eq!(vault.as_deref(), Some("kv-dev"));
+                assert_eq!(storage_account.as_deref(), Some("stdev01"));
+                assert_eq!(container, crate::config::init::DEFAULT_CONTAINER);
+                assert!(!create_vault);
+                assert!(yes);
+            }
+            _ => panic!("Expected init command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "init", "--create-vault"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "init", "--check", "--vault", "kv-dev"]).is_ok());
+        assert!(Cli::try_parse_from(["xv", "init", "--check", "--yes"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "init", "--blob-only"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["xv", "init", "--blob-only", "--storage-account", "stdev01"])
+                .is_ok()
+        );
+        assert!(Cli::try_parse_from(["xv", "init", "--blob-only", "--vault", "kv-dev"]).is_err());
+    }
+
+    #[test]
+    fn test_secret_list_size_conflicts_with_deleted_and_names_only() {
+        for args in [
+            ["xv", "list", "--size", "--deleted"],
+            ["xv", "list", "--size", "--names-only"],
+        ] {
+            assert!(
+                Cli::try_parse_from(args).is_err(),
+                "{args:?} should conflict"
+            );
+        }
+        let cli = Cli::try_parse_from(["xv", "list", "--size"]).unwrap();
+        match cli.command {
+            Commands::List { size, .. } => assert!(size),
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_backup_vault_args_parse() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "backup",
+            "vault",
+            "kv-app",
+            "--output-dir",
+            "backups",
+            "--encrypt",
+            "--include-values",
+            "--keep",
+            "14",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Backup {
+                command:
+                    BackupCommands::Vault {
+                        name,
+                        encrypt,
+                        include_values,
+                        keep,
+                        ..
+                    },
+            } => {
+                assert_eq!(name, "kv-app");
+                assert!(encrypt && include_values);
+                assert_eq!(keep, Some(14));
+            }
+            _ => panic!("Expected backup vault command"),
+        }
+
+        // --keep 0 would prune the backup just written.
+        assert!(Cli::try_parse_from([
+            "xv",
+            "backup",
+            "vault",
+            "kv",
+            "--output-dir",
+            "b",
+            "--keep",
+            "0"
+        ])
+        .is_err());
+        // A recipient is meaningless without --encrypt.
+        assert!(Cli::try_parse_from([
+            "xv",
+            "backup",
+            "vault",
+            "kv",
+            "--output-dir",
+            "b",
+            "--recipient",
+            "age1x"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_vault_share_invite_args_parse() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "vault",
+            "share",
+            "invite",
+            "kv-prod",
+            "guest@partner.com",
+            "--level",
+            "reader",
+            "--no-email",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Vault {
+                command:
+                    VaultCommands::Share {
+                        command:
+                            VaultShareCommands::Invite {
+                                vault_name,
+                                email,
+                                level,
+                                no_email,
+                                wait,
+                                redirect_url,
+                                ..
+                            },
+                    },
+            } => {
+                assert_eq!(vault_name, "kv-prod");
+                assert_eq!(email, "guest@partner.com");
+                assert_eq!(level, "reader");
+                assert!(no_email);
+                assert_eq!(wait, 120);
+                assert_eq!(redirect_url, "https://myapps.microsoft.com");
+            }
+            _ => panic!("Expected vault share invite command"),
+        }
+    }
+
+    #[test]
+    fn test_exec_alias_parses_as_run_with_folder() {
+        let cli = Cli::try_parse_from([
+            "xv", "exec", "--group", "backend", "--folder", "prod/db", "--", "npm", "start",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Run {
+                group,
+                folder,
+                command,
+                ..
+            } => {
+                assert_eq!(group, vec!["backend"]);
+                assert_eq!(folder.as_deref(), Some("prod/db"));
+                assert_eq!(command, vec!["npm", "start"]);
+            }
+            _ => panic!("Expected run command"),
+        }
+    }
+
+    #[test]
+    fn test_render_args_parse() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "render",
+            "app.conf.tpl",
+            "-o",
+            "app.conf",
+            "-g",
+            "backend",
+            "--folder",
+            "prod",
+            "--strict",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Render {
+                template,
+                out,
+                group,
+                folder,
+                strict,
+                ..
+            } => {
+                assert_eq!(template, "app.conf.tpl");
+                assert_eq!(out.as_deref(), Some("app.conf"));
+                assert_eq!(group, vec!["backend"]);
+                assert_eq!(folder.as_deref(), Some("prod"));
+                assert!(strict);
+            }
+            _ => panic!("Expected render command"),
+        }
+    }
+
+    #[test]
+    fn test_vault_cache_warm_and_get_offline_args_parse() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "vault",
+            "cache",
+            "warm",
+            "--group",
+            "break-glass",
+            "--protect",
+            "key",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Vault {
+                command:
+                    VaultCommands::Cache {
+                        command:
+                            VaultCacheCommands::Warm {
+                                vault_name,
+                                group,
+                                expires,
+                                protect,
+                            },
+                    },
+            } => {
+                assert_eq!(vault_name, None);
+                assert_eq!(group, "break-glass");
+                assert_eq!(expires, "72h");
+                assert_eq!(protect, crate::cache::offline::Protection::Key);
+            }
+            _ => panic!("Expected vault cache warm command"),
+        }
+
+        let cli = Cli::try_parse_from(["xv", "get", "db-password", "--offline", "--raw"]).unwrap();
+        match cli.command {
+            Commands::Get { offline, raw, .. } => assert!(offline && raw),
+            _ => panic!("Expected get command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "get", "db", "--offline", "--version", "v1"]).is_err());
+    }
+
+    #[test]
+    fn test_get_accepts_several_names_or_a_group() {
+        let cli = Cli::try_parse_from(["xv", "get", "db-host", "db-user", "db-password"]).unwrap();
+        match cli.command {
+            Commands::Get { names, group, .. } => {
+                assert_eq!(names, ["db-host", "db-user", "db-password"]);
+                assert!(group.is_none());
+            }
+            _ => panic!("Expected get command"),
+        }
+        let cli = Cli::try_parse_from(["xv", "get", "--group", "api"]).unwrap();
+        match cli.command {
+            Commands::Get { names, group, .. } => {
+                assert!(names.is_empty());
+                assert_eq!(group.as_deref(), Some("api"));
+            }
+            _ => panic!("Expected get command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "get"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "get", "--group", "api", "--qr"]).is_err());
+    }
+
+    #[test]
+    fn test_bundle_set_parses_repeated_kv() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "bundle",
+            "set",
+            "app-config",
+            "--kv",
+            "host=db1",
+            "--kv",
+            "port=5432",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Bundle {
+                command: BundleCommands::Set { name, entries, .. },
+            } => {
+                assert_eq!(name, "app-config");
+                assert_eq!(
+                    entries,
+                    [
+                        ("host".to_string(), "db1".to_string()),
+                        ("port".to_string(), "5432".to_string())
+                    ]
+                );
+            }
+            _ => panic!("Expected bundle set command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "bundle", "set", "app-config"]).is_err());
+    }
+
+    #[test]
+    fn test_dotenv_commands() {
+        let cli = Cli::try_parse_from(["xv", "dotenv", "pull", "--folder", "dev/api"]).unwrap();
+        match cli.command {
+            Commands::Dotenv {
+                command: DotenvCommands::Pull { output, folder, .. },
+            } => {
+                assert_eq!(output, ".env");
+                assert_eq!(folder.as_deref(), Some("dev/api"));
+            }
+            _ => panic!("Expected dotenv pull command"),
+        }
+        let cli = Cli::try_parse_from([
+            "xv",
+            "dotenv",
+            "push",
+            "-i",
+            ".env.local",
+            "--map",
+            "xv.map",
+            "--overwrite",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Dotenv {
+                command:
+                    DotenvCommands::Push {
+                        input,
+                        map,
+                        overwrite,
+                        ..
+                    },
+            } => {
+                assert_eq!(input, ".env.local");
+                assert_eq!(map, Some(std::path::PathBuf::from("xv.map")));
+                assert!(overwrite);
+            }
+            _ => panic!("Expected dotenv push command"),
+        }
+    }
+
+    #[test]
+    fn test_direnv_commands() {
+        let cli = Cli::try_parse_from(["xv", "direnv", "init", "-g", "api", "--write"]).unwrap();
+        match cli.command {
+            Commands::Direnv {
+                command: DirenvCommands::Init { group, write, .. },
+            } => {
+                assert_eq!(group, vec!["api".to_string()]);
+                assert!(write);
+            }
+            _ => panic!("Expected direnv init command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "direnv", "export", "--folder", "dev"]).is_ok());
+    }
+
+    #[test]
+    fn test_folder_commands() {
+        let cli = Cli::try_parse_from(["xv", "folders", "app"]).unwrap();
+        match cli.command {
+            Commands::Folder {
+                command: None,
+                path,
+            } => assert_eq!(path.as_deref(), Some("app")),
+            _ => panic!("Expected folder tree command"),
+        }
+        let cli = Cli::try_parse_from(["xv", "folder", "rename", "app/db", "app/database", "-y"])
+            .unwrap();
+        match cli.command {
+            Commands::Folder {
+                command: Some(FolderCommands::Rename { old, new, yes, .. }),
+                ..
+            } => {
+                assert_eq!(old, "app/db");
+                assert_eq!(new, "app/database");
+                assert!(yes);
+            }
+            _ => panic!("Expected folder rename command"),
+        }
+        let cli = Cli::try_parse_from(["xv", "ls", "--folder", "app/database"]).unwrap();
+        match cli.command {
+            Commands::List { folder, path, .. } => {
+                assert_eq!(folder.as_deref(), Some("app/database"));
+                assert_eq!(path, None);
+            }
+            _ => panic!("Expected list command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "ls", "app", "--folder", "prod"]).is_err());
+    }
+
+    #[test]
+    fn test_list_tag_filters() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "ls",
+            "--tag",
+            "owner=team-data",
+            "--tag",
+            "env=prod",
+            "--tag-exists",
+            "cost-center",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::List {
+                tag, tag_exists, ..
+            } => {
+                assert_eq!(
+                    tag,
+                    vec![
+                        ("owner".to_string(), "team-data".to_string()),
+                        ("env".to_string(), "prod".to_string())
+                    ]
+                );
+                assert_eq!(tag_exists, vec!["cost-center"]);
+            }
+            _ => panic!("Expected List command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "ls", "--tag", "owner"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "ls", "--deleted", "--tag-exists", "env"]).is_err());
+    }
+
+    #[test]
+    fn test_dev_proxy_defaults_and_groups() {
+        let cli = Cli::try_parse_from(["xv", "dev-proxy"]).unwrap();
+        match cli.command {
+            Commands::DevProxy {
+                port, group, ttl, ..
+            } => {
+                assert_eq!(port, 8200);
+                assert!(group.is_empty());
+                assert_eq!(ttl, 300);
+            }
+            _ => panic!("Expected dev-proxy command"),
+        }
+        let cli = Cli::try_parse_from([
+            "xv",
+            "dev-proxy",
+            "--port",
+            "9000",
+            "-g",
+            "app",
+            "--group",
+            "shared",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::DevProxy { port, group, .. } => {
+                assert_eq!(port, 9000);
+                assert_eq!(group, vec!["app", "shared"]);
+            }
+            _ => panic!("Expected dev-proxy command"),
+        }
+    }
+
+    #[test]
+    fn test_index_commands_and_find_no_cache() {
+        let cli = Cli::try_parse_from(["xv", "index", "rebuild"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Index {
+                command: IndexCommands::Rebuild
+            }
+        ));
+        let cli = Cli::try_parse_from(["xv", "name", "map", "--format", "csv"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Name {
+                command: NameCommands::Map
+            }
+        ));
+        let cli = Cli::try_parse_from(["xv", "search", "db", "--no-cache"]).unwrap();
+        match cli.command {
+            Commands::Find { no_cache, .. } => assert!(no_cache),
+            _ => panic!("Expected find command"),
+        }
+    }
+
+    #[test]
+    fn test_group_commands() {
+        let cli = Cli::try_parse_from(["xv", "group", "add", "web", "a", "app/b"]).unwrap();
+        match cli.command {
+            Commands::Group {
+                command:
+                    GroupCommands::Add {
+                        group,
+                        secrets,
+                        filter,
+                        ..
+                    },
+            } => {
+                assert_eq!(group, "web");
+                assert_eq!(secrets, vec!["a", "app/b"]);
+                assert_eq!(filter, None);
+            }
+            _ => panic!("Expected group add command"),
+        }
+        let cli =
+            Cli::try_parse_from(["xv", "groups", "remove", "web", "--filter", "tmp-*"]).unwrap();
+        match cli.command {
+            Commands::Group {
+                command:
+                    GroupCommands::Remove {
+                        secrets, filter, ..
+                    },
+            } => {
+                assert!(secrets.is_empty());
+                assert_eq!(filter.as_deref(), Some("tmp-*"));
+            }
+            _ => panic!("Expected group remove command"),
+        }
+        // add/remove need secrets or --filter.
+        assert!(Cli::try_parse_from(["xv", "group", "add", "web"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "group", "rename", "a", "b", "--dry-run"]).is_ok());
+    }
+
+    #[test]
+    fn test_ssh_commands() {
+        let cli = Cli::try_parse_from(["xv", "ssh", "keygen", "deploy-key", "-C", "ci"]).unwrap();
+        match cli.command {
+            Commands::Ssh {
+                command:
+                    SshCommands::Keygen {
+                        name,
+                        comment,
+                        force,
+                    },
+            } => {
+                assert_eq!(name, "deploy-key");
+                assert_eq!(comment.as_deref(), Some("ci"));
+                assert!(!force);
+            }
+            _ => panic!("Expected ssh keygen command"),
+        }
+        let cli = Cli::try_parse_from(["xv", "ssh", "load", "deploy-key", "-t", "1h"]).unwrap();
+        match cli.command {
+            Commands::Ssh {
+                command: SshCommands::Load { name, lifetime },
+            } => {
+                assert_eq!(name, "deploy-key");
+                assert_eq!(lifetime.as_deref(), Some("1h"));
+            }
+            _ => panic!("Expected ssh load command"),
+        }
+    }
+
+    #[test]
+    fn test_edit_command() {
+        let cli = Cli::try_parse_from(["xv", "edit", "app-config", "--extension", "yaml"]).unwrap();
+        match cli.command {
+            Commands::Edit { name, extension } => {
+                assert_eq!(name, "app-config");
+                assert_eq!(extension.as_deref(), Some("yaml"));
+            }
+            _ => panic!("Expected edit command"),
+        }
+    }
+
+    #[test]
+    fn test_get_clipboard_flag_conflicts_with_raw() {
+        let cli = Cli::try_parse_from(["xv", "get", "db-password", "--clipboard"]).unwrap();
+        match cli.command {
+            Commands::Get { clipboard, raw, .. } => assert!(clipboard && !raw),
+            _ => panic!("Expected get command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "get", "db", "--clipboard", "--raw"]).is_err());
+    }
+
+    #[test]
+    fn test_appservice_check_args_parse() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "appservice",
+            "check",
+            "--app",
+            "web-prod",
+            "--rg",
+            "rg-web",
+            "--slot",
+            "staging",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Appservice {
+                command:
+                    AppserviceCommands::Check {
+                        app,
+                        resource_group,
+                        slot,
+                    },
+            } => {
+                assert_eq!(app, "web-prod");
+                assert_eq!(resource_group.as_deref(), Some("rg-web"));
+                assert_eq!(slot.as_deref(), Some("staging"));
+            }
+            _ => panic!("Expected appservice check command"),
+        }
+        assert!(Cli::try_parse_from(["xv", "appservice", "check"]).is_err());
+    }
+
+    #[test]
+    fn test_backup_restore_args_parse() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "backup",
+            "restore",
+            "backups/kv-prod-20260601T000000Z.json.age",
+            "--to",
+            "kv-staging",
+            "--only",
+            "group:db",
+            "--on-conflict",
+            "replace",
+            "--dry-run",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Backup {
+                command:
+                    BackupCommands::Restore {
+                        to,
+                        only,
+                        on_conflict,
+                        dry_run,
+                        identity,
+                        ..
+                    },
+            } => {
+                assert_eq!(to.as_deref(), Some("kv-staging"));
+                assert_eq!(only.as_deref(), Some("group:db"));
+                assert_eq!(on_conflict, OnConflict::Replace);
+                assert!(dry_run);
+                assert!(identity.is_none());
+            }
+            _ => panic!("Expected backup restore command"),
+        }
+    }
+
+    #[test]
+    fn test_scan_staged_and_all_conflict() {
         // `--staged` and `--all` select different scan sources; passing both
         // must be a hard clap error rather than silently honoring one.
         let result = Cli::try_parse_from(["xv", "scan", "--staged", "--all"]);
@@ -2474,6 +4485,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vault_list_all_subscriptions_excludes_resource_group() {
+        let cli = Cli::try_parse_from(["xv", "vault", "list", "--all-subscriptions"]).unwrap();
+        match cli.command {
+            Commands::Vault {
+                command:
+                    VaultCommands::List {
+                        all_subscriptions, ..
+                    },
+            } => assert!(all_subscriptions),
+            _ => panic!("Expected vault list command"),
+        }
+
+        assert!(Cli::try_parse_from([
+            "xv",
+            "vault",
+            "list",
+            "--all-subscriptions",
+            "--resource-group",
+            "rg",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_vault_list_with_counts_parses_and_excludes_names_only() {
+        let cli = Cli::try_parse_from(["xv", "vault", "list", "--with-counts"]).unwrap();
+        match cli.command {
+            Commands::Vault {
+                command: VaultCommands::List { with_counts, .. },
+            } => assert!(with_counts),
+            _ => panic!("Expected vault list command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["xv", "vault", "list", "--with-counts", "--names-only"]).is_err()
+        );
+    }
+
     #[test]
     fn test_share_list_pagination_args_parse() {
         let cli = Cli::try_parse_from([
@@ -2592,6 +4642,9 @@ mod tests {
             "--dry-run",
             "--on-conflict",
             "replace",
+            "--report",
+            "migrate-report.json",
+            "--resume",
         ])
         .unwrap();
 
@@ -2605,6 +4658,8 @@ mod tests {
                 on_conflict,
                 force_replace,
                 concurrency,
+                report,
+                resume,
             } => {
                 assert_eq!(from, "azure");
                 assert_eq!(to, "local");
@@ -2614,6 +4669,8 @@ mod tests {
                 assert_eq!(on_conflict, OnConflict::Replace);
                 assert!(!force_replace);
                 assert_eq!(concurrency, 8);
+                assert_eq!(report.as_deref(), Some("migrate-report.json"));
+                assert!(resume);
             }
             _ => panic!("Expected Migrate command"),
         }
@@ -2634,6 +4691,8 @@ mod tests {
                 on_conflict,
                 force_replace,
                 concurrency,
+                report,
+                resume,
             } => {
                 assert_eq!(from, "local");
                 assert_eq!(to, "azure");
@@ -2643,6 +4702,8 @@ mod tests {
                 assert_eq!(on_conflict, OnConflict::Skip);
                 assert!(!force_replace);
                 assert_eq!(concurrency, 8);
+                assert_eq!(report, None);
+                assert!(!resume);
             }
             _ => panic!("Expected Migrate command"),
         }
@@ -2799,6 +4860,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bench_command_defaults() {
+        let cli = Cli::try_parse_from(["xv", "bench", "--secrets", "250"]).unwrap();
+        match cli.command {
+            Commands::Bench {
+                secrets,
+                concurrency,
+                list_runs,
+                vault,
+            } => {
+                assert_eq!((secrets, concurrency, list_runs), (250, 8, 10));
+                assert_eq!(vault, None);
+            }
+            _ => panic!("Expected Bench command"),
+        }
+    }
+
+    #[test]
+    fn test_selftest_command() {
+        let cli = Cli::try_parse_from([
+            "xv",
+            "selftest",
+            "--rg",
+            "scratch-rg",
+            "--cleanup",
+            "--report",
+            "selftest.json",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Selftest {
+                resource_group,
+                location,
+                cleanup,
+                skip_files,
+                report,
+            } => {
+                assert_eq!(resource_group.as_deref(), Some("scratch-rg"));
+                assert_eq!(location, None);
+                assert!(cleanup);
+                assert!(!skip_files);
+                assert_eq!(report.as_deref(), Some("selftest.json"));
+            }
+            _ => panic!("Expected Selftest command"),
+        }
+    }
+
+    #[test]
+    fn test_gen_modes_conflict() {
+        // `generate` is an alias of `gen`.
+        let cli = Cli::try_parse_from([
+            "xv",
+            "generate",
+            "--save",
+            "api-token",
+            "--passphrase",
+            "--words",
+            "8",
+            "-g",
+            "api",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Gen {
+                save,
+                passphrase,
+                words,
+                meta,
+                ..
+            } => {
+                assert_eq!(save.as_deref(), Some("api-token"));
+                assert!(passphrase);
+                assert_eq!(words, Some(8));
+                assert_eq!(meta.group, vec!["api".to_string()]);
+            }
+            _ => panic!("Expected Gen command"),
+        }
+
+        assert!(Cli::try_parse_from(["xv", "gen", "--words", "5"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "gen", "--passphrase", "--encoding", "hex"]).is_err());
+        assert!(Cli::try_parse_from(["xv", "gen", "--passphrase", "--length", "20"]).is_err());
+        assert!(
+            Cli::try_parse_from(["xv", "gen", "--charset", "hex", "--encoding", "hex"]).is_err()
+        );
+    }
+
     #[test]
     fn test_set_accepts_group_flag() {
         // set gained --group as the symmetric bonus of the shared struct.
@@ -2906,6 +5053,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn env_without_subcommand_prints_exports() {
+        let cli = Cli::try_parse_from(["xv", "env", "--group", "api", "--shell", "fish"]).unwrap();
+        match cli.command {
+            Commands::Env {
+                command: None,
+                group,
+                shell,
+                ..
+            } => {
+                assert_eq!(group, vec!["api".to_string()]);
+                assert_eq!(shell, EnvShell::Fish);
+            }
+            _ => panic!("expected env export"),
+        }
+        // bash and zsh are spellings of the default.
+        match Cli::try_parse_from(["xv", "env", "--shell", "bash"])
+            .unwrap()
+            .command
+        {
+            Commands::Env { shell, .. } => assert_eq!(shell, EnvShell::Posix),
+            _ => panic!("expected env export"),
+        }
+        // Export flags don't mix with the profile subcommands.
+        assert!(Cli::try_parse_from(["xv", "env", "--group", "api", "list"]).is_err());
+    }
+
     #[test]
     #[cfg(feature = "file-ops")]
     fn test_ls_alias_on_file_list() {
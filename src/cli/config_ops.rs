@@ -4,7 +4,7 @@ use crate::cli::commands::{
     CacheCommands, CharsetType, ConfigCommands, ContextCommands, EnvCommands,
 };
 use crate::cli::helpers::format_cache_size;
-use crate::config::Config;
+use crate::config::{Config, GetOutput};
 use crate::error::{CrosstacheError, Result};
 use crate::utils::output;
 use zeroize::Zeroizing;
@@ -146,6 +146,14 @@ async fn execute_config_show(config: &Config) -> Result<()> {
         });
     }
 
+    if let Some(key_secret) = blob_config.key_secret {
+        items.push(ConfigItem {
+            key: "storage_key_secret".to_string(),
+            value: key_secret,
+            source: "config".to_string(),
+        });
+    }
+
     items.push(ConfigItem {
         key: "blob_chunk_size_mb".to_string(),
         value: blob_config.chunk_size_mb.to_string(),
@@ -158,6 +166,20 @@ async fn execute_config_show(config: &Config) -> Result<()> {
         source: "config".to_string(),
     });
 
+    items.push(ConfigItem {
+        key: "blob_transfer_retries".to_string(),
+        value: blob_config.transfer_retries.to_string(),
+        source: "config".to_string(),
+    });
+
+    for (command, format) in &config.output_defaults {
+        items.push(ConfigItem {
+            key: format!("output.{command}"),
+            value: format.clone(),
+            source: "config".to_string(),
+        });
+    }
+
     let items = items;
 
     // Documented exception: json/yaml serialize the whole Config object
@@ -212,8 +234,6 @@ async fn execute_config_path() -> Result<()> {
 /// `code --wait`), so the value is split on whitespace: the first token is
 /// the program and the rest are passed as leading arguments.
 async fn execute_config_edit(config: &Config) -> Result<()> {
-    use std::process::Command;
-
     let config_path = Config::get_config_path()?;
 
     // Seed a missing config file with a VALID default so the editor opens on
@@ -231,6 +251,14 @@ async fn execute_config_edit(config: &Config) -> Result<()> {
             })?;
     }
 
+    open_in_editor(&config_path)
+}
+
+/// Open `path` in the resolved editor and wait for it to exit. Shared by
+/// `xv config edit` and `xv edit`.
+pub(crate) fn open_in_editor(path: &std::path::Path) -> Result<()> {
+    use std::process::Command;
+
     let editor = resolve_editor();
     // Split so `$EDITOR` values like `code --wait` or `emacs -nw` work.
     let mut parts = editor.split_whitespace();
@@ -240,7 +268,7 @@ async fn execute_config_edit(config: &Config) -> Result<()> {
 
     let status = Command::new(program)
         .args(parts)
-        .arg(&config_path)
+        .arg(path)
         .status()
         .map_err(|e| {
             CrosstacheError::config(format!(
@@ -679,7 +707,91 @@ async fn execute_config_show_resolved(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Keys `xv config set` accepts, in their canonical spelling.
+const CONFIG_KEYS: &[&str] = &[
+    "debug",
+    "subscription_id",
+    "default_vault",
+    "default_resource_group",
+    "default_location",
+    "tenant_id",
+    "cache_enabled",
+    "cache_ttl_secs",
+    "output_json",
+    "no_color",
+    "azure_credential_priority",
+    "storage_account",
+    "storage_container",
+    "storage_endpoint",
+    "storage_key_secret",
+    "blob_chunk_size_mb",
+    "blob_max_concurrent_uploads",
+    "blob_transfer_retries",
+    "clipboard_timeout",
+    "gen_default_charset",
+    "get_default_output",
+    "inherit_vault_tags",
+    "forbid_command_line_values",
+    "allow_qr_and_paste",
+    "permission_preflight",
+    "fetch_concurrency",
+    "key_vault_api_version",
+    "paging",
+    "pager",
+];
+
+/// Other spellings of a canonical key, after normalization.
+const CONFIG_KEY_ALIASES: &[(&str, &str)] = &[
+    ("cache_ttl", "cache_ttl_secs"),
+    ("blob_container", "storage_container"),
+    ("blob_container_name", "storage_container"),
+    ("blob_endpoint", "storage_endpoint"),
+    ("blob_key_secret", "storage_key_secret"),
+];
+
+/// Resolve a user-typed key to its canonical form: trimmed, case-insensitive,
+/// `-` read as `_`, and dotted nested keys (`blob.chunk_size_mb`,
+/// `cache.ttl_secs`, `blob.storage_account`) flattened. `output.<command>`
+/// keeps its dot. Unknown keys fail with the nearest known keys.
+fn normalize_config_key(raw: &str) -> Result<String> {
+    let key = raw.trim().to_lowercase().replace('-', "_");
+    if let Some(command) = key.strip_prefix("output.") {
+        return Ok(format!("output.{command}"));
+    }
+
+    let known = |candidate: &str| -> Option<String> {
+        if CONFIG_KEYS.contains(&candidate) {
+            return Some(candidate.to_string());
+        }
+        CONFIG_KEY_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == candidate)
+            .map(|(_, canonical)| canonical.to_string())
+    };
+    if let Some(canonical) = known(&key.replace('.', "_")) {
+        return Ok(canonical);
+    }
+    // `section.field` where the field alone is already a key.
+    if let Some(canonical) = key.rsplit_once('.').and_then(|(_, field)| known(field)) {
+        return Ok(canonical);
+    }
+
+    let suggestions = crate::utils::suggestions::close_matches(&key.replace('.', "_"), CONFIG_KEYS);
+    let hint = if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(" Did you mean: {}?", suggestions.join(", "))
+    };
+    Err(CrosstacheError::config(format!(
+        "Unknown configuration key: {}.{hint} Available keys: {}, output.<command>",
+        raw.trim(),
+        CONFIG_KEYS.join(", ")
+    )))
+}
+
 async fn execute_config_set(key: &str, value: &str, mut config: Config) -> Result<()> {
+    let key = normalize_config_key(key)?;
+    let key = key.as_str();
     match key {
         "debug" => {
             config.debug = value.to_lowercase() == "true" || value == "1";
@@ -702,7 +814,7 @@ async fn execute_config_set(key: &str, value: &str, mut config: Config) -> Resul
         "cache_enabled" => {
             config.cache_enabled = value.to_lowercase() == "true" || value == "1";
         }
-        "cache_ttl" | "cache_ttl_secs" => {
+        "cache_ttl_secs" => {
             let seconds = value.parse::<u64>().map_err(|_| {
                 CrosstacheError::config(format!("Invalid value for cache_ttl_secs: {value}"))
             })?;
@@ -740,6 +852,15 @@ async fn execute_config_set(key: &str, value: &str, mut config: Config) -> Resul
             };
             config.set_blob_config(blob_config);
         }
+        "storage_key_secret" => {
+            let mut blob_config = config.get_blob_config();
+            blob_config.key_secret = if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string())
+            };
+            config.set_blob_config(blob_config);
+        }
         "blob_chunk_size_mb" => {
             let chunk_size = value.parse::<usize>().map_err(|_| {
                 CrosstacheError::config(format!("Invalid value for blob_chunk_size_mb: {value}"))
@@ -758,6 +879,14 @@ async fn execute_config_set(key: &str, value: &str, mut config: Config) -> Resul
             blob_config.max_concurrent_uploads = max_uploads;
             config.set_blob_config(blob_config);
         }
+        "blob_transfer_retries" => {
+            let retries = value.parse::<usize>().map_err(|_| {
+                CrosstacheError::config(format!("Invalid value for blob_transfer_retries: {value}"))
+            })?;
+            let mut blob_config = config.get_blob_config();
+            blob_config.transfer_retries = retries;
+            config.set_blob_config(blob_config);
+        }
         "clipboard_timeout" => {
             config.clipboard_timeout = value.parse::<u64>().map_err(|_| {
                 CrosstacheError::config(format!(
@@ -771,11 +900,69 @@ async fn execute_config_set(key: &str, value: &str, mut config: Config) -> Resul
                 .map_err(CrosstacheError::config)?;
             config.gen_default_charset = Some(charset.to_string());
         }
-        _ => {
-            return Err(CrosstacheError::config(format!(
-                "Unknown configuration key: {key}. Available keys: debug, subscription_id, default_vault, default_resource_group, default_location, tenant_id, cache_enabled, cache_ttl_secs, output_json, no_color, azure_credential_priority, storage_account, storage_container, storage_endpoint, blob_chunk_size_mb, blob_max_concurrent_uploads, clipboard_timeout, gen_default_charset"
-            )));
+        "get_default_output" => {
+            config.get_default_output = value
+                .parse::<GetOutput>()
+                .map_err(CrosstacheError::config)?;
+        }
+        "inherit_vault_tags" => {
+            config.inherit_vault_tags = value
+                .split(',')
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        "forbid_command_line_values" => {
+            config.forbid_command_line_values = value.parse::<bool>().map_err(|_| {
+                CrosstacheError::config(format!(
+                    "Invalid value for forbid_command_line_values: {value} (expected true or false)"
+                ))
+            })?;
+        }
+        "allow_qr_and_paste" => {
+            config.allow_qr_and_paste = value.parse::<bool>().map_err(|_| {
+                CrosstacheError::config(format!(
+                    "Invalid value for allow_qr_and_paste: {value} (expected true or false)"
+                ))
+            })?;
+        }
+        "fetch_concurrency" => {
+            config.fetch_concurrency = value
+                .parse::<usize>()
+                .ok()
+                .filter(|limit| *limit > 0)
+                .ok_or_else(|| {
+                    CrosstacheError::config(format!(
+                        "Invalid value for fetch_concurrency: {value} (expected a positive integer)"
+                    ))
+                })?;
+        }
+        "key_vault_api_version" => {
+            crate::backend::azure::kv_api::KeyVaultApi::new(value)?;
+            config.key_vault_api_version = value.trim().to_string();
+        }
+        "permission_preflight" => {
+            config.permission_preflight = value.parse::<bool>().map_err(|_| {
+                CrosstacheError::config(format!(
+                    "Invalid value for permission_preflight: {value} (expected true or false)"
+                ))
+            })?;
+        }
+        "paging" => {
+            config.paging = value.parse::<bool>().map_err(|_| {
+                CrosstacheError::config(format!(
+                    "Invalid value for paging: {value} (expected true or false)"
+                ))
+            })?;
         }
+        "pager" => {
+            config.pager = Some(value.trim().to_string()).filter(|command| !command.is_empty());
+        }
+        _ if key.starts_with("output.") => {
+            set_output_default(&mut config, &key["output.".len()..], value)?;
+        }
+        _ => unreachable!("normalize_config_key only returns known keys"),
     }
 
     config.save().await?;
@@ -784,6 +971,38 @@ async fn execute_config_set(key: &str, value: &str, mut config: Config) -> Resul
     Ok(())
 }
 
+/// `xv config set output.<command> <format>`; an empty value removes the
+/// default.
+fn set_output_default(config: &mut Config, command: &str, value: &str) -> Result<()> {
+    use crate::cli::commands::OUTPUT_DEFAULT_KEYS;
+    use crate::utils::format::OutputFormat;
+    use clap::ValueEnum;
+
+    if !OUTPUT_DEFAULT_KEYS.contains(&command) {
+        return Err(CrosstacheError::config(format!(
+            "Unknown command '{command}' for output defaults. Available: {}",
+            OUTPUT_DEFAULT_KEYS.join(", ")
+        )));
+    }
+    if value.is_empty() {
+        config.output_defaults.remove(command);
+        return Ok(());
+    }
+    let format = OutputFormat::from_str(value, true).map_err(|_| {
+        CrosstacheError::config(format!(
+            "Invalid output format '{value}'. Valid formats: auto, table, json, yaml, csv, plain, template, raw"
+        ))
+    })?;
+    let canonical = format
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_else(|| value.to_lowercase());
+    config
+        .output_defaults
+        .insert(command.to_string(), canonical);
+    Ok(())
+}
+
 // ── Cache ────────────────────────────────────────────────────────────────────
 
 pub(crate) async fn execute_cache_command(command: CacheCommands, config: Config) -> Result<()> {
@@ -1156,6 +1375,47 @@ Use `xv --env {vault_name} <command>` to activate it, or set XV_ENV={vault_name}
     Ok(())
 }
 
+/// After a "No vault specified" error: print the commands that set a vault
+/// context, naming recently used vaults, and on a terminal offer a picker
+/// that switches to one of them.
+#[allow(dead_code)] // called from src/main.rs::offer_setup_fix (binary-only path)
+pub(crate) async fn suggest_vault_context(config: &Config) -> Result<()> {
+    use crate::config::ContextManager;
+    use std::io::IsTerminal;
+
+    let context_manager = ContextManager::load().await?;
+    let recent: Vec<_> = context_manager.list_recent().into_iter().cloned().collect();
+    eprintln!("  fix: xv cx use <vault>   (sets the vault for this and later commands)");
+    eprintln!("  fix: xv <command> --vault <vault>   (for one command)");
+    if recent.is_empty() {
+        eprintln!("  fix: xv vault list   (shows the vaults you can use)");
+        return Ok(());
+    }
+    let names: Vec<String> = recent.iter().map(|c| c.vault_name.clone()).collect();
+    eprintln!("  recent: {}", names.join(", "));
+
+    if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
+        return Ok(());
+    }
+    let mut options = names;
+    options.push("Cancel".to_string());
+    let prompt = crate::utils::interactive::InteractivePrompt::new();
+    let choice = prompt.select("Switch to a recent vault?", &options, Some(0))?;
+    let Some(context) = recent.get(choice) else {
+        return Ok(());
+    };
+    execute_context_use(
+        &context.vault_name,
+        context.resource_group.clone(),
+        false,
+        false,
+        config,
+    )
+    .await?;
+    output::hint("Run the command again.");
+    Ok(())
+}
+
 async fn execute_context_list(config: &Config) -> Result<()> {
     use crate::config::ContextManager;
     use crate::utils::format::OutputFormat;
@@ -1367,6 +1627,7 @@ async fn execute_context_init(
             resource_group,
             group: None,
             folder: None,
+            file_prefix: None,
             backend: profile_backend.map(String::from),
             vaults: Vec::new(),
         },
@@ -1951,6 +2212,7 @@ pub(crate) async fn execute_env_command(
             backend,
             group,
             folder,
+            file_prefix,
             default,
             force,
         } => {
@@ -1961,6 +2223,7 @@ pub(crate) async fn execute_env_command(
                 backend.as_deref(),
                 group.as_deref(),
                 folder.as_deref(),
+                file_prefix.as_deref(),
                 default,
                 force,
                 &config,
@@ -1972,10 +2235,82 @@ pub(crate) async fn execute_env_command(
         EnvCommands::Pull {
             format,
             group,
+            folder,
+            map,
             output,
-        } => execute_env_pull(&format, group, output, &config, registry).await,
-        EnvCommands::Push { file, overwrite } => {
-            execute_env_push(file, overwrite, &config, registry).await
+            shell,
+        } => {
+            let scope = EnvFileScope {
+                groups: group,
+                folder,
+                map,
+            };
+            execute_env_pull(&format, output, shell, &scope, &config, registry).await
+        }
+        EnvCommands::Push {
+            file,
+            folder,
+            group,
+            map,
+            overwrite,
+        } => {
+            let scope = EnvFileScope {
+                groups: group,
+                folder,
+                map,
+            };
+            execute_env_push(file, overwrite, &scope, &config, registry).await
+        }
+    }
+}
+
+/// `xv dotenv pull|push`: `xv env pull|push` in dotenv format, with `.env`
+/// as the default file and `-` for stdout/stdin.
+pub(crate) async fn execute_dotenv_command(
+    command: crate::cli::commands::DotenvCommands,
+    config: &Config,
+    registry: Option<&crate::backend::BackendRegistry>,
+) -> Result<()> {
+    use crate::cli::commands::{DotenvCommands, EnvShell};
+    use crate::utils::format::OutputFormat;
+
+    match command {
+        DotenvCommands::Pull {
+            output,
+            folder,
+            group,
+            map,
+        } => {
+            let scope = EnvFileScope {
+                groups: group,
+                folder,
+                map,
+            };
+            let output = (output != "-").then_some(output);
+            execute_env_pull(
+                &OutputFormat::Plain,
+                output,
+                EnvShell::Posix,
+                &scope,
+                config,
+                registry,
+            )
+            .await
+        }
+        DotenvCommands::Push {
+            input,
+            folder,
+            group,
+            map,
+            overwrite,
+        } => {
+            let scope = EnvFileScope {
+                groups: group,
+                folder,
+                map,
+            };
+            let file = (input != "-").then_some(input);
+            execute_env_push(file, overwrite, &scope, config, registry).await
         }
     }
 }
@@ -2184,6 +2519,7 @@ async fn execute_env_create(
     backend: Option<&str>,
     group: Option<&str>,
     folder: Option<&str>,
+    file_prefix: Option<&str>,
     set_default: bool,
     force: bool,
     _config: &Config,
@@ -2210,6 +2546,7 @@ async fn execute_env_create(
         backend: backend.map(String::from),
         group: group.map(String::from),
         folder: folder.map(String::from),
+        file_prefix: file_prefix.map(String::from),
         vaults: Vec::new(),
     };
 
@@ -2323,16 +2660,21 @@ async fn execute_env_show(config: &Config) -> Result<()> {
     if let Some(f) = &profile.folder {
         println!("  folder: {f}");
     }
+    if let Some(p) = &profile.file_prefix {
+        println!("  file_prefix: {p}");
+    }
     Ok(())
 }
 
-fn is_posix_assignment_name(name: &str) -> bool {
+/// True for names a POSIX shell accepts as a variable:
+/// `[A-Za-z_][A-Za-z0-9_]*`.
+pub(crate) fn is_posix_assignment_name(name: &str) -> bool {
     let mut chars = name.chars();
     matches!(chars.next(), Some('A'..='Z' | 'a'..='z' | '_'))
         && chars.all(|c| matches!(c, 'A'..='Z' | 'a'..='z' | '0'..='9' | '_'))
 }
 
-fn quote_posix_shell_value(value: &str) -> String {
+pub(crate) fn quote_posix_shell_value(value: &str) -> String {
     if !value.is_empty()
         && value.chars().all(|c| {
             c.is_ascii_alphanumeric()
@@ -2356,15 +2698,191 @@ fn neutralize_spreadsheet_formula(value: &str) -> String {
     }
 }
 
+/// `xv env` with no subcommand: print the current vault's secrets (or a
+/// group/folder of them) as export statements for `shell`, to be `eval`ed.
+/// Names are upper-snake-cased as in `xv run`. Nothing is printed unless
+/// every selected secret could be read, so an `eval` never half-applies.
+pub(crate) async fn execute_env_export(
+    groups: Vec<String>,
+    folder: Option<String>,
+    shell: crate::cli::commands::EnvShell,
+    config: &Config,
+    registry: Option<&crate::backend::BackendRegistry>,
+) -> Result<()> {
+    use crate::secret::manager::groups_tag_contains;
+    use crate::utils::helpers::to_env_var_name;
+    use futures::stream::{self, StreamExt};
+    use std::collections::BTreeMap;
+
+    // Like `xv run`: no --group falls back to the env profile's group.
+    let groups = if groups.is_empty() {
+        config.resolve_group(None).await?.into_iter().collect()
+    } else {
+        groups
+    };
+    let (backend, _backend_name, vault_name) =
+        crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+    let secrets_backend = backend.secrets();
+
+    let folder = folder.map(|f| f.trim_matches('/').to_string());
+    let selected: Vec<_> = secrets_backend
+        .list_secrets(&vault_name, None)
+        .await?
+        .into_iter()
+        .filter(|s| {
+            groups.is_empty()
+                || groups
+                    .iter()
+                    .any(|g| groups_tag_contains(s.groups.as_deref(), g))
+        })
+        .filter(|s| {
+            folder.as_deref().is_none_or(|path| {
+                crate::cli::ls_view::folder_in_scope(s.folder.as_deref().unwrap_or(""), path)
+            })
+        })
+        .filter(|s| s.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET)
+        .collect();
+    if selected.is_empty() {
+        output::warn(&format!(
+            "No secrets matched in vault '{vault_name}' (group={groups:?}, folder={folder:?})"
+        ));
+        return Ok(());
+    }
+
+    let vault = vault_name.as_str();
+    let fetched: Vec<_> = stream::iter(&selected)
+        .map(|s| async move { (s, secrets_backend.get_secret(vault, &s.name, true).await) })
+        .buffered(config.fetch_concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut record_types = None;
+    let mut sources: BTreeMap<String, &str> = BTreeMap::new();
+    let mut lines: BTreeMap<String, Zeroizing<String>> = BTreeMap::new();
+    for (summary, result) in fetched {
+        let display = if summary.original_name.is_empty() {
+            &summary.name
+        } else {
+            &summary.original_name
+        };
+        let secret = result.map_err(CrosstacheError::from)?;
+        let types = if crate::records::is_record(&secret.content_type) {
+            crate::cli::secret_ops::resolve_types_lazily(&mut record_types, config).await?
+        } else {
+            Vec::new()
+        };
+        let value = crate::cli::secret_ops::record_field_value(display, &secret, None, &types)?;
+        let key = to_env_var_name(display);
+        if !is_posix_assignment_name(&key) {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "secret '{display}' maps to '{key}', which is not a valid environment variable name"
+            )));
+        }
+        if let Some(other) = sources.insert(key.clone(), display) {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "secrets '{other}' and '{display}' both map to '{key}'; narrow the selection with --group or --folder"
+            )));
+        }
+        let line = Zeroizing::new(shell_assignment(shell, &key, &value, true) + "\n");
+        lines.insert(key, line);
+    }
+    // Sorted by name so the output is stable between runs.
+    for line in lines.values() {
+        print!("{}", line.as_str());
+    }
+    Ok(())
+}
+
+/// One `NAME=value` assignment in `shell`'s syntax, value quoted so it is
+/// taken literally. `export` prefixes POSIX lines with `export`; fish and
+/// PowerShell assignments always reach the environment.
+fn shell_assignment(
+    shell: crate::cli::commands::EnvShell,
+    key: &str,
+    value: &str,
+    export: bool,
+) -> String {
+    use crate::cli::commands::EnvShell;
+
+    match shell {
+        EnvShell::Posix => format!(
+            "{}{key}={}",
+            if export { "export " } else { "" },
+            quote_posix_shell_value(value)
+        ),
+        // Inside fish single quotes only `\` and `'` need escaping.
+        EnvShell::Fish => format!(
+            "set -gx {key} '{}'",
+            value.replace('\\', "\\\\").replace('\'', "\\'")
+        ),
+        EnvShell::Powershell => format!(
+            "$env:{key} = {}",
+            crate::utils::windows::quote_powershell(value)
+        ),
+    }
+}
+
+/// Which secrets `xv env pull`/`push` read or write, and the mapping file
+/// that renames variables.
+struct EnvFileScope {
+    groups: Vec<String>,
+    folder: Option<String>,
+    map: Option<std::path::PathBuf>,
+}
+
+impl EnvFileScope {
+    /// `--folder` without surrounding slashes; `None` for the vault root.
+    fn folder(&self) -> Option<String> {
+        self.folder
+            .as_deref()
+            .map(|f| f.trim_matches('/').to_string())
+            .filter(|f| !f.is_empty())
+    }
+
+    /// Variable name → secret name, read from the `--map` file.
+    fn mapping(&self) -> Result<std::collections::BTreeMap<String, String>> {
+        let mut mapping = std::collections::BTreeMap::new();
+        let Some(path) = &self.map else {
+            return Ok(mapping);
+        };
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            CrosstacheError::config(format!("Cannot read mapping file {}: {e}", path.display()))
+        })?;
+        for (variable, secret) in crate::utils::dotenv::parse(&content)
+            .map_err(|e| CrosstacheError::config(format!("{}: {e}", path.display())))?
+        {
+            if secret.trim().is_empty() {
+                return Err(CrosstacheError::config(format!(
+                    "{}: {variable} maps to an empty secret name",
+                    path.display()
+                )));
+            }
+            mapping.insert(variable, secret.trim().to_string());
+        }
+        Ok(mapping)
+    }
+}
+
 async fn execute_env_pull(
     format: &crate::utils::format::OutputFormat,
-    groups: Vec<String>,
     output: Option<String>,
+    shell: crate::cli::commands::EnvShell,
+    scope: &EnvFileScope,
     config: &Config,
     registry: Option<&crate::backend::BackendRegistry>,
 ) -> Result<()> {
+    use crate::cli::commands::EnvShell;
+    use crate::secret::manager::groups_tag_contains;
     use crate::utils::format::OutputFormat;
 
+    // `.env` files are read by POSIX tools or dot-sourced by PowerShell;
+    // fish export lines come from `xv env --shell fish`.
+    if shell == EnvShell::Fish {
+        return Err(CrosstacheError::invalid_argument(
+            "'xv env pull' writes POSIX or PowerShell lines; use 'xv env --shell fish' for fish",
+        ));
+    }
+
     // Route through the active backend trait so `xv env pull` works on every
     // backend (azure/local/aws), not just Azure.
     let reg = registry.ok_or_else(|| {
@@ -2380,47 +2898,46 @@ async fn execute_env_pull(
         crate::cli::vault_ops::resolve_current_vault(config, Some(reg)).await?;
     let secrets_backend = backend.secrets();
 
+    let mapping = scope.mapping()?;
+    // Secret name → variable name.
+    let variable_for: std::collections::HashMap<&str, &str> = mapping
+        .iter()
+        .map(|(variable, secret)| (secret.as_str(), variable.as_str()))
+        .collect();
+    let folder = scope.folder();
+
     eprintln!("Pulling secrets from vault '{}'...", vault_name);
 
-    // Get all secrets or filtered by group
+    // Get all secrets, or those in any of the groups, under the folder
+    let selected: Vec<_> = secrets_backend
+        .list_secrets(&vault_name, None)
+        .await
+        .map_err(CrosstacheError::from)?
+        .into_iter()
+        .filter(|s| {
+            scope.groups.is_empty()
+                || scope
+                    .groups
+                    .iter()
+                    .any(|g| groups_tag_contains(s.groups.as_deref(), g))
+        })
+        .filter(|s| {
+            folder.as_deref().is_none_or(|path| {
+                crate::cli::ls_view::folder_in_scope(s.folder.as_deref().unwrap_or(""), path)
+            })
+        })
+        .collect();
     let mut all_secrets = Vec::new();
-    if groups.is_empty() {
-        // Get all secrets
-        let secrets = secrets_backend
-            .list_secrets(&vault_name, None)
+    for secret_summary in selected {
+        match secrets_backend
+            .get_secret(&vault_name, &secret_summary.name, true)
             .await
-            .map_err(CrosstacheError::from)?;
-        for secret_summary in secrets {
-            match secrets_backend
-                .get_secret(&vault_name, &secret_summary.name, true)
-                .await
-            {
-                Ok(secret) => all_secrets.push(secret),
-                Err(e) => eprintln!(
-                    "Warning: Failed to get secret '{}': {}",
-                    secret_summary.name, e
-                ),
-            }
-        }
-    } else {
-        // Get secrets filtered by groups
-        for group in &groups {
-            let secrets = secrets_backend
-                .list_secrets(&vault_name, Some(group))
-                .await
-                .map_err(CrosstacheError::from)?;
-            for secret_summary in secrets {
-                match secrets_backend
-                    .get_secret(&vault_name, &secret_summary.name, true)
-                    .await
-                {
-                    Ok(secret) => all_secrets.push(secret),
-                    Err(e) => eprintln!(
-                        "Warning: Failed to get secret '{}': {}",
-                        secret_summary.name, e
-                    ),
-                }
-            }
+        {
+            Ok(secret) => all_secrets.push(secret),
+            Err(e) => eprintln!(
+                "Warning: Failed to get secret '{}': {}",
+                secret_summary.name, e
+            ),
         }
     }
 
@@ -2482,17 +2999,24 @@ async fn execute_env_pull(
             let mut dotenv_content = String::new();
             for secret in &all_secrets {
                 if let Some(ref value) = secret.value {
-                    let key = &secret.original_name;
+                    let key = variable_for
+                        .get(secret.original_name.as_str())
+                        .or_else(|| variable_for.get(secret.name.as_str()))
+                        .copied()
+                        .unwrap_or(&secret.original_name);
+                    // `$env:NAME` accepts the same characters as a POSIX name.
                     if !is_posix_assignment_name(key) {
+                        let shell_name = match shell {
+                            EnvShell::Powershell => "PowerShell",
+                            EnvShell::Posix | EnvShell::Fish => "POSIX",
+                        };
                         return Err(CrosstacheError::invalid_argument(format!(
-                            "secret name '{key}' is not a valid POSIX environment variable name"
+                            "secret name '{key}' is not a valid {shell_name} environment variable name; \
+                             name a variable for it in a --map file"
                         )));
                     }
-                    dotenv_content.push_str(&format!(
-                        "{}={}\n",
-                        key,
-                        quote_posix_shell_value(value)
-                    ));
+                    dotenv_content.push_str(&shell_assignment(shell, key, value, false));
+                    dotenv_content.push('\n');
                 }
             }
             dotenv_content
@@ -2520,11 +3044,12 @@ async fn execute_env_pull(
 async fn execute_env_push(
     file: Option<String>,
     overwrite: bool,
+    scope: &EnvFileScope,
     config: &Config,
     registry: Option<&crate::backend::BackendRegistry>,
 ) -> Result<()> {
     use crate::secret::manager::SecretRequest;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::io::Read;
 
     // Route through the active backend trait so `xv env push` works on every
@@ -2542,8 +3067,12 @@ async fn execute_env_push(
         crate::cli::vault_ops::resolve_current_vault(config, Some(reg)).await?;
     let secrets_backend = backend.secrets();
 
+    let mapping = scope.mapping()?;
+    let folder = scope.folder();
+
     // Read .env content from file or stdin
-    let env_content = if let Some(file_path) = file {
+    let source = file.as_deref().unwrap_or("stdin").to_string();
+    let env_content = Zeroizing::new(if let Some(file_path) = file {
         println!("Reading .env file from '{}'...", file_path);
         std::fs::read_to_string(&file_path)?
     } else {
@@ -2551,49 +3080,16 @@ async fn execute_env_push(
         let mut buffer = String::new();
         std::io::stdin().read_to_string(&mut buffer)?;
         buffer
-    };
-
-    // Parse .env content
-    let mut secrets = HashMap::new();
-    for (line_num, line) in env_content.lines().enumerate() {
-        let line = line.trim();
-
-        // Skip empty lines and comments
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-
-        // Parse KEY=VALUE format
-        if let Some(eq_pos) = line.find('=') {
-            let key = line[..eq_pos].trim();
-            let value = line[eq_pos + 1..].trim();
-
-            // Handle quoted values
-            let processed_value =
-                if value.starts_with('"') && value.ends_with('"') && value.len() >= 2 {
-                    let unquoted = &value[1..value.len() - 1];
-                    // Unescape quoted content
-                    unquoted
-                        .replace("\\\"", "\"")
-                        .replace("\\n", "\n")
-                        .replace("\\\\", "\\")
-                } else {
-                    value.to_string()
-                };
-
-            if key.is_empty() {
-                eprintln!("Warning: Empty key on line {} - skipping", line_num + 1);
-                continue;
-            }
+    });
 
-            secrets.insert(key.to_string(), processed_value);
-        } else {
-            eprintln!(
-                "Warning: Invalid format on line {} - skipping: {}",
-                line_num + 1,
-                line
-            );
-        }
+    // Parse .env content; a mapped variable is stored under its secret name,
+    // and later assignments win, as when the file is sourced.
+    let entries = crate::utils::dotenv::parse(&env_content)
+        .map_err(|e| CrosstacheError::invalid_argument(format!("{source}: {e}")))?;
+    let mut secrets = BTreeMap::new();
+    for (variable, value) in entries {
+        let name = mapping.get(&variable).cloned().unwrap_or(variable);
+        secrets.insert(name, value);
     }
 
     if secrets.is_empty() {
@@ -2650,15 +3146,15 @@ async fn execute_env_push(
         }
         let secret_request = SecretRequest {
             name: key.clone(),
-            value: Zeroizing::new(value.clone()),
+            value,
             content_type: Some("text/plain".to_string()),
             enabled: Some(true),
             expires_on: None,
             not_before: None,
             tags: Some(HashMap::new()),
-            groups: None,
+            groups: (!scope.groups.is_empty()).then(|| scope.groups.clone()),
             note: None,
-            folder: None,
+            folder: folder.clone(),
         };
 
         match secrets_backend
@@ -2714,13 +3210,61 @@ async fn execute_env_push(
 
 #[cfg(test)]
 mod tests {
-    use super::resolve_editor_from;
+    use super::{normalize_config_key, resolve_editor_from};
 
     #[cfg(not(windows))]
     const DEFAULT_EDITOR: &str = "nano";
     #[cfg(windows)]
     const DEFAULT_EDITOR: &str = "notepad";
 
+    #[test]
+    fn assignments_quote_for_each_shell() {
+        use crate::cli::commands::EnvShell;
+
+        assert_eq!(
+            super::shell_assignment(EnvShell::Posix, "DB_PASS", "it's", true),
+            format!(
+                "export {}",
+                super::shell_assignment(EnvShell::Posix, "DB_PASS", "it's", false)
+            ),
+        );
+        assert_eq!(
+            super::shell_assignment(EnvShell::Posix, "A", "x y", false),
+            "A='x y'"
+        );
+        assert_eq!(
+            super::shell_assignment(EnvShell::Fish, "A", r"it's a \ path", true),
+            r"set -gx A 'it\'s a \\ path'"
+        );
+        assert!(
+            super::shell_assignment(EnvShell::Powershell, "A", "x", true).starts_with("$env:A = ")
+        );
+    }
+
+    #[test]
+    fn mapping_file_lists_variable_to_secret() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            file.path(),
+            "# local name = vault name\nDATABASE_URL=prod-db-url\nexport STRIPE_KEY=stripe-live-key\n",
+        )
+        .unwrap();
+        let mut scope = super::EnvFileScope {
+            groups: Vec::new(),
+            folder: Some("/dev/api/".to_string()),
+            map: Some(file.path().to_path_buf()),
+        };
+        let mapping = scope.mapping().unwrap();
+        assert_eq!(mapping["DATABASE_URL"], "prod-db-url");
+        assert_eq!(mapping["STRIPE_KEY"], "stripe-live-key");
+        assert_eq!(scope.folder().as_deref(), Some("dev/api"));
+
+        scope.map = None;
+        scope.folder = Some("/".to_string());
+        assert!(scope.mapping().unwrap().is_empty());
+        assert_eq!(scope.folder(), None);
+    }
+
     #[test]
     fn visual_takes_precedence_over_editor() {
         let got = resolve_editor_from(Some("vim".into()), Some("emacs".into()));
@@ -2760,6 +3304,49 @@ mod tests {
         assert_eq!(got, "code --wait");
     }
 
+    #[test]
+    fn config_keys_normalize_case_separators_and_nesting() {
+        for raw in [
+            "storage_account",
+            "Storage_Account",
+            "  STORAGE-ACCOUNT ",
+            "storage.account",
+            "blob.storage_account",
+        ] {
+            assert_eq!(
+                normalize_config_key(raw).unwrap(),
+                "storage_account",
+                "{raw}"
+            );
+        }
+        assert_eq!(
+            normalize_config_key("blob.chunk_size_mb").unwrap(),
+            "blob_chunk_size_mb"
+        );
+        assert_eq!(normalize_config_key("cache.ttl").unwrap(), "cache_ttl_secs");
+        assert_eq!(
+            normalize_config_key("blob.container").unwrap(),
+            "storage_container"
+        );
+        assert_eq!(
+            normalize_config_key("get.default_output").unwrap(),
+            "get_default_output"
+        );
+        assert_eq!(normalize_config_key("Output.LS").unwrap(), "output.ls");
+    }
+
+    #[test]
+    fn unknown_config_keys_suggest_near_misses() {
+        let err = normalize_config_key("storage_acount").unwrap_err();
+        assert!(
+            err.to_string().contains("Did you mean: storage_account?"),
+            "{err}"
+        );
+        let err = normalize_config_key("nonsense").unwrap_err();
+        assert!(!err.to_string().contains("Did you mean"), "{err}");
+        assert!(err.to_string().contains("Available keys: debug"), "{err}");
+    }
+
     mod resolve_alias_target {
         use super::super::resolve_alias_target_index;
         use crate::workspace::WorkspaceEntryConfig;
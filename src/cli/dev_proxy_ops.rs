@@ -0,0 +1,488 @@
+//! CLI executor for `xv dev-proxy`: a localhost-only HTTP endpoint that
+//! serves secret values to local dev servers, so they can read secrets at
+//! startup with a plain HTTP GET instead of a cloud SDK.
+//!
+//! API: `GET /secret/{name}` returns the value as `text/plain` (409 when the
+//! secret has no value); `GET /health` returns `ok`. Every request needs
+//! `Authorization: Bearer <token>`. Like `xv ui`, the listener is bound to
+//! 127.0.0.1 and checks Host/Origin, which stops other web pages in the
+//! browser from reading secrets through DNS rebinding. Values and the list
+//! of servable secrets are cached for `--ttl` seconds, and each request is
+//! logged to stderr and, with `--audit-log`, appended to that file through
+//! [`crate::utils::audit_log`] before the answer is sent.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use zeroize::Zeroizing;
+
+use crate::backend::{Backend, BackendRegistry};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::SecretSummary;
+
+/// Pins the bearer token instead of generating one per run.
+const TOKEN_ENV: &str = "XV_DEV_PROXY_TOKEN";
+/// Requests with a larger head are rejected.
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What `xv dev-proxy` serves and how.
+pub(crate) struct DevProxyOptions {
+    pub port: u16,
+    pub groups: Vec<String>,
+    pub folder: Option<String>,
+    pub ttl: Duration,
+    pub audit_log: Option<PathBuf>,
+}
+
+pub(crate) async fn execute_dev_proxy(
+    options: DevProxyOptions,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let folder = options
+        .folder
+        .as_deref()
+        .map(|f| f.trim_matches('/').to_string())
+        .filter(|f| !f.is_empty());
+    if let Some(folder) = &folder {
+        crate::utils::helpers::validate_folder_path(folder)?;
+    }
+    let (backend, backend_name, vault) =
+        crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+
+    let (token, generated) = match std::env::var(TOKEN_ENV) {
+        Ok(token) if !token.is_empty() => (token, false),
+        _ => {
+            let mut buf = [0u8; 32];
+            rand::rng().fill_bytes(&mut buf);
+            (hex::encode(buf), true)
+        }
+    };
+    let listener = TcpListener::bind(("127.0.0.1", options.port))
+        .await
+        .map_err(|e| {
+            CrosstacheError::config(format!("failed to bind 127.0.0.1:{}: {e}", options.port))
+        })?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| CrosstacheError::config(format!("local_addr: {e}")))?
+        .port();
+
+    let proxy = Arc::new(DevProxy {
+        backend,
+        backend_name,
+        vault,
+        groups: options.groups,
+        folder,
+        token,
+        ttl: options.ttl,
+        audit_log: options.audit_log,
+        cache: Mutex::new(ProxyCache::default()),
+    });
+    proxy.refresh_scope().await?;
+
+    let scope = match (proxy.groups.is_empty(), &proxy.folder) {
+        (true, None) => "every secret".to_string(),
+        (true, Some(folder)) => format!("folder '{folder}'"),
+        (false, None) => format!("group(s) {}", proxy.groups.join(", ")),
+        (false, Some(folder)) => {
+            format!("group(s) {} in folder '{folder}'", proxy.groups.join(", "))
+        }
+    };
+    println!(
+        "xv dev-proxy serving {scope} from vault '{}' at http://127.0.0.1:{port}",
+        proxy.vault
+    );
+    if generated {
+        println!("  export {TOKEN_ENV}={}", proxy.token);
+    }
+    println!(
+        "  curl -H \"Authorization: Bearer ${TOKEN_ENV}\" http://127.0.0.1:{port}/secret/<name>"
+    );
+    println!("Press Ctrl-C to stop.");
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let proxy = Arc::clone(&proxy);
+                    tokio::spawn(async move { proxy.serve(stream).await });
+                }
+                Err(e) => tracing::debug!("dev-proxy accept failed: {e}"),
+            },
+        }
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct ProxyCache {
+    /// Servable secrets: display and stored names → stored name.
+    scope: Option<(Instant, HashMap<String, String>)>,
+    /// Stored name → value.
+    values: HashMap<String, (Instant, Zeroizing<String>)>,
+}
+
+struct DevProxy {
+    backend: Arc<dyn Backend>,
+    backend_name: String,
+    vault: String,
+    groups: Vec<String>,
+    folder: Option<String>,
+    token: String,
+    ttl: Duration,
+    audit_log: Option<PathBuf>,
+    cache: Mutex<ProxyCache>,
+}
+
+/// The parts of a request head the proxy looks at.
+#[derive(Debug, PartialEq, Eq)]
+struct RequestHead<'a> {
+    method: &'a str,
+    path: &'a str,
+    host: Option<&'a str>,
+    origin: Option<&'a str>,
+    bearer: Option<&'a str>,
+}
+
+/// Parse an HTTP/1.x request head; `None` when malformed.
+fn parse_head(head: &str) -> Option<RequestHead<'_>> {
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?;
+    // The query string is not part of the secret name.
+    let path = request_line.next()?.split('?').next()?;
+    if !request_line.next()?.starts_with("HTTP/1.") {
+        return None;
+    }
+    let mut parsed = RequestHead {
+        method,
+        path,
+        host: None,
+        origin: None,
+        bearer: None,
+    };
+    for line in lines.take_while(|l| !l.is_empty()) {
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "host" => parsed.host = Some(value),
+            "origin" => parsed.origin = Some(value),
+            "authorization" => parsed.bearer = value.strip_prefix("Bearer "),
+            _ => {}
+        }
+    }
+    Some(parsed)
+}
+
+/// Reject requests that are not from a local client holding `token`, with
+/// the status and reason to send back.
+fn authorize(head: &RequestHead<'_>, token: &str) -> std::result::Result<(), (u16, &'static str)> {
+    if head.method != "GET" {
+        return Err((405, "only GET is supported"));
+    }
+    crate::utils::local_auth::check_request(head.host, head.origin, head.bearer, token)
+        .map_err(|rejection| (rejection.status(), rejection.reason()))
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        500 => "Internal Server Error",
+        _ => "Bad Gateway",
+    }
+}
+
+/// What a secret lookup found.
+enum Lookup {
+    /// The value, and whether it came from the cache.
+    Found(Zeroizing<String>, bool),
+    /// Unknown, disabled or out of scope.
+    NotServed,
+    /// In scope, but the backend holds no value for it.
+    NoValue,
+}
+
+/// The status, body and audit outcome for a secret lookup.
+fn answer(lookup: Result<Lookup>) -> (u16, Zeroizing<String>, Outcome) {
+    let text = |s: &str| Zeroizing::new(s.to_string());
+    match lookup {
+        Ok(Lookup::Found(value, hit)) => {
+            (200, value, if hit { Outcome::Hit } else { Outcome::Miss })
+        }
+        Ok(Lookup::NotServed) => (404, text("not found"), Outcome::Denied("not served")),
+        Ok(Lookup::NoValue) => (
+            409,
+            text("secret has no value"),
+            Outcome::Denied("no value"),
+        ),
+        Err(e) => (502, text("backend error"), Outcome::Failed(e.to_string())),
+    }
+}
+
+/// How a request was answered, for the audit line.
+enum Outcome {
+    /// `/health`; no secret is read, so nothing is audited.
+    Health,
+    Hit,
+    Miss,
+    Denied(&'static str),
+    Failed(String),
+}
+
+impl DevProxy {
+    /// Read one request, answer it, log it, and close the connection.
+    async fn serve(&self, mut stream: TcpStream) {
+        let mut buf = Vec::with_capacity(1024);
+        let read = tokio::time::timeout(READ_TIMEOUT, async {
+            let mut chunk = [0u8; 1024];
+            while !buf.windows(4).any(|w| w == b"\r\n\r\n") && buf.len() < MAX_REQUEST_HEAD {
+                match stream.read(&mut chunk).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                }
+            }
+        })
+        .await;
+        if read.is_err() {
+            return;
+        }
+
+        let text = String::from_utf8_lossy(&buf);
+        let (mut status, mut body, name, outcome) = match parse_head(&text) {
+            None => (400, Zeroizing::new("bad request".to_string()), None, None),
+            Some(head) => {
+                let name = head
+                    .path
+                    .strip_prefix("/secret/")
+                    .and_then(|n| urlencoding::decode(n).ok())
+                    .map(|n| n.into_owned());
+                let (status, body, outcome) = self.respond(&head, name.as_deref()).await;
+                (status, body, name, Some(outcome))
+            }
+        };
+
+        // Audited before the answer goes out: a value whose read could not
+        // be recorded is not served.
+        if let Some(outcome) = &outcome {
+            if let Err(e) = self.audit(name.as_deref(), status, outcome) {
+                crate::utils::output::warn(&e.to_string());
+                if matches!(outcome, Outcome::Hit | Outcome::Miss) && name.is_some() {
+                    status = 500;
+                    body = Zeroizing::new("audit log unavailable".to_string());
+                }
+            }
+        }
+
+        let response = format!(
+            "HTTP/1.1 {status} {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+            reason_phrase(status),
+            body.len()
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        let _ = stream.write_all(body.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+
+    async fn respond(
+        &self,
+        head: &RequestHead<'_>,
+        name: Option<&str>,
+    ) -> (u16, Zeroizing<String>, Outcome) {
+        let text = |s: &str| Zeroizing::new(s.to_string());
+        if let Err((status, reason)) = authorize(head, &self.token) {
+            return (status, text(reason), Outcome::Denied(reason));
+        }
+        if head.path == "/health" {
+            return (200, text("ok"), Outcome::Health);
+        }
+        let Some(name) = name.filter(|n| !n.is_empty()) else {
+            return (404, text("not found"), Outcome::Denied("unknown path"));
+        };
+        answer(self.value(name).await)
+    }
+
+    /// Re-list the vault and rebuild the set of servable names.
+    async fn refresh_scope(&self) -> Result<HashMap<String, String>> {
+        let secrets = self
+            .backend
+            .secrets()
+            .list_secrets(&self.vault, None)
+            .await?;
+        let scope: HashMap<String, String> = secrets
+            .iter()
+            .filter(|s| self.serves(s))
+            .flat_map(|s| {
+                let display = crate::cli::ls_view::display_name(s).to_string();
+                [(display, s.name.clone()), (s.name.clone(), s.name.clone())]
+            })
+            .collect();
+        self.cache.lock().await.scope = Some((Instant::now(), scope.clone()));
+        Ok(scope)
+    }
+
+    fn serves(&self, secret: &SecretSummary) -> bool {
+        secret.enabled
+            && secret.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET
+            && (self.groups.is_empty()
+                || self.groups.iter().any(|g| {
+                    crate::secret::manager::groups_tag_contains(secret.groups.as_deref(), g)
+                }))
+            && self.folder.as_deref().is_none_or(|folder| {
+                crate::cli::ls_view::folder_in_scope(secret.folder.as_deref().unwrap_or(""), folder)
+            })
+    }
+
+    /// Look up `name` in the cache, or fetch it from the backend.
+    async fn value(&self, name: &str) -> Result<Lookup> {
+        let stored = {
+            let cache = self.cache.lock().await;
+            match &cache.scope {
+                Some((at, scope)) if at.elapsed() < self.ttl => Some(scope.get(name).cloned()),
+                _ => None,
+            }
+        };
+        let stored = match stored {
+            Some(stored) => stored,
+            None => self.refresh_scope().await?.get(name).cloned(),
+        };
+        let Some(stored) = stored else {
+            return Ok(Lookup::NotServed);
+        };
+
+        if let Some((at, value)) = self.cache.lock().await.values.get(&stored) {
+            if at.elapsed() < self.ttl {
+                return Ok(Lookup::Found(value.clone(), true));
+            }
+        }
+        let secret = self
+            .backend
+            .secrets()
+            .get_secret(&self.vault, &stored, true)
+            .await?;
+        let Some(value) = secret.value else {
+            return Ok(Lookup::NoValue);
+        };
+        if !self.ttl.is_zero() {
+            self.cache
+                .lock()
+                .await
+                .values
+                .insert(stored, (Instant::now(), value.clone()));
+        }
+        Ok(Lookup::Found(value, false))
+    }
+
+    /// Log one request to stderr and, with `--audit-log`, append it to that
+    /// log. Health probes are not logged. Only the append can fail.
+    fn audit(&self, name: Option<&str>, status: u16, outcome: &Outcome) -> Result<()> {
+        use crate::utils::audit_log::{AuditEntry, AuditEvent};
+
+        let at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let shown = name.unwrap_or("-");
+        let (event, result, detail) = match outcome {
+            Outcome::Health => return Ok(()),
+            Outcome::Hit => (AuditEvent::ProxyRead, "cache", None),
+            Outcome::Miss => (AuditEvent::ProxyRead, "fetched", None),
+            Outcome::Denied(reason) => {
+                (AuditEvent::ProxyDenied, "denied", Some(reason.to_string()))
+            }
+            Outcome::Failed(error) => (AuditEvent::ProxyError, "error", Some(error.clone())),
+        };
+        match &detail {
+            Some(detail) => eprintln!("{at} {status} {shown} {result}: {detail}"),
+            None => eprintln!("{at} {status} {shown} {result}"),
+        }
+
+        let Some(path) = &self.audit_log else {
+            return Ok(());
+        };
+        let mut entry =
+            AuditEntry::new(event, &self.backend_name, &self.vault).detail(match &detail {
+                Some(detail) => format!("{status} {result}: {detail}"),
+                None => format!("{status} {result}"),
+            });
+        if let Some(name) = name {
+            entry = entry.secret(name);
+        }
+        crate::utils::audit_log::append(path, &entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn head(extra: &str) -> String {
+        format!("GET /secret/db-url HTTP/1.1\r\nHost: 127.0.0.1:8200\r\n{extra}\r\n")
+    }
+
+    #[test]
+    fn only_local_requests_with_the_token_pass() {
+        let ok = head("Authorization: Bearer t0k\r\n");
+        let parsed = parse_head(&ok).unwrap();
+        assert_eq!(parsed.path, "/secret/db-url");
+        assert_eq!(authorize(&parsed, "t0k"), Ok(()));
+
+        let no_token = head("");
+        assert_eq!(
+            authorize(&parse_head(&no_token).unwrap(), "t0k")
+                .unwrap_err()
+                .0,
+            401
+        );
+
+        let rebound = "GET /secret/x HTTP/1.1\r\nHost: evil.example:8200\r\nAuthorization: Bearer t0k\r\n\r\n";
+        assert_eq!(
+            authorize(&parse_head(rebound).unwrap(), "t0k")
+                .unwrap_err()
+                .0,
+            403
+        );
+
+        let cross_site = head("Authorization: Bearer t0k\r\nOrigin: https://evil.example\r\n");
+        assert_eq!(
+            authorize(&parse_head(&cross_site).unwrap(), "t0k")
+                .unwrap_err()
+                .0,
+            403
+        );
+
+        let post = "POST /secret/x HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        assert_eq!(
+            authorize(&parse_head(post).unwrap(), "t0k").unwrap_err().0,
+            405
+        );
+        assert!(parse_head("garbage").is_none());
+
+        let query = "GET /secret/db-url?v=2 HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        assert_eq!(parse_head(query).unwrap().path, "/secret/db-url");
+    }
+
+    #[test]
+    fn secrets_without_a_value_are_a_conflict_not_an_empty_body() {
+        let (status, body, _) = answer(Ok(Lookup::NoValue));
+        assert_eq!(status, 409);
+        assert_eq!(body.as_str(), "secret has no value");
+        assert_eq!(reason_phrase(status), "Conflict");
+
+        let (status, body, _) = answer(Ok(Lookup::Found(Zeroizing::new(String::new()), false)));
+        assert_eq!((status, body.as_str()), (200, ""));
+        assert_eq!(answer(Ok(Lookup::NotServed)).0, 404);
+    }
+}
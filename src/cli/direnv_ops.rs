@@ -0,0 +1,104 @@
+//! CLI executors for `xv direnv init` / `xv direnv export`.
+//!
+//! `init` prints (or appends to `.envrc`) a snippet that has direnv call
+//! `xv direnv export` whenever the directory is entered. The vault comes
+//! from the project's `.xv.toml` context, so each project loads its own
+//! secrets.
+
+use std::io::Write;
+use std::path::Path;
+
+use crate::backend::BackendRegistry;
+use crate::cli::commands::{DirenvCommands, EnvShell};
+use crate::cli::config_ops::quote_posix_shell_value;
+use crate::config::Config;
+use crate::error::Result;
+use crate::utils::output;
+
+/// First line of the snippet; also how `init --write` spots an existing one.
+const SNIPPET_MARKER: &str = "# Load this project's vault secrets (xv direnv)";
+
+pub(crate) async fn execute_direnv_command(
+    command: DirenvCommands,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        DirenvCommands::Init {
+            group,
+            folder,
+            write,
+        } => execute_direnv_init(&group, folder.as_deref(), write),
+        DirenvCommands::Export { group, folder } => {
+            crate::cli::config_ops::execute_env_export(
+                group,
+                folder,
+                EnvShell::Posix,
+                config,
+                registry,
+            )
+            .await
+        }
+    }
+}
+
+/// The `.envrc` lines for the given selection.
+fn envrc_snippet(groups: &[String], folder: Option<&str>) -> String {
+    let mut command = String::from("xv direnv export");
+    for group in groups {
+        command.push_str(&format!(" --group {}", quote_posix_shell_value(group)));
+    }
+    if let Some(folder) = folder {
+        command.push_str(&format!(" --folder {}", quote_posix_shell_value(folder)));
+    }
+    format!("{SNIPPET_MARKER}\nwatch_file .xv.toml\neval \"$({command})\"\n")
+}
+
+fn execute_direnv_init(groups: &[String], folder: Option<&str>, write: bool) -> Result<()> {
+    let snippet = envrc_snippet(groups, folder);
+    if !write {
+        print!("{snippet}");
+        return Ok(());
+    }
+
+    let path = Path::new(".envrc");
+    let existing = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    if existing.contains(SNIPPET_MARKER) {
+        output::info(".envrc already loads secrets with xv; left unchanged.");
+        return Ok(());
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        file.write_all(b"\n")?;
+    }
+    file.write_all(snippet.as_bytes())?;
+
+    output::success("Added the xv snippet to .envrc");
+    output::hint("Run 'direnv allow' to load it.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snippet_quotes_the_selection() {
+        assert_eq!(
+            envrc_snippet(&[], None),
+            format!("{SNIPPET_MARKER}\nwatch_file .xv.toml\neval \"$(xv direnv export)\"\n")
+        );
+        let snippet = envrc_snippet(&["api".to_string()], Some("dev/my app"));
+        assert!(
+            snippet.contains("eval \"$(xv direnv export --group api --folder 'dev/my app')\""),
+            "{snippet}"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! CLI executor for `xv edit`.
+//!
+//! Writes the current value to a private temp file (in RAM-backed
+//! `/dev/shm` where available), opens it in `$VISUAL`/`$EDITOR`, and saves a
+//! new version only when the text changed. The temp file is overwritten
+//! with zeros before it is removed, whether or not the edit went through.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use zeroize::Zeroizing;
+
+use crate::backend::BackendRegistry;
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::{FieldUpdate, SecretUpdateRequest};
+use crate::utils::output;
+
+/// A temp file that is zeroed before `tempfile` deletes it.
+struct ScratchFile(tempfile::NamedTempFile);
+
+impl Drop for ScratchFile {
+    fn drop(&mut self) {
+        // The editor may have replaced the file rather than written to it,
+        // so zero whatever is at the path now.
+        if let Err(e) = shred(self.0.path()) {
+            output::warn(&format!(
+                "Could not overwrite temp file {}: {e}",
+                self.0.path().display()
+            ));
+        }
+    }
+}
+
+/// Overwrite every byte of `path` with zeros and flush it to disk.
+pub(crate) fn shred(path: &Path) -> std::io::Result<()> {
+    let len = std::fs::metadata(path)?.len();
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    let zeros = [0u8; 8192];
+    let mut left = len;
+    while left > 0 {
+        let n = left.min(zeros.len() as u64) as usize;
+        file.write_all(&zeros[..n])?;
+        left -= n as u64;
+    }
+    file.sync_all()
+}
+
+/// Where the temp file goes: `/dev/shm` on Linux so the value never
+/// reaches a disk, the system temp dir elsewhere.
+pub(crate) fn scratch_dir() -> PathBuf {
+    let shm = Path::new("/dev/shm");
+    if cfg!(target_os = "linux") && shm.is_dir() {
+        shm.to_path_buf()
+    } else {
+        std::env::temp_dir()
+    }
+}
+
+/// Editors add a final newline on save; drop it again when the original
+/// value had none, so opening and saving is not a change.
+fn strip_added_newline(original: &str, mut edited: String) -> String {
+    if !original.ends_with('\n') && edited.ends_with('\n') {
+        edited.pop();
+        if edited.ends_with('\r') {
+            edited.pop();
+        }
+    }
+    edited
+}
+
+/// `json` for values that parse as a JSON object or array, `txt` otherwise.
+fn default_extension(value: &str) -> &'static str {
+    match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Object(_) | serde_json::Value::Array(_)) => "json",
+        _ => "txt",
+    }
+}
+
+/// Edit a secret's value (a typed record's primary field) in an editor.
+pub(crate) async fn execute_edit_command(
+    name: &str,
+    extension: Option<String>,
+    config: &Config,
+) -> Result<()> {
+    let (backend, backend_name, vault_name, name) =
+        crate::cli::helpers::resolve_workspace_or_default(
+            name,
+            config,
+            crate::workspace::TargetMode::Write,
+        )
+        .await?;
+    let name = name.as_str();
+    if name == crate::secret::attachments::ATTACHMENT_KEY_SECRET {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "'{name}' is the attachment encryption key for this vault and cannot be edited"
+        )));
+    }
+    let secret = backend
+        .secrets()
+        .get_secret(&vault_name, name, true)
+        .await?;
+
+    let is_record = crate::records::is_record(&secret.content_type);
+    let original: Zeroizing<String> = if is_record {
+        let types = config.resolve_record_types().await?;
+        crate::cli::secret_ops::record_field_value(name, &secret, None, &types)?
+    } else {
+        secret.value.clone().unwrap_or_default()
+    };
+
+    let extension = extension.unwrap_or_else(|| default_extension(&original).to_string());
+    let scratch = ScratchFile(
+        tempfile::Builder::new()
+            .prefix("xv-edit-")
+            .suffix(&format!(".{}", extension.trim_start_matches('.')))
+            .tempfile_in(scratch_dir())?,
+    );
+    scratch.0.as_file().write_all(original.as_bytes())?;
+    scratch.0.as_file().sync_all()?;
+
+    crate::cli::config_ops::open_in_editor(scratch.0.path())?;
+    let edited = Zeroizing::new(strip_added_newline(
+        &original,
+        std::fs::read_to_string(scratch.0.path())?,
+    ));
+    drop(scratch);
+
+    if *edited == *original {
+        output::info(&format!("No changes; '{name}' was not updated."));
+        return Ok(());
+    }
+    if edited.is_empty() {
+        return Err(CrosstacheError::invalid_argument(
+            "The edited value is empty; secret not updated. Use 'xv delete' to remove it.",
+        ));
+    }
+    // Someone else may have written a version while the editor was open;
+    // saving over it would silently drop their change.
+    let current = backend
+        .secrets()
+        .get_secret(&vault_name, name, false)
+        .await?;
+    if current.version != secret.version {
+        return Err(CrosstacheError::conflict(format!(
+            "'{name}' changed while it was being edited (now version {}); \
+             your edit was not saved. Run 'xv edit {name}' again.",
+            current.version
+        )));
+    }
+
+    let registry = BackendRegistry::new(backend);
+    let props = if is_record {
+        crate::cli::secret_ops::execute_record_primary_update(
+            name,
+            edited.as_str(),
+            &secret,
+            None,
+            &vault_name,
+            config,
+            &registry,
+            &backend_name,
+        )
+        .await?
+    } else {
+        registry
+            .active()
+            .secrets()
+            .update_secret(
+                &vault_name,
+                name,
+                SecretUpdateRequest {
+                    name: name.to_string(),
+                    expected_revision: None,
+                    value: Some(edited),
+                    content_type: None,
+                    enabled: None,
+                    expires_on: FieldUpdate::Unchanged,
+                    not_before: FieldUpdate::Unchanged,
+                    tags: None,
+                    groups: None,
+                    note: FieldUpdate::Unchanged,
+                    folder: FieldUpdate::Unchanged,
+                    replace_tags: false,
+                    replace_groups: false,
+                },
+            )
+            .await?
+    };
+    crate::cli::secret_ops::invalidate_trait_secret_cache(config, &backend_name, &vault_name);
+
+    output::success(&format!("Updated '{name}'"));
+    if !props.version.is_empty() {
+        println!("New version: {}", props.version);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn editor_newline_is_dropped_only_when_the_value_had_none() {
+        assert_eq!(strip_added_newline("abc", "abc\n".into()), "abc");
+        assert_eq!(strip_added_newline("abc", "abc\r\n".into()), "abc");
+        assert_eq!(strip_added_newline("abc\n", "abc\n".into()), "abc\n");
+        assert_eq!(strip_added_newline("abc", "abd".into()), "abd");
+    }
+
+    #[test]
+    fn json_values_open_as_json() {
+        assert_eq!(default_extension(r#"{"user": "app"}"#), "json");
+        assert_eq!(default_extension("[1, 2]"), "json");
+        assert_eq!(default_extension("42"), "txt");
+        assert_eq!(default_extension("hunter2"), "txt");
+    }
+
+    #[test]
+    fn shred_zeroes_the_file_in_place() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), b"top secret").unwrap();
+        shred(file.path()).unwrap();
+        assert_eq!(std::fs::read(file.path()).unwrap(), vec![0u8; 10]);
+    }
+}
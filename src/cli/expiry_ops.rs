@@ -0,0 +1,114 @@
+//! CLI executor for `xv expiry-report`.
+
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::expiry::ExpiryReport;
+use crate::utils::format::{OutputFormat, TableFormatter};
+use crate::utils::output;
+
+/// Report the secrets of the current vault (or, with `all_vaults`, every
+/// vault the active backend can list) that have expired, expire within
+/// `days`, or have no expiration date, and with `max_age` those not rotated
+/// within that many days.
+pub(crate) async fn execute_expiry_report_command(
+    days: u32,
+    all_vaults: bool,
+    max_age: Option<u32>,
+    format: OutputFormat,
+    config: &Config,
+    registry: Option<&crate::backend::BackendRegistry>,
+) -> Result<()> {
+    let (backend, vaults): (std::sync::Arc<dyn crate::backend::Backend>, Vec<String>) =
+        if all_vaults {
+            let reg = registry.ok_or_else(|| {
+                CrosstacheError::config(
+                    "No backend registry available. Run 'xv config show' to check your configuration.",
+                )
+            })?;
+            let backend = reg.active_arc();
+            let Some(vault_backend) = backend.vaults() else {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "--all-vaults is not supported on the {} backend (it cannot enumerate \
+                     vaults). Report on a single vault instead.",
+                    backend.name()
+                )));
+            };
+            let vaults = vault_backend
+                .list_vaults(None)
+                .await
+                .map_err(CrosstacheError::from)?
+                .into_iter()
+                .map(|v| v.name)
+                .collect();
+            (backend, vaults)
+        } else {
+            let (backend, _backend_name, vault) =
+                crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+            (backend, vec![vault])
+        };
+
+    // With --all-vaults, a vault that can't be listed is skipped with a
+    // warning rather than failing the whole report.
+    let mut secrets = Vec::new();
+    let mut scanned = Vec::with_capacity(vaults.len());
+    for vault in vaults {
+        match backend.secrets().list_secrets(&vault, None).await {
+            Ok(listed) => {
+                secrets.extend(
+                    listed
+                        .into_iter()
+                        .filter(|s| {
+                            s.original_name != crate::secret::attachments::ATTACHMENT_KEY_SECRET
+                        })
+                        .map(|s| (vault.clone(), s)),
+                );
+                scanned.push(vault);
+            }
+            Err(e) if all_vaults => output::warn(&format!("Skipping vault '{vault}': {e}")),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let report = ExpiryReport::build(
+        scanned,
+        secrets,
+        i64::from(days),
+        max_age.map(i64::from),
+        chrono::Utc::now(),
+    );
+    match format.resolve_for_stdout() {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&report)?),
+        resolved => {
+            if report.entries.is_empty() && resolved == OutputFormat::Table {
+                output::success(&format!(
+                    "No secrets expired, expiring within {days} day(s), or without an expiry"
+                ));
+                if let Some(max_age) = max_age {
+                    output::success(&format!("No secrets unrotated for {max_age} day(s)"));
+                }
+            } else {
+                let formatter = TableFormatter::new(
+                    resolved,
+                    config.no_color,
+                    config.template.clone(),
+                    config.runtime_columns.clone(),
+                );
+                println!("{}", formatter.format_table(&report.entries)?);
+            }
+            let stale = max_age
+                .map(|max_age| format!("{} unrotated for {max_age} day(s), ", report.stale))
+                .unwrap_or_default();
+            output::info(&format!(
+                "{} expired, {} expiring within {days} day(s), {stale}{} without expiry, \
+                 {} expiring later, across {} vault(s)",
+                report.expired,
+                report.expiring,
+                report.no_expiry,
+                report.later,
+                report.vaults.len()
+            ));
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 //! File/blob CLI command definitions.
 
-use crate::cli::helpers::parse_key_val;
+use crate::cli::helpers::{parse_day_count, parse_key_val};
 use clap::Subcommand;
 
 #[cfg(feature = "file-ops")]
@@ -8,7 +8,7 @@ use clap::Subcommand;
 pub enum FileCommands {
     /// Upload one or more files to blob storage
     Upload {
-        /// Local file path(s) to upload
+        /// Local file path(s) to upload (`-` streams stdin; requires --name)
         #[arg(required = true, num_args = 1..)]
         files: Vec<String>,
         /// Remote name (only valid when uploading single file)
@@ -43,13 +43,38 @@ pub enum FileCommands {
         /// uploads only.
         #[arg(long)]
         encrypt: bool,
+        /// Upload even when the stored file already has identical content
+        #[arg(long)]
+        force: bool,
+    },
+    /// Upload the clipboard's text as a file (needs `allow_qr_and_paste =
+    /// true`; each use is audited)
+    Paste {
+        /// Remote file name
+        name: String,
+        /// Groups to assign to the file
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Metadata key-value pairs
+        #[arg(short, long, value_parser = parse_key_val::<String, String>)]
+        metadata: Vec<(String, String)>,
+        /// Tags key-value pairs
+        #[arg(short, long, value_parser = parse_key_val::<String, String>)]
+        tag: Vec<(String, String)>,
+        /// Content type (default: text/plain)
+        #[arg(long)]
+        content_type: Option<String>,
+        /// Overwrite the file if it exists
+        #[arg(short, long)]
+        force: bool,
     },
     /// Download one or more files from blob storage
     Download {
         /// Remote file name(s) or prefix patterns to download
         #[arg(required = true, num_args = 1..)]
         files: Vec<String>,
-        /// Local output path (optional, defaults to current directory)
+        /// Local output path (optional, defaults to current directory; `-`
+        /// streams a single file to stdout)
         #[arg(short, long)]
         output: Option<String>,
         /// Rename file (only valid for single file download)
@@ -67,6 +92,10 @@ pub enum FileCommands {
         /// Continue on error when downloading multiple files
         #[arg(long)]
         continue_on_error: bool,
+        /// Skip files already downloaded by an interrupted or failed run of
+        /// the same multi-file download into the same directory
+        #[arg(long)]
+        resume: bool,
     },
     /// List files in blob storage (alias: ls)
     ///
@@ -105,6 +134,17 @@ pub enum FileCommands {
         /// Bypass the local cache and fetch fresh data
         #[arg(long)]
         no_cache: bool,
+        /// Page through large containers on the server: lists one page of
+        /// --page-size files (default 1000) recursively, then prints the token
+        /// for the next page to stderr. Give the flag alone to start, and the
+        /// printed token to continue.
+        #[arg(
+            long = "continue",
+            value_name = "TOKEN",
+            num_args = 0..=1,
+            default_missing_value = ""
+        )]
+        continue_from: Option<String>,
     },
     /// Delete one or more files from blob storage
     #[command(alias = "rm")]
@@ -123,6 +163,90 @@ pub enum FileCommands {
     Info {
         /// Remote file name
         name: String,
+        /// Print a short-lived, read-only URL for the file instead of its
+        /// details (Azure user delegation SAS or S3 presigned URL)
+        #[arg(long)]
+        url: bool,
+        /// Lifetime of the --url link in minutes (max 7 days)
+        #[arg(
+            long,
+            value_name = "MINUTES",
+            default_value_t = 15,
+            requires = "url",
+            value_parser = clap::value_parser!(u32).range(1..=10080)
+        )]
+        url_ttl: u32,
+    },
+    /// Change a file's groups, metadata or tags without re-uploading it
+    ///
+    /// New values are merged into the stored ones (groups added, keys
+    /// overwritten) unless --replace is given.
+    Update {
+        /// Remote file name
+        name: String,
+        /// Groups to add (repeatable)
+        #[arg(short, long)]
+        group: Vec<String>,
+        /// Metadata key-value pairs to set (repeatable)
+        #[arg(short, long, value_parser = parse_key_val::<String, String>)]
+        metadata: Vec<(String, String)>,
+        /// Tag key-value pairs to set (repeatable)
+        #[arg(short, long, value_parser = parse_key_val::<String, String>)]
+        tag: Vec<(String, String)>,
+        /// Replace the stored groups, metadata or tags with the given ones
+        /// instead of merging; kinds not given are left unchanged
+        #[arg(long)]
+        replace: bool,
+    },
+    /// Copy a file on the server, keeping its groups, metadata and tags
+    /// (alias: cp)
+    #[command(alias = "cp")]
+    Copy {
+        /// Remote file name to copy
+        source: String,
+        /// Name of the copy
+        destination: String,
+        /// Copy into this container (Azure) or bucket (S3) instead of the
+        /// configured one
+        #[arg(long)]
+        container: Option<String>,
+        /// Overwrite the destination if it exists
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Rename a file, or move it to another container, on the server
+    /// (alias: mv)
+    #[command(alias = "mv")]
+    Move {
+        /// Remote file name to move
+        source: String,
+        /// New name
+        destination: String,
+        /// Move into this container (Azure) or bucket (S3) instead of the
+        /// configured one
+        #[arg(long)]
+        container: Option<String>,
+        /// Overwrite the destination if it exists
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Bundle every file under a prefix into a local .zip or .tar.gz
+    ///
+    /// Files are fetched and added one at a time, so memory use is bounded
+    /// by the largest file, not the whole bundle.
+    Archive {
+        /// Remote prefix to bundle (default: all files)
+        #[arg(short, long)]
+        prefix: Option<String>,
+        /// Archive file to write (.zip, .tar.gz or .tgz)
+        #[arg(short, long)]
+        output: String,
+        /// Archive format (default: from the --output extension)
+        #[arg(long, value_name = "FORMAT")]
+        archive_format: Option<crate::blob::archive::ArchiveFormat>,
+        /// Overwrite the output file if it exists
+        #[arg(short, long)]
+        force: bool,
     },
     /// Sync files between local and remote
     Sync {
@@ -141,6 +265,42 @@ pub enum FileCommands {
         #[arg(long)]
         delete: bool,
     },
+    /// Manage retention rules in the storage account's lifecycle policy
+    /// (Azure only)
+    Lifecycle {
+        #[command(subcommand)]
+        command: FileLifecycleCommands,
+    },
+}
+
+#[cfg(feature = "file-ops")]
+#[derive(Subcommand)]
+pub enum FileLifecycleCommands {
+    /// Create or update the rule for a prefix
+    Set {
+        /// Blob prefix the rule applies to (default: the whole container)
+        #[arg(short, long)]
+        prefix: Option<String>,
+        /// Delete blobs this long after their last modification (e.g. 90d,
+        /// 12w, 1y)
+        #[arg(long, value_parser = parse_day_count)]
+        delete_after: Option<u32>,
+        /// Move blobs to the Cool tier this long after their last
+        /// modification
+        #[arg(long, value_parser = parse_day_count)]
+        tier_cool_after: Option<u32>,
+        /// Resource group of the storage account (default: the configured
+        /// resource group)
+        #[arg(short, long)]
+        resource_group: Option<String>,
+    },
+    /// Show the storage account's lifecycle rules
+    Show {
+        /// Resource group of the storage account (default: the configured
+        /// resource group)
+        #[arg(short, long)]
+        resource_group: Option<String>,
+    },
 }
 
 #[cfg(feature = "file-ops")]
@@ -5,11 +5,14 @@
 use crate::backend::file::FileBackend;
 use crate::backend::BackendKind;
 use crate::blob::models::{
-    BlobListItem, FileDownloadRequest, FileInfo, FileListRequest, FileUploadRequest,
+    BlobListItem, FileCopyRequest, FileDetails, FileDownloadRequest, FileInfo, FileListPage,
+    FileListRequest, FilePropertiesUpdate, FileReadUrl, FileUploadRequest, FileUploadSpec,
 };
-use crate::cli::file::{FileCommands, SyncDirection};
+use crate::cli::file::{FileCommands, FileLifecycleCommands, SyncDirection};
 use crate::config::Config;
 use crate::error::{CrosstacheError, Result};
+use crate::utils::audit_log::{AuditEntry, AuditEvent};
+use crate::utils::checkpoint::Checkpoint;
 use crate::utils::format::OutputFormat;
 use crate::utils::output;
 use crate::utils::pagination::Pagination;
@@ -32,6 +35,10 @@ pub(crate) struct FileOps<'a> {
     /// Resolved backend kind, for backend-aware user messaging and
     /// Azure-only constraints (e.g. the blob 10-tag cap) in the shared handlers.
     kind: BackendKind,
+    /// The active env's `file_prefix` (normalized, trailing `/`). Every name
+    /// the handlers pass in is relative to it, and every name handed back is
+    /// stripped of it, so the namespace is invisible above this seam.
+    key_prefix: Option<String>,
 }
 
 impl<'a> FileOps<'a> {
@@ -48,17 +55,69 @@ impl<'a> FileOps<'a> {
             vault,
             backend_name,
             kind,
+            key_prefix: None,
         }
     }
 
+    /// Scope every operation to `key_prefix` (see [`Config::resolve_file_prefix`]).
+    fn with_key_prefix(mut self, key_prefix: Option<String>) -> Self {
+        self.key_prefix = key_prefix;
+        self
+    }
+
+    /// The stored key for a handler-facing `name`.
+    fn remote_name(&self, name: &str) -> String {
+        match &self.key_prefix {
+            Some(prefix) => format!("{prefix}{name}"),
+            None => name.to_string(),
+        }
+    }
+
+    /// The handler-facing name for a stored key.
+    fn local_name(&self, key: &str) -> String {
+        self.key_prefix
+            .as_deref()
+            .and_then(|prefix| key.strip_prefix(prefix))
+            .unwrap_or(key)
+            .to_string()
+    }
+
+    fn localize(&self, mut info: FileInfo) -> FileInfo {
+        info.name = self.local_name(&info.name);
+        info
+    }
+
+    fn scoped_list_request(&self, mut request: FileListRequest) -> FileListRequest {
+        if self.key_prefix.is_some() {
+            request.prefix = Some(self.remote_name(request.prefix.as_deref().unwrap_or("")));
+        }
+        request
+    }
+
     async fn upload_file(
         &self,
-        request: FileUploadRequest,
+        mut request: FileUploadRequest,
         reporter: &dyn ProgressReporter,
     ) -> Result<FileInfo> {
+        request.name = self.remote_name(&request.name);
         self.files
             .upload_file(self.vault, request, Some(reporter))
             .await
+            .map(|info| self.localize(info))
+            .map_err(CrosstacheError::from)
+    }
+
+    async fn upload_file_from_reader(
+        &self,
+        mut spec: FileUploadSpec,
+        reader: &mut (dyn tokio::io::AsyncRead + Unpin + Send),
+        reporter: &dyn ProgressReporter,
+    ) -> Result<FileInfo> {
+        spec.name = self.remote_name(&spec.name);
+        self.files
+            .upload_file_from_reader(self.vault, spec, reader, Some(reporter))
+            .await
+            .map(|info| self.localize(info))
             .map_err(CrosstacheError::from)
     }
 
@@ -71,7 +130,24 @@ impl<'a> FileOps<'a> {
             self.secrets,
             self.files,
             self.vault,
-            &request.name,
+            &self.remote_name(&request.name),
+            Some(reporter),
+        )
+        .await
+    }
+
+    async fn download_file_to_writer(
+        &self,
+        name: &str,
+        writer: &mut (dyn tokio::io::AsyncWrite + Unpin + Send),
+        reporter: &dyn ProgressReporter,
+    ) -> Result<u64> {
+        crate::secret::attachments::download_decrypted_to_writer(
+            self.secrets,
+            self.files,
+            self.vault,
+            &self.remote_name(name),
+            writer,
             Some(reporter),
         )
         .await
@@ -79,9 +155,10 @@ impl<'a> FileOps<'a> {
 
     async fn upload_file_encrypted(
         &self,
-        request: FileUploadRequest,
+        mut request: FileUploadRequest,
         reporter: &dyn ProgressReporter,
     ) -> Result<FileInfo> {
+        request.name = self.remote_name(&request.name);
         crate::secret::attachments::upload_encrypted(
             self.secrets,
             self.files,
@@ -90,32 +167,111 @@ impl<'a> FileOps<'a> {
             Some(reporter),
         )
         .await
+        .map(|info| self.localize(info))
     }
 
     async fn list_files(&self, request: FileListRequest) -> Result<Vec<FileInfo>> {
         self.files
-            .list_files(self.vault, request)
+            .list_files(self.vault, self.scoped_list_request(request))
             .await
+            .map(|files| files.into_iter().map(|f| self.localize(f)).collect())
             .map_err(CrosstacheError::from)
     }
 
+    async fn list_files_page(
+        &self,
+        request: FileListRequest,
+        marker: Option<&str>,
+    ) -> Result<FileListPage> {
+        let page = self
+            .files
+            .list_files_page(self.vault, self.scoped_list_request(request), marker)
+            .await
+            .map_err(CrosstacheError::from)?;
+        Ok(FileListPage {
+            files: page.files.into_iter().map(|f| self.localize(f)).collect(),
+            next_marker: page.next_marker,
+        })
+    }
+
     async fn list_files_hierarchical(&self, request: FileListRequest) -> Result<Vec<BlobListItem>> {
-        self.files
-            .list_files_hierarchical(self.vault, request)
+        let items = self
+            .files
+            .list_files_hierarchical(self.vault, self.scoped_list_request(request))
             .await
-            .map_err(CrosstacheError::from)
+            .map_err(CrosstacheError::from)?;
+        Ok(items
+            .into_iter()
+            .map(|item| match item {
+                BlobListItem::File(info) => BlobListItem::File(self.localize(info)),
+                BlobListItem::Directory { name, full_path } => BlobListItem::Directory {
+                    name,
+                    full_path: self.local_name(&full_path),
+                },
+            })
+            .collect())
     }
 
     async fn delete_file(&self, name: &str) -> Result<()> {
         self.files
-            .delete_file(self.vault, name)
+            .delete_file(self.vault, &self.remote_name(name))
             .await
             .map_err(CrosstacheError::from)
     }
 
     async fn get_file_info(&self, name: &str) -> Result<FileInfo> {
         self.files
-            .get_file_info(self.vault, name)
+            .get_file_info(self.vault, &self.remote_name(name))
+            .await
+            .map(|info| self.localize(info))
+            .map_err(CrosstacheError::from)
+    }
+
+    async fn get_file_details(&self, name: &str) -> Result<FileDetails> {
+        let mut details = self
+            .files
+            .get_file_details(self.vault, &self.remote_name(name))
+            .await
+            .map_err(CrosstacheError::from)?;
+        details.info = self.localize(details.info);
+        Ok(details)
+    }
+
+    async fn update_file_properties(
+        &self,
+        name: &str,
+        update: FilePropertiesUpdate,
+    ) -> Result<FileInfo> {
+        self.files
+            .update_file_properties(self.vault, &self.remote_name(name), update)
+            .await
+            .map(|info| self.localize(info))
+            .map_err(CrosstacheError::from)
+    }
+
+    async fn copy_file(
+        &self,
+        source: &str,
+        destination: &str,
+        destination_container: Option<String>,
+        overwrite: bool,
+    ) -> Result<FileInfo> {
+        let request = FileCopyRequest {
+            source: self.remote_name(source),
+            destination: self.remote_name(destination),
+            destination_container,
+            overwrite,
+        };
+        self.files
+            .copy_file(self.vault, request)
+            .await
+            .map(|info| self.localize(info))
+            .map_err(CrosstacheError::from)
+    }
+
+    async fn read_url(&self, name: &str, ttl: std::time::Duration) -> Result<FileReadUrl> {
+        self.files
+            .read_url(self.vault, &self.remote_name(name), ttl)
             .await
             .map_err(CrosstacheError::from)
     }
@@ -138,15 +294,23 @@ async fn resolve_file_backend(
     Ok((backend, backend_name, vault))
 }
 
+/// The fix the Azure capability-gate error names; also how the CLI spots
+/// that error to offer running it.
+const BLOB_ONLY_INIT: &str = "run 'xv init --blob-only'";
+
 /// Actionable capability-gate error for a backend without file storage.
 pub(crate) fn file_storage_unsupported_error(
     backend: &dyn crate::backend::Backend,
 ) -> CrosstacheError {
     use crate::backend::BackendKind;
     let hint = match backend.kind() {
-        BackendKind::Azure => "set a storage account (AZURE_STORAGE_ACCOUNT or [azure].storage_account) and run 'xv init'",
-        BackendKind::Aws => "set an S3 bucket ([aws].s3_bucket)",
-        BackendKind::Local => "the local backend stores files per vault; this should not happen",
+        BackendKind::Azure => format!(
+            "{BLOB_ONLY_INIT} to add a storage account (or set AZURE_STORAGE_ACCOUNT or [azure].storage_account)"
+        ),
+        BackendKind::Aws => "set an S3 bucket ([aws].s3_bucket)".to_string(),
+        BackendKind::Local => {
+            "the local backend stores files per vault; this should not happen".to_string()
+        }
     };
     CrosstacheError::invalid_argument(format!(
         "The {} backend has no file storage configured. To use 'xv file', {hint}.",
@@ -154,6 +318,12 @@ pub(crate) fn file_storage_unsupported_error(
     ))
 }
 
+/// True for the Azure error from [`file_storage_unsupported_error`].
+#[allow(dead_code)] // called from src/main.rs::offer_setup_fix (binary-only path)
+pub(crate) fn is_missing_blob_storage(error: &CrosstacheError) -> bool {
+    matches!(error, CrosstacheError::InvalidArgument(msg) if msg.contains(BLOB_ONLY_INIT))
+}
+
 pub(crate) fn progress_threshold_bytes(config: &Config) -> u64 {
     let blob_config = config.get_blob_config();
     (blob_config.progress_threshold_mb as u64) * 1024 * 1024
@@ -178,7 +348,8 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
         &vault,
         &backend_name,
         backend.kind(),
-    );
+    )
+    .with_key_prefix(config.resolve_file_prefix().await?);
 
     match command {
         FileCommands::Upload {
@@ -193,6 +364,7 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
             content_type,
             continue_on_error,
             encrypt,
+            force,
         } => {
             // ponytail: --encrypt is single-file only; extend to multi/recursive when needed
             if encrypt && (recursive || files.len() > 1) {
@@ -223,6 +395,7 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                     continue_on_error,
                     flatten,
                     prefix,
+                    force,
                     &config,
                 )
                 .await?;
@@ -237,6 +410,7 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                     tag,
                     content_type,
                     encrypt,
+                    force,
                     &config,
                 )
                 .await?;
@@ -254,6 +428,7 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                     metadata,
                     tag,
                     continue_on_error,
+                    force,
                     &config,
                 )
                 .await?;
@@ -269,6 +444,34 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                 });
             }
         }
+        FileCommands::Paste {
+            name,
+            group,
+            metadata,
+            tag,
+            content_type,
+            force,
+        } => {
+            execute_file_paste(
+                &blob_manager,
+                name,
+                group,
+                metadata,
+                tag,
+                content_type,
+                force,
+                &config,
+            )
+            .await?;
+            let cache_manager = crate::cache::CacheManager::from_config(&config);
+            for recursive in [true, false] {
+                cache_manager.invalidate(&crate::cache::CacheKey::FileList {
+                    backend: backend_name.clone(),
+                    vault_name: vault.clone(),
+                    recursive,
+                });
+            }
+        }
         FileCommands::Download {
             files,
             output,
@@ -277,7 +480,19 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
             flatten,
             force,
             continue_on_error,
+            resume,
         } => {
+            if output.as_deref() == Some("-") && (recursive || files.len() > 1 || rename.is_some())
+            {
+                return Err(CrosstacheError::invalid_argument(
+                    "--output - streams a single file and cannot be combined with --recursive, --rename or several files",
+                ));
+            }
+            if resume && !recursive && files.len() == 1 {
+                return Err(CrosstacheError::invalid_argument(
+                    "--resume applies to --recursive or multi-file downloads",
+                ));
+            }
             // Handle recursive download
             if recursive {
                 // Validate that --rename is not used with --recursive
@@ -286,7 +501,14 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                         "--rename cannot be used with --recursive",
                     ));
                 }
-                execute_file_download_recursive(
+                let mut checkpoint = open_download_checkpoint(
+                    &blob_manager,
+                    &files,
+                    output.as_deref(),
+                    flatten,
+                    resume,
+                )?;
+                let outcome = execute_file_download_recursive(
                     &blob_manager,
                     files,
                     output,
@@ -294,8 +516,11 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                     flatten,
                     continue_on_error,
                     &config,
+                    &mut checkpoint,
                 )
-                .await?;
+                .await;
+                checkpoint.finish(&outcome);
+                outcome?;
             } else {
                 // Validate --rename only works with single file
                 if rename.is_some() && files.len() > 1 {
@@ -315,15 +540,25 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                     execute_file_download(&blob_manager, &files[0], output_path, force, &config)
                         .await?;
                 } else {
-                    execute_file_download_multiple(
+                    let mut checkpoint = open_download_checkpoint(
+                        &blob_manager,
+                        &files,
+                        output.as_deref(),
+                        false,
+                        resume,
+                    )?;
+                    let outcome = execute_file_download_multiple(
                         &blob_manager,
                         files,
                         output,
                         force,
                         continue_on_error,
                         &config,
+                        &mut checkpoint,
                     )
-                    .await?;
+                    .await;
+                    checkpoint.finish(&outcome);
+                    outcome?;
                 }
             }
         }
@@ -337,12 +572,36 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
             recursive,
             names_only,
             no_cache,
+            continue_from,
         } => {
             use crate::utils::pagination::Pagination;
 
-            let pager = pager
-                .map(crate::cli::commands::PagerWhen::wants_pager)
-                .unwrap_or(false);
+            let pager = crate::cli::commands::PagerWhen::resolve(pager, &config);
+
+            if let Some(marker) = continue_from {
+                if limit.is_some() || page.is_some() {
+                    return Err(CrosstacheError::invalid_argument(
+                        "--continue pages on the server; use --page-size instead of --limit or --page",
+                    ));
+                }
+                let page_size = page_size.unwrap_or(DEFAULT_CONTINUATION_PAGE_SIZE);
+                if page_size == 0 {
+                    return Err(CrosstacheError::invalid_argument(
+                        "--page-size must be greater than zero",
+                    ));
+                }
+                execute_file_list_page(
+                    &blob_manager,
+                    prefix,
+                    group,
+                    page_size,
+                    (!marker.is_empty()).then_some(marker),
+                    names_only,
+                    &config,
+                )
+                .await?;
+                return Ok(());
+            }
 
             if limit.is_some() && page_size.is_some() {
                 return Err(CrosstacheError::invalid_argument(
@@ -404,8 +663,95 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
                 });
             }
         }
-        FileCommands::Info { name } => {
-            execute_file_info(&blob_manager, &name, &config).await?;
+        FileCommands::Info { name, url, url_ttl } => {
+            if url {
+                execute_file_read_url(&blob_manager, &name, url_ttl, &config).await?;
+            } else {
+                execute_file_info(&blob_manager, &name, &config).await?;
+            }
+        }
+        FileCommands::Update {
+            name,
+            group,
+            metadata,
+            tag,
+            replace,
+        } => {
+            execute_file_update(&blob_manager, &name, group, metadata, tag, replace, &config)
+                .await?;
+            let cache_manager = crate::cache::CacheManager::from_config(&config);
+            for recursive in [true, false] {
+                cache_manager.invalidate(&crate::cache::CacheKey::FileList {
+                    backend: backend_name.clone(),
+                    vault_name: vault.clone(),
+                    recursive,
+                });
+            }
+        }
+        FileCommands::Copy {
+            source,
+            destination,
+            container,
+            force,
+        } => {
+            execute_file_copy(
+                &blob_manager,
+                &source,
+                &destination,
+                container,
+                force,
+                false,
+            )
+            .await?;
+            let cache_manager = crate::cache::CacheManager::from_config(&config);
+            for recursive in [true, false] {
+                cache_manager.invalidate(&crate::cache::CacheKey::FileList {
+                    backend: backend_name.clone(),
+                    vault_name: vault.clone(),
+                    recursive,
+                });
+            }
+        }
+        FileCommands::Move {
+            source,
+            destination,
+            container,
+            force,
+        } => {
+            execute_file_copy(&blob_manager, &source, &destination, container, force, true).await?;
+            let cache_manager = crate::cache::CacheManager::from_config(&config);
+            for recursive in [true, false] {
+                cache_manager.invalidate(&crate::cache::CacheKey::FileList {
+                    backend: backend_name.clone(),
+                    vault_name: vault.clone(),
+                    recursive,
+                });
+            }
+        }
+        FileCommands::Archive {
+            prefix,
+            output,
+            archive_format,
+            force,
+        } => {
+            execute_file_archive(
+                &blob_manager,
+                prefix,
+                &output,
+                archive_format,
+                force,
+                &config,
+            )
+            .await?;
+        }
+        FileCommands::Lifecycle { command } => {
+            if backend.kind() != BackendKind::Azure {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "`xv file lifecycle` manages Azure Storage lifecycle policies; the {} backend has none.",
+                    backend.name()
+                )));
+            }
+            execute_file_lifecycle(command, &config).await?;
         }
         FileCommands::Sync {
             local_path,
@@ -439,6 +785,106 @@ pub(crate) async fn execute_file_command(command: FileCommands, config: Config)
     Ok(())
 }
 
+/// `xv file lifecycle set|show`: edit or list the storage account's
+/// management policy through ARM.
+async fn execute_file_lifecycle(command: FileLifecycleCommands, config: &Config) -> Result<()> {
+    use crate::blob::lifecycle::{
+        blob_prefix, rule_name, rules_in, upsert_rule, LifecycleRule, ManagementPolicyClient,
+    };
+    use crate::utils::format::TableFormatter;
+
+    let resource_group = match &command {
+        FileLifecycleCommands::Set { resource_group, .. }
+        | FileLifecycleCommands::Show { resource_group } => resource_group
+            .clone()
+            .unwrap_or_else(|| config.default_resource_group.clone()),
+    };
+    let blob_config = config.get_blob_config();
+    let auth_provider = crate::backend::BackendRegistry::create_azure_auth_provider(config)
+        .map_err(|e| CrosstacheError::authentication(e.to_string()))?;
+    let client = ManagementPolicyClient::new(
+        auth_provider,
+        &config.subscription_id,
+        &resource_group,
+        &blob_config.storage_account,
+    )?;
+
+    match command {
+        FileLifecycleCommands::Set {
+            prefix,
+            delete_after,
+            tier_cool_after,
+            ..
+        } => {
+            if delete_after.is_none() && tier_cool_after.is_none() {
+                return Err(CrosstacheError::invalid_argument(
+                    "Give --delete-after and/or --tier-cool-after",
+                ));
+            }
+            if let (Some(delete), Some(cool)) = (delete_after, tier_cool_after) {
+                if cool >= delete {
+                    return Err(CrosstacheError::invalid_argument(format!(
+                        "--tier-cool-after ({cool} days) must be shorter than --delete-after ({delete} days)"
+                    )));
+                }
+            }
+
+            let prefix = blob_prefix(&blob_config.container_name, prefix.as_deref());
+            let rule = LifecycleRule {
+                name: rule_name(&prefix),
+                prefix,
+                tier_cool_after_days: tier_cool_after,
+                delete_after_days: delete_after,
+                enabled: true,
+            };
+            let existing = client.get().await?;
+            let replaced = rules_in(existing.as_ref())
+                .iter()
+                .any(|r| r.name == rule.name);
+            client.put(&upsert_rule(existing.as_ref(), &rule)).await?;
+
+            output::success(&format!(
+                "{} lifecycle rule '{}' for '{}' on storage account '{}'",
+                if replaced { "Updated" } else { "Created" },
+                rule.name,
+                rule.prefix,
+                blob_config.storage_account
+            ));
+            if let Some(days) = rule.tier_cool_after_days {
+                println!("   Cool tier after: {days} days");
+            }
+            if let Some(days) = rule.delete_after_days {
+                println!("   Delete after: {days} days");
+            }
+            output::hint(
+                "Azure applies lifecycle rules once a day; changes can take up to 24 hours to act.",
+            );
+        }
+        FileLifecycleCommands::Show { .. } => {
+            let rules = rules_in(client.get().await?.as_ref());
+            let formatter = TableFormatter::new(
+                config.runtime_output_format,
+                config.no_color,
+                config.template.clone(),
+                config.runtime_columns.clone(),
+            );
+            let human_table_like = matches!(
+                config.runtime_output_format,
+                OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
+            );
+            if rules.is_empty() && human_table_like {
+                output::info(&crate::utils::list_output::empty_state_message(
+                    "lifecycle rules",
+                    None,
+                ));
+                return Ok(());
+            }
+            println!("{}", formatter.format_table(&rules)?);
+        }
+    }
+    Ok(())
+}
+
 /// `xv info` when resource type is file/blob.
 pub(crate) async fn execute_file_info_from_root(file_name: &str, config: &Config) -> Result<()> {
     let (backend, backend_name, vault) = resolve_file_backend(config).await?;
@@ -451,7 +897,8 @@ pub(crate) async fn execute_file_info_from_root(file_name: &str, config: &Config
         &vault,
         &backend_name,
         backend.kind(),
-    );
+    )
+    .with_key_prefix(config.resolve_file_prefix().await?);
 
     execute_file_info(&blob_manager, file_name, config).await
 }
@@ -519,6 +966,7 @@ async fn execute_file_upload(
     tags: Vec<(String, String)>,
     content_type: Option<String>,
     encrypt: bool,
+    force: bool,
     config: &Config,
 ) -> Result<()> {
     use crate::blob::models::FileUploadRequest;
@@ -526,6 +974,19 @@ async fn execute_file_upload(
     use std::fs;
     use std::path::Path;
 
+    if file_path == "-" {
+        let name = name.ok_or_else(|| {
+            CrosstacheError::invalid_argument("--name is required when uploading from stdin (-)")
+        })?;
+        if encrypt {
+            return Err(CrosstacheError::invalid_argument(
+                "--encrypt cannot be used when uploading from stdin (-)",
+            ));
+        }
+        return execute_stdin_upload(blob_manager, name, groups, metadata, tags, content_type)
+            .await;
+    }
+
     // Check if file exists
     if !Path::new(file_path).exists() {
         return Err(CrosstacheError::config(format!(
@@ -577,6 +1038,14 @@ async fn execute_file_upload(
         tags: tags_map,
     };
 
+    // Encrypted uploads are never identical: age output is randomized.
+    if !encrypt && !force && upload_is_unchanged(blob_manager, &upload_request).await {
+        output::info(&format!(
+            "File '{remote_name}' unchanged (identical content already stored), skipped upload"
+        ));
+        return Ok(());
+    }
+
     // Upload file
     let threshold = progress_threshold_bytes(config);
     let tty = is_tty();
@@ -605,6 +1074,151 @@ async fn execute_file_upload(
     Ok(())
 }
 
+/// Whether `request.name` already stores what `request` would upload: the
+/// backend reports a content MD5 that matches, and the stored file carries
+/// every group, metadata entry and tag the request sets (and its content
+/// type, when given). A failed lookup, or a file the backend has no MD5 for
+/// (Azure block-list and S3 multipart or SSE-KMS uploads), counts as changed.
+async fn upload_is_unchanged(blob_manager: &FileOps<'_>, request: &FileUploadRequest) -> bool {
+    let Ok(details) = blob_manager.get_file_details(&request.name).await else {
+        return false;
+    };
+    let Some(stored_md5) = details.content_md5.as_deref() else {
+        return false;
+    };
+    let stored = &details.info;
+    stored_md5.eq_ignore_ascii_case(&crate::backend::file::content_md5(&request.content))
+        && request
+            .content_type
+            .as_ref()
+            .is_none_or(|content_type| *content_type == stored.content_type)
+        && request.groups.iter().all(|g| stored.groups.contains(g))
+        && request
+            .metadata
+            .iter()
+            .all(|(k, v)| stored.metadata.get(k) == Some(v))
+        && request
+            .tags
+            .iter()
+            .all(|(k, v)| stored.tags.get(k) == Some(v))
+}
+
+/// `xv upload - --name <name>`: stream stdin to `name`. Azure and S3 send
+/// it in blocks/parts as it is read, so the stream is never held in memory;
+/// the local backend buffers it before writing.
+async fn execute_stdin_upload(
+    blob_manager: &FileOps<'_>,
+    name: String,
+    groups: Vec<String>,
+    metadata: Vec<(String, String)>,
+    tags: Vec<(String, String)>,
+    content_type: Option<String>,
+) -> Result<()> {
+    if blob_manager.kind == BackendKind::Azure && tags.len() > 10 {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Too many tags ({}) — Azure Blob Storage allows a maximum of 10 tags per blob. Remove {} tag(s).",
+            tags.len(),
+            tags.len() - 10
+        )));
+    }
+
+    let spec = FileUploadSpec {
+        name: name.clone(),
+        content_type,
+        groups,
+        metadata: metadata.into_iter().collect(),
+        tags: tags.into_iter().collect(),
+    };
+    // The length is unknown up front, so a TTY gets a spinner, not a bar.
+    let reporter: Box<dyn ProgressReporter> = if is_tty() {
+        Box::new(progress::SpinnerReporter::new(&format!(
+            "Uploading stdin to '{name}'..."
+        )))
+    } else {
+        Box::new(NoopReporter)
+    };
+    let mut stdin = tokio::io::stdin();
+    let file_info = blob_manager
+        .upload_file_from_reader(spec, &mut stdin, reporter.as_ref())
+        .await?;
+    reporter.finish_clear();
+    output::success(&format!(
+        "Successfully uploaded stdin as '{}'",
+        file_info.name
+    ));
+    println!("   Size: {} bytes", file_info.size);
+    println!("   Content-Type: {}", file_info.content_type);
+    Ok(())
+}
+
+/// `xv file paste`: upload the clipboard's text as `name`. The stored file
+/// records `uploaded_via=clipboard` in its metadata, and the upload is
+/// recorded in the transfer audit log before it starts.
+#[allow(clippy::too_many_arguments)]
+async fn execute_file_paste(
+    blob_manager: &FileOps<'_>,
+    name: String,
+    groups: Vec<String>,
+    metadata: Vec<(String, String)>,
+    tags: Vec<(String, String)>,
+    content_type: Option<String>,
+    force: bool,
+    config: &Config,
+) -> Result<()> {
+    crate::cli::helpers::ensure_qr_and_paste_allowed(config, "xv file paste")?;
+    if blob_manager.kind == BackendKind::Azure && tags.len() > 10 {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Too many tags ({}) — Azure Blob Storage allows a maximum of 10 tags per blob. Remove {} tag(s).",
+            tags.len(),
+            tags.len() - 10
+        )));
+    }
+    if !force {
+        match blob_manager.get_file_info(&name).await {
+            Ok(_) => {
+                return Err(CrosstacheError::config(format!(
+                    "File '{name}' already exists (use --force to overwrite)"
+                )))
+            }
+            // Azure reports a missing blob as VaultNotFound.
+            Err(CrosstacheError::SecretNotFound { .. } | CrosstacheError::VaultNotFound { .. }) => {
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    let text = crate::cli::helpers::read_clipboard().map_err(CrosstacheError::config)?;
+    if text.is_empty() {
+        return Err(CrosstacheError::config("The clipboard is empty"));
+    }
+    crate::utils::audit_log::append_transfer(
+        &AuditEntry::new(
+            AuditEvent::ClipboardPaste,
+            blob_manager.backend_name,
+            blob_manager.vault,
+        )
+        .detail(format!("file {name}")),
+    )?;
+
+    let mut metadata: std::collections::HashMap<String, String> = metadata.into_iter().collect();
+    metadata.insert("uploaded_via".to_string(), "clipboard".to_string());
+    let request = FileUploadRequest {
+        name,
+        content: text.into_bytes(),
+        content_type: Some(content_type.unwrap_or_else(|| "text/plain".to_string())),
+        groups,
+        metadata,
+        tags: tags.into_iter().collect(),
+    };
+    let info = blob_manager.upload_file(request, &NoopReporter).await?;
+    output::success(&format!(
+        "Uploaded clipboard to '{}' ({})",
+        info.name,
+        crate::utils::format::format_size(info.size)
+    ));
+    Ok(())
+}
+
 async fn execute_file_download(
     blob_manager: &FileOps<'_>,
     name: &str,
@@ -612,6 +1226,9 @@ async fn execute_file_download(
     force: bool,
     config: &Config,
 ) -> Result<()> {
+    if output.as_deref() == Some("-") {
+        return execute_file_download_to_stdout(blob_manager, name, config).await;
+    }
     let output_path = resolve_single_download_path(name, output.as_deref())?;
     execute_file_download_to_path(blob_manager, name, output_path, force, config).await
 }
@@ -676,6 +1293,40 @@ async fn execute_file_download_to_path(
     Ok(())
 }
 
+/// `--output -`: stream the file to stdout so it can be piped straight into
+/// another program. Progress and status go to stderr only.
+async fn execute_file_download_to_stdout(
+    blob_manager: &FileOps<'_>,
+    name: &str,
+    config: &Config,
+) -> Result<()> {
+    use std::io::IsTerminal;
+
+    let tty = std::io::stderr().is_terminal();
+    let file_size = if tty {
+        blob_manager
+            .get_file_info(name)
+            .await
+            .map(|info| info.size)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+    let reporter = progress::create_file_reporter(file_size, progress_threshold_bytes(config), tty);
+    reporter.set_message(format!("Downloading '{name}'..."));
+
+    let mut stdout = tokio::io::stdout();
+    let written = blob_manager
+        .download_file_to_writer(name, &mut stdout, reporter.as_ref())
+        .await?;
+    reporter.finish_clear();
+    output::success(&format!(
+        "Streamed '{name}' to stdout ({})",
+        crate::utils::format::format_size(written)
+    ));
+    Ok(())
+}
+
 pub(crate) fn resolve_single_download_path(name: &str, output: Option<&str>) -> Result<String> {
     use crate::utils::helpers::safe_join;
 
@@ -853,7 +1504,9 @@ async fn execute_file_list(
     };
     let use_cache = cache_manager.is_enabled() && !no_cache;
 
-    let is_unfiltered = prefix.is_none() && group.is_none();
+    // The cache holds the vault's whole listing; a namespaced view is a
+    // filtered one.
+    let is_unfiltered = prefix.is_none() && group.is_none() && blob_manager.key_prefix.is_none();
 
     if use_cache && is_unfiltered {
         if let Some(cached) = cache_manager.get::<Vec<BlobListItem>>(&cache_key) {
@@ -933,6 +1586,86 @@ async fn execute_file_list(
     Ok(())
 }
 
+/// Server-side page size for `xv file list --continue` without `--page-size`.
+const DEFAULT_CONTINUATION_PAGE_SIZE: usize = 1000;
+
+/// One server-side page of a recursive listing (`xv file list --continue`).
+/// The cache is bypassed, since a page is not the full listing. The token for
+/// the next page goes to stderr so stdout stays the page's data in every
+/// output format.
+async fn execute_file_list_page(
+    blob_manager: &FileOps<'_>,
+    prefix: Option<String>,
+    group: Option<String>,
+    page_size: usize,
+    marker: Option<String>,
+    names_only: bool,
+    config: &Config,
+) -> Result<()> {
+    let request = FileListRequest {
+        prefix: prefix.clone(),
+        groups: group.clone().map(|g| vec![g]),
+        limit: Some(page_size),
+        delimiter: None,
+    };
+    let FileListPage { files, next_marker } = blob_manager
+        .list_files_page(request, marker.as_deref())
+        .await?;
+
+    if names_only {
+        for file in &files {
+            println!("{}", file.name);
+        }
+    } else {
+        let items: Vec<BlobListItem> = files.into_iter().map(BlobListItem::File).collect();
+        let output = display_file_list_items(&items, true, config)?;
+        if !output.is_empty() {
+            println!("{output}");
+        }
+    }
+
+    if let Some(token) = next_marker {
+        output::hint(&format!(
+            "More files: {}",
+            continuation_command(
+                &token,
+                page_size,
+                prefix.as_deref(),
+                group.as_deref(),
+                names_only
+            )
+        ));
+    }
+    Ok(())
+}
+
+/// The `xv file list` invocation that fetches the page after `token`.
+fn continuation_command(
+    token: &str,
+    page_size: usize,
+    prefix: Option<&str>,
+    group: Option<&str>,
+    names_only: bool,
+) -> String {
+    // Markers carry `!`, `/` and `=`; single quotes keep shells from
+    // expanding them.
+    let quote = |value: &str| format!("'{}'", value.replace('\'', r"'\''"));
+    let mut command = format!("xv file list --continue {}", quote(token));
+    if page_size != DEFAULT_CONTINUATION_PAGE_SIZE {
+        command.push_str(&format!(" --page-size {page_size}"));
+    }
+    if let Some(prefix) = prefix {
+        command.push_str(&format!(" --prefix {}", quote(prefix)));
+    }
+    if let Some(group) = group {
+        command.push_str(&format!(" --group {}", quote(group)));
+    }
+    if names_only {
+        command.push_str(" --names-only");
+    }
+    command
+}
+
 async fn execute_file_delete(
     blob_manager: &FileOps<'_>,
     name: &str,
@@ -975,9 +1708,281 @@ async fn execute_file_delete(
 }
 
 async fn execute_file_info(blob_manager: &FileOps<'_>, name: &str, config: &Config) -> Result<()> {
-    // Get file info
-    let file_info = blob_manager.get_file_info(name).await?;
-    display_file_info(&file_info, config)
+    let details = blob_manager.get_file_details(name).await?;
+    display_file_details(&details, config)
+}
+
+/// `xv file update`: merge (or with `replace`, swap in) new groups,
+/// metadata and tags, then write them without re-uploading the content.
+async fn execute_file_update(
+    blob_manager: &FileOps<'_>,
+    name: &str,
+    groups: Vec<String>,
+    metadata: Vec<(String, String)>,
+    tags: Vec<(String, String)>,
+    replace: bool,
+    config: &Config,
+) -> Result<()> {
+    if groups.is_empty() && metadata.is_empty() && tags.is_empty() {
+        return Err(CrosstacheError::invalid_argument(
+            "Nothing to update: give --group, --metadata or --tag",
+        ));
+    }
+
+    let current = blob_manager.get_file_info(name).await?;
+    let update = merge_file_properties(&current, groups, metadata, tags, replace);
+    if let Some(tags) = &update.tags {
+        if blob_manager.kind == BackendKind::Azure && tags.len() > 10 {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "Too many tags ({}) — Azure Blob Storage allows a maximum of 10 tags per blob. Remove {} tag(s).",
+                tags.len(),
+                tags.len() - 10
+            )));
+        }
+    }
+
+    let updated = blob_manager.update_file_properties(name, update).await?;
+    output::success(&format!("Updated file '{}'", updated.name));
+    display_file_info(&updated, config)
+}
+
+/// `xv file cp` / `xv file mv`: copy `source` on the server, then for a
+/// move delete it once the copy exists.
+async fn execute_file_copy(
+    blob_manager: &FileOps<'_>,
+    source: &str,
+    destination: &str,
+    container: Option<String>,
+    force: bool,
+    remove_source: bool,
+) -> Result<()> {
+    if source == destination && container.is_none() {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "'{source}' is both the source and the destination"
+        )));
+    }
+
+    let target = match &container {
+        Some(container) => format!("'{destination}' in '{container}'"),
+        None => format!("'{destination}'"),
+    };
+    let copied = blob_manager
+        .copy_file(source, destination, container.clone(), force)
+        .await?;
+    if remove_source {
+        blob_manager.delete_file(source).await?;
+        output::success(&format!("Moved '{source}' to {target}"));
+    } else {
+        output::success(&format!(
+            "Copied '{source}' to {target} ({})",
+            crate::utils::format::format_size(copied.size)
+        ));
+    }
+    Ok(())
+}
+
+/// `xv file archive`: write every file under `prefix` into one local zip or
+/// tar.gz. The archive is built in a temporary file next to `output` and
+/// only moved into place once complete.
+async fn execute_file_archive(
+    blob_manager: &FileOps<'_>,
+    prefix: Option<String>,
+    output: &str,
+    format: Option<crate::blob::archive::ArchiveFormat>,
+    force: bool,
+    config: &Config,
+) -> Result<()> {
+    use crate::blob::archive::{ArchiveFormat, ArchiveWriter};
+
+    let output_path = Path::new(output);
+    let format = format
+        .or_else(|| ArchiveFormat::from_path(output_path))
+        .ok_or_else(|| {
+            CrosstacheError::invalid_argument(format!(
+                "Cannot tell the archive format from '{output}': name it .zip or .tar.gz, or pass --archive-format"
+            ))
+        })?;
+    if output_path.exists() && !force {
+        return Err(CrosstacheError::config(format!(
+            "File already exists: {output} (use --force to overwrite)"
+        )));
+    }
+
+    let files = blob_manager
+        .list_files(FileListRequest {
+            prefix: prefix.clone(),
+            groups: None,
+            limit: None,
+            delimiter: None,
+        })
+        .await?;
+    if files.is_empty() {
+        return Err(CrosstacheError::config(match &prefix {
+            Some(prefix) => format!("No files found matching prefix: {prefix}"),
+            None => "No files found to archive".to_string(),
+        }));
+    }
+
+    let parent = match output_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let temp = tempfile::NamedTempFile::new_in(parent)?;
+    let mut writer = ArchiveWriter::new(temp.reopen()?, format);
+
+    let tty = is_tty();
+    let mp = MultiProgressContext::new(files.len() as u64, progress_threshold_bytes(config), tty);
+    let _interrupt_guard = crate::utils::interrupt::watch();
+    let mut total_bytes = 0u64;
+    for (index, file_info) in files.iter().enumerate() {
+        if crate::utils::interrupt::requested() {
+            mp.finish();
+            let remaining: Vec<String> = files[index..].iter().map(|f| f.name.clone()).collect();
+            return Err(crate::utils::interrupt::stopped(index, &remaining));
+        }
+        if !tty {
+            println!("Adding: {}", file_info.name);
+        }
+        let content = blob_manager
+            .download_file(
+                FileDownloadRequest {
+                    name: file_info.name.clone(),
+                },
+                &NoopReporter,
+            )
+            .await?;
+        writer.append(&file_info.name, &content, file_info.last_modified)?;
+        total_bytes += content.len() as u64;
+        mp.advance_overall(&file_info.name);
+    }
+    mp.finish();
+    writer.finish()?;
+
+    if force {
+        temp.persist(output_path).map_err(|e| e.error)?;
+    } else {
+        temp.persist_noclobber(output_path).map_err(|e| e.error)?;
+    }
+    output::success(&format!(
+        "Archived {} file(s) ({}) to {output}",
+        files.len(),
+        crate::utils::format::format_size(total_bytes)
+    ));
+    Ok(())
+}
+
+/// The properties `xv file update` writes for `current`. Groups are added
+/// and metadata/tag keys overwritten, or with `replace` each kind that was
+/// given replaces the stored one. Tags stay `None` (untouched) unless some
+/// were given, and the reserved `groups` metadata key is never carried over
+/// as user metadata.
+fn merge_file_properties(
+    current: &FileInfo,
+    groups: Vec<String>,
+    metadata: Vec<(String, String)>,
+    tags: Vec<(String, String)>,
+    replace: bool,
+) -> FilePropertiesUpdate {
+    let mut merged_groups = if replace && !groups.is_empty() {
+        Vec::new()
+    } else {
+        current.groups.clone()
+    };
+    for group in groups {
+        if !merged_groups.contains(&group) {
+            merged_groups.push(group);
+        }
+    }
+
+    let mut merged_metadata: std::collections::HashMap<String, String> =
+        if replace && !metadata.is_empty() {
+            std::collections::HashMap::new()
+        } else {
+            current.metadata.clone()
+        };
+    merged_metadata.remove("groups");
+    merged_metadata.extend(metadata);
+
+    let merged_tags = (!tags.is_empty()).then(|| {
+        let mut merged = if replace {
+            std::collections::HashMap::new()
+        } else {
+            current.tags.clone()
+        };
+        merged.extend(tags);
+        merged
+    });
+
+    FilePropertiesUpdate {
+        groups: merged_groups,
+        metadata: merged_metadata,
+        tags: merged_tags,
+    }
+}
+
+/// `xv file info --url`: print a presigned read URL. Text output is the bare
+/// URL so it can be piped or substituted; the expiry goes to stderr.
+async fn execute_file_read_url(
+    blob_manager: &FileOps<'_>,
+    name: &str,
+    ttl_minutes: u32,
+    config: &Config,
+) -> Result<()> {
+    // Fail with "not found" instead of signing a URL for a missing file.
+    blob_manager.get_file_info(name).await?;
+    let read_url = blob_manager
+        .read_url(
+            name,
+            std::time::Duration::from_secs(u64::from(ttl_minutes) * 60),
+        )
+        .await?;
+
+    if config.output_json {
+        let json_output = serde_json::to_string_pretty(&read_url).map_err(|e| {
+            CrosstacheError::serialization(format!("Failed to serialize read URL: {e}"))
+        })?;
+        println!("{json_output}");
+    } else {
+        println!("{}", read_url.url);
+        output::hint(&format!(
+            "Anyone with this URL can read '{name}' until {}",
+            read_url.expires_on.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+    }
+    Ok(())
+}
+
+/// Render [`FileDetails`] to stdout: the [`FileInfo`] fields, then the
+/// storage properties the backend reported.
+fn display_file_details(details: &FileDetails, config: &Config) -> Result<()> {
+    if config.output_json {
+        let json_output = serde_json::to_string_pretty(details).map_err(|e| {
+            CrosstacheError::serialization(format!("Failed to serialize file info: {e}"))
+        })?;
+        println!("{json_output}");
+        return Ok(());
+    }
+
+    display_file_info(&details.info, config)?;
+    if let Some(tier) = &details.tier {
+        println!("  Access Tier: {tier}");
+    }
+    if let Some(lease_state) = &details.lease_state {
+        println!("  Lease State: {lease_state}");
+    }
+    if let Some(version_id) = &details.version_id {
+        println!("  Version ID: {version_id}");
+    }
+    if let Some(encrypted) = details.server_encrypted {
+        println!(
+            "  Server Encrypted: {}",
+            if encrypted { "yes" } else { "no" }
+        );
+    }
+    if let Some(md5) = &details.content_md5 {
+        println!("  Content-MD5: {md5}");
+    }
+    Ok(())
 }
 
 /// Render a [`FileInfo`] to stdout (shared by the Azure and AWS executors).
@@ -1179,6 +2184,7 @@ async fn execute_file_upload_recursive(
     continue_on_error: bool,
     flatten: bool,
     prefix: Option<String>,
+    force: bool,
     config: &Config,
 ) -> Result<()> {
     use std::path::Path;
@@ -1214,6 +2220,7 @@ async fn execute_file_upload_recursive(
     println!("Found {} file(s) to upload", all_files.len());
 
     let mut success_count = 0;
+    let mut unchanged_count = 0;
     let mut failure_count = 0;
     let threshold = progress_threshold_bytes(config);
     let tty = is_tty();
@@ -1268,17 +2275,27 @@ async fn execute_file_upload_recursive(
                 metadata: metadata.iter().cloned().collect::<HashMap<_, _>>(),
                 tags: tag.iter().cloned().collect::<HashMap<_, _>>(),
             };
-            blob_manager
-                .upload_file(upload_request, &NoopReporter)
-                .await
+            if !force && upload_is_unchanged(blob_manager, &upload_request).await {
+                Ok(false)
+            } else {
+                blob_manager
+                    .upload_file(upload_request, &NoopReporter)
+                    .await
+                    .map(|_| true)
+            }
         };
 
         match result {
-            Ok(_) => {
+            Ok(true) => {
                 success_count += 1;
                 mp.log(&format!("Uploaded: {}", file_info.blob_name));
                 mp.advance_overall(&file_info.blob_name);
             }
+            Ok(false) => {
+                unchanged_count += 1;
+                mp.log(&format!("Unchanged: {}", file_info.blob_name));
+                mp.advance_overall(&file_info.blob_name);
+            }
             Err(e) => {
                 output::error(&format!("Failed to upload '{}': {}", local_path_str, e));
                 failure_count += 1;
@@ -1303,6 +2320,9 @@ async fn execute_file_upload_recursive(
             output::should_use_rich_stdout()
         )
     );
+    if unchanged_count > 0 {
+        println!("  Unchanged: {unchanged_count}");
+    }
     if failure_count > 0 {
         println!(
             "  {}",
@@ -1331,6 +2351,7 @@ async fn execute_file_upload_multiple(
     metadata: Vec<(String, String)>,
     tag: Vec<(String, String)>,
     continue_on_error: bool,
+    force: bool,
     config: &Config,
 ) -> Result<()> {
     println!("Uploading {} file(s)...", files.len());
@@ -1348,6 +2369,7 @@ async fn execute_file_upload_multiple(
             tag.clone(),
             None,  // content_type is not allowed for multiple files
             false, // --encrypt is single-file only
+            force,
             config,
         )
         .await
@@ -1420,6 +2442,31 @@ pub(crate) fn resolve_multi_download_dir(output: Option<&str>) -> Result<PathBuf
     }
 }
 
+/// Checkpoint for a multi-file download, keyed by the source (backend,
+/// vault, env file prefix), the destination directory and the names or
+/// prefixes asked for.
+fn open_download_checkpoint(
+    blob_manager: &FileOps<'_>,
+    requested: &[String],
+    output: Option<&str>,
+    flatten: bool,
+    resume: bool,
+) -> Result<Checkpoint> {
+    let destination = output.unwrap_or(".");
+    let destination = std::path::absolute(destination)
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| destination.to_string());
+    let scope = format!(
+        "{}\n{}\n{}\n{destination}\nflatten={flatten}\n{}",
+        blob_manager.backend_name,
+        blob_manager.vault,
+        blob_manager.key_prefix.as_deref().unwrap_or(""),
+        requested.join("\n")
+    );
+    Checkpoint::open("file download", &scope, resume)
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn execute_file_download_multiple(
     blob_manager: &FileOps<'_>,
     files: Vec<String>,
@@ -1427,6 +2474,7 @@ async fn execute_file_download_multiple(
     force: bool,
     continue_on_error: bool,
     config: &Config,
+    checkpoint: &mut Checkpoint,
 ) -> Result<()> {
     use crate::utils::helpers::safe_join;
 
@@ -1436,8 +2484,19 @@ async fn execute_file_download_multiple(
 
     let mut success_count = 0;
     let mut error_count = 0;
+    let _interrupt_guard = crate::utils::interrupt::watch();
 
-    for file_name in files {
+    for (index, file_name) in files.iter().enumerate() {
+        if crate::utils::interrupt::requested() {
+            println!("\nDownload stopped: {success_count} succeeded, {error_count} failed");
+            return Err(crate::utils::interrupt::stopped(index, &files[index..]));
+        }
+        if checkpoint.is_done(file_name) {
+            println!("  {file_name}: downloaded by an earlier run (--resume)");
+            success_count += 1;
+            continue;
+        }
+        let file_name = file_name.clone();
         // Compute a unique per-file output path via traversal guard.
         let per_file_output = match safe_join(&output_dir, &file_name) {
             Ok(p) => p.to_string_lossy().into_owned(),
@@ -1475,6 +2534,7 @@ async fn execute_file_download_multiple(
                         output::should_use_rich_stdout()
                     )
                 );
+                checkpoint.mark_done(file_name);
                 success_count += 1;
             }
             Err(e) => {
@@ -1505,6 +2565,7 @@ async fn execute_file_download_multiple(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_file_download_recursive(
     blob_manager: &FileOps<'_>,
     prefixes: Vec<String>,
@@ -1513,6 +2574,7 @@ async fn execute_file_download_recursive(
     flatten: bool,
     continue_on_error: bool,
     config: &Config,
+    checkpoint: &mut Checkpoint,
 ) -> Result<()> {
     use crate::blob::models::FileListRequest;
     use std::fs;
@@ -1579,9 +2641,26 @@ async fn execute_file_download_recursive(
     let threshold = progress_threshold_bytes(config);
     let tty = is_tty();
     let mp = MultiProgressContext::new(all_files_to_download.len() as u64, threshold, tty);
+    let _interrupt_guard = crate::utils::interrupt::watch();
 
-    for file_info in &all_files_to_download {
+    for (index, file_info) in all_files_to_download.iter().enumerate() {
+        if crate::utils::interrupt::requested() {
+            mp.finish();
+            let remaining: Vec<String> = all_files_to_download[index..]
+                .iter()
+                .map(|f| f.name.clone())
+                .collect();
+            return Err(crate::utils::interrupt::stopped(index, &remaining));
+        }
         let blob_name = &file_info.name;
+        if checkpoint.is_done(blob_name) {
+            mp.log(&format!(
+                "Skipped: {blob_name} (downloaded by an earlier run)"
+            ));
+            success_count += 1;
+            mp.advance_overall(blob_name);
+            continue;
+        }
 
         // Determine local file path.
         // Security: blob names come from the remote listing and are untrusted.
@@ -1677,6 +2756,7 @@ async fn execute_file_download_recursive(
 
         match result {
             Ok(_) => {
+                checkpoint.mark_done(blob_name.as_str());
                 success_count += 1;
                 mp.log(&format!("Downloaded: {}", blob_name));
                 mp.advance_overall(blob_name);
@@ -1757,8 +2837,14 @@ async fn execute_file_delete_multiple(
 
     let mut success_count = 0;
     let mut error_count = 0;
+    let _interrupt_guard = crate::utils::interrupt::watch();
 
-    for file_name in files {
+    for (index, file_name) in files.iter().enumerate() {
+        if crate::utils::interrupt::requested() {
+            println!("\nDelete stopped: {success_count} succeeded, {error_count} failed");
+            return Err(crate::utils::interrupt::stopped(index, &files[index..]));
+        }
+        let file_name = file_name.clone();
         match execute_file_delete(blob_manager, &file_name, force, config).await {
             Ok(_) => {
                 println!(
@@ -1965,13 +3051,15 @@ async fn file_sync_delete_remote_not_local(
 }
 
 /// Read local file, upload blob, align local mtime to server `last_modified`.
+/// A file whose content is already stored is not re-sent; its local mtime
+/// is aligned all the same, and `false` is returned.
 async fn file_sync_perform_upload(
     blob_manager: &FileOps<'_>,
     info: &FileUploadInfo,
     blob_name: &str,
     output_json: bool,
     reporter: &dyn crate::utils::progress::ProgressReporter,
-) -> Result<()> {
+) -> Result<bool> {
     use crate::blob::models::FileUploadRequest;
     use crate::blob::sync;
     use std::collections::HashMap;
@@ -1987,12 +3075,20 @@ async fn file_sync_perform_upload(
         metadata: HashMap::new(),
         tags: HashMap::new(),
     };
+    if upload_is_unchanged(blob_manager, &upload_request).await {
+        if !output_json && !is_tty() {
+            println!("unchanged: {blob_name}");
+        }
+        let remote = blob_manager.get_file_info(blob_name).await?;
+        sync::set_file_mtime_utc(&info.local_path, remote.last_modified)?;
+        return Ok(false);
+    }
     if !output_json && !is_tty() {
         println!("upload: {} → {blob_name}", info.local_path.display());
     }
     let uploaded_info = blob_manager.upload_file(upload_request, reporter).await?;
     sync::set_file_mtime_utc(&info.local_path, uploaded_info.last_modified)?;
-    Ok(())
+    Ok(true)
 }
 
 /// Download blob to local path (with traversal check, parents, mtime).
@@ -2159,7 +3255,7 @@ async fn execute_file_sync(
                     mp.advance_overall(blob_name);
                     continue;
                 }
-                file_sync_perform_upload(
+                let uploaded = file_sync_perform_upload(
                     blob_manager,
                     info,
                     blob_name,
@@ -2167,15 +3263,22 @@ async fn execute_file_sync(
                     &NoopReporter,
                 )
                 .await?;
-                summary.uploaded += 1;
-                if tty && !config.output_json {
-                    mp.log(&format!(
-                        "upload: {} → {blob_name}",
-                        info.local_path.display()
-                    ));
+                if uploaded {
+                    summary.uploaded += 1;
+                    if tty && !config.output_json {
+                        mp.log(&format!(
+                            "upload: {} → {blob_name}",
+                            info.local_path.display()
+                        ));
+                    }
+                    mutated = true;
+                } else {
+                    summary.skipped += 1;
+                    if tty && !config.output_json {
+                        mp.log(&format!("unchanged: {blob_name}"));
+                    }
                 }
                 mp.advance_overall(blob_name);
-                mutated = true;
             }
             mp.finish();
             file_sync_delete_remote_not_local(
@@ -2287,7 +3390,7 @@ async fn execute_file_sync(
                             mp.advance_overall(blob_name);
                             continue;
                         }
-                        file_sync_perform_upload(
+                        let uploaded = file_sync_perform_upload(
                             blob_manager,
                             info,
                             blob_name,
@@ -2295,15 +3398,22 @@ async fn execute_file_sync(
                             &NoopReporter,
                         )
                         .await?;
-                        summary.uploaded += 1;
-                        if tty && !config.output_json {
-                            mp.log(&format!(
-                                "upload: {} → {blob_name}",
-                                info.local_path.display()
-                            ));
+                        if uploaded {
+                            summary.uploaded += 1;
+                            if tty && !config.output_json {
+                                mp.log(&format!(
+                                    "upload: {} → {blob_name}",
+                                    info.local_path.display()
+                                ));
+                            }
+                            mutated = true;
+                        } else {
+                            summary.skipped += 1;
+                            if tty && !config.output_json {
+                                mp.log(&format!("unchanged: {blob_name}"));
+                            }
                         }
                         mp.advance_overall(blob_name);
-                        mutated = true;
                     }
                     (false, true) => {
                         let remote_info = remote_by_name.get(blob_name).unwrap();
@@ -2360,7 +3470,7 @@ async fn execute_file_sync(
                                     mp.advance_overall(blob_name);
                                     continue;
                                 }
-                                file_sync_perform_upload(
+                                let uploaded = file_sync_perform_upload(
                                     blob_manager,
                                     info,
                                     blob_name,
@@ -2368,15 +3478,22 @@ async fn execute_file_sync(
                                     &NoopReporter,
                                 )
                                 .await?;
-                                summary.uploaded += 1;
-                                if tty && !config.output_json {
-                                    mp.log(&format!(
-                                        "upload: {} → {blob_name}",
-                                        info.local_path.display()
-                                    ));
+                                if uploaded {
+                                    summary.uploaded += 1;
+                                    if tty && !config.output_json {
+                                        mp.log(&format!(
+                                            "upload: {} → {blob_name}",
+                                            info.local_path.display()
+                                        ));
+                                    }
+                                    mutated = true;
+                                } else {
+                                    summary.skipped += 1;
+                                    if tty && !config.output_json {
+                                        mp.log(&format!("unchanged: {blob_name}"));
+                                    }
                                 }
                                 mp.advance_overall(blob_name);
-                                mutated = true;
                             }
                             BothAction::Download => {
                                 let target =
@@ -2538,7 +3655,8 @@ pub(crate) async fn execute_file_upload_quick(
         &vault,
         &backend_name,
         backend.kind(),
-    );
+    )
+    .with_key_prefix(config.resolve_file_prefix().await?);
 
     // Convert parameters to match FileCommands::Upload format
     let groups_vec = groups
@@ -2565,6 +3683,7 @@ pub(crate) async fn execute_file_upload_quick(
         Vec::new(),
         None,
         false, // no --encrypt flag on the quick-upload path
+        false,
         config,
     )
     .await
@@ -2587,8 +3706,17 @@ pub(crate) async fn execute_file_download_quick(
         &vault,
         &backend_name,
         backend.kind(),
-    );
+    )
+    .with_key_prefix(config.resolve_file_prefix().await?);
 
+    if output.as_deref() == Some("-") {
+        if open {
+            return Err(CrosstacheError::invalid_argument(
+                "--open cannot be used with --output -",
+            ));
+        }
+        return execute_file_download_to_stdout(&blob_manager, name, config).await;
+    }
     let final_output_path = resolve_single_download_path(name, output.as_deref())?;
     execute_file_download(
         &blob_manager,
@@ -2790,4 +3918,78 @@ mod tests {
         assert!(!local_meta.contains_key("attachments/db/cert.pem"));
         assert!(local_by_blob.contains_key("docs/plain.txt"));
     }
+
+    #[test]
+    fn continuation_command_repeats_the_listing_filters() {
+        assert_eq!(
+            continuation_command(
+                "2!96!MDA=",
+                DEFAULT_CONTINUATION_PAGE_SIZE,
+                None,
+                None,
+                false
+            ),
+            "xv file list --continue '2!96!MDA='"
+        );
+        assert_eq!(
+            continuation_command("tok", 50, Some("it's/"), Some("ops"), true),
+            "xv file list --continue 'tok' --page-size 50 --prefix 'it'\\''s/' --group 'ops' --names-only"
+        );
+    }
+
+    fn stored_file() -> FileInfo {
+        FileInfo {
+            name: "report.pdf".to_string(),
+            size: 1,
+            content_type: "application/pdf".to_string(),
+            last_modified: chrono::Utc::now(),
+            etag: String::new(),
+            groups: vec!["finance".to_string()],
+            metadata: std::collections::HashMap::from([
+                ("groups".to_string(), "finance".to_string()),
+                ("owner".to_string(), "ana".to_string()),
+            ]),
+            tags: std::collections::HashMap::from([("env".to_string(), "prod".to_string())]),
+        }
+    }
+
+    #[test]
+    fn file_update_merges_by_default() {
+        let update = merge_file_properties(
+            &stored_file(),
+            vec!["audit".to_string(), "finance".to_string()],
+            vec![("owner".to_string(), "bo".to_string())],
+            Vec::new(),
+            false,
+        );
+        assert_eq!(update.groups, vec!["finance", "audit"]);
+        assert_eq!(
+            update.metadata,
+            std::collections::HashMap::from([("owner".to_string(), "bo".to_string())])
+        );
+        assert_eq!(update.tags, None, "tags not given must stay untouched");
+    }
+
+    #[test]
+    fn file_update_replace_swaps_only_the_given_kinds() {
+        let update = merge_file_properties(
+            &stored_file(),
+            Vec::new(),
+            vec![("team".to_string(), "ops".to_string())],
+            vec![("tier".to_string(), "cold".to_string())],
+            true,
+        );
+        assert_eq!(update.groups, vec!["finance"]);
+        assert_eq!(
+            update.metadata,
+            std::collections::HashMap::from([("team".to_string(), "ops".to_string())])
+        );
+        assert_eq!(
+            update.tags,
+            Some(std::collections::HashMap::from([(
+                "tier".to_string(),
+                "cold".to_string()
+            )]))
+        );
+    }
 }
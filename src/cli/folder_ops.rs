@@ -0,0 +1,271 @@
+//! CLI executors for `xv folder` / `xv folders`: the folder tree with
+//! secret counts, and `folder rename`.
+//!
+//! Folders are the client-side view `xv ls` derives from each secret's
+//! `folder` tag; renaming one is a bulk `xv mv OLD/ NEW/`.
+
+use std::collections::BTreeMap;
+
+use crate::backend::BackendRegistry;
+use crate::cli::commands::FolderCommands;
+use crate::cli::ls_view::relative_to_scope;
+use crate::config::Config;
+use crate::error::Result;
+use crate::secret::manager::SecretSummary;
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+
+pub(crate) async fn execute_folder_command(
+    command: Option<FolderCommands>,
+    path: Option<String>,
+    config: Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        None => execute_folder_tree(path, &config, registry).await,
+        Some(FolderCommands::Rename {
+            old,
+            new,
+            dry_run,
+            yes,
+        }) => {
+            let new = if new.trim_matches('/').is_empty() {
+                "/".to_string()
+            } else {
+                format!("{}/", new.trim_end_matches('/'))
+            };
+            crate::cli::mv_ops::execute_mv(
+                Some(format!("{}/", old.trim_end_matches('/'))),
+                Some(new),
+                None,
+                dry_run,
+                yes,
+                config,
+                registry,
+            )
+            .await
+        }
+    }
+}
+
+/// Secret counts for one folder of the tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct FolderCount {
+    /// Secrets directly in the folder.
+    direct: usize,
+    /// Secrets in the folder and all of its subfolders.
+    total: usize,
+}
+
+/// One row of `xv folders --format json|yaml|csv`.
+#[derive(tabled::Tabled, serde::Serialize)]
+struct FolderRow {
+    #[tabled(rename = "Folder")]
+    folder: String,
+    #[tabled(rename = "Secrets")]
+    secrets: usize,
+    #[tabled(rename = "Total")]
+    total: usize,
+}
+
+/// Count the secrets under `root` ("" = vault root) per folder, keyed by
+/// the folder path relative to `root`. Every ancestor of a folder is
+/// present, even one holding no secrets itself. Also returns the number
+/// of secrets directly in `root`.
+fn folder_counts(secrets: &[SecretSummary], root: &str) -> (BTreeMap<String, FolderCount>, usize) {
+    let mut counts: BTreeMap<String, FolderCount> = BTreeMap::new();
+    let mut at_root = 0;
+    for secret in secrets {
+        let folder = secret.folder.as_deref().unwrap_or("");
+        let Some(rel) = relative_to_scope(folder, root) else {
+            continue;
+        };
+        if rel.is_empty() {
+            at_root += 1;
+            continue;
+        }
+        let mut end = 0;
+        for segment in rel.split('/') {
+            end += segment.len();
+            counts.entry(rel[..end].to_string()).or_default().total += 1;
+            end += 1;
+        }
+        if let Some(count) = counts.get_mut(rel) {
+            count.direct += 1;
+        }
+    }
+    (counts, at_root)
+}
+
+/// Render `counts` (from [`folder_counts`]) as an indented tree under a
+/// `header` line, one `name/ (total)` entry per folder.
+fn render_tree(header: &str, counts: &BTreeMap<String, FolderCount>) -> String {
+    fn children<'a>(
+        counts: &'a BTreeMap<String, FolderCount>,
+        parent: &str,
+    ) -> Vec<(&'a str, &'a FolderCount)> {
+        counts
+            .iter()
+            .filter(|(path, _)| match path.rsplit_once('/') {
+                Some((dir, _)) => dir == parent,
+                None => parent.is_empty(),
+            })
+            .map(|(path, count)| (path.as_str(), count))
+            .collect()
+    }
+
+    fn walk(counts: &BTreeMap<String, FolderCount>, parent: &str, prefix: &str, out: &mut String) {
+        let entries = children(counts, parent);
+        for (i, (path, count)) in entries.iter().enumerate() {
+            let last = i + 1 == entries.len();
+            let name = path.rsplit('/').next().unwrap_or(path);
+            out.push_str(&format!(
+                "{prefix}{}{name}/ ({})\n",
+                if last { "└── " } else { "├── " },
+                count.total
+            ));
+            let child_prefix = format!("{prefix}{}", if last { "    " } else { "│   " });
+            walk(counts, path, &child_prefix, out);
+        }
+    }
+
+    let mut out = format!("{header}\n");
+    walk(counts, "", "", &mut out);
+    out
+}
+
+async fn execute_folder_tree(
+    path: Option<String>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let root = path
+        .as_deref()
+        .map(|p| p.trim_matches('/').to_string())
+        .unwrap_or_default();
+    if !root.is_empty() {
+        crate::utils::helpers::validate_folder_path(&root)?;
+    }
+
+    let (backend, _backend_name, vault_name) =
+        crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+    let secrets = backend.secrets().list_secrets(&vault_name, None).await?;
+    let (counts, at_root) = folder_counts(&secrets, &root);
+
+    if config.format_explicit && config.runtime_output_format != OutputFormat::Table {
+        let rows: Vec<FolderRow> = counts
+            .iter()
+            .map(|(rel, count)| FolderRow {
+                folder: if root.is_empty() {
+                    rel.clone()
+                } else {
+                    format!("{root}/{rel}")
+                },
+                secrets: count.direct,
+                total: count.total,
+            })
+            .collect();
+        let formatter = crate::utils::format::TableFormatter::new(
+            config.runtime_output_format,
+            config.no_color,
+            config.template.clone(),
+            config.runtime_columns.clone(),
+        );
+        println!("{}", formatter.format_table(&rows)?);
+        return Ok(());
+    }
+
+    let total = at_root
+        + counts
+            .iter()
+            .filter(|(rel, _)| !rel.contains('/'))
+            .map(|(_, count)| count.total)
+            .sum::<usize>();
+    if counts.is_empty() {
+        match (root.is_empty(), total) {
+            (true, _) => output::info(&format!("No folders in vault '{vault_name}'.")),
+            (false, 0) => output::info(&format!("No secrets in folder '{root}'.")),
+            (false, _) => output::info(&format!("Folder '{root}' has no subfolders.")),
+        }
+        return Ok(());
+    }
+    let header = if root.is_empty() {
+        format!("{vault_name} ({total})")
+    } else {
+        format!("{root}/ ({total})")
+    };
+    print!("{}", render_tree(&header, &counts));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(name: &str, folder: Option<&str>) -> SecretSummary {
+        SecretSummary {
+            name: name.to_string(),
+            original_name: name.to_string(),
+            note: None,
+            folder: folder.map(str::to_string),
+            groups: None,
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            content_type: String::new(),
+            tags: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn counts_roll_up_to_ancestors() {
+        let secrets = vec![
+            secret("a", None),
+            secret("b", Some("app")),
+            secret("c", Some("app/db")),
+            secret("d", Some("app/db")),
+            secret("e", Some("prod/edge/eu")),
+        ];
+        let (counts, at_root) = folder_counts(&secrets, "");
+        assert_eq!(at_root, 1);
+        assert_eq!(
+            counts["app"],
+            FolderCount {
+                direct: 1,
+                total: 3
+            }
+        );
+        assert_eq!(
+            counts["app/db"],
+            FolderCount {
+                direct: 2,
+                total: 2
+            }
+        );
+        assert_eq!(
+            counts["prod"],
+            FolderCount {
+                direct: 0,
+                total: 1
+            }
+        );
+        assert_eq!(counts["prod/edge/eu"].direct, 1);
+
+        let (scoped, at_app) = folder_counts(&secrets, "app");
+        assert_eq!(at_app, 1);
+        assert_eq!(scoped.keys().collect::<Vec<_>>(), vec!["db"]);
+
+        let expected = [
+            "kv (5)",
+            "├── app/ (3)",
+            "│   └── db/ (2)",
+            "└── prod/ (1)",
+            "    └── edge/ (1)",
+            "        └── eu/ (1)",
+        ];
+        assert_eq!(
+            render_tree("kv (5)", &counts),
+            format!("{}\n", expected.join("\n"))
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! CLI executors for `xv group`: list, show, rename, delete, add, remove.
+//!
+//! Groups live in each secret's comma-separated `groups` tag; there is no
+//! group object. Renaming or deleting a group rewrites that tag on every
+//! member, and add/remove rewrite it on the named secrets.
+
+use crate::backend::BackendRegistry;
+use crate::cli::commands::GroupCommands;
+use crate::cli::helpers::{confirm_proceed, resolve_vault_for_trait, use_trait_path};
+use crate::cli::ls_view::{display_name, qualified_display_name};
+use crate::cli::secret_ops::invalidate_trait_secret_cache;
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::{
+    groups_tag_contains, FieldUpdate, SecretSummary, SecretUpdateRequest,
+};
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+use crate::utils::suggestions::closest_match;
+
+pub(crate) async fn execute_group_command(
+    command: GroupCommands,
+    config: Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        GroupCommands::List { no_cache } => {
+            crate::cli::secret_ops::execute_group_list(no_cache, config, registry).await
+        }
+        GroupCommands::Show { group } => execute_group_show(&group, &config, registry).await,
+        GroupCommands::Rename {
+            old,
+            new,
+            dry_run,
+            yes,
+        } => {
+            validate_group_name(&new)?;
+            let change = GroupChange::Rename {
+                from: &old,
+                to: &new,
+            };
+            apply_group_change(change, Selection::Members, dry_run, yes, &config, registry).await
+        }
+        GroupCommands::Delete {
+            group,
+            dry_run,
+            yes,
+        } => {
+            let change = GroupChange::Remove(&group);
+            apply_group_change(change, Selection::Members, dry_run, yes, &config, registry).await
+        }
+        GroupCommands::Add {
+            group,
+            secrets,
+            filter,
+            dry_run,
+            yes,
+        } => {
+            validate_group_name(&group)?;
+            let selection = Selection::Named {
+                names: &secrets,
+                filter: filter.as_deref(),
+            };
+            let change = GroupChange::Add(&group);
+            apply_group_change(change, selection, dry_run, yes, &config, registry).await
+        }
+        GroupCommands::Remove {
+            group,
+            secrets,
+            filter,
+            dry_run,
+            yes,
+        } => {
+            let selection = Selection::Named {
+                names: &secrets,
+                filter: filter.as_deref(),
+            };
+            let change = GroupChange::Remove(&group);
+            apply_group_change(change, selection, dry_run, yes, &config, registry).await
+        }
+    }
+}
+
+/// Group names are stored comma-separated, so they cannot contain a comma.
+fn validate_group_name(group: &str) -> Result<()> {
+    if group.trim().is_empty() || group.contains(',') || group.trim() != group {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "invalid group name '{group}': use a non-empty name without commas or surrounding spaces"
+        )));
+    }
+    Ok(())
+}
+
+/// An edit to secrets' group lists.
+#[derive(Debug, Clone, Copy)]
+enum GroupChange<'a> {
+    Add(&'a str),
+    Remove(&'a str),
+    Rename { from: &'a str, to: &'a str },
+}
+
+impl GroupChange<'_> {
+    /// The group whose members `Selection::Members` picks.
+    fn source_group(&self) -> &str {
+        match self {
+            GroupChange::Add(group) | GroupChange::Remove(group) => group,
+            GroupChange::Rename { from, .. } => from,
+        }
+    }
+
+    /// `groups` (the raw tag value) after this change, or `None` when the
+    /// change leaves it as is. Order is kept and duplicates are dropped.
+    fn apply(&self, groups: Option<&str>) -> Option<Vec<String>> {
+        let current: Vec<&str> = groups
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|g| !g.is_empty())
+            .collect();
+        let renamed: Vec<&str> = match *self {
+            GroupChange::Add(group) => {
+                if current.contains(&group) {
+                    return None;
+                }
+                current.iter().copied().chain([group]).collect()
+            }
+            GroupChange::Remove(group) => {
+                if !current.contains(&group) {
+                    return None;
+                }
+                current.into_iter().filter(|g| *g != group).collect()
+            }
+            GroupChange::Rename { from, to } => {
+                if !current.contains(&from) {
+                    return None;
+                }
+                current
+                    .into_iter()
+                    .map(|g| if g == from { to } else { g })
+                    .collect()
+            }
+        };
+        let mut updated: Vec<String> = Vec::with_capacity(renamed.len());
+        for group in renamed {
+            if !updated.iter().any(|u| u == group) {
+                updated.push(group.to_string());
+            }
+        }
+        Some(updated)
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            GroupChange::Add(group) => format!("Add to group '{group}'"),
+            GroupChange::Remove(group) => format!("Remove from group '{group}'"),
+            GroupChange::Rename { from, to } => format!("Rename group '{from}' to '{to}' on"),
+        }
+    }
+}
+
+/// Which secrets a change is applied to.
+enum Selection<'a> {
+    /// Every current member of the change's source group.
+    Members,
+    /// Secrets named on the command line (display name, backend name or
+    /// `folder/name`) plus those whose name matches `filter`.
+    Named {
+        names: &'a [String],
+        filter: Option<&'a str>,
+    },
+}
+
+impl Selection<'_> {
+    fn select<'s>(
+        &self,
+        secrets: &'s [SecretSummary],
+        group: &str,
+        vault_name: &str,
+    ) -> Result<Vec<&'s SecretSummary>> {
+        let (names, filter) = match self {
+            Selection::Members => {
+                return Ok(secrets
+                    .iter()
+                    .filter(|s| groups_tag_contains(s.groups.as_deref(), group))
+                    .collect())
+            }
+            Selection::Named { names, filter } => (names, filter),
+        };
+
+        let matcher = filter
+            .map(crate::utils::helpers::compile_name_glob)
+            .transpose()?;
+        let mut selected: Vec<&SecretSummary> = Vec::new();
+        for name in names.iter() {
+            let found = secrets.iter().find(|s| {
+                s.name == *name
+                    || display_name(s) == name.as_str()
+                    || qualified_display_name(s, "") == *name
+            });
+            let Some(found) = found else {
+                let candidates: Vec<String> = secrets
+                    .iter()
+                    .map(|s| qualified_display_name(s, ""))
+                    .collect();
+                return Err(CrosstacheError::secret_not_found(format!(
+                    "{name} (in vault '{vault_name}')"
+                ))
+                .with_suggestion(closest_match(name, &candidates).map(String::from)));
+            };
+            selected.push(found);
+        }
+        if let (Some(matcher), Some(pattern)) = (&matcher, filter) {
+            let matched: Vec<&SecretSummary> = secrets
+                .iter()
+                .filter(|s| {
+                    crate::utils::helpers::glob_matches_either_name(
+                        matcher,
+                        &s.name,
+                        &s.original_name,
+                    )
+                })
+                .collect();
+            if matched.is_empty() {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "no secrets matched --filter '{pattern}'"
+                )));
+            }
+            selected.extend(matched);
+        }
+        selected.sort_by(|a, b| a.name.cmp(&b.name));
+        selected.dedup_by(|a, b| a.name == b.name);
+        Ok(selected)
+    }
+}
+
+/// Fetch the active vault's secrets (attachment key excluded), bypassing
+/// the cache since the result drives writes.
+async fn fetch_secrets(
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<(
+    std::sync::Arc<dyn crate::backend::Backend>,
+    String,
+    Vec<SecretSummary>,
+)> {
+    if !use_trait_path(registry) {
+        return Err(CrosstacheError::config(
+            "No backend registry available. Run 'xv config show' to check your configuration.",
+        ));
+    }
+    let reg = registry.expect("use_trait_path guarantees Some");
+    let vault_name = resolve_vault_for_trait(config, registry).await?;
+    let backend = reg.active_arc();
+    let mut secrets = backend.secrets().list_secrets(&vault_name, None).await?;
+    secrets.retain(|s| s.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET);
+    Ok((backend, vault_name, secrets))
+}
+
+/// One row of `xv group show`.
+#[derive(Debug, Clone, serde::Serialize, tabled::Tabled)]
+struct GroupMemberRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Folder")]
+    folder: String,
+    #[tabled(rename = "Updated")]
+    updated: String,
+    #[tabled(rename = "Enabled")]
+    enabled: bool,
+}
+
+async fn execute_group_show(
+    group: &str,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let (_backend, vault_name, secrets) = fetch_secrets(config, registry).await?;
+    let mut rows: Vec<GroupMemberRow> = secrets
+        .iter()
+        .filter(|s| groups_tag_contains(s.groups.as_deref(), group))
+        .map(|s| GroupMemberRow {
+            name: display_name(s).to_string(),
+            folder: s.folder.clone().unwrap_or_default(),
+            updated: s.updated_on.clone(),
+            enabled: s.enabled,
+        })
+        .collect();
+    rows.sort_by(|a, b| (&a.folder, &a.name).cmp(&(&b.folder, &b.name)));
+
+    let fmt = config.runtime_output_format;
+    let human = matches!(
+        fmt,
+        OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
+    );
+    if rows.is_empty() && human {
+        let groups: Vec<String> = secrets
+            .iter()
+            .filter_map(|s| s.groups.as_deref())
+            .flat_map(|g| g.split(','))
+            .map(|g| g.trim().to_string())
+            .filter(|g| !g.is_empty())
+            .collect();
+        let mut message = format!("No secrets in group '{group}' in vault '{vault_name}'.");
+        if let Some(close) = closest_match(group, &groups) {
+            message.push_str(&format!(" Did you mean '{close}'?"));
+        }
+        output::info(&message);
+        return Ok(());
+    }
+
+    let formatter = crate::utils::format::TableFormatter::new(
+        fmt,
+        config.no_color,
+        config.template.clone(),
+        config.runtime_columns.clone(),
+    );
+    println!("{}", formatter.format_table(&rows)?);
+    if human {
+        println!(
+            "{}",
+            crate::utils::list_output::count_label(
+                rows.len(),
+                rows.len(),
+                "secret",
+                "secrets",
+                Some(&format!("group '{group}'")),
+                false,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Apply `change` to the selected secrets: preview, confirm (for whole
+/// groups and `--filter` selections), then update each secret, attempting
+/// all and reporting how many failed.
+async fn apply_group_change(
+    change: GroupChange<'_>,
+    selection: Selection<'_>,
+    dry_run: bool,
+    yes: bool,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let (backend, vault_name, secrets) = fetch_secrets(config, registry).await?;
+    let group = change.source_group();
+    let selected = selection.select(&secrets, group, &vault_name)?;
+
+    if matches!(selection, Selection::Members) && selected.is_empty() {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "no secrets in group '{group}' in vault '{vault_name}'"
+        )));
+    }
+
+    // (label, backend name, new groups)
+    let updates: Vec<(String, String, Vec<String>)> = selected
+        .iter()
+        .filter_map(|s| {
+            change
+                .apply(s.groups.as_deref())
+                .map(|groups| (qualified_display_name(s, ""), s.name.clone(), groups))
+        })
+        .collect();
+    let unchanged = selected.len() - updates.len();
+    if updates.is_empty() {
+        output::info(&format!(
+            "{unchanged} secret(s) already up to date; nothing to change"
+        ));
+        return Ok(());
+    }
+
+    if dry_run {
+        for (label, _, groups) in &updates {
+            println!("{label}: groups = {}", groups.join(","));
+        }
+        output::info(&format!("{} secrets would change (dry run)", updates.len()));
+        return Ok(());
+    }
+
+    let bulk = match &selection {
+        Selection::Members => true,
+        Selection::Named { filter, .. } => filter.is_some(),
+    };
+    if bulk {
+        eprintln!("{} {} secrets:", change.describe(), updates.len());
+        for (label, _, _) in updates.iter().take(10) {
+            eprintln!("  {label}");
+        }
+        if updates.len() > 10 {
+            eprintln!("  ... ({} more; --dry-run to list all)", updates.len() - 10);
+        }
+        if !confirm_proceed(yes, &format!("Update {} secrets?", updates.len()), "--yes")? {
+            output::info("Aborted; nothing changed.");
+            return Ok(());
+        }
+    }
+
+    let mut failures = 0usize;
+    for (label, name, groups) in &updates {
+        let request = SecretUpdateRequest {
+            name: name.clone(),
+            expected_revision: None,
+            value: None,
+            content_type: None,
+            enabled: None,
+            expires_on: FieldUpdate::Unchanged,
+            not_before: FieldUpdate::Unchanged,
+            tags: None,
+            groups: Some(groups.clone()),
+            note: FieldUpdate::Unchanged,
+            folder: FieldUpdate::Unchanged,
+            replace_tags: false,
+            replace_groups: true,
+        };
+        if let Err(e) = backend
+            .secrets()
+            .update_secret(&vault_name, name, request)
+            .await
+        {
+            failures += 1;
+            output::warn(&format!("failed to update '{label}': {e}"));
+        }
+    }
+    invalidate_trait_secret_cache(config, config.effective_backend_name(), &vault_name);
+
+    let updated = updates.len() - failures;
+    if failures > 0 {
+        return Err(CrosstacheError::unknown(format!(
+            "updated {updated} of {} secrets; {failures} failed (see warnings above)",
+            updates.len()
+        )));
+    }
+    let summary = match change {
+        GroupChange::Add(group) => format!("Added {updated} secret(s) to group '{group}'"),
+        GroupChange::Remove(group) => {
+            format!("Removed {updated} secret(s) from group '{group}'")
+        }
+        GroupChange::Rename { from, to } => {
+            format!("Renamed group '{from}' to '{to}' on {updated} secret(s)")
+        }
+    };
+    output::success(&summary);
+    if unchanged > 0 {
+        output::info(&format!("{unchanged} secret(s) already up to date"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_rewrite_the_groups_tag() {
+        let add = GroupChange::Add("web");
+        assert_eq!(
+            add.apply(Some("api, db")),
+            Some(vec!["api".into(), "db".into(), "web".into()])
+        );
+        assert_eq!(add.apply(None), Some(vec!["web".into()]));
+        assert_eq!(add.apply(Some("web")), None);
+
+        let remove = GroupChange::Remove("db");
+        assert_eq!(remove.apply(Some("api,db")), Some(vec!["api".into()]));
+        assert_eq!(remove.apply(Some("db")), Some(vec![]));
+        assert_eq!(remove.apply(Some("api")), None);
+
+        let rename = GroupChange::Rename {
+            from: "db",
+            to: "database",
+        };
+        assert_eq!(
+            rename.apply(Some("db,api")),
+            Some(vec!["database".into(), "api".into()])
+        );
+        // Already in the target group: the old name just goes away.
+        assert_eq!(
+            rename.apply(Some("database,db")),
+            Some(vec!["database".into()])
+        );
+        assert_eq!(rename.apply(Some("api")), None);
+    }
+
+    #[test]
+    fn group_names_cannot_hold_commas() {
+        assert!(validate_group_name("app-prod").is_ok());
+        assert!(validate_group_name("a,b").is_err());
+        assert!(validate_group_name(" ").is_err());
+    }
+}
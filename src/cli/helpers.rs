@@ -74,8 +74,8 @@ pub(crate) fn share_unsupported_error(
 }
 
 /// Enrich vault-role access assignments with principal display names/emails
-/// (via the backend's directory resolution) and apply the presentation-side
-/// include-all filter.
+/// and group member counts (via the backend's directory resolution) and
+/// apply the presentation-side include-all filter.
 ///
 /// This is the split of the former `VaultManager::resolve_and_filter_roles`:
 /// the resolution half now runs through the [`VaultBackend`] trait
@@ -99,6 +99,16 @@ pub(crate) async fn enrich_and_filter_roles(
             .collect()
     };
     let resolved = backend.resolve_principal_ids(&principal_ids).await;
+    let group_ids: Vec<String> = principal_ids
+        .iter()
+        .filter(|id| {
+            roles
+                .iter()
+                .any(|r| &r.principal_id == *id && r.principal_type == "Group")
+        })
+        .cloned()
+        .collect();
+    let member_counts = backend.group_member_counts(&group_ids).await;
     for role in roles.iter_mut() {
         if let Some((name, email)) = resolved.get(&role.principal_id) {
             if !name.is_empty() {
@@ -106,6 +116,7 @@ pub(crate) async fn enrich_and_filter_roles(
             }
             role.email = email.clone();
         }
+        role.member_count = member_counts.get(&role.principal_id).copied();
     }
     if !include_all {
         roles.retain(|r| r.principal_type != "ServicePrincipal");
@@ -303,6 +314,21 @@ pub(crate) fn vault_ref_cache_identity(
     }
 }
 
+/// Whether a failed listing may fall back to its cached copy: the backend
+/// was unreachable and the cache is on.
+pub(crate) fn can_list_offline(error: &CrosstacheError, use_cache: bool) -> bool {
+    use_cache && crate::utils::network::is_unreachable_error(error)
+}
+
+/// Warn that a listing is being served from the cache, however old, because
+/// the backend could not be reached.
+pub(crate) fn warn_offline_listing(subject: &str, cached_at: chrono::DateTime<chrono::Utc>) {
+    crate::utils::output::warn(&format!(
+        "Offline: showing the cached listing of {subject} from {}; it may be out of date",
+        crate::utils::datetime::describe_age(cached_at, chrono::Utc::now())
+    ));
+}
+
 /// Decide whether a destructive operation may proceed, prompting when possible.
 ///
 /// Behaviour, matching the Azure legacy delete/purge paths so confirmation is
@@ -336,6 +362,28 @@ pub(crate) fn confirm_proceed(yes: bool, prompt: &str, flag_hint: &str) -> Resul
     crate::utils::interactive::InteractivePrompt::new().confirm(prompt, false)
 }
 
+/// Parse a day count such as `90d`, `12w` or `1y` (365 days); a bare number
+/// is days. Used for lifecycle ages, which Azure counts in whole days.
+pub(crate) fn parse_day_count(s: &str) -> std::result::Result<u32, String> {
+    let s = s.trim();
+    let (number, per_unit) = match s.char_indices().last() {
+        Some((i, 'd')) => (&s[..i], 1),
+        Some((i, 'w')) => (&s[..i], 7),
+        Some((i, 'y')) => (&s[..i], 365),
+        _ => (s, 1),
+    };
+    let count: u32 = number
+        .parse()
+        .map_err(|_| format!("expected a duration like 30d, 12w or 1y, got `{s}`"))?;
+    let days = count
+        .checked_mul(per_unit)
+        .ok_or_else(|| format!("`{s}` is too long"))?;
+    if days == 0 {
+        return Err("must be at least one day".to_string());
+    }
+    Ok(days)
+}
+
 /// Parse a single key-value pair from `KEY=value` format.
 pub(crate) fn parse_key_val<T, U>(
     s: &str,
@@ -413,6 +461,25 @@ pub(crate) fn extract_claims_from_token(token: &str) -> Result<TokenClaims> {
     })
 }
 
+/// Whether a command that copies by default should print instead: always
+/// with `--raw`, and also where there is no clipboard to copy to (WSL
+/// without WSLg, containers, SSH sessions without a display), with a note
+/// on stderr saying why.
+pub(crate) fn print_instead_of_copy(raw: bool) -> bool {
+    if raw {
+        return true;
+    }
+    let environment = crate::utils::environment::current();
+    if environment.has_clipboard() {
+        return false;
+    }
+    crate::utils::output::hint(&format!(
+        "No clipboard available ({}); printing the value instead. Pass --raw to skip this note.",
+        environment.describe()
+    ));
+    true
+}
+
 /// Copy text to the system clipboard.
 ///
 /// On Linux (X11/Wayland), `arboard` clipboard content is lost when the process exits
@@ -508,6 +575,103 @@ fn linux_clipboard_copy(text: &str) -> Option<std::result::Result<(), String>> {
     None // no suitable tool found
 }
 
+/// Read text from the system clipboard.
+///
+/// Uses the same Linux tools as [`copy_to_clipboard`] (`wl-paste`, `xclip`,
+/// `xsel`) and falls back to `arboard` elsewhere or when none is installed.
+pub(crate) fn read_clipboard() -> std::result::Result<String, String> {
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(result) = linux_clipboard_paste() {
+            return result;
+        }
+    }
+
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {e}"))?;
+    clipboard
+        .get_text()
+        .map_err(|e| format!("Failed to read clipboard: {e}"))
+}
+
+/// Try to read the clipboard with a Linux external tool.
+/// Returns `None` if no suitable tool is available.
+#[cfg(target_os = "linux")]
+fn linux_clipboard_paste() -> Option<std::result::Result<String, String>> {
+    use std::process::{Command, Stdio};
+
+    let is_wayland = std::env::var("WAYLAND_DISPLAY").is_ok();
+    let candidates: &[(&str, &[&str])] = if is_wayland {
+        &[
+            ("wl-paste", &["--no-newline"]),
+            ("xclip", &["-selection", "clipboard", "-o"]),
+            ("xsel", &["--clipboard", "--output"]),
+        ]
+    } else {
+        &[
+            ("xclip", &["-selection", "clipboard", "-o"]),
+            ("xsel", &["--clipboard", "--output"]),
+            ("wl-paste", &["--no-newline"]),
+        ]
+    };
+
+    for &(cmd, args) in candidates {
+        let found = Command::new("which")
+            .arg(cmd)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success());
+        if !found {
+            continue;
+        }
+        return Some(
+            match Command::new(cmd)
+                .args(args)
+                .stdin(Stdio::null())
+                .stderr(Stdio::null())
+                .output()
+            {
+                Ok(out) if out.status.success() => String::from_utf8(out.stdout)
+                    .map_err(|_| format!("{cmd}: clipboard does not hold text")),
+                Ok(out) => Err(format!("{cmd} exited with {}", out.status)),
+                Err(e) => Err(format!("{cmd}: {e}")),
+            },
+        );
+    }
+
+    None
+}
+
+/// Render `text` as a QR code for the terminal, two modules per character
+/// cell. Light modules are drawn as blocks so the code scans on the usual
+/// dark terminal background.
+pub(crate) fn render_qr(text: &str) -> Result<String> {
+    use qrcode::render::unicode::Dense1x2;
+
+    let code = qrcode::QrCode::new(text.as_bytes()).map_err(|e| {
+        CrosstacheError::invalid_argument(format!("Value cannot be shown as a QR code: {e}"))
+    })?;
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .quiet_zone(true)
+        .build())
+}
+
+/// `xv get --qr` and `xv file paste` move values past the vault's access
+/// logs, so they stay off unless `allow_qr_and_paste` is set. `what` names
+/// the command in the error.
+pub(crate) fn ensure_qr_and_paste_allowed(config: &Config, what: &str) -> Result<()> {
+    if config.allow_qr_and_paste {
+        return Ok(());
+    }
+    Err(CrosstacheError::config(format!(
+        "{what} is turned off; enable it with 'xv config set allow_qr_and_paste true'"
+    )))
+}
+
 /// Spawn a detached child process that clears the clipboard after `seconds`.
 /// The child outlives the parent process, fixing the issue where std::thread::spawn
 /// would be killed when the CLI exits.
@@ -575,7 +739,7 @@ pub(crate) fn generate_random_value(
 
     if let Some(generator_script) = custom_generator {
         // Execute custom generator script
-        return execute_custom_generator(&generator_script, length).map(Zeroizing::new);
+        return run_generator_script(&generator_script, length).map(Zeroizing::new);
     }
 
     if length == 0 {
@@ -604,8 +768,9 @@ pub(crate) fn generate_random_value(
     Ok(Zeroizing::new(random_value))
 }
 
-/// Execute a custom generator script
-fn execute_custom_generator(script_path: &str, length: usize) -> Result<String> {
+/// Execute a custom generator script with `XV_SECRET_LENGTH` set and return
+/// its trimmed stdout (`xv rotate --generator`).
+pub(crate) fn run_generator_script(script_path: &str, length: usize) -> Result<String> {
     use std::process::{Command, Stdio};
 
     let script = std::path::Path::new(script_path);
@@ -696,10 +861,192 @@ pub(crate) fn mask_secrets(text: &str, secrets: &[Zeroizing<String>]) -> String
     result
 }
 
+/// Tag keys xv stores its own metadata under; a vault tag with one of these
+/// names is never copied onto a secret.
+pub(crate) const RESERVED_SECRET_TAGS: &[&str] =
+    &["groups", "folder", "note", "original_name", "created_by"];
+
+/// The vault tags named by the `inherit_vault_tags` config key, for copying
+/// onto secrets written to `vault`. Empty when the key is unset. A vault whose
+/// tags cannot be read (e.g. a data-plane-only role) yields a warning and no
+/// tags rather than failing the write.
+pub(crate) async fn inherited_vault_tags(
+    backend: &dyn Backend,
+    vault: &str,
+    config: &Config,
+) -> std::collections::HashMap<String, String> {
+    if config.inherit_vault_tags.is_empty() {
+        return Default::default();
+    }
+    let Some(vaults) = backend.vaults() else {
+        return Default::default();
+    };
+    match vaults.get_vault_tags(vault, None).await {
+        Ok(tags) => tags
+            .into_iter()
+            .filter(|(key, _)| {
+                config.inherit_vault_tags.contains(key)
+                    && !RESERVED_SECRET_TAGS.contains(&key.as_str())
+            })
+            .collect(),
+        Err(e) => {
+            crate::utils::output::warn(&format!(
+                "Could not read tags of vault '{vault}' to inherit ({e}); writing without them"
+            ));
+            Default::default()
+        }
+    }
+}
+
+/// Add `inherited` tags to `request`, keeping any tag the request sets itself.
+pub(crate) fn apply_inherited_tags(
+    request: &mut crate::secret::manager::SecretRequest,
+    inherited: &std::collections::HashMap<String, String>,
+) {
+    if inherited.is_empty() {
+        return;
+    }
+    let tags = request.tags.get_or_insert_with(Default::default);
+    for (key, value) in inherited {
+        tags.entry(key.clone()).or_insert_with(|| value.clone());
+    }
+}
+
+/// Vet a secret value given directly on the command line, where it lands in
+/// shell history. `source` names how it was passed (e.g. `--value`). With
+/// `forbid_command_line_values` set this is an error; otherwise interactive
+/// sessions get a warning pointing at `--stdin`. Non-interactive runs (CI,
+/// scripts) keep no history and stay quiet.
+pub(crate) fn check_command_line_value(source: &str, config: &Config) -> Result<()> {
+    use std::io::IsTerminal;
+
+    if config.forbid_command_line_values {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Secret values passed as {source} are not allowed (forbid_command_line_values = true): \
+             they are saved in shell history. Pipe the value with --stdin or omit it to be prompted"
+        )));
+    }
+    if std::io::stdin().is_terminal() {
+        crate::utils::output::warn(&format!(
+            "The secret value passed as {source} is now in your shell history"
+        ));
+        crate::utils::output::hint(
+            "Pipe the value with --stdin (or omit it to be prompted) to keep it out of history",
+        );
+    }
+    Ok(())
+}
+
+/// Fail fast when the current identity lacks a vault permission a bulk
+/// operation (`operation`, e.g. "import") needs, naming the role to request.
+/// Skipped when `permission_preflight` is off; when the backend can't check
+/// (no vaults, access-policy mode, no Resource Manager access) the operation
+/// goes ahead and any denial surfaces item by item as before.
+pub(crate) async fn preflight_permissions(
+    config: &Config,
+    backend: &dyn Backend,
+    vault: &str,
+    resource_group: Option<&str>,
+    needed: &[crate::vault::models::VaultPermission],
+    operation: &str,
+) -> Result<()> {
+    if !config.permission_preflight {
+        return Ok(());
+    }
+    let Some(vaults) = backend.vaults() else {
+        return Ok(());
+    };
+    let missing = match vaults
+        .missing_permissions(vault, resource_group, needed)
+        .await
+    {
+        Ok(missing) => missing,
+        Err(e) => {
+            tracing::debug!("Skipping permission preflight for '{vault}': {e}");
+            return Ok(());
+        }
+    };
+    let Some(first) = missing.first() else {
+        return Ok(());
+    };
+    let actions: Vec<&str> = missing.iter().map(|p| p.describe()).collect();
+    Err(CrosstacheError::permission_denied(format!(
+        "Cannot {operation} on vault '{vault}': the current identity may not {}. \
+         Ask for the '{}' role on the vault, or set permission_preflight = false to skip this check",
+        actions.join(" or "),
+        first.azure_role()
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn qr_codes_render_as_square_blocks_of_text() {
+        let qr = render_qr("otpauth://totp/xv?secret=JBSWY3DPEHPK3PXP").unwrap();
+        let widths: Vec<usize> = qr.lines().map(|l| l.chars().count()).collect();
+        assert!(widths.len() > 10);
+        assert!(widths.iter().all(|w| *w == widths[0]));
+        // Two modules per row: height in cells is about half the width.
+        assert!(widths.len() * 2 >= widths[0]);
+        assert!(
+            render_qr(&"x".repeat(8000)).is_err(),
+            "too long for a QR code"
+        );
+    }
+
+    #[test]
+    fn day_counts_accept_days_weeks_and_years() {
+        assert_eq!(parse_day_count("90d"), Ok(90));
+        assert_eq!(parse_day_count("12w"), Ok(84));
+        assert_eq!(parse_day_count("1y"), Ok(365));
+        assert_eq!(parse_day_count("30"), Ok(30));
+        assert!(parse_day_count("0d").is_err());
+        assert!(parse_day_count("3h").is_err());
+        assert!(parse_day_count("d").is_err());
+    }
+
+    #[test]
+    fn command_line_values_are_rejected_when_forbidden() {
+        let allowed = Config::default();
+        assert!(check_command_line_value("--value", &allowed).is_ok());
+
+        let forbidding = Config {
+            forbid_command_line_values: true,
+            ..Default::default()
+        };
+        let err = check_command_line_value("--value", &forbidding).unwrap_err();
+        assert_eq!(err.code(), "xv-invalid-argument");
+        let message = err.to_string();
+        assert!(message.contains("--value") && message.contains("--stdin"));
+    }
+
+    #[test]
+    fn inherited_tags_fill_gaps_without_overriding_explicit_tags() {
+        let mut request = crate::secret::manager::SecretRequest {
+            name: "db".into(),
+            value: Zeroizing::new("v".into()),
+            content_type: None,
+            enabled: Some(true),
+            expires_on: None,
+            not_before: None,
+            tags: Some([("team".to_string(), "payments".to_string())].into()),
+            groups: None,
+            note: None,
+            folder: None,
+        };
+        let inherited = [
+            ("team".to_string(), "platform".to_string()),
+            ("env".to_string(), "prod".to_string()),
+        ]
+        .into();
+        apply_inherited_tags(&mut request, &inherited);
+        let tags = request.tags.unwrap();
+        assert_eq!(tags["team"], "payments");
+        assert_eq!(tags["env"], "prod");
+    }
+
     #[test]
     fn aws_share_error_names_backend_and_suggests_iam() {
         let err = share_unsupported_error(BackendKind::Aws, "aws", "access sharing");
@@ -0,0 +1,110 @@
+//! CLI executors for `xv index rebuild` / `xv index status`, and the hidden
+//! `xv __complete-search` completion feed.
+//!
+//! The search index (`crate::cache::index`) is written with every
+//! secrets-list cache entry; `rebuild` just forces a fresh listing.
+
+use crate::backend::BackendRegistry;
+use crate::cache::{CacheKey, CacheManager};
+use crate::cli::commands::IndexCommands;
+use crate::cli::helpers::{resolve_vault_for_trait, use_trait_path};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::utils::output;
+
+pub(crate) async fn execute_index_command(
+    command: IndexCommands,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let vault_name = resolve_vault_for_trait(config, registry).await?;
+    let cache_key = CacheKey::SecretsList {
+        backend: config.effective_backend_name().to_string(),
+        vault_name: vault_name.clone(),
+    };
+    let cache_manager = CacheManager::from_config(config);
+    match command {
+        IndexCommands::Rebuild => {
+            if !cache_manager.is_enabled() {
+                return Err(CrosstacheError::config(
+                    "The search index lives in the metadata cache, which is disabled. \
+                     Enable it with 'xv config set cache_enabled true'.",
+                ));
+            }
+            if !use_trait_path(registry) {
+                return Err(CrosstacheError::config(
+                    "No backend registry available. Run 'xv config show' to check your configuration.",
+                ));
+            }
+            let reg = registry.expect("use_trait_path guarantees Some");
+            let secrets = reg
+                .active()
+                .secrets()
+                .list_secrets(&vault_name, None)
+                .await?;
+            cache_manager.set(&cache_key, &secrets);
+            let terms = cache_manager
+                .search_index(&cache_key)
+                .map_or(0, |index| index.terms.len());
+            output::success(&format!(
+                "Indexed {} secrets ({terms} terms) in vault '{vault_name}'",
+                secrets.len()
+            ));
+        }
+        IndexCommands::Status => {
+            let path = cache_manager
+                .search_index_path(&cache_key)
+                .expect("secrets-list keys have an index path");
+            let Some(index) = crate::cache::index::SearchIndex::load(&path) else {
+                output::info(&format!("No search index for vault '{vault_name}'."));
+                output::hint("Run 'xv index rebuild' (or any 'xv ls') to build it.");
+                return Ok(());
+            };
+            let age = index.age_secs();
+            let state = if !cache_manager.is_enabled() {
+                "unused (cache disabled)"
+            } else if age < config.cache_ttl_secs {
+                "fresh"
+            } else {
+                "stale"
+            };
+            println!("Vault     : {vault_name}");
+            println!("Path      : {}", path.display());
+            println!(
+                "Built     : {} ({age}s ago)",
+                index.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+            );
+            println!("State     : {state}");
+            println!("Secrets   : {}", index.secrets.len());
+            println!("Terms     : {}", index.terms.len());
+        }
+    }
+    Ok(())
+}
+
+/// Print the indexed terms starting with `prefix`, one per line, for shell
+/// completion of `xv search`. Index-only: silent when there is no fresh
+/// index, so a Tab press never reaches the backend.
+#[allow(dead_code)] // called from src/main.rs::run_complete_search (binary-only path)
+pub(crate) async fn execute_complete_search(config: Config, prefix: &str) -> Result<()> {
+    // Same non-materializing vault resolution as `execute_complete_secrets`.
+    let ws = crate::workspace::resolve_workspace(&config)
+        .await?
+        .ok_or_else(|| {
+            CrosstacheError::config(
+                "internal error: resolve_workspace returned None; the degenerate \
+             workspace-of-one must always yield Some or Err",
+            )
+        })?;
+    let entry = ws.default_entry()?;
+    let cache_key = CacheKey::SecretsList {
+        backend: entry.backend.clone(),
+        vault_name: entry.vault.clone(),
+    };
+    if let Some(index) = CacheManager::from_config(&config).search_index(&cache_key) {
+        for term in index.complete(prefix) {
+            println!("{}", crate::utils::format::sanitize_control_chars(term));
+        }
+    }
+    Ok(())
+}
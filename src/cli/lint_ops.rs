@@ -0,0 +1,112 @@
+//! CLI executor for `xv lint`.
+
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::lint::{discover, extract, LintReport, VaultSecret};
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+use std::path::PathBuf;
+
+/// Check the secret references in `dir`'s config files against the current
+/// vault. Fails with `LintFailed` (exit 51) when a reference is missing, or
+/// when `fail_on_unused` is set and the vault has unreferenced secrets.
+pub(crate) async fn execute_lint_command(
+    dir: PathBuf,
+    fail_on_unused: bool,
+    format: OutputFormat,
+    config: &Config,
+    registry: Option<&crate::backend::BackendRegistry>,
+) -> Result<()> {
+    if !dir.is_dir() {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "'{}' is not a directory",
+            dir.display()
+        )));
+    }
+
+    let files = discover(&dir)?;
+    if files.is_empty() {
+        output::warn(&format!(
+            "No appsettings.json, .env.example or helm values files found under '{}'",
+            dir.display()
+        ));
+        return Ok(());
+    }
+    let mut references = Vec::new();
+    let mut relative = Vec::with_capacity(files.len());
+    for file in &files {
+        let rel = file.strip_prefix(&dir).unwrap_or(file).to_path_buf();
+        let content = std::fs::read_to_string(file).map_err(|e| {
+            CrosstacheError::config(format!("Failed to read '{}': {e}", file.display()))
+        })?;
+        references.extend(extract(&rel, &content));
+        relative.push(rel);
+    }
+
+    let (backend, _backend_name, vault) =
+        crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+    let secrets: Vec<VaultSecret> = backend
+        .secrets()
+        .list_secrets(&vault, None)
+        .await
+        .map_err(CrosstacheError::from)?
+        .into_iter()
+        .map(|s| VaultSecret {
+            name: s.name,
+            original_name: s.original_name,
+        })
+        .collect();
+
+    let report = LintReport::build(&vault, relative, &references, &secrets);
+    render_report(&report, format)?;
+
+    let unused = if fail_on_unused {
+        report.unused.len()
+    } else {
+        0
+    };
+    if !report.missing.is_empty() || unused > 0 {
+        return Err(CrosstacheError::lint_failed(report.missing.len(), unused));
+    }
+    Ok(())
+}
+
+fn render_report(report: &LintReport, format: OutputFormat) -> Result<()> {
+    match format.resolve_for_stdout() {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(report)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(report)?),
+        _ => {
+            if !report.missing.is_empty() {
+                println!("Missing from vault '{}':", report.vault);
+                for missing in &report.missing {
+                    println!("  {}  {}", missing.name, missing.locations.join(", "));
+                }
+            }
+            if !report.unused.is_empty() {
+                println!("Unused in vault '{}':", report.vault);
+                for name in &report.unused {
+                    println!("  {name}");
+                }
+            }
+            let summary = format!(
+                "Checked {} reference(s) in {} file(s): {} missing, {} unused",
+                report.references,
+                report.files.len(),
+                report.missing.len(),
+                report.unused.len()
+            );
+            if report.missing.is_empty() {
+                output::success(&summary);
+            } else {
+                output::warn(&summary);
+            }
+            if report.other_vault > 0 {
+                output::info(&format!(
+                    "{} reference(s) to other vaults were not checked",
+                    report.other_vault
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -29,6 +29,17 @@ pub(crate) struct ScopedList {
     pub subtree: Vec<SecretSummary>,
 }
 
+/// Synthetic tag carrying a secret's value size in bytes, stashed by
+/// `xv ls --size` after the listing is fetched (never cached or written
+/// back). Its presence on any row adds the long view's SIZE column.
+pub(crate) const VALUE_SIZE_TAG: &str = "__xv_value_size";
+
+/// Read back the [`VALUE_SIZE_TAG`] byte count. `None` when sizes weren't
+/// requested or the value couldn't be read.
+pub(crate) fn value_size_of(s: &SecretSummary) -> Option<usize> {
+    s.tags.get(VALUE_SIZE_TAG).and_then(|v| v.parse().ok())
+}
+
 /// User-facing name: `original_name` when present, else the (sanitized) `name`.
 pub(crate) fn display_name(s: &SecretSummary) -> &str {
     if s.original_name.is_empty() {
@@ -299,21 +310,27 @@ fn truncate_note(note: &str, max: usize) -> String {
 }
 
 /// Borderless long listing: NAME  UPDATED  GROUPS  NOTE. Folders render as
-/// `name/` with `-` placeholders, mirroring `xv file list`'s <DIR> rows.
+/// `name/` with `-` placeholders, mirroring `xv file list`'s <DIR> rows. A
+/// SIZE column follows UPDATED when any secret carries [`VALUE_SIZE_TAG`].
 pub(crate) fn render_long(entries: &[LsEntry], color: bool) -> String {
     struct Row {
         name: String,
         updated: String,
+        size: String,
         groups: String,
         note: String,
         is_folder: bool,
     }
+    let with_size = entries
+        .iter()
+        .any(|e| matches!(e, LsEntry::Secret(s) if s.tags.contains_key(VALUE_SIZE_TAG)));
     let rows: Vec<Row> = entries
         .iter()
         .map(|entry| match entry {
             LsEntry::Folder(name) => Row {
                 name: format!("{}/", sanitize_control_chars(name)),
                 updated: "-".to_string(),
+                size: "-".to_string(),
                 groups: "-".to_string(),
                 note: "-".to_string(),
                 is_folder: true,
@@ -321,6 +338,9 @@ pub(crate) fn render_long(entries: &[LsEntry], color: bool) -> String {
             LsEntry::Secret(s) => Row {
                 name: sanitize_control_chars(display_name(s)),
                 updated: date_portion_for_display(&s.updated_on),
+                size: value_size_of(s)
+                    .map(|n| crate::utils::format::format_size(n as u64))
+                    .unwrap_or_else(|| "-".to_string()),
                 groups: sanitize_control_chars(s.groups.as_deref().unwrap_or("-")),
                 note: sanitize_control_chars(&truncate_note(
                     s.note.as_deref().unwrap_or(""),
@@ -343,18 +363,32 @@ pub(crate) fn render_long(entries: &[LsEntry], color: bool) -> String {
         .chain(["UPDATED".len()])
         .max()
         .unwrap_or(7);
+    let size_w = rows
+        .iter()
+        .map(|r| display_width(&r.size))
+        .chain(["SIZE".len()])
+        .max()
+        .unwrap_or(4);
     let groups_w = rows
         .iter()
         .map(|r| display_width(&r.groups))
         .chain(["GROUPS".len()])
         .max()
         .unwrap_or(6);
+    let size_cell = |size: &str| {
+        if with_size {
+            format!("{}  ", pad_to(size, size_w))
+        } else {
+            String::new()
+        }
+    };
 
     let mut out = String::new();
     let header = format!(
-        "{}  {}  {}  NOTE",
+        "{}  {}  {}{}  NOTE",
         pad_to("NAME", name_w),
         pad_to("UPDATED", updated_w),
+        size_cell("SIZE"),
         pad_to("GROUPS", groups_w)
     );
     out.push_str(header.trim_end());
@@ -367,8 +401,9 @@ pub(crate) fn render_long(entries: &[LsEntry], color: bool) -> String {
             padded_name
         };
         let line = format!(
-            "{name_cell}  {}  {}  {}",
+            "{name_cell}  {}  {}{}  {}",
             pad_to(&row.updated, updated_w),
+            size_cell(&row.size),
             pad_to(&row.groups, groups_w),
             row.note
         );
@@ -400,14 +435,16 @@ pub(crate) fn render_name_grid(names: &[String], width: usize) -> String {
     render_grid(&entries, width, false)
 }
 
-/// Borderless long listing for deleted secrets: NAME  DELETED  PURGE SCHEDULED.
-/// Missing dates render as `-`, mirroring `render_long`'s folder placeholders.
+/// Borderless long listing for deleted secrets: NAME  DELETED  PURGE
+/// SCHEDULED, plus RECOVERY LEVEL when the backend reports one. Missing dates
+/// render as `-`, mirroring `render_long`'s folder placeholders.
 pub(crate) fn render_deleted_long(items: &[DeletedSecretSummary]) -> String {
     let cell = |v: &Option<String>| match v {
         Some(ts) => sanitize_control_chars(&date_portion_for_display(ts)),
         None => "-".to_string(),
     };
-    let rows: Vec<(String, String, String)> = items
+    let with_recovery = items.iter().any(|s| s.recovery_level.is_some());
+    let rows: Vec<[String; 4]> = items
         .iter()
         .map(|s| {
             let name = if s.original_name.is_empty() {
@@ -415,44 +452,40 @@ pub(crate) fn render_deleted_long(items: &[DeletedSecretSummary]) -> String {
             } else {
                 &s.original_name
             };
-            (
+            [
                 sanitize_control_chars(name),
                 cell(&s.deleted_on),
                 cell(&s.scheduled_purge_on),
-            )
+                s.recovery_level
+                    .as_deref()
+                    .map(sanitize_control_chars)
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
         })
         .collect();
 
-    let name_w = rows
-        .iter()
-        .map(|r| display_width(&r.0))
-        .chain(["NAME".len()])
-        .max()
-        .unwrap_or(4);
-    let deleted_w = rows
-        .iter()
-        .map(|r| display_width(&r.1))
-        .chain(["DELETED".len()])
-        .max()
-        .unwrap_or(7);
+    let headers = ["NAME", "DELETED", "PURGE SCHEDULED", "RECOVERY LEVEL"];
+    let columns = if with_recovery { 4 } else { 3 };
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .map(|r| display_width(&r[i]))
+                .chain([headers[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let render = |cells: [&str; 4]| {
+        let line = (0..columns)
+            .map(|i| pad_to(cells[i], widths[i]))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
 
-    let mut out = String::new();
-    let header = format!(
-        "{}  {}  PURGE SCHEDULED",
-        pad_to("NAME", name_w),
-        pad_to("DELETED", deleted_w)
-    );
-    out.push_str(header.trim_end());
-    out.push('\n');
-    for (name, deleted, purge) in rows {
-        let line = format!(
-            "{}  {}  {}",
-            pad_to(&name, name_w),
-            pad_to(&deleted, deleted_w),
-            purge
-        );
-        out.push_str(line.trim_end());
-        out.push('\n');
+    let mut out = render(headers);
+    for row in &rows {
+        out.push_str(&render([&row[0], &row[1], &row[2], &row[3]]));
     }
     out
 }
@@ -712,6 +745,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn long_listing_adds_size_column_only_when_sizes_are_present() {
+        let out = render_long(&[secret_entry("plain")], false);
+        assert!(!out.contains("SIZE"), "no SIZE column by default: {out}");
+
+        let mut sized = summary("big", None);
+        sized
+            .tags
+            .insert(VALUE_SIZE_TAG.to_string(), "2048".to_string());
+        let entries = vec![folder("prod"), LsEntry::Secret(sized)];
+        let out = render_long(&entries, false);
+        let lines: Vec<&str> = out.lines().collect();
+        let size_idx = lines[0].find("SIZE").expect("SIZE header");
+        assert!(
+            lines[0].find("UPDATED").unwrap() < size_idx
+                && size_idx < lines[0].find("GROUPS").unwrap(),
+            "SIZE sits between UPDATED and GROUPS: {out}"
+        );
+        assert!(lines[1].contains('-'), "folder placeholder: {out}");
+        assert_eq!(lines[2].find("2.00 KB"), Some(size_idx), "aligned: {out}");
+    }
+
     #[test]
     fn long_listing_truncates_multiline_and_overlong_notes() {
         let mut s = summary("a", None);
@@ -802,6 +857,8 @@ mod tests {
             original_name: name.to_string(),
             deleted_on: deleted_on.map(str::to_string),
             scheduled_purge_on: purge.map(str::to_string),
+            recovery_level: None,
+            tags: Default::default(),
         }
     }
 
@@ -831,6 +888,23 @@ mod tests {
         for line in &lines {
             assert_eq!(line.trim_end(), *line);
         }
+        assert!(!lines[0].contains("RECOVERY LEVEL"));
+    }
+
+    #[test]
+    fn deleted_long_adds_recovery_level_when_reported() {
+        let mut gone = deleted(
+            "gone",
+            Some("2026-06-30 10:00:00 UTC"),
+            Some("2026-09-28 10:00:00 UTC"),
+        );
+        gone.recovery_level = Some("Recoverable+Purgeable".to_string());
+        let items = vec![gone, deleted("other", None, None)];
+        let out = render_deleted_long(&items);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].ends_with("RECOVERY LEVEL"), "{out}");
+        assert!(lines[1].ends_with("Recoverable+Purgeable"), "{out}");
+        assert!(lines[2].starts_with("other") && lines[2].ends_with('-'));
     }
 
     #[test]
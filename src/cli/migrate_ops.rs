@@ -7,10 +7,14 @@ use crate::backend::{Backend, BackendError, BackendRef, BackendRegistry};
 use crate::config::settings::Config;
 use crate::error::{CrosstacheError, Result};
 use crate::secret::manager::SecretRequest;
+use crate::utils::checkpoint::Checkpoint;
+use crate::utils::interrupt;
 use crate::utils::output;
+use crate::utils::report::{ItemStatus, ReportRecorder};
+use futures::future;
 use futures::stream::{self, StreamExt};
 use std::sync::Arc;
-use zeroize::Zeroizing;
+use std::time::{Duration, Instant};
 
 const TAG_MIGRATED_FROM: &str = "xv:migrated_from";
 const TAG_MIGRATED_AT: &str = "xv:migrated_at";
@@ -107,11 +111,19 @@ fn print_diff_summary(
     println!();
 }
 
+/// Build the target write from the source secret. Errors when the source
+/// answered without a value (e.g. the caller can read metadata but not
+/// values): writing on would silently replace the secret with "".
 fn build_request_from_props(
     props: &crate::secret::manager::SecretProperties,
     source_name: &str,
     vault: &str,
-) -> SecretRequest {
+) -> std::result::Result<SecretRequest, String> {
+    let value = props.value.as_ref().ok_or_else(|| {
+        "source returned no value (check read permission on the source vault); \
+         refusing to write an empty secret"
+            .to_string()
+    })?;
     let mut tags = props.tags.clone();
     let groups = tags.remove("groups").map(|groups| {
         groups
@@ -129,15 +141,9 @@ fn build_request_from_props(
     );
     tags.insert(TAG_MIGRATED_AT.into(), chrono::Utc::now().to_rfc3339());
 
-    SecretRequest {
+    Ok(SecretRequest {
         name: props.original_name.clone(),
-        value: Zeroizing::new(
-            props
-                .value
-                .as_ref()
-                .map(|v| v.as_str().to_string())
-                .unwrap_or_default(),
-        ),
+        value: value.clone(),
         content_type: if props.content_type.is_empty() {
             None
         } else {
@@ -150,7 +156,7 @@ fn build_request_from_props(
         groups,
         note,
         folder,
-    }
+    })
 }
 
 async fn migrate_one(
@@ -217,7 +223,8 @@ async fn migrate_one(
         }
     }
 
-    let request = build_request_from_props(&props, source_name_for_tag, source_vault);
+    let request = build_request_from_props(&props, source_name_for_tag, source_vault)
+        .map_err(|e| (name.to_string(), e))?;
 
     // Retry with exponential backoff on RateLimited
     let mut attempt = 0u32;
@@ -281,7 +288,45 @@ pub(crate) async fn execute_migrate(
     on_conflict: crate::cli::commands::OnConflict,
     force_replace: bool,
     concurrency: usize,
+    report: Option<String>,
+    resume: bool,
     config: Config,
+) -> Result<()> {
+    let mut recorder =
+        ReportRecorder::new("migrate").with_endpoints(Some(from.clone()), Some(to.clone()));
+    let result = run_migrate(
+        from,
+        to,
+        vault,
+        filter,
+        dry_run,
+        on_conflict,
+        force_replace,
+        concurrency,
+        resume,
+        config,
+        &mut recorder,
+    )
+    .await;
+    match report {
+        Some(path) => recorder.write_for(std::path::Path::new(&path), result),
+        None => result,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_migrate(
+    from: String,
+    to: String,
+    vault: Option<String>,
+    filter: Option<String>,
+    dry_run: bool,
+    on_conflict: crate::cli::commands::OnConflict,
+    force_replace: bool,
+    concurrency: usize,
+    resume: bool,
+    config: Config,
+    recorder: &mut ReportRecorder,
 ) -> Result<()> {
     if concurrency == 0 {
         return Err(CrosstacheError::invalid_argument(
@@ -414,10 +459,46 @@ pub(crate) async fn execute_migrate(
     let mut names_to_process: Vec<String> = diff.to_migrate.clone();
     if on_conflict == crate::cli::commands::OnConflict::Replace {
         names_to_process.extend(diff.conflicts.clone());
+    } else {
+        for name in &diff.conflicts {
+            recorder.record(
+                name,
+                ItemStatus::Skipped,
+                Duration::ZERO,
+                Some("already exists in target (--on-conflict skip)".to_string()),
+            );
+        }
     }
 
+    // Completed names stay valid whatever --filter selects, so the
+    // checkpoint is keyed by the two endpoints only.
+    let mut checkpoint = Checkpoint::open(
+        "migrate",
+        &format!(
+            "{}:{} -> {}:{}",
+            source.name(),
+            source_vault,
+            target.name(),
+            target_vault
+        ),
+        resume,
+    )?;
+    names_to_process.retain(|name| {
+        if !checkpoint.is_done(name) {
+            return true;
+        }
+        recorder.record(
+            name,
+            ItemStatus::Skipped,
+            Duration::ZERO,
+            Some("migrated by an earlier run (--resume)".to_string()),
+        );
+        false
+    });
+
     if names_to_process.is_empty() {
         output::info("No secrets to migrate.");
+        checkpoint.finish(&Ok(()));
         return Ok(());
     }
 
@@ -428,20 +509,24 @@ pub(crate) async fn execute_migrate(
     let src_vault_clone = source_vault.clone();
     let tgt_vault_clone = target_vault.clone();
 
-    let results: Vec<_> =
-        stream::iter(
-            names_to_process.iter().map(|name| {
-                let source = source_arc.clone();
-                let target = target_arc.clone();
-                let sv = src_vault_clone.clone();
-                let tv = tgt_vault_clone.clone();
-                let name = name.clone();
-                let src_tag = source_name_tag.clone();
-                async move {
-                    migrate_one(&source, &target, &sv, &tv, &name, force_replace, &src_tag).await
-                }
-            }),
-        )
+    // After Ctrl+C no new transfers start; those already running finish.
+    let _interrupt_guard = interrupt::watch();
+    let results: Vec<_> = stream::iter(names_to_process.iter())
+        .take_while(|_| future::ready(!interrupt::requested()))
+        .map(|name| {
+            let source = source_arc.clone();
+            let target = target_arc.clone();
+            let sv = src_vault_clone.clone();
+            let tv = tgt_vault_clone.clone();
+            let name = name.clone();
+            let src_tag = source_name_tag.clone();
+            async move {
+                let started = Instant::now();
+                let outcome =
+                    migrate_one(&source, &target, &sv, &tv, &name, force_replace, &src_tag).await;
+                (outcome, started.elapsed())
+            }
+        })
         .buffer_unordered(concurrency)
         .collect()
         .await;
@@ -449,45 +534,76 @@ pub(crate) async fn execute_migrate(
     let mut migrated = 0usize;
     let mut skipped = 0usize;
     let mut errors: Vec<(String, String)> = Vec::new();
+    let mut processed = std::collections::HashSet::new();
 
-    for r in results {
+    for (r, elapsed) in results {
         match r {
             Ok(MigrateOutcome::Migrated(name)) => {
                 println!("  [ok] {}", name);
+                checkpoint.mark_done(&name);
+                processed.insert(name.clone());
+                recorder.record(name, ItemStatus::Succeeded, elapsed, None);
                 migrated += 1;
             }
             Ok(MigrateOutcome::Skipped(name)) => {
                 println!("  [skip] {} — already migrated (same source version)", name);
+                checkpoint.mark_done(&name);
+                processed.insert(name.clone());
+                recorder.record(
+                    name,
+                    ItemStatus::Skipped,
+                    elapsed,
+                    Some("already migrated (same source version)".to_string()),
+                );
                 skipped += 1;
             }
             Err((name, msg)) => {
                 println!("  [error] {} — {}", name, msg);
+                processed.insert(name.clone());
+                recorder.record(&name, ItemStatus::Failed, elapsed, Some(msg.clone()));
                 errors.push((name, msg));
             }
         }
     }
 
+    let remaining: Vec<String> = names_to_process
+        .iter()
+        .filter(|name| !processed.contains(*name))
+        .cloned()
+        .collect();
+    for name in &remaining {
+        recorder.record(
+            name,
+            ItemStatus::Skipped,
+            Duration::ZERO,
+            Some("not processed (interrupted)".to_string()),
+        );
+    }
+
     // 7. Print summary
     println!();
-    if !errors.is_empty() {
+    let outcome = if !remaining.is_empty() {
+        Err(interrupt::stopped(processed.len(), &remaining))
+    } else if !errors.is_empty() {
         output::warn(&format!(
             "Migrated {} secret(s), {} skipped, {} error(s)",
             migrated,
             skipped,
             errors.len()
         ));
-        return Err(CrosstacheError::Unknown(format!(
+        Err(CrosstacheError::Unknown(format!(
             "Migration failed for {} secret(s)",
             errors.len()
-        )));
+        )))
     } else {
         output::success(&format!(
             "Migrated {} secret(s) ({} skipped)",
             migrated, skipped
         ));
-    }
-
-    Ok(())
+        Ok(())
+    };
+    checkpoint.finish(&outcome);
+    outcome
 }
 
 #[cfg(test)]
@@ -497,6 +613,7 @@ mod tests {
     use crate::config::settings::LocalConfig;
     use std::collections::HashMap;
     use tempfile::TempDir;
+    use zeroize::Zeroizing;
 
     #[test]
     fn resolve_vault_name_from_flag() {
@@ -602,10 +719,11 @@ mod tests {
             not_before: None,
             tags,
             content_type: "text/plain".to_string(),
+            id: None,
             recovery_level: None,
         };
 
-        let request = build_request_from_props(&props, "local", "default");
+        let request = build_request_from_props(&props, "local", "default").unwrap();
 
         assert_eq!(
             request.groups,
@@ -625,6 +743,13 @@ mod tests {
         assert!(!request_tags.contains_key("groups"));
         assert!(!request_tags.contains_key("note"));
         assert!(!request_tags.contains_key("folder"));
+
+        let without_value = crate::secret::manager::SecretProperties {
+            value: None,
+            ..props
+        };
+        let err = build_request_from_props(&without_value, "local", "default").unwrap_err();
+        assert!(err.contains("refusing to write an empty secret"), "{err}");
     }
 
     #[tokio::test]
@@ -638,6 +763,8 @@ mod tests {
             crate::cli::commands::OnConflict::Skip,
             false,
             0,
+            None,
+            false,
             Config::default(),
         )
         .await;
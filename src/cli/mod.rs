@@ -3,21 +3,39 @@
 //! This module contains all command-line interface related functionality,
 //! including command definitions, argument parsing, and command execution.
 
+pub(crate) mod appservice_ops;
 #[cfg(feature = "file-ops")]
 pub(crate) mod attach_ops;
+pub(crate) mod backup_ops;
+pub(crate) mod bench_ops;
+pub(crate) mod bundle_ops;
 pub mod commands;
 pub(crate) mod config_ops;
+pub(crate) mod dev_proxy_ops;
+pub(crate) mod direnv_ops;
+pub(crate) mod edit_ops;
+pub(crate) mod expiry_ops;
 #[cfg(feature = "file-ops")]
 pub mod file;
 #[cfg(feature = "file-ops")]
 pub mod file_ops;
+pub(crate) mod folder_ops;
+pub(crate) mod group_ops;
 pub(crate) mod helpers;
+pub(crate) mod index_ops;
+pub(crate) mod lint_ops;
 pub(crate) mod local_ops;
 pub(crate) mod ls_view;
 pub(crate) mod migrate_ops;
 pub(crate) mod mv_ops;
+pub(crate) mod name_ops;
+pub(crate) mod offline_ops;
+pub(crate) mod render_ops;
+pub(crate) mod scaffold_ops;
 pub(crate) mod scan_ops;
 pub(crate) mod secret_ops;
+pub(crate) mod selftest_ops;
+pub(crate) mod ssh_ops;
 pub(crate) mod system_ops;
 pub(crate) mod type_ops;
 pub(crate) mod upgrade_ops;
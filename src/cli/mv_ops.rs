@@ -1331,6 +1331,7 @@ mod tests {
             not_before: None,
             tags: std::collections::HashMap::new(),
             content_type: String::new(),
+            id: None,
             recovery_level: None,
         }
     }
@@ -0,0 +1,134 @@
+//! CLI executor for `xv name map`: the secrets whose stored (sanitized)
+//! name differs from the name xv shows.
+//!
+//! Azure Key Vault only allows `[0-9a-zA-Z-]` in names, so xv stores a
+//! sanitized name and keeps the original in a tag; names that sanitize to
+//! nothing or run too long are stored as a hash. Other tools only see the
+//! stored names, and this report lets teams map them back.
+
+use crate::backend::BackendRegistry;
+use crate::cli::commands::NameCommands;
+use crate::config::Config;
+use crate::error::Result;
+use crate::secret::manager::SecretSummary;
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+use crate::utils::sanitizer::hash_secret_name;
+
+pub(crate) async fn execute_name_command(
+    command: NameCommands,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        NameCommands::Map => execute_name_map(config, registry).await,
+    }
+}
+
+/// One row of `xv name map`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, tabled::Tabled)]
+struct NameMapRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Stored As")]
+    stored_name: String,
+    /// `sanitized` or `hashed`.
+    #[tabled(rename = "Kind")]
+    kind: &'static str,
+    #[tabled(rename = "Folder")]
+    folder: String,
+}
+
+/// Rows for the secrets stored under a different name, sorted by name.
+fn name_map(secrets: &[SecretSummary]) -> Vec<NameMapRow> {
+    let mut rows: Vec<NameMapRow> = secrets
+        .iter()
+        .filter(|s| !s.original_name.is_empty() && s.name != s.original_name)
+        .filter(|s| s.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET)
+        .map(|s| NameMapRow {
+            name: s.original_name.clone(),
+            stored_name: s.name.clone(),
+            kind: if s.name == hash_secret_name(&s.original_name) {
+                "hashed"
+            } else {
+                "sanitized"
+            },
+            folder: s.folder.clone().unwrap_or_default(),
+        })
+        .collect();
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+    rows
+}
+
+async fn execute_name_map(config: &Config, registry: Option<&BackendRegistry>) -> Result<()> {
+    let (backend, _backend_name, vault_name) =
+        crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+    let secrets = backend.secrets().list_secrets(&vault_name, None).await?;
+    let rows = name_map(&secrets);
+
+    let fmt = config.runtime_output_format;
+    let human = matches!(
+        fmt,
+        OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
+    );
+    if rows.is_empty() && human {
+        output::info(&format!(
+            "Every secret in vault '{vault_name}' is stored under its own name."
+        ));
+        return Ok(());
+    }
+
+    let formatter = crate::utils::format::TableFormatter::new(
+        fmt,
+        config.no_color,
+        config.template.clone(),
+        config.runtime_columns.clone(),
+    );
+    println!("{}", formatter.format_table(&rows)?);
+    if human {
+        let hashed = rows.iter().filter(|r| r.kind == "hashed").count();
+        println!(
+            "{} of {} secrets in vault '{vault_name}' stored under a different name ({hashed} hashed)",
+            rows.len(),
+            secrets.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(stored: &str, original: &str) -> SecretSummary {
+        SecretSummary {
+            name: stored.to_string(),
+            original_name: original.to_string(),
+            note: None,
+            folder: None,
+            groups: None,
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            content_type: String::new(),
+            tags: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn lists_only_renamed_secrets_and_spots_hashes() {
+        let long = "x_".repeat(80);
+        let secrets = vec![
+            secret("plain", "plain"),
+            secret("db-url", "db_url"),
+            secret(&hash_secret_name(&long), &long),
+            secret("legacy", ""),
+        ];
+        let rows = name_map(&secrets);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name, "db_url");
+        assert_eq!(rows[0].stored_name, "db-url");
+        assert_eq!(rows[0].kind, "sanitized");
+        assert_eq!(rows[1].kind, "hashed");
+    }
+}
@@ -0,0 +1,345 @@
+//! CLI executors for the break-glass offline cache: `xv vault cache
+//! warm|status|clear` and the `xv get --offline` fallback.
+//!
+//! See [`crate::cache::offline`] for the file format and audit log.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::PathBuf;
+use tabled::Tabled;
+use zeroize::Zeroizing;
+
+use crate::backend::local::config::ResolvedLocalConfig;
+use crate::backend::BackendRegistry;
+use crate::cache::offline::{
+    append_audit, bundle_path, list_summaries, open_bundle, seal_bundle, summary_path, AuditEntry,
+    AuditEvent, Credential, OfflineBundle, Protection, Sealer, PASSPHRASE_ENV,
+};
+use crate::cli::commands::VaultCacheCommands;
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::SecretProperties;
+use crate::utils::output;
+
+pub(crate) async fn execute_vault_cache_command(
+    command: VaultCacheCommands,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    match command {
+        VaultCacheCommands::Warm {
+            vault_name,
+            group,
+            expires,
+            protect,
+        } => execute_cache_warm(vault_name, &group, &expires, protect, config, registry).await,
+        VaultCacheCommands::Status => execute_cache_status(config),
+        VaultCacheCommands::Clear { vault_name } => execute_cache_clear(vault_name, config),
+    }
+}
+
+fn cache_root(config: &Config) -> PathBuf {
+    crate::cache::CacheManager::from_config(config)
+        .cache_dir()
+        .clone()
+}
+
+async fn execute_cache_warm(
+    vault_name: Option<String>,
+    group: &str,
+    expires: &str,
+    protect: Protection,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let expires_at = crate::utils::datetime::parse_datetime_or_duration(expires)?;
+    if expires_at <= Utc::now() {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "--expires {expires} is already in the past"
+        )));
+    }
+    let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+    let vault = match vault_name {
+        Some(vault) => vault,
+        None => crate::cli::helpers::resolve_vault_for_trait(config, registry).await?,
+    };
+    let backend_name = config.effective_backend_name().to_string();
+    let root = cache_root(config);
+    let path = bundle_path(&root, &backend_name, &vault)?;
+
+    let summaries = backend
+        .secrets()
+        .list_secrets(&vault, Some(group))
+        .await
+        .map_err(CrosstacheError::from)?;
+    let mut secrets = Vec::with_capacity(summaries.len());
+    for summary in summaries
+        .iter()
+        .filter(|s| s.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET)
+    {
+        // A break-glass cache missing one secret fails when it is needed,
+        // so any read failure aborts the warm.
+        secrets.push(
+            backend
+                .secrets()
+                .get_secret(&vault, &summary.name, true)
+                .await
+                .map_err(CrosstacheError::from)?,
+        );
+    }
+    if secrets.is_empty() {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "No secrets in group '{group}' of vault '{vault}'"
+        )));
+    }
+
+    let sealer = match protect {
+        Protection::Passphrase => Sealer::Passphrase(obtain_passphrase(true)?),
+        Protection::Key => {
+            let local = ResolvedLocalConfig::from_raw(config.local.as_ref());
+            Sealer::Recipients(
+                crate::backend::local::crypto::load_recipients(&local.recipients_file)
+                    .map_err(CrosstacheError::from)?,
+            )
+        }
+    };
+    let bundle = OfflineBundle {
+        backend: backend_name.clone(),
+        vault: vault.clone(),
+        group: group.to_string(),
+        created_at: Utc::now(),
+        expires_at,
+        secrets,
+    };
+    let sealed = seal_bundle(&bundle, &sealer)?;
+    let summary = serde_json::to_vec_pretty(&bundle.summary(protect))?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    append_audit(
+        &root,
+        &AuditEntry::new(AuditEvent::Warm, &backend_name, &vault).detail(format!(
+            "{} secret(s) from group '{group}', {protect}-protected, expires {}",
+            bundle.secrets.len(),
+            expires_at.to_rfc3339()
+        )),
+    )?;
+    crate::utils::helpers::write_sensitive_file(&path, &sealed).map_err(|e| {
+        CrosstacheError::unknown(format!(
+            "Failed to write offline cache '{}': {e}",
+            path.display()
+        ))
+    })?;
+    crate::utils::helpers::write_sensitive_file(&summary_path(&path), &summary)?;
+
+    output::success(&format!(
+        "Cached {} secret(s) from group '{group}' of vault '{vault}' for offline use until {}",
+        bundle.secrets.len(),
+        format_time(expires_at)
+    ));
+    output::hint(&format!(
+        "`xv get <name> --offline` serves them when the vault is unreachable; \
+         reads are logged to {}",
+        root.join(crate::cache::offline::BREAK_GLASS_DIR)
+            .join(crate::cache::offline::AUDIT_FILE)
+            .display()
+    ));
+    Ok(())
+}
+
+#[derive(Tabled, Serialize)]
+struct CacheStatusRow {
+    #[tabled(rename = "Backend")]
+    backend: String,
+    #[tabled(rename = "Vault")]
+    vault: String,
+    #[tabled(rename = "Group")]
+    group: String,
+    #[tabled(rename = "Secrets")]
+    secrets: usize,
+    #[tabled(rename = "Protection")]
+    protection: Protection,
+    #[tabled(rename = "Created")]
+    created: String,
+    #[tabled(rename = "Expires")]
+    expires: String,
+    #[tabled(rename = "State")]
+    state: &'static str,
+}
+
+fn execute_cache_status(config: &Config) -> Result<()> {
+    let now = Utc::now();
+    let rows: Vec<CacheStatusRow> = list_summaries(&cache_root(config))
+        .into_iter()
+        .map(|s| CacheStatusRow {
+            backend: s.backend,
+            vault: s.vault,
+            group: s.group,
+            secrets: s.secret_count,
+            protection: s.protection,
+            created: format_time(s.created_at),
+            expires: format_time(s.expires_at),
+            state: if now >= s.expires_at {
+                "expired"
+            } else {
+                "valid"
+            },
+        })
+        .collect();
+    if rows.is_empty() {
+        output::info(
+            "No break-glass caches. Create one with `xv vault cache warm --group <group>`.",
+        );
+        return Ok(());
+    }
+    let formatter = crate::utils::format::TableFormatter::new(
+        config.runtime_output_format,
+        config.no_color,
+        config.template.clone(),
+        config.runtime_columns.clone(),
+    );
+    println!("{}", formatter.format_table(&rows)?);
+    Ok(())
+}
+
+fn execute_cache_clear(vault_name: Option<String>, config: &Config) -> Result<()> {
+    let root = cache_root(config);
+    let targets: Vec<(String, String)> = match vault_name {
+        Some(vault) => vec![(config.effective_backend_name().to_string(), vault)],
+        None => list_summaries(&root)
+            .into_iter()
+            .map(|s| (s.backend, s.vault))
+            .collect(),
+    };
+    let mut cleared = 0;
+    for (backend, vault) in targets {
+        let path = bundle_path(&root, &backend, &vault)?;
+        if !path.exists() {
+            continue;
+        }
+        std::fs::remove_file(&path)?;
+        let _ = std::fs::remove_file(summary_path(&path));
+        append_audit(&root, &AuditEntry::new(AuditEvent::Clear, &backend, &vault))?;
+        cleared += 1;
+    }
+    if cleared == 0 {
+        output::info("No break-glass cache to clear");
+    } else {
+        output::success(&format!("Cleared {cleared} break-glass cache(s)"));
+    }
+    Ok(())
+}
+
+/// `xv get --offline` fallback after `cause` showed the vault unreachable.
+/// Returns `cause` itself when there is no cache for the vault or the secret
+/// is not in it; refuses an expired cache. Every outcome past "no cache" is
+/// audited, and an audit failure fails the read.
+pub(crate) fn read_offline_secret(
+    backend_name: &str,
+    vault: &str,
+    name: &str,
+    cause: CrosstacheError,
+    config: &Config,
+) -> Result<SecretProperties> {
+    let root = cache_root(config);
+    let path = bundle_path(&root, backend_name, vault)?;
+    let Ok(data) = std::fs::read(&path) else {
+        output::hint(&format!(
+            "No break-glass cache for vault '{vault}'; warm one while online with \
+             `xv vault cache warm --group <group>`"
+        ));
+        return Err(cause);
+    };
+    let bundle = open_bundle(&data, |protection| unlock(protection, config))?;
+    let entry = AuditEntry::new(AuditEvent::Refused, backend_name, vault).secret(name);
+
+    if bundle.is_expired_at(Utc::now()) {
+        append_audit(
+            &root,
+            &entry.detail(format!("cache expired {}", bundle.expires_at.to_rfc3339())),
+        )?;
+        return Err(CrosstacheError::config(format!(
+            "The break-glass cache for vault '{vault}' expired {}; it is no longer served. \
+             Re-warm it with `xv vault cache warm --group {}` once the vault is reachable.",
+            format_time(bundle.expires_at),
+            bundle.group
+        )));
+    }
+    let Some(secret) = bundle.find(name) else {
+        append_audit(
+            &root,
+            &entry.detail(format!("not in cached group '{}'", bundle.group)),
+        )?;
+        output::hint(&format!(
+            "'{name}' is not in the break-glass cache of vault '{vault}' (group '{}')",
+            bundle.group
+        ));
+        return Err(cause);
+    };
+
+    append_audit(
+        &root,
+        &AuditEntry::new(AuditEvent::OfflineRead, backend_name, vault)
+            .secret(name)
+            .detail(cause.to_string()),
+    )?;
+    output::warn(&format!(
+        "Vault '{vault}' is unreachable ({cause}); serving '{name}' from the break-glass \
+         cache taken {} (expires {})",
+        format_time(bundle.created_at),
+        format_time(bundle.expires_at)
+    ));
+    Ok(secret.clone())
+}
+
+fn unlock(protection: Protection, config: &Config) -> Result<Credential> {
+    match protection {
+        Protection::Passphrase => Ok(Credential::Passphrase(obtain_passphrase(false)?)),
+        Protection::Key => {
+            let local = ResolvedLocalConfig::from_raw(config.local.as_ref());
+            Ok(Credential::Identity(
+                crate::backend::local::crypto::load_identity(&local.key_file)
+                    .map_err(CrosstacheError::from)?,
+            ))
+        }
+    }
+}
+
+/// The cache passphrase from `XV_BREAK_GLASS_PASSPHRASE`, else a prompt
+/// (entered twice when `confirm`).
+fn obtain_passphrase(confirm: bool) -> Result<Zeroizing<String>> {
+    use std::io::IsTerminal;
+
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        if !passphrase.is_empty() {
+            return Ok(Zeroizing::new(passphrase));
+        }
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "The break-glass cache passphrase is needed: set {PASSPHRASE_ENV} or run interactively"
+        )));
+    }
+    let passphrase = Zeroizing::new(rpassword::prompt_password(
+        "Break-glass cache passphrase: ",
+    )?);
+    if passphrase.is_empty() {
+        return Err(CrosstacheError::invalid_argument(
+            "The break-glass cache passphrase cannot be empty",
+        ));
+    }
+    if confirm {
+        let again = Zeroizing::new(rpassword::prompt_password("Confirm passphrase: ")?);
+        if *again != *passphrase {
+            return Err(CrosstacheError::invalid_argument(
+                "Passphrases do not match",
+            ));
+        }
+    }
+    Ok(passphrase)
+}
+
+fn format_time(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
@@ -0,0 +1,210 @@
+//! CLI executor for `xv render`: fill a template's `{{ secret:name }}`
+//! placeholders from secrets in one vault, folder or group. The syntax is
+//! the one `xv inject` reads; see [`crate::template`].
+
+use std::collections::HashMap;
+use std::io::Read;
+use zeroize::Zeroizing;
+
+use crate::backend::BackendRegistry;
+use crate::cli::ls_view::{display_name, folder_in_scope};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::{groups_tag_contains, SecretSummary};
+use crate::utils::output;
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn execute_render(
+    template: &str,
+    out: Option<String>,
+    vault: Option<String>,
+    groups: Vec<String>,
+    folder: Option<String>,
+    strict: bool,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let content = if template == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        std::fs::read_to_string(template).map_err(|e| {
+            CrosstacheError::config(format!("Failed to read template file '{template}': {e}"))
+        })?
+    };
+
+    let names = crate::template::placeholders(&content);
+    let mut values: HashMap<String, Zeroizing<String>> = HashMap::new();
+    if names.is_empty() {
+        output::warn("No {{ secret:name }} placeholders in the template; writing it unchanged");
+    } else {
+        let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+        let vault_name = match vault {
+            Some(vault) => vault,
+            None => crate::cli::helpers::resolve_vault_for_trait(config, registry).await?,
+        };
+        let folder = folder.map(|f| f.trim_matches('/').to_string());
+        let in_scope: Vec<SecretSummary> = backend
+            .secrets()
+            .list_secrets(&vault_name, None)
+            .await
+            .map_err(CrosstacheError::from)?
+            .into_iter()
+            .filter(|s| s.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET)
+            .filter(|s| in_groups(s, &groups))
+            .filter(|s| {
+                folder
+                    .as_deref()
+                    .is_none_or(|path| folder_in_scope(s.folder.as_deref().unwrap_or(""), path))
+            })
+            .collect();
+
+        let mut record_types = None;
+        for name in &names {
+            // An exact name wins; only then is `name.field` one record field.
+            let (summary, field) = match find_in_scope(&in_scope, name)? {
+                Some(summary) => (summary, None),
+                None => {
+                    let Some((base, field)) = name.rsplit_once('.') else {
+                        continue;
+                    };
+                    match find_in_scope(&in_scope, base)? {
+                        Some(summary) => (summary, Some(field)),
+                        None => continue,
+                    }
+                }
+            };
+            let secret = backend
+                .secrets()
+                .get_secret(&vault_name, &summary.name, true)
+                .await
+                .map_err(CrosstacheError::from)?;
+            let value = if field.is_some() || crate::records::is_record(&secret.content_type) {
+                let types =
+                    crate::cli::secret_ops::resolve_types_lazily(&mut record_types, config).await?;
+                crate::cli::secret_ops::record_field_value(
+                    display_name(summary),
+                    &secret,
+                    field,
+                    &types,
+                )?
+            } else {
+                match secret.value {
+                    Some(value) => value,
+                    None => continue,
+                }
+            };
+            values.insert(name.clone(), value);
+        }
+    }
+
+    let rendered = crate::template::render(&content, |name| {
+        values.get(name).map(|value| value.as_str())
+    });
+    if !rendered.missing.is_empty() {
+        if strict {
+            return Err(CrosstacheError::SecretNotFound {
+                name: rendered.missing.join(", "),
+                suggestion: None,
+            });
+        }
+        output::warn(&format!(
+            "No secret for placeholder(s) {}; left them as written (use --strict to fail instead)",
+            rendered.missing.join(", ")
+        ));
+    }
+
+    match out {
+        Some(path) => {
+            crate::utils::helpers::write_sensitive_file(
+                std::path::Path::new(&path),
+                rendered.output.as_bytes(),
+            )
+            .map_err(|e| {
+                CrosstacheError::config(format!("Failed to write to output file '{path}': {e}"))
+            })?;
+            output::success(&format!(
+                "Rendered {} placeholder(s) into '{path}'",
+                names.len() - rendered.missing.len()
+            ));
+        }
+        None => print!("{}", rendered.output.as_str()),
+    }
+    Ok(())
+}
+
+/// True when no group filter is given or the secret is in one of `groups`.
+fn in_groups(secret: &SecretSummary, groups: &[String]) -> bool {
+    groups.is_empty()
+        || groups
+            .iter()
+            .any(|group| groups_tag_contains(secret.groups.as_deref(), group))
+}
+
+/// The one in-scope secret a placeholder names, matching the user-facing
+/// name first and the backend name second. Two secrets with the same name
+/// in different folders are an error: the template would render whichever
+/// came first.
+fn find_in_scope<'a>(
+    in_scope: &'a [SecretSummary],
+    name: &str,
+) -> Result<Option<&'a SecretSummary>> {
+    let mut matches = in_scope.iter().filter(|s| display_name(s) == name);
+    let first = matches.next();
+    if let Some(first) = first {
+        let others: Vec<&str> = matches
+            .map(|s| s.folder.as_deref().unwrap_or("/"))
+            .collect();
+        if !others.is_empty() {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "Placeholder '{name}' matches secrets in folders {}, {}; narrow it with --folder",
+                first.folder.as_deref().unwrap_or("/"),
+                others.join(", ")
+            )));
+        }
+        return Ok(Some(first));
+    }
+    Ok(in_scope.iter().find(|s| s.name == name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(name: &str, folder: Option<&str>, groups: Option<&str>) -> SecretSummary {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "original_name": name,
+            "enabled": true,
+            "updated_on": "",
+            "content_type": "",
+            "folder": folder,
+            "groups": groups,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn duplicate_names_across_folders_are_ambiguous() {
+        let secrets = vec![
+            summary("db-password", Some("prod"), None),
+            summary("db-password", Some("dev"), None),
+            summary("api-key", None, None),
+        ];
+        assert!(find_in_scope(&secrets, "db-password").is_err());
+        assert_eq!(
+            find_in_scope(&secrets, "api-key").unwrap().unwrap().name,
+            "api-key"
+        );
+        assert!(find_in_scope(&secrets, "missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn group_filter_matches_any_listed_group() {
+        let secret = summary("db-password", None, Some("backend, db"));
+        assert!(in_groups(&secret, &[]));
+        assert!(in_groups(&secret, &["db".to_string()]));
+        assert!(!in_groups(&secret, &["frontend".to_string()]));
+    }
+}
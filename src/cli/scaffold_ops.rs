@@ -0,0 +1,259 @@
+//! `xv scaffold`: create a standard set of secrets from a template.
+//!
+//! Template definitions, the built-ins, and value rendering live in
+//! [`crate::secret::scaffold`]; this module resolves each field's value
+//! (`--set`, prompt, generation, derivation) and writes the secrets through
+//! the active backend's secret trait.
+
+use crate::backend::BackendRegistry;
+use crate::cli::commands::CharsetType;
+use crate::cli::helpers::{apply_inherited_tags, generate_random_value, inherited_vault_tags};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::SecretRequest;
+use crate::secret::scaffold::{
+    load_template, render_value, secret_name, ScaffoldField, ScaffoldTemplate,
+    DEFAULT_GENERATED_LENGTH,
+};
+use crate::utils::output;
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use zeroize::Zeroizing;
+
+/// How a field gets its value; shown by `--dry-run`.
+fn value_source(
+    field: &ScaffoldField,
+    provided: &HashMap<String, String>,
+    interactive: bool,
+) -> &'static str {
+    if provided.contains_key(&field.name) {
+        "--set"
+    } else if field.is_derived() {
+        "derived"
+    } else if field.generate {
+        "generated"
+    } else if interactive {
+        "prompt"
+    } else {
+        "default"
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn execute_scaffold(
+    template: String,
+    prefix: String,
+    vault: Option<String>,
+    values: Vec<(String, String)>,
+    yes: bool,
+    force: bool,
+    dry_run: bool,
+    config: Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let prefix = prefix.trim_matches('/').to_string();
+    if prefix.is_empty() {
+        return Err(CrosstacheError::invalid_argument(
+            "--prefix must name a folder (e.g. app/db)",
+        ));
+    }
+
+    let user_dir = Config::get_config_path()
+        .ok()
+        .and_then(|path| path.parent().map(|dir| dir.join("scaffolds")));
+    let template = load_template(&template, user_dir.as_deref())?;
+
+    let provided: HashMap<String, String> = values.into_iter().collect();
+    if let Some(unknown) = provided.keys().find(|k| template.field(k).is_none()) {
+        let fields: Vec<&str> = template.fields.iter().map(|f| f.name.as_str()).collect();
+        return Err(CrosstacheError::invalid_argument(format!(
+            "template '{}' has no field '{unknown}' (fields: {})",
+            template.name,
+            fields.join(", ")
+        )));
+    }
+    let interactive = !yes && std::io::stdin().is_terminal();
+
+    let (backend, backend_name, vault_name) = match vault {
+        Some(v) => (
+            crate::cli::vault_ops::active_or_construct_backend(registry, &config).await?,
+            config.effective_backend_name().to_string(),
+            v,
+        ),
+        None => crate::cli::vault_ops::resolve_current_vault(&config, registry).await?,
+    };
+    let secrets = backend.secrets();
+
+    let names: Vec<(&ScaffoldField, String)> = template
+        .fields
+        .iter()
+        .map(|f| (f, secret_name(&prefix, &f.name)))
+        .collect();
+    let mut existing = Vec::new();
+    for (_, name) in &names {
+        if secrets.secret_exists(&vault_name, name).await? {
+            existing.push(name.clone());
+        }
+    }
+
+    if dry_run {
+        output::info(&format!(
+            "Dry run: would create {} secrets from template '{}' in folder '{prefix}' of vault '{vault_name}':",
+            names.len(),
+            template.name
+        ));
+        for (field, name) in &names {
+            let note = if existing.contains(name) {
+                " (exists)"
+            } else {
+                ""
+            };
+            println!(
+                "  {name}  [{}]{note}",
+                value_source(field, &provided, interactive)
+            );
+        }
+        return Ok(());
+    }
+
+    if !existing.is_empty() && !force {
+        return Err(CrosstacheError::conflict(format!(
+            "{} already exist in vault '{vault_name}'; pass --force to overwrite",
+            existing.join(", ")
+        )));
+    }
+
+    let values = resolve_values(&template, &provided, interactive, &config)?;
+    let inherited = inherited_vault_tags(backend.as_ref(), &vault_name, &config).await;
+
+    let mut created = Vec::new();
+    for (field, name) in &names {
+        let value = values
+            .get(&field.name)
+            .cloned()
+            .expect("resolve_values covers every field");
+        let mut request = SecretRequest {
+            name: name.clone(),
+            value,
+            content_type: None,
+            enabled: Some(true),
+            expires_on: None,
+            not_before: None,
+            tags: None,
+            groups: None,
+            note: None,
+            folder: Some(prefix.clone()),
+        };
+        apply_inherited_tags(&mut request, &inherited);
+        if let Err(e) = secrets.set_secret(&vault_name, request).await {
+            crate::cli::secret_ops::invalidate_trait_secret_cache(
+                &config,
+                &backend_name,
+                &vault_name,
+            );
+            let done = if created.is_empty() {
+                "none".to_string()
+            } else {
+                created.join(", ")
+            };
+            return Err(CrosstacheError::unknown(format!(
+                "failed to write '{name}': {e} (already created: {done})"
+            )));
+        }
+        created.push(name.clone());
+    }
+    crate::cli::secret_ops::invalidate_trait_secret_cache(&config, &backend_name, &vault_name);
+
+    output::success(&format!(
+        "Created {} secrets from template '{}' in folder '{prefix}':",
+        created.len(),
+        template.name
+    ));
+    for name in &created {
+        println!("  {name}");
+    }
+    Ok(())
+}
+
+/// Resolve every field's value: `--set` first, then generation or the
+/// prompt (or the default when not interactive), then derived fields.
+fn resolve_values(
+    template: &ScaffoldTemplate,
+    provided: &HashMap<String, String>,
+    interactive: bool,
+    config: &Config,
+) -> Result<HashMap<String, Zeroizing<String>>> {
+    use crate::utils::interactive::InteractivePrompt;
+
+    let mut values = HashMap::new();
+    for field in template.fields.iter().filter(|f| !f.is_derived()) {
+        let value = if let Some(v) = provided.get(&field.name) {
+            Zeroizing::new(v.clone())
+        } else if field.generate {
+            generate_random_value(
+                field.length.unwrap_or(DEFAULT_GENERATED_LENGTH),
+                field_charset(field, config)?,
+                None,
+            )?
+        } else if interactive {
+            let answer = if field.secret {
+                Zeroizing::new(rpassword::prompt_password(format!(
+                    "{}: ",
+                    field.prompt_text()
+                ))?)
+            } else {
+                Zeroizing::new(
+                    InteractivePrompt::new()
+                        .input_text(field.prompt_text(), field.default.as_deref())?,
+                )
+            };
+            match (answer.is_empty(), &field.default) {
+                (true, Some(default)) => Zeroizing::new(default.clone()),
+                (true, None) => {
+                    return Err(CrosstacheError::invalid_argument(format!(
+                        "field '{}' needs a value",
+                        field.name
+                    )));
+                }
+                (false, _) => answer,
+            }
+        } else if let Some(default) = &field.default {
+            Zeroizing::new(default.clone())
+        } else {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "field '{}' has no default; pass --set {}=<value>",
+                field.name, field.name
+            )));
+        };
+        values.insert(field.name.clone(), value);
+    }
+
+    for field in &template.fields {
+        if let Some(value_template) = &field.value {
+            let value = match provided.get(&field.name) {
+                Some(v) => Zeroizing::new(v.clone()),
+                None => render_value(value_template, &values)?,
+            };
+            values.insert(field.name.clone(), value);
+        }
+    }
+    Ok(values)
+}
+
+/// The field's charset, else `gen_default_charset`, else alphanumeric (same
+/// fallback as `xv gen`).
+fn field_charset(field: &ScaffoldField, config: &Config) -> Result<CharsetType> {
+    if let Some(charset) = &field.charset {
+        return charset
+            .parse::<CharsetType>()
+            .map_err(|e| CrosstacheError::config(format!("scaffold field '{}': {e}", field.name)));
+    }
+    match &config.gen_default_charset {
+        Some(s) => s.parse::<CharsetType>().map_err(|e| {
+            CrosstacheError::config(format!(
+                "Invalid value for config key 'gen_default_charset': {e}"
+            ))
+        }),
+        None => Ok(CharsetType::Alphanumeric),
+    }
+}
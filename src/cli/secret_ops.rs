@@ -2,17 +2,19 @@
 
 use crate::backend::BackendCapabilities;
 use crate::backend::{BackendKind, BackendRef, BackendRegistry};
-use crate::cli::commands::{CharsetType, SecretWriteArgs, ShareCommands};
+use crate::cli::commands::{EnabledFilter, SecretWriteArgs, ShareCommands};
 use crate::cli::helpers::{
-    confirm_destructive, confirm_proceed, copy_to_clipboard, generate_random_value, mask_secrets,
-    resolve_vault_for_trait, schedule_clipboard_clear, share_unsupported_error, use_trait_path,
+    apply_inherited_tags, check_command_line_value, confirm_destructive, confirm_proceed,
+    copy_to_clipboard, inherited_vault_tags, mask_secrets, render_qr, resolve_vault_for_trait,
+    schedule_clipboard_clear, share_unsupported_error, use_trait_path,
 };
-use crate::config::Config;
+use crate::config::{Config, GetOutput};
 use crate::error::{CrosstacheError, Result};
 use crate::records::{
     encode_envelope, find_type, FieldDef, FieldKind, RecordType, FIELD_TAG_PREFIX,
     RECORD_CONTENT_TYPE, TYPE_TAG,
 };
+use crate::utils::audit_log::{AuditEntry, AuditEvent};
 use crate::utils::format::OutputFormat;
 use crate::utils::output;
 use crate::utils::pagination::Pagination;
@@ -477,6 +479,21 @@ pub(crate) async fn execute_secret_set_direct(
             "--type can only be used when setting a single secret (not with KEY=value bulk args)",
         ));
     }
+    if value.is_some() {
+        check_command_line_value("--value", &config)?;
+    }
+    // `KEY=@file` reads the value from disk; only inline values are exposed.
+    if is_bulk
+        && args.iter().any(|arg| {
+            arg.split_once('=')
+                .is_some_and(|(_, v)| !v.trim().starts_with('@'))
+        })
+    {
+        check_command_line_value("KEY=value arguments", &config)?;
+    }
+    if !secret_fields.is_empty() {
+        check_command_line_value("--field-secret", &config)?;
+    }
 
     // ── Trait-based path (non-Azure backends) ──────────────────────────
     if use_trait_path(registry) {
@@ -510,7 +527,7 @@ pub(crate) async fn execute_secret_set_direct(
                 output::info("Aborted; secret not set.");
                 return Ok(());
             }
-            let request = build_record_set_request(
+            let mut request = build_record_set_request(
                 name,
                 value.clone(),
                 stdin,
@@ -524,6 +541,10 @@ pub(crate) async fn execute_secret_set_direct(
                 backend.kind(),
             )
             .await?;
+            apply_inherited_tags(
+                &mut request,
+                &inherited_vault_tags(backend.as_ref(), &vault_name, &config).await,
+            );
             let props = backend.secrets().set_secret(&vault_name, request).await?;
             output::success(&format!(
                 "Successfully set record '{}' (type: {})",
@@ -566,7 +587,11 @@ pub(crate) async fn execute_secret_set_direct(
             }
             // Build the request via the shared helper so `set` and `gen --save`
             // construct identical requests from the same metadata flags.
-            let request = meta.to_secret_request(name, Zeroizing::new(secret_value))?;
+            let mut request = meta.to_secret_request(name, Zeroizing::new(secret_value))?;
+            apply_inherited_tags(
+                &mut request,
+                &inherited_vault_tags(backend.as_ref(), &vault_name, &config).await,
+            );
             let props = backend.secrets().set_secret(&vault_name, request).await?;
             output::success(&format!(
                 "Successfully set secret '{}'",
@@ -605,6 +630,11 @@ pub(crate) async fn execute_secret_set_direct(
             // wrong `(backend, vault)` cache directory.
             let mut touched_vaults: std::collections::HashSet<(String, String)> =
                 std::collections::HashSet::new();
+            // Inherited vault tags, looked up once per (backend, vault).
+            let mut vault_tags: std::collections::HashMap<
+                (String, String),
+                std::collections::HashMap<String, String>,
+            > = std::collections::HashMap::new();
             for (key, value) in pairs {
                 // Workspace-aware resolution per key (BLOCKER fix): each
                 // bulk pair is resolved independently so `alias:KEY=value`
@@ -643,7 +673,14 @@ pub(crate) async fn execute_secret_set_direct(
                 // the same write-time metadata (--group/--note/--folder/--tag)
                 // as the single-secret path. (--expires/--not-before are rejected
                 // for bulk above, so they're always None here.)
-                let request = meta.to_secret_request(&resolved_key, Zeroizing::new(value))?;
+                let mut request = meta.to_secret_request(&resolved_key, Zeroizing::new(value))?;
+                let tag_key = (backend_name.clone(), key_vault_name.clone());
+                if !vault_tags.contains_key(&tag_key) {
+                    let tags =
+                        inherited_vault_tags(backend.as_ref(), &key_vault_name, &config).await;
+                    vault_tags.insert(tag_key.clone(), tags);
+                }
+                apply_inherited_tags(&mut request, &vault_tags[&tag_key]);
                 match backend.secrets().set_secret(&key_vault_name, request).await {
                     Ok(props) => {
                         output::success(&format!("  ✓ {}", props.original_name));
@@ -764,13 +801,13 @@ fn field_clipboard_outcome(
 /// field (mirroring plain `get`'s compatibility contract) when `field` is
 /// `None` and the secret is a typed record. An untyped secret with
 /// `field: None` returns its value unchanged; an untyped secret with an
-/// explicit field is an error.
+/// explicit field is an error. A bundle's field is one of its keys.
 ///
 /// Shared by `get`'s plain/`--field` read paths and `xv inject`'s
 /// `{{ secret:name.field }}` / `xv://vault/name#field` grammar (record-types
 /// plan Task 12) so field-read semantics can't drift between the two
 /// commands.
-fn record_field_value(
+pub(crate) fn record_field_value(
     name: &str,
     secret: &crate::secret::manager::SecretProperties,
     field: Option<&str>,
@@ -780,6 +817,16 @@ fn record_field_value(
     let raw_value = secret.value.as_deref().map(|s| s.as_str()).unwrap_or("");
 
     if let Some(field_name) = field {
+        if crate::secret::bundle::is_bundle(&secret.content_type) {
+            let entries = crate::secret::bundle::parse(name, raw_value)?;
+            return match entries.get(field_name) {
+                Some(v) => Ok(Zeroizing::new(v.clone())),
+                None => Err(CrosstacheError::config(format!(
+                    "bundle '{name}' has no key '{field_name}'. Keys: {}",
+                    entries.keys().cloned().collect::<Vec<_>>().join(", ")
+                ))),
+            };
+        }
         if !is_rec {
             return Err(CrosstacheError::config(format!(
                 "secret '{name}' is not a typed record (value is not marked {}); field access \
@@ -836,7 +883,7 @@ fn record_field_value(
 /// actually a record (`crate::records::is_record`); an untyped secret
 /// should never trigger it at all. `CrosstacheError` isn't `Clone`, so the
 /// error path is cached as a `String` and re-wrapped on each cached lookup.
-async fn resolve_types_lazily(
+pub(crate) async fn resolve_types_lazily(
     cache: &mut Option<std::result::Result<Vec<RecordType>, String>>,
     config: &Config,
 ) -> Result<Vec<RecordType>> {
@@ -857,7 +904,9 @@ async fn resolve_types_lazily(
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn execute_secret_get_direct(
     name: &str,
-    raw: bool,
+    output: Option<GetOutput>,
+    qr: bool,
+    offline: bool,
     version: Option<String>,
     field: Option<String>,
     record: bool,
@@ -865,12 +914,32 @@ pub(crate) async fn execute_secret_get_direct(
     config: Config,
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
+    use std::io::IsTerminal;
+    // Checked before the read so a refused `--qr` never fetches the value.
+    if qr {
+        crate::cli::helpers::ensure_qr_and_paste_allowed(&config, "--qr")?;
+    }
+    if qr && !std::io::stdout().is_terminal() {
+        return Err(CrosstacheError::invalid_argument(
+            "--qr draws the code on a terminal; use --raw to pipe the value instead",
+        ));
+    }
+    // An explicit `--raw`/`--clipboard` wins over `get_default_output`.
+    let output = match output.unwrap_or(config.get_default_output) {
+        GetOutput::Clipboard
+            if !qr && !record && crate::cli::helpers::print_instead_of_copy(false) =>
+        {
+            GetOutput::Raw
+        }
+        mode => mode,
+    };
+
     // ── Trait-based path (non-Azure backends) ──────────────────────────
     if use_trait_path(registry) {
         // Workspace-aware resolution: no workspace attached ⇒ this returns
         // exactly (reg.active_arc(), resolve_vault_for_trait(...), name) —
         // byte-identical to the pre-workspace behavior.
-        let (backend, _backend_name, vault_name, name) =
+        let (backend, backend_name, vault_name, name) =
             crate::cli::helpers::resolve_workspace_or_default(
                 name,
                 &config,
@@ -879,16 +948,26 @@ pub(crate) async fn execute_secret_get_direct(
             .await?;
         let name = name.as_str();
 
-        let secret = if let Some(ref ver) = version {
+        let fetched = if let Some(ref ver) = version {
             backend
                 .secrets()
                 .get_secret_version(&vault_name, name, ver, true)
-                .await?
+                .await
         } else {
-            backend
-                .secrets()
-                .get_secret(&vault_name, name, true)
-                .await?
+            backend.secrets().get_secret(&vault_name, name, true).await
+        };
+        let secret = match fetched.map_err(CrosstacheError::from) {
+            Ok(secret) => secret,
+            Err(e) if offline && crate::utils::network::is_unreachable_error(&e) => {
+                crate::cli::offline_ops::read_offline_secret(
+                    &backend_name,
+                    &vault_name,
+                    name,
+                    e,
+                    &config,
+                )?
+            }
+            Err(e) => return Err(e),
         };
 
         let is_rec = crate::records::is_record(&secret.content_type);
@@ -951,11 +1030,12 @@ pub(crate) async fn execute_secret_get_direct(
 
         // ── `--field NAME`: one field, either kind ──
         if let Some(field_name) = field {
-            if !is_rec {
+            let is_bundle = crate::secret::bundle::is_bundle(&secret.content_type);
+            if !is_rec && !is_bundle {
                 return Err(CrosstacheError::config(format!(
                     "secret '{name}' is not a typed record (value is not marked {}); \
-                     --field only applies to typed records. Use 'xv update {name} --type <type>' \
-                     to convert it.",
+                     --field only applies to typed records and bundles. Use 'xv update {name} \
+                     --type <type>' to convert it.",
                     crate::records::RECORD_CONTENT_TYPE
                 )));
             }
@@ -966,12 +1046,26 @@ pub(crate) async fn execute_secret_get_direct(
             // already validated the field exists, so re-parsing the
             // envelope here is purely for this classification, not for the
             // lookup/error-message logic (which lives in one place now).
-            let value = secret.value.as_deref().map(|s| s.as_str()).unwrap_or("");
-            let envelope = parse_record_envelope_or_fail(name, &secret.content_type, value)?;
-            let is_secret_field = envelope.contains_key(&field_name);
+            // Every bundle key lives in the value.
+            let is_secret_field = is_bundle || {
+                let value = secret.value.as_deref().map(|s| s.as_str()).unwrap_or("");
+                let envelope = parse_record_envelope_or_fail(name, &secret.content_type, value)?;
+                envelope.contains_key(&field_name)
+            };
 
-            if raw {
+            if qr {
+                let entry = AuditEntry::new(AuditEvent::QrReveal, &backend_name, &vault_name)
+                    .secret(name)
+                    .detail(format!("field {field_name}"));
+                show_secret_qr(
+                    &format!("{name} --field {field_name}"),
+                    &field_value,
+                    &entry,
+                )?;
+            } else if output == GetOutput::Raw {
                 print!("{}", field_value.as_str());
+            } else if output == GetOutput::Masked {
+                print_masked(&format!("{name} --field {field_name}"), &field_value);
             } else {
                 match copy_to_clipboard(&field_value) {
                     Ok(()) => {
@@ -1007,10 +1101,24 @@ pub(crate) async fn execute_secret_get_direct(
             secret.value
         };
 
-        if raw {
+        if qr {
+            match effective_value {
+                Some(value) => show_secret_qr(
+                    name,
+                    &value,
+                    &AuditEntry::new(AuditEvent::QrReveal, &backend_name, &vault_name).secret(name),
+                )?,
+                None => output::warn(&format!("Secret '{name}' has no value")),
+            }
+        } else if output == GetOutput::Raw {
             if let Some(value) = effective_value {
                 print!("{}", value.as_str());
             }
+        } else if output == GetOutput::Masked {
+            match effective_value {
+                Some(value) => print_masked(name, &value),
+                None => output::warn(&format!("Secret '{name}' has no value")),
+            }
         } else if let Some(ref value) = effective_value {
             match copy_to_clipboard(value) {
                 Ok(()) => {
@@ -1040,15 +1148,139 @@ pub(crate) async fn execute_secret_get_direct(
     ))
 }
 
-fn secret_summary_matches_group(
-    secret: &crate::secret::manager::SecretSummary,
-    group: &str,
-) -> bool {
-    secret
-        .groups
-        .as_ref()
-        .map(|groups| groups.split(',').any(|grp| grp.trim() == group))
-        .unwrap_or(false)
+/// `xv get a b c` / `xv get --group G`: fetch every value concurrently and
+/// print them together — a JSON (or YAML) object keyed by secret name, or
+/// dotenv lines (`NAME=value`, names upper-snake-cased as for `xv run`)
+/// otherwise. Any secret that cannot be read fails the whole command, so a
+/// script never sees a partial set.
+pub(crate) async fn execute_secret_get_many(
+    names: Vec<String>,
+    group: Option<String>,
+    format: OutputFormat,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    use crate::utils::helpers::to_env_var_name;
+    use futures::stream::{self, StreamExt};
+
+    let (backend, _backend_name, vault_name) =
+        crate::cli::vault_ops::resolve_current_vault(config, registry).await?;
+    let secrets = backend.secrets();
+    let names = match group {
+        Some(ref group) => {
+            let mut listed: Vec<String> = secrets
+                .list_secrets(&vault_name, Some(group))
+                .await?
+                .into_iter()
+                .map(|s| s.name)
+                .collect();
+            listed.sort();
+            listed
+        }
+        None => names,
+    };
+    if names.is_empty() {
+        output::warn(&format!(
+            "No secrets in group '{}'",
+            group.unwrap_or_default()
+        ));
+        return Ok(());
+    }
+
+    let vault = vault_name.as_str();
+    let fetched: Vec<_> = stream::iter(&names)
+        .map(|name| async move { (name, secrets.get_secret(vault, name, true).await) })
+        .buffered(config.fetch_concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut record_types = None;
+    let mut values: Vec<(String, Zeroizing<String>)> = Vec::with_capacity(fetched.len());
+    for (name, result) in fetched {
+        let secret = result.map_err(CrosstacheError::from)?;
+        let types = if crate::records::is_record(&secret.content_type) {
+            resolve_types_lazily(&mut record_types, config).await?
+        } else {
+            Vec::new()
+        };
+        let value = record_field_value(name, &secret, None, &types)?;
+        let label = if secret.original_name.is_empty() {
+            name.clone()
+        } else {
+            secret.original_name.clone()
+        };
+        values.push((label, value));
+    }
+
+    let object = || -> BTreeMap<&str, &str> {
+        values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect()
+    };
+    match format.resolve_for_stdout() {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&object())?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&object())?),
+        _ => {
+            let mut seen: BTreeMap<String, &str> = BTreeMap::new();
+            let mut lines = Zeroizing::new(String::new());
+            for (name, value) in &values {
+                let key = to_env_var_name(name);
+                if let Some(other) = seen.insert(key.clone(), name) {
+                    return Err(CrosstacheError::invalid_argument(format!(
+                        "secrets '{other}' and '{name}' both map to '{key}'; use --format json"
+                    )));
+                }
+                lines.push_str(&format!(
+                    "{key}={}\n",
+                    crate::cli::config_ops::quote_posix_shell_value(value)
+                ));
+            }
+            print!("{}", lines.as_str());
+        }
+    }
+    Ok(())
+}
+
+/// Preview of a secret for `get_default_output = "masked"`: the last four
+/// characters of values long enough that they give little away, stars for
+/// the rest.
+fn mask_value(value: &str) -> String {
+    let len = value.chars().count();
+    if len < 16 {
+        return "*".repeat(len.clamp(1, 8));
+    }
+    let tail: String = value.chars().skip(len - 4).collect();
+    format!("{}{tail}", "*".repeat(8))
+}
+
+fn print_masked(label: &str, value: &str) {
+    println!("{}", mask_value(value));
+    output::hint(&format!(
+        "{} characters, masked by get_default_output; use 'xv get {label} --raw' or --clipboard for the value.",
+        value.chars().count()
+    ));
+}
+
+/// `xv get --qr`: record `entry` in the transfer audit log, draw `value`
+/// as a QR code, then once the user presses Enter clear the screen and
+/// scrollback so the code does not linger. Nothing is drawn if the log
+/// cannot be written.
+fn show_secret_qr(label: &str, value: &str, entry: &AuditEntry) -> Result<()> {
+    use std::io::{IsTerminal, Write};
+
+    let code = render_qr(value)?;
+    crate::utils::audit_log::append_transfer(entry)?;
+    println!("{code}");
+    if std::io::stdin().is_terminal() {
+        eprint!("Scan the code for '{label}', then press Enter to clear it.");
+        std::io::stderr().flush()?;
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        print!("\x1b[2J\x1b[3J\x1b[H");
+        std::io::stdout().flush()?;
+    }
+    Ok(())
 }
 
 fn trait_secret_cache_key(backend_name: &str, vault_name: &str) -> crate::cache::CacheKey {
@@ -1114,21 +1346,29 @@ pub(crate) fn confirm_reserved_key_write(
 fn filter_secret_summaries_for_display(
     mut secrets: Vec<crate::secret::manager::SecretSummary>,
     group: Option<&str>,
-    all: bool,
+    enabled: EnabledFilter,
 ) -> Vec<crate::secret::manager::SecretSummary> {
     // The attachment key is infrastructure, not a user secret.
     secrets.retain(|s| s.name != crate::secret::attachments::ATTACHMENT_KEY_SECRET);
-    if !all {
-        secrets.retain(|s| s.enabled);
-    }
+    secrets.retain(|s| enabled.admits(s.enabled));
     if let Some(g) = group {
-        secrets.retain(|s| secret_summary_matches_group(s, g));
+        secrets.retain(|s| crate::secret::manager::groups_tag_contains(s.groups.as_deref(), g));
     }
     secrets
 }
 
+/// Count-line suffix naming the disabled secrets the default (enabled-only)
+/// view left out, e.g. " (3 disabled hidden; use --all to show)".
+fn disabled_hidden_suffix(disabled_hidden: usize) -> String {
+    if disabled_hidden == 0 {
+        String::new()
+    } else {
+        format!(" ({disabled_hidden} disabled hidden; use --all to show)")
+    }
+}
+
 /// Fold summaries into (group → member count), tokenizing the comma-separated
-/// `groups` tag exactly like `secret_summary_matches_group`. A group repeated
+/// `groups` tag exactly like `groups_tag_contains`. A group repeated
 /// within one secret counts that secret once.
 fn derive_group_rows(secrets: &[crate::secret::manager::SecretSummary]) -> Vec<GroupListRow> {
     let mut counts: std::collections::BTreeMap<String, usize> = std::collections::BTreeMap::new();
@@ -1150,19 +1390,7 @@ fn derive_group_rows(secrets: &[crate::secret::manager::SecretSummary]) -> Vec<G
         .collect()
 }
 
-pub(crate) async fn execute_group_command(
-    command: crate::cli::commands::GroupCommands,
-    config: Config,
-    registry: Option<&BackendRegistry>,
-) -> Result<()> {
-    match command {
-        crate::cli::commands::GroupCommands::List { no_cache } => {
-            execute_group_list(no_cache, config, registry).await
-        }
-    }
-}
-
-async fn execute_group_list(
+pub(crate) async fn execute_group_list(
     no_cache: bool,
     config: Config,
     registry: Option<&BackendRegistry>,
@@ -1206,7 +1434,7 @@ async fn execute_group_list(
         }
     };
 
-    let filtered = filter_secret_summaries_for_display(secrets, None, false);
+    let filtered = filter_secret_summaries_for_display(secrets, None, EnabledFilter::EnabledOnly);
     let rows = derive_group_rows(&filtered);
     let fmt = config.runtime_output_format;
     let human = matches!(
@@ -1284,6 +1512,8 @@ struct SecretListDisplayRow {
     folder: String,
     #[tabled(rename = "Groups")]
     groups: String,
+    #[tabled(rename = "Expires")]
+    expires: String,
     #[tabled(rename = "Updated")]
     updated_on: String,
 }
@@ -1314,10 +1544,25 @@ struct SecretListDisplayRowTyped {
     folder: String,
     #[tabled(rename = "Groups")]
     groups: String,
+    #[tabled(rename = "Expires")]
+    expires: String,
     #[tabled(rename = "Updated")]
     updated_on: String,
 }
 
+/// `Expires` cell for the list table: the expiry date plus a relative
+/// indicator, or empty (so the column hides) when no expiry is set. Table
+/// cells are escape-sanitized, so the color-coded form is `xv info` only.
+fn expires_cell(secret: &crate::secret::manager::SecretSummary) -> String {
+    secret
+        .expires_on
+        .map(|expiry| {
+            let (label, _) = crate::utils::datetime::describe_expiry(expiry, chrono::Utc::now());
+            format!("{} ({label})", expiry.format("%Y-%m-%d"))
+        })
+        .unwrap_or_default()
+}
+
 fn format_secret_list_rows_for_human(
     secrets: &[crate::secret::manager::SecretSummary],
 ) -> Vec<SecretListDisplayRow> {
@@ -1332,6 +1577,7 @@ fn format_secret_list_rows_for_human(
                 .unwrap_or_default(),
             folder: secret.folder.clone().unwrap_or_default(),
             groups: secret.groups.clone().unwrap_or_default(),
+            expires: expires_cell(secret),
             updated_on: crate::cli::ls_view::date_portion_for_display(&secret.updated_on),
         })
         .collect()
@@ -1352,6 +1598,7 @@ fn format_secret_list_rows_for_human_typed(
                 .unwrap_or_default(),
             folder: secret.folder.clone().unwrap_or_default(),
             groups: secret.groups.clone().unwrap_or_default(),
+            expires: expires_cell(secret),
             updated_on: crate::cli::ls_view::date_portion_for_display(&secret.updated_on),
         })
         .collect()
@@ -1375,6 +1622,8 @@ struct SecretListDisplayRowVault {
     folder: String,
     #[tabled(rename = "Groups")]
     groups: String,
+    #[tabled(rename = "Expires")]
+    expires: String,
     #[tabled(rename = "Updated")]
     updated_on: String,
 }
@@ -1396,6 +1645,8 @@ struct SecretListDisplayRowVaultTyped {
     folder: String,
     #[tabled(rename = "Groups")]
     groups: String,
+    #[tabled(rename = "Expires")]
+    expires: String,
     #[tabled(rename = "Updated")]
     updated_on: String,
 }
@@ -1415,6 +1666,7 @@ fn format_secret_list_rows_for_human_vault(
                 .unwrap_or_default(),
             folder: secret.folder.clone().unwrap_or_default(),
             groups: secret.groups.clone().unwrap_or_default(),
+            expires: expires_cell(secret),
             updated_on: crate::cli::ls_view::date_portion_for_display(&secret.updated_on),
         })
         .collect()
@@ -1436,6 +1688,7 @@ fn format_secret_list_rows_for_human_vault_typed(
                 .unwrap_or_default(),
             folder: secret.folder.clone().unwrap_or_default(),
             groups: secret.groups.clone().unwrap_or_default(),
+            expires: expires_cell(secret),
             updated_on: crate::cli::ls_view::date_portion_for_display(&secret.updated_on),
         })
         .collect()
@@ -1459,6 +1712,34 @@ fn filter_secrets_by_type(
     secrets
 }
 
+/// `xv ls --tag KEY=VALUE` / `--tag-exists KEY`: every condition must hold.
+/// Applied to the full listing like `--type`, so a cached listing answers
+/// it without another backend call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct TagQuery {
+    pub equals: Vec<(String, String)>,
+    pub exists: Vec<String>,
+}
+
+impl TagQuery {
+    pub(crate) fn matches(&self, secret: &crate::secret::manager::SecretSummary) -> bool {
+        self.equals
+            .iter()
+            .all(|(key, value)| secret.tags.get(key) == Some(value))
+            && self.exists.iter().all(|key| secret.tags.contains_key(key))
+    }
+
+    fn filter(
+        &self,
+        mut secrets: Vec<crate::secret::manager::SecretSummary>,
+    ) -> Vec<crate::secret::manager::SecretSummary> {
+        if *self != Self::default() {
+            secrets.retain(|s| self.matches(s));
+        }
+        secrets
+    }
+}
+
 /// Filters `secrets` down to those whose name (either the user-facing
 /// `original_name` or the backend `name`) matches `filter`'s glob pattern.
 /// Used by `xv ls --filter` and, on the pre-scoring candidate set, by
@@ -1496,7 +1777,7 @@ fn filter_deleted_secrets_by_glob(
 /// `xv-type` tag into `record_type`, for `ls --format json` (record-types
 /// plan Task 10). Other keys match `SecretSummary`'s existing JSON shape
 /// exactly (same field names, no `tags` key) so untyped-secret JSON output
-/// is unaffected beyond the two new keys.
+/// is unaffected beyond the two new keys. `xv ls --size` adds `value_size`.
 fn secret_summary_to_json_with_fields(
     s: &crate::secret::manager::SecretSummary,
 ) -> serde_json::Value {
@@ -1506,7 +1787,7 @@ fn secret_summary_to_json_with_fields(
             fields.insert(f.to_string(), serde_json::Value::String(v.clone()));
         }
     }
-    serde_json::json!({
+    let mut value = serde_json::json!({
         "name": s.name,
         "original_name": s.original_name,
         "note": s.note,
@@ -1517,7 +1798,13 @@ fn secret_summary_to_json_with_fields(
         "content_type": s.content_type,
         "record_type": s.tags.get(TYPE_TAG),
         "fields": fields,
-    })
+    });
+    if let Some(size) = crate::cli::ls_view::value_size_of(s) {
+        if let serde_json::Value::Object(ref mut map) = value {
+            map.insert("value_size".to_string(), serde_json::json!(size));
+        }
+    }
+    value
 }
 
 fn wrap_text_to_width(input: &str, width: usize) -> String {
@@ -1592,7 +1879,7 @@ fn push_wrapped_word(word: &str, width: usize, current: &mut String, lines: &mut
 pub(crate) fn display_cached_secret_list(
     secrets: Vec<crate::secret::manager::SecretSummary>,
     group: Option<String>,
-    all: bool,
+    enabled: EnabledFilter,
     path: &str,
     long: bool,
     recursive: bool,
@@ -1604,6 +1891,7 @@ pub(crate) fn display_cached_secret_list(
     names_only: bool,
     type_filter: Option<&str>,
     filter: Option<&str>,
+    tags: &TagQuery,
     show_vault: bool,
 ) -> Result<()> {
     use crate::cli::ls_view::{self, LsEntry};
@@ -1611,9 +1899,21 @@ pub(crate) fn display_cached_secret_list(
     use crate::utils::pagination::{paginate_slice, pagination_footer_text};
     use std::fmt::Write as _;
 
-    let filtered = filter_secret_summaries_for_display(secrets, group.as_deref(), all);
-    let filtered = filter_secrets_by_type(filtered, type_filter);
-    let filtered = filter_secrets_by_glob(filtered, filter)?;
+    // Filter on everything but enabled state first, so the count line can
+    // say how many disabled secrets in this scope the default view hid.
+    let candidates =
+        filter_secret_summaries_for_display(secrets, group.as_deref(), EnabledFilter::All);
+    let candidates = filter_secrets_by_type(candidates, type_filter);
+    let candidates = filter_secrets_by_glob(candidates, filter)?;
+    let candidates = tags.filter(candidates);
+    let (filtered, hidden): (Vec<_>, Vec<_>) = candidates
+        .into_iter()
+        .partition(|s| enabled.admits(s.enabled));
+    let disabled_hidden = if enabled == EnabledFilter::EnabledOnly {
+        ls_view::scope_secrets(hidden, path).subtree.len()
+    } else {
+        0
+    };
     let mut scoped = ls_view::scope_secrets(filtered, path);
     if sort == crate::cli::commands::LsSort::Updated {
         // Deliberate: `--sort updated` is an explicit user request for time
@@ -1757,16 +2057,21 @@ pub(crate) fn display_cached_secret_list(
         } else {
             format!("vault '{vault_name}'")
         };
-        let msg = if all {
-            crate::utils::list_output::empty_state_message("secrets", Some(&scope_desc))
-        } else {
-            format!(
+        let msg = match enabled {
+            EnabledFilter::All => {
+                crate::utils::list_output::empty_state_message("secrets", Some(&scope_desc))
+            }
+            EnabledFilter::DisabledOnly => crate::utils::list_output::empty_state_message(
+                "disabled secrets",
+                Some(&scope_desc),
+            ),
+            EnabledFilter::EnabledOnly => format!(
                 "{} Use --all to show disabled secrets.",
                 crate::utils::list_output::empty_state_message(
                     "enabled secrets",
                     Some(&scope_desc)
                 )
-            )
+            ),
         };
         crate::utils::output::info(&msg);
         return Ok(());
@@ -1818,7 +2123,7 @@ pub(crate) fn display_cached_secret_list(
         output.push('\n');
         let _ = writeln!(
             output,
-            "{} in vault '{}'",
+            "{} in vault '{}'{}",
             crate::utils::list_output::count_label(
                 page.items.len(),
                 page.total_items,
@@ -1827,7 +2132,8 @@ pub(crate) fn display_cached_secret_list(
                 None,
                 page.page_size.is_some(),
             ),
-            vault_name
+            vault_name,
+            disabled_hidden_suffix(disabled_hidden)
         );
         if let Some(footer) = pagination_footer_text(&page, "secret", "secrets", fmt) {
             output.push('\n');
@@ -1915,7 +2221,13 @@ pub(crate) fn display_cached_secret_list(
             crate::utils::list_output::pluralize(folder_count, "folder", "folders")
         );
     }
-    let _ = writeln!(output, "{} in vault '{}'", count_line, vault_name);
+    let _ = writeln!(
+        output,
+        "{} in vault '{}'{}",
+        count_line,
+        vault_name,
+        disabled_hidden_suffix(disabled_hidden)
+    );
     if let Some(footer) = pagination_footer_text(&page, "entry", "entries", fmt) {
         output.push('\n');
         output.push_str(&footer);
@@ -1934,6 +2246,8 @@ struct DeletedSecretListRow {
     deleted: String,
     #[tabled(rename = "Purge Scheduled")]
     purge_scheduled: String,
+    #[tabled(rename = "Recovery Level")]
+    recovery_level: String,
 }
 
 fn deleted_display_name(s: &crate::secret::manager::DeletedSecretSummary) -> &str {
@@ -1963,6 +2277,7 @@ fn deleted_list_rows(
                 name: deleted_display_name(s).to_string(),
                 deleted: fmt_date(&s.deleted_on),
                 purge_scheduled: fmt_date(&s.scheduled_purge_on),
+                recovery_level: s.recovery_level.clone().unwrap_or_default(),
             }
         })
         .collect()
@@ -2337,6 +2652,69 @@ async fn execute_deleted_secret_list_workspace(
     )
 }
 
+/// Upper bound on concurrent value reads for `xv ls --size`.
+const VALUE_SIZE_CONCURRENCY: usize = 8;
+
+/// `xv ls --size`: read each secret at or under the listing scope `path`
+/// (the rest can't be displayed) and stash its value's byte length under
+/// [`crate::cli::ls_view::VALUE_SIZE_TAG`], then warn about values near the
+/// backend's size limit. Runs after the list is cached so the synthetic tag
+/// never reaches the cache. A failed read is a warning that leaves the row's
+/// size blank, matching the expiry filter's per-secret fetch handling.
+async fn annotate_value_sizes(
+    backend: &dyn crate::backend::Backend,
+    vault_name: &str,
+    secrets: &mut [crate::secret::manager::SecretSummary],
+    path: &str,
+) {
+    use crate::cli::ls_view::{folder_in_scope, VALUE_SIZE_TAG};
+    use futures::stream::{self, StreamExt};
+
+    let limit = backend.capabilities().max_secret_size;
+    let sizes: Vec<(usize, Option<usize>)> = stream::iter(
+        secrets
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| folder_in_scope(s.folder.as_deref().unwrap_or(""), path)),
+    )
+    .map(|(index, s)| async move {
+        match backend
+            .secrets()
+            .get_secret(vault_name, &s.name, true)
+            .await
+        {
+            Ok(props) => (index, props.value.map(|v| v.len())),
+            Err(e) => {
+                output::warn(&format!("Failed to read size of secret '{}': {e}", s.name));
+                (index, None)
+            }
+        }
+    })
+    .buffer_unordered(VALUE_SIZE_CONCURRENCY)
+    .collect()
+    .await;
+
+    let mut near_limit: Vec<(usize, usize, u64)> = Vec::new();
+    for (index, size) in sizes {
+        let Some(size) = size else { continue };
+        secrets[index]
+            .tags
+            .insert(VALUE_SIZE_TAG.to_string(), size.to_string());
+        if let Some(pct) = crate::secret::models::value_size_pressure(size, limit) {
+            near_limit.push((index, size, pct));
+        }
+    }
+    near_limit.sort_by_key(|entry| std::cmp::Reverse(entry.2));
+    for (index, size, pct) in near_limit {
+        output::warn(&format!(
+            "'{}' is {} ({pct}% of the backend's {} value limit)",
+            crate::cli::ls_view::display_name(&secrets[index]),
+            crate::utils::format::format_size(size as u64),
+            crate::utils::format::format_size(limit.unwrap_or_default() as u64)
+        ));
+    }
+}
+
 /// Union `ls` over every vault attached to a workspace (multi-vault
 /// workspaces plan, Phase B Task 7). Only reached when
 /// [`crate::workspace::resolve_workspace`] returns `Some` — the no-workspace
@@ -2356,7 +2734,7 @@ async fn execute_secret_list_workspace(
     ws: crate::workspace::Workspace,
     path: String,
     group: Option<String>,
-    all: bool,
+    enabled: EnabledFilter,
     expiring: Option<String>,
     expired: bool,
     no_cache: bool,
@@ -2368,6 +2746,8 @@ async fn execute_secret_list_workspace(
     sort: crate::cli::commands::LsSort,
     type_filter: Option<String>,
     filter: Option<String>,
+    tags: TagQuery,
+    size: bool,
     config: Config,
 ) -> Result<()> {
     use crate::cache::CacheManager;
@@ -2429,7 +2809,7 @@ async fn execute_secret_list_workspace(
             use crate::utils::datetime::{is_expired, is_expiring_within};
 
             let display_candidates =
-                filter_secret_summaries_for_display(secrets, group.as_deref(), all);
+                filter_secret_summaries_for_display(secrets, group.as_deref(), enabled);
             let mut filtered_secrets = Vec::new();
             for secret_summary in display_candidates {
                 match backend
@@ -2466,6 +2846,10 @@ async fn execute_secret_list_workspace(
             secrets = filtered_secrets;
         }
 
+        if size {
+            annotate_value_sizes(&*backend, &entry.vault, &mut secrets, &path).await;
+        }
+
         for s in &mut secrets {
             s.tags
                 .insert(WORKSPACE_ALIAS_TAG.to_string(), entry.alias.clone());
@@ -2493,9 +2877,9 @@ async fn execute_secret_list_workspace(
             group
         },
         if expired || expiring.is_some() {
-            true
+            EnabledFilter::All
         } else {
-            all
+            enabled
         },
         &path,
         long,
@@ -2508,6 +2892,7 @@ async fn execute_secret_list_workspace(
         names_only,
         type_filter.as_deref(),
         filter.as_deref(),
+        &tags,
         show_vault,
     )
 }
@@ -2516,7 +2901,7 @@ async fn execute_secret_list_workspace(
 pub(crate) async fn execute_secret_list_direct(
     path: String,
     group: Option<String>,
-    all: bool,
+    enabled: EnabledFilter,
     expiring: Option<String>,
     expired: bool,
     no_cache: bool,
@@ -2528,6 +2913,8 @@ pub(crate) async fn execute_secret_list_direct(
     sort: crate::cli::commands::LsSort,
     type_filter: Option<String>,
     filter: Option<String>,
+    tags: TagQuery,
+    size: bool,
     config: Config,
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
@@ -2547,7 +2934,7 @@ pub(crate) async fn execute_secret_list_direct(
             ws,
             path,
             group,
-            all,
+            enabled,
             expiring,
             expired,
             no_cache,
@@ -2559,6 +2946,8 @@ pub(crate) async fn execute_secret_list_direct(
             sort,
             type_filter,
             filter,
+            tags,
+            size,
             config,
         )
         .await;
@@ -2578,13 +2967,16 @@ pub(crate) async fn execute_secret_list_direct(
 
         // Try cache (skip for expiry filters — they need per-secret API calls)
         if use_cache && expiring.is_none() && !expired {
-            if let Some(cached) =
+            if let Some(mut cached) =
                 cache_manager.get::<Vec<crate::secret::manager::SecretSummary>>(&cache_key)
             {
+                if size {
+                    annotate_value_sizes(reg.active(), &vault_name, &mut cached, &path).await;
+                }
                 return display_cached_secret_list(
                     cached,
                     group,
-                    all,
+                    enabled,
                     &path,
                     long,
                     recursive,
@@ -2596,6 +2988,7 @@ pub(crate) async fn execute_secret_list_direct(
                     names_only,
                     type_filter.as_deref(),
                     filter.as_deref(),
+                    &tags,
                     false,
                 );
             }
@@ -2605,11 +2998,49 @@ pub(crate) async fn execute_secret_list_direct(
         // derive the display set from this cached dataset after applying the
         // cheap group/enabled filters so we only call get_secret for rows that
         // can actually be displayed.
-        let all_secrets = reg
+        let all_secrets = match reg
             .active()
             .secrets()
             .list_secrets(&vault_name, None)
-            .await?;
+            .await
+            .map_err(CrosstacheError::from)
+        {
+            Ok(secrets) => secrets,
+            Err(e)
+                if expiring.is_none()
+                    && !expired
+                    && crate::cli::helpers::can_list_offline(&e, use_cache) =>
+            {
+                let Some((cached, cached_at)) = cache_manager
+                    .get_stale::<Vec<crate::secret::manager::SecretSummary>>(&cache_key)
+                else {
+                    return Err(e);
+                };
+                crate::cli::helpers::warn_offline_listing(
+                    &format!("vault '{vault_name}'"),
+                    cached_at,
+                );
+                return display_cached_secret_list(
+                    cached,
+                    group,
+                    enabled,
+                    &path,
+                    long,
+                    recursive,
+                    sort,
+                    pagination,
+                    pager,
+                    &vault_name,
+                    &config,
+                    names_only,
+                    type_filter.as_deref(),
+                    filter.as_deref(),
+                    &tags,
+                    false,
+                );
+            }
+            Err(e) => return Err(e),
+        };
 
         // Cache the unfiltered list so subsequent calls see the full dataset.
         if use_cache {
@@ -2617,11 +3048,11 @@ pub(crate) async fn execute_secret_list_direct(
         }
 
         // Apply expiry filtering if requested (requires per-secret trait calls)
-        let secrets = if expired || expiring.is_some() {
+        let mut secrets = if expired || expiring.is_some() {
             use crate::utils::datetime::{is_expired, is_expiring_within};
 
             let display_candidates =
-                filter_secret_summaries_for_display(all_secrets, group.as_deref(), all);
+                filter_secret_summaries_for_display(all_secrets, group.as_deref(), enabled);
             let mut filtered_secrets = Vec::new();
             for secret_summary in display_candidates {
                 match reg
@@ -2660,6 +3091,9 @@ pub(crate) async fn execute_secret_list_direct(
         } else {
             all_secrets
         };
+        if size {
+            annotate_value_sizes(reg.active(), &vault_name, &mut secrets, &path).await;
+        }
 
         return display_cached_secret_list(
             secrets,
@@ -2669,9 +3103,9 @@ pub(crate) async fn execute_secret_list_direct(
                 group
             },
             if expired || expiring.is_some() {
-                true
+                EnabledFilter::All
             } else {
-                all
+                enabled
             },
             &path,
             long,
@@ -2684,6 +3118,7 @@ pub(crate) async fn execute_secret_list_direct(
             names_only,
             type_filter.as_deref(),
             filter.as_deref(),
+            &tags,
             false,
         );
     }
@@ -2724,6 +3159,15 @@ pub(crate) async fn execute_secret_delete_direct(
                 output::info(&format!("No secrets found in group '{group_name}'"));
                 return Ok(());
             }
+            crate::cli::helpers::preflight_permissions(
+                &config,
+                &*backend,
+                &vault_name,
+                None,
+                &[crate::vault::models::VaultPermission::Delete],
+                "delete secrets",
+            )
+            .await?;
             // Exclude reserved-key entries from the prompt count (they're refused below anyway)
             let deletable_count = secrets
                 .iter()
@@ -2884,6 +3328,45 @@ pub(crate) async fn execute_secret_delete_direct(
     ))
 }
 
+/// One `xv history` row: a version and whether a read without `--version`
+/// returns it. Serializes as the version's own fields plus `current`.
+#[derive(tabled::Tabled, serde::Serialize)]
+struct HistoryRow {
+    #[serde(flatten)]
+    #[tabled(inline)]
+    version: crate::secret::manager::SecretProperties,
+    #[tabled(rename = "Current", display_with = "display_current")]
+    current: bool,
+}
+
+fn display_current(current: &bool) -> String {
+    if *current {
+        "*".to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// The version a plain read returns: on AWS the one staged `AWSCURRENT`
+/// (a rollback moves the label, not the version order), elsewhere the
+/// newest.
+fn current_version_index(versions: &[crate::secret::manager::SecretProperties]) -> Option<usize> {
+    versions
+        .iter()
+        .position(|v| {
+            v.tags
+                .get("aws:stages")
+                .is_some_and(|stages| stages.split(',').any(|s| s.trim() == "AWSCURRENT"))
+        })
+        .or_else(|| {
+            versions
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, v)| (v.version_number, v.created_timestamp))
+                .map(|(index, _)| index)
+        })
+}
+
 pub(crate) async fn execute_secret_history_direct(
     name: &str,
     config: Config,
@@ -2918,45 +3401,39 @@ pub(crate) async fn execute_secret_history_direct(
         }
 
         let versions = backend.secrets().list_versions(&vault_name, name).await?;
-        if versions.is_empty() {
-            let fmt = config.runtime_output_format;
-            use crate::utils::format::TableFormatter;
-            let formatter = TableFormatter::new(
-                fmt,
-                config.no_color,
-                config.template.clone(),
-                config.runtime_columns.clone(),
-            );
-            if matches!(
-                fmt,
-                OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
-            ) {
-                formatter.validate_columns::<crate::secret::manager::SecretProperties>()?;
-                output::info(&format!("No version history for '{name}'"));
-            } else {
-                // Valid-empty machine output on stdout (e.g. `[]` for JSON).
-                println!("{}", formatter.format_table(&versions)?);
-            }
+        let current = current_version_index(&versions);
+        let rows: Vec<HistoryRow> = versions
+            .into_iter()
+            .enumerate()
+            .map(|(index, version)| HistoryRow {
+                version,
+                current: Some(index) == current,
+            })
+            .collect();
+        let fmt = config.runtime_output_format;
+        use crate::utils::format::TableFormatter;
+        let formatter = TableFormatter::new(
+            fmt,
+            config.no_color,
+            config.template.clone(),
+            config.runtime_columns.clone(),
+        );
+        let human = matches!(
+            fmt,
+            OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
+        );
+        if rows.is_empty() && human {
+            formatter.validate_columns::<HistoryRow>()?;
+            output::info(&format!("No version history for '{name}'"));
         } else {
-            use crate::utils::format::TableFormatter;
-            let formatter = TableFormatter::new(
-                config.runtime_output_format,
-                config.no_color,
-                config.template.clone(),
-                config.runtime_columns.clone(),
-            );
-            let table = formatter.format_table(&versions)?;
-            println!("{table}");
-            let fmt = config.runtime_output_format;
-            if matches!(
-                fmt,
-                OutputFormat::Table | OutputFormat::Plain | OutputFormat::Raw
-            ) {
+            // Valid-empty machine output on stdout (e.g. `[]` for JSON).
+            println!("{}", formatter.format_table(&rows)?);
+            if human {
                 println!(
                     "{} of '{name}'",
                     crate::utils::list_output::count_label(
-                        versions.len(),
-                        versions.len(),
+                        rows.len(),
+                        rows.len(),
                         "version",
                         "versions",
                         None,
@@ -3083,9 +3560,7 @@ async fn resolve_rotate_target(
 pub(crate) async fn execute_secret_rotate_direct(
     name: &str,
     vault: Option<String>,
-    length: usize,
-    charset: CharsetType,
-    generator: Option<String>,
+    rotator: Box<dyn crate::secret::rotate::Rotator>,
     native: bool,
     show_value: bool,
     force: bool,
@@ -3124,9 +3599,7 @@ pub(crate) async fn execute_secret_rotate_direct(
         &backend_name,
         &resolved_name,
         Some(vault_name.clone()),
-        length,
-        charset,
-        generator,
+        rotator.as_ref(),
         show_value,
         force,
         &config,
@@ -3246,6 +3719,7 @@ async fn execute_secret_rotate_native(
 pub(crate) async fn execute_secret_run_direct(
     vault: Option<String>,
     group: Vec<String>,
+    folder: Option<String>,
     include: Vec<String>,
     exclude: Vec<String>,
     no_masking: bool,
@@ -3268,6 +3742,7 @@ pub(crate) async fn execute_secret_run_direct(
         reg,
         vault,
         group,
+        folder,
         include,
         exclude,
         no_masking,
@@ -3323,7 +3798,9 @@ fn confirm_record_action(yes: bool, prompt: &str) -> Result<bool> {
 
 /// Non-reserved, non-`f.*` user tag entries of `tags` — the same "everything
 /// else" set the tag-budget check counts as `user_tags` on `xv set --type`.
-fn user_tags_of(tags: &std::collections::HashMap<String, String>) -> BTreeMap<String, String> {
+pub(crate) fn user_tags_of(
+    tags: &std::collections::HashMap<String, String>,
+) -> BTreeMap<String, String> {
     tags.iter()
         .filter(|(k, _)| {
             let k = k.as_str();
@@ -3626,7 +4103,7 @@ fn resolve_primary_field<'a>(
 /// envelope field are preserved exactly like a `--field`/`--field-secret`
 /// edit.
 #[allow(clippy::too_many_arguments)]
-async fn execute_record_primary_update(
+pub(crate) async fn execute_record_primary_update(
     name: &str,
     new_primary_value: &str,
     secret: &crate::secret::manager::SecretProperties,
@@ -3892,6 +4369,13 @@ pub(crate) async fn execute_secret_update_direct(
     config: Config,
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
+    if value.is_some() {
+        check_command_line_value("a command-line argument", &config)?;
+    }
+    if !secret_fields.is_empty() {
+        check_command_line_value("--field-secret", &config)?;
+    }
+
     // ── Trait-based path (non-Azure backends) ──────────────────────────
     if use_trait_path(registry) {
         use crate::secret::manager::FieldUpdate;
@@ -4222,6 +4706,7 @@ pub(crate) async fn execute_secret_update_direct(
 pub(crate) async fn execute_secret_purge_direct(
     name: &str,
     force: bool,
+    when_eligible: bool,
     config: Config,
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
@@ -4259,6 +4744,24 @@ pub(crate) async fn execute_secret_purge_direct(
     }
 
     let name = resolved_name.as_str();
+    // Best-effort, like restore: the lookup only adds detail.
+    let deleted = backend
+        .secrets()
+        .get_deleted_secret(&vault_name, name)
+        .await
+        .ok()
+        .flatten();
+    if let Some(deleted) = &deleted {
+        if !deleted.is_purgeable() {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "Deleted secret '{name}' cannot be purged: its recovery level is '{}', so vault \
+                 '{vault_name}' has purge protection on. {}",
+                deleted.recovery_level.as_deref().unwrap_or_default(),
+                purged_automatically(deleted.scheduled_purge_on.as_deref())
+            )));
+        }
+        print_purge_schedule(deleted.scheduled_purge_on.as_deref(), chrono::Utc::now());
+    }
     if !confirm_destructive(
         force,
         &format!("PERMANENTLY DELETE secret '{name}'? This cannot be undone."),
@@ -4266,12 +4769,124 @@ pub(crate) async fn execute_secret_purge_direct(
         output::info("Aborted; secret not purged.");
         return Ok(());
     }
-    backend.secrets().purge_secret(&vault_name, name).await?;
+    let scheduled = deleted.and_then(|d| d.scheduled_purge_on);
+    purge_with_retry(
+        &format!("secret '{name}'"),
+        scheduled.as_deref(),
+        when_eligible,
+        || backend.secrets().purge_secret(&vault_name, name),
+    )
+    .await?;
     output::success(&format!("Successfully purged secret '{name}'"));
     invalidate_trait_secret_cache(&config, &backend_name, &vault_name);
     Ok(())
 }
 
+/// How long `--when-eligible` keeps retrying a purge, and how often.
+const PURGE_WAIT_LIMIT: std::time::Duration = std::time::Duration::from_secs(300);
+const PURGE_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Days from `now` until `scheduled`, a purge date as backends format it
+/// (RFC 3339, or `2024-09-01 12:00:00 UTC`). `None` when it doesn't parse.
+fn days_until_purge(scheduled: &str, now: chrono::DateTime<chrono::Utc>) -> Option<i64> {
+    let when = crate::utils::datetime::parse_iso_datetime(scheduled)
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDateTime::parse_from_str(scheduled, "%Y-%m-%d %H:%M:%S UTC")
+                .ok()
+                .map(|naive| naive.and_utc())
+        })?;
+    Some((when - now).num_days().max(0))
+}
+
+/// The sentence saying when a protected item goes away on its own.
+fn purged_automatically(scheduled: Option<&str>) -> String {
+    match scheduled {
+        Some(date) => format!(
+            "It will be purged automatically on {}.",
+            crate::cli::ls_view::date_portion_for_display(date)
+        ),
+        None => "It will be purged automatically when its retention period ends.".to_string(),
+    }
+}
+
+/// Before a purge: when the item would be purged on its own, and that
+/// purging now gives up the rest of the window in which it can be restored.
+pub(crate) fn print_purge_schedule(scheduled: Option<&str>, now: chrono::DateTime<chrono::Utc>) {
+    let Some(date) = scheduled else {
+        return;
+    };
+    let display = crate::cli::ls_view::date_portion_for_display(date);
+    match days_until_purge(date, now) {
+        Some(days) => output::info(&format!(
+            "Scheduled for permanent deletion on {display} (in {days} day(s)); purging now \
+             gives up those days in which it can still be restored."
+        )),
+        None => output::info(&format!(
+            "Scheduled for permanent deletion on {display}; purging now gives up the time \
+             left in which it can still be restored."
+        )),
+    }
+}
+
+/// Run `purge` for `what`. With `when_eligible`, retry while the backend
+/// still reports the item as mid-delete: Azure answers 404/409 for a while
+/// after a soft delete, until the item is purgeable. A refusal because of
+/// purge protection is reported as such, with `scheduled` if known.
+pub(crate) async fn purge_with_retry<F, Fut>(
+    what: &str,
+    scheduled: Option<&str>,
+    when_eligible: bool,
+    mut purge: F,
+) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<(), crate::backend::BackendError>>,
+{
+    use crate::backend::BackendError;
+
+    let started = std::time::Instant::now();
+    let mut announced = false;
+    loop {
+        match purge().await {
+            Ok(()) => return Ok(()),
+            Err(e) if e.to_string().to_lowercase().contains("purge protection") => {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "Cannot purge {what}: purge protection is enabled. {}",
+                    purged_automatically(scheduled)
+                )));
+            }
+            Err(BackendError::NotFound { .. } | BackendError::Conflict(_))
+                if when_eligible && started.elapsed() < PURGE_WAIT_LIMIT =>
+            {
+                if !announced {
+                    output::step(&format!(
+                        "Waiting for {what} to become purgeable (up to {} minutes)...",
+                        PURGE_WAIT_LIMIT.as_secs() / 60
+                    ));
+                    announced = true;
+                }
+                tokio::time::sleep(PURGE_RETRY_INTERVAL).await;
+            }
+            Err(e) => {
+                if when_eligible
+                    && matches!(e, BackendError::NotFound { .. } | BackendError::Conflict(_))
+                {
+                    output::warn(&format!(
+                        "{what} was still not purgeable after {} minutes",
+                        PURGE_WAIT_LIMIT.as_secs() / 60
+                    ));
+                } else if matches!(e, BackendError::Conflict(_)) {
+                    output::hint(
+                        "If it was deleted moments ago, retry with --when-eligible to wait until it can be purged.",
+                    );
+                }
+                return Err(e.into());
+            }
+        }
+    }
+}
+
 pub(crate) async fn execute_secret_restore_direct(
     name: &str,
     config: Config,
@@ -4309,6 +4924,22 @@ pub(crate) async fn execute_secret_restore_direct(
         )));
     }
 
+    // Best-effort: the lookup only adds detail, so a failure (e.g. no
+    // list permission on deleted secrets) must not block the restore.
+    let deleted = backend
+        .secrets()
+        .get_deleted_secret(&vault_name, &resolved_name)
+        .await
+        .ok()
+        .flatten();
+    if let Some(deleted) = deleted.as_ref().filter(|d| !d.is_recoverable()) {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Deleted secret '{}' cannot be restored: its recovery level is '{}'. It can only be purged.",
+            deleted_display_name(deleted),
+            deleted.recovery_level.as_deref().unwrap_or_default()
+        )));
+    }
+
     let props = backend
         .secrets()
         .restore_secret(&vault_name, &resolved_name)
@@ -4317,24 +4948,90 @@ pub(crate) async fn execute_secret_restore_direct(
         "Successfully restored secret '{}'",
         props.original_name
     ));
+    if let Some(date) = deleted
+        .as_ref()
+        .and_then(|d| d.scheduled_purge_on.as_deref())
+    {
+        println!(
+            "   Was scheduled for purge: {}",
+            crate::cli::ls_view::date_portion_for_display(date)
+        );
+    }
+    if let Some(level) = props
+        .recovery_level
+        .as_deref()
+        .or_else(|| deleted.as_ref().and_then(|d| d.recovery_level.as_deref()))
+    {
+        println!("   Recovery level: {level}");
+    }
     invalidate_trait_secret_cache(&config, &backend_name, &vault_name);
     Ok(())
 }
 
-pub(crate) async fn execute_diff_command(
-    vault1: &str,
-    vault2: &str,
-    show_values: bool,
+/// What `xv restore --all` and `xv purge --all` do to each deleted secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeletedBulkAction {
+    Restore,
+    Purge,
+}
+
+impl DeletedBulkAction {
+    fn verb(self) -> &'static str {
+        match self {
+            Self::Restore => "restore",
+            Self::Purge => "purge",
+        }
+    }
+
+    fn done(self) -> &'static str {
+        match self {
+            Self::Restore => "restored",
+            Self::Purge => "purged",
+        }
+    }
+}
+
+/// Whether a deleted secret falls under the bulk `--group`/`--folder`
+/// filters: in `group`, and at or under `folder`.
+fn deleted_in_scope(
+    deleted: &crate::secret::manager::DeletedSecretSummary,
+    group: Option<&str>,
+    folder: Option<&str>,
+) -> bool {
+    let in_group = group.is_none_or(|group| {
+        crate::secret::manager::groups_tag_contains(
+            deleted.tags.get("groups").map(String::as_str),
+            group,
+        )
+    });
+    let in_folder = folder.is_none_or(|path| {
+        crate::cli::ls_view::folder_in_scope(
+            deleted.tags.get("folder").map(String::as_str).unwrap_or(""),
+            path,
+        )
+    });
+    in_group && in_folder
+}
+
+/// `xv restore --all` / `xv purge --all --force`: restore or purge every
+/// deleted secret of the default vault (optionally only those of a group or
+/// folder), `fetch_concurrency` at a time. Failures don't stop the run; they
+/// are summarized at the end and make the command fail.
+pub(crate) async fn execute_deleted_bulk(
+    action: DeletedBulkAction,
     group: Option<String>,
+    folder: Option<String>,
     config: Config,
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
-    use std::collections::BTreeSet;
+    use crate::utils::output::Level;
+    use crate::utils::progress::ItemProgress;
+    use crate::utils::report::{ItemStatus, ReportRecorder};
+    use futures::stream::{self, StreamExt};
+    use std::time::Instant;
 
-    // Every backend resolves through the trait path now; registry==None
-    // rebuilds from config so a startup init failure surfaces as a clean error.
     let rebuilt_registry;
-    let reg = match registry {
+    let _reg = match registry {
         Some(r) => r,
         None => {
             rebuilt_registry = BackendRegistry::from_config(&config)
@@ -4343,136 +5040,251 @@ pub(crate) async fn execute_diff_command(
         }
     };
 
-    // Workspace-aware: each vault argument resolves against attached aliases
-    // first, else a literal vault on the active backend (spec §Addressing) —
-    // so `xv diff work stage` can span backends, while raw names with no
-    // workspace or alias match behave exactly as before.
-    let (ws, ws_registry) = crate::cli::helpers::resolve_workspace_and_registry(&config).await?;
-    let (backend_a, _backend_a_name, vault1_resolved) =
-        crate::cli::helpers::resolve_vault_ref_with_workspace(
-            vault1,
-            ws.as_ref(),
-            ws_registry.as_ref(),
-            reg,
-            &config,
-        )
-        .await?;
-    let (backend_b, _backend_b_name, vault2_resolved) =
-        crate::cli::helpers::resolve_vault_ref_with_workspace(
-            vault2,
-            ws.as_ref(),
-            ws_registry.as_ref(),
-            reg,
-            &config,
-        )
-        .await?;
-
-    // List secrets from both vaults
-    let secrets_a = backend_a
-        .secrets()
-        .list_secrets(&vault1_resolved, group.as_deref())
-        .await?;
-    let secrets_b = backend_b
-        .secrets()
-        .list_secrets(&vault2_resolved, group.as_deref())
-        .await?;
+    // Not a single addressable name: an empty raw resolves to the
+    // workspace's default vault, as `xv delete --group` does.
+    let (backend, backend_name, vault_name, _) = crate::cli::helpers::resolve_workspace_or_default(
+        "",
+        &config,
+        crate::workspace::TargetMode::Write,
+    )
+    .await?;
 
-    // Build name sets
-    let names_a: BTreeSet<String> = secrets_a.iter().map(|s| s.name.clone()).collect();
-    let names_b: BTreeSet<String> = secrets_b.iter().map(|s| s.name.clone()).collect();
-    let all_names: BTreeSet<String> = names_a.union(&names_b).cloned().collect();
+    if !backend.capabilities().has_soft_delete {
+        return Err(CrosstacheError::InvalidArgument(format!(
+            "The {} backend does not support {} (soft-delete not available).",
+            backend.name(),
+            action.verb()
+        )));
+    }
 
-    // Fetch values from both vaults for comparison
-    let mut values_a = std::collections::HashMap::new();
-    let mut values_b = std::collections::HashMap::new();
+    let folder = folder.map(|f| f.trim_matches('/').to_string());
+    let mut deleted = backend.secrets().list_deleted_secrets(&vault_name).await?;
+    deleted.retain(|d| deleted_in_scope(d, group.as_deref(), folder.as_deref()));
+    if deleted.is_empty() {
+        output::info(&format!(
+            "No deleted secrets to {} in vault '{vault_name}'",
+            action.verb()
+        ));
+        return Ok(());
+    }
 
-    for name in &names_a {
-        match backend_a
-            .secrets()
-            .get_secret(&vault1_resolved, name, true)
-            .await
-        {
-            Ok(props) => {
-                if let Some(val) = props.value {
-                    values_a.insert(name.clone(), val);
-                }
+    let mut recorder = ReportRecorder::new(action.verb());
+    let mut skipped = 0;
+    if action == DeletedBulkAction::Restore {
+        // Entries whose recovery level only allows purging would fail one
+        // by one; report them up front instead.
+        deleted.retain(|d| {
+            if d.is_recoverable() {
+                return true;
             }
-            Err(e) => {
-                output::warn(&format!("Failed to get '{}' from {}: {}", name, vault1, e));
+            recorder.record(
+                deleted_display_name(d),
+                ItemStatus::Skipped,
+                std::time::Duration::ZERO,
+                Some(format!(
+                    "recovery level '{}' only allows purging",
+                    d.recovery_level.as_deref().unwrap_or_default()
+                )),
+            );
+            skipped += 1;
+            false
+        });
+    } else {
+        // Under purge protection nothing can be purged before its date.
+        deleted.retain(|d| {
+            if d.is_purgeable() {
+                return true;
             }
-        }
+            recorder.record(
+                deleted_display_name(d),
+                ItemStatus::Skipped,
+                std::time::Duration::ZERO,
+                Some(format!(
+                    "recovery level '{}' means purge protection is on",
+                    d.recovery_level.as_deref().unwrap_or_default()
+                )),
+            );
+            skipped += 1;
+            false
+        });
     }
 
-    for name in &names_b {
-        match backend_b
-            .secrets()
-            .get_secret(&vault2_resolved, name, true)
-            .await
-        {
-            Ok(props) => {
-                if let Some(val) = props.value {
-                    values_b.insert(name.clone(), val);
+    let secrets = backend.secrets();
+    let vault = vault_name.as_str();
+    let progress = ItemProgress::new(deleted.len() as u64, output::is_tty_stderr());
+    let mut outcomes = stream::iter(&deleted)
+        .map(|d| async move {
+            let started = Instant::now();
+            let result = match action {
+                DeletedBulkAction::Restore => {
+                    secrets.restore_secret(vault, &d.name).await.map(|_| ())
                 }
+                DeletedBulkAction::Purge => secrets.purge_secret(vault, &d.name).await,
+            };
+            (d, started.elapsed(), result)
+        })
+        .buffer_unordered(config.fetch_concurrency.max(1));
+
+    let (mut succeeded, mut failed) = (0, 0);
+    while let Some((d, elapsed, result)) = outcomes.next().await {
+        let name = deleted_display_name(d);
+        match result {
+            Ok(()) => {
+                let label = match action {
+                    DeletedBulkAction::Restore => "Restored",
+                    DeletedBulkAction::Purge => "Purged",
+                };
+                progress.item(Level::Success, &format!("{label} secret: {name}"));
+                recorder.record(name, ItemStatus::Succeeded, elapsed, None);
+                progress.inc();
+                succeeded += 1;
             }
             Err(e) => {
-                output::warn(&format!("Failed to get '{}' from {}: {}", name, vault2, e));
+                progress.notice(
+                    Level::Error,
+                    &format!("Failed to {} secret '{name}': {e}", action.verb()),
+                );
+                recorder.record(name, ItemStatus::Failed, elapsed, Some(e.to_string()));
+                progress.inc_failed();
+                failed += 1;
             }
         }
     }
+    progress.finish();
+
+    if succeeded > 0 {
+        invalidate_trait_secret_cache(&config, &backend_name, &vault_name);
+    }
+
+    crate::cli::vault_ops::print_bulk_summary(&recorder, action.done(), &config);
+    let summary = format!(
+        "Bulk {} completed: {succeeded} {}, {skipped} skipped, {failed} failed",
+        action.verb(),
+        action.done()
+    );
+    if failed > 0 {
+        output::warn(&summary);
+        Err(CrosstacheError::unknown(format!(
+            "{}: {failed} deleted secret(s) could not be {} in vault '{vault_name}'",
+            action.verb(),
+            action.done()
+        )))
+    } else {
+        output::success(&summary);
+        Ok(())
+    }
+}
+
+pub(crate) async fn execute_diff_command(
+    vault1: &str,
+    vault2: &str,
+    values: DiffValues,
+    group: Option<String>,
+    config: Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    use crate::secret::diff::{diff_secrets, fingerprint, DiffKind};
+    use crossterm::style::{Color, Stylize};
+
+    let side_a = load_diff_side(vault1, values, group.as_deref(), &config, registry).await?;
+    let side_b = load_diff_side(vault2, values, group.as_deref(), &config, registry).await?;
+    let entries = diff_secrets(&side_a, &side_b);
+
+    let value_of = |side: &[crate::secret::manager::SecretProperties], name: &str| {
+        side.iter()
+            .find(|s| s.original_name == name)
+            .and_then(|s| s.value.as_deref())
+            .map(|v| match values {
+                DiffValues::Show => v.to_string(),
+                _ => fingerprint(v),
+            })
+            .unwrap_or_else(|| "<not read>".to_string())
+    };
+    let reveal = matches!(values, DiffValues::Show | DiffValues::Hash);
+
+    if config.format_explicit
+        && matches!(
+            config.runtime_output_format,
+            OutputFormat::Json | OutputFormat::Yaml
+        )
+    {
+        let body: Vec<serde_json::Value> = entries
+            .iter()
+            .map(|entry| {
+                let (status, fields) = match &entry.kind {
+                    DiffKind::Added => ("added", Vec::new()),
+                    DiffKind::Removed => ("removed", Vec::new()),
+                    DiffKind::Changed(fields) => ("changed", fields.clone()),
+                    DiffKind::Identical => ("identical", Vec::new()),
+                };
+                let mut item = serde_json::json!({
+                    "name": entry.name,
+                    "status": status,
+                    "fields": fields,
+                });
+                if reveal && fields.contains(&"value") {
+                    item["values"] = serde_json::json!([
+                        value_of(&side_a, &entry.name),
+                        value_of(&side_b, &entry.name),
+                    ]);
+                }
+                item
+            })
+            .collect();
+        let rendered = if config.runtime_output_format == OutputFormat::Json {
+            serde_json::to_string_pretty(&body).map_err(|e| {
+                CrosstacheError::serialization(format!("Failed to serialize diff: {e}"))
+            })?
+        } else {
+            serde_yaml::to_string(&body).map_err(|e| {
+                CrosstacheError::serialization(format!("Failed to serialize diff: {e}"))
+            })?
+        };
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let rich = output::should_use_rich_stdout();
+    let paint = |line: String, color: Color| {
+        if rich {
+            line.with(color).to_string()
+        } else {
+            line
+        }
+    };
 
-    // Compare and output
     println!("Comparing {} → {}", vault1, vault2);
     println!();
 
-    let mut added = 0u32;
-    let mut removed = 0u32;
-    let mut changed = 0u32;
-    let mut identical = 0u32;
-
-    // Find max name length for alignment
-    let max_len = all_names.iter().map(|n| n.len()).max().unwrap_or(0);
+    let (mut added, mut removed, mut changed, mut identical) = (0u32, 0u32, 0u32, 0u32);
+    let max_len = entries.iter().map(|e| e.name.len()).max().unwrap_or(0);
 
-    for name in &all_names {
-        let in_a = names_a.contains(name);
-        let in_b = names_b.contains(name);
-
-        match (in_a, in_b) {
-            (false, true) => {
-                println!(
-                    "  + {:<width$}  (only in {})",
-                    name,
-                    vault2,
-                    width = max_len
-                );
+    for entry in &entries {
+        let name = &entry.name;
+        match &entry.kind {
+            DiffKind::Added => {
+                let line = format!("  + {name:<max_len$}  (only in {vault2})");
+                println!("{}", paint(line, Color::Green));
                 added += 1;
             }
-            (true, false) => {
-                println!(
-                    "  - {:<width$}  (only in {})",
-                    name,
-                    vault1,
-                    width = max_len
-                );
+            DiffKind::Removed => {
+                let line = format!("  - {name:<max_len$}  (only in {vault1})");
+                println!("{}", paint(line, Color::Red));
                 removed += 1;
             }
-            (true, true) => {
-                let val_a = values_a.get(name);
-                let val_b = values_b.get(name);
-                if val_a == val_b {
-                    println!("  = {:<width$}  (identical)", name, width = max_len);
-                    identical += 1;
-                } else {
-                    println!("  ~ {:<width$}  (value differs)", name, width = max_len);
-                    if show_values {
-                        let a_str = val_a.map(|v| v.as_str()).unwrap_or("<empty>");
-                        let b_str = val_b.map(|v| v.as_str()).unwrap_or("<empty>");
-                        println!("      {} : {}", vault1, a_str);
-                        println!("      {} : {}", vault2, b_str);
-                    }
-                    changed += 1;
+            DiffKind::Changed(fields) => {
+                let line = format!("  ~ {name:<max_len$}  ({} differs)", fields.join(", "));
+                println!("{}", paint(line, Color::Yellow));
+                if reveal && fields.contains(&"value") {
+                    println!("      {} : {}", vault1, value_of(&side_a, name));
+                    println!("      {} : {}", vault2, value_of(&side_b, name));
                 }
+                changed += 1;
+            }
+            DiffKind::Identical => {
+                println!("  = {name:<max_len$}  (identical)");
+                identical += 1;
             }
-            (false, false) => unreachable!(),
         }
     }
 
@@ -4485,6 +5297,81 @@ pub(crate) async fn execute_diff_command(
     Ok(())
 }
 
+/// How `xv diff` treats secret values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiffValues {
+    /// Compare values, print neither (the default).
+    Compare,
+    /// Compare values and print differing ones (`--show-values`).
+    Show,
+    /// Compare values and print a SHA-256 fingerprint of each
+    /// (`--hash-values`).
+    Hash,
+    /// Names and metadata only; values are never read (`--no-values`).
+    Skip,
+}
+
+/// One side of `xv diff`: a JSON export file, or a vault (name or workspace
+/// alias) read with values unless `values` is [`DiffValues::Skip`].
+async fn load_diff_side(
+    arg: &str,
+    values: DiffValues,
+    group: Option<&str>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<Vec<crate::secret::manager::SecretProperties>> {
+    if crate::secret::diff::is_export_file(arg) {
+        return crate::secret::diff::load_export_file(std::path::Path::new(arg), group);
+    }
+
+    // Every backend resolves through the trait path now; registry==None
+    // rebuilds from config so a startup init failure surfaces as a clean error.
+    let rebuilt_registry;
+    let reg = match registry {
+        Some(r) => r,
+        None => {
+            rebuilt_registry = BackendRegistry::from_config(config)
+                .map_err(|e| CrosstacheError::config(e.to_string()))?;
+            &rebuilt_registry
+        }
+    };
+
+    // Workspace-aware: each vault argument resolves against attached aliases
+    // first, else a literal vault on the active backend (spec §Addressing) —
+    // so `xv diff work stage` can span backends, while raw names with no
+    // workspace or alias match behave exactly as before.
+    let (ws, ws_registry) = crate::cli::helpers::resolve_workspace_and_registry(config).await?;
+    let (backend, _backend_name, vault) = crate::cli::helpers::resolve_vault_ref_with_workspace(
+        arg,
+        ws.as_ref(),
+        ws_registry.as_ref(),
+        reg,
+        config,
+    )
+    .await?;
+
+    if values == DiffValues::Skip {
+        let summaries = backend.secrets().list_secrets(&vault, group).await?;
+        return Ok(summaries
+            .into_iter()
+            .filter(|s| s.original_name != crate::secret::attachments::ATTACHMENT_KEY_SECRET)
+            .map(crate::secret::diff::properties_from_summary)
+            .collect());
+    }
+
+    let (secrets, failures) = crate::cli::vault_ops::read_secrets_with_values(
+        backend.as_ref(),
+        &vault,
+        group,
+        config.fetch_concurrency,
+    )
+    .await?;
+    for (name, e) in failures {
+        output::warn(&format!("Failed to get '{name}' from {arg}: {e}"));
+    }
+    Ok(secrets)
+}
+
 pub(crate) async fn execute_secret_copy_direct(
     name: &str,
     from_vault: &str,
@@ -4731,6 +5618,84 @@ fn find_empty_message(
     }
 }
 
+/// Parse `xv find --in` values; the name is always searched.
+fn parse_find_fields(in_fields: &[String]) -> Result<Vec<crate::utils::fuzzy::FuzzyField>> {
+    use crate::utils::fuzzy::FuzzyField;
+
+    let mut fields: Vec<FuzzyField> = vec![FuzzyField::Name];
+    for raw in in_fields {
+        let parsed = match raw.to_ascii_lowercase().as_str() {
+            "name" => FuzzyField::Name,
+            "folder" => FuzzyField::Folder,
+            "groups" => FuzzyField::Groups,
+            "note" => FuzzyField::Note,
+            "tags" => FuzzyField::Tags,
+            "all" => {
+                for field in FuzzyField::ALL {
+                    if !fields.contains(&field) {
+                        fields.push(field);
+                    }
+                }
+                continue;
+            }
+            other => {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "unknown --in field: '{other}' (allowed: name, folder, groups, note, tags, all)"
+                )));
+            }
+        };
+        if !fields.contains(&parsed) {
+            fields.push(parsed);
+        }
+    }
+    Ok(fields)
+}
+
+fn compile_find_regex(pattern: &str) -> Result<regex::Regex> {
+    regex::Regex::new(pattern)
+        .map_err(|e| CrosstacheError::invalid_argument(format!("invalid regex '{pattern}': {e}")))
+}
+
+/// Match `items` against the `xv find` pattern in `mode`. Fuzzy matches
+/// are ranked and cut at `min_score` (a fraction of the top score);
+/// substring and regex matches are sorted by name.
+fn find_matches<'a>(
+    pattern: Option<&str>,
+    mode: crate::cli::commands::FindMode,
+    items: &'a [crate::utils::fuzzy::CandidateItem],
+    fields: &[crate::utils::fuzzy::FuzzyField],
+    min_score: f32,
+) -> Result<Vec<crate::utils::fuzzy::Match<'a>>> {
+    use crate::cli::commands::FindMode;
+    use crate::utils::fuzzy::{filter_matches, score_matches};
+
+    let pattern = pattern.unwrap_or("");
+    match mode {
+        FindMode::Fuzzy => {
+            let mut matches = score_matches(pattern, items, fields);
+            // Empty pattern → every score is 0; skip filtering.
+            if !pattern.is_empty() && !matches.is_empty() {
+                let top = matches[0].score as f32;
+                if top > 0.0 {
+                    let cutoff = (top * min_score).ceil() as u32;
+                    matches.retain(|m| m.score >= cutoff);
+                }
+            }
+            Ok(matches)
+        }
+        FindMode::Substring => {
+            let needle = pattern.to_lowercase();
+            Ok(filter_matches(items, fields, |value| {
+                value.to_lowercase().contains(&needle)
+            }))
+        }
+        FindMode::Regex => {
+            let regex = compile_find_regex(pattern)?;
+            Ok(filter_matches(items, fields, |value| regex.is_match(value)))
+        }
+    }
+}
+
 /// Render find matches through the shared TableFormatter: all formats work
 /// (CSV included), `--columns`/`--no-color` inherited, machine formats emit
 /// valid-empty output on stdout when nothing matched.
@@ -4778,6 +5743,7 @@ fn render_find_matches(
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn execute_secret_find_direct(
     pattern: Option<String>,
+    mode: crate::cli::commands::FindMode,
     in_fields: Vec<String>,
     limit: usize,
     min_score: f32,
@@ -4786,6 +5752,7 @@ pub(crate) async fn execute_secret_find_direct(
     names_only: bool,
     format: crate::utils::format::OutputFormat,
     filter: Option<String>,
+    no_cache: bool,
     config: Config,
     registry: Option<&crate::backend::BackendRegistry>,
 ) -> Result<()> {
@@ -4794,6 +5761,9 @@ pub(crate) async fn execute_secret_find_direct(
     if let Some(pattern) = filter.as_deref() {
         crate::utils::helpers::compile_name_glob(pattern)?;
     }
+    if let (crate::cli::commands::FindMode::Regex, Some(pattern)) = (mode, pattern.as_deref()) {
+        compile_find_regex(pattern)?;
+    }
 
     // Normalize --folder: trim trailing '/', validate, treat empty as absent.
     let folder_scope: Option<String> = match folder {
@@ -4821,26 +5791,9 @@ pub(crate) async fn execute_secret_find_direct(
     // present.
     if !all_vaults {
         if let Some(ws) = crate::workspace::resolve_configured_workspace(&config).await? {
-            use crate::utils::fuzzy::{score_matches, CandidateItem, FuzzyField};
-
-            let mut fields: Vec<FuzzyField> = vec![FuzzyField::Name];
-            for raw in &in_fields {
-                let parsed = match raw.to_ascii_lowercase().as_str() {
-                    "name" => FuzzyField::Name,
-                    "folder" => FuzzyField::Folder,
-                    "groups" => FuzzyField::Groups,
-                    "note" => FuzzyField::Note,
-                    "tags" => FuzzyField::Tags,
-                    other => {
-                        return Err(CrosstacheError::invalid_argument(format!(
-                        "unknown --in field: '{other}' (allowed: name, folder, groups, note, tags)"
-                    )));
-                    }
-                };
-                if !fields.contains(&parsed) {
-                    fields.push(parsed);
-                }
-            }
+            use crate::utils::fuzzy::CandidateItem;
+
+            let fields = parse_find_fields(&in_fields)?;
 
             let backend_names: Vec<String> = ws.entries.iter().map(|e| e.backend.clone()).collect();
             let ws_registry = crate::backend::BackendRegistry::with_lazy(&config, &backend_names)
@@ -4890,15 +5843,7 @@ pub(crate) async fn execute_secret_find_direct(
                 None => items,
             };
 
-            let pattern_str = pattern.as_deref().unwrap_or("");
-            let mut matches = score_matches(pattern_str, &items, &fields);
-            if !pattern_str.is_empty() && !matches.is_empty() {
-                let top = matches[0].score as f32;
-                if top > 0.0 {
-                    let cutoff = (top * min_score).ceil() as u32;
-                    matches.retain(|m| m.score >= cutoff);
-                }
-            }
+            let mut matches = find_matches(pattern.as_deref(), mode, &items, &fields, min_score)?;
             matches.truncate(limit);
 
             if names_only {
@@ -4932,27 +5877,9 @@ pub(crate) async fn execute_secret_find_direct(
     // ── Trait-based path (non-Azure backends) ──────────────────────────
     if use_trait_path(registry) {
         let reg = registry.expect("use_trait_path guarantees Some");
-        use crate::utils::fuzzy::{score_matches, CandidateItem, FuzzyField};
-
-        // Parse --in fields
-        let mut fields: Vec<FuzzyField> = vec![FuzzyField::Name];
-        for raw in &in_fields {
-            let parsed = match raw.to_ascii_lowercase().as_str() {
-                "name" => FuzzyField::Name,
-                "folder" => FuzzyField::Folder,
-                "groups" => FuzzyField::Groups,
-                "note" => FuzzyField::Note,
-                "tags" => FuzzyField::Tags,
-                other => {
-                    return Err(CrosstacheError::invalid_argument(format!(
-                        "unknown --in field: '{other}' (allowed: name, folder, groups, note, tags)"
-                    )));
-                }
-            };
-            if !fields.contains(&parsed) {
-                fields.push(parsed);
-            }
-        }
+        use crate::utils::fuzzy::CandidateItem;
+
+        let fields = parse_find_fields(&in_fields)?;
 
         let mut scope_vault: Option<String> = None;
         let items: Vec<CandidateItem> = if all_vaults {
@@ -4984,11 +5911,27 @@ pub(crate) async fn execute_secret_find_direct(
         } else {
             let vault_name = resolve_vault_for_trait(&config, registry).await?;
             scope_vault = Some(vault_name.clone());
-            let all_secrets = reg
-                .active()
-                .secrets()
-                .list_secrets(&vault_name, None)
-                .await?;
+            // Answer from the search index when it is fresh; otherwise fetch
+            // and cache the listing, which also rebuilds the index.
+            let cache_manager = crate::cache::CacheManager::from_config(&config);
+            let cache_key = trait_secret_cache_key(config.effective_backend_name(), &vault_name);
+            let indexed = if no_cache {
+                None
+            } else {
+                cache_manager.search_index(&cache_key)
+            };
+            let all_secrets = match indexed {
+                Some(index) => index.secrets,
+                None => {
+                    let fetched = reg
+                        .active()
+                        .secrets()
+                        .list_secrets(&vault_name, None)
+                        .await?;
+                    cache_manager.set(&cache_key, &fetched);
+                    fetched
+                }
+            };
             let all_secrets = filter_secrets_by_glob(all_secrets, filter.as_deref())?;
             all_secrets
                 .iter()
@@ -5006,16 +5949,7 @@ pub(crate) async fn execute_secret_find_direct(
             None => items,
         };
 
-        let pattern_str = pattern.as_deref().unwrap_or("");
-        let mut matches = score_matches(pattern_str, &items, &fields);
-
-        if !pattern_str.is_empty() && !matches.is_empty() {
-            let top = matches[0].score as f32;
-            if top > 0.0 {
-                let cutoff = (top * min_score).ceil() as u32;
-                matches.retain(|m| m.score >= cutoff);
-            }
-        }
+        let mut matches = find_matches(pattern.as_deref(), mode, &items, &fields, min_score)?;
         matches.truncate(limit);
 
         if names_only {
@@ -5073,6 +6007,7 @@ pub(crate) async fn execute_secret_find_direct(
         &backend,
         single_vault,
         pattern.as_deref(),
+        mode,
         in_fields,
         limit,
         min_score,
@@ -5091,6 +6026,7 @@ async fn execute_secret_find(
     backend: &std::sync::Arc<dyn crate::backend::Backend>,
     single_vault: Option<String>,
     pattern: Option<&str>,
+    mode: crate::cli::commands::FindMode,
     in_fields: Vec<String>,
     limit: usize,
     min_score: f32,
@@ -5101,27 +6037,10 @@ async fn execute_secret_find(
     filter: Option<&str>,
     config: &Config,
 ) -> Result<()> {
-    use crate::utils::fuzzy::{score_matches, CandidateItem, FuzzyField};
+    use crate::utils::fuzzy::CandidateItem;
 
     // Parse --in fields first so argument errors fire before vault resolution.
-    let mut fields: Vec<FuzzyField> = vec![FuzzyField::Name];
-    for raw in &in_fields {
-        let parsed = match raw.to_ascii_lowercase().as_str() {
-            "name" => FuzzyField::Name,
-            "folder" => FuzzyField::Folder,
-            "groups" => FuzzyField::Groups,
-            "note" => FuzzyField::Note,
-            "tags" => FuzzyField::Tags,
-            other => {
-                return Err(CrosstacheError::invalid_argument(format!(
-                    "unknown --in field: '{other}' (allowed: name, folder, groups, note, tags)"
-                )));
-            }
-        };
-        if !fields.contains(&parsed) {
-            fields.push(parsed);
-        }
-    }
+    let fields = parse_find_fields(&in_fields)?;
 
     let items: Vec<CandidateItem> = if all_vaults {
         // `--all-vaults`: every vault the active backend can list. Vault
@@ -5187,18 +6106,7 @@ async fn execute_secret_find(
         None => items,
     };
 
-    let pattern_str = pattern.unwrap_or("");
-    let mut matches = score_matches(pattern_str, &items, &fields);
-
-    // Apply min_score (relative to the top score, so 0.3 means 30% of
-    // top). Empty pattern → every score is 0; skip filtering.
-    if !pattern_str.is_empty() && !matches.is_empty() {
-        let top = matches[0].score as f32;
-        if top > 0.0 {
-            let cutoff = (top * min_score).ceil() as u32;
-            matches.retain(|m| m.score >= cutoff);
-        }
-    }
+    let mut matches = find_matches(pattern, mode, &items, &fields, min_score)?;
 
     // Apply limit.
     matches.truncate(limit);
@@ -5324,15 +6232,14 @@ async fn execute_secret_rotate(
     backend_name: &str,
     name: &str,
     vault: Option<String>,
-    length: usize,
-    charset: CharsetType,
-    custom_generator: Option<String>,
+    rotator: &dyn crate::secret::rotate::Rotator,
     show_value: bool,
     force: bool,
     config: &Config,
 ) -> Result<()> {
     use crate::config::ContextManager;
-    use crate::secret::manager::SecretRequest;
+    use crate::secret::manager::{SecretRequest, SecretUpdateRequest};
+    use crate::secret::rotate::{rotation_tags, RotationContext};
     use crate::utils::interactive::InteractivePrompt;
 
     // The vault was already resolved through the workspace seam by
@@ -5359,13 +6266,7 @@ async fn execute_secret_rotate(
 
     output::step(&format!("Rotating secret: {}", name));
 
-    // Show generation parameters
-    if let Some(ref script) = custom_generator {
-        println!("  Generator: {} (length: {})", script, length);
-    } else {
-        println!("  Character set: {:?}", charset);
-        println!("  Length: {}", length);
-    }
+    println!("  Rotator: {}", rotator.describe());
 
     // Confirm rotation unless force flag is used
     if !force {
@@ -5393,8 +6294,15 @@ async fn execute_secret_rotate(
         return Ok(());
     }
 
-    // Generate the new value
-    let new_value = generate_random_value(length, charset, custom_generator)?;
+    // Provision the new value
+    let new_value = rotator
+        .provision(&RotationContext {
+            vault: &vault_name,
+            name,
+            current_version: &existing_secret.version,
+        })
+        .await?;
+    let tags = rotation_tags(rotator, &existing_secret.version, chrono::Utc::now());
 
     // A typed record's generated value becomes the new primary field inside
     // the envelope, via the same write-back path as `xv update <name>
@@ -5422,9 +6330,37 @@ async fn execute_secret_rotate(
             backend_name,
         )
         .await?;
+        // The envelope write keeps the record's tags; stamp the rotation
+        // on top without replacing them.
+        reg.active()
+            .secrets()
+            .update_secret(
+                &vault_name,
+                name,
+                SecretUpdateRequest {
+                    name: name.to_string(),
+                    expected_revision: None,
+                    value: None,
+                    content_type: None,
+                    enabled: None,
+                    expires_on: crate::secret::manager::FieldUpdate::Unchanged,
+                    not_before: crate::secret::manager::FieldUpdate::Unchanged,
+                    tags: Some(tags),
+                    groups: None,
+                    note: crate::secret::manager::FieldUpdate::Unchanged,
+                    folder: crate::secret::manager::FieldUpdate::Unchanged,
+                    replace_tags: false,
+                    replace_groups: false,
+                },
+            )
+            .await?;
         props.version
     } else {
-        // Preserve existing secret metadata
+        // Preserve existing secret metadata, replacing any earlier rotation
+        // stamp
+        let mut existing_tags = existing_secret.tags;
+        existing_tags.remove(crate::secret::rotate::TAG_PREVIOUS);
+        existing_tags.extend(tags);
         let set_request = SecretRequest {
             name: name.to_string(),
             value: new_value.clone(),
@@ -5436,11 +6372,7 @@ async fn execute_secret_rotate(
             enabled: Some(true),
             expires_on: existing_secret.expires_on,
             not_before: existing_secret.not_before,
-            tags: if existing_secret.tags.is_empty() {
-                None
-            } else {
-                Some(existing_secret.tags)
-            },
+            tags: Some(existing_tags),
             groups: None, // Groups are managed via tags
             note: None,
             folder: None,
@@ -5458,6 +6390,9 @@ async fn execute_secret_rotate(
 
     output::success(&format!("Successfully rotated secret '{}'", name));
     println!("New version: {}", new_version);
+    if !existing_secret.version.is_empty() {
+        println!("Previous version: {}", existing_secret.version);
+    }
 
     if show_value {
         println!("Generated value: {}", new_value.as_str());
@@ -5718,6 +6653,7 @@ async fn execute_secret_run(
     reg: &BackendRegistry,
     vault: Option<String>,
     groups: Vec<String>,
+    folder: Option<String>,
     include: Vec<String>,
     exclude: Vec<String>,
     no_masking: bool,
@@ -5820,6 +6756,18 @@ async fn execute_secret_run(
     } else {
         secrets
     };
+    // --folder scopes like `xv ls <path>`: the folder itself and everything
+    // below it.
+    let folder = folder.map(|f| f.trim_matches('/').to_string());
+    let filtered_secrets: Vec<_> = match &folder {
+        Some(path) => filtered_secrets
+            .into_iter()
+            .filter(|s| {
+                crate::cli::ls_view::folder_in_scope(s.folder.as_deref().unwrap_or(""), path)
+            })
+            .collect(),
+        None => filtered_secrets,
+    };
 
     // Apply name-based --include / --exclude on top of the group filter.
     // --include restricts to the named secrets; --exclude removes them.
@@ -5840,7 +6788,7 @@ async fn execute_secret_run(
         .into_iter()
         .filter(|secret| include.is_empty() || include.iter().any(|n| name_matches(secret, n)))
         .collect();
-    let positive_selector = !groups.is_empty() || !include.is_empty();
+    let positive_selector = !groups.is_empty() || folder.is_some() || !include.is_empty();
     if selected.is_empty() && positive_selector {
         let group_source = if group_from_profile_default {
             " (from env profile default)"
@@ -5849,7 +6797,7 @@ async fn execute_secret_run(
         };
         return Err(CrosstacheError::invalid_argument(format!(
             "No secrets matched the requested selection in vault '{vault_name}' \
-             (group={groups:?}{group_source}, include={include:?}). \
+             (group={groups:?}{group_source}, folder={folder:?}, include={include:?}). \
              Refusing to run the command with nothing injected — check the values."
         )));
     }
@@ -6314,17 +7262,17 @@ async fn execute_secret_inject(
     };
 
     // Parse template for secret references
-    // Supports: {{ secret:name }}, {{ secret:name.field }}, and
+    // Supports: {{ secret:name }}, {{ secret:name.field }} (parsed by
+    // `crate::template`, shared with `xv render`), and
     // xv://[backend:]vault/secret[#field]. The `.field` split (on the LAST
     // dot) is resolved later, after the vault's secrets are loaded: an exact
     // name match always wins first, so an untyped secret literally named
     // `a.b` keeps resolving as itself (record-types plan Task 12). The `#`
     // fragment is unambiguous up front since `#` is invalid in secret names
     // on every backend.
-    let secret_regex = Regex::new(r"\{\{\s*secret:([^}\s]+)\s*\}\}").unwrap();
     let uri_regex = Regex::new(r"xv://([^/\s]+)/([^/\s#]+)(?:#([A-Za-z0-9._-]+))?").unwrap();
 
-    let mut required_secrets: Vec<String> = Vec::new();
+    let required_secrets = crate::template::placeholders(&template_content);
     // (original_uri, parsed_ref, optional #field)
     let mut cross_vault_refs: Vec<(String, BackendRef, Option<String>)> = Vec::new();
 
@@ -6342,16 +7290,6 @@ async fn execute_secret_inject(
     // treated as a failure too, not silently skipped.
     let mut fetch_failures: Vec<String> = Vec::new();
 
-    // Find {{ secret:name }} references (current vault)
-    for captures in secret_regex.captures_iter(&template_content) {
-        if let Some(secret_name) = captures.get(1) {
-            let name = secret_name.as_str().to_string();
-            if !required_secrets.contains(&name) {
-                required_secrets.push(name);
-            }
-        }
-    }
-
     // Find xv://[backend:]vault/secret[#field] URI references
     for captures in uri_regex.captures_iter(&template_content) {
         let vault_part = captures.get(1).map_or("", |m| m.as_str());
@@ -6689,17 +7627,12 @@ async fn execute_secret_inject(
         total_injected
     ));
 
-    // Replace secret references with actual values
-    let mut result_content = Zeroizing::new(template_content);
-
-    // Replace {{ secret:name }} references (current vault)
-    for (secret_name, secret_value) in &secret_values {
-        let pattern = format!(r"\{{\{{\s*secret:{}\s*\}}\}}", regex::escape(secret_name));
-        let regex_pattern = Regex::new(&pattern).unwrap();
-        *result_content = regex_pattern
-            .replace_all(&result_content, secret_value.as_str())
-            .to_string();
-    }
+    // Replace {{ secret:name }} references (current vault); unresolved ones
+    // (only with --best-effort) stay as written.
+    let mut result_content = crate::template::render(&template_content, |name| {
+        secret_values.get(name).map(|value| value.as_str())
+    })
+    .output;
 
     // Replace xv://vault/secret URI references. Longest-key-first: a bare
     // `xv://vault/name` is a strict prefix of its own `#field` form (and,
@@ -7059,9 +7992,7 @@ async fn execute_secret_share(
             use crate::utils::pagination::{paginate_slice, pagination_footer_text, Pagination};
             use std::fmt::Write as _;
 
-            let pager = pager
-                .map(crate::cli::commands::PagerWhen::wants_pager)
-                .unwrap_or(false);
+            let pager = crate::cli::commands::PagerWhen::resolve(pager, config);
             let mut roles = vault_backend
                 .list_secret_access(vault_name, &secret_name)
                 .await?;
@@ -7456,6 +8387,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn expires_cell_is_empty_without_expiry_and_dated_with_one() {
+        let mut secret = summary_with_groups(None);
+        assert_eq!(expires_cell(&secret), "");
+
+        secret.expires_on = Some(chrono::Utc::now() + chrono::Duration::days(45));
+        let cell = expires_cell(&secret);
+        assert!(cell.starts_with(&secret.expires_on.unwrap().format("%Y-%m-%d").to_string()));
+        assert!(cell.contains("expires in"), "{cell}");
+    }
+
     #[tokio::test]
     async fn azure_trait_vault_resolution_does_not_fallback_to_default() {
         // #342: `resolve_vault_for_trait` -> `Config::resolve_vault_name`
@@ -7743,7 +8685,7 @@ mod tests {
                 summary_named("ungrouped", None, true),
             ],
             Some("prod"),
-            false,
+            EnabledFilter::EnabledOnly,
         );
 
         let names: Vec<_> = candidates.into_iter().map(|s| s.name).collect();
@@ -7751,29 +8693,28 @@ mod tests {
     }
 
     #[test]
-    fn trait_secret_cache_key_and_invalidation_use_same_resolved_vault_name() {
-        let key = trait_secret_cache_key("local", "local-vault");
-        assert_eq!(key.to_string(), "secrets:local:local-vault");
+    fn disabled_only_filter_keeps_just_disabled_secrets() {
+        let candidates = filter_secret_summaries_for_display(
+            vec![
+                summary_named("on", None, true),
+                summary_named("off", None, false),
+            ],
+            None,
+            EnabledFilter::DisabledOnly,
+        );
+        let names: Vec<_> = candidates.into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["off"]);
+        assert_eq!(disabled_hidden_suffix(0), "");
+        assert_eq!(
+            disabled_hidden_suffix(3),
+            " (3 disabled hidden; use --all to show)"
+        );
     }
 
     #[test]
-    fn test_secret_summary_group_filter_is_exact_comma_separated_match() {
-        assert!(secret_summary_matches_group(
-            &summary_with_groups(Some("prod, infra")),
-            "prod"
-        ));
-        assert!(secret_summary_matches_group(
-            &summary_with_groups(Some("prod, infra")),
-            "infra"
-        ));
-        assert!(!secret_summary_matches_group(
-            &summary_with_groups(Some("production")),
-            "prod"
-        ));
-        assert!(!secret_summary_matches_group(
-            &summary_with_groups(None),
-            "prod"
-        ));
+    fn trait_secret_cache_key_and_invalidation_use_same_resolved_vault_name() {
+        let key = trait_secret_cache_key("local", "local-vault");
+        assert_eq!(key.to_string(), "secrets:local:local-vault");
     }
 
     #[test]
@@ -8080,6 +9021,7 @@ mod tests {
             not_before: None,
             tags: std::collections::HashMap::new(),
             content_type: String::new(),
+            id: None,
             recovery_level: None,
         }
     }
@@ -8420,6 +9362,26 @@ mod tests {
         assert!(!message.contains("auto-clears"), "{message}");
     }
 
+    #[test]
+    fn mask_value_shows_only_the_tail_of_long_values() {
+        assert_eq!(mask_value("correct-horse-battery"), "********tery");
+        assert_eq!(mask_value("hunter2"), "*******");
+        assert_eq!(mask_value("a-short-password"), "********word");
+        assert_eq!(mask_value("fifteen-chars!!"), "********");
+        assert_eq!(mask_value(""), "*");
+    }
+
+    #[test]
+    fn days_until_purge_reads_both_backend_date_formats() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-08-20T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(days_until_purge("2024-09-01 12:00:00 UTC", now), Some(12));
+        assert_eq!(days_until_purge("2024-09-01T12:00:00Z", now), Some(12));
+        assert_eq!(days_until_purge("2024-08-01T00:00:00Z", now), Some(0));
+        assert_eq!(days_until_purge("soon", now), None);
+    }
+
     // ── Multi-vault workspaces plan (Phase B) union `ls`/`ls --deleted` ──
 
     fn workspace_secret(name: &str, alias: &str) -> crate::secret::manager::SecretSummary {
@@ -8484,6 +9446,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deleted_bulk_scope_matches_group_and_folder_subtree() {
+        use crate::secret::manager::DeletedSecretSummary;
+
+        let deleted = DeletedSecretSummary {
+            name: "db".to_string(),
+            original_name: "db".to_string(),
+            deleted_on: None,
+            scheduled_purge_on: None,
+            recovery_level: None,
+            tags: [
+                ("groups".to_string(), "app, infra".to_string()),
+                ("folder".to_string(), "prod/db".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        assert!(deleted_in_scope(&deleted, None, None));
+        assert!(deleted_in_scope(&deleted, Some("infra"), Some("prod")));
+        assert!(deleted_in_scope(&deleted, None, Some("prod/db")));
+        assert!(!deleted_in_scope(&deleted, Some("ap"), None));
+        assert!(!deleted_in_scope(&deleted, None, Some("pro")));
+    }
+
     /// Bugbot review MEDIUM: `execute_deleted_secret_list_workspace` must
     /// filter BARE per-vault names before applying the `alias/` display
     /// prefix, mirroring the live union `ls` path's ordering. This is
@@ -8507,6 +9493,8 @@ mod tests {
             original_name: "PROD_ALPHA".to_string(), // user-facing: matches "PROD_*"
             deleted_on: None,
             scheduled_purge_on: None,
+            recovery_level: None,
+            tags: Default::default(),
         };
 
         // CORRECT order (this function's fix): filter bare names first,
@@ -8560,11 +9548,62 @@ mod tests {
             summary("normal"),
             summary(crate::secret::attachments::ATTACHMENT_KEY_SECRET),
         ];
-        let out = filter_secret_summaries_for_display(secrets, None, true);
+        let out = filter_secret_summaries_for_display(secrets, None, EnabledFilter::All);
         assert_eq!(out.len(), 1);
         assert_eq!(out[0].name, "normal");
     }
 
+    #[test]
+    fn tag_query_requires_every_condition() {
+        fn tagged(name: &str, tags: &[(&str, &str)]) -> crate::secret::manager::SecretSummary {
+            crate::secret::manager::SecretSummary {
+                name: name.to_string(),
+                original_name: name.to_string(),
+                note: None,
+                folder: None,
+                groups: None,
+                updated_on: String::new(),
+                enabled: true,
+                expires_on: None,
+                content_type: String::new(),
+                tags: tags
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            }
+        }
+        let secrets = vec![
+            tagged("a", &[("owner", "data"), ("env", "prod")]),
+            tagged("b", &[("owner", "data"), ("env", "dev")]),
+            tagged("c", &[("owner", "web"), ("env", "prod"), ("cost", "")]),
+        ];
+        let names = |query: TagQuery| -> Vec<String> {
+            query
+                .filter(secrets.clone())
+                .into_iter()
+                .map(|s| s.name)
+                .collect()
+        };
+        assert_eq!(names(TagQuery::default()), vec!["a", "b", "c"]);
+        assert_eq!(
+            names(TagQuery {
+                equals: vec![
+                    ("owner".into(), "data".into()),
+                    ("env".into(), "prod".into())
+                ],
+                exists: vec![],
+            }),
+            vec!["a"]
+        );
+        assert_eq!(
+            names(TagQuery {
+                equals: vec![],
+                exists: vec!["cost".into()],
+            }),
+            vec!["c"]
+        );
+    }
+
     #[test]
     fn bulk_set_refuses_reserved_key_name() {
         assert!(is_reserved_attachment_key(
@@ -8610,4 +9649,37 @@ mod tests {
             "{err}"
         );
     }
+
+    #[test]
+    fn history_marks_the_newest_or_aws_current_version() {
+        let version = |number: u32, stages: Option<&str>| {
+            let mut props = fake_secret_properties("api-key");
+            props.version_number = Some(number);
+            props.created_timestamp = i64::from(number);
+            if let Some(stages) = stages {
+                props
+                    .tags
+                    .insert("aws:stages".to_string(), stages.to_string());
+            }
+            props
+        };
+        // Azure lists newest first, local oldest first: the newest wins.
+        assert_eq!(
+            current_version_index(&[version(3, None), version(2, None), version(1, None)]),
+            Some(0)
+        );
+        assert_eq!(
+            current_version_index(&[version(1, None), version(2, None)]),
+            Some(1)
+        );
+        // After an AWS rollback the label, not the order, says which is current.
+        assert_eq!(
+            current_version_index(&[
+                version(1, Some("AWSCURRENT")),
+                version(2, Some("AWSPREVIOUS")),
+            ]),
+            Some(0)
+        );
+        assert_eq!(current_version_index(&[]), None);
+    }
 }
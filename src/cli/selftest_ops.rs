@@ -0,0 +1,527 @@
+//! CLI executor for `xv selftest`.
+//!
+//! Provisions a throwaway vault (and, on Azure, a scratch blob container),
+//! runs a fixed matrix of secret and file operations against it through the
+//! backend traits, and reports each step. With `--cleanup` the vault and
+//! container are removed afterwards, whatever the outcome.
+
+use std::future::Future;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use zeroize::Zeroizing;
+
+use crate::backend::{Backend, BackendError, BackendKind, BackendRegistry};
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::{FieldUpdate, SecretRequest, SecretUpdateRequest};
+use crate::utils::format::OutputFormat;
+use crate::utils::output;
+use crate::utils::report::{ItemStatus, ReportRecorder};
+use crate::vault::models::VaultCreateRequest;
+
+/// Attempts for a call that hits a resource the service is still settling:
+/// Azure finishes soft deletes, restores and new-vault DNS asynchronously.
+const SETTLE_ATTEMPTS: usize = 15;
+const SETTLE_DELAY: Duration = Duration::from_secs(2);
+
+const SECRET: &str = "selftest-secret";
+const RENAMED: &str = "selftest-renamed";
+const FILE: &str = "selftest/hello.txt";
+
+/// Why a step did not pass.
+enum StepFailure {
+    /// The backend does not offer the operation; the step is skipped.
+    Unsupported(String),
+    Failed(String),
+}
+
+impl From<BackendError> for StepFailure {
+    fn from(e: BackendError) -> Self {
+        match e {
+            BackendError::Unsupported(what) => Self::Unsupported(format!("unsupported: {what}")),
+            other => Self::Failed(other.to_string()),
+        }
+    }
+}
+
+type Step<T> = std::result::Result<T, StepFailure>;
+
+fn ensure(condition: bool, message: impl FnOnce() -> String) -> Step<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(StepFailure::Failed(message()))
+    }
+}
+
+/// Retry `op` while the service reports the resource as busy or not there
+/// yet.
+async fn settled<T, F, Fut>(mut op: F) -> std::result::Result<T, BackendError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, BackendError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(
+                BackendError::Conflict(_)
+                | BackendError::NotFound { .. }
+                | BackendError::VaultNotFound { .. }
+                | BackendError::Network(_),
+            ) if attempt < SETTLE_ATTEMPTS => {
+                attempt += 1;
+                tokio::time::sleep(SETTLE_DELAY).await;
+            }
+            outcome => return outcome,
+        }
+    }
+}
+
+/// Records and prints step outcomes.
+struct Run {
+    recorder: ReportRecorder,
+    quiet: bool,
+}
+
+impl Run {
+    /// Run one step. Returns its value when it passed.
+    async fn step<T>(&mut self, name: &str, step: impl Future<Output = Step<T>>) -> Option<T> {
+        let started = Instant::now();
+        let outcome = step.await;
+        let elapsed = started.elapsed();
+        let (status, detail, value) = match outcome {
+            Ok(value) => (ItemStatus::Succeeded, None, Some(value)),
+            Err(StepFailure::Unsupported(why)) => (ItemStatus::Skipped, Some(why), None),
+            Err(StepFailure::Failed(why)) => (ItemStatus::Failed, Some(why), None),
+        };
+        if !self.quiet {
+            let line = match &detail {
+                Some(detail) => format!("{name}: {detail}"),
+                None => format!("{name} ({} ms)", elapsed.as_millis()),
+            };
+            match status {
+                ItemStatus::Succeeded => output::success(&line),
+                ItemStatus::Skipped => output::info(&format!("Skipped {line}")),
+                ItemStatus::Failed => output::error(&line),
+            }
+        }
+        self.recorder.record(name, status, elapsed, detail);
+        value
+    }
+
+    fn skip(&mut self, name: &str, why: &str) {
+        if !self.quiet {
+            output::info(&format!("Skipped {name}: {why}"));
+        }
+        self.recorder
+            .record(name, ItemStatus::Skipped, Duration::ZERO, Some(why.into()));
+    }
+}
+
+/// Provision a temporary vault, exercise set/get/list/update/rename/delete/
+/// restore/purge and file upload/download against it, and report the
+/// result. Fails when any step failed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn execute_selftest_command(
+    resource_group: Option<String>,
+    location: Option<String>,
+    cleanup: bool,
+    skip_files: bool,
+    report: Option<String>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
+    let is_azure = backend.kind() == BackendKind::Azure;
+    let resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
+    if is_azure && resource_group.is_empty() {
+        return Err(CrosstacheError::invalid_argument(
+            "xv selftest needs a resource group for the temporary vault: pass --resource-group \
+             or set default_resource_group",
+        ));
+    }
+    let location = location.unwrap_or_else(|| config.default_location.clone());
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    let vault = format!("xv-selftest-{}", &id[..8]);
+
+    let quiet = config.format_explicit
+        && matches!(
+            config.runtime_output_format,
+            OutputFormat::Json | OutputFormat::Yaml
+        );
+    let mut run = Run {
+        recorder: ReportRecorder::new("selftest")
+            .with_endpoints(None, Some(format!("{}:{vault}", backend.name()))),
+        quiet,
+    };
+    if !quiet {
+        output::step(&format!(
+            "Running self-test against temporary vault '{vault}' on the {} backend...\n",
+            backend.name()
+        ));
+    }
+
+    let created = run
+        .step(
+            "create vault",
+            create_vault(backend.as_ref(), &vault, &resource_group, &location),
+        )
+        .await;
+    let outcome = match created {
+        Some(vault_location) => {
+            secret_matrix(&mut run, backend.as_ref(), &vault).await;
+            #[cfg(feature = "file-ops")]
+            {
+                if skip_files {
+                    run.skip("file operations", "--skip-files");
+                } else {
+                    file_matrix(
+                        &mut run,
+                        backend.as_ref(),
+                        &vault,
+                        &id[..8],
+                        cleanup,
+                        config,
+                        registry,
+                    )
+                    .await;
+                }
+            }
+            #[cfg(not(feature = "file-ops"))]
+            let _ = skip_files;
+
+            if cleanup {
+                teardown_vault(
+                    &mut run,
+                    backend.as_ref(),
+                    &vault,
+                    &resource_group,
+                    vault_location,
+                )
+                .await;
+            } else if !quiet {
+                let rg = if is_azure {
+                    format!(" --resource-group {resource_group}")
+                } else {
+                    String::new()
+                };
+                output::hint(&format!(
+                    "Temporary vault '{vault}' was kept; remove it with \
+                     'xv vault delete {vault}{rg} --force' or rerun with --cleanup"
+                ));
+            }
+            summarize(&run)
+        }
+        None => Err(CrosstacheError::unknown(format!(
+            "xv selftest could not create temporary vault '{vault}'; nothing was tested"
+        ))),
+    };
+
+    if quiet {
+        let transcript = run.recorder.finish(outcome.as_ref().err());
+        let rendered = if config.runtime_output_format == OutputFormat::Json {
+            serde_json::to_string_pretty(&transcript)?
+        } else {
+            serde_yaml::to_string(&transcript)?
+        };
+        println!("{rendered}");
+    }
+    match report {
+        Some(path) => run.recorder.write_for(Path::new(&path), outcome),
+        None => outcome,
+    }
+}
+
+/// Create the vault; `Some(location)` for teardown on success. Backends
+/// whose vaults are implicit namespaces skip creation.
+async fn create_vault(
+    backend: &dyn Backend,
+    vault: &str,
+    resource_group: &str,
+    location: &str,
+) -> Step<Option<String>> {
+    let Some(vaults) = backend.vaults() else {
+        return Ok(None);
+    };
+    let request = VaultCreateRequest {
+        name: vault.to_string(),
+        location: location.to_string(),
+        resource_group: resource_group.to_string(),
+        soft_delete_retention_in_days: Some(7),
+        purge_protection: Some(false),
+        tags: Some(std::collections::HashMap::from([(
+            "created_by".to_string(),
+            "crosstache-selftest".to_string(),
+        )])),
+        ..VaultCreateRequest::default()
+    };
+    match vaults.create_vault(request).await {
+        Ok(created) => Ok(Some(created.location)),
+        Err(BackendError::Unsupported(_)) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn secret_matrix(run: &mut Run, backend: &dyn Backend, vault: &str) {
+    let secrets = backend.secrets();
+    let capabilities = backend.capabilities();
+    let value = Zeroizing::new(format!("selftest-{}", uuid::Uuid::new_v4()));
+    let updated = Zeroizing::new(format!("updated-{}", uuid::Uuid::new_v4()));
+
+    let set = run
+        .step("set secret", async {
+            let request = SecretRequest {
+                name: SECRET.to_string(),
+                value: value.clone(),
+                content_type: None,
+                enabled: None,
+                expires_on: None,
+                not_before: None,
+                tags: None,
+                groups: Some(vec!["selftest".to_string()]),
+                note: Some("xv selftest".to_string()),
+                folder: None,
+            };
+            settled(|| secrets.set_secret(vault, request.clone())).await?;
+            Ok(())
+        })
+        .await;
+    if set.is_none() {
+        return;
+    }
+
+    run.step("get secret", async {
+        let got = settled(|| secrets.get_secret(vault, SECRET, true)).await?;
+        ensure(
+            got.value.as_deref().map(String::as_str) == Some(value.as_str()),
+            || "read back a different value".to_string(),
+        )
+    })
+    .await;
+
+    run.step("list secrets", async {
+        let listed = secrets.list_secrets(vault, None).await?;
+        ensure(
+            listed
+                .iter()
+                .any(|s| s.original_name == SECRET || s.name == SECRET),
+            || format!("'{SECRET}' missing from the listing"),
+        )
+    })
+    .await;
+
+    run.step("update secret", async {
+        let request = SecretUpdateRequest {
+            name: SECRET.to_string(),
+            expected_revision: None,
+            value: Some(updated.clone()),
+            content_type: None,
+            enabled: None,
+            expires_on: FieldUpdate::Unchanged,
+            not_before: FieldUpdate::Unchanged,
+            tags: None,
+            groups: None,
+            note: FieldUpdate::Set("xv selftest (updated)".to_string()),
+            folder: FieldUpdate::Unchanged,
+            replace_tags: false,
+            replace_groups: false,
+        };
+        secrets.update_secret(vault, SECRET, request).await?;
+        let got = settled(|| secrets.get_secret(vault, SECRET, true)).await?;
+        ensure(
+            got.value.as_deref().map(String::as_str) == Some(updated.as_str()),
+            || "value unchanged after update".to_string(),
+        )
+    })
+    .await;
+
+    let renamed = run
+        .step("rename secret", async {
+            secrets.rename_secret(vault, SECRET, RENAMED).await?;
+            let got = settled(|| secrets.get_secret(vault, RENAMED, true)).await?;
+            ensure(
+                got.value.as_deref().map(String::as_str) == Some(updated.as_str()),
+                || "renamed secret has a different value".to_string(),
+            )
+        })
+        .await;
+    let current = if renamed.is_some() { RENAMED } else { SECRET };
+
+    let deleted = run
+        .step("delete secret", async {
+            secrets.delete_secret(vault, current).await?;
+            Ok(())
+        })
+        .await;
+    if deleted.is_none() {
+        return;
+    }
+
+    if capabilities.has_restore {
+        run.step("restore secret", async {
+            settled(|| secrets.restore_secret(vault, current)).await?;
+            let got = settled(|| secrets.get_secret(vault, current, true)).await?;
+            ensure(
+                got.value.as_deref().map(String::as_str) == Some(updated.as_str()),
+                || "restored secret has a different value".to_string(),
+            )
+        })
+        .await;
+    } else {
+        run.skip("restore secret", "the backend has no soft delete");
+    }
+
+    if capabilities.has_purge {
+        run.step("purge secret", async {
+            if secrets.secret_exists(vault, current).await? {
+                secrets.delete_secret(vault, current).await?;
+            }
+            settled(|| secrets.purge_secret(vault, current)).await?;
+            Ok(())
+        })
+        .await;
+    } else {
+        run.skip("purge secret", "the backend deletes permanently");
+    }
+}
+
+/// Upload, download and delete a file. On Azure this runs in a scratch
+/// container of the configured storage account, so the shared container
+/// is never touched.
+#[cfg(feature = "file-ops")]
+async fn file_matrix(
+    run: &mut Run,
+    backend: &dyn Backend,
+    vault: &str,
+    id: &str,
+    cleanup: bool,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) {
+    use crate::backend::file::FileBackend;
+
+    let container = format!("xv-selftest-{id}");
+    let mut scratch = None;
+    if backend.kind() == BackendKind::Azure && backend.files().is_some() {
+        scratch = run
+            .step("create container", async {
+                let auth = match registry.and_then(|r| r.azure_auth_provider()) {
+                    Some(auth) => auth,
+                    None => BackendRegistry::create_azure_auth_provider(config)?,
+                };
+                let files =
+                    crate::backend::azure::file_backend_for_container(config, auth, &container)?;
+                files.create_container().await?;
+                Ok(files)
+            })
+            .await;
+        if scratch.is_none() {
+            return;
+        }
+    }
+    let files: &dyn FileBackend = match (&scratch, backend.files()) {
+        (Some(scratch), _) => scratch,
+        (None, Some(files)) => files,
+        (None, None) => {
+            run.skip("file operations", "no file storage is configured");
+            return;
+        }
+    };
+
+    let content = format!("xv selftest {id}\n").into_bytes();
+    let uploaded = run
+        .step("file upload", async {
+            let request = crate::blob::models::FileUploadRequest {
+                name: FILE.to_string(),
+                content: content.clone(),
+                content_type: Some("text/plain".to_string()),
+                groups: vec!["selftest".to_string()],
+                metadata: std::collections::HashMap::new(),
+                tags: std::collections::HashMap::new(),
+            };
+            files.upload_file(vault, request, None).await?;
+            Ok(())
+        })
+        .await;
+    if uploaded.is_some() {
+        run.step("file download", async {
+            let downloaded = files.download_file(vault, FILE, None).await?;
+            ensure(downloaded == content, || {
+                "downloaded content differs from the upload".to_string()
+            })
+        })
+        .await;
+        run.step("file delete", async {
+            files.delete_file(vault, FILE).await?;
+            Ok(())
+        })
+        .await;
+    }
+
+    if let Some(scratch) = &scratch {
+        if cleanup {
+            run.step("delete container", async {
+                scratch.delete_container().await?;
+                Ok(())
+            })
+            .await;
+        } else if !run.quiet {
+            output::hint(&format!("Scratch container '{container}' was kept"));
+        }
+    }
+}
+
+async fn teardown_vault(
+    run: &mut Run,
+    backend: &dyn Backend,
+    vault: &str,
+    resource_group: &str,
+    location: Option<String>,
+) {
+    let Some(vaults) = backend.vaults() else {
+        return;
+    };
+    let Some(location) = location else {
+        return;
+    };
+    let deleted = run
+        .step("delete vault", async {
+            vaults.delete_vault(vault, Some(resource_group)).await?;
+            Ok(())
+        })
+        .await;
+    if deleted.is_some() {
+        run.step("purge vault", async {
+            settled(|| vaults.purge_vault(vault, Some(&location))).await?;
+            Ok(())
+        })
+        .await;
+    }
+}
+
+fn summarize(run: &Run) -> Result<()> {
+    let summary = run.recorder.finish(None).summary;
+    let line = format!(
+        "{} passed, {} skipped, {} failed in {:.1}s",
+        summary.succeeded,
+        summary.skipped,
+        summary.failed,
+        run.recorder.elapsed().as_secs_f64()
+    );
+    if summary.failed > 0 {
+        if !run.quiet {
+            output::warn(&line);
+        }
+        Err(CrosstacheError::unknown(format!(
+            "xv selftest: {} step(s) failed",
+            summary.failed
+        )))
+    } else {
+        if !run.quiet {
+            output::success(&line);
+        }
+        Ok(())
+    }
+}
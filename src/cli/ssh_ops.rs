@@ -0,0 +1,219 @@
+//! CLI executors for `xv ssh keygen` / `xv ssh load`.
+//!
+//! Key generation and agent loading are delegated to OpenSSH's `ssh-keygen`
+//! and `ssh-add`. `keygen` has `ssh-keygen` write the pair into a private
+//! scratch directory (RAM-backed `/dev/shm` where available), stores the
+//! private key, and zeroes the files. `load` pipes the key to `ssh-add -`,
+//! so it is never written to disk.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use zeroize::Zeroizing;
+
+use crate::backend::BackendError;
+use crate::cli::commands::SshCommands;
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::{FieldUpdate, SecretRequest, SecretUpdateRequest};
+use crate::utils::output;
+
+/// Content type of a secret holding an OpenSSH private key.
+const SSH_KEY_CONTENT_TYPE: &str = "application/x-openssh-private-key";
+/// Tag carrying the key's public half, so it can be read without the value.
+const PUBLIC_KEY_TAG: &str = "xv-ssh-public-key";
+
+pub(crate) async fn execute_ssh_command(command: SshCommands, config: &Config) -> Result<()> {
+    match command {
+        SshCommands::Keygen {
+            name,
+            comment,
+            force,
+        } => execute_ssh_keygen(&name, comment, force, config).await,
+        SshCommands::Load { name, lifetime } => execute_ssh_load(&name, lifetime, config).await,
+    }
+}
+
+/// Generate an ed25519 key pair with `ssh-keygen`, returning the private
+/// key and the public key line.
+fn generate_ed25519(comment: &str) -> Result<(Zeroizing<String>, String)> {
+    let dir = tempfile::Builder::new()
+        .prefix("xv-ssh-")
+        .tempdir_in(crate::cli::edit_ops::scratch_dir())?;
+    let key_path = dir.path().join("id_ed25519");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-C", comment, "-f"])
+        .arg(&key_path)
+        .stdin(Stdio::null())
+        .status()
+        .map_err(|e| {
+            CrosstacheError::config(format!(
+                "Failed to run ssh-keygen: {e}. Install OpenSSH to use 'xv ssh keygen'."
+            ))
+        })?;
+    let private = std::fs::read_to_string(&key_path).map(Zeroizing::new);
+    if key_path.exists() {
+        if let Err(e) = crate::cli::edit_ops::shred(&key_path) {
+            output::warn(&format!(
+                "Could not overwrite temp file {}: {e}",
+                key_path.display()
+            ));
+        }
+    }
+    if !status.success() {
+        return Err(CrosstacheError::config(format!(
+            "ssh-keygen exited with {status}"
+        )));
+    }
+    let private = private?;
+    let public = std::fs::read_to_string(key_path.with_extension("pub"))?
+        .trim()
+        .to_string();
+    Ok((private, public))
+}
+
+/// Create (or with `force`, replace) secret `name` holding a new ed25519
+/// private key, and print the public key.
+async fn execute_ssh_keygen(
+    name: &str,
+    comment: Option<String>,
+    force: bool,
+    config: &Config,
+) -> Result<()> {
+    let (backend, backend_name, vault_name, name) =
+        crate::cli::helpers::resolve_workspace_or_default(
+            name,
+            config,
+            crate::workspace::TargetMode::Write,
+        )
+        .await?;
+    let name = name.as_str();
+    let secrets = backend.secrets();
+    let exists = match secrets.get_secret(&vault_name, name, false).await {
+        Ok(_) => true,
+        Err(BackendError::NotFound { .. }) => false,
+        Err(e) => return Err(e.into()),
+    };
+    if exists && !force {
+        return Err(CrosstacheError::conflict(format!(
+            "secret '{name}' already exists; pass --force to replace it with a new key"
+        )));
+    }
+
+    let comment = comment.unwrap_or_else(|| format!("{name}@xv"));
+    let (private_key, public_key) = generate_ed25519(&comment)?;
+    let tags = HashMap::from([(PUBLIC_KEY_TAG.to_string(), public_key.clone())]);
+    if exists {
+        secrets
+            .update_secret(
+                &vault_name,
+                name,
+                SecretUpdateRequest {
+                    name: name.to_string(),
+                    expected_revision: None,
+                    value: Some(private_key),
+                    content_type: Some(SSH_KEY_CONTENT_TYPE.to_string()),
+                    enabled: None,
+                    expires_on: FieldUpdate::Unchanged,
+                    not_before: FieldUpdate::Unchanged,
+                    tags: Some(tags),
+                    groups: None,
+                    note: FieldUpdate::Unchanged,
+                    folder: FieldUpdate::Unchanged,
+                    replace_tags: false,
+                    replace_groups: false,
+                },
+            )
+            .await?;
+    } else {
+        secrets
+            .set_secret(
+                &vault_name,
+                SecretRequest {
+                    name: name.to_string(),
+                    value: private_key,
+                    content_type: Some(SSH_KEY_CONTENT_TYPE.to_string()),
+                    enabled: None,
+                    expires_on: None,
+                    not_before: None,
+                    tags: Some(tags),
+                    groups: None,
+                    note: None,
+                    folder: None,
+                },
+            )
+            .await?;
+    }
+    crate::cli::secret_ops::invalidate_trait_secret_cache(config, &backend_name, &vault_name);
+
+    output::success(&format!(
+        "Stored a new ed25519 private key in '{name}'; public key:"
+    ));
+    println!("{public_key}");
+    Ok(())
+}
+
+/// Add the private key in secret `name` to the running ssh-agent.
+async fn execute_ssh_load(name: &str, lifetime: Option<String>, config: &Config) -> Result<()> {
+    if std::env::var_os("SSH_AUTH_SOCK").is_none() {
+        return Err(CrosstacheError::config(
+            "No ssh-agent found (SSH_AUTH_SOCK is not set). Start one with 'eval \"$(ssh-agent)\"'.",
+        ));
+    }
+    let (backend, _backend_name, vault_name, name) =
+        crate::cli::helpers::resolve_workspace_or_default(
+            name,
+            config,
+            crate::workspace::TargetMode::Read,
+        )
+        .await?;
+    let name = name.as_str();
+    let secret = backend
+        .secrets()
+        .get_secret(&vault_name, name, true)
+        .await?;
+    let mut key = secret.value.unwrap_or_default();
+    if !key.contains("PRIVATE KEY-----") {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "secret '{name}' does not hold an OpenSSH private key"
+        )));
+    }
+    if !key.ends_with('\n') {
+        // ssh-add rejects a key whose last line is unterminated.
+        key.push('\n');
+    }
+
+    let mut command = Command::new("ssh-add");
+    if let Some(lifetime) = &lifetime {
+        command.arg("-t").arg(lifetime);
+    }
+    let mut child = command
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            CrosstacheError::config(format!(
+                "Failed to run ssh-add: {e}. Install OpenSSH to use 'xv ssh load'."
+            ))
+        })?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(key.as_bytes())?;
+    }
+    let result = child.wait_with_output()?;
+    if !result.status.success() {
+        return Err(CrosstacheError::config(format!(
+            "ssh-add failed ({}): {}",
+            result.status,
+            String::from_utf8_lossy(&result.stderr).trim()
+        )));
+    }
+
+    match lifetime {
+        Some(lifetime) => output::success(&format!("Added '{name}' to ssh-agent for {lifetime}")),
+        None => output::success(&format!("Added '{name}' to ssh-agent")),
+    }
+    Ok(())
+}
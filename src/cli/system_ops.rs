@@ -2,6 +2,7 @@
 //!
 //! Extracted from `commands.rs` — pure mechanical move, no logic changes.
 
+use crate::backend::azure::arm::ArmClient;
 use crate::cli::helpers::{
     copy_to_clipboard, extract_claims_from_token, generate_random_value, schedule_clipboard_clear,
 };
@@ -14,7 +15,7 @@ use clap_complete::Shell;
 use super::commands::{CharsetType, Cli, ResourceType};
 
 /// One audit event as rendered by every output format. Machine formats emit
-/// exactly these five fields (the pre-unification `--raw` per-entry documents
+/// exactly these six fields (the pre-unification `--raw` per-entry documents
 /// with `---` separators are gone — changelog-documented breaking change).
 #[derive(tabled::Tabled, serde::Serialize)]
 struct AuditRow {
@@ -28,6 +29,12 @@ struct AuditRow {
     caller: String,
     #[tabled(rename = "Status")]
     status: String,
+    #[tabled(rename = "Correlation ID", display_with = "display_correlation_id")]
+    correlation_id: Option<String>,
+}
+
+fn display_correlation_id(id: &Option<String>) -> String {
+    id.clone().unwrap_or_else(|| "-".to_string())
 }
 
 /// Render audit rows through the shared TableFormatter: global `--format`
@@ -184,35 +191,53 @@ async fn execute_backend_audit(
             resource: event.resource_name.clone(),
             caller: event.caller.clone(),
             status: event.status.clone(),
+            correlation_id: event.correlation_id.clone(),
         })
         .collect();
 
     render_audit_rows(&rows, config)
 }
 
-pub(crate) async fn execute_init_command(_config: Config) -> Result<()> {
+/// `xv init`: the interactive setup, or with `unattended` the flag-driven
+/// Azure setup that never prompts (an existing config needs `--yes`).
+pub(crate) async fn execute_init_command(
+    unattended: Option<crate::config::init::UnattendedInit>,
+    yes: bool,
+    _config: Config,
+) -> Result<()> {
     use crate::config::init::ConfigInitializer;
     use crate::config::settings::Config as SettingsConfig;
 
     // Warn if config already exists
     if let Ok(config_path) = SettingsConfig::get_config_path() {
-        if config_path.exists() {
+        if config_path.exists() && !yes {
             output::warn(&format!(
                 "Configuration already exists at {}",
                 config_path.display()
             ));
             output::hint("This will overwrite your existing configuration.");
-            let prompt = crate::utils::interactive::InteractivePrompt::new();
-            if !prompt.confirm("Continue with re-initialization?", false)? {
+            let proceed = if unattended.is_some() {
+                crate::cli::helpers::confirm_proceed(
+                    false,
+                    "Continue with re-initialization?",
+                    "--yes",
+                )?
+            } else {
+                let prompt = crate::utils::interactive::InteractivePrompt::new();
+                prompt.confirm("Continue with re-initialization?", false)?
+            };
+            if !proceed {
                 output::info("Init cancelled. Existing configuration preserved.");
                 return Ok(());
             }
         }
     }
 
-    // Create the initializer and run the interactive setup
     let initializer = ConfigInitializer::new();
-    let new_config = initializer.run_interactive_setup().await?;
+    let new_config = match unattended {
+        Some(options) => initializer.run_unattended_setup(options).await?,
+        None => initializer.run_interactive_setup().await?,
+    };
 
     // Show setup summary
     initializer.show_setup_summary(&new_config)?;
@@ -220,6 +245,99 @@ pub(crate) async fn execute_init_command(_config: Config) -> Result<()> {
     Ok(())
 }
 
+/// `xv init --blob-only`: add blob storage to the current Azure setup.
+pub(crate) async fn execute_init_blob_only(
+    storage: Option<(String, String)>,
+    config: Config,
+) -> Result<()> {
+    let config = crate::config::init::ConfigInitializer::new()
+        .run_blob_only_setup(config, storage)
+        .await?;
+    if let Some(blob_config) = &config.blob_config {
+        output::success(&format!(
+            "File storage ready: container '{}' in storage account '{}'",
+            blob_config.container_name, blob_config.storage_account
+        ));
+    }
+    output::hint("Upload a file: xv file upload <path>");
+    Ok(())
+}
+
+/// After `xv file` fails for want of blob storage, offer (on a terminal)
+/// to run `xv init --blob-only` there and then.
+#[cfg(feature = "file-ops")]
+#[allow(dead_code)] // called from src/main.rs::offer_setup_fix (binary-only path)
+pub(crate) async fn offer_blob_only_init(config: Config) -> Result<()> {
+    use std::io::IsTerminal;
+
+    if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
+        return Ok(());
+    }
+    let prompt = crate::utils::interactive::InteractivePrompt::new();
+    if !prompt.confirm("Set up blob storage now (xv init --blob-only)?", true)? {
+        return Ok(());
+    }
+    execute_init_blob_only(None, config).await?;
+    output::hint("Run the file command again.");
+    Ok(())
+}
+
+/// `xv init --check`: report whether the environment is ready for
+/// `xv init` (or still is, for an existing setup) without changing anything.
+/// Exits non-zero when a check fails, so CI can run it as a preflight.
+pub(crate) async fn execute_init_check_command(
+    flags: Option<crate::config::init::UnattendedInit>,
+    config: Config,
+) -> Result<()> {
+    use crate::config::readiness::{check_readiness, CheckStatus, ReadinessTarget};
+    use crate::utils::format::OutputFormat;
+
+    let target = ReadinessTarget::for_init(flags, &config);
+    let checks = check_readiness(&target).await;
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+
+    if config.format_explicit
+        && matches!(
+            config.runtime_output_format,
+            OutputFormat::Json | OutputFormat::Yaml
+        )
+    {
+        let rendered = if config.runtime_output_format == OutputFormat::Json {
+            serde_json::to_string_pretty(&checks).map_err(|e| {
+                CrosstacheError::serialization(format!("Failed to serialize checks: {e}"))
+            })?
+        } else {
+            serde_yaml::to_string(&checks).map_err(|e| {
+                CrosstacheError::serialization(format!("Failed to serialize checks: {e}"))
+            })?
+        };
+        println!("{rendered}");
+    } else {
+        output::step("Checking environment readiness (nothing is changed)...\n");
+        for check in &checks {
+            let line = format!("{}: {}", check.name, check.detail);
+            match check.status {
+                CheckStatus::Pass => output::success(&line),
+                CheckStatus::Warn => output::warn(&line),
+                CheckStatus::Fail => output::error(&line),
+            }
+        }
+        if failed == 0 {
+            output::success("Environment is ready for xv init");
+        }
+    }
+
+    if failed > 0 {
+        return Err(CrosstacheError::config(format!(
+            "{failed} readiness check(s) failed"
+        )));
+    }
+    Ok(())
+}
+
 pub(crate) async fn execute_info_command(
     resource: String,
     resource_type: Option<ResourceType>,
@@ -286,11 +404,15 @@ async fn execute_secret_info_from_root(
     // display record from the returned properties (the same tag-derived
     // groups/folder/note extraction the legacy `get_secret_info` did).
     let backend = crate::cli::vault_ops::active_or_construct_backend(registry, config).await?;
-    let props = backend
+    // Fetch the value too, but only to measure it: the size is reported so
+    // secrets approaching the backend's value limit are visible before
+    // writes start failing. The plaintext is dropped (zeroized) right here.
+    let mut props = backend
         .secrets()
-        .get_secret(&vault_name, secret_name, false)
+        .get_secret(&vault_name, secret_name, true)
         .await
         .map_err(CrosstacheError::from)?;
+    let value_size = props.value.take().map(|v| v.len());
 
     let tags = props.tags.clone();
     let parse_ts = |s: &str| -> Option<DateTime<Utc>> {
@@ -306,7 +428,10 @@ async fn execute_secret_info_from_root(
         name: props.name.clone(),
         original_name: SecretInfo::extract_original_name(&tags)
             .or_else(|| (!props.original_name.is_empty()).then(|| props.original_name.clone())),
-        id: format!("{}/secrets/{}/{}", vault_name, props.name, props.version),
+        id: props
+            .id
+            .clone()
+            .unwrap_or_else(|| format!("{}/secrets/{}/{}", vault_name, props.name, props.version)),
         version: (!props.version.is_empty()).then(|| props.version.clone()),
         enabled: props.enabled,
         created: parse_ts(&props.created_on),
@@ -320,6 +445,8 @@ async fn execute_secret_info_from_root(
         note: SecretInfo::extract_note(&tags),
         vault_uri: vault_name.clone(),
         version_count: None,
+        value_size,
+        value_size_limit: backend.capabilities().max_secret_size,
         tags,
     };
 
@@ -368,6 +495,7 @@ pub(crate) async fn execute_completion_command(shell: Shell) -> Result<()> {
 }
 
 pub(crate) async fn execute_whoami_command(
+    verbose: bool,
     config: Config,
     registry: Option<&crate::backend::BackendRegistry>,
 ) -> Result<()> {
@@ -394,9 +522,13 @@ pub(crate) async fn execute_whoami_command(
         }
     };
 
+    if verbose {
+        print_credential_chain().await;
+    }
+
     // Get access token to validate authentication
     let token = match auth_provider
-        .get_token(&["https://vault.azure.net/.default"])
+        .get_token(&[crate::backend::azure::kv_api::KEY_VAULT_SCOPE])
         .await
     {
         Ok(token) => token,
@@ -408,13 +540,8 @@ pub(crate) async fn execute_whoami_command(
 
     output::success("Authentication successful\n");
 
-    // Try to get tenant and subscription information
-    let management_token = auth_provider
-        .get_token(&["https://management.azure.com/.default"])
-        .await
-        .map_err(|e| {
-            CrosstacheError::authentication(format!("Failed to get management token: {e}"))
-        })?;
+    // Tenant and subscription names come from Resource Manager.
+    let arm = ArmClient::new(auth_provider.clone())?;
 
     // Parse token to get identity info (from JWT)
     let token_claims = extract_claims_from_token(token.token.secret())?;
@@ -434,7 +561,7 @@ pub(crate) async fn execute_whoami_command(
 
     // Get tenant name
     if let Some(ref tid) = token_claims.tenant_id {
-        let tenant_display = match get_tenant_name(management_token.token.secret(), tid).await {
+        let tenant_display = match get_tenant_name(&arm, tid).await {
             Ok(name) => format!("{} ({})", name, tid),
             Err(_) => tid.clone(),
         };
@@ -442,7 +569,7 @@ pub(crate) async fn execute_whoami_command(
     }
 
     // Get subscription information with name
-    match get_current_subscription_details(management_token.token.secret()).await {
+    match get_current_subscription_details(&arm).await {
         Ok((sub_id, sub_name)) => {
             println!("   Subscription: {} ({})", sub_name, sub_id);
         }
@@ -502,110 +629,171 @@ pub(crate) async fn execute_whoami_command(
         "   Credential priority: {:?}",
         config.azure_credential_priority
     );
+    let environment = crate::utils::environment::current();
+    println!("   Environment: {}", environment.describe());
+    if config.azure_credential_priority == crate::config::settings::AzureCredentialType::Default {
+        if let Some(adapted) =
+            environment.adapted_credential(crate::utils::environment::has_env_azure_credentials())
+        {
+            println!("   Credential in use: {adapted} (no browser or az CLI here)");
+        }
+    }
 
     Ok(())
 }
 
-/// Resolve a tenant ID to its display name via the Azure management API.
-async fn get_tenant_name(token: &str, _tenant_id: &str) -> Result<String> {
-    use crate::utils::network::{create_http_client, NetworkConfig};
-
-    let network_config = NetworkConfig::default();
-    let http_client = create_http_client(&network_config)?;
-
-    let mut headers = reqwest::header::HeaderMap::new();
-    headers.insert(
-        reqwest::header::AUTHORIZATION,
-        format!("Bearer {}", token)
-            .parse()
-            .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-    );
+/// `xv whoami --verbose`: each default-chain source tried on its own.
+async fn print_credential_chain() {
+    output::step("Credential chain:");
+    let attempts = crate::auth::provider::diagnose_credential_chain(&[
+        crate::backend::azure::kv_api::KEY_VAULT_SCOPE,
+    ])
+    .await;
+    for attempt in attempts {
+        match attempt.outcome {
+            Ok(()) => output::success(&format!("{}: token acquired", attempt.source)),
+            Err(reason) => output::warn(&format!("{}: {reason}", attempt.source)),
+        }
+    }
+    println!();
+}
 
-    let response = http_client
-        .get("https://management.azure.com/tenants?api-version=2020-01-01")
-        .headers(headers)
-        .send()
-        .await
-        .map_err(|e| CrosstacheError::azure_api(format!("Failed to get tenants: {e}")))?;
+/// Probe each Azure endpoint xv talks to and report which ones are reachable
+/// from here, and through which proxy. Short timeouts keep a dead network
+/// path from looking like a hang.
+pub(crate) async fn execute_doctor_command(config: Config) -> Result<()> {
+    use crate::backend::azure::types::AzureVaultName;
+    use crate::utils::network::{create_http_client, env_proxy_for, probe_endpoint, NetworkConfig};
+    use std::time::Duration;
 
-    if !response.status().is_success() {
-        return Err(CrosstacheError::azure_api(
-            "Failed to get tenant information",
+    let backend = config.effective_backend_name();
+    if backend != "azure" {
+        output::info(&format!(
+            "The active backend is '{backend}'; `xv doctor` checks Azure endpoints only."
         ));
+        return Ok(());
     }
 
-    let json: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| CrosstacheError::azure_api(format!("Failed to parse response: {e}")))?;
+    let mut endpoints: Vec<(String, String)> = vec![
+        (
+            "Microsoft Entra ID".to_string(),
+            "https://login.microsoftonline.com/common/v2.0/.well-known/openid-configuration"
+                .to_string(),
+        ),
+        (
+            "Azure Resource Manager".to_string(),
+            "https://management.azure.com/".to_string(),
+        ),
+    ];
+    if let Ok(vault) = AzureVaultName::try_from(config.default_vault.as_str()) {
+        let mut url = vault.key_vault_url()?;
+        url.set_path("/healthstatus");
+        endpoints.push((format!("Key Vault '{vault}'"), url.to_string()));
+    }
+    if let Some(blob) = config
+        .blob_config
+        .as_ref()
+        .filter(|blob| !blob.storage_account.is_empty())
+    {
+        let url = blob
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://{}.blob.core.windows.net/", blob.storage_account));
+        endpoints.push((format!("Blob storage '{}'", blob.storage_account), url));
+    }
 
-    if let Some(tenants) = json["value"].as_array() {
-        for tenant in tenants {
-            if tenant["tenantId"].as_str() == Some(_tenant_id) {
-                if let Some(name) = tenant["displayName"].as_str() {
-                    return Ok(name.to_string());
-                }
+    let network_config = NetworkConfig {
+        connect_timeout: Duration::from_secs(5),
+        request_timeout: Duration::from_secs(10),
+        ..NetworkConfig::default()
+    };
+    let client = create_http_client(&network_config)?;
+
+    output::step("Checking connectivity to Azure endpoints...\n");
+    output::info(&format!(
+        "Environment: {}",
+        crate::utils::environment::current().describe()
+    ));
+    match env_proxy_for(&endpoints[0].1) {
+        Some(proxy) => output::info(&format!("Using proxy {proxy} (from the environment)")),
+        None => output::info("No proxy configured (HTTPS_PROXY/ALL_PROXY unset)"),
+    }
+
+    let probes = futures::future::join_all(
+        endpoints
+            .iter()
+            .map(|(name, url)| probe_endpoint(&client, name, url)),
+    )
+    .await;
+
+    let mut failures = 0;
+    for probe in &probes {
+        match &probe.outcome {
+            Ok(elapsed) => output::success(&format!(
+                "{}: reachable ({} ms)",
+                probe.name,
+                elapsed.as_millis()
+            )),
+            Err(reason) => {
+                failures += 1;
+                output::error(&format!("{}: {reason} ({})", probe.name, probe.url));
             }
         }
     }
 
-    Err(CrosstacheError::azure_api("Tenant name not found"))
-}
-
-/// Get the current subscription ID and display name.
-async fn get_current_subscription_details(token: &str) -> Result<(String, String)> {
-    use crate::utils::network::{create_http_client, NetworkConfig};
-
-    let network_config = NetworkConfig::default();
-    let http_client = create_http_client(&network_config)?;
-
-    let mut headers = reqwest::header::HeaderMap::new();
-    headers.insert(
-        reqwest::header::AUTHORIZATION,
-        format!("Bearer {}", token)
-            .parse()
-            .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-    );
-
-    let response = http_client
-        .get("https://management.azure.com/subscriptions?api-version=2020-01-01")
-        .headers(headers)
-        .send()
-        .await
-        .map_err(|e| CrosstacheError::azure_api(format!("Failed to get subscriptions: {e}")))?;
-
-    if !response.status().is_success() {
-        return Err(CrosstacheError::azure_api(
-            "Failed to get subscription information",
-        ));
+    if failures > 0 {
+        output::hint(
+            "Behind a corporate proxy, export HTTPS_PROXY (and NO_PROXY for hosts that \
+             bypass it), and make sure the proxy allows the hosts above.",
+        );
+        return Err(CrosstacheError::network(format!(
+            "{failures} of {} Azure endpoints unreachable",
+            probes.len()
+        )));
     }
+    Ok(())
+}
 
-    let json: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| CrosstacheError::azure_api(format!("Failed to parse response: {e}")))?;
-
-    if let Some(subscriptions) = json["value"].as_array() {
-        if let Some(first_sub) = subscriptions.first() {
-            let sub_id = first_sub["subscriptionId"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string();
-            let sub_name = first_sub["displayName"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string();
-            return Ok((sub_id, sub_name));
-        }
-    }
+/// Resolve a tenant ID to its display name via the Azure management API.
+async fn get_tenant_name(arm: &ArmClient, tenant_id: &str) -> Result<String> {
+    let url = arm.url("/tenants?api-version=2020-01-01");
+    arm.find_in_pages(
+        url,
+        "Failed to get tenant information",
+        |tenants: Vec<serde_json::Value>| {
+            tenants
+                .iter()
+                .find(|tenant| tenant["tenantId"].as_str() == Some(tenant_id))
+                .and_then(|tenant| tenant["displayName"].as_str())
+                .map(str::to_string)
+        },
+    )
+    .await?
+    .ok_or_else(|| CrosstacheError::azure_api("Tenant name not found"))
+}
 
-    Err(CrosstacheError::azure_api("No subscriptions found"))
+/// Get the current subscription ID and display name.
+async fn get_current_subscription_details(arm: &ArmClient) -> Result<(String, String)> {
+    let url = arm.url("/subscriptions?api-version=2020-01-01");
+    arm.find_in_pages(
+        url,
+        "Failed to get subscription information",
+        |subscriptions: Vec<serde_json::Value>| {
+            subscriptions.first().map(|first_sub| {
+                let field = |key: &str| first_sub[key].as_str().unwrap_or("unknown").to_string();
+                (field("subscriptionId"), field("displayName"))
+            })
+        },
+    )
+    .await?
+    .ok_or_else(|| CrosstacheError::azure_api("No subscriptions found"))
 }
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn execute_gen_command(
-    length: usize,
+    length: Option<usize>,
     charset: Option<CharsetType>,
+    generator: Option<crate::secret::generate::Generator<'_>>,
     save: Option<String>,
     vault: Option<String>,
     raw: bool,
@@ -613,13 +801,6 @@ pub(crate) async fn execute_gen_command(
     config: Config,
     registry: Option<&crate::backend::BackendRegistry>,
 ) -> Result<()> {
-    // Validate length
-    if !(6..=100).contains(&length) {
-        return Err(CrosstacheError::invalid_argument(
-            "Length must be between 6 and 100",
-        ));
-    }
-
     // Metadata flags only make sense when saving — there is nothing to attach
     // them to otherwise. Reject early with a clear message instead of silently
     // ignoring them.
@@ -630,21 +811,33 @@ pub(crate) async fn execute_gen_command(
         ));
     }
 
-    // Resolve charset: CLI flag → config default → Alphanumeric
-    let resolved_charset = if let Some(c) = charset {
-        c
-    } else if let Some(ref s) = config.gen_default_charset {
-        s.parse::<CharsetType>().map_err(|e| {
-            CrosstacheError::config(format!(
-                "Invalid value for config key 'gen_default_charset': {e}"
-            ))
-        })?
-    } else {
-        CharsetType::Alphanumeric
+    // Generate the password: a passphrase or byte string when asked for,
+    // otherwise characters from the resolved charset.
+    let password = match generator {
+        Some(generator) => generator.generate()?,
+        None => {
+            let length = length.unwrap_or(15);
+            if !(6..=100).contains(&length) {
+                return Err(CrosstacheError::invalid_argument(
+                    "Length must be between 6 and 100",
+                ));
+            }
+            // Resolve charset: CLI flag → config default → Alphanumeric
+            let resolved_charset = if let Some(c) = charset {
+                c
+            } else if let Some(ref s) = config.gen_default_charset {
+                s.parse::<CharsetType>().map_err(|e| {
+                    CrosstacheError::config(format!(
+                        "Invalid value for config key 'gen_default_charset': {e}"
+                    ))
+                })?
+            } else {
+                CharsetType::Alphanumeric
+            };
+            generate_random_value(length, resolved_charset, None)?
+        }
     };
-
-    // Generate the password
-    let password = generate_random_value(length, resolved_charset, None)?;
+    let raw = crate::cli::helpers::print_instead_of_copy(raw);
 
     // Handle --save
     if let Some(ref name) = save {
@@ -740,7 +933,9 @@ async fn save_generated_secret(
     config: &Config,
     registry: Option<&crate::backend::BackendRegistry>,
 ) -> Result<()> {
-    use crate::cli::helpers::{resolve_vault_for_trait, use_trait_path};
+    use crate::cli::helpers::{
+        apply_inherited_tags, inherited_vault_tags, resolve_vault_for_trait, use_trait_path,
+    };
 
     // Apply env-profile `group`/`folder` write-time defaults when the
     // caller didn't pass an explicit `--group`/`--folder`, exactly like
@@ -761,7 +956,7 @@ async fn save_generated_secret(
         return Ok(());
     }
 
-    let request = meta.to_secret_request(name, zeroize::Zeroizing::new(value.to_string()))?;
+    let mut request = meta.to_secret_request(name, zeroize::Zeroizing::new(value.to_string()))?;
 
     // Trait path: any backend exposed through the registry (local, aws, and
     // Azure once it has a registry). A --vault flag overrides the resolved
@@ -772,6 +967,10 @@ async fn save_generated_secret(
             Some(v) => v,
             None => resolve_vault_for_trait(config, registry).await?,
         };
+        apply_inherited_tags(
+            &mut request,
+            &inherited_vault_tags(reg.active(), &vault_name, config).await,
+        );
         reg.active()
             .secrets()
             .set_secret(&vault_name, request)
@@ -793,6 +992,10 @@ async fn save_generated_secret(
         Some(v) => v,
         None => resolve_vault_for_trait(config, registry).await?,
     };
+    apply_inherited_tags(
+        &mut request,
+        &inherited_vault_tags(backend.as_ref(), &vault_name, config).await,
+    );
     backend
         .secrets()
         .set_secret(&vault_name, request)
@@ -999,6 +1202,7 @@ mod tests {
                 resource: "mysecret".to_string(),
                 caller: "user@example.com".to_string(),
                 status: "Success".to_string(),
+                correlation_id: None,
             },
             AuditRow {
                 timestamp: "2024-01-15 10:31:12".to_string(),
@@ -1006,6 +1210,7 @@ mod tests {
                 resource: "anothersecret".to_string(),
                 caller: "admin@example.com".to_string(),
                 status: "Success".to_string(),
+                correlation_id: None,
             },
         ];
 
@@ -1037,6 +1242,7 @@ mod tests {
             resource: "oldsecret".to_string(),
             caller: "operator@example.com".to_string(),
             status: "Success".to_string(),
+            correlation_id: Some("0b1c2d3e-4f50-6172-8394-a5b6c7d8e9f0".to_string()),
         }];
 
         let formatter = TableFormatter::new(OutputFormat::Json, true, None, None);
@@ -1067,5 +1273,10 @@ mod tests {
             Some("SecretDelete"),
             "JSON object should contain operation field with correct value"
         );
+        assert_eq!(
+            first_obj.get("correlation_id").and_then(|v| v.as_str()),
+            Some("0b1c2d3e-4f50-6172-8394-a5b6c7d8e9f0"),
+            "JSON object should carry the Activity Log correlation id"
+        );
     }
 }
@@ -5,9 +5,12 @@ use crate::cli::commands::{VaultCommands, VaultShareCommands};
 use crate::cli::helpers::{share_unsupported_error, use_vault_trait_path};
 use crate::config::Config;
 use crate::error::{CrosstacheError, Result};
-use crate::utils::output;
-use crate::vault::VaultCreateRequest;
+use crate::utils::output::{self, Level};
+use crate::utils::progress::ItemProgress;
+use crate::utils::report::{ItemStatus, ReportRecorder};
+use crate::vault::{VaultCreateRequest, VaultPermission};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use zeroize::Zeroizing;
 
 /// Materialize the active (or, when the registry failed to build, the
@@ -90,6 +93,31 @@ pub(crate) async fn execute_vault_command(
     config: Config,
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
+    // The break-glass cache works against any backend's secrets.
+    if let VaultCommands::Cache { command } = command {
+        return crate::cli::offline_ops::execute_vault_cache_command(command, &config, registry)
+            .await;
+    }
+    // Sync resolves each side through the workspace, so it can span backends.
+    if let VaultCommands::Sync {
+        source,
+        dest,
+        dry_run,
+        delete,
+        group,
+        yes,
+        report,
+    } = command
+    {
+        let options = SyncOptions {
+            dry_run,
+            delete,
+            group,
+            yes,
+        };
+        return execute_vault_sync(&source, &dest, options, report, &config, registry).await;
+    }
+
     // ── Non-Azure trait path ───────────────────────────────────────────
     // Local/AWS resolve the core CRUD verbs (create/list/delete/info) here
     // through `VaultBackend`; the section below covers Azure plus the verbs
@@ -114,7 +142,11 @@ pub(crate) async fn execute_vault_command(
         let vaults_backend = vaults_of(reg.active())?;
 
         match command {
-            VaultCommands::Create { name, .. } => {
+            VaultCommands::Create {
+                name,
+                update_if_exists,
+                ..
+            } => {
                 let request = crate::vault::models::VaultCreateRequest {
                     name: name.clone(),
                     location: String::new(),
@@ -129,11 +161,23 @@ pub(crate) async fn execute_vault_command(
                     tags: None,
                     access_policies: None,
                 };
-                let vault = vaults_backend.create_vault(request).await?;
-                output::success(&format!("Successfully created vault '{}'", vault.name));
+                match vaults_backend.create_vault(request).await {
+                    Ok(vault) => {
+                        output::success(&format!("Successfully created vault '{}'", vault.name))
+                    }
+                    Err(crate::backend::error::BackendError::Conflict(_)) if update_if_exists => {
+                        output::info(&format!(
+                            "Vault '{name}' already exists; leaving it unchanged."
+                        ));
+                    }
+                    Err(e) => return Err(e.into()),
+                }
             }
             VaultCommands::List {
                 names_only,
+                no_cache,
+                with_counts,
+                all_subscriptions,
                 page,
                 page_size,
                 pager,
@@ -141,10 +185,16 @@ pub(crate) async fn execute_vault_command(
             } => {
                 use crate::utils::pagination::Pagination;
 
-                let pager = pager
-                    .map(crate::cli::commands::PagerWhen::wants_pager)
-                    .unwrap_or(false);
-                let vaults = vaults_backend.list_vaults(None).await?;
+                let pager = crate::cli::commands::PagerWhen::resolve(pager, &config);
+                let mut vaults = if all_subscriptions {
+                    vaults_backend.list_vaults_all_subscriptions().await?
+                } else {
+                    vaults_backend.list_vaults(None).await?
+                };
+                if with_counts {
+                    attach_secret_counts(reg.active().secrets(), &mut vaults, no_cache, &config)
+                        .await;
+                }
                 let output_format = config.runtime_output_format;
                 let pagination = Pagination::from_args(page, page_size)?;
 
@@ -217,12 +267,16 @@ pub(crate) async fn execute_vault_command(
             name,
             resource_group,
             location,
+            update_if_exists,
+            policy_check,
         } => {
             execute_vault_create(
                 vaults_of(backend.as_ref())?,
                 &name,
                 resource_group,
                 location,
+                update_if_exists,
+                policy_check,
                 &config,
             )
             .await?;
@@ -232,20 +286,22 @@ pub(crate) async fn execute_vault_command(
             resource_group,
             names_only,
             no_cache,
+            with_counts,
+            all_subscriptions,
             page,
             page_size,
             pager,
         } => {
             execute_vault_list(
-                vaults_of(backend.as_ref())?,
+                backend.as_ref(),
                 resource_group,
                 names_only,
                 no_cache,
+                with_counts,
+                all_subscriptions,
                 page,
                 page_size,
-                pager
-                    .map(crate::cli::commands::PagerWhen::wants_pager)
-                    .unwrap_or(false),
+                crate::cli::commands::PagerWhen::resolve(pager, &config),
                 &config,
             )
             .await?;
@@ -269,8 +325,26 @@ pub(crate) async fn execute_vault_command(
             name,
             resource_group,
         } => {
-            execute_vault_info(vaults_of(backend.as_ref())?, &name, resource_group, &config)
-                .await?;
+            execute_vault_info(backend.as_ref(), &name, resource_group, &config).await?;
+        }
+        VaultCommands::ExportTemplate {
+            name,
+            resource_group,
+            output,
+            template_format,
+        } => {
+            execute_vault_export_template(
+                vaults_of(backend.as_ref())?,
+                &name,
+                resource_group,
+                output,
+                template_format,
+                &config,
+            )
+            .await?;
+        }
+        VaultCommands::CheckName { name } => {
+            execute_vault_check_name(vaults_of(backend.as_ref())?, &name, &config).await?;
         }
         VaultCommands::Restore { name, location } => {
             execute_vault_restore(vaults_of(backend.as_ref())?, &name, &location, &config).await?;
@@ -280,12 +354,14 @@ pub(crate) async fn execute_vault_command(
             name,
             location,
             force,
+            when_eligible,
         } => {
             execute_vault_purge(
                 vaults_of(backend.as_ref())?,
                 &name,
                 &location,
                 force,
+                when_eligible,
                 &config,
             )
             .await?;
@@ -298,6 +374,7 @@ pub(crate) async fn execute_vault_command(
             format,
             include_values,
             group,
+            report,
         } => {
             execute_vault_export(
                 backend.as_ref(),
@@ -307,6 +384,7 @@ pub(crate) async fn execute_vault_command(
                 &format,
                 include_values,
                 group,
+                report,
                 &config,
             )
             .await?;
@@ -318,6 +396,8 @@ pub(crate) async fn execute_vault_command(
             format,
             overwrite,
             dry_run,
+            report,
+            resume,
         } => {
             execute_vault_import(
                 backend.as_ref(),
@@ -327,6 +407,8 @@ pub(crate) async fn execute_vault_command(
                 &format,
                 overwrite,
                 dry_run,
+                report,
+                resume,
                 &config,
             )
             .await?;
@@ -380,6 +462,8 @@ pub(crate) async fn execute_vault_command(
             }
             execute_vault_share(vaults_of(backend.as_ref())?, command, &config).await?;
         }
+        VaultCommands::Cache { .. } => unreachable!("vault cache is dispatched above"),
+        VaultCommands::Sync { .. } => unreachable!("vault sync is dispatched above"),
     }
     Ok(())
 }
@@ -389,15 +473,65 @@ async fn execute_vault_create(
     name: &str,
     resource_group: Option<String>,
     location: Option<String>,
+    update_if_exists: bool,
+    policy_check: bool,
     config: &Config,
 ) -> Result<()> {
     // Use defaults from config if not provided
     let resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
-    let location = location.unwrap_or_else(|| config.default_location.clone());
+    let requested_location = location;
+    let location = requested_location
+        .clone()
+        .unwrap_or_else(|| config.default_location.clone());
+
+    // An Azure PUT on an existing vault silently rewrites it (including its
+    // access policies), so look before creating.
+    match vaults_backend.get_vault(name, Some(&resource_group)).await {
+        Ok(existing) => {
+            if !update_if_exists {
+                return Err(CrosstacheError::Conflict(format!(
+                    "Vault '{name}' already exists in resource group '{resource_group}'. \
+                     Pass --update-if-exists to accept the existing vault, or choose another name."
+                )));
+            }
+            if let Some(requested) = requested_location {
+                if !existing.location.eq_ignore_ascii_case(&requested) {
+                    return Err(CrosstacheError::InvalidArgument(format!(
+                        "Vault '{name}' already exists in '{}', not '{requested}'; \
+                         a vault cannot be moved to another location.",
+                        existing.location
+                    )));
+                }
+            }
+            output::info(&format!(
+                "Vault '{name}' already exists in resource group '{resource_group}'; leaving it unchanged."
+            ));
+            println!("   Location: {}", existing.location);
+            println!("   URI: {}", existing.uri);
+            return Ok(());
+        }
+        Err(crate::backend::error::BackendError::VaultNotFound { .. }) => {}
+        Err(e) => tracing::debug!("vault create: existence pre-check for '{name}' failed: {e}"),
+    }
 
-    println!(
-        "Creating vault '{name}' in resource group '{resource_group}' at location '{location}'..."
-    );
+    // A soft-deleted vault keeps its name reserved until it is purged; the
+    // create would fail with an opaque conflict.
+    match vaults_backend.find_deleted_vault(name).await {
+        Ok(Some(deleted)) => {
+            let purge_date = deleted
+                .scheduled_purge_date
+                .map(|date| format!(" until {date}"))
+                .unwrap_or_default();
+            return Err(CrosstacheError::Conflict(format!(
+                "A soft-deleted vault named '{name}' (deleted in '{location}') reserves the name{purge_date}. \
+                 Restore it with 'xv vault restore {name} --location {location}' or remove it \
+                 permanently with 'xv vault purge {name} --location {location}'.",
+                location = deleted.location
+            )));
+        }
+        Ok(None) => {}
+        Err(e) => tracing::debug!("vault create: deleted-vault check for '{name}' failed: {e}"),
+    }
 
     // Config-derived request. The Azure adapter fills the current user as an
     // admin access policy and applies purge-protection defaults inside
@@ -423,7 +557,24 @@ async fn execute_vault_create(
         access_policies: None, // Will be set automatically by the backend
     };
 
-    let vault = vaults_backend.create_vault(create_request).await?;
+    if policy_check {
+        check_vault_create_policy(vaults_backend, &create_request).await?;
+    }
+
+    println!(
+        "Creating vault '{name}' in resource group '{resource_group}' at location '{location}'..."
+    );
+
+    let vault = match vaults_backend.create_vault(create_request).await {
+        Ok(vault) => vault,
+        Err(crate::backend::error::BackendError::Conflict(detail)) => {
+            return Err(CrosstacheError::Conflict(format!(
+                "Vault name '{name}' is not available: {detail}. Key Vault names are \
+                 globally unique, so it may belong to another resource group, subscription, or tenant."
+            )));
+        }
+        Err(e) => return Err(e.into()),
+    };
 
     output::success(&format!("Successfully created vault '{}'", vault.name));
     println!("   Resource Group: {}", vault.resource_group);
@@ -438,6 +589,79 @@ async fn execute_vault_create(
     Ok(())
 }
 
+/// `vault create --policy-check`: report what Azure Policy would do to the
+/// vault before creating it. A denial stops the create up front instead of
+/// failing mid-way; changes a policy would make and audit findings are only
+/// reported. A check that cannot run is noted and the create goes ahead.
+async fn check_vault_create_policy(
+    vaults_backend: &dyn crate::backend::vault::VaultBackend,
+    request: &VaultCreateRequest,
+) -> Result<()> {
+    let findings = match vaults_backend.check_create_policy(request).await {
+        Ok(findings) => findings,
+        Err(crate::backend::error::BackendError::Unsupported(_)) => {
+            output::info("Skipping policy pre-check: this backend has no policy engine.");
+            return Ok(());
+        }
+        Err(e) => {
+            output::warn(&format!(
+                "Policy pre-check failed ({e}); creating the vault without it."
+            ));
+            return Ok(());
+        }
+    };
+
+    if findings.is_empty() {
+        output::info("Policy pre-check: no policy assignment restricts this vault.");
+        return Ok(());
+    }
+
+    for finding in &findings {
+        let line = describe_policy_finding(finding);
+        if finding.is_denial() || finding.is_modification() {
+            output::warn(&line);
+        } else {
+            output::info(&line);
+        }
+    }
+
+    let denials = findings.iter().filter(|f| f.is_denial()).count();
+    if denials > 0 {
+        return Err(CrosstacheError::permission_denied(format!(
+            "Azure Policy would deny creating vault '{}' ({denials} {} above); nothing was created. \
+             Adjust the settings or request a policy exemption for resource group '{}'.",
+            request.name,
+            if denials == 1 { "denial" } else { "denials" },
+            request.resource_group
+        )));
+    }
+    Ok(())
+}
+
+/// One line per policy finding, e.g. `Policy 'kv-private-only' will set
+/// properties.publicNetworkAccess to Disabled`.
+fn describe_policy_finding(finding: &crate::vault::models::PolicyFinding) -> String {
+    let policy = finding
+        .policy
+        .as_deref()
+        .map(|p| format!("Policy '{p}'"))
+        .unwrap_or_else(|| "A policy".to_string());
+    let field = finding.field.as_deref().unwrap_or("the vault");
+    let detail = finding.detail.as_deref();
+    match finding.effect.to_ascii_lowercase().as_str() {
+        "deny" => match detail {
+            Some(values) => format!("{policy} denies {field} ({values})"),
+            None => format!("{policy} denies {field}"),
+        },
+        "required" | "modify" | "append" => match detail {
+            Some(value) => format!("{policy} will set {field} to {value}"),
+            None => format!("{policy} will modify {field}"),
+        },
+        "removed" => format!("{policy} will remove {field}"),
+        effect => format!("{policy} reports {field} ({effect})"),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 /// Shared rendering for `vault list`'s cached and fresh branches: names-only
 /// output, empty-state messaging (stderr for humans, valid-empty JSON/etc. on
@@ -503,12 +727,67 @@ fn render_vault_list(
     Ok(())
 }
 
+/// Upper bound on concurrent per-vault count calls for `--with-counts`.
+const VAULT_COUNT_CONCURRENCY: usize = 8;
+
+/// Fill in `secret_count` for each vault. Counts come from the per-vault
+/// secrets-list cache when warm; the rest use the backend's count-only listing
+/// concurrently (bounded by [`VAULT_COUNT_CONCURRENCY`]), which skips the
+/// per-secret detail fetch a full list needs. A vault the caller cannot read
+/// keeps an empty count instead of failing the whole listing.
+async fn attach_secret_counts(
+    secrets: &dyn crate::backend::secret::SecretBackend,
+    vaults: &mut [crate::vault::models::VaultSummary],
+    no_cache: bool,
+    config: &Config,
+) {
+    use crate::cache::{CacheKey, CacheManager};
+    use crate::secret::manager::SecretSummary;
+    use futures::stream::{self, StreamExt};
+
+    let cache_manager = CacheManager::from_config(config);
+    let use_cache = cache_manager.is_enabled() && !no_cache;
+    let cache_key = |vault: &str| CacheKey::SecretsList {
+        backend: config.effective_backend_name().to_string(),
+        vault_name: vault.to_string(),
+    };
+
+    let counts: Vec<(usize, Option<usize>)> = stream::iter(vaults.iter().enumerate())
+        .map(|(index, vault)| {
+            let key = cache_key(&vault.name);
+            let cache_manager = &cache_manager;
+            async move {
+                if use_cache {
+                    if let Some(cached) = cache_manager.get::<Vec<SecretSummary>>(&key) {
+                        return (index, Some(cached.len()));
+                    }
+                }
+                match secrets.count_secrets(&vault.name).await {
+                    Ok(count) => (index, Some(count)),
+                    Err(e) => {
+                        tracing::debug!("secret count for vault '{}' failed: {e}", vault.name);
+                        (index, None)
+                    }
+                }
+            }
+        })
+        .buffer_unordered(VAULT_COUNT_CONCURRENCY)
+        .collect()
+        .await;
+
+    for (index, count) in counts {
+        vaults[index].secret_count = count;
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn execute_vault_list(
-    vaults_backend: &dyn crate::backend::vault::VaultBackend,
+    backend: &dyn Backend,
     resource_group: Option<String>,
     names_only: bool,
     no_cache: bool,
+    with_counts: bool,
+    all_subscriptions: bool,
     page: Option<usize>,
     page_size: Option<usize>,
     pager: bool,
@@ -524,25 +803,45 @@ async fn execute_vault_list(
     let output_format = config.runtime_output_format;
     let pagination = Pagination::from_args(page, page_size)?;
 
-    if use_cache && resource_group.is_none() {
-        if let Some(cached) = cache_manager.get::<Vec<VaultSummary>>(&cache_key) {
-            return render_vault_list(
-                &cached,
-                output_format,
-                pagination,
-                pager,
-                names_only,
-                config,
-            );
-        }
-    }
-
-    let vaults = vaults_backend
-        .list_vaults(resource_group.as_deref())
-        .await?;
+    // The default listing stays a single ARM call (or a cache hit); only
+    // `--with-counts` touches each vault's data plane. The vault-list cache
+    // holds the configured subscription only, so `--all-subscriptions`
+    // neither reads nor writes it.
+    let cacheable = use_cache && resource_group.is_none() && !all_subscriptions;
+    let cached = if cacheable {
+        cache_manager.get::<Vec<VaultSummary>>(&cache_key)
+    } else {
+        None
+    };
+    let mut vaults = match cached {
+        Some(cached) => cached,
+        None if all_subscriptions => vaults_of(backend)?.list_vaults_all_subscriptions().await?,
+        None => match vaults_of(backend)?
+            .list_vaults(resource_group.as_deref())
+            .await
+            .map_err(CrosstacheError::from)
+        {
+            Ok(vaults) => {
+                if cacheable {
+                    cache_manager.set(&cache_key, &vaults);
+                }
+                vaults
+            }
+            Err(e) if crate::cli::helpers::can_list_offline(&e, cacheable) => {
+                let Some((cached, cached_at)) =
+                    cache_manager.get_stale::<Vec<VaultSummary>>(&cache_key)
+                else {
+                    return Err(e);
+                };
+                crate::cli::helpers::warn_offline_listing("vaults", cached_at);
+                cached
+            }
+            Err(e) => return Err(e),
+        },
+    };
 
-    if use_cache && resource_group.is_none() {
-        cache_manager.set(&cache_key, &vaults);
+    if with_counts {
+        attach_secret_counts(backend.secrets(), &mut vaults, no_cache, config).await;
     }
 
     render_vault_list(
@@ -627,11 +926,12 @@ async fn attach_vault_suggestion(
 }
 
 async fn execute_vault_info(
-    vaults_backend: &dyn crate::backend::vault::VaultBackend,
+    backend: &dyn Backend,
     name: &str,
     resource_group: Option<String>,
     config: &Config,
 ) -> Result<()> {
+    let vaults_backend = vaults_of(backend)?;
     // Use provided resource group or fall back to config default.
     let resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
 
@@ -647,31 +947,109 @@ async fn execute_vault_info(
         }
     };
 
+    let info = enrich_vault_info(backend, vaults_backend, vault, &resource_group).await;
+
     if config.output_json {
-        let json_output = serde_json::to_string_pretty(&vault).map_err(|e| {
+        let json_output = serde_json::to_string_pretty(&info).map_err(|e| {
             CrosstacheError::serialization(format!("Failed to serialize vault info: {e}"))
         })?;
         println!("{json_output}");
     } else {
-        display_vault_details(&vault, config.no_color)?;
+        display_vault_details(&info, config.no_color)?;
     }
 
     Ok(())
 }
 
+/// Gather the counts `xv vault info` shows alongside the vault's properties.
+/// The three lookups run concurrently; one the backend does not support or
+/// the caller may not read is left out rather than failing the command.
+async fn enrich_vault_info(
+    backend: &dyn Backend,
+    vaults_backend: &dyn crate::backend::vault::VaultBackend,
+    vault: crate::vault::models::VaultProperties,
+    resource_group: &str,
+) -> crate::vault::models::VaultInfo {
+    let name = vault.name.as_str();
+    let lookup_failed = |what: &str, e: &dyn std::fmt::Display| {
+        tracing::debug!("vault info: {what} for '{name}' unavailable: {e}");
+    };
+
+    let secret_count = async {
+        match backend.secrets().count_secrets(name).await {
+            Ok(count) => Some(count),
+            Err(e) => {
+                lookup_failed("secret count", &e);
+                None
+            }
+        }
+    };
+    let access_count = async {
+        if vault.enable_rbac_authorization != Some(true) {
+            return Some(vault.access_policies.len());
+        }
+        match vaults_backend.list_access(name, Some(resource_group)).await {
+            Ok(assignments) => Some(assignments.len()),
+            Err(e) => {
+                lookup_failed("role assignments", &e);
+                None
+            }
+        }
+    };
+    let diagnostics_enabled = async {
+        match vaults_backend
+            .diagnostics_enabled(name, Some(resource_group))
+            .await
+        {
+            Ok(enabled) => Some(enabled),
+            Err(e) => {
+                lookup_failed("diagnostic settings", &e);
+                None
+            }
+        }
+    };
+
+    let (secret_count, access_count, diagnostics_enabled) =
+        futures::join!(secret_count, access_count, diagnostics_enabled);
+
+    crate::vault::models::VaultInfo {
+        vault,
+        secret_count,
+        access_count,
+        diagnostics_enabled,
+    }
+}
+
 /// Human-readable vault-properties display, relocated CLI-side from the retired
 /// `VaultManager::display_vault_details` (presentation, not a backend concern).
-fn display_vault_details(
-    vault: &crate::vault::models::VaultProperties,
-    no_color: bool,
-) -> Result<()> {
+fn display_vault_details(info: &crate::vault::models::VaultInfo, no_color: bool) -> Result<()> {
     use crate::utils::format::{DisplayUtils, OutputFormat, TableFormatter};
 
+    let vault = &info.vault;
     let du = DisplayUtils::new(no_color);
     du.print_header(&format!("Vault: {}", vault.name))?;
 
     let vault_uri = vault.get_vault_uri();
     let retention_days = format!("{} days", vault.soft_delete_retention_in_days);
+    let unknown = || "unknown".to_string();
+    let secrets = info.secret_count.map_or_else(unknown, |n| n.to_string());
+    let access = match (info.access_count, vault.enable_rbac_authorization) {
+        (Some(n), Some(true)) => format!("{n} role assignment(s) (RBAC)"),
+        (Some(n), _) => format!("{n} access polic(ies)"),
+        (None, _) => unknown(),
+    };
+    let diagnostics = match info.diagnostics_enabled {
+        Some(true) => "Enabled".to_string(),
+        Some(false) => "Disabled".to_string(),
+        None => unknown(),
+    };
+    let network = match (&vault.public_network_access, &vault.network_acls) {
+        (Some(public), _) if public.eq_ignore_ascii_case("Disabled") => {
+            "Public access disabled (private endpoints only)".to_string()
+        }
+        (_, Some(acls)) => acls.describe(),
+        _ => "Public access, no firewall rules".to_string(),
+    };
 
     let details = vec![
         ("Resource ID", vault.id.as_str()),
@@ -713,6 +1091,10 @@ fn display_vault_details(
                 "Disabled"
             },
         ),
+        ("Secrets", secrets.as_str()),
+        ("Access", access.as_str()),
+        ("Diagnostics", diagnostics.as_str()),
+        ("Network", network.as_str()),
     ];
 
     let formatted_details = du.format_key_value_pairs(&details);
@@ -752,13 +1134,105 @@ pub(crate) async fn execute_vault_info_from_root(
     registry: Option<&BackendRegistry>,
 ) -> Result<()> {
     let backend = active_or_construct_backend(registry, config).await?;
-    execute_vault_info(
-        vaults_of(backend.as_ref())?,
-        vault_name,
-        resource_group,
-        config,
-    )
-    .await
+    execute_vault_info(backend.as_ref(), vault_name, resource_group, config).await
+}
+
+async fn execute_vault_export_template(
+    vaults_backend: &dyn crate::backend::vault::VaultBackend,
+    name: &str,
+    resource_group: Option<String>,
+    output: Option<String>,
+    template_format: Option<crate::vault::template::TemplateFormat>,
+    config: &Config,
+) -> Result<()> {
+    use crate::vault::template::{render_template, TemplateFormat};
+
+    let resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
+    let resource = vaults_backend
+        .get_vault_resource(name, Some(&resource_group))
+        .await?;
+
+    let format = template_format.unwrap_or_else(|| {
+        output
+            .as_deref()
+            .map_or(TemplateFormat::Arm, TemplateFormat::from_path)
+    });
+    let template = render_template(&resource, format);
+
+    match output {
+        Some(file_path) => {
+            std::fs::write(&file_path, template).map_err(|e| {
+                CrosstacheError::unknown(format!("Failed to write to output file: {e}"))
+            })?;
+            output::success(&format!(
+                "Exported template for vault '{name}' to {file_path}"
+            ));
+        }
+        None => print!("{template}"),
+    }
+
+    Ok(())
+}
+
+async fn execute_vault_check_name(
+    vaults_backend: &dyn crate::backend::vault::VaultBackend,
+    name: &str,
+    config: &Config,
+) -> Result<()> {
+    use crate::vault::models::VaultNameStatus;
+
+    let status = vaults_backend.check_vault_name(name).await?;
+
+    if config.output_json {
+        let mut json = serde_json::to_value(&status).map_err(|e| {
+            CrosstacheError::serialization(format!("Failed to serialize name check: {e}"))
+        })?;
+        json["name"] = serde_json::Value::String(name.to_string());
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json).map_err(|e| {
+                CrosstacheError::serialization(format!("Failed to serialize name check: {e}"))
+            })?
+        );
+        return Ok(());
+    }
+
+    match status {
+        VaultNameStatus::Available => {
+            output::success(&format!("Vault name '{name}' is available"));
+        }
+        VaultNameStatus::Taken { message } => {
+            output::warn(&format!("Vault name '{name}' is taken"));
+            if let Some(message) = message {
+                println!("   {message}");
+            }
+            output::hint(
+                "Key Vault names are global; the vault may be in another subscription or tenant.",
+            );
+        }
+        VaultNameStatus::SoftDeleted(deleted) => {
+            output::warn(&format!(
+                "Vault name '{name}' is reserved by a soft-deleted vault in '{}'",
+                deleted.location
+            ));
+            if let Some(date) = deleted.scheduled_purge_date {
+                println!("   Scheduled purge: {date}");
+            }
+            output::hint(&format!(
+                "Restore it with 'xv vault restore {name} --location {location}' or free the name \
+                 with 'xv vault purge {name} --location {location}'",
+                location = deleted.location
+            ));
+        }
+        VaultNameStatus::Invalid { message } => {
+            output::warn(&format!("'{name}' is not a valid vault name"));
+            if let Some(message) = message {
+                println!("   {message}");
+            }
+        }
+    }
+
+    Ok(())
 }
 
 async fn execute_vault_restore(
@@ -781,16 +1255,47 @@ async fn execute_vault_purge(
     name: &str,
     location: &str,
     force: bool,
+    when_eligible: bool,
     _config: &Config,
 ) -> Result<()> {
+    // Best-effort: the lookup only adds detail to the purge.
+    let deleted = vaults_backend.find_deleted_vault(name).await.ok().flatten();
+    let scheduled = deleted
+        .as_ref()
+        .and_then(|d| d.scheduled_purge_date.clone());
+    if deleted
+        .as_ref()
+        .is_some_and(|d| d.purge_protection == Some(true))
+    {
+        let when = scheduled
+            .as_deref()
+            .map(|date| {
+                format!(
+                    " on {}",
+                    crate::cli::ls_view::date_portion_for_display(date)
+                )
+            })
+            .unwrap_or_default();
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Vault '{name}' has purge protection enabled and cannot be purged before its \
+             retention period ends. Azure purges it automatically{when}."
+        )));
+    }
     if !force {
         output::warn(&format!(
             "This will PERMANENTLY delete vault '{name}' and all its contents!"
         ));
         output::warn("This action cannot be undone.");
     }
+    crate::cli::secret_ops::print_purge_schedule(scheduled.as_deref(), chrono::Utc::now());
     // Thread the CLI `--location` through (see restore above).
-    vaults_backend.purge_vault(name, Some(location)).await?;
+    crate::cli::secret_ops::purge_with_retry(
+        &format!("vault '{name}'"),
+        scheduled.as_deref(),
+        when_eligible,
+        || vaults_backend.purge_vault(name, Some(location)),
+    )
+    .await?;
     output::success(&format!(
         "Successfully purged vault '{name}' (permanent deletion)"
     ));
@@ -815,8 +1320,58 @@ fn format_env_line(key: &str, value: &str) -> String {
     format!("{key}={}", shell_single_quote(value))
 }
 
+/// A bundle exports one line per key, `<NAME>_<KEY>`; `None` for other
+/// secrets, and for bundles whose keys don't all make valid names.
+fn bundle_env_lines(env_name: &str, content_type: &str, value: &str) -> Option<Vec<String>> {
+    if !crate::secret::bundle::is_bundle(content_type) {
+        return None;
+    }
+    let entries = crate::secret::bundle::parse(env_name, value).ok()?;
+    entries
+        .iter()
+        .map(|(key, value)| {
+            let name = format!("{env_name}_{}", key.to_uppercase().replace(['-', '.'], "_"));
+            is_valid_env_key(&name).then(|| format_env_line(&name, value))
+        })
+        .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn execute_vault_export(
+    backend: &dyn Backend,
+    name: &str,
+    resource_group: Option<String>,
+    output: Option<String>,
+    format: &str,
+    include_values: bool,
+    group: Option<String>,
+    report: Option<String>,
+    config: &Config,
+) -> Result<()> {
+    let mut recorder = ReportRecorder::new("vault export").with_endpoints(
+        Some(name.to_string()),
+        Some(output.clone().unwrap_or_else(|| "stdout".to_string())),
+    );
+    let result = export_vault_secrets(
+        backend,
+        name,
+        resource_group,
+        output,
+        format,
+        include_values,
+        group,
+        config,
+        &mut recorder,
+    )
+    .await;
+    match report {
+        Some(path) => recorder.write_for(std::path::Path::new(&path), result),
+        None => result,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn export_vault_secrets(
     backend: &dyn Backend,
     name: &str,
     resource_group: Option<String>,
@@ -825,7 +1380,26 @@ async fn execute_vault_export(
     include_values: bool,
     group: Option<String>,
     config: &Config,
+    recorder: &mut ReportRecorder,
 ) -> Result<()> {
+    let format = format.to_lowercase();
+    if include_values && matches!(format.as_str(), "bicep" | "terraform") {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "--include-values cannot be used with --fmt {format}: IaC stubs never carry secret \
+             values (each value is left to a secure parameter or sensitive variable)"
+        )));
+    }
+    if include_values {
+        crate::cli::helpers::preflight_permissions(
+            config,
+            backend,
+            name,
+            resource_group.as_deref(),
+            &[VaultPermission::Read],
+            "export secret values",
+        )
+        .await?;
+    }
     let _resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
 
     let secrets_backend = backend.secrets();
@@ -837,22 +1411,92 @@ async fn execute_vault_export(
         .list_secrets(name, group.as_deref())
         .await
         .map_err(CrosstacheError::from)?;
+    let export_data = render_vault_export(
+        secrets_backend,
+        name,
+        &secrets,
+        &format,
+        include_values,
+        config.fetch_concurrency,
+        recorder,
+    )
+    .await?;
 
-    // Prepare export data based on format
-    let export_data = match format.to_lowercase().as_str() {
-        "json" => {
-            let mut export_json = serde_json::Map::new();
-            export_json.insert(
-                "vault".to_string(),
-                serde_json::Value::String(name.to_string()),
+    // Write to output
+    match output {
+        Some(file_path) => {
+            crate::utils::helpers::write_sensitive_file(
+                std::path::Path::new(&file_path),
+                export_data.as_bytes(),
+            )
+            .map_err(|e| {
+                CrosstacheError::unknown(format!("Failed to write to output file: {e}"))
+            })?;
+            println!(
+                "Exported {} secrets to {} (permissions: owner-only)",
+                secrets.len(),
+                file_path
             );
-            export_json.insert(
+        }
+        None => {
+            println!("{export_data}");
+        }
+    }
+    print_bulk_summary(recorder, "exported", config);
+
+    Ok(())
+}
+
+/// Render `secrets` of vault `name` in the export `format` (lowercase),
+/// reading each value when `include_values`, up to `concurrency` reads at a
+/// time. Per-secret outcomes go to `recorder`, with a progress bar on a
+/// terminal. Shared by `xv vault export` and `xv backup vault`.
+pub(crate) async fn render_vault_export(
+    secrets_backend: &dyn crate::backend::SecretBackend,
+    name: &str,
+    secrets: &[crate::secret::manager::SecretSummary],
+    format: &str,
+    include_values: bool,
+    concurrency: usize,
+    recorder: &mut ReportRecorder,
+) -> Result<String> {
+    use futures::stream::{self, StreamExt};
+
+    let progress = ItemProgress::new(secrets.len() as u64, output::is_tty_stderr());
+    // Values are read ahead, `concurrency` at a time, and come out in
+    // listing order with how long each read took; the loops below take
+    // one per secret. Lazy, so formats that never read values send nothing.
+    let mut values = std::pin::pin!(stream::iter(secrets)
+        .map(|secret| async move {
+            let started = Instant::now();
+            let fetched = secrets_backend
+                .get_secret(name, &secret.original_name, true)
+                .await;
+            (fetched, started.elapsed())
+        })
+        .buffered(concurrency.max(1)));
+
+    // Prepare export data based on format
+    let export_data = match format {
+        "json" => {
+            let mut export_json = serde_json::Map::new();
+            export_json.insert(
+                "format_version".to_string(),
+                serde_json::Value::from(crate::vault::export_format::EXPORT_FORMAT_VERSION),
+            );
+            export_json.insert(
+                "vault".to_string(),
+                serde_json::Value::String(name.to_string()),
+            );
+            export_json.insert(
                 "exported_at".to_string(),
                 serde_json::Value::String(chrono::Utc::now().to_rfc3339()),
             );
 
             let mut secrets_json = Vec::new();
-            for secret in &secrets {
+            for secret in secrets {
+                let mut elapsed = Duration::ZERO;
+                let mut failure = None;
                 let mut secret_data = serde_json::Map::new();
                 secret_data.insert(
                     "name".to_string(),
@@ -866,13 +1510,27 @@ async fn execute_vault_export(
                     "content_type".to_string(),
                     serde_json::Value::String(secret.content_type.clone()),
                 );
+                if !secret.tags.is_empty() {
+                    // Sorted so repeated exports of an unchanged vault diff cleanly.
+                    let tags: std::collections::BTreeMap<&String, &String> =
+                        secret.tags.iter().collect();
+                    secret_data.insert("tags".to_string(), serde_json::json!(tags));
+                }
+                if let Some(expires_on) = secret.expires_on {
+                    secret_data.insert(
+                        "expires_on".to_string(),
+                        serde_json::Value::String(expires_on.to_rfc3339()),
+                    );
+                }
 
                 if include_values {
                     // Get actual secret value
-                    match secrets_backend
-                        .get_secret(name, &secret.original_name, true)
+                    let (fetched, took) = values
+                        .next()
                         .await
-                    {
+                        .expect("one value read per listed secret");
+                    elapsed = took;
+                    match fetched {
                         Ok(secret_props) => {
                             if let Some(value) = secret_props.value {
                                 secret_data.insert(
@@ -880,16 +1538,28 @@ async fn execute_vault_export(
                                     serde_json::Value::String(value.to_string()),
                                 );
                             }
+                            // Listings don't carry not-before; the full read does.
+                            if let Some(not_before) = secret_props.not_before {
+                                secret_data.insert(
+                                    "not_before".to_string(),
+                                    serde_json::Value::String(not_before.to_rfc3339()),
+                                );
+                            }
                         }
                         Err(e) => {
-                            eprintln!(
-                                "Warning: Failed to get value for secret '{}': {}",
-                                secret.original_name, e
+                            progress.notice(
+                                Level::Warn,
+                                &format!(
+                                    "Failed to get value for secret '{}': {e}",
+                                    secret.original_name
+                                ),
                             );
+                            failure = Some(e.to_string());
                         }
                     }
                 }
 
+                record_export_item(recorder, &progress, &secret.original_name, elapsed, failure);
                 secrets_json.push(serde_json::Value::Object(secret_data));
             }
             export_json.insert(
@@ -909,12 +1579,15 @@ async fn execute_vault_export(
                 chrono::Utc::now().to_rfc3339()
             ));
 
-            for secret in &secrets {
+            for secret in secrets {
+                let mut elapsed = Duration::ZERO;
                 if include_values {
-                    match secrets_backend
-                        .get_secret(name, &secret.original_name, true)
+                    let (fetched, took) = values
+                        .next()
                         .await
-                    {
+                        .expect("one value read per listed secret");
+                    elapsed = took;
+                    match fetched {
                         Ok(secret_props) => {
                             if let Some(value) = secret_props.value {
                                 let env_name = secret
@@ -923,19 +1596,57 @@ async fn execute_vault_export(
                                     .replace("-", "_")
                                     .replace(".", "_");
                                 if is_valid_env_key(&env_name) {
-                                    env_lines.push(format_env_line(&env_name, value.as_str()));
+                                    match bundle_env_lines(
+                                        &env_name,
+                                        &secret.content_type,
+                                        value.as_str(),
+                                    ) {
+                                        Some(lines) => env_lines.extend(lines),
+                                        None => env_lines
+                                            .push(format_env_line(&env_name, value.as_str())),
+                                    }
                                 } else {
-                                    eprintln!(
-                                        "Warning: Skipping secret '{}' — derived env name '{}' is not a valid shell identifier",
-                                        secret.original_name, env_name
+                                    progress.notice(
+                                        Level::Warn,
+                                        &format!(
+                                            "Skipping secret '{}' — derived env name '{env_name}' is not a valid shell identifier",
+                                            secret.original_name
+                                        ),
                                     );
+                                    progress.inc();
+                                    recorder.record(
+                                        &secret.original_name,
+                                        ItemStatus::Skipped,
+                                        elapsed,
+                                        Some(format!(
+                                            "derived env name '{env_name}' is not a valid shell identifier"
+                                        )),
+                                    );
+                                    continue;
                                 }
                             }
+                            record_export_item(
+                                recorder,
+                                &progress,
+                                &secret.original_name,
+                                elapsed,
+                                None,
+                            );
                         }
                         Err(e) => {
-                            eprintln!(
-                                "Warning: Failed to get value for secret '{}': {}",
-                                secret.original_name, e
+                            progress.notice(
+                                Level::Warn,
+                                &format!(
+                                    "Failed to get value for secret '{}': {e}",
+                                    secret.original_name
+                                ),
+                            );
+                            record_export_item(
+                                recorder,
+                                &progress,
+                                &secret.original_name,
+                                elapsed,
+                                Some(e.to_string()),
                             );
                         }
                     }
@@ -946,6 +1657,7 @@ async fn execute_vault_export(
                         .replace("-", "_")
                         .replace(".", "_");
                     env_lines.push(format!("# {env_name}"));
+                    record_export_item(recorder, &progress, &secret.original_name, elapsed, None);
                 }
             }
 
@@ -957,68 +1669,496 @@ async fn execute_vault_export(
             txt_lines.push(format!("Exported: {}", chrono::Utc::now().to_rfc3339()));
             txt_lines.push("".to_string());
 
-            for secret in &secrets {
+            for secret in secrets {
+                let mut elapsed = Duration::ZERO;
+                let mut failure = None;
                 txt_lines.push(format!("Secret: {}", secret.original_name));
                 txt_lines.push(format!("  Enabled: {}", secret.enabled));
-                txt_lines.push(format!("  Content Type: {}", secret.content_type));
+                if !secret.content_type.is_empty() {
+                    txt_lines.push(format!("  Content Type: {}", secret.content_type));
+                }
                 txt_lines.push(format!("  Updated: {}", secret.updated_on));
 
                 if include_values {
-                    match secrets_backend
-                        .get_secret(name, &secret.original_name, true)
+                    let (fetched, took) = values
+                        .next()
                         .await
-                    {
+                        .expect("one value read per listed secret");
+                    elapsed = took;
+                    match fetched {
                         Ok(secret_props) => {
                             if let Some(value) = secret_props.value {
                                 txt_lines.push(format!("  Value: {}", value.as_str()));
                             }
                         }
                         Err(e) => {
-                            eprintln!(
-                                "Warning: Failed to get value for secret '{}': {}",
-                                secret.original_name, e
+                            progress.notice(
+                                Level::Warn,
+                                &format!(
+                                    "Failed to get value for secret '{}': {e}",
+                                    secret.original_name
+                                ),
                             );
+                            failure = Some(e.to_string());
                         }
                     }
                 }
+                record_export_item(recorder, &progress, &secret.original_name, elapsed, failure);
                 txt_lines.push("".to_string());
             }
 
             txt_lines.join("\n")
         }
+        "bicep" | "terraform" => {
+            // Names and metadata only — no value is ever read. Sorted so
+            // re-exports diff cleanly.
+            let started = Instant::now();
+            let mut sorted = secrets.to_vec();
+            sorted.sort_by(|a, b| a.name.cmp(&b.name));
+            let rendered = if format == "bicep" {
+                crate::vault::template::secrets_bicep(name, &sorted)
+            } else {
+                crate::vault::template::secrets_terraform(name, &sorted)
+            };
+            for secret in secrets {
+                record_export_item(
+                    recorder,
+                    &progress,
+                    &secret.original_name,
+                    started.elapsed(),
+                    None,
+                );
+            }
+            rendered
+        }
         _ => {
+            progress.finish();
             return Err(CrosstacheError::invalid_argument(format!(
                 "Unsupported export format: {format}"
             )));
         }
     };
+    progress.finish();
 
-    // Write to output
-    match output {
-        Some(file_path) => {
-            crate::utils::helpers::write_sensitive_file(
-                std::path::Path::new(&file_path),
-                export_data.as_bytes(),
-            )
-            .map_err(|e| {
-                CrosstacheError::unknown(format!("Failed to write to output file: {e}"))
-            })?;
-            println!(
-                "Exported {} secrets to {} (permissions: owner-only)",
-                secrets.len(),
-                file_path
-            );
+    Ok(export_data)
+}
+
+/// Print the end-of-run summary of a bulk import or export to stderr: one
+/// row per outcome (`done` names success), with a few of the secrets in
+/// each, and the overall throughput.
+pub(crate) fn print_bulk_summary(recorder: &ReportRecorder, done: &str, config: &Config) {
+    use crate::utils::format::{OutputFormat, TableFormatter};
+
+    #[derive(tabled::Tabled, serde::Serialize)]
+    struct SummaryRow {
+        #[tabled(rename = "Outcome")]
+        outcome: String,
+        #[tabled(rename = "Count")]
+        count: usize,
+        #[tabled(rename = "Reason")]
+        reason: String,
+        #[tabled(rename = "Secrets")]
+        secrets: String,
+    }
+
+    const SHOWN: usize = 3;
+    let groups = recorder.groups();
+    let total: usize = groups.iter().map(|g| g.names.len()).sum();
+    if total == 0 {
+        return;
+    }
+    let rows: Vec<SummaryRow> = groups
+        .into_iter()
+        .map(|group| {
+            let mut secrets = group.names[..group.names.len().min(SHOWN)].join(", ");
+            if group.names.len() > SHOWN {
+                secrets.push_str(&format!(" (+{} more)", group.names.len() - SHOWN));
+            }
+            SummaryRow {
+                outcome: match group.status {
+                    ItemStatus::Succeeded => done.to_string(),
+                    ItemStatus::Skipped => "skipped".to_string(),
+                    ItemStatus::Failed => "failed".to_string(),
+                },
+                count: group.names.len(),
+                reason: group.detail.unwrap_or_default(),
+                secrets,
+            }
+        })
+        .collect();
+
+    let formatter = TableFormatter::new(OutputFormat::Table, config.no_color, None, None);
+    if let Ok(table) = formatter.format_table(&rows) {
+        eprintln!("{table}");
+    }
+    let elapsed = recorder.elapsed().as_secs_f64();
+    output::info(&format!(
+        "{total} secret(s) in {elapsed:.1}s ({:.1}/s)",
+        total as f64 / elapsed.max(0.001)
+    ));
+}
+
+fn record_export_item(
+    recorder: &mut ReportRecorder,
+    progress: &ItemProgress,
+    name: &str,
+    elapsed: Duration,
+    failure: Option<String>,
+) {
+    let status = if failure.is_some() {
+        progress.inc_failed();
+        ItemStatus::Failed
+    } else {
+        progress.inc();
+        ItemStatus::Succeeded
+    };
+    recorder.record(name, status, elapsed, failure);
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn execute_vault_import(
+    backend: &dyn Backend,
+    name: &str,
+    resource_group: Option<String>,
+    input: Option<String>,
+    format: &str,
+    overwrite: bool,
+    dry_run: bool,
+    report: Option<String>,
+    resume: bool,
+    config: &Config,
+) -> Result<()> {
+    let mut recorder = ReportRecorder::new("vault import").with_endpoints(
+        Some(input.clone().unwrap_or_else(|| "stdin".to_string())),
+        Some(name.to_string()),
+    );
+    let result = import_vault_secrets(
+        backend,
+        name,
+        resource_group,
+        input,
+        format,
+        overwrite,
+        dry_run,
+        resume,
+        config,
+        &mut recorder,
+    )
+    .await;
+    match report {
+        Some(path) => recorder.write_for(std::path::Path::new(&path), result),
+        None => result,
+    }
+}
+
+/// Flags for `xv vault sync`.
+struct SyncOptions {
+    dry_run: bool,
+    delete: bool,
+    group: Option<String>,
+    yes: bool,
+}
+
+async fn execute_vault_sync(
+    source: &str,
+    dest: &str,
+    options: SyncOptions,
+    report: Option<String>,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+) -> Result<()> {
+    let mut recorder = ReportRecorder::new("vault sync")
+        .with_endpoints(Some(source.to_string()), Some(dest.to_string()));
+    let result = sync_vault_secrets(source, dest, &options, config, registry, &mut recorder).await;
+    match report {
+        Some(path) => recorder.write_for(std::path::Path::new(&path), result),
+        None => result,
+    }
+}
+
+/// Read every secret of `vault` with its value, `concurrency` at a time,
+/// leaving out the reserved attachment key (it is per-vault and never
+/// comparable or copyable). Returns the secrets that were read and the
+/// names that could not be, with why. Shared by `xv vault sync` and
+/// `xv diff`.
+pub(crate) async fn read_secrets_with_values(
+    backend: &dyn Backend,
+    vault: &str,
+    group: Option<&str>,
+    concurrency: usize,
+) -> Result<(
+    Vec<crate::secret::manager::SecretProperties>,
+    Vec<(String, CrosstacheError)>,
+)> {
+    use futures::stream::{self, StreamExt};
+
+    let secrets = backend.secrets();
+    let mut summaries = secrets.list_secrets(vault, group).await?;
+    summaries.retain(|s| s.original_name != crate::secret::attachments::ATTACHMENT_KEY_SECRET);
+    let reads: Vec<_> = stream::iter(summaries)
+        .map(|summary| async move {
+            let result = secrets
+                .get_secret(vault, &summary.original_name, true)
+                .await;
+            (summary.original_name, result)
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut props = Vec::with_capacity(reads.len());
+    let mut failures = Vec::new();
+    for (name, result) in reads {
+        match result {
+            Ok(secret) => props.push(secret),
+            Err(e) => failures.push((name, e.into())),
         }
+    }
+    Ok((props, failures))
+}
+
+async fn sync_vault_secrets(
+    source: &str,
+    dest: &str,
+    options: &SyncOptions,
+    config: &Config,
+    registry: Option<&BackendRegistry>,
+    recorder: &mut ReportRecorder,
+) -> Result<()> {
+    use crate::vault::sync::{plan_sync, SyncAction};
+
+    let rebuilt_registry;
+    let reg = match registry {
+        Some(r) => r,
         None => {
-            println!("{export_data}");
+            rebuilt_registry = BackendRegistry::from_config(config)
+                .map_err(|e| CrosstacheError::config(e.to_string()))?;
+            &rebuilt_registry
         }
+    };
+    let (ws, ws_registry) = crate::cli::helpers::resolve_workspace_and_registry(config).await?;
+    let (source_backend, source_backend_name, source_vault) =
+        crate::cli::helpers::resolve_vault_ref_with_workspace(
+            source,
+            ws.as_ref(),
+            ws_registry.as_ref(),
+            reg,
+            config,
+        )
+        .await?;
+    let (dest_backend, dest_backend_name, dest_vault) =
+        crate::cli::helpers::resolve_vault_ref_with_workspace(
+            dest,
+            ws.as_ref(),
+            ws_registry.as_ref(),
+            reg,
+            config,
+        )
+        .await?;
+    if source_backend_name == dest_backend_name && source_vault == dest_vault {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Source and destination are the same vault ('{dest_vault}')"
+        )));
     }
 
-    Ok(())
+    if !options.dry_run {
+        crate::cli::helpers::preflight_permissions(
+            config,
+            dest_backend.as_ref(),
+            &dest_vault,
+            None,
+            &[VaultPermission::Write],
+            "sync secrets",
+        )
+        .await?;
+    }
+
+    let group = options.group.as_deref();
+    let (source_secrets, source_failures) = read_secrets_with_values(
+        source_backend.as_ref(),
+        &source_vault,
+        group,
+        config.fetch_concurrency,
+    )
+    .await?;
+    let (dest_secrets, dest_failures) = read_secrets_with_values(
+        dest_backend.as_ref(),
+        &dest_vault,
+        group,
+        config.fetch_concurrency,
+    )
+    .await?;
+    let mut read_failures = 0;
+    for (vault, failures) in [
+        (&source_vault, source_failures),
+        (&dest_vault, dest_failures),
+    ] {
+        for (name, e) in failures {
+            output::error(&format!(
+                "Failed to read '{name}' from vault '{vault}': {e}"
+            ));
+            recorder.record(
+                &name,
+                ItemStatus::Failed,
+                Duration::ZERO,
+                Some(e.to_string()),
+            );
+            read_failures += 1;
+        }
+    }
+    if read_failures > 0 && options.delete {
+        // A secret we could not read from the source would look like an
+        // extra in the destination and be deleted.
+        return Err(CrosstacheError::unknown(format!(
+            "vault sync: {read_failures} secret(s) could not be read; not syncing with --delete"
+        )));
+    }
+
+    let plan = plan_sync(&source_secrets, &dest_secrets, options.delete);
+    if plan.is_empty() {
+        output::success(&format!(
+            "Vault '{dest_vault}' is in sync with '{source_vault}'"
+        ));
+        return if read_failures > 0 {
+            Err(CrosstacheError::unknown(format!(
+                "vault sync: {read_failures} secret(s) could not be read"
+            )))
+        } else {
+            Ok(())
+        };
+    }
+
+    let deletes = plan
+        .iter()
+        .filter(|item| item.action == SyncAction::Delete)
+        .count();
+    output::info(&format!(
+        "{}Syncing '{source_vault}' -> '{dest_vault}': {} change(s)",
+        if options.dry_run { "Dry run: " } else { "" },
+        plan.len()
+    ));
+    for item in &plan {
+        match &item.action {
+            SyncAction::Create => println!("  + {}", item.name),
+            SyncAction::Update(fields) => println!("  ~ {} ({})", item.name, fields.join(", ")),
+            SyncAction::Delete => println!("  - {}", item.name),
+        }
+    }
+
+    if options.dry_run {
+        for item in &plan {
+            recorder.record(
+                &item.name,
+                ItemStatus::Skipped,
+                Duration::ZERO,
+                Some("dry run".to_string()),
+            );
+        }
+        return Ok(());
+    }
+
+    if deletes > 0
+        && !crate::cli::helpers::confirm_proceed(
+            options.yes,
+            &format!("Delete {deletes} secret(s) from vault '{dest_vault}'?"),
+            "--yes",
+        )?
+    {
+        output::info("Aborted; nothing synced.");
+        return Ok(());
+    }
+
+    let source_by_name: std::collections::HashMap<&str, _> = source_secrets
+        .iter()
+        .map(|s| (s.original_name.as_str(), s))
+        .collect();
+    let dest_secrets_backend = dest_backend.secrets();
+    let progress = ItemProgress::new(plan.len() as u64, output::is_tty_stderr());
+    let (mut written, mut deleted, mut failed) = (0, 0, read_failures);
+
+    for item in &plan {
+        let started = Instant::now();
+        let result = match item.action {
+            SyncAction::Delete => dest_secrets_backend
+                .delete_secret(&dest_vault, &item.name)
+                .await
+                .map_err(CrosstacheError::from),
+            SyncAction::Create | SyncAction::Update(_) => {
+                let request = crate::backend::secret::rename_request_from_properties(
+                    &item.name,
+                    source_by_name[item.name.as_str()],
+                )
+                .map_err(CrosstacheError::from);
+                match request.and_then(|request| {
+                    crate::cli::secret_ops::check_dest_tag_budget(dest_backend.as_ref(), &request)
+                        .map(|()| request)
+                }) {
+                    Ok(request) => dest_secrets_backend
+                        .set_secret(&dest_vault, request)
+                        .await
+                        .map(|_| ())
+                        .map_err(CrosstacheError::from),
+                    Err(e) => Err(e),
+                }
+            }
+        };
+        let verb = match item.action {
+            SyncAction::Create => "Created",
+            SyncAction::Update(_) => "Updated",
+            SyncAction::Delete => "Deleted",
+        };
+        match result {
+            Ok(()) => {
+                progress.item(Level::Success, &format!("{verb} secret: {}", item.name));
+                recorder.record(&item.name, ItemStatus::Succeeded, started.elapsed(), None);
+                progress.inc();
+                if item.action == SyncAction::Delete {
+                    deleted += 1;
+                } else {
+                    written += 1;
+                }
+            }
+            Err(e) => {
+                progress.notice(
+                    Level::Error,
+                    &format!("Failed to sync secret '{}': {e}", item.name),
+                );
+                recorder.record(
+                    &item.name,
+                    ItemStatus::Failed,
+                    started.elapsed(),
+                    Some(e.to_string()),
+                );
+                progress.inc_failed();
+                failed += 1;
+            }
+        }
+    }
+    progress.finish();
+
+    if written + deleted > 0 {
+        crate::cli::secret_ops::invalidate_trait_secret_cache(
+            config,
+            &dest_backend_name,
+            &dest_vault,
+        );
+    }
+
+    print_bulk_summary(recorder, "synced", config);
+    let summary = format!("Sync completed: {written} written, {deleted} deleted, {failed} failed");
+    if failed > 0 {
+        output::warn(&summary);
+        Err(CrosstacheError::unknown(format!(
+            "vault sync: {failed} secret(s) failed to sync into vault '{dest_vault}'"
+        )))
+    } else {
+        output::success(&summary);
+        Ok(())
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
-async fn execute_vault_import(
+async fn import_vault_secrets(
     backend: &dyn Backend,
     name: &str,
     resource_group: Option<String>,
@@ -1026,14 +2166,38 @@ async fn execute_vault_import(
     format: &str,
     overwrite: bool,
     dry_run: bool,
+    resume: bool,
     config: &Config,
+    recorder: &mut ReportRecorder,
 ) -> Result<()> {
     use crate::secret::manager::SecretRequest;
+    use crate::utils::checkpoint::Checkpoint;
+    use crate::utils::interrupt;
     use std::fs;
     use std::io::{self, Read};
 
+    if !dry_run {
+        crate::cli::helpers::preflight_permissions(
+            config,
+            backend,
+            name,
+            resource_group.as_deref(),
+            &[VaultPermission::Write],
+            "import secrets",
+        )
+        .await?;
+    }
     let _resource_group = resource_group.unwrap_or_else(|| config.default_resource_group.clone());
 
+    // Checkpoints are keyed by target vault and input file; stdin imports
+    // have no stable identity and are never checkpointed.
+    let checkpoint_scope = input.as_deref().map(|file_path| {
+        let file_path = fs::canonicalize(file_path)
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| file_path.to_string());
+        format!("{name}\n{file_path}")
+    });
+
     // Read import data
     let import_data = match input {
         Some(file_path) => fs::read_to_string(file_path)
@@ -1050,51 +2214,37 @@ async fn execute_vault_import(
     // Parse import data based on format
     let secrets_to_import = match format.to_lowercase().as_str() {
         "json" => {
-            let json_data: serde_json::Value = serde_json::from_str(&import_data).map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse JSON: {e}"))
-            })?;
-
-            let secrets_array = json_data
-                .get("secrets")
-                .and_then(|s| s.as_array())
-                .ok_or_else(|| CrosstacheError::serialization("Missing 'secrets' array in JSON"))?;
+            let export = crate::vault::export_format::parse_export(&import_data)?;
+            tracing::debug!(
+                "import: format_version {} export of vault {:?} taken {:?}",
+                export.format_version,
+                export.vault,
+                export.exported_at
+            );
 
             let mut secrets = Vec::new();
-            for secret_value in secrets_array {
-                let secret_obj = secret_value.as_object().ok_or_else(|| {
-                    CrosstacheError::serialization("Invalid secret object in JSON")
-                })?;
-
-                let name = secret_obj
-                    .get("name")
-                    .and_then(|n| n.as_str())
-                    .ok_or_else(|| CrosstacheError::serialization("Missing secret name"))?;
-
-                let value = match secret_obj.get("value").and_then(|v| v.as_str()) {
-                    Some(v) => v,
-                    None => {
-                        eprintln!(
-                            "Warning: Skipping secret '{}' — no value in export (was it exported with --include-values?)",
-                            name
-                        );
-                        continue;
-                    }
+            for secret in export.secrets {
+                let Some(value) = secret.value else {
+                    eprintln!(
+                        "Warning: Skipping secret '{}' — no value in export (was it exported with --include-values?)",
+                        secret.name
+                    );
+                    recorder.record(
+                        &secret.name,
+                        ItemStatus::Skipped,
+                        Duration::ZERO,
+                        Some("no value in export".to_string()),
+                    );
+                    continue;
                 };
 
-                let content_type = secret_obj
-                    .get("content_type")
-                    .and_then(|ct| ct.as_str())
-                    .map(|s| s.to_string());
-
-                let enabled = secret_obj.get("enabled").and_then(|e| e.as_bool());
-
                 secrets.push(SecretRequest {
-                    name: name.to_string(),
-                    value: Zeroizing::new(value.to_string()),
-                    content_type,
-                    enabled,
-                    expires_on: None,
-                    not_before: None,
+                    name: secret.name,
+                    value: Zeroizing::new(value),
+                    content_type: secret.content_type,
+                    enabled: secret.enabled,
+                    expires_on: secret.expires_on,
+                    not_before: secret.not_before,
                     tags: None,
                     groups: None,
                     note: None,
@@ -1193,27 +2343,71 @@ async fn execute_vault_import(
         ));
         for secret in &secrets_to_import {
             println!("  - {}", secret.name);
+            recorder.record(
+                &secret.name,
+                ItemStatus::Skipped,
+                Duration::ZERO,
+                Some("dry run".to_string()),
+            );
         }
         return Ok(());
     }
 
     // Import secrets through the active backend's secret trait.
     let secrets_backend = backend.secrets();
+    let inherited_tags = crate::cli::helpers::inherited_vault_tags(backend, name, config).await;
 
     let mut imported_count = 0;
     let mut skipped_count = 0;
     let mut failed_count = 0;
 
-    for secret_request in secrets_to_import {
+    let mut checkpoint = match &checkpoint_scope {
+        Some(scope) => Some(Checkpoint::open("vault import", scope, resume)?),
+        None => None,
+    };
+    let _interrupt_guard = interrupt::watch();
+    let names: Vec<String> = secrets_to_import.iter().map(|s| s.name.clone()).collect();
+    let mut remaining: &[String] = &[];
+    let progress = ItemProgress::new(names.len() as u64, output::is_tty_stderr());
+
+    for (index, mut secret_request) in secrets_to_import.into_iter().enumerate() {
+        if interrupt::requested() {
+            remaining = &names[index..];
+            break;
+        }
+        let started = Instant::now();
         let secret_name = secret_request.name.clone();
+        crate::cli::helpers::apply_inherited_tags(&mut secret_request, &inherited_tags);
+
+        if checkpoint.as_ref().is_some_and(|c| c.is_done(&secret_name)) {
+            recorder.record(
+                &secret_name,
+                ItemStatus::Skipped,
+                Duration::ZERO,
+                Some("imported by an earlier run (--resume)".to_string()),
+            );
+            progress.inc();
+            skipped_count += 1;
+            continue;
+        }
 
         // Never let an imported entry silently clobber the reserved
         // attachment encryption key — same convention as bulk `xv set`.
         if secret_name == crate::secret::attachments::ATTACHMENT_KEY_SECRET {
-            output::warn(&format!(
-                "Skipping '{secret_name}': reserved for attachment encryption; use 'xv set {secret_name}' \
-                 (single-secret form) to overwrite it interactively"
-            ));
+            progress.notice(
+                Level::Warn,
+                &format!(
+                    "Skipping '{secret_name}': reserved for attachment encryption; use 'xv set {secret_name}' \
+                     (single-secret form) to overwrite it interactively"
+                ),
+            );
+            recorder.record(
+                &secret_name,
+                ItemStatus::Skipped,
+                started.elapsed(),
+                Some("reserved for attachment encryption".to_string()),
+            );
+            progress.inc();
             skipped_count += 1;
             continue;
         }
@@ -1222,7 +2416,17 @@ async fn execute_vault_import(
         if !overwrite {
             match secrets_backend.secret_exists(name, &secret_name).await {
                 Ok(true) => {
-                    output::hint(&format!("Skipping existing secret: {secret_name}"));
+                    progress.item(
+                        Level::Hint,
+                        &format!("Skipping existing secret: {secret_name}"),
+                    );
+                    recorder.record(
+                        &secret_name,
+                        ItemStatus::Skipped,
+                        started.elapsed(),
+                        Some("already exists".to_string()),
+                    );
+                    progress.inc();
                     skipped_count += 1;
                     continue;
                 }
@@ -1238,26 +2442,31 @@ async fn execute_vault_import(
 
         match secrets_backend.set_secret(name, secret_request).await {
             Ok(_) => {
-                output::success(&format!("Imported secret: {secret_name}"));
+                progress.item(Level::Success, &format!("Imported secret: {secret_name}"));
+                recorder.record(&secret_name, ItemStatus::Succeeded, started.elapsed(), None);
+                progress.inc();
+                if let Some(checkpoint) = checkpoint.as_mut() {
+                    checkpoint.mark_done(&secret_name);
+                }
                 imported_count += 1;
             }
             Err(e) => {
-                output::error(&format!("Failed to import secret '{secret_name}': {e}"));
+                progress.notice(
+                    Level::Error,
+                    &format!("Failed to import secret '{secret_name}': {e}"),
+                );
+                recorder.record(
+                    &secret_name,
+                    ItemStatus::Failed,
+                    started.elapsed(),
+                    Some(e.to_string()),
+                );
+                progress.inc_failed();
                 failed_count += 1;
             }
         }
     }
-
-    // Don't dress a partial failure up as success: use a warning summary when
-    // any secret failed (the non-zero exit is returned below), and reserve the
-    // `[ok]` success line for a fully clean import.
-    let summary =
-        format!("Import completed: {imported_count} imported, {skipped_count} skipped, {failed_count} failed");
-    if failed_count > 0 {
-        output::warn(&summary);
-    } else {
-        output::success(&summary);
-    }
+    progress.finish();
 
     // Invalidate the secrets list cache for the target vault. Import is an
     // Azure-legacy-only path (see `use_vault_trait_path`'s doc comment at
@@ -1272,15 +2481,43 @@ async fn execute_vault_import(
         });
     }
 
+    for name in remaining {
+        recorder.record(
+            name,
+            ItemStatus::Skipped,
+            Duration::ZERO,
+            Some("not processed (interrupted)".to_string()),
+        );
+    }
+
+    print_bulk_summary(recorder, "imported", config);
+
+    // Don't dress a partial failure up as success: use a warning summary when
+    // any secret failed (the non-zero exit is returned below), and reserve the
+    // `[ok]` success line for a fully clean import.
+    let summary =
+        format!("Import completed: {imported_count} imported, {skipped_count} skipped, {failed_count} failed");
+    if failed_count > 0 {
+        output::warn(&summary);
+    } else {
+        output::success(&summary);
+    }
+
     // Any failed secret import must surface as a non-zero exit so scripted
     // imports don't silently drop secrets.
-    if failed_count > 0 {
-        return Err(CrosstacheError::unknown(format!(
+    let outcome = if !remaining.is_empty() {
+        Err(interrupt::stopped(names.len() - remaining.len(), remaining))
+    } else if failed_count > 0 {
+        Err(CrosstacheError::unknown(format!(
             "vault import: {failed_count} secret(s) failed to import into vault '{name}'"
-        )));
+        )))
+    } else {
+        Ok(())
+    };
+    if let Some(checkpoint) = checkpoint {
+        checkpoint.finish(&outcome);
     }
-
-    Ok(())
+    outcome
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1357,13 +2594,52 @@ async fn check_vault_rbac_mode(
     Ok(())
 }
 
+/// How often `vault share invite` checks whether a new guest is assignable.
+const INVITE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Parse a `vault share` access level.
+fn parse_access_level(level: &str) -> Result<crate::vault::models::AccessLevel> {
+    use crate::vault::models::AccessLevel;
+    match level.to_lowercase().as_str() {
+        "reader" | "read" => Ok(AccessLevel::Reader),
+        "contributor" | "write" => Ok(AccessLevel::Contributor),
+        "admin" | "administrator" => Ok(AccessLevel::Admin),
+        _ => Err(CrosstacheError::invalid_argument(format!(
+            "Invalid access level: {level}"
+        ))),
+    }
+}
+
+/// Whether a role assignment failed only because ARM cannot see a new
+/// principal yet (`PrincipalNotFound`), so retrying will succeed.
+fn is_principal_not_found(message: &str) -> bool {
+    message.contains("PrincipalNotFound") || message.contains("does not exist in the directory")
+}
+
+/// Resolve a `vault share` target to a principal object id:
+/// `group:<name-or-id>` is a directory security group, anything else a user
+/// or service principal.
+async fn resolve_share_principal(
+    vault_backend: &dyn crate::backend::vault::VaultBackend,
+    target: &str,
+) -> Result<String> {
+    let resolved = match target.strip_prefix("group:") {
+        Some(group) if group.trim().is_empty() => {
+            return Err(CrosstacheError::invalid_argument(
+                "'group:' needs a group name or object id",
+            ))
+        }
+        Some(group) => vault_backend.resolve_group(group.trim()).await?,
+        None => vault_backend.resolve_principal(target).await?,
+    };
+    Ok(resolved)
+}
+
 async fn execute_vault_share(
     vault_backend: &dyn crate::backend::vault::VaultBackend,
     command: VaultShareCommands,
     config: &Config,
 ) -> Result<()> {
-    use crate::vault::models::AccessLevel;
-
     match command {
         VaultShareCommands::Grant {
             vault_name,
@@ -1378,21 +2654,12 @@ async fn execute_vault_share(
 
             check_vault_rbac_mode(vault_backend, &vault_name, resource_group).await?;
 
-            let object_id = vault_backend.resolve_principal(&user).await?;
+            let object_id = resolve_share_principal(vault_backend, &user).await?;
             if object_id != user {
                 println!("Resolved '{}' to object ID '{}'", user, object_id);
             }
 
-            let access_level = match level.to_lowercase().as_str() {
-                "reader" | "read" => AccessLevel::Reader,
-                "contributor" | "write" => AccessLevel::Contributor,
-                "admin" | "administrator" => AccessLevel::Admin,
-                _ => {
-                    return Err(CrosstacheError::invalid_argument(format!(
-                        "Invalid access level: {level}"
-                    )));
-                }
-            };
+            let access_level = parse_access_level(&level)?;
 
             // Output parity with the retired `VaultManager::grant_vault_access`
             // (which framed the trait call with these info/success lines).
@@ -1407,6 +2674,94 @@ async fn execute_vault_share(
                 "Successfully granted {access_level_str} access to vault '{vault_name}' for user '{user}'"
             ));
         }
+        VaultShareCommands::Invite {
+            vault_name,
+            email,
+            resource_group,
+            level,
+            redirect_url,
+            no_email,
+            wait,
+        } => {
+            let resource_group = resource_group.as_deref();
+            let access_level = parse_access_level(&level)?;
+            if !email.contains('@') {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "'{email}' is not an email address"
+                )));
+            }
+            check_vault_rbac_mode(vault_backend, &vault_name, resource_group).await?;
+
+            let invitation = vault_backend
+                .invite_guest(&email, &redirect_url, !no_email)
+                .await?;
+            if invitation.status.eq_ignore_ascii_case("Completed") {
+                output::info(&format!(
+                    "'{email}' is already in the directory (object ID '{}')",
+                    invitation.user_id
+                ));
+            } else if no_email {
+                output::success(&format!(
+                    "Created a guest invitation for '{email}' (object ID '{}')",
+                    invitation.user_id
+                ));
+            } else {
+                output::success(&format!(
+                    "Emailed a guest invitation to '{email}' (object ID '{}')",
+                    invitation.user_id
+                ));
+            }
+
+            // A fresh guest takes a while to replicate through the directory,
+            // and longer still before role assignments accept it.
+            let deadline = Instant::now() + Duration::from_secs(wait);
+            let timed_out = || {
+                CrosstacheError::connection_timeout(format!(
+                    "Guest '{email}' was invited but did not become assignable within {wait}s. \
+                     Once it has replicated, run: xv vault share grant {vault_name} {} --level {level}",
+                    invitation.user_id
+                ))
+            };
+            while !vault_backend.principal_exists(&invitation.user_id).await? {
+                if Instant::now() >= deadline {
+                    return Err(timed_out());
+                }
+                tokio::time::sleep(INVITE_POLL_INTERVAL).await;
+            }
+
+            let access_level_str = access_level_display(&access_level);
+            output::info(&format!(
+                "Granting {access_level_str} access to vault '{vault_name}' for guest '{email}'..."
+            ));
+            loop {
+                match vault_backend
+                    .grant_access(
+                        &vault_name,
+                        resource_group,
+                        &invitation.user_id,
+                        access_level.clone(),
+                    )
+                    .await
+                {
+                    Ok(()) => break,
+                    Err(e) if is_principal_not_found(&e.to_string()) => {
+                        if Instant::now() >= deadline {
+                            return Err(timed_out());
+                        }
+                        tokio::time::sleep(INVITE_POLL_INTERVAL).await;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            output::success(&format!(
+                "Successfully granted {access_level_str} access to vault '{vault_name}' for guest '{email}'"
+            ));
+            if no_email {
+                if let Some(url) = &invitation.redeem_url {
+                    println!("Send the guest this link to accept the invitation:\n{url}");
+                }
+            }
+        }
         VaultShareCommands::Revoke {
             vault_name,
             user,
@@ -1416,7 +2771,7 @@ async fn execute_vault_share(
 
             check_vault_rbac_mode(vault_backend, &vault_name, resource_group).await?;
 
-            let object_id = vault_backend.resolve_principal(&user).await?;
+            let object_id = resolve_share_principal(vault_backend, &user).await?;
             if object_id != user {
                 println!("Resolved '{}' to object ID '{}'", user, object_id);
             }
@@ -1442,9 +2797,7 @@ async fn execute_vault_share(
             use crate::utils::pagination::{paginate_slice, pagination_footer_text, Pagination};
             use std::fmt::Write as _;
 
-            let pager = pager
-                .map(crate::cli::commands::PagerWhen::wants_pager)
-                .unwrap_or(false);
+            let pager = crate::cli::commands::PagerWhen::resolve(pager, config);
             let resource_group = resource_group.as_deref();
 
             check_vault_rbac_mode(vault_backend, &vault_name, resource_group).await?;
@@ -1516,7 +2869,56 @@ async fn execute_vault_share(
 
 #[cfg(test)]
 mod tests {
-    use super::{format_env_line, is_valid_env_key, shell_single_quote};
+    use super::{
+        bundle_env_lines, describe_policy_finding, format_env_line, is_principal_not_found,
+        is_valid_env_key, parse_access_level, shell_single_quote,
+    };
+    use crate::vault::models::{AccessLevel, PolicyFinding};
+
+    #[test]
+    fn access_levels_accept_role_and_verb_spellings() {
+        assert_eq!(parse_access_level("Reader").unwrap(), AccessLevel::Reader);
+        assert_eq!(
+            parse_access_level("write").unwrap(),
+            AccessLevel::Contributor
+        );
+        assert_eq!(
+            parse_access_level("administrator").unwrap(),
+            AccessLevel::Admin
+        );
+        assert!(parse_access_level("owner").is_err());
+    }
+
+    #[test]
+    fn unreplicated_principals_are_retried() {
+        assert!(is_principal_not_found(
+            "HTTP 400: Principal 0f1e does not exist in the directory 72f9."
+        ));
+        assert!(is_principal_not_found("PrincipalNotFound"));
+        assert!(!is_principal_not_found("HTTP 403: AuthorizationFailed"));
+    }
+
+    #[test]
+    fn policy_findings_read_as_sentences() {
+        let finding = |effect: &str, detail: Option<&str>| PolicyFinding {
+            effect: effect.to_string(),
+            field: Some("properties.publicNetworkAccess".to_string()),
+            policy: Some("kv-private-only".to_string()),
+            detail: detail.map(str::to_string),
+        };
+        assert_eq!(
+            describe_policy_finding(&finding("Required", Some("Disabled"))),
+            "Policy 'kv-private-only' will set properties.publicNetworkAccess to Disabled"
+        );
+        assert_eq!(
+            describe_policy_finding(&finding("Deny", None)),
+            "Policy 'kv-private-only' denies properties.publicNetworkAccess"
+        );
+        assert_eq!(
+            describe_policy_finding(&finding("Audit", None)),
+            "Policy 'kv-private-only' reports properties.publicNetworkAccess (audit)"
+        );
+    }
 
     fn adversarial_values() -> Vec<(&'static str, &'static str)> {
         vec![
@@ -1573,6 +2975,23 @@ mod tests {
         assert_eq!(shell_single_quote(""), "''");
     }
 
+    #[test]
+    fn bundle_exports_one_env_line_per_key() {
+        let value = r#"{"db.host":"db1","port":"5432"}"#;
+        assert_eq!(
+            bundle_env_lines(
+                "APP_CONFIG",
+                crate::secret::bundle::BUNDLE_CONTENT_TYPE,
+                value
+            ),
+            Some(vec![
+                "APP_CONFIG_DB_HOST='db1'".to_string(),
+                "APP_CONFIG_PORT='5432'".to_string()
+            ])
+        );
+        assert_eq!(bundle_env_lines("APP_CONFIG", "", value), None);
+    }
+
     #[test]
     fn format_env_line_quotes_value() {
         assert_eq!(format_env_line("KEY", "v'al"), "KEY='v'\\''al'");
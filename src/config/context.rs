@@ -377,28 +377,9 @@ impl ContextManager {
             }
         }
 
-        // Use XDG Base Directory specification on Linux and macOS
-        // On Windows, use the platform-appropriate config directory
-        #[cfg(any(target_os = "linux", target_os = "macos"))]
-        {
-            use std::env;
-            let config_dir = if let Ok(xdg_config_home) = env::var("XDG_CONFIG_HOME") {
-                PathBuf::from(xdg_config_home)
-            } else {
-                let home_dir = env::var("HOME")
-                    .map_err(|_| CrosstacheError::config("HOME environment variable not set"))?;
-                PathBuf::from(home_dir).join(".config")
-            };
-            Ok(config_dir.join("xv").join("context"))
-        }
-
-        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
-        {
-            // Use platform-appropriate config directory for other platforms
-            let config_dir = dirs::config_dir()
-                .ok_or_else(|| CrosstacheError::config("Could not determine config directory"))?;
-            Ok(config_dir.join("xv").join("context"))
-        }
+        let config_dir = crate::utils::environment::config_dir()
+            .ok_or_else(|| CrosstacheError::config("Could not determine config directory"))?;
+        Ok(config_dir.join("context"))
     }
 
     /// Check if local context directory exists
@@ -14,6 +14,9 @@ use crate::vault::manager::VaultManager;
 use crate::vault::models::VaultCreateRequest;
 use std::sync::Arc;
 
+/// Blob container `xv init` creates in a new storage account.
+pub const DEFAULT_CONTAINER: &str = "crosstache-files";
+
 /// Interactive configuration initialization
 pub struct ConfigInitializer {
     prompt: InteractivePrompt,
@@ -40,6 +43,64 @@ pub struct InitConfig {
     pub aws_default_vault: Option<String>,
 }
 
+/// Values for `xv init` without prompts (Azure). Checked with the same
+/// validators the interactive prompts use.
+#[derive(Debug, Clone, Default)]
+pub struct UnattendedInit {
+    pub subscription_id: String,
+    /// Looked up from the `az` CLI's subscription list when absent.
+    pub tenant_id: Option<String>,
+    pub resource_group: String,
+    pub location: String,
+    pub vault: String,
+    /// Create the vault instead of configuring an existing one.
+    pub create_vault: bool,
+    /// Storage account for file operations; created when it does not exist.
+    pub storage_account: Option<String>,
+    pub container: String,
+}
+
+impl UnattendedInit {
+    /// Check every value up front, so nothing is provisioned for a run that
+    /// would fail on a later flag.
+    pub fn validate(&self) -> Result<()> {
+        let check =
+            |flag: &str, value: &str, validator: fn(&str) -> std::result::Result<(), String>| {
+                if value.trim().is_empty() {
+                    return Err(CrosstacheError::config(format!(
+                        "xv init without prompts needs {flag}"
+                    )));
+                }
+                validator(value).map_err(|e| CrosstacheError::config(format!("{flag}: {e}")))
+            };
+        check(
+            "--subscription",
+            &self.subscription_id,
+            SetupHelper::validate_subscription_id,
+        )?;
+        check(
+            "--resource-group",
+            &self.resource_group,
+            SetupHelper::validate_resource_group_name,
+        )?;
+        check("--location", &self.location, |_| Ok(()))?;
+        check("--vault", &self.vault, SetupHelper::validate_vault_name)?;
+        if let Some(account) = &self.storage_account {
+            check(
+                "--storage-account",
+                account,
+                SetupHelper::validate_storage_account_name,
+            )?;
+            check(
+                "--container",
+                &self.container,
+                SetupHelper::validate_container_name,
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl ConfigInitializer {
     /// Create a new configuration initializer
     pub fn new() -> Self {
@@ -157,6 +218,164 @@ impl ConfigInitializer {
         Ok(config)
     }
 
+    /// Run the Azure setup from flags: create the resource group if it is
+    /// missing, set up blob storage when a storage account is given, create
+    /// the vault when asked, then save the configuration.
+    pub async fn run_unattended_setup(&self, options: UnattendedInit) -> Result<Config> {
+        options.validate()?;
+        let UnattendedInit {
+            subscription_id,
+            tenant_id,
+            resource_group,
+            location,
+            vault,
+            create_vault,
+            storage_account,
+            container,
+        } = options;
+        let subscription_id = subscription_id.trim().to_string();
+
+        let tenant_id = match tenant_id.filter(|t| !t.trim().is_empty()) {
+            Some(tenant_id) => tenant_id,
+            None => AzureDetector::detect_environment()
+                .await?
+                .subscriptions
+                .into_iter()
+                .find(|s| s.id.eq_ignore_ascii_case(&subscription_id))
+                .map(|s| s.tenant_id)
+                .ok_or_else(|| {
+                    CrosstacheError::config(format!(
+                        "Subscription '{subscription_id}' is not in the Azure CLI's subscription list; \
+                         sign in with 'az login' or pass --tenant"
+                    ))
+                })?,
+        };
+        let subscription = AzureSubscription {
+            id: subscription_id,
+            name: String::new(),
+            tenant_id,
+            is_default: false,
+            state: String::new(),
+        };
+
+        if !AzureDetector::resource_group_exists(&subscription.id, &resource_group)
+            .await
+            .unwrap_or(false)
+        {
+            let progress = ProgressIndicator::new("Creating resource group...");
+            AzureDetector::create_resource_group(&subscription.id, &resource_group, &location)
+                .await?;
+            progress.finish_success(&format!("Created resource group '{resource_group}'"));
+        }
+
+        let (storage_account_name, blob_container_name) = match storage_account {
+            Some(account) => {
+                self.provision_storage(
+                    &account,
+                    &container,
+                    &subscription,
+                    &resource_group,
+                    &location,
+                )
+                .await?;
+                (account, container)
+            }
+            None => (String::new(), String::new()),
+        };
+
+        if create_vault {
+            self.create_test_vault(&vault, &subscription, &resource_group, &location)
+                .await?;
+        }
+
+        let init_config = InitConfig {
+            subscription_id: subscription.id,
+            tenant_id: subscription.tenant_id,
+            default_resource_group: resource_group,
+            default_location: location,
+            default_vault: Some(vault),
+            create_test_vault: create_vault,
+            create_storage_account: !storage_account_name.is_empty(),
+            storage_account_name,
+            blob_container_name,
+            backend_choice: "azure".to_string(),
+            aws_region: None,
+            aws_profile: None,
+            aws_default_vault: None,
+        };
+        let config = self.build_config(init_config).await?;
+        self.save_config(&config).await?;
+        output::success("Setup completed successfully!");
+        Ok(config)
+    }
+
+    /// `xv init --blob-only`: add (or replace) the blob storage of an
+    /// existing Azure setup, leaving the rest of the configuration as is.
+    /// `storage` is the `(account, container)` given by flags; without it
+    /// the account and container are prompted for.
+    pub async fn run_blob_only_setup(
+        &self,
+        mut config: Config,
+        storage: Option<(String, String)>,
+    ) -> Result<Config> {
+        use crate::config::settings::BlobConfig;
+
+        if let Some(backend) = config.backend.as_deref().filter(|b| *b != "azure") {
+            return Err(CrosstacheError::config(format!(
+                "xv init --blob-only sets up Azure blob storage; the active backend is '{backend}'"
+            )));
+        }
+        if config.subscription_id.is_empty() || config.default_resource_group.is_empty() {
+            return Err(CrosstacheError::config(
+                "xv init --blob-only needs an existing Azure setup; run 'xv init' first",
+            ));
+        }
+        let subscription = AzureSubscription {
+            id: config.subscription_id.clone(),
+            name: String::new(),
+            tenant_id: config.tenant_id.clone(),
+            is_default: false,
+            state: String::new(),
+        };
+        let resource_group = config.default_resource_group.clone();
+        let location = if config.default_location.is_empty() {
+            self.configure_location(&subscription).await?
+        } else {
+            config.default_location.clone()
+        };
+
+        let (storage_account, container_name) = match storage {
+            Some((account, container)) => {
+                SetupHelper::validate_storage_account_name(&account)
+                    .map_err(|e| CrosstacheError::config(format!("--storage-account: {e}")))?;
+                SetupHelper::validate_container_name(&container)
+                    .map_err(|e| CrosstacheError::config(format!("--container: {e}")))?;
+                self.provision_storage(
+                    &account,
+                    &container,
+                    &subscription,
+                    &resource_group,
+                    &location,
+                )
+                .await?;
+                (account, container)
+            }
+            None => {
+                self.choose_blob_storage(&subscription, &resource_group, &location)
+                    .await?
+            }
+        };
+
+        config.blob_config = Some(BlobConfig {
+            storage_account,
+            container_name,
+            endpoint: None,
+            ..config.blob_config.take().unwrap_or_default()
+        });
+        self.save_config(&config).await?;
+        Ok(config)
+    }
+
     /// Run the simplified local backend setup (3 steps).
     async fn run_local_setup(&self) -> Result<Config> {
         // Step 1: Store path
@@ -537,6 +756,20 @@ impl ConfigInitializer {
             return Ok((String::new(), String::new(), false));
         }
 
+        let (storage_name, container_name) = self
+            .choose_blob_storage(subscription, resource_group, location)
+            .await?;
+        Ok((storage_name, container_name, true))
+    }
+
+    /// Prompt for a storage account (existing or new) and container, and
+    /// create whichever does not exist yet.
+    async fn choose_blob_storage(
+        &self,
+        subscription: &AzureSubscription,
+        resource_group: &str,
+        location: &str,
+    ) -> Result<(String, String)> {
         let progress = ProgressIndicator::new("Loading existing storage accounts...");
 
         // Try to get existing storage accounts in the resource group
@@ -586,7 +819,7 @@ impl ConfigInitializer {
 
         let container_name = self.prompt.input_text_validated(
             "Enter container name for files",
-            Some("crosstache-files"),
+            Some(DEFAULT_CONTAINER),
             SetupHelper::validate_container_name,
         )?;
 
@@ -600,7 +833,32 @@ impl ConfigInitializer {
                 .await?;
         }
 
-        Ok((storage_name, container_name, true))
+        Ok((storage_name, container_name))
+    }
+
+    /// Create storage account `account` unless it exists, and `container`
+    /// in it unless the new account already came with it.
+    async fn provision_storage(
+        &self,
+        account: &str,
+        container: &str,
+        subscription: &AzureSubscription,
+        resource_group: &str,
+        location: &str,
+    ) -> Result<()> {
+        let exists = AzureDetector::storage_account_exists(&subscription.id, account)
+            .await
+            .unwrap_or(false);
+        if !exists {
+            self.create_storage_account(account, subscription, resource_group, location)
+                .await?;
+        }
+        // A new account comes with the default container only.
+        if exists || container != DEFAULT_CONTAINER {
+            self.create_blob_container(account, container, subscription)
+                .await?;
+        }
+        Ok(())
     }
 
     /// Create storage account and container
@@ -692,7 +950,7 @@ impl ConfigInitializer {
                     "container",
                     "create",
                     "--name",
-                    "crosstache-files",
+                    DEFAULT_CONTAINER,
                     "--account-name",
                     storage_name,
                     "--subscription",
@@ -725,7 +983,7 @@ impl ConfigInitializer {
         // Always verify if the container actually exists, regardless of command result
         progress.set_message("Verifying container creation...");
         let container_exists =
-            AzureDetector::container_exists(&subscription.id, storage_name, "crosstache-files")
+            AzureDetector::container_exists(&subscription.id, storage_name, DEFAULT_CONTAINER)
                 .await
                 .unwrap_or(false);
 
@@ -1001,6 +1259,8 @@ impl ConfigInitializer {
                 chunk_size_mb: 4,
                 max_concurrent_uploads: 3,
                 progress_threshold_mb: 5,
+                transfer_retries: 3,
+                key_secret: None,
             })
         } else {
             None
@@ -1147,6 +1407,41 @@ mod tests {
         assert!(init_config.default_vault.is_some());
     }
 
+    #[test]
+    fn unattended_init_validates_every_flag_before_provisioning() {
+        let options = UnattendedInit {
+            subscription_id: "00000000-0000-0000-0000-000000000000".to_string(),
+            tenant_id: None,
+            resource_group: "rg-dev".to_string(),
+            location: "eastus".to_string(),
+            vault: "kv-dev".to_string(),
+            create_vault: false,
+            storage_account: Some("stdev01".to_string()),
+            container: DEFAULT_CONTAINER.to_string(),
+        };
+        options.validate().unwrap();
+
+        let missing = UnattendedInit {
+            vault: String::new(),
+            ..options.clone()
+        };
+        let err = missing.validate().unwrap_err();
+        assert!(err.to_string().contains("--vault"), "{err}");
+
+        let bad_storage = UnattendedInit {
+            storage_account: Some("Not_Valid".to_string()),
+            ..options.clone()
+        };
+        let err = bad_storage.validate().unwrap_err();
+        assert!(err.to_string().contains("--storage-account"), "{err}");
+
+        let bad_subscription = UnattendedInit {
+            subscription_id: "prod".to_string(),
+            ..options
+        };
+        assert!(bad_subscription.validate().is_err());
+    }
+
     #[tokio::test]
     async fn azure_without_vault_keeps_the_legacy_cli_shape_only() {
         let initializer = ConfigInitializer::new();
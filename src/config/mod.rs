@@ -7,6 +7,7 @@
 pub mod context;
 pub mod init;
 pub mod project;
+pub mod readiness;
 pub mod settings;
 pub mod setup;
 
@@ -55,6 +55,11 @@ pub struct EnvProfile {
     pub group: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub folder: Option<String>,
+    /// Blob key prefix for `xv file` commands in this env (e.g. `prod/`).
+    /// Uploads land under it, listings are scoped to it, and file names are
+    /// shown and accepted relative to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_prefix: Option<String>,
     /// Backend to use for this env. Must be one of: `azure`, `local`, `aws`.
     /// Overrides the global config `backend` key but loses to `--backend` CLI flag.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,414 @@
+//! Environment readiness checks for `xv init --check`
+//!
+//! Runs the detection steps of `xv init` (Azure CLI, sign-in, subscription,
+//! resource group, vault and storage account) plus the caller's data-plane
+//! RBAC on the vault and storage account, without writing configuration or
+//! creating anything. Each step yields one [`ReadinessCheck`]; a step whose
+//! prerequisite failed is not run.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::auth::provider::{AzureAuthProvider, DefaultAzureCredentialProvider};
+use crate::config::init::UnattendedInit;
+use crate::config::settings::Config;
+use crate::error::CrosstacheError;
+use crate::utils::azure_detect::AzureDetector;
+use crate::utils::interactive::SetupHelper;
+use crate::vault::models::VaultPermission;
+use crate::vault::operations::{AzureVaultOperations, VaultOperations};
+
+/// Blob data actions file operations need on the storage account
+/// (Storage Blob Data Contributor grants both).
+const BLOB_DATA_ACTIONS: &[&str] = &[
+    "Microsoft.Storage/storageAccounts/blobServices/containers/blobs/read",
+    "Microsoft.Storage/storageAccounts/blobServices/containers/blobs/write",
+];
+
+/// Outcome of one readiness check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    /// Usable, but `xv init` would have to create something or could not
+    /// verify it.
+    Warn,
+    Fail,
+}
+
+/// One step of the readiness report.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl ReadinessCheck {
+    fn new(name: impl Into<String>, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// What to check. Unset fields skip their checks; the subscription
+/// defaults to the Azure CLI's current one.
+#[derive(Debug, Clone, Default)]
+pub struct ReadinessTarget {
+    pub subscription_id: Option<String>,
+    pub resource_group: Option<String>,
+    pub vault: Option<String>,
+    /// The vault is to be created, so its absence is expected.
+    pub create_vault: bool,
+    /// Looked up in `resource_group`, where `xv init` creates it.
+    pub storage_account: Option<String>,
+    pub container: String,
+}
+
+impl ReadinessTarget {
+    /// `xv init` flags where given, the saved configuration otherwise, so
+    /// `xv init --check` alone re-checks the current setup.
+    pub fn for_init(flags: Option<UnattendedInit>, config: &Config) -> Self {
+        let non_empty = |value: &str| Some(value.to_string()).filter(|v| !v.trim().is_empty());
+        let blob = config.blob_config.as_ref();
+        match flags {
+            Some(flags) => Self {
+                subscription_id: non_empty(&flags.subscription_id)
+                    .or_else(|| non_empty(&config.subscription_id)),
+                resource_group: non_empty(&flags.resource_group)
+                    .or_else(|| non_empty(&config.default_resource_group)),
+                vault: non_empty(&flags.vault).or_else(|| non_empty(&config.default_vault)),
+                create_vault: flags.create_vault,
+                storage_account: flags
+                    .storage_account
+                    .or_else(|| blob.and_then(|b| non_empty(&b.storage_account))),
+                container: flags.container,
+            },
+            None => Self {
+                subscription_id: non_empty(&config.subscription_id),
+                resource_group: non_empty(&config.default_resource_group),
+                vault: non_empty(&config.default_vault),
+                create_vault: false,
+                storage_account: blob.and_then(|b| non_empty(&b.storage_account)),
+                container: blob
+                    .and_then(|b| non_empty(&b.container_name))
+                    .unwrap_or_else(|| crate::config::init::DEFAULT_CONTAINER.to_string()),
+            },
+        }
+    }
+}
+
+/// Run every check that applies to `target`, in order.
+pub async fn check_readiness(target: &ReadinessTarget) -> Vec<ReadinessCheck> {
+    use CheckStatus::{Fail, Pass, Warn};
+
+    let mut checks = Vec::new();
+    let env = match AzureDetector::detect_environment().await {
+        Ok(env) => env,
+        Err(e) => {
+            checks.push(ReadinessCheck::new("Azure CLI", Fail, e.to_string()));
+            return checks;
+        }
+    };
+    if !env.cli_available {
+        checks.push(ReadinessCheck::new(
+            "Azure CLI",
+            Fail,
+            env.get_status_message(),
+        ));
+        return checks;
+    }
+    checks.push(ReadinessCheck::new(
+        "Azure CLI",
+        Pass,
+        format!("v{}", env.cli_version.as_deref().unwrap_or("unknown")),
+    ));
+    if !env.cli_logged_in {
+        checks.push(ReadinessCheck::new(
+            "Azure sign-in",
+            Fail,
+            "Not logged in; run 'az login'",
+        ));
+        return checks;
+    }
+    checks.push(ReadinessCheck::new(
+        "Azure sign-in",
+        Pass,
+        format!("{} subscription(s) available", env.subscriptions.len()),
+    ));
+
+    let subscription = match target.subscription_id.as_deref().map(str::trim) {
+        Some(id) => env
+            .subscriptions
+            .iter()
+            .find(|s| s.id.eq_ignore_ascii_case(id))
+            .ok_or_else(|| format!("'{id}' is not in the Azure CLI's subscription list")),
+        None => env
+            .current_subscription
+            .as_ref()
+            .ok_or_else(|| "No current subscription; pass --subscription".to_string()),
+    };
+    let subscription = match subscription {
+        Ok(subscription) => {
+            checks.push(ReadinessCheck::new(
+                "Subscription",
+                Pass,
+                format!("{} ({})", subscription.name, subscription.id),
+            ));
+            subscription.clone()
+        }
+        Err(reason) => {
+            checks.push(ReadinessCheck::new("Subscription", Fail, reason));
+            return checks;
+        }
+    };
+
+    let Some(resource_group) = target.resource_group.as_deref() else {
+        return checks;
+    };
+    if let Err(e) = SetupHelper::validate_resource_group_name(resource_group) {
+        checks.push(ReadinessCheck::new("Resource group", Fail, e));
+        return checks;
+    }
+    match AzureDetector::resource_group_exists(&subscription.id, resource_group).await {
+        Ok(true) => checks.push(ReadinessCheck::new(
+            "Resource group",
+            Pass,
+            format!("'{resource_group}' exists"),
+        )),
+        Ok(false) => checks.push(ReadinessCheck::new(
+            "Resource group",
+            Warn,
+            format!("'{resource_group}' does not exist; xv init would create it"),
+        )),
+        Err(e) => checks.push(ReadinessCheck::new("Resource group", Fail, e.to_string())),
+    }
+
+    if target.vault.is_none() && target.storage_account.is_none() {
+        return checks;
+    }
+    let operations = DefaultAzureCredentialProvider::new().and_then(|provider| {
+        AzureVaultOperations::new(
+            Arc::new(provider) as Arc<dyn AzureAuthProvider>,
+            subscription.id.clone(),
+        )
+    });
+    let operations = match operations {
+        Ok(operations) => operations,
+        Err(e) => {
+            checks.push(ReadinessCheck::new(
+                "Azure credentials",
+                Fail,
+                e.to_string(),
+            ));
+            return checks;
+        }
+    };
+
+    if let Some(vault) = target.vault.as_deref() {
+        checks.extend(check_vault(&operations, vault, resource_group, target.create_vault).await);
+    }
+    if let Some(account) = target.storage_account.as_deref() {
+        checks.extend(
+            check_storage(
+                &operations,
+                &subscription.id,
+                account,
+                resource_group,
+                &target.container,
+            )
+            .await,
+        );
+    }
+    checks
+}
+
+async fn check_vault(
+    operations: &AzureVaultOperations,
+    vault: &str,
+    resource_group: &str,
+    create_vault: bool,
+) -> Vec<ReadinessCheck> {
+    use CheckStatus::{Fail, Pass, Warn};
+
+    let resource = match operations.get_vault_resource(vault, resource_group).await {
+        Ok(resource) => resource,
+        Err(CrosstacheError::VaultNotFound { .. }) if create_vault => {
+            return vec![ReadinessCheck::new(
+                "Vault",
+                Pass,
+                format!("'{vault}' does not exist yet; xv init would create it"),
+            )];
+        }
+        Err(CrosstacheError::VaultNotFound { .. }) => {
+            return vec![ReadinessCheck::new(
+                "Vault",
+                Fail,
+                format!("'{vault}' not found in resource group '{resource_group}'"),
+            )];
+        }
+        Err(e) => return vec![ReadinessCheck::new("Vault", Fail, e.to_string())],
+    };
+    let mut checks = vec![ReadinessCheck::new(
+        "Vault",
+        Pass,
+        format!("'{vault}' exists"),
+    )];
+
+    let uses_rbac = resource
+        .pointer("/properties/enableRbacAuthorization")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    if !uses_rbac {
+        checks.push(ReadinessCheck::new(
+            "Vault permissions",
+            Warn,
+            "The vault uses access policies; permissions were not checked",
+        ));
+        return checks;
+    }
+    let needed = [
+        VaultPermission::Read,
+        VaultPermission::Write,
+        VaultPermission::Delete,
+    ];
+    let check = match operations.caller_data_actions(vault, resource_group).await {
+        Ok(grants) => {
+            let missing: Vec<VaultPermission> = needed
+                .into_iter()
+                .filter(|p| !grants.iter().any(|g| g.permits(p.azure_data_action())))
+                .collect();
+            match missing.first() {
+                None => ReadinessCheck::new(
+                    "Vault permissions",
+                    Pass,
+                    "Can read, write and delete secrets",
+                ),
+                Some(first) => ReadinessCheck::new(
+                    "Vault permissions",
+                    Fail,
+                    format!(
+                        "Cannot {}; ask for the '{}' role on the vault",
+                        missing
+                            .iter()
+                            .map(|p| p.describe())
+                            .collect::<Vec<_>>()
+                            .join(" or "),
+                        first.azure_role()
+                    ),
+                ),
+            }
+        }
+        Err(e) => ReadinessCheck::new("Vault permissions", Warn, e.to_string()),
+    };
+    checks.push(check);
+    checks
+}
+
+async fn check_storage(
+    operations: &AzureVaultOperations,
+    subscription_id: &str,
+    account: &str,
+    resource_group: &str,
+    container: &str,
+) -> Vec<ReadinessCheck> {
+    use CheckStatus::{Fail, Pass, Warn};
+
+    if let Err(e) = SetupHelper::validate_storage_account_name(account) {
+        return vec![ReadinessCheck::new("Storage account", Fail, e)];
+    }
+    match AzureDetector::storage_account_exists(subscription_id, account).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return vec![ReadinessCheck::new(
+                "Storage account",
+                Warn,
+                format!("'{account}' does not exist; xv init would create it"),
+            )];
+        }
+        Err(e) => return vec![ReadinessCheck::new("Storage account", Fail, e.to_string())],
+    }
+    let mut checks = vec![ReadinessCheck::new(
+        "Storage account",
+        Pass,
+        format!("'{account}' exists"),
+    )];
+
+    let resource_id = format!(
+        "/subscriptions/{subscription_id}/resourceGroups/{resource_group}/providers/Microsoft.Storage/storageAccounts/{account}"
+    );
+    let check = match operations
+        .caller_data_actions_on(&resource_id, "Failed to read storage account permissions")
+        .await
+    {
+        Ok(grants) => {
+            if BLOB_DATA_ACTIONS
+                .iter()
+                .all(|action| grants.iter().any(|g| g.permits(action)))
+            {
+                ReadinessCheck::new("Storage permissions", Pass, "Can read and write blobs")
+            } else {
+                ReadinessCheck::new(
+                    "Storage permissions",
+                    Fail,
+                    "Cannot read and write blobs; ask for the 'Storage Blob Data Contributor' role on the account",
+                )
+            }
+        }
+        Err(e) => ReadinessCheck::new("Storage permissions", Warn, e.to_string()),
+    };
+    checks.push(check);
+
+    let container_check = match AzureDetector::container_exists(subscription_id, account, container)
+        .await
+    {
+        Ok(true) => ReadinessCheck::new("Blob container", Pass, format!("'{container}' exists")),
+        Ok(false) => ReadinessCheck::new(
+            "Blob container",
+            Warn,
+            format!("'{container}' not found; xv init would create it"),
+        ),
+        Err(e) => ReadinessCheck::new("Blob container", Warn, e.to_string()),
+    };
+    checks.push(container_check);
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init_flags_override_the_saved_configuration() {
+        let config = Config {
+            subscription_id: "saved-sub".to_string(),
+            default_resource_group: "saved-rg".to_string(),
+            default_vault: "saved-kv".to_string(),
+            ..Config::default()
+        };
+
+        let saved = ReadinessTarget::for_init(None, &config);
+        assert_eq!(saved.subscription_id.as_deref(), Some("saved-sub"));
+        assert_eq!(saved.vault.as_deref(), Some("saved-kv"));
+        assert_eq!(saved.storage_account, None);
+
+        let flags = UnattendedInit {
+            vault: "kv-new".to_string(),
+            create_vault: true,
+            storage_account: Some("stnew".to_string()),
+            container: "files".to_string(),
+            ..UnattendedInit::default()
+        };
+        let target = ReadinessTarget::for_init(Some(flags), &config);
+        assert_eq!(target.subscription_id.as_deref(), Some("saved-sub"));
+        assert_eq!(target.resource_group.as_deref(), Some("saved-rg"));
+        assert_eq!(target.vault.as_deref(), Some("kv-new"));
+        assert!(target.create_vault);
+        assert_eq!(target.storage_account.as_deref(), Some("stnew"));
+        assert_eq!(target.container, "files");
+    }
+}
@@ -105,6 +105,8 @@ pub enum AzureCredentialType {
     ManagedIdentity,
     /// Use environment variable credentials first
     Environment,
+    /// Sign in with the device-code flow (for shells without a browser)
+    DeviceCode,
     /// Use the default credential chain order
     #[default]
     Default,
@@ -116,6 +118,7 @@ impl fmt::Display for AzureCredentialType {
             Self::Cli => write!(f, "cli"),
             Self::ManagedIdentity => write!(f, "managed_identity"),
             Self::Environment => write!(f, "environment"),
+            Self::DeviceCode => write!(f, "device_code"),
             Self::Default => write!(f, "default"),
         }
     }
@@ -129,8 +132,47 @@ impl std::str::FromStr for AzureCredentialType {
             "cli" | "azure-cli" | "az" => Ok(Self::Cli),
             "managed_identity" | "managed-identity" | "msi" => Ok(Self::ManagedIdentity),
             "environment" | "env" => Ok(Self::Environment),
+            "device_code" | "device-code" => Ok(Self::DeviceCode),
             "default" => Ok(Self::Default),
-            _ => Err(format!("Invalid credential type: {s}. Valid options: cli, managed_identity, environment, default")),
+            _ => Err(format!("Invalid credential type: {s}. Valid options: cli, managed_identity, environment, device_code, default")),
+        }
+    }
+}
+
+/// Where `xv get` sends a secret value when no output flag is given.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GetOutput {
+    /// Copy to the clipboard (printing instead when there is none)
+    #[default]
+    Clipboard,
+    /// Print the value to stdout
+    Raw,
+    /// Print a masked preview and never the value itself
+    Masked,
+}
+
+impl fmt::Display for GetOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Clipboard => write!(f, "clipboard"),
+            Self::Raw => write!(f, "raw"),
+            Self::Masked => write!(f, "masked"),
+        }
+    }
+}
+
+impl std::str::FromStr for GetOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "clipboard" => Ok(Self::Clipboard),
+            "raw" => Ok(Self::Raw),
+            "masked" => Ok(Self::Masked),
+            _ => Err(format!(
+                "Invalid get output: {s}. Valid options: clipboard, raw, masked"
+            )),
         }
     }
 }
@@ -145,12 +187,24 @@ pub struct BlobConfig {
     pub max_concurrent_uploads: usize,
     #[serde(default = "default_progress_threshold_mb")]
     pub progress_threshold_mb: usize,
+    /// Further attempts for each failed block, part or range of a transfer.
+    #[serde(default = "default_transfer_retries")]
+    pub transfer_retries: usize,
+    /// Vault secret holding the storage account key or a connection string,
+    /// as `<secret>` (default vault) or `<vault>/<secret>`. Unset means
+    /// Entra ID token auth.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_secret: Option<String>,
 }
 
 fn default_progress_threshold_mb() -> usize {
     5
 }
 
+fn default_transfer_retries() -> usize {
+    3
+}
+
 impl Default for BlobConfig {
     fn default() -> Self {
         Self {
@@ -161,6 +215,8 @@ impl Default for BlobConfig {
             chunk_size_mb: 4,
             max_concurrent_uploads: 3,
             progress_threshold_mb: default_progress_threshold_mb(),
+            transfer_retries: default_transfer_retries(),
+            key_secret: None,
         }
     }
 }
@@ -213,6 +269,10 @@ pub struct Config {
     #[serde(skip)]
     #[tabled(skip)]
     pub runtime_columns: Option<Vec<String>>,
+    /// Global `--no-pager` (set in `Cli::execute`, not persisted).
+    #[serde(skip)]
+    #[tabled(skip)]
+    pub no_pager: bool,
     #[tabled(rename = "No Color")]
     pub no_color: bool,
     #[tabled(skip)]
@@ -247,6 +307,64 @@ pub struct Config {
     #[tabled(skip)]
     #[serde(default)]
     pub gen_default_charset: Option<String>,
+    /// What `xv get` does with a value when neither `--raw` nor
+    /// `--clipboard` is given: `clipboard` (default), `raw` or `masked`.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub get_default_output: GetOutput,
+    /// Vault tag keys copied onto secrets written by xv (`set`, `gen --save`,
+    /// `vault import`) unless the write sets that tag itself.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub inherit_vault_tags: Vec<String>,
+    /// Reject secret values passed as command-line arguments (`xv set
+    /// --value`, `KEY=value`, `xv update NAME VALUE`), which end up in shell
+    /// history. When unset such values are accepted with a warning.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub forbid_command_line_values: bool,
+    /// Allow `xv get --qr` and `xv file paste`, which move values between
+    /// the vault and the screen or clipboard. Off by default; each use is
+    /// appended to the transfer audit log next to the config file.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub allow_qr_and_paste: bool,
+    /// Check up front that the current identity holds the vault permission
+    /// a bulk operation needs (`vault import`, `vault export
+    /// --include-values`, `delete --group`) so it fails before the first
+    /// item rather than part-way through.
+    #[tabled(skip)]
+    #[serde(default = "default_permission_preflight")]
+    pub permission_preflight: bool,
+    /// Most per-secret requests in flight at once when a listing fetches
+    /// each secret's details or an export reads each value.
+    #[tabled(skip)]
+    #[serde(default = "default_fetch_concurrency")]
+    pub fetch_concurrency: usize,
+    /// Key Vault REST `api-version` for data-plane calls.
+    #[tabled(skip)]
+    #[serde(default = "default_key_vault_api_version")]
+    pub key_vault_api_version: String,
+    /// Page long list output even without `--pager`, as `--pager auto`
+    /// would: only on a terminal, and only when it is taller than the screen.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub paging: bool,
+    /// Command that pages output (e.g. `less -FRX`). `XV_PAGER` overrides
+    /// it; without either, `PAGER` is used, then the built-in pager.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub pager: Option<String>,
+    /// Per-command output format defaults from the `[output]` table, keyed
+    /// by command (`list`, `get`, `vault_list`, `file_list`, ...). Used when
+    /// `--format` is not given.
+    #[tabled(skip)]
+    #[serde(
+        default,
+        rename = "output",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty"
+    )]
+    pub output_defaults: std::collections::BTreeMap<String, String>,
     /// CLI `--env` flag override for active env in `.xv.toml`. Set
     /// once in main.rs from `cli.env`. Lower priority than the
     /// `XV_ENV` env var.
@@ -321,6 +439,18 @@ fn default_cache_enabled() -> bool {
     true
 }
 
+fn default_permission_preflight() -> bool {
+    true
+}
+
+fn default_fetch_concurrency() -> usize {
+    10
+}
+
+fn default_key_vault_api_version() -> String {
+    crate::backend::azure::kv_api::DEFAULT_API_VERSION.to_string()
+}
+
 fn default_cache_ttl_secs() -> u64 {
     900
 }
@@ -342,6 +472,7 @@ impl Default for Config {
             template: None,
             format_explicit: false,
             runtime_columns: None,
+            no_pager: false,
             no_color: false,
             blob_config: None,
             azure_credential_priority: AzureCredentialType::Default,
@@ -350,6 +481,16 @@ impl Default for Config {
             named_backends: std::collections::HashMap::new(),
             clipboard_timeout: default_clipboard_timeout(),
             gen_default_charset: None,
+            get_default_output: GetOutput::Clipboard,
+            inherit_vault_tags: Vec::new(),
+            forbid_command_line_values: false,
+            allow_qr_and_paste: false,
+            permission_preflight: default_permission_preflight(),
+            fetch_concurrency: default_fetch_concurrency(),
+            key_vault_api_version: default_key_vault_api_version(),
+            paging: false,
+            pager: None,
+            output_defaults: std::collections::BTreeMap::new(),
             env_flag: None,
             cli_backend: None,
             cli_backend_was_arg: false,
@@ -392,29 +533,12 @@ impl Config {
         Ok(())
     }
 
+    /// `xv.conf` in [`crate::utils::environment::config_dir`]: XDG on Linux
+    /// and macOS, `%APPDATA%\xv` on Windows, `$XV_CONFIG_DIR` anywhere.
     pub fn get_config_path() -> Result<PathBuf> {
-        // Use XDG Base Directory specification on Linux and macOS
-        // On Windows, use the platform-appropriate config directory
-        #[cfg(any(target_os = "linux", target_os = "macos"))]
-        {
-            use std::env;
-            let config_dir = if let Ok(xdg_config_home) = env::var("XDG_CONFIG_HOME") {
-                PathBuf::from(xdg_config_home)
-            } else {
-                let home_dir = env::var("HOME")
-                    .map_err(|_| CrosstacheError::config("HOME environment variable not set"))?;
-                PathBuf::from(home_dir).join(".config")
-            };
-            Ok(config_dir.join("xv").join("xv.conf"))
-        }
-
-        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
-        {
-            // Use platform-appropriate config directory for other platforms
-            let config_dir = dirs::config_dir()
-                .ok_or_else(|| CrosstacheError::config("Unable to determine config directory"))?;
-            Ok(config_dir.join("xv").join("xv.conf"))
-        }
+        let config_dir = crate::utils::environment::config_dir()
+            .ok_or_else(|| CrosstacheError::config("Unable to determine config directory"))?;
+        Ok(config_dir.join("xv.conf"))
     }
 
     #[allow(dead_code)]
@@ -499,9 +623,7 @@ impl Config {
             return Ok(self.default_vault.clone());
         }
 
-        Err(CrosstacheError::config(
-            "No vault specified. Use --vault, set context with 'xv context use', or configure default_vault"
-        ))
+        Err(CrosstacheError::no_vault_context())
     }
 
     /// Resolve resource group with context awareness
@@ -631,6 +753,35 @@ impl Config {
         Ok(None)
     }
 
+    /// Resolve the `xv file` blob key prefix for the active env.
+    /// Priority: .xv.toml env profile `file_prefix` > None.
+    ///
+    /// Returned normalized to `segment/.../` form (no leading slash, one
+    /// trailing slash) so callers can join it to a file name directly. A
+    /// blank or all-slash value counts as "no prefix", like a blank
+    /// `folder`.
+    pub async fn resolve_file_prefix(&self) -> Result<Option<String>> {
+        use crate::config::project;
+
+        let cwd = std::env::current_dir()?;
+        if let Some((path, cfg)) = project::find_project_config(&cwd).await? {
+            if let Some((name, profile)) = project::resolve_env(&cfg, self.env_flag.as_deref())? {
+                if path.parent().map(|p| p != cwd.as_path()).unwrap_or(false) {
+                    if let Some(line) = project::capture_cross_boundary_notice(&path, name) {
+                        eprintln!("{line}");
+                    }
+                }
+                let prefix = profile.file_prefix.as_deref().unwrap_or("").trim();
+                let prefix = prefix.trim_matches('/');
+                if !prefix.is_empty() {
+                    return Ok(Some(format!("{prefix}/")));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Resolve subscription ID with context awareness
     /// Priority: CLI argument > context > config default
     #[allow(dead_code)]
@@ -656,6 +807,24 @@ impl Config {
         Err(CrosstacheError::config("No subscription ID specified"))
     }
 
+    /// The `[output]` default for `command`, if one is configured. An
+    /// unparseable value is reported and ignored rather than failing the
+    /// command.
+    pub fn output_default(&self, command: &str) -> Option<OutputFormat> {
+        use clap::ValueEnum;
+
+        let value = self.output_defaults.get(command)?;
+        match OutputFormat::from_str(value, true) {
+            Ok(format) => Some(format),
+            Err(_) => {
+                crate::utils::output::warn(&format!(
+                    "Ignoring [output] {command} = \"{value}\": not a valid output format"
+                ));
+                None
+            }
+        }
+    }
+
     /// Get blob storage configuration, creating default if not present
     pub fn get_blob_config(&self) -> BlobConfig {
         self.blob_config.clone().unwrap_or_default()
@@ -785,6 +954,22 @@ fn load_from_env(config: &mut Config) {
         }
     }
 
+    if let Ok(value) = std::env::var("XV_FETCH_CONCURRENCY") {
+        if let Ok(limit) = value.parse::<usize>() {
+            config.fetch_concurrency = limit;
+        }
+    }
+
+    if let Ok(value) = std::env::var("XV_KEY_VAULT_API_VERSION") {
+        if !value.is_empty() {
+            config.key_vault_api_version = value;
+        }
+    }
+
+    if let Ok(value) = std::env::var("XV_PAGER") {
+        config.pager = Some(value);
+    }
+
     // Load Azure credential priority from environment variable
     if let Ok(value) = std::env::var("AZURE_CREDENTIAL_PRIORITY") {
         if let Ok(cred_type) = value.parse::<AzureCredentialType>() {
@@ -814,6 +999,11 @@ fn load_from_env(config: &mut Config) {
         blob_config_updated = true;
     }
 
+    if let Ok(value) = std::env::var("AZURE_STORAGE_KEY_SECRET") {
+        blob_config.key_secret = (!value.is_empty()).then_some(value);
+        blob_config_updated = true;
+    }
+
     if let Ok(value) = std::env::var("BLOB_CHUNK_SIZE_MB") {
         if let Ok(chunk_size) = value.parse::<usize>() {
             blob_config.chunk_size_mb = chunk_size;
@@ -828,6 +1018,13 @@ fn load_from_env(config: &mut Config) {
         }
     }
 
+    if let Ok(value) = std::env::var("BLOB_TRANSFER_RETRIES") {
+        if let Ok(retries) = value.parse::<usize>() {
+            blob_config.transfer_retries = retries;
+            blob_config_updated = true;
+        }
+    }
+
     if let Ok(value) = std::env::var("PROGRESS_THRESHOLD_MB") {
         if let Ok(threshold) = value.parse::<usize>() {
             blob_config.progress_threshold_mb = threshold;
@@ -932,6 +1129,11 @@ mod tests {
             AzureCredentialType::Environment
         );
 
+        assert_eq!(
+            AzureCredentialType::from_str("device-code").unwrap(),
+            AzureCredentialType::DeviceCode
+        );
+
         assert_eq!(
             AzureCredentialType::from_str("default").unwrap(),
             AzureCredentialType::Default
@@ -951,6 +1153,7 @@ mod tests {
             "managed_identity"
         );
         assert_eq!(AzureCredentialType::Environment.to_string(), "environment");
+        assert_eq!(AzureCredentialType::DeviceCode.to_string(), "device_code");
         assert_eq!(AzureCredentialType::Default.to_string(), "default");
     }
 
@@ -1014,6 +1217,51 @@ mod tests {
         assert!(config.gen_default_charset.is_none());
     }
 
+    #[test]
+    fn test_get_default_output_parses_and_defaults_to_clipboard() {
+        assert_eq!(Config::default().get_default_output, GetOutput::Clipboard);
+        let config = Config {
+            get_default_output: GetOutput::Masked,
+            ..Default::default()
+        };
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        assert!(serialized.contains(r#"get_default_output = "masked""#));
+        let deserialized: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.get_default_output, GetOutput::Masked);
+        assert_eq!("RAW".parse::<GetOutput>().unwrap(), GetOutput::Raw);
+        assert!("stdout".parse::<GetOutput>().is_err());
+    }
+
+    #[test]
+    fn test_output_defaults_parse_from_output_table() {
+        let toml = r#"
+            debug = false
+            subscription_id = ""
+            default_vault = ""
+            default_resource_group = "Vaults"
+            default_location = "eastus"
+            tenant_id = ""
+            output_json = false
+            no_color = false
+
+            [output]
+            list = "table"
+            get = "raw"
+            file_list = "bogus"
+        "#;
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(config.output_default("list"), Some(OutputFormat::Table));
+        assert_eq!(config.output_default("get"), Some(OutputFormat::Raw));
+        assert_eq!(config.output_default("file_list"), None);
+        assert_eq!(config.output_default("find"), None);
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        assert!(serialized.contains("[output]"), "{serialized}");
+        assert!(!toml::to_string_pretty(&Config::default())
+            .unwrap()
+            .contains("[output]"));
+    }
+
     #[test]
     fn test_cache_config_defaults() {
         let config = Config::default();
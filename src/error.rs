@@ -56,7 +56,7 @@ impl SafeSetupError {
                 "The setup configuration is invalid.",
                 "Review the setup fields and try again.",
             ),
-            PermissionDenied(_) => (
+            PermissionDenied(_) | Forbidden { .. } => (
                 "xv-permission-denied",
                 "The selected identity does not have permission to list this vault.",
                 setup_permission_hint(backend),
@@ -357,6 +357,10 @@ fn redact_setup_diagnostics(value: &str) -> String {
     safe_setup_text(&safe, 2048)
 }
 
+/// Message of [`CrosstacheError::no_vault_context`].
+const NO_VAULT_CONTEXT_MESSAGE: &str =
+    "No vault specified. Use --vault, set context with 'xv context use', or configure default_vault";
+
 /// Render the `EnvNotDefined` message. When `available` is empty the
 /// `.xv.toml` in question defines zero `[env.*]` blocks at all (a
 /// types-only project file, see #331) rather than simply lacking the
@@ -387,6 +391,24 @@ fn format_ambiguous_secret(name: &str, candidates: &[String]) -> String {
     )
 }
 
+/// The permission an Azure 403 message names as missing: the RBAC data
+/// action (`Action: '...'`), the ARM action (`perform action '...'`) or the
+/// access-policy permission (`does not have secrets get permission`).
+fn parse_required_permission(message: &str) -> Option<String> {
+    let quoted_after = |marker: &str| {
+        let rest = &message[message.find(marker)? + marker.len()..];
+        let end = rest.find('\'')?;
+        Some(rest[..end].to_string()).filter(|action| !action.is_empty())
+    };
+    quoted_after("Action: '")
+        .or_else(|| quoted_after("perform action '"))
+        .or_else(|| {
+            let start = message.find("does not have ")? + "does not have ".len();
+            let end = start + message[start..].find(" permission on ")?;
+            Some(message[start..end].to_string())
+        })
+}
+
 /// Main error type for crosstache operations
 #[derive(Debug, Error)]
 pub enum CrosstacheError {
@@ -399,8 +421,12 @@ pub enum CrosstacheError {
     #[error("Conflict: {0}")]
     Conflict(String),
 
-    #[error("Rate limited: {0}")]
-    RateLimited(String),
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        /// Server-requested wait from a `Retry-After` header, when present.
+        retry_after_secs: Option<u64>,
+    },
 
     #[error("Configuration error: {0}")]
     ConfigError(String),
@@ -430,6 +456,17 @@ pub enum CrosstacheError {
     #[error("Permission denied: {0}")]
     PermissionDenied(String),
 
+    #[error("Forbidden: {message}")]
+    Forbidden {
+        message: String,
+        /// The permission the service said was missing (a Key Vault data
+        /// action, an access-policy permission or a role), when it said.
+        required_permission: Option<String>,
+    },
+
+    #[error("Not found: {resource}: {message}")]
+    NotFound { resource: String, message: String },
+
     #[error("Network error: {0}")]
     NetworkError(String),
 
@@ -481,6 +518,18 @@ pub enum CrosstacheError {
     #[error("Scan detected {count} potential leak(s)")]
     ScanLeakDetected { count: usize },
 
+    #[error("Lint found {missing} missing secret reference(s) and {unused} unused secret(s)")]
+    LintFailed { missing: usize, unused: usize },
+
+    #[error("App '{app}' has {broken} broken Key Vault reference(s)")]
+    ReferenceCheckFailed { broken: usize, app: String },
+
+    #[error("Interrupted: {completed} item(s) completed, {remaining} not processed")]
+    Interrupted { completed: usize, remaining: usize },
+
+    #[error("Backup '{archive}' is incomplete: {failed} secret(s) could not be read")]
+    BackupIncomplete { failed: usize, archive: String },
+
     #[error("Rename of secret '{source}' to '{destination}' in vault '{vault}' is incomplete: the new secret was created, but deleting the original failed: {cause}. Both secrets still exist and no secret material was lost. Next steps: with vault '{vault}' active, verify the new secret (`xv get {destination}`), then delete the original (`xv delete {source}`) or retry the deletion later.")]
     RenameIncomplete {
         source: String,
@@ -510,7 +559,7 @@ impl CrosstacheError {
             Self::AuthenticationError(_) => "xv-auth-failed",
             Self::AzureApiError(_) => "xv-azure-api",
             Self::Conflict(_) => "xv-conflict",
-            Self::RateLimited(_) => "xv-rate-limited",
+            Self::RateLimited { .. } => "xv-rate-limited",
             Self::ConfigError(_) => "xv-config-invalid",
             Self::ConfigLoadError(_) => "xv-config-invalid",
             Self::BackendUnavailable { .. } => "xv-backend-unavailable",
@@ -518,6 +567,8 @@ impl CrosstacheError {
             Self::VaultNotFound { .. } => "xv-vault-not-found",
             Self::EnvNotDefined { .. } => "xv-env-not-defined",
             Self::PermissionDenied(_) => "xv-permission-denied",
+            Self::Forbidden { .. } => "xv-forbidden",
+            Self::NotFound { .. } => "xv-not-found",
             Self::NetworkError(_) => "xv-network",
             Self::DnsResolutionError { .. } => "xv-network-dns",
             Self::ConnectionTimeout(_) => "xv-network-timeout",
@@ -534,6 +585,10 @@ impl CrosstacheError {
             Self::InvalidArgument(_) => "xv-invalid-argument",
             Self::Upgrade(_) => "xv-upgrade",
             Self::ScanLeakDetected { .. } => "xv-scan-leak-detected",
+            Self::LintFailed { .. } => "xv-lint-failed",
+            Self::ReferenceCheckFailed { .. } => "xv-reference-check-failed",
+            Self::Interrupted { .. } => "xv-interrupted",
+            Self::BackupIncomplete { .. } => "xv-backup-incomplete",
             Self::RenameIncomplete { .. } => "xv-rename-incomplete",
             Self::AmbiguousSecret { .. } => "xv-ambiguous-secret",
             Self::Unknown(_) => "xv-unknown",
@@ -550,9 +605,10 @@ impl CrosstacheError {
 
             Self::SecretNotFound { .. } => 10,
             Self::VaultNotFound { .. } => 11,
+            Self::NotFound { .. } => 14,
 
             Self::AuthenticationError(_) => 20,
-            Self::PermissionDenied(_) => 21,
+            Self::PermissionDenied(_) | Self::Forbidden { .. } => 21,
 
             Self::NetworkError(_) => 30,
             Self::DnsResolutionError { .. } => 31,
@@ -563,8 +619,9 @@ impl CrosstacheError {
 
             Self::AzureApiError(_) => 40,
             Self::Conflict(_) => 41,
-            Self::RateLimited(_) => 42,
+            Self::RateLimited { .. } => 42,
             Self::RenameIncomplete { .. } => 43,
+            Self::BackupIncomplete { .. } => 44,
 
             // 10–19 — secret-family errors (workspace ambiguity is a
             // read-resolution failure, same family as "secret not found").
@@ -572,6 +629,11 @@ impl CrosstacheError {
 
             // 50–59 — policy/scan findings
             Self::ScanLeakDetected { .. } => 50,
+            Self::LintFailed { .. } => 51,
+            Self::ReferenceCheckFailed { .. } => 52,
+
+            // 128 + SIGINT, the shell convention for Ctrl+C.
+            Self::Interrupted { .. } => 130,
 
             Self::SerializationError(_)
             | Self::IoError(_)
@@ -600,13 +662,94 @@ impl CrosstacheError {
 
     #[allow(dead_code)]
     pub fn rate_limited<S: Into<String>>(msg: S) -> Self {
-        Self::RateLimited(msg.into())
+        Self::RateLimited {
+            message: msg.into(),
+            retry_after_secs: None,
+        }
+    }
+
+    /// Classify a non-success HTTP status into the matching typed variant.
+    ///
+    /// `resource` names what the request addressed (a URL, a vault, a
+    /// container) and is reported by a 404. Callers that know the missing
+    /// resource is a secret or a vault handle 404 themselves first, so the
+    /// more specific variant and its suggestion win.
+    pub fn from_http_status(
+        status: u16,
+        retry_after_secs: Option<u64>,
+        resource: &str,
+        message: String,
+    ) -> Self {
+        match status {
+            401 => Self::AuthenticationError(message),
+            403 => Self::Forbidden {
+                required_permission: parse_required_permission(&message),
+                message,
+            },
+            404 => Self::NotFound {
+                resource: resource.to_string(),
+                message,
+            },
+            408 | 504 => Self::ConnectionTimeout(message),
+            409 => Self::Conflict(message),
+            429 => Self::RateLimited {
+                message,
+                retry_after_secs,
+            },
+            _ => Self::AzureApiError(message),
+        }
+    }
+
+    /// Name the missing permission on a `Forbidden` error, replacing any
+    /// read from the response. No-op for other variants.
+    pub fn with_required_permission(mut self, permission: Option<String>) -> Self {
+        if let Self::Forbidden {
+            required_permission,
+            ..
+        } = &mut self
+        {
+            *required_permission = permission;
+        }
+        self
+    }
+
+    /// The permission a `Forbidden` error says was missing, if known.
+    pub fn required_permission(&self) -> Option<&str> {
+        match self {
+            Self::Forbidden {
+                required_permission,
+                ..
+            } => required_permission.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Server-requested wait before retrying, if the error carries one.
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            Self::RateLimited {
+                retry_after_secs, ..
+            } => *retry_after_secs,
+            _ => None,
+        }
     }
 
     pub fn config<S: Into<String>>(msg: S) -> Self {
         Self::ConfigError(msg.into())
     }
 
+    /// The configuration error raised when no vault could be resolved
+    /// (no `--vault`, context, `.xv.toml` profile or `default_vault`).
+    pub fn no_vault_context() -> Self {
+        Self::ConfigError(NO_VAULT_CONTEXT_MESSAGE.to_string())
+    }
+
+    /// True for [`CrosstacheError::no_vault_context`]; the CLI follows it
+    /// with the commands that set a context.
+    pub fn is_no_vault_context(&self) -> bool {
+        matches!(self, Self::ConfigError(msg) if msg == NO_VAULT_CONTEXT_MESSAGE)
+    }
+
     #[allow(dead_code)]
     pub fn backend_unavailable<S: Into<String>, R: Into<String>>(backend: S, reason: R) -> Self {
         Self::BackendUnavailable {
@@ -654,6 +797,40 @@ impl CrosstacheError {
         Self::ScanLeakDetected { count }
     }
 
+    /// Build the `LintFailed` variant (exit 51, `xv-lint-failed`). `unused`
+    /// is only counted when the caller gates on unused secrets.
+    pub fn lint_failed(missing: usize, unused: usize) -> Self {
+        Self::LintFailed { missing, unused }
+    }
+
+    /// Build the `ReferenceCheckFailed` variant (exit 52,
+    /// `xv-reference-check-failed`): `xv appservice check` found references
+    /// the app cannot resolve.
+    pub fn reference_check_failed(broken: usize, app: impl Into<String>) -> Self {
+        Self::ReferenceCheckFailed {
+            broken,
+            app: app.into(),
+        }
+    }
+
+    /// Build the `Interrupted` variant (exit 130, `xv-interrupted`): a bulk
+    /// operation stopped early on Ctrl+C.
+    pub fn interrupted(completed: usize, remaining: usize) -> Self {
+        Self::Interrupted {
+            completed,
+            remaining,
+        }
+    }
+
+    /// Build the `BackupIncomplete` variant (exit 44, `xv-backup-incomplete`):
+    /// the archive was written, but `failed` secrets are missing from it.
+    pub fn backup_incomplete(failed: usize, archive: impl Into<String>) -> Self {
+        Self::BackupIncomplete {
+            failed,
+            archive: archive.into(),
+        }
+    }
+
     /// Build the `AmbiguousSecret` variant (exit 13, `xv-ambiguous-secret`):
     /// an unqualified read matched `name` in two or more attached workspace
     /// vaults. `candidates` are the aliases it was found in.
@@ -828,6 +1005,99 @@ mod tests {
         assert_eq!(err.to_string(), "Connection timeout: 30s elapsed");
     }
 
+    #[test]
+    fn test_from_http_status_classifies_status_classes() {
+        let msg = || "Failed to get secret: HTTP".to_string();
+        assert!(matches!(
+            CrosstacheError::from_http_status(401, None, "kv/secrets/db", msg()),
+            CrosstacheError::AuthenticationError(_)
+        ));
+        assert!(matches!(
+            CrosstacheError::from_http_status(403, None, "kv/secrets/db", msg()),
+            CrosstacheError::Forbidden {
+                required_permission: None,
+                ..
+            }
+        ));
+        assert!(matches!(
+            CrosstacheError::from_http_status(408, None, "kv/secrets/db", msg()),
+            CrosstacheError::ConnectionTimeout(_)
+        ));
+        assert!(matches!(
+            CrosstacheError::from_http_status(504, None, "kv/secrets/db", msg()),
+            CrosstacheError::ConnectionTimeout(_)
+        ));
+        assert!(matches!(
+            CrosstacheError::from_http_status(409, None, "kv/secrets/db", msg()),
+            CrosstacheError::Conflict(_)
+        ));
+        assert!(matches!(
+            CrosstacheError::from_http_status(500, None, "kv/secrets/db", msg()),
+            CrosstacheError::AzureApiError(_)
+        ));
+        let missing = CrosstacheError::from_http_status(404, None, "kv/secrets/db", msg());
+        assert!(matches!(
+            missing,
+            CrosstacheError::NotFound { ref resource, .. } if resource == "kv/secrets/db"
+        ));
+        assert_eq!(missing.exit_code(), 14);
+        assert_eq!(
+            missing.to_string(),
+            "Not found: kv/secrets/db: Failed to get secret: HTTP"
+        );
+
+        let throttled = CrosstacheError::from_http_status(429, Some(7), "kv/secrets/db", msg());
+        assert_eq!(throttled.retry_after_secs(), Some(7));
+        assert_eq!(throttled.exit_code(), 42);
+        assert_eq!(
+            throttled.to_string(),
+            "Rate limited: Failed to get secret: HTTP"
+        );
+    }
+
+    #[test]
+    fn test_forbidden_names_the_required_permission() {
+        let permission = |message: &str| {
+            CrosstacheError::from_http_status(403, None, "kv", message.to_string())
+                .required_permission()
+                .map(str::to_string)
+        };
+        assert_eq!(
+            permission(
+                "Failed to get secret: HTTP 403 Forbidden - Caller is not authorized to perform action on resource.\r\nCaller: appid=abc\r\nAction: 'Microsoft.KeyVault/vaults/secrets/getSecret/action'\r\nResource: 'kv'"
+            )
+            .as_deref(),
+            Some("Microsoft.KeyVault/vaults/secrets/getSecret/action")
+        );
+        assert_eq!(
+            permission(
+                "Failed to get vault: HTTP 403 Forbidden - The client 'me' with object id 'abc' does not have authorization to perform action 'Microsoft.KeyVault/vaults/read' over scope '/subscriptions/x'"
+            )
+            .as_deref(),
+            Some("Microsoft.KeyVault/vaults/read")
+        );
+        assert_eq!(
+            permission(
+                "Failed to list secrets: HTTP 403 Forbidden - The user, group or application 'appid=abc' does not have secrets list permission on key vault 'kv;location=eastus'."
+            )
+            .as_deref(),
+            Some("secrets list")
+        );
+        assert_eq!(
+            permission("Insufficient privileges to complete the operation."),
+            None
+        );
+
+        let overridden = CrosstacheError::from_http_status(403, None, "kv", "denied".into())
+            .with_required_permission(Some("Storage Blob Data Contributor".into()));
+        assert_eq!(
+            overridden.required_permission(),
+            Some("Storage Blob Data Contributor")
+        );
+        assert_eq!(overridden.exit_code(), 21);
+        assert_eq!(overridden.to_string(), "Forbidden: denied");
+    }
+
     #[test]
     fn test_connection_refused_constructor() {
         let err = CrosstacheError::connection_refused("port 443 closed");
@@ -938,6 +1208,14 @@ mod tests {
                 CrosstacheError::permission_denied("x"),
                 "xv-permission-denied",
             ),
+            (
+                CrosstacheError::from_http_status(403, None, "kv", "x".into()),
+                "xv-forbidden",
+            ),
+            (
+                CrosstacheError::from_http_status(404, None, "kv", "x".into()),
+                "xv-not-found",
+            ),
             (CrosstacheError::network("x"), "xv-network"),
             (CrosstacheError::dns_resolution("x", "y"), "xv-network-dns"),
             (
@@ -1191,7 +1469,7 @@ mod tests {
             SecuritySurface {
                 category: "error variant",
                 name: "RateLimited",
-                fields: &["msg"],
+                fields: &["message", "retry_after_secs"],
                 allowed_value_like_fields: &[],
             },
             SecuritySurface {
@@ -1230,6 +1508,18 @@ mod tests {
                 fields: &["msg"],
                 allowed_value_like_fields: &[],
             },
+            SecuritySurface {
+                category: "error variant",
+                name: "Forbidden",
+                fields: &["message", "required_permission"],
+                allowed_value_like_fields: &[],
+            },
+            SecuritySurface {
+                category: "error variant",
+                name: "NotFound",
+                fields: &["resource", "message"],
+                allowed_value_like_fields: &[],
+            },
             SecuritySurface {
                 category: "error variant",
                 name: "NetworkError",
@@ -1332,6 +1622,30 @@ mod tests {
                 fields: &["count"],
                 allowed_value_like_fields: &[],
             },
+            SecuritySurface {
+                category: "error variant",
+                name: "LintFailed",
+                fields: &["missing", "unused"],
+                allowed_value_like_fields: &[],
+            },
+            SecuritySurface {
+                category: "error variant",
+                name: "ReferenceCheckFailed",
+                fields: &["broken", "app"],
+                allowed_value_like_fields: &[],
+            },
+            SecuritySurface {
+                category: "error variant",
+                name: "Interrupted",
+                fields: &["completed", "remaining"],
+                allowed_value_like_fields: &[],
+            },
+            SecuritySurface {
+                category: "error variant",
+                name: "BackupIncomplete",
+                fields: &["failed", "archive"],
+                allowed_value_like_fields: &[],
+            },
             SecuritySurface {
                 category: "error variant",
                 name: "RenameIncomplete",
@@ -1506,13 +1820,26 @@ mod tests {
             SecuritySurface {
                 category: "structured output",
                 name: "error envelope",
-                fields: &["error", "code", "message", "exit_code", "suggestion"],
+                fields: &[
+                    "error",
+                    "code",
+                    "message",
+                    "exit_code",
+                    "suggestion",
+                    "required_permission",
+                ],
                 allowed_value_like_fields: &[],
             },
             SecuritySurface {
                 category: "log output",
                 name: "plain error",
-                fields: &["code", "message", "hint", "suggestion"],
+                fields: &[
+                    "code",
+                    "message",
+                    "hint",
+                    "suggestion",
+                    "required_permission",
+                ],
                 allowed_value_like_fields: &[],
             },
             SecuritySurface {
@@ -1571,6 +1898,39 @@ mod tests {
         assert_eq!(err.exit_code(), 50);
     }
 
+    #[test]
+    fn test_lint_failed_constructor_and_exit_code() {
+        let err = CrosstacheError::lint_failed(2, 0);
+        assert_eq!(err.code(), "xv-lint-failed");
+        assert_eq!(err.exit_code(), 51);
+        assert!(err.to_string().contains("2 missing secret reference(s)"));
+    }
+
+    #[test]
+    fn test_reference_check_failed_constructor_and_exit_code() {
+        let err = CrosstacheError::reference_check_failed(2, "web-prod");
+        assert_eq!(err.code(), "xv-reference-check-failed");
+        assert_eq!(err.exit_code(), 52);
+        assert!(err.to_string().contains("2 broken Key Vault reference(s)"));
+    }
+
+    #[test]
+    fn test_backup_incomplete_constructor_and_exit_code() {
+        let err = CrosstacheError::backup_incomplete(3, "backups/kv-20260101T000000Z.json.age");
+        assert_eq!(err.code(), "xv-backup-incomplete");
+        assert_eq!(err.exit_code(), 44);
+        assert!(err.to_string().contains("3 secret(s) could not be read"));
+    }
+
+    #[test]
+    fn test_interrupted_constructor_and_exit_code() {
+        let err = CrosstacheError::interrupted(4, 6);
+        assert_eq!(err.code(), "xv-interrupted");
+        assert_eq!(err.exit_code(), 130);
+        let s = err.to_string();
+        assert!(s.contains("4 item(s) completed") && s.contains("6 not processed"));
+    }
+
     #[test]
     fn test_scan_leak_detected_display_includes_count() {
         let err = CrosstacheError::scan_leak_detected(7);
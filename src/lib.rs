@@ -11,9 +11,11 @@ pub mod cache;
 pub mod cli;
 pub mod config;
 pub mod error;
+pub mod lint;
 pub mod records;
 pub mod scan;
 pub mod secret;
+pub mod template;
 #[cfg(feature = "tui")]
 pub mod tui;
 pub mod utils;
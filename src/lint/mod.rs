@@ -0,0 +1,483 @@
+//! Secret reference lint for `xv lint`.
+//!
+//! Project config files name the secrets an app expects at deploy time.
+//! This module finds those names and checks them against a vault's
+//! listing, so CI can fail on a reference nothing will resolve (and flag
+//! vault secrets no config mentions any more).
+//!
+//! Files looked at, anywhere below the lint root (minus the scanner's
+//! [`DEFAULT_EXCLUDES`](crate::scan::walker::DEFAULT_EXCLUDES) and
+//! `.gitignore`/`.xvignore` entries):
+//!
+//! | File | References |
+//! |------|------------|
+//! | `appsettings.json`, `appsettings.*.json` | any explicit reference |
+//! | `.env.example`, `.env.sample`, `.env.template` | explicit references, plus every key left empty (`DB_PASSWORD=`) |
+//! | `values.yaml`, `values-*.yaml`, `values.*.yaml` (helm) | any explicit reference |
+//!
+//! Explicit references are `{{ secret:name }}`, `xv://vault/name` and
+//! App Service `@Microsoft.KeyVault(SecretName=name)` /
+//! `@Microsoft.KeyVault(SecretUri=https://vault.vault.azure.net/secrets/name)`.
+
+use crate::error::Result;
+use crate::scan::walker::build_exclude_set;
+use crate::utils::helpers::to_env_var_name;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+static TEMPLATE_REF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*secret:([^}\s]+)\s*\}\}").unwrap());
+static URI_REF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"xv://([^/\s"']+)/([^/\s#"'?]+)"#).unwrap());
+static KEY_VAULT_REF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"@Microsoft\.KeyVault\(([^)]*)\)").unwrap());
+
+/// How a config file referenced a secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReferenceKind {
+    /// `{{ secret:name }}`
+    Template,
+    /// `xv://vault/name`
+    Uri,
+    /// `@Microsoft.KeyVault(...)`
+    KeyVault,
+    /// An empty key in a `.env.example`, matched against secrets by their
+    /// environment-variable form (`db-password` → `DB_PASSWORD`).
+    EnvKey,
+}
+
+/// One secret name found in a config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SecretReference {
+    pub name: String,
+    /// Vault named by the reference itself, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vault: Option<String>,
+    pub kind: ReferenceKind,
+    /// File path relative to the lint root.
+    pub file: PathBuf,
+    /// 1-based line number.
+    pub line: usize,
+}
+
+impl SecretReference {
+    fn location(&self) -> String {
+        format!("{}:{}", self.file.display(), self.line)
+    }
+}
+
+/// What kind of config file a path is, if lint reads it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFile {
+    AppSettings,
+    EnvExample,
+    HelmValues,
+}
+
+impl ConfigFile {
+    fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name == "appsettings.json"
+            || (name.starts_with("appsettings.") && name.ends_with(".json"))
+        {
+            Some(Self::AppSettings)
+        } else if matches!(
+            name.as_str(),
+            ".env.example" | ".env.sample" | ".env.template"
+        ) {
+            Some(Self::EnvExample)
+        } else if matches!(name.as_str(), "values.yaml" | "values.yml")
+            || ((name.starts_with("values-") || name.starts_with("values."))
+                && (name.ends_with(".yaml") || name.ends_with(".yml")))
+        {
+            Some(Self::HelmValues)
+        } else {
+            None
+        }
+    }
+}
+
+/// Find the config files below `root` that lint reads, sorted by path.
+/// Dotfiles are included (`.env.example` is one); `.gitignore`d paths and
+/// the scanner's default excludes are not.
+pub fn discover(root: &Path) -> Result<Vec<PathBuf>> {
+    let excludes = build_exclude_set(&[])?;
+    let mut files = Vec::new();
+    for entry in ignore::WalkBuilder::new(root)
+        .hidden(false)
+        .add_custom_ignore_filename(".xvignore")
+        .build()
+        .flatten()
+    {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        let path = entry.path();
+        let rel = path.strip_prefix(root).unwrap_or(path);
+        if excludes.is_match(rel) || ConfigFile::from_path(path).is_none() {
+            continue;
+        }
+        files.push(path.to_path_buf());
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Every secret reference in one config file's `content`. `file` is
+/// recorded on each reference as given; a path that is not a lint config
+/// file yields nothing.
+pub fn extract(file: &Path, content: &str) -> Vec<SecretReference> {
+    let Some(config_file) = ConfigFile::from_path(file) else {
+        return Vec::new();
+    };
+    let mut refs = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let mut found: Vec<(&str, Option<&str>, ReferenceKind)> = Vec::new();
+        for caps in TEMPLATE_REF.captures_iter(line) {
+            // `alias:name.field` — the secret is the part after any alias
+            // and before any record field.
+            let token = caps.get(1).map_or("", |m| m.as_str());
+            let token = token.rsplit(':').next().unwrap_or(token);
+            found.push((
+                token.split('.').next().unwrap_or(token),
+                None,
+                ReferenceKind::Template,
+            ));
+        }
+        for caps in URI_REF.captures_iter(line) {
+            // `backend:vault` keeps only the vault.
+            let vault = caps.get(1).map_or("", |m| m.as_str());
+            let name = caps.get(2).map_or("", |m| m.as_str());
+            found.push((
+                name,
+                Some(vault.rsplit(':').next().unwrap_or(vault)),
+                ReferenceKind::Uri,
+            ));
+        }
+        for caps in KEY_VAULT_REF.captures_iter(line) {
+            if let Some((name, vault)) = key_vault_reference(caps.get(1).map_or("", |m| m.as_str()))
+            {
+                found.push((name, vault, ReferenceKind::KeyVault));
+            }
+        }
+        if found.is_empty() && config_file == ConfigFile::EnvExample {
+            if let Some(key) = empty_env_key(line) {
+                found.push((key, None, ReferenceKind::EnvKey));
+            }
+        }
+        refs.extend(
+            found
+                .into_iter()
+                .map(|(name, vault, kind)| SecretReference {
+                    name: name.to_string(),
+                    vault: vault.map(str::to_string),
+                    kind,
+                    file: file.to_path_buf(),
+                    line: index + 1,
+                }),
+        );
+    }
+    refs
+}
+
+/// Secret and vault named by the `;`-separated body of an App Service
+/// `@Microsoft.KeyVault(...)` reference: `VaultName=v;SecretName=s` or
+/// `SecretUri=https://v.vault.azure.net/secrets/s[/version]`.
+fn key_vault_reference(body: &str) -> Option<(&str, Option<&str>)> {
+    let mut name = None;
+    let mut vault = None;
+    for (key, value) in body.split(';').filter_map(|pair| pair.split_once('=')) {
+        match key.trim() {
+            "SecretName" => name = Some(value.trim()),
+            "VaultName" => vault = Some(value.trim()),
+            "SecretUri" => {
+                let rest = value.trim().split_once("://")?.1;
+                let (host, path) = rest.split_once('/')?;
+                vault = host.split('.').next();
+                name = path.strip_prefix("secrets/")?.split('/').next();
+            }
+            _ => {}
+        }
+    }
+    name.filter(|n| !n.is_empty()).map(|n| (n, vault))
+}
+
+/// The key of a `KEY=` line with nothing after the `=` (an optional
+/// `export ` prefix and empty quotes allowed).
+fn empty_env_key(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if line.starts_with('#') {
+        return None;
+    }
+    let line = line.strip_prefix("export ").unwrap_or(line);
+    let (key, value) = line.split_once('=')?;
+    let key = key.trim();
+    let value = value.trim();
+    let empty = value.is_empty() || value == "\"\"" || value == "''";
+    let valid_key = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    (empty && valid_key).then_some(key)
+}
+
+/// A vault secret as lint sees it: the listed name and, when the backend
+/// had to sanitize it, the name it was created under.
+#[derive(Debug, Clone)]
+pub struct VaultSecret {
+    pub name: String,
+    pub original_name: String,
+}
+
+impl VaultSecret {
+    fn matches(&self, reference: &SecretReference) -> bool {
+        let names = [self.name.as_str(), self.original_name.as_str()];
+        let names = names.iter().filter(|n| !n.is_empty());
+        match reference.kind {
+            ReferenceKind::EnvKey => names
+                .into_iter()
+                .any(|n| to_env_var_name(n) == reference.name),
+            _ => names
+                .into_iter()
+                .any(|n| n.eq_ignore_ascii_case(&reference.name)),
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        if self.original_name.is_empty() {
+            &self.name
+        } else {
+            &self.original_name
+        }
+    }
+}
+
+/// A referenced name with no matching secret, and where it was referenced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MissingSecret {
+    pub name: String,
+    pub kind: ReferenceKind,
+    /// `file:line` of every reference to the name.
+    pub locations: Vec<String>,
+}
+
+/// Outcome of checking a project's references against one vault.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LintReport {
+    pub vault: String,
+    /// Config files read, relative to the lint root.
+    pub files: Vec<PathBuf>,
+    /// Number of references found.
+    pub references: usize,
+    /// Referenced secrets the vault does not have.
+    pub missing: Vec<MissingSecret>,
+    /// Vault secrets no reference names.
+    pub unused: Vec<String>,
+    /// References to other vaults, which are not checked.
+    pub other_vault: usize,
+}
+
+impl LintReport {
+    /// Check `references` against the secrets of `vault`. A reference that
+    /// names another vault is counted but neither checked nor allowed to
+    /// mark a secret of this vault as used.
+    pub fn build(
+        vault: &str,
+        files: Vec<PathBuf>,
+        references: &[SecretReference],
+        secrets: &[VaultSecret],
+    ) -> Self {
+        let (local, other): (Vec<&SecretReference>, Vec<&SecretReference>) =
+            references.iter().partition(|r| {
+                r.vault
+                    .as_deref()
+                    .is_none_or(|v| v.eq_ignore_ascii_case(vault))
+            });
+
+        let mut missing: BTreeMap<(String, ReferenceKind), Vec<String>> = BTreeMap::new();
+        let mut used = vec![false; secrets.len()];
+        for reference in &local {
+            let mut found = false;
+            for (secret, used) in secrets.iter().zip(used.iter_mut()) {
+                if secret.matches(reference) {
+                    *used = true;
+                    found = true;
+                }
+            }
+            if !found {
+                missing
+                    .entry((reference.name.clone(), reference.kind))
+                    .or_default()
+                    .push(reference.location());
+            }
+        }
+
+        let mut unused: Vec<String> = secrets
+            .iter()
+            .zip(&used)
+            .filter(|(_, used)| !**used)
+            .map(|(secret, _)| secret.display_name().to_string())
+            .collect();
+        unused.sort_by_key(|n| n.to_lowercase());
+
+        Self {
+            vault: vault.to_string(),
+            files,
+            references: references.len(),
+            missing: missing
+                .into_iter()
+                .map(|((name, kind), locations)| MissingSecret {
+                    name,
+                    kind,
+                    locations,
+                })
+                .collect(),
+            unused,
+            other_vault: other.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(name: &str) -> VaultSecret {
+        VaultSecret {
+            name: name.to_string(),
+            original_name: String::new(),
+        }
+    }
+
+    #[test]
+    fn only_known_config_files_are_read() {
+        let is_config = |p: &str| ConfigFile::from_path(Path::new(p)).is_some();
+        assert!(is_config("src/appsettings.json"));
+        assert!(is_config("appsettings.Production.json"));
+        assert!(is_config("api/.env.example"));
+        assert!(is_config("charts/app/values.yaml"));
+        assert!(is_config("charts/app/values-prod.yml"));
+        assert!(!is_config(".env"));
+        assert!(!is_config("settings.json"));
+        assert!(!is_config("chart.yaml"));
+    }
+
+    #[test]
+    fn explicit_references_are_extracted() {
+        let content = r#"{
+  "Db": "@Microsoft.KeyVault(VaultName=prod-kv;SecretName=db-password)",
+  "Api": "@Microsoft.KeyVault(SecretUri=https://prod-kv.vault.azure.net/secrets/api-key/abc123)",
+  "Smtp": "{{ secret:work:mail-cred.username }}",
+  "Redis": "xv://aws:shared/redis-url#host"
+}"#;
+        let refs = extract(Path::new("appsettings.json"), content);
+        let got: Vec<(&str, Option<&str>, usize)> = refs
+            .iter()
+            .map(|r| (r.name.as_str(), r.vault.as_deref(), r.line))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                ("db-password", Some("prod-kv"), 2),
+                ("api-key", Some("prod-kv"), 3),
+                ("mail-cred", None, 4),
+                ("redis-url", Some("shared"), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_env_example_keys_are_references() {
+        let content =
+            "# comment=\nDB_PASSWORD=\nexport API_KEY=\"\"\nPORT=8080\nREDIS={{ secret:redis-url }}\n";
+        let refs = extract(Path::new(".env.example"), content);
+        let got: Vec<(&str, ReferenceKind)> =
+            refs.iter().map(|r| (r.name.as_str(), r.kind)).collect();
+        assert_eq!(
+            got,
+            vec![
+                ("DB_PASSWORD", ReferenceKind::EnvKey),
+                ("API_KEY", ReferenceKind::EnvKey),
+                ("redis-url", ReferenceKind::Template),
+            ]
+        );
+        // Empty keys elsewhere are not references.
+        assert!(extract(
+            Path::new("values.yaml"),
+            "password:
+"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn report_lists_missing_and_unused() {
+        let refs = [
+            extract(
+                Path::new(".env.example"),
+                "DB_PASSWORD=
+MISSING_KEY=
+",
+            ),
+            extract(
+                Path::new("values.yaml"),
+                "a: xv://prod/api-key
+b: xv://other/elsewhere
+c: '{{ secret:gone }}'
+",
+            ),
+        ]
+        .concat();
+        let secrets = [secret("db-password"), secret("API-KEY"), secret("stale")];
+        let report = LintReport::build("prod", Vec::new(), &refs, &secrets);
+
+        let missing: Vec<&str> = report.missing.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(missing, vec!["MISSING_KEY", "gone"]);
+        assert_eq!(report.missing[1].locations, vec!["values.yaml:3"]);
+        assert_eq!(report.unused, vec!["stale"]);
+        assert_eq!(report.references, 5);
+        assert_eq!(report.other_vault, 1);
+    }
+
+    #[test]
+    fn original_names_match_too() {
+        let refs = extract(
+            Path::new("values.yaml"),
+            "a: '{{ secret:db_password }}'
+",
+        );
+        let sanitized = VaultSecret {
+            name: "db-password".to_string(),
+            original_name: "db_password".to_string(),
+        };
+        let report = LintReport::build("v", Vec::new(), &refs, &[sanitized]);
+        assert!(report.missing.is_empty());
+        assert!(report.unused.is_empty());
+    }
+
+    #[test]
+    fn discover_finds_dotfiles_and_skips_excludes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("charts/app")).unwrap();
+        std::fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        std::fs::write(root.join(".env.example"), "A=\n").unwrap();
+        std::fs::write(root.join("charts/app/values.yaml"), "").unwrap();
+        std::fs::write(root.join("node_modules/pkg/values.yaml"), "").unwrap();
+        std::fs::write(root.join("README.md"), "").unwrap();
+
+        let found: Vec<PathBuf> = discover(root)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from(".env.example"),
+                PathBuf::from("charts/app/values.yaml")
+            ]
+        );
+    }
+}
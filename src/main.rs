@@ -15,9 +15,11 @@ mod cache;
 mod cli;
 mod config;
 mod error;
+mod lint;
 mod records;
 mod scan;
 mod secret;
+mod template;
 #[cfg(feature = "tui")]
 mod tui;
 mod utils;
@@ -35,6 +37,7 @@ async fn main() {
     // Reset SIGPIPE to default behavior so piping to commands like `head` or
     // `echo` doesn't cause a panic when the reader closes the pipe early.
     reset_sigpipe();
+    utils::windows::enable_ansi();
 
     // Initialize logging
     init_logging();
@@ -61,18 +64,80 @@ async fn main() {
         return;
     }
 
+    if args.len() > 1 && args[1] == "__complete-search" {
+        let format = OutputFormat::Plain; // Default format for completion
+        let prefix = args.get(2).map(String::as_str).unwrap_or("");
+        if let Err(e) = run_complete_search(prefix).await {
+            error!("Error: {}", e);
+            print_user_friendly_error(&e, format);
+            std::process::exit(e.exit_code());
+        }
+        return;
+    }
+
     // Parse command-line arguments
     let cli = Cli::parse();
     let format = cli.format; // OutputFormat is Copy
 
     // Execute the command
-    if let Err(e) = run(cli).await {
+    let result = match cli.timeout {
+        Some(secs) => run_with_timeout(cli, secs).await,
+        None => run(cli).await,
+    };
+    if let Err(e) = result {
         error!("Error: {}", e);
         print_user_friendly_error(&e, format);
+        if !matches!(format, OutputFormat::Json | OutputFormat::Yaml) {
+            report_offline(&e).await;
+            offer_setup_fix(&e).await;
+        }
         std::process::exit(e.exit_code());
     }
 }
 
+/// After an error caused by missing setup (no vault context, no blob
+/// storage), print the commands that fix it and, on a terminal, offer to
+/// run them. A failure here is only logged: the original error stands.
+async fn offer_setup_fix(error: &CrosstacheError) {
+    let is_no_vault = error.is_no_vault_context();
+    #[cfg(feature = "file-ops")]
+    let is_no_blob_storage = crate::cli::file_ops::is_missing_blob_storage(error);
+    #[cfg(not(feature = "file-ops"))]
+    let is_no_blob_storage = false;
+    if !is_no_vault && !is_no_blob_storage {
+        return;
+    }
+    let result = match load_config_without_validation().await {
+        Ok(config) if is_no_vault => crate::cli::config_ops::suggest_vault_context(&config).await,
+        Ok(config) => crate::cli::system_ops::offer_blob_only_init(config).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        error!("Could not offer a fix: {}", e);
+    }
+}
+
+/// After a transport failure, say plainly whether the machine is offline
+/// and point at what still works without a network.
+async fn report_offline(error: &CrosstacheError) {
+    use crate::utils::network::{is_unreachable_error, offline_reason};
+
+    if !is_unreachable_error(error) {
+        return;
+    }
+    let Some(reason) = offline_reason().await else {
+        return;
+    };
+    eprintln!("  offline: this machine has no network connection ({reason})");
+    eprintln!("  cached: `xv ls` and `xv vault list` show their last cached listing while offline");
+    let cache_dir = crate::cache::CacheManager::resolve_cache_dir();
+    if !crate::cache::offline::list_summaries(&cache_dir).is_empty() {
+        eprintln!(
+            "  cached: `xv get <name> --offline` reads the break-glass cache (`xv vault cache status`)"
+        );
+    }
+}
+
 async fn run_complete_secrets() -> Result<()> {
     // Load config without validation for internal complete-secrets command
     let config = load_config_without_validation().await?;
@@ -85,6 +150,42 @@ async fn run_complete_folders() -> Result<()> {
     crate::cli::secret_ops::execute_complete_folders(config).await
 }
 
+async fn run_complete_search(prefix: &str) -> Result<()> {
+    // Load config without validation for the internal completion command
+    let config = load_config_without_validation().await?;
+    crate::cli::index_ops::execute_complete_search(config, prefix).await
+}
+
+/// Extra time the `--timeout` watchdog allows before forcing an exit.
+const TIMEOUT_WATCHDOG_GRACE: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Run the command under a global `--timeout`. On expiry the command future
+/// is dropped, cancelling in-flight requests. A command stuck in a blocking
+/// call cannot be dropped that way, so a watchdog task on the runtime's
+/// worker threads exits the process shortly after the deadline instead.
+async fn run_with_timeout(cli: Cli, secs: u64) -> Result<()> {
+    let deadline = std::time::Duration::from_secs(secs);
+    let format = cli.format;
+    let watchdog = tokio::spawn(async move {
+        tokio::time::sleep(deadline + TIMEOUT_WATCHDOG_GRACE).await;
+        let e = command_timeout_error(secs);
+        print_user_friendly_error(&e, format);
+        std::process::exit(e.exit_code());
+    });
+
+    let result = tokio::time::timeout(deadline, run(cli))
+        .await
+        .unwrap_or_else(|_| Err(command_timeout_error(secs)));
+    watchdog.abort();
+    result
+}
+
+fn command_timeout_error(secs: u64) -> CrosstacheError {
+    CrosstacheError::connection_timeout(format!(
+        "command did not finish within {secs}s (--timeout)"
+    ))
+}
+
 async fn run(cli: Cli) -> Result<()> {
     info!("Starting crosstache");
 
@@ -291,9 +392,10 @@ Rebuild with `cargo build --features aws` or install an AWS-enabled binary.",
     let needs_backend = !matches!(
         cli.command,
         crate::cli::Commands::Config { .. }
-            | crate::cli::Commands::Init
+            | crate::cli::Commands::Init { .. }
             | crate::cli::Commands::Upgrade { .. }
             | crate::cli::Commands::Version
+            | crate::cli::Commands::Doctor
             | crate::cli::Commands::Completion { .. }
             | crate::cli::Commands::Parse { .. }
             | crate::cli::Commands::Cache { .. }
@@ -304,11 +406,17 @@ Rebuild with `cargo build --features aws` or install an AWS-enabled binary.",
             // `env pull` / `env push` DO talk to the active backend, so they are
             // intentionally excluded here and get a registry built below.
             | crate::cli::Commands::Env {
-                command: crate::cli::commands::EnvCommands::List
-                    | crate::cli::commands::EnvCommands::Use { .. }
-                    | crate::cli::commands::EnvCommands::Create { .. }
-                    | crate::cli::commands::EnvCommands::Delete { .. }
-                    | crate::cli::commands::EnvCommands::Show,
+                command: Some(
+                    crate::cli::commands::EnvCommands::List
+                        | crate::cli::commands::EnvCommands::Use { .. }
+                        | crate::cli::commands::EnvCommands::Create { .. }
+                        | crate::cli::commands::EnvCommands::Delete { .. }
+                        | crate::cli::commands::EnvCommands::Show,
+                ),
+                ..
+            }
+            | crate::cli::Commands::Direnv {
+                command: crate::cli::commands::DirenvCommands::Init { .. },
             }
             | crate::cli::Commands::Migrate { .. }
             | crate::cli::Commands::Scan {
@@ -413,6 +521,12 @@ fn print_user_friendly_error(error: &CrosstacheError, format: crate::utils::form
         if let Some(s) = error.suggestion() {
             envelope["error"]["suggestion"] = serde_json::Value::String(s.to_string());
         }
+        if let Some(p) = error.required_permission() {
+            envelope["error"]["required_permission"] = serde_json::Value::String(p.to_string());
+        }
+        if let Some(id) = crate::utils::correlation::sent_correlation_id() {
+            envelope["error"]["correlation_id"] = serde_json::Value::String(id.to_string());
+        }
         let rendered = match format {
             OutputFormat::Json => serde_json::to_string(&envelope).unwrap_or_default(),
             OutputFormat::Yaml => serde_yaml::to_string(&envelope).unwrap_or_default(),
@@ -431,6 +545,16 @@ fn print_user_friendly_error(error: &CrosstacheError, format: crate::utils::form
         eprintln!("  did you mean: {s}?");
     }
 
+    if let Some(p) = error.required_permission() {
+        eprintln!("  required permission: {p}");
+    }
+
+    // Only set once a request actually went to Azure; quote it to Microsoft
+    // support to locate the failing calls.
+    if let Some(id) = crate::utils::correlation::sent_correlation_id() {
+        eprintln!("  correlation id: {id}");
+    }
+
     if std::io::stderr().is_terminal() {
         if let Some(hint) = hint_for(error.code()) {
             eprintln!("  hint: {hint}");
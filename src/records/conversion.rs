@@ -787,6 +787,7 @@ mod tests {
             not_before: None,
             tags,
             content_type: content_type.into(),
+            id: None,
             recovery_level: None,
         }
     }
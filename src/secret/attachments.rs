@@ -218,6 +218,37 @@ pub async fn download_decrypted(
     Ok(plaintext.to_vec())
 }
 
+/// Stream `name` to `writer`, decrypting it first when it is a flagged
+/// attachment. Plain files are streamed chunk by chunk; an encrypted one is
+/// decrypted in memory, since age needs the whole ciphertext to verify it.
+/// Returns the number of bytes written.
+#[cfg(feature = "file-ops")]
+pub async fn download_decrypted_to_writer(
+    secrets: &dyn SecretBackend,
+    files: &dyn FileBackend,
+    vault: &str,
+    name: &str,
+    writer: &mut (dyn tokio::io::AsyncWrite + Unpin + Send),
+    reporter: Option<&dyn ProgressReporter>,
+) -> Result<u64> {
+    use tokio::io::AsyncWriteExt;
+
+    let info = files
+        .get_file_info(vault, name)
+        .await
+        .map_err(CrosstacheError::from)?;
+    if info.metadata.get(ENC_METADATA_KEY).map(String::as_str) != Some(ENC_METADATA_VALUE) {
+        return files
+            .download_file_to_writer(vault, name, writer, reporter)
+            .await
+            .map_err(CrosstacheError::from);
+    }
+    let plaintext = download_decrypted(secrets, files, vault, name, reporter).await?;
+    writer.write_all(&plaintext).await?;
+    writer.flush().await?;
+    Ok(plaintext.len() as u64)
+}
+
 /// List all attachments of `secret_name` (full blob names).
 #[cfg(feature = "file-ops")]
 pub async fn list_attachments(
@@ -304,6 +335,7 @@ mod tests {
             not_before: None,
             tags: HashMap::new(),
             content_type: String::new(),
+            id: None,
             recovery_level: None,
         }
     }
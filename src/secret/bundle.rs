@@ -0,0 +1,83 @@
+//! Bundles: several related settings (`host`, `port`, `user`, ...) kept as
+//! one secret.
+//!
+//! A bundle's value is a JSON object of strings, the same encoding as a
+//! record envelope, and its content type is [`BUNDLE_CONTENT_TYPE`]. Unlike
+//! a record it has no declared type: any keys may be added or removed.
+
+use std::collections::BTreeMap;
+
+use zeroize::Zeroizing;
+
+use crate::error::{CrosstacheError, Result};
+use crate::records::{encode_envelope, parse_envelope};
+
+/// Content type marking a secret as a bundle. Only an exact match counts.
+pub const BUNDLE_CONTENT_TYPE: &str = "application/vnd.xv.bundle";
+
+/// Returns true iff `content_type` exactly matches [`BUNDLE_CONTENT_TYPE`].
+pub fn is_bundle(content_type: &str) -> bool {
+    content_type == BUNDLE_CONTENT_TYPE
+}
+
+/// Keys are non-empty and limited to letters, digits, `_`, `-` and `.`, so
+/// they can be used in dotenv names and `{{ secret:name.key }}` references.
+pub fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(CrosstacheError::invalid_argument(format!(
+            "Invalid bundle key '{key}': use letters, digits, '_', '-' or '.'"
+        )));
+    }
+    Ok(())
+}
+
+/// Decode the value of bundle `name`.
+pub fn parse(name: &str, value: &str) -> Result<BTreeMap<String, String>> {
+    parse_envelope(value).map_err(|_| {
+        CrosstacheError::config(format!(
+            "secret '{name}' is marked as a bundle but its value is not a JSON object of strings"
+        ))
+    })
+}
+
+/// Encode `entries` as a bundle value, keys sorted.
+pub fn encode(entries: &BTreeMap<String, String>) -> Result<Zeroizing<String>> {
+    encode_envelope(entries).map(Zeroizing::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_entries() {
+        let entries = BTreeMap::from([
+            ("port".to_string(), "5432".to_string()),
+            ("host".to_string(), "db1".to_string()),
+        ]);
+        let encoded = encode(&entries).unwrap();
+        assert_eq!(encoded.as_str(), r#"{"host":"db1","port":"5432"}"#);
+        assert_eq!(parse("app-config", &encoded).unwrap(), entries);
+        assert!(parse("app-config", "[1]").is_err());
+    }
+
+    #[test]
+    fn keys_must_be_identifier_like() {
+        assert!(validate_key("db.host").is_ok());
+        assert!(validate_key("API_KEY-2").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key("has space").is_err());
+        assert!(validate_key("a=b").is_err());
+    }
+
+    #[test]
+    fn is_bundle_matches_exactly() {
+        assert!(is_bundle(BUNDLE_CONTENT_TYPE));
+        assert!(!is_bundle("application/json"));
+        assert!(!is_bundle(crate::records::RECORD_CONTENT_TYPE));
+    }
+}
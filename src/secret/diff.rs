@@ -0,0 +1,206 @@
+//! Secret comparison for `xv diff`
+//!
+//! Either side of a diff is a list of [`SecretProperties`], read from a
+//! vault or loaded from an `xv vault export --fmt json` file. Entries are
+//! matched by display name and compared on value and metadata with the same
+//! rules `xv vault sync` uses to decide what to copy, so a clean diff means
+//! a sync would do nothing. Values are never part of the result; callers
+//! that show them pick plaintext or a [`fingerprint`].
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::error::{CrosstacheError, Result};
+use crate::secret::manager::{groups_tag_contains, SecretProperties, SecretSummary};
+
+/// How a secret differs between the two sides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffKind {
+    /// Only on the second side.
+    Added,
+    /// Only on the first side.
+    Removed,
+    /// On both sides; lists what differs.
+    Changed(Vec<&'static str>),
+    Identical,
+}
+
+/// One secret's comparison result, by display name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffEntry {
+    pub name: String,
+    pub kind: DiffKind,
+}
+
+/// Compare `a` with `b`, in name order. A side read without values (a
+/// `--no-values` diff, or an export made without `--include-values`) makes
+/// that secret's value unknown, so it is not reported as different.
+pub fn diff_secrets(a: &[SecretProperties], b: &[SecretProperties]) -> Vec<DiffEntry> {
+    let a_by_name: HashMap<&str, &SecretProperties> =
+        a.iter().map(|s| (s.original_name.as_str(), s)).collect();
+    let b_by_name: HashMap<&str, &SecretProperties> =
+        b.iter().map(|s| (s.original_name.as_str(), s)).collect();
+    let names: std::collections::BTreeSet<&str> =
+        a_by_name.keys().chain(b_by_name.keys()).copied().collect();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let kind = match (a_by_name.get(name), b_by_name.get(name)) {
+                (None, _) => DiffKind::Added,
+                (_, None) => DiffKind::Removed,
+                (Some(a), Some(b)) => {
+                    let mut fields = crate::vault::sync::differing_fields(a, b);
+                    if a.value.is_none() || b.value.is_none() {
+                        fields.retain(|f| *f != "value");
+                    }
+                    if fields.is_empty() {
+                        DiffKind::Identical
+                    } else {
+                        DiffKind::Changed(fields)
+                    }
+                }
+            };
+            DiffEntry {
+                name: name.to_string(),
+                kind,
+            }
+        })
+        .collect()
+}
+
+/// A short, stable stand-in for a value: `sha256:` and the first 12 hex
+/// digits of its SHA-256. Enough to tell values apart on screen without
+/// printing them.
+pub fn fingerprint(value: &str) -> String {
+    let digest = hex::encode(Sha256::digest(value.as_bytes()));
+    format!("sha256:{}", &digest[..12])
+}
+
+/// Properties for a listed secret whose value was not read.
+pub fn properties_from_summary(summary: SecretSummary) -> SecretProperties {
+    SecretProperties {
+        name: summary.name,
+        original_name: summary.original_name,
+        value: None,
+        version: String::new(),
+        id: None,
+        version_number: None,
+        created_timestamp: 0,
+        created_on: String::new(),
+        updated_on: summary.updated_on,
+        enabled: summary.enabled,
+        expires_on: summary.expires_on,
+        not_before: None,
+        tags: summary.tags,
+        content_type: summary.content_type,
+        recovery_level: None,
+    }
+}
+
+/// Whether a `xv diff` argument names an export file rather than a vault:
+/// an existing `.json` file.
+pub fn is_export_file(arg: &str) -> bool {
+    let path = Path::new(arg);
+    path.extension().is_some_and(|ext| ext == "json") && path.is_file()
+}
+
+/// Load the secrets of an `xv vault export --fmt json` file. Entries
+/// exported without `--include-values` have no value. With `group`, only
+/// secrets tagged with that group are kept.
+pub fn load_export_file(path: &Path, group: Option<&str>) -> Result<Vec<SecretProperties>> {
+    let text = std::fs::read_to_string(path).map_err(|e| {
+        CrosstacheError::config(format!("Failed to read '{}': {e}", path.display()))
+    })?;
+    parse_export(&text, group).map_err(|e| match e {
+        CrosstacheError::SerializationError(msg) => {
+            CrosstacheError::serialization(format!("{}: {msg}", path.display()))
+        }
+        other => other,
+    })
+}
+
+fn parse_export(text: &str, group: Option<&str>) -> Result<Vec<SecretProperties>> {
+    let export = crate::vault::export_format::parse_export(text)?;
+    Ok(export
+        .secrets
+        .into_iter()
+        .filter(|secret| {
+            group.is_none_or(|group| {
+                groups_tag_contains(secret.tags.get("groups").map(String::as_str), group)
+            })
+        })
+        .map(|secret| SecretProperties {
+            name: secret.name.clone(),
+            original_name: secret.name,
+            value: secret.value.map(Zeroizing::new),
+            version: String::new(),
+            id: None,
+            version_number: None,
+            created_timestamp: 0,
+            created_on: String::new(),
+            updated_on: String::new(),
+            enabled: secret.enabled.unwrap_or(true),
+            expires_on: secret.expires_on,
+            not_before: secret.not_before,
+            tags: secret.tags.into_iter().collect(),
+            content_type: secret.content_type.unwrap_or_default(),
+            recovery_level: None,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{
+        "vault": "dev",
+        "exported_at": "2026-01-01T00:00:00Z",
+        "secrets": [
+            {"name": "db-url", "enabled": true, "content_type": "", "value": "a",
+             "tags": {"groups": "app", "original_name": "db-url"}},
+            {"name": "api-key", "enabled": true, "content_type": "", "value": "x"},
+            {"name": "no-value", "enabled": false, "content_type": ""}
+        ]
+    }"#;
+
+    #[test]
+    fn diffs_exports_by_name_value_and_metadata() {
+        let a = parse_export(EXPORT, None).unwrap();
+        let mut b = parse_export(EXPORT, None).unwrap();
+        b[0].value = Some(Zeroizing::new("changed".to_string()));
+        b[2].enabled = true;
+        b.remove(1);
+
+        let diff = diff_secrets(&a, &b);
+        let kinds: Vec<(&str, &DiffKind)> =
+            diff.iter().map(|e| (e.name.as_str(), &e.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("api-key", &DiffKind::Removed),
+                ("db-url", &DiffKind::Changed(vec!["value"])),
+                ("no-value", &DiffKind::Changed(vec!["enabled"])),
+            ]
+        );
+        assert!(diff_secrets(&b, &a)
+            .iter()
+            .any(|e| e.name == "api-key" && e.kind == DiffKind::Added));
+    }
+
+    #[test]
+    fn export_group_filter_and_fingerprints() {
+        let app = parse_export(EXPORT, Some("app")).unwrap();
+        assert_eq!(app.len(), 1);
+        assert_eq!(app[0].original_name, "db-url");
+        assert!(parse_export("{}", None).is_err());
+
+        assert_eq!(fingerprint("a"), fingerprint("a"));
+        assert_ne!(fingerprint("a"), fingerprint("b"));
+        assert_eq!(fingerprint("a").len(), "sha256:".len() + 12);
+    }
+}
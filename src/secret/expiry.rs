@@ -0,0 +1,250 @@
+//! Secret expiry report for `xv expiry-report`
+//!
+//! Sorts listed secrets into three buckets: already expired, expiring
+//! within a window, and without any expiration date. Secrets with an
+//! expiry further out than the window are counted but not listed. With a
+//! maximum age, secrets whose last `xv rotate` (the `rotated_at` tag) is
+//! older than that, or that were never rotated, are listed as stale.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::secret::manager::SecretSummary;
+use crate::secret::rotate::last_rotated;
+
+/// Why a secret is in the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpiryStatus {
+    Expired,
+    Expiring,
+    Stale,
+    NoExpiry,
+}
+
+impl std::fmt::Display for ExpiryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Expired => "expired",
+            Self::Expiring => "expiring",
+            Self::Stale => "stale",
+            Self::NoExpiry => "no expiry",
+        })
+    }
+}
+
+/// One reported secret.
+#[derive(Debug, Clone, Serialize, Tabled)]
+pub struct ExpiryEntry {
+    #[tabled(rename = "Vault")]
+    pub vault: String,
+    #[tabled(rename = "Name")]
+    pub name: String,
+    #[tabled(rename = "Status")]
+    pub status: ExpiryStatus,
+    #[tabled(rename = "Expires", display_with = "display_expiry")]
+    pub expires_on: Option<DateTime<Utc>>,
+    /// Whole days until expiry; negative once expired.
+    #[tabled(rename = "Days Left", display_with = "display_days")]
+    pub days_left: Option<i64>,
+    /// From the `rotated_at` tag written by `xv rotate`.
+    #[tabled(rename = "Last Rotated", display_with = "display_expiry")]
+    pub last_rotated: Option<DateTime<Utc>>,
+}
+
+fn display_expiry(expires_on: &Option<DateTime<Utc>>) -> String {
+    expires_on
+        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_default()
+}
+
+fn display_days(days: &Option<i64>) -> String {
+    days.map(|d| d.to_string()).unwrap_or_default()
+}
+
+/// The report over every scanned vault.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpiryReport {
+    pub vaults: Vec<String>,
+    pub within_days: i64,
+    /// Rotation age beyond which a secret is stale, when checked.
+    pub max_age_days: Option<i64>,
+    pub generated_at: DateTime<Utc>,
+    pub expired: usize,
+    pub expiring: usize,
+    pub stale: usize,
+    pub no_expiry: usize,
+    /// Secrets expiring after the window; not listed.
+    pub later: usize,
+    pub entries: Vec<ExpiryEntry>,
+}
+
+impl ExpiryReport {
+    /// Classify `secrets` (vault, summary) as of `now`. Entries are
+    /// ordered expired first, then expiring soonest first, then stale
+    /// (only with `max_age_days`), then those without an expiry, by vault
+    /// and name.
+    pub fn build(
+        vaults: Vec<String>,
+        secrets: impl IntoIterator<Item = (String, SecretSummary)>,
+        within_days: i64,
+        max_age_days: Option<i64>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let horizon = now + Duration::days(within_days);
+        let mut later = 0;
+        let mut entries: Vec<ExpiryEntry> = secrets
+            .into_iter()
+            .filter_map(|(vault, secret)| {
+                let last_rotated = last_rotated(&secret.tags);
+                let stale = max_age_days.is_some_and(|days| {
+                    last_rotated.is_none_or(|at| at <= now - Duration::days(days))
+                });
+                let status = match secret.expires_on {
+                    Some(at) if at <= now => ExpiryStatus::Expired,
+                    Some(at) if at <= horizon => ExpiryStatus::Expiring,
+                    _ if stale => ExpiryStatus::Stale,
+                    None => ExpiryStatus::NoExpiry,
+                    Some(_) => {
+                        later += 1;
+                        return None;
+                    }
+                };
+                let name = if secret.original_name.is_empty() {
+                    secret.name
+                } else {
+                    secret.original_name
+                };
+                Some(ExpiryEntry {
+                    vault,
+                    name,
+                    status,
+                    expires_on: secret.expires_on,
+                    days_left: secret.expires_on.map(|at| (at - now).num_days()),
+                    last_rotated,
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (a.status, a.expires_on, &a.vault, &a.name).cmp(&(
+                b.status,
+                b.expires_on,
+                &b.vault,
+                &b.name,
+            ))
+        });
+
+        let count = |status| entries.iter().filter(|e| e.status == status).count();
+        Self {
+            vaults,
+            within_days,
+            max_age_days,
+            generated_at: now,
+            expired: count(ExpiryStatus::Expired),
+            expiring: count(ExpiryStatus::Expiring),
+            stale: count(ExpiryStatus::Stale),
+            no_expiry: count(ExpiryStatus::NoExpiry),
+            later,
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn summary(name: &str, expires_on: Option<DateTime<Utc>>) -> SecretSummary {
+        SecretSummary {
+            name: name.to_string(),
+            original_name: name.to_string(),
+            note: None,
+            folder: None,
+            groups: None,
+            updated_on: String::new(),
+            enabled: true,
+            expires_on,
+            content_type: String::new(),
+            tags: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn buckets_and_orders_secrets_by_urgency() {
+        let now = Utc::now();
+        let secrets = vec![
+            (
+                "kv".to_string(),
+                summary("later", Some(now + Duration::days(90))),
+            ),
+            ("kv".to_string(), summary("none", None)),
+            (
+                "kv".to_string(),
+                summary("soon", Some(now + Duration::days(3))),
+            ),
+            (
+                "kv".to_string(),
+                summary("sooner", Some(now + Duration::hours(30))),
+            ),
+            (
+                "kv".to_string(),
+                summary("gone", Some(now - Duration::days(2))),
+            ),
+        ];
+
+        let report = ExpiryReport::build(vec!["kv".to_string()], secrets, 30, None, now);
+        let names: Vec<&str> = report.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["gone", "sooner", "soon", "none"]);
+        assert_eq!(
+            (
+                report.expired,
+                report.expiring,
+                report.no_expiry,
+                report.later
+            ),
+            (1, 2, 1, 1)
+        );
+        assert_eq!(report.entries[0].days_left, Some(-2));
+        assert_eq!(report.entries[1].days_left, Some(1));
+        assert_eq!(report.entries[3].days_left, None);
+    }
+
+    #[test]
+    fn max_age_flags_secrets_not_rotated_recently() {
+        let now = Utc::now();
+        let rotated = |days_ago: i64| {
+            let mut secret = summary("x", Some(now + Duration::days(365)));
+            secret.tags.insert(
+                crate::secret::rotate::TAG_ROTATED_AT.to_string(),
+                (now - Duration::days(days_ago)).to_rfc3339(),
+            );
+            secret
+        };
+        let mut fresh = rotated(10);
+        fresh.name = "fresh".to_string();
+        fresh.original_name = "fresh".to_string();
+        let mut old = rotated(120);
+        old.name = "old".to_string();
+        old.original_name = "old".to_string();
+        let secrets = vec![
+            ("kv".to_string(), fresh),
+            ("kv".to_string(), old),
+            ("kv".to_string(), summary("never", None)),
+        ];
+
+        let report = ExpiryReport::build(vec!["kv".to_string()], secrets, 30, Some(90), now);
+        let flagged: Vec<(&str, ExpiryStatus)> = report
+            .entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.status))
+            .collect();
+        assert_eq!(
+            flagged,
+            vec![("never", ExpiryStatus::Stale), ("old", ExpiryStatus::Stale)]
+        );
+        assert_eq!((report.stale, report.later), (2, 1));
+        assert!(report.entries[1].last_rotated.is_some());
+    }
+}
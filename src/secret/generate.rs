@@ -0,0 +1,138 @@
+//! Passphrases and encoded random bytes for `xv gen`
+//!
+//! `xv gen --passphrase` draws words from a built-in 1024-word list and
+//! `xv gen --encoding` writes random bytes as hex or base64. Character
+//! passwords come from [`crate::cli::helpers::generate_random_value`]. All
+//! randomness comes from the thread-local CSPRNG.
+
+use base64::Engine;
+use zeroize::Zeroizing;
+
+use crate::error::{CrosstacheError, Result};
+
+/// Words for passphrases: 1024 short, common, lowercase English words, so
+/// each word adds exactly 10 bits of entropy.
+const WORDLIST: &str = include_str!("wordlist.txt");
+
+/// How random bytes are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ByteEncoding {
+    /// Lowercase hexadecimal, two characters per byte
+    Hex,
+    /// Standard base64 with padding
+    Base64,
+}
+
+/// What to generate instead of a character password.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Generator<'a> {
+    /// `words` random words joined by `separator`.
+    Passphrase { words: usize, separator: &'a str },
+    /// `count` random bytes, encoded.
+    Bytes {
+        count: usize,
+        encoding: ByteEncoding,
+    },
+}
+
+impl Generator<'_> {
+    /// Reject sizes too small to be secret or too large to be useful.
+    pub fn validate(&self) -> Result<()> {
+        let (size, range, unit) = match self {
+            Self::Passphrase { words, .. } => (*words, 3..=64, "Word count"),
+            Self::Bytes { count, .. } => (*count, 8..=1024, "Byte count"),
+        };
+        if !range.contains(&size) {
+            return Err(CrosstacheError::invalid_argument(format!(
+                "{unit} must be between {} and {}",
+                range.start(),
+                range.end()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Generate one value.
+    pub fn generate(&self) -> Result<Zeroizing<String>> {
+        use rand::prelude::*;
+
+        self.validate()?;
+        let mut rng = rand::rng();
+        let value = match self {
+            Self::Passphrase { words, separator } => {
+                let list = wordlist();
+                (0..*words)
+                    .map(|_| list[rng.random_range(0..list.len())])
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            }
+            Self::Bytes { count, encoding } => {
+                let mut bytes = Zeroizing::new(vec![0u8; *count]);
+                rng.fill_bytes(&mut bytes);
+                match encoding {
+                    ByteEncoding::Hex => hex::encode(&*bytes),
+                    ByteEncoding::Base64 => {
+                        base64::engine::general_purpose::STANDARD.encode(&*bytes)
+                    }
+                }
+            }
+        };
+        Ok(Zeroizing::new(value))
+    }
+}
+
+fn wordlist() -> Vec<&'static str> {
+    WORDLIST.lines().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wordlist_has_1024_distinct_lowercase_words() {
+        let list = wordlist();
+        let distinct: std::collections::HashSet<&str> = list.iter().copied().collect();
+        assert_eq!(list.len(), 1024);
+        assert_eq!(distinct.len(), 1024);
+        assert!(list
+            .iter()
+            .all(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_lowercase())));
+    }
+
+    #[test]
+    fn generates_each_shape_with_expected_size() {
+        let phrase = Generator::Passphrase {
+            words: 5,
+            separator: ".",
+        };
+        assert_eq!(phrase.generate().unwrap().split('.').count(), 5);
+
+        let hex = Generator::Bytes {
+            count: 16,
+            encoding: ByteEncoding::Hex,
+        };
+        assert_eq!(hex.generate().unwrap().len(), 32);
+        let b64 = Generator::Bytes {
+            count: 32,
+            encoding: ByteEncoding::Base64,
+        };
+        assert_eq!(b64.generate().unwrap().len(), 44);
+    }
+
+    #[test]
+    fn rejects_out_of_range_sizes() {
+        assert!(Generator::Passphrase {
+            words: 2,
+            separator: "-"
+        }
+        .validate()
+        .is_err());
+        assert!(Generator::Bytes {
+            count: 4096,
+            encoding: ByteEncoding::Hex
+        }
+        .validate()
+        .is_err());
+    }
+}
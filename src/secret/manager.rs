@@ -14,10 +14,17 @@ use tabled::Tabled;
 use zeroize::Zeroizing;
 
 use crate::auth::provider::AzureAuthProvider;
+use crate::backend::azure::kv_api::{Capability, KeyVaultApi};
+use crate::backend::azure::kv_rest::{
+    read_json, BackupBlob, DeletedSecretBundle, KvRestClient, Page, SecretAttributes, SecretBundle,
+    SecretSetParameters, SecretUpdateParameters,
+};
 use crate::backend::azure::types::AzureVaultName;
 use crate::error::{CrosstacheError, Result};
 use crate::utils::helpers::{parse_connection_string, validate_folder_path};
-use crate::utils::network::{classify_network_error, create_http_client, NetworkConfig};
+use crate::utils::network::{
+    classify_http_error, classify_http_response, is_deleted_but_recoverable,
+};
 use crate::utils::sanitizer::sanitize_secret_name;
 
 /// Secret properties and metadata
@@ -31,6 +38,11 @@ pub struct SecretProperties {
     pub value: Option<Zeroizing<String>>,
     #[tabled(skip)]
     pub version: String,
+    /// Full backend identifier of this version (the Azure secret id URI or
+    /// the AWS secret ARN); `version` holds only the version segment.
+    #[tabled(skip)]
+    #[serde(default)]
+    pub id: Option<String>,
     /// Human-readable sequential version number (1 = oldest). None when not in a version list context.
     #[tabled(rename = "Version", display_with = "display_version_number")]
     pub version_number: Option<u32>,
@@ -195,6 +207,12 @@ pub struct SecretSummary {
     pub tags: HashMap<String, String>,
 }
 
+/// Whether a comma-separated `groups` tag names `group`. Entries are trimmed
+/// and compared exactly, so `production` is not in group `prod`.
+pub(crate) fn groups_tag_contains(groups: Option<&str>, group: &str) -> bool {
+    groups.is_some_and(|groups| groups.split(',').any(|g| g.trim() == group))
+}
+
 /// Summary of a soft-deleted secret awaiting purge.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeletedSecretSummary {
@@ -204,6 +222,36 @@ pub struct DeletedSecretSummary {
     pub deleted_on: Option<String>,
     /// When the backend will permanently purge it (None = no schedule).
     pub scheduled_purge_on: Option<String>,
+    /// Backend recovery level (Azure `recoveryLevel`, e.g.
+    /// `Recoverable+Purgeable`), when known.
+    #[serde(default)]
+    pub recovery_level: Option<String>,
+    /// Tags at deletion time, with groups and folder under the canonical
+    /// `groups`/`folder` keys, for the bulk `restore`/`purge` filters.
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+impl DeletedSecretSummary {
+    /// Whether the backend allows recovering this entry. Azure reports
+    /// levels without "Recoverable" (plain `Purgeable`) for vaults whose
+    /// deleted items can only be purged; unknown levels are assumed
+    /// recoverable.
+    pub fn is_recoverable(&self) -> bool {
+        self.recovery_level
+            .as_deref()
+            .is_none_or(|level| level.contains("Recoverable"))
+    }
+
+    /// Whether the backend allows purging this entry before its scheduled
+    /// date. Azure levels without "Purgeable" (`Recoverable`,
+    /// `CustomizedRecoverable+ProtectedSubscription`, ...) mean purge
+    /// protection is on; unknown levels are assumed purgeable.
+    pub fn is_purgeable(&self) -> bool {
+        self.recovery_level
+            .as_deref()
+            .is_none_or(|level| level.contains("Purgeable"))
+    }
 }
 
 /// Connection string component
@@ -283,6 +331,11 @@ pub trait SecretOperations: Send + Sync {
         group_filter: Option<&str>,
     ) -> Result<Vec<SecretSummary>>;
 
+    /// Count the secrets in a vault without fetching per-secret details.
+    async fn count_secrets(&self, vault_name: &str) -> Result<usize> {
+        Ok(self.list_secrets(vault_name, None).await?.len())
+    }
+
     /// Delete a secret (soft delete)
     async fn delete_secret(&self, vault_name: &str, secret_name: &str) -> Result<()>;
 
@@ -324,6 +377,20 @@ pub trait SecretOperations: Send + Sync {
     /// List soft-deleted secrets awaiting purge.
     async fn list_deleted_secrets(&self, vault_name: &str) -> Result<Vec<DeletedSecretSummary>>;
 
+    /// Look up one soft-deleted secret; `None` when no deleted secret has
+    /// that name.
+    async fn get_deleted_secret(
+        &self,
+        vault_name: &str,
+        secret_name: &str,
+    ) -> Result<Option<DeletedSecretSummary>> {
+        Ok(self
+            .list_deleted_secrets(vault_name)
+            .await?
+            .into_iter()
+            .find(|s| s.name == secret_name || s.original_name == secret_name))
+    }
+
     /// Check if secret exists
     async fn secret_exists(&self, vault_name: &str, secret_name: &str) -> Result<bool>;
 
@@ -355,46 +422,35 @@ pub trait SecretOperations: Send + Sync {
     ) -> Result<SecretProperties>;
 }
 
-/// Deserialize a JSON response body while enforcing a hard size limit.
-///
-/// Checks the `Content-Length` header first (fast path) and then verifies
-/// the actual byte count after buffering, to guard against oversized responses.
-async fn read_json_body<T: serde::de::DeserializeOwned>(
-    response: reqwest::Response,
-    max_bytes: usize,
-) -> Result<T> {
-    if let Some(content_length) = response.content_length() {
-        if content_length > max_bytes as u64 {
-            return Err(CrosstacheError::azure_api(format!(
-                "Response body too large: {} bytes (max: {} bytes)",
-                content_length, max_bytes
-            )));
-        }
-    }
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|e| CrosstacheError::azure_api(format!("Failed to read response body: {e}")))?;
-    if bytes.len() > max_bytes {
-        return Err(CrosstacheError::azure_api(format!(
-            "Response body too large: {} bytes (max: {} bytes)",
-            bytes.len(),
-            max_bytes
-        )));
-    }
-    serde_json::from_slice(&bytes)
-        .map_err(|e| CrosstacheError::serialization(format!("Failed to parse JSON response: {e}")))
-}
-
 /// Azure Key Vault secret operations implementation
 pub struct AzureSecretOperations {
     auth_provider: Arc<dyn AzureAuthProvider>,
+    /// Most per-secret detail requests `list_secrets` keeps in flight.
+    detail_concurrency: usize,
+    rest: KvRestClient,
 }
 
 impl AzureSecretOperations {
     /// Create a new Azure secret operations instance
     pub fn new(auth_provider: Arc<dyn AzureAuthProvider>) -> Self {
-        Self { auth_provider }
+        Self {
+            rest: KvRestClient::new(auth_provider.clone()),
+            auth_provider,
+            detail_concurrency: 10,
+        }
+    }
+
+    /// Build REST calls against `api` (`key_vault_api_version` in config).
+    pub fn with_api(mut self, api: KeyVaultApi) -> Self {
+        self.rest = self.rest.with_api(api);
+        self
+    }
+
+    /// Set how many per-secret detail requests `list_secrets` keeps in
+    /// flight (`fetch_concurrency` in config); at least one.
+    pub fn with_detail_concurrency(mut self, limit: usize) -> Self {
+        self.detail_concurrency = limit.max(1);
+        self
     }
 
     fn validated_vault_name(&self, vault_name: &str) -> Result<AzureVaultName> {
@@ -406,22 +462,7 @@ impl AzureSecretOperations {
         vault_name: &AzureVaultName,
         path_segments: &[&str],
     ) -> Result<String> {
-        let mut url = vault_name.key_vault_url()?;
-        {
-            let mut segments = url.path_segments_mut().map_err(|_| {
-                CrosstacheError::invalid_url(format!(
-                    "Cannot build Key Vault URL for vault '{}'",
-                    vault_name.as_str()
-                ))
-            })?;
-            segments.clear();
-            segments.extend(path_segments.iter().copied());
-        }
-        // Key Vault REST API 7.4 is stable and covers the operations we use;
-        // keep this explicit so SDK crate version bumps do not silently change
-        // the wire contract.
-        url.query_pairs_mut().append_pair("api-version", "7.4");
-        Ok(url.to_string())
+        self.rest.url(vault_name, path_segments)
     }
 
     /// Create a secret client for the specified vault
@@ -521,64 +562,46 @@ impl AzureSecretOperations {
     }
 }
 
-/// Read the body of an HTTP error response for diagnostic messages.
-///
-/// If reading the body fails (e.g. the connection was dropped), returns a
-/// descriptive placeholder rather than a bare empty string so callers always
-/// have actionable context in their error messages.
-async fn read_error_body(response: reqwest::Response) -> String {
-    response
-        .text()
-        .await
-        .unwrap_or_else(|e| format!("(failed to read error body: {e})"))
-}
-
-/// Parse one item from Azure's `GET {vault}/deletedsecrets` (api 7.4).
-/// `deletedDate`/`scheduledPurgeDate` are top-level epoch-second fields on
-/// the deleted-secret item (not under `attributes`).
-fn parse_deleted_secret_summary(item: &serde_json::Value) -> Option<DeletedSecretSummary> {
-    let id = item.get("id").and_then(|v| v.as_str())?;
+/// Summarize one item from `GET {vault}/deletedsecrets`, or the bundle from
+/// `GET {vault}/deletedsecrets/{name}`. The deleted-secret `id` ends in the
+/// name; an item without one is skipped.
+fn deleted_secret_summary(item: &DeletedSecretBundle) -> Option<DeletedSecretSummary> {
+    let id = item.secret.id.as_deref()?;
     let name = id.rsplit('/').next().unwrap_or(id).to_string();
     if name.is_empty() {
         return None;
     }
 
-    let epoch_string = |key: &str| {
-        item.get(key)
-            .and_then(|v| v.as_i64())
-            .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+    let epoch_string = |ts: Option<i64>| {
+        ts.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
             .map(|dt| dt.to_string())
     };
 
-    let original_name = item
-        .get("tags")
-        .and_then(|v| v.as_object())
-        .and_then(|tags| {
-            tags.get("original_name")
-                .or_else(|| tags.get("name"))
-                .and_then(|v| v.as_str())
-        })
-        .map(str::to_string)
-        .unwrap_or_else(|| name.clone());
-
     Some(DeletedSecretSummary {
+        original_name: original_name_from_tags(&name, &item.secret.tags),
         name,
-        original_name,
-        deleted_on: epoch_string("deletedDate"),
-        scheduled_purge_on: epoch_string("scheduledPurgeDate"),
+        deleted_on: epoch_string(item.deleted_date),
+        scheduled_purge_on: epoch_string(item.scheduled_purge_date),
+        recovery_level: item.secret.attributes.recovery_level.clone(),
+        tags: item.secret.tags.clone(),
     })
 }
 
-fn json_string_tags(json: &serde_json::Value) -> HashMap<String, String> {
-    let mut tags = HashMap::new();
-    if let Some(tags_obj) = json.get("tags").and_then(|v| v.as_object()) {
-        for (key, value) in tags_obj {
-            if let Some(tag_value) = value.as_str() {
-                tags.insert(key.clone(), tag_value.to_string());
-            }
-        }
-    }
-    tags
+/// Error text for a write refused because `name` still belongs to a
+/// soft-deleted secret, naming the purge date when it is known.
+pub fn deleted_name_conflict_message(name: &str, deleted: Option<&DeletedSecretSummary>) -> String {
+    let until = deleted
+        .and_then(|d| d.scheduled_purge_on.as_deref())
+        .map(|date| format!(" until {}", date.split_whitespace().next().unwrap_or(date)))
+        .unwrap_or_default();
+    let level = deleted
+        .and_then(|d| d.recovery_level.as_deref())
+        .map(|level| format!(" (recovery level: {level})"))
+        .unwrap_or_default();
+    format!(
+        "Secret '{name}' is deleted but recoverable{level}, so its name cannot be reused{until}. \
+         Restore it with `xv restore {name}` or remove it for good with `xv purge {name}`."
+    )
 }
 
 fn original_name_from_tags(fallback: &str, tags: &HashMap<String, String>) -> String {
@@ -588,96 +611,109 @@ fn original_name_from_tags(fallback: &str, tags: &HashMap<String, String>) -> St
         .unwrap_or_else(|| fallback.to_string())
 }
 
-fn timestamp_string(attributes: &serde_json::Value, field: &str) -> String {
-    attributes
-        .get(field)
-        .and_then(|v| v.as_i64())
-        .map(|ts| {
-            DateTime::from_timestamp(ts, 0)
-                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-                .unwrap_or_else(|| "Unknown".to_string())
-        })
+fn timestamp_string(ts: Option<i64>) -> String {
+    ts.and_then(|ts| DateTime::from_timestamp(ts, 0))
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
         .unwrap_or_else(|| "Unknown".to_string())
 }
 
-fn optional_timestamp(attributes: &serde_json::Value, field: &str) -> Option<DateTime<Utc>> {
-    attributes
-        .get(field)
-        .and_then(|v| v.as_i64())
-        .and_then(|ts| DateTime::from_timestamp(ts, 0))
+fn optional_timestamp(ts: Option<i64>) -> Option<DateTime<Utc>> {
+    ts.and_then(|ts| DateTime::from_timestamp(ts, 0))
 }
 
-fn parse_secret_properties_bundle(
-    json: &serde_json::Value,
+/// The writable attributes of a set or update request.
+fn request_attributes(
+    enabled: Option<bool>,
+    expires_on: Option<DateTime<Utc>>,
+    not_before: Option<DateTime<Utc>>,
+) -> SecretAttributes {
+    SecretAttributes {
+        enabled,
+        exp: expires_on.map(|dt| dt.timestamp()),
+        nbf: not_before.map(|dt| dt.timestamp()),
+        ..Default::default()
+    }
+}
+
+/// Confirm a `PUT {vault}/secrets/{name}` response describes the write just
+/// made: its `id` must name the secret and end in a version segment.
+///
+/// The PUT bundle is the authoritative record of the new version — a
+/// read-back GET can race replication and report the previous version — so
+/// a response that doesn't identify the version it created is an error
+/// rather than something to reconcile with a follow-up read.
+fn confirm_written_version(bundle: &SecretBundle, sanitized_name: &str) -> Result<()> {
+    let (name, version) = bundle.name_and_version();
+    // Key Vault secret names are case-insensitive; the service echoes the
+    // stored casing, which may differ from the request path.
+    if version.is_empty() || !name.eq_ignore_ascii_case(sanitized_name) {
+        return Err(CrosstacheError::azure_api(format!(
+            "Set secret response did not confirm the new version of '{sanitized_name}' \
+             (response id: '{}')",
+            bundle.id.as_deref().unwrap_or_default()
+        )));
+    }
+    Ok(())
+}
+
+/// Page size requested by [`SecretOperations::count_secrets`]; 25 is the
+/// largest `maxresults` Key Vault accepts for the secrets list.
+const SECRET_COUNT_PAGE_SIZE: usize = 25;
+
+/// Count the secret items on one `GET {vault}/secrets` page.
+fn count_secret_list_page(page: &Page<SecretBundle>) -> usize {
+    page.value.iter().filter(|item| item.id.is_some()).count()
+}
+
+/// Convert a secret bundle to [`SecretProperties`]. `fallback_name` and
+/// `default_version` fill in for an `id` that lacks them; the value is kept
+/// only when `include_value` is set.
+fn secret_properties_from_bundle(
+    bundle: SecretBundle,
     fallback_name: &str,
     include_value: bool,
     default_version: &str,
-) -> Result<SecretProperties> {
-    let id = json.get("id").and_then(|v| v.as_str());
-    let name = id
-        .and_then(|id| id.rsplit('/').nth(1))
-        .filter(|s| !s.is_empty())
-        .unwrap_or(fallback_name)
-        .to_string();
-    let version = id
-        .and_then(|id| id.split('/').next_back())
-        .filter(|s| !s.is_empty())
-        .unwrap_or(default_version)
-        .to_string();
-
-    let attributes = json.get("attributes").unwrap_or(&serde_json::Value::Null);
-    let enabled = attributes
-        .get("enabled")
-        .and_then(|v| v.as_bool())
-        .unwrap_or(true);
-    let created_ts = attributes
-        .get("created")
-        .and_then(|v| v.as_i64())
-        .unwrap_or(0);
-    let tags = json_string_tags(json);
-    let original_name = original_name_from_tags(&name, &tags);
-    let value = if include_value {
-        json.get("value")
-            .and_then(|v| v.as_str())
-            .map(|s| Zeroizing::new(s.to_string()))
+) -> SecretProperties {
+    let (name, version) = bundle.name_and_version();
+    let name = if name.is_empty() { fallback_name } else { name }.to_string();
+    let version = if version.is_empty() {
+        default_version
     } else {
-        None
-    };
+        version
+    }
+    .to_string();
+    let original_name = original_name_from_tags(&name, &bundle.tags);
+    let attributes = bundle.attributes;
 
-    Ok(SecretProperties {
+    SecretProperties {
         name,
         original_name,
-        value,
+        value: if include_value { bundle.value } else { None },
         version,
-        created_on: timestamp_string(attributes, "created"),
-        updated_on: timestamp_string(attributes, "updated"),
-        enabled,
-        expires_on: optional_timestamp(attributes, "exp"),
-        not_before: optional_timestamp(attributes, "nbf"),
-        tags,
-        content_type: json
-            .get("contentType")
-            .and_then(|v| v.as_str())
-            .unwrap_or("text/plain")
-            .to_string(),
+        created_on: timestamp_string(attributes.created),
+        updated_on: timestamp_string(attributes.updated),
+        enabled: attributes.enabled.unwrap_or(true),
+        expires_on: optional_timestamp(attributes.exp),
+        not_before: optional_timestamp(attributes.nbf),
+        tags: bundle.tags,
+        // Absent means the secret was stored without one; don't invent a type.
+        content_type: bundle.content_type.unwrap_or_default(),
         version_number: None,
-        created_timestamp: created_ts,
-        recovery_level: attributes
-            .get("recoveryLevel")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-    })
+        created_timestamp: attributes.created.unwrap_or(0),
+        id: bundle.id,
+        recovery_level: attributes.recovery_level,
+    }
 }
 
 // These codecs are macros rather than private functions because backup and
 // restore are opt-in trait operations. The UI-only binary cannot reach those
 // methods, but unit tests still expand and exercise the exact production logic.
 macro_rules! decode_backup_value {
-    ($json:expr) => {{
+    ($blob:expr) => {{
         use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
-        let json: &serde_json::Value = $json;
-        json.get("value")
-            .and_then(|value| value.as_str())
+        let blob: &BackupBlob = $blob;
+        blob.value
+            .as_deref()
             .ok_or_else(|| CrosstacheError::azure_api("Backup response missing 'value' field"))
             .and_then(|value| {
                 URL_SAFE_NO_PAD
@@ -694,26 +730,25 @@ macro_rules! decode_backup_value {
 macro_rules! build_restore_request_body {
     ($backup_data:expr) => {{
         use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
-        serde_json::json!({ "value": URL_SAFE_NO_PAD.encode($backup_data) })
+        BackupBlob {
+            value: Some(URL_SAFE_NO_PAD.encode($backup_data)),
+        }
     }};
 }
 
 macro_rules! parse_restored_secret_properties {
-    ($json:expr) => {{
-        let json: &serde_json::Value = $json;
+    ($bundle:expr) => {{
+        let bundle: SecretBundle = $bundle;
         (|| -> Result<SecretProperties> {
-            let id = json
-                .get("id")
-                .and_then(|value| value.as_str())
-                .ok_or_else(|| {
-                    CrosstacheError::azure_api("Restore response missing secret 'id' field")
-                })?;
+            let id = bundle.id.as_deref().ok_or_else(|| {
+                CrosstacheError::azure_api("Restore response missing secret 'id' field")
+            })?;
             if id.rsplit('/').nth(1).is_none_or(str::is_empty) {
                 return Err(CrosstacheError::azure_api(format!(
                     "Restore response contained unexpected secret id '{id}'"
                 )));
             }
-            parse_secret_properties_bundle(json, "", false, "")
+            Ok(secret_properties_from_bundle(bundle, "", false, ""))
         })()
     }};
 }
@@ -731,84 +766,57 @@ impl SecretOperations for AzureSecretOperations {
         // The Azure Key Vault SDK crate does not expose the full tag-bearing
         // SecretBundle shape for this flow, so use REST directly.
         let secret_url = self.key_vault_api_url(&vault_name, &["secrets", &sanitized_name])?;
+        let body = SecretSetParameters {
+            value: request.value.as_str(),
+            tags: (!tags.is_empty()).then_some(&tags),
+            content_type: request.content_type.as_deref(),
+            attributes: request_attributes(request.enabled, request.expires_on, request.not_before),
+        };
 
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
+        let response = self
+            .rest
+            .send(reqwest::Method::PUT, &secret_url, Some(&body))
             .await?;
 
-        // Create the request body
-        let mut body = serde_json::json!({
-            "value": request.value,
-        });
-
-        // Add tags if any
-        if !tags.is_empty() {
-            body["tags"] = serde_json::json!(tags);
-        }
-
-        // Add content type if specified
-        if let Some(content_type) = &request.content_type {
-            body["contentType"] = serde_json::json!(content_type);
-        }
-
-        // Add attributes
-        let mut attributes = serde_json::json!({});
-        if let Some(enabled) = request.enabled {
-            attributes["enabled"] = serde_json::json!(enabled);
-        }
-        if let Some(expires_on) = request.expires_on {
-            attributes["exp"] = serde_json::json!(expires_on.timestamp());
-        }
-        if let Some(not_before) = request.not_before {
-            attributes["nbf"] = serde_json::json!(not_before.timestamp());
-        }
-        if attributes.as_object().is_some_and(|obj| !obj.is_empty()) {
-            body["attributes"] = attributes;
+        if response.status() == reqwest::StatusCode::CONFLICT {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if is_deleted_but_recoverable(&body) {
+                let deleted = self
+                    .get_deleted_secret(vault_name.as_str(), &sanitized_name)
+                    .await
+                    .ok()
+                    .flatten();
+                return Err(CrosstacheError::Conflict(deleted_name_conflict_message(
+                    &request.name,
+                    deleted.as_ref(),
+                )));
+            }
+            return Err(classify_http_error(
+                status,
+                None,
+                &body,
+                &secret_url,
+                "Failed to set secret",
+            ));
         }
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-        headers.insert(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-
-        // Make the REST API call
-        let response = client
-            .put(&secret_url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &secret_url))?;
-
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to set secret: HTTP {status} - {error_text}"
-            )));
+            return Err(classify_http_response(response, "Failed to set secret").await);
         }
 
-        // Parse the response and convert to SecretProperties. The PUT
-        // response is a full secret bundle (id, value, attributes, tags), so
-        // build the result from it directly instead of a confirmation GET —
-        // a follow-up GET would return HTTP 403 `SecretDisabled` when this
-        // write just disabled the secret (enabled=false), failing the
-        // operation *after* the write succeeded.
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-        parse_secret_properties_bundle(&json, &sanitized_name, true, "")
+        // The PUT response is a full secret bundle (id, value, attributes,
+        // tags), so build the result from it directly instead of a
+        // confirmation GET — a follow-up GET would return HTTP 403
+        // `SecretDisabled` when this write just disabled the secret
+        // (enabled=false), failing the operation *after* the write succeeded.
+        let bundle: SecretBundle = read_json(response).await?;
+        confirm_written_version(&bundle, &sanitized_name)?;
+        Ok(secret_properties_from_bundle(
+            bundle,
+            &sanitized_name,
+            true,
+            "",
+        ))
     }
 
     async fn get_secret(
@@ -823,51 +831,21 @@ impl SecretOperations for AzureSecretOperations {
         // The Azure Key Vault SDK crate does not expose all tags consistently here,
         // so use REST directly to get full secret details including tags
         let secret_url = self.key_vault_api_url(&vault_name, &["secrets", &sanitized_name])?;
+        let bundle: SecretBundle = self
+            .rest
+            .get(&secret_url, "Failed to get secret")
+            .await?
+            .ok_or_else(|| CrosstacheError::SecretNotFound {
+                name: secret_name.to_string(),
+                suggestion: None,
+            })?;
 
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-
-        // Make the REST API call
-        let response = client
-            .get(&secret_url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &secret_url))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            if status == 404 {
-                return Err(CrosstacheError::SecretNotFound {
-                    name: secret_name.to_string(),
-                    suggestion: None,
-                });
-            }
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to get secret: HTTP {status} - {error_text}"
-            )));
-        }
-
-        // Parse the response
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-
-        parse_secret_properties_bundle(&json, &sanitized_name, include_value, "")
+        Ok(secret_properties_from_bundle(
+            bundle,
+            &sanitized_name,
+            include_value,
+            "",
+        ))
     }
 
     async fn get_secret_version(
@@ -881,52 +859,22 @@ impl SecretOperations for AzureSecretOperations {
         let sanitized_name = sanitize_secret_name(secret_name)?;
         let secret_url =
             self.key_vault_api_url(&vault_name, &["secrets", &sanitized_name, version])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let http_client = create_http_client(&network_config)?;
-
-        // Make the REST API call
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-
-        let response = http_client
-            .get(&secret_url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &secret_url))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            if status == 404 {
-                return Err(CrosstacheError::azure_api(format!(
+        let bundle: SecretBundle = self
+            .rest
+            .get(&secret_url, "Failed to get secret version")
+            .await?
+            .ok_or_else(|| {
+                CrosstacheError::azure_api(format!(
                     "Secret version '{version}' not found for secret '{secret_name}'"
-                )));
-            }
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to get secret version: HTTP {} - {}",
-                status, error_text
-            )));
-        }
-
-        // Parse the JSON response
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
+                ))
+            })?;
 
-        parse_secret_properties_bundle(&json, secret_name, include_value, version)
+        Ok(secret_properties_from_bundle(
+            bundle,
+            secret_name,
+            include_value,
+            version,
+        ))
     }
 
     async fn list_secrets(
@@ -938,99 +886,33 @@ impl SecretOperations for AzureSecretOperations {
         // The Azure Key Vault SDK crate list shape omits the tag details this CLI
         // displays, so use REST directly
         let list_url = self.key_vault_api_url(&vault_name, &["secrets"])?;
+        let items: Vec<SecretBundle> = self.rest.list(list_url, "Failed to list secrets").await?;
 
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-
-        // Bounded concurrency for the per-secret detail fetch below. Azure Key
-        // Vault's list response does NOT include tags, so the original_name /
-        // groups / folder / note (all stored in tags) require a per-secret GET.
-        // We collect the lightweight per-item fields during pagination, then
-        // fetch the tag-bearing details concurrently instead of serially — the
-        // old code awaited get_secret once per secret in sequence (N+1 latency).
-        const LIST_DETAIL_CONCURRENCY: usize = 10;
-
+        // The per-secret detail fetch below (original_name / groups / folder /
+        // note / content type) runs after pagination with at most
+        // `detail_concurrency` requests in flight, instead of one GET per
+        // secret in sequence (N+1 latency).
         // (name, enabled, updated_on) gathered cheaply from the list response.
-        let mut pending: Vec<(String, bool, String)> = Vec::new();
-        let mut next_url: Option<String> = Some(list_url);
-        let mut page_count: usize = 0;
-
-        while let Some(current_url) = next_url.take() {
-            page_count += 1;
-            if page_count > crate::utils::MAX_PAGES {
-                return Err(CrosstacheError::azure_api(format!(
-                    "Pagination exceeded maximum of {} pages",
-                    crate::utils::MAX_PAGES
-                )));
-            }
-
-            let response = client
-                .get(&current_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| classify_network_error(&e, &current_url))?;
-
-            if !response.status().is_success() {
-                let status = response.status();
-                let error_text = read_error_body(response).await;
-                return Err(CrosstacheError::azure_api(format!(
-                    "Failed to list secrets: HTTP {status} - {error_text}"
-                )));
-            }
-
-            let json: serde_json::Value =
-                read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-
-            if let Some(values) = json.get("value").and_then(|v| v.as_array()) {
-                for secret_value in values {
-                    if let Some(id) = secret_value.get("id").and_then(|v| v.as_str()) {
-                        let name = id.rsplit('/').next().unwrap_or(id).to_string();
-
-                        let attributes = secret_value
-                            .get("attributes")
-                            .unwrap_or(&serde_json::Value::Null);
-                        let enabled = attributes
-                            .get("enabled")
-                            .and_then(|v| v.as_bool())
-                            .unwrap_or(true);
-                        let updated = attributes
-                            .get("updated")
-                            .and_then(|v| v.as_i64())
-                            .map(|ts| {
-                                chrono::DateTime::from_timestamp(ts, 0)
-                                    .map(|dt| dt.to_string())
-                                    .unwrap_or_else(|| "Unknown".to_string())
-                            })
-                            .unwrap_or_else(|| "Unknown".to_string());
-
-                        // Defer the tag-bearing get_secret to a bounded-concurrency
-                        // pass after pagination, instead of awaiting it serially here.
-                        pending.push((name, enabled, updated));
-                    }
-                }
-            }
-
-            // Follow pagination nextLink if present
-            next_url = json
-                .get("nextLink")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-        }
+        let pending: Vec<(String, bool, String)> = items
+            .iter()
+            .filter(|item| item.id.is_some())
+            .map(|item| {
+                let updated = item
+                    .attributes
+                    .updated
+                    .map(|ts| {
+                        chrono::DateTime::from_timestamp(ts, 0)
+                            .map(|dt| dt.to_string())
+                            .unwrap_or_else(|| "Unknown".to_string())
+                    })
+                    .unwrap_or_else(|| "Unknown".to_string());
+                (
+                    item.name_and_version().0.to_string(),
+                    item.attributes.enabled.unwrap_or(true),
+                    updated,
+                )
+            })
+            .collect();
 
         // Fetch per-secret details (tags → original_name/groups/folder/note)
         // concurrently with a bounded number of in-flight requests, preserving
@@ -1075,14 +957,14 @@ impl SecretOperations for AzureSecretOperations {
                                 updated_on: updated,
                                 enabled,
                                 expires_on: None,
-                                content_type: "text/plain".to_string(),
+                                content_type: String::new(),
                                 tags: HashMap::new(),
                             }
                         }
                     }
                 }
             })
-            .buffer_unordered(LIST_DETAIL_CONCURRENCY)
+            .buffer_unordered(self.detail_concurrency)
             .collect()
             .await;
         // buffer_unordered yields completion-order; restore a stable name order
@@ -1115,14 +997,39 @@ impl SecretOperations for AzureSecretOperations {
         Ok(result)
     }
 
+    async fn count_secrets(&self, vault_name: &str) -> Result<usize> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        // The list response alone is enough to count; skipping the per-secret
+        // tag fetch that list_secrets needs keeps this at one request per page.
+        let mut list_url = url::Url::parse(&self.key_vault_api_url(&vault_name, &["secrets"])?)
+            .map_err(|e| CrosstacheError::invalid_url(format!("Invalid Key Vault URL: {e}")))?;
+        list_url
+            .query_pairs_mut()
+            .append_pair("maxresults", &SECRET_COUNT_PAGE_SIZE.to_string());
+
+        let mut count = 0usize;
+        self.rest
+            .for_each_page(
+                list_url.to_string(),
+                "Failed to count secrets",
+                |page: Page<SecretBundle>| count += count_secret_list_page(&page),
+            )
+            .await?;
+        Ok(count)
+    }
+
     async fn delete_secret(&self, vault_name: &str, secret_name: &str) -> Result<()> {
         let client = self.create_secret_client(vault_name).await?;
         let sanitized_name = sanitize_secret_name(secret_name)?;
 
         // Delete the secret from Azure Key Vault (soft delete)
-        client.delete(&sanitized_name).await.map_err(|e| {
-            CrosstacheError::azure_api(format!("Failed to delete secret '{secret_name}': {e}"))
-        })?;
+        client
+            .delete(&sanitized_name)
+            .context(crate::utils::correlation::sdk_context())
+            .await
+            .map_err(|e| {
+                CrosstacheError::azure_api(format!("Failed to delete secret '{secret_name}': {e}"))
+            })?;
 
         Ok(())
     }
@@ -1133,9 +1040,10 @@ impl SecretOperations for AzureSecretOperations {
         _secret_name: &str,
         request: &SecretRequest,
     ) -> Result<SecretProperties> {
-        // For update operations, Azure Key Vault doesn't have a separate update operation
-        // Setting a secret with the same name creates a new version
-        // We'll use the same implementation as set_secret
+        // A value write always creates a new version, so this is exactly
+        // `set_secret` — including building the result from the PUT response
+        // rather than a read-back. Attribute-only changes belong in
+        // `update_secret_attributes`.
         self.set_secret(vault_name, request).await
     }
 
@@ -1148,85 +1056,40 @@ impl SecretOperations for AzureSecretOperations {
         let vault_name = self.validated_vault_name(vault_name)?;
         let sanitized_name = sanitize_secret_name(secret_name)?;
 
-        // `PATCH {vault}/secrets/{name}?api-version=7.4` (UpdateSecret):
-        // updates attributes/tags of the latest version without reading or
-        // writing the value, so it works on disabled secrets and never
-        // creates a new version. Omitted body fields are left unchanged.
+        // `PATCH {vault}/secrets/{name}` (UpdateSecret): updates
+        // attributes/tags of the latest version without reading or writing
+        // the value, so it works on disabled secrets and never creates a new
+        // version. Omitted body fields are left unchanged.
         let secret_url = self.key_vault_api_url(&vault_name, &["secrets", &sanitized_name])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Build the request body from only the fields being changed.
-        let mut body = serde_json::json!({});
-        let mut attributes = serde_json::json!({});
-        if let Some(enabled) = update.enabled {
-            attributes["enabled"] = serde_json::json!(enabled);
-        }
-        if let Some(expires_on) = update.expires_on {
-            attributes["exp"] = serde_json::json!(expires_on.timestamp());
-        }
-        if let Some(not_before) = update.not_before {
-            attributes["nbf"] = serde_json::json!(not_before.timestamp());
-        }
-        if attributes.as_object().is_some_and(|obj| !obj.is_empty()) {
-            body["attributes"] = attributes;
-        }
-        if let Some(ref content_type) = update.content_type {
-            body["contentType"] = serde_json::json!(content_type);
-        }
-        if let Some(ref tags) = update.tags {
-            if let Some(folder) = tags.get("folder") {
-                validate_folder_path(folder)?;
-            }
-            body["tags"] = serde_json::json!(tags);
-        }
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-        headers.insert(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-
-        // Make the REST API call
-        let response = client
-            .patch(&secret_url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &secret_url))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            if status == 404 {
-                return Err(CrosstacheError::SecretNotFound {
-                    name: secret_name.to_string(),
-                    suggestion: None,
-                });
-            }
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to update secret attributes: HTTP {status} - {error_text}"
-            )));
+        if let Some(folder) = update.tags.as_ref().and_then(|tags| tags.get("folder")) {
+            validate_folder_path(folder)?;
         }
+        let body = SecretUpdateParameters {
+            content_type: update.content_type.as_deref(),
+            attributes: request_attributes(update.enabled, update.expires_on, update.not_before),
+            tags: update.tags.as_ref(),
+        };
 
         // The PATCH response is a secret bundle without the value.
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-        parse_secret_properties_bundle(&json, &sanitized_name, false, "")
+        let bundle: SecretBundle = self
+            .rest
+            .call(
+                reqwest::Method::PATCH,
+                &secret_url,
+                Some(&body),
+                "Failed to update secret attributes",
+            )
+            .await?
+            .ok_or_else(|| CrosstacheError::SecretNotFound {
+                name: secret_name.to_string(),
+                suggestion: None,
+            })?;
+        Ok(secret_properties_from_bundle(
+            bundle,
+            &sanitized_name,
+            false,
+            "",
+        ))
     }
 
     async fn restore_secret(
@@ -1234,64 +1097,37 @@ impl SecretOperations for AzureSecretOperations {
         vault_name: &str,
         secret_name: &str,
     ) -> Result<SecretProperties> {
+        self.rest.api().require(Capability::DeletedSecrets)?;
         let vault_name = self.validated_vault_name(vault_name)?;
         let sanitized_name = sanitize_secret_name(secret_name)?;
 
         // Use REST API to restore a deleted secret
         let restore_url =
             self.key_vault_api_url(&vault_name, &["deletedsecrets", &sanitized_name, "recover"])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-        headers.insert(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-
-        // Make the REST API call to restore the secret
-        let response = client
-            .post(&restore_url)
-            .headers(headers)
-            .json(&serde_json::json!({})) // Empty JSON body to satisfy Content-Length requirement
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &restore_url))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            if status == 404 {
-                return Err(CrosstacheError::azure_api(format!(
+        let bundle: SecretBundle = self
+            .rest
+            .post(
+                &restore_url,
+                &serde_json::json!({}),
+                "Failed to restore secret",
+            )
+            .await?
+            .ok_or_else(|| {
+                CrosstacheError::azure_api(format!(
                     "Deleted secret '{secret_name}' not found or cannot be restored"
-                )));
-            }
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to restore secret: HTTP {status} - {error_text}"
-            )));
-        }
-
-        // Parse the response to get the restored secret properties
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
+                ))
+            })?;
 
-        parse_secret_properties_bundle(&json, &sanitized_name, false, "")
+        Ok(secret_properties_from_bundle(
+            bundle,
+            &sanitized_name,
+            false,
+            "",
+        ))
     }
 
     async fn purge_secret(&self, vault_name: &str, secret_name: &str) -> Result<()> {
+        self.rest.api().require(Capability::DeletedSecrets)?;
         let vault_name = self.validated_vault_name(vault_name)?;
         let sanitized_name = sanitize_secret_name(secret_name)?;
 
@@ -1301,42 +1137,13 @@ impl SecretOperations for AzureSecretOperations {
         // ("Method DELETE does not allow operation 'purge'").
         let purge_url =
             self.key_vault_api_url(&vault_name, &["deletedsecrets", &sanitized_name])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-
-        // Make the REST API call to permanently purge the secret
-        let response = client
-            .delete(&purge_url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &purge_url))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            if status == 404 {
-                return Err(CrosstacheError::azure_api(format!(
-                    "Deleted secret '{secret_name}' not found or cannot be purged"
-                )));
-            }
-            let error_text = read_error_body(response).await;
+        if !self
+            .rest
+            .delete(&purge_url, "Failed to purge secret")
+            .await?
+        {
             return Err(CrosstacheError::azure_api(format!(
-                "Failed to purge secret: HTTP {status} - {error_text}"
+                "Deleted secret '{secret_name}' not found or cannot be purged"
             )));
         }
 
@@ -1344,73 +1151,17 @@ impl SecretOperations for AzureSecretOperations {
     }
 
     async fn list_deleted_secrets(&self, vault_name: &str) -> Result<Vec<DeletedSecretSummary>> {
+        self.rest.api().require(Capability::DeletedSecrets)?;
         let vault_name = self.validated_vault_name(vault_name)?;
 
         // Use REST API to list soft-deleted secrets
         let list_url = self.key_vault_api_url(&vault_name, &["deletedsecrets"])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
+        let items: Vec<DeletedSecretBundle> = self
+            .rest
+            .list(list_url, "Failed to list deleted secrets")
             .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-
-        let mut summaries = Vec::new();
-        let mut next_url: Option<String> = Some(list_url);
-        let mut page_count: usize = 0;
-
-        while let Some(current_url) = next_url.take() {
-            page_count += 1;
-            if page_count > crate::utils::MAX_PAGES {
-                return Err(CrosstacheError::azure_api(format!(
-                    "Pagination exceeded maximum of {} pages",
-                    crate::utils::MAX_PAGES
-                )));
-            }
-
-            let response = client
-                .get(&current_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| classify_network_error(&e, &current_url))?;
-
-            if !response.status().is_success() {
-                let status = response.status();
-                let error_text = read_error_body(response).await;
-                return Err(CrosstacheError::azure_api(format!(
-                    "Failed to list deleted secrets: HTTP {status} - {error_text}"
-                )));
-            }
-
-            let json: serde_json::Value =
-                read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-
-            if let Some(values) = json.get("value").and_then(|v| v.as_array()) {
-                for item in values {
-                    if let Some(summary) = parse_deleted_secret_summary(item) {
-                        summaries.push(summary);
-                    }
-                }
-            }
-
-            // Follow pagination nextLink if present
-            next_url = json
-                .get("nextLink")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-        }
+        let mut summaries: Vec<DeletedSecretSummary> =
+            items.iter().filter_map(deleted_secret_summary).collect();
 
         // Sort by name for consistent output
         summaries.sort_by(|a, b| a.original_name.cmp(&b.original_name));
@@ -1418,6 +1169,21 @@ impl SecretOperations for AzureSecretOperations {
         Ok(summaries)
     }
 
+    async fn get_deleted_secret(
+        &self,
+        vault_name: &str,
+        secret_name: &str,
+    ) -> Result<Option<DeletedSecretSummary>> {
+        self.rest.api().require(Capability::DeletedSecrets)?;
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let sanitized_name = sanitize_secret_name(secret_name)?;
+        let url = self.key_vault_api_url(&vault_name, &["deletedsecrets", &sanitized_name])?;
+
+        let item: Option<DeletedSecretBundle> =
+            self.rest.get(&url, "Failed to get deleted secret").await?;
+        Ok(item.as_ref().and_then(deleted_secret_summary))
+    }
+
     async fn secret_exists(&self, vault_name: &str, secret_name: &str) -> Result<bool> {
         match self.get_secret(vault_name, secret_name, false).await {
             Ok(_) => Ok(true),
@@ -1435,122 +1201,21 @@ impl SecretOperations for AzureSecretOperations {
         let sanitized_name = sanitize_secret_name(secret_name)?;
         let versions_url =
             self.key_vault_api_url(&vault_name, &["secrets", &sanitized_name, "versions"])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
+        let items: Vec<SecretBundle> = self
+            .rest
+            .list(versions_url, "Failed to list secret versions")
             .await?;
 
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let http_client = create_http_client(&network_config)?;
-
-        // Make the REST API call to list versions
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-
-        let mut versions = Vec::new();
-        let mut next_url: Option<String> = Some(versions_url);
-        let mut page_count: usize = 0;
-
-        while let Some(current_url) = next_url.take() {
-            page_count += 1;
-            if page_count > crate::utils::MAX_PAGES {
-                return Err(CrosstacheError::azure_api(format!(
-                    "Pagination exceeded maximum of {} pages",
-                    crate::utils::MAX_PAGES
-                )));
-            }
-
-            let response = http_client
-                .get(&current_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| classify_network_error(&e, &current_url))?;
-
-            if !response.status().is_success() {
-                let status = response.status();
-                let error_text = read_error_body(response).await;
-                return Err(CrosstacheError::azure_api(format!(
-                    "Failed to list secret versions: HTTP {} - {}",
-                    status, error_text
-                )));
-            }
-
-            let json: serde_json::Value =
-                read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-
-            if let Some(value_array) = json["value"].as_array() {
-                for version_json in value_array {
-                    let attributes = version_json
-                        .get("attributes")
-                        .unwrap_or(&serde_json::Value::Null);
-
-                    let version = version_json["id"]
-                        .as_str()
-                        .and_then(|id| id.split('/').next_back())
-                        .unwrap_or("unknown")
-                        .to_string();
-
-                    let enabled = attributes
-                        .get("enabled")
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(true);
-                    let created_timestamp = attributes
-                        .get("created")
-                        .and_then(|v| v.as_i64())
-                        .unwrap_or(0);
-                    let updated_timestamp = attributes
-                        .get("updated")
-                        .and_then(|v| v.as_i64())
-                        .unwrap_or(0);
-
-                    let created_on = DateTime::from_timestamp(created_timestamp, 0)
-                        .unwrap_or_else(Utc::now)
-                        .format("%Y-%m-%d %H:%M:%S UTC")
-                        .to_string();
-
-                    let updated_on = DateTime::from_timestamp(updated_timestamp, 0)
-                        .unwrap_or_else(Utc::now)
-                        .format("%Y-%m-%d %H:%M:%S UTC")
-                        .to_string();
-
-                    let recovery_level = attributes
-                        .get("recoveryLevel")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string());
-
-                    versions.push(SecretProperties {
-                        name: secret_name.to_string(),
-                        original_name: secret_name.to_string(),
-                        value: None,
-                        version,
-                        version_number: None,
-                        created_on,
-                        updated_on,
-                        enabled,
-                        expires_on: None,
-                        not_before: None,
-                        tags: HashMap::new(),
-                        content_type: String::new(),
-                        created_timestamp,
-                        recovery_level,
-                    });
-                }
-            }
-
-            next_url = json
-                .get("nextLink")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-        }
+        let mut versions: Vec<SecretProperties> = items
+            .into_iter()
+            .map(|item| {
+                let mut version =
+                    secret_properties_from_bundle(item, &sanitized_name, false, "unknown");
+                version.name = secret_name.to_string();
+                version.original_name = secret_name.to_string();
+                version
+            })
+            .collect();
 
         // Sort ascending by raw Unix timestamp to assign sequential version numbers (oldest = v1)
         versions.sort_by_key(|v| v.created_timestamp);
@@ -1582,7 +1247,8 @@ impl SecretOperations for AzureSecretOperations {
         let request = SecretRequest {
             name: secret_name.to_string(),
             value,
-            content_type: Some(old_version.content_type.clone()),
+            content_type: (!old_version.content_type.is_empty())
+                .then(|| old_version.content_type.clone()),
             enabled: Some(old_version.enabled),
             expires_on: old_version.expires_on,
             not_before: old_version.not_before,
@@ -1596,61 +1262,26 @@ impl SecretOperations for AzureSecretOperations {
     }
 
     async fn backup_secret(&self, vault_name: &str, secret_name: &str) -> Result<Vec<u8>> {
+        self.rest.api().require(Capability::Backup)?;
         let vault_name = self.validated_vault_name(vault_name)?;
         let sanitized_name = sanitize_secret_name(secret_name)?;
 
         // Use REST API to download a protected backup of the secret
         let backup_url =
             self.key_vault_api_url(&vault_name, &["secrets", &sanitized_name, "backup"])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-        headers.insert(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-
-        // Make the REST API call to back up the secret
-        let response = client
-            .post(&backup_url)
-            .headers(headers)
-            .json(&serde_json::json!({})) // Empty JSON body to satisfy Content-Length requirement
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &backup_url))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            if status == 404 {
-                return Err(CrosstacheError::SecretNotFound {
-                    name: secret_name.to_string(),
-                    suggestion: None,
-                });
-            }
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to backup secret: HTTP {status} - {error_text}"
-            )));
-        }
-
-        // Parse the response and decode the base64url backup blob
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-        decode_backup_value!(&json)
+        let blob: BackupBlob = self
+            .rest
+            .post(
+                &backup_url,
+                &serde_json::json!({}),
+                "Failed to backup secret",
+            )
+            .await?
+            .ok_or_else(|| CrosstacheError::SecretNotFound {
+                name: secret_name.to_string(),
+                suggestion: None,
+            })?;
+        decode_backup_value!(&blob)
     }
 
     async fn restore_secret_from_backup(
@@ -1658,54 +1289,24 @@ impl SecretOperations for AzureSecretOperations {
         vault_name: &str,
         backup_data: &[u8],
     ) -> Result<SecretProperties> {
+        self.rest.api().require(Capability::Backup)?;
         let vault_name = self.validated_vault_name(vault_name)?;
 
         // Use REST API to restore a secret from a protected backup blob
         let restore_url = self.key_vault_api_url(&vault_name, &["secrets", "restore"])?;
-
-        // Get an access token for Key Vault
-        let token = self
-            .auth_provider
-            .get_token(&["https://vault.azure.net/.default"])
-            .await?;
-
-        // Create HTTP client with proper timeout configuration
-        let network_config = NetworkConfig::default();
-        let client = create_http_client(&network_config)?;
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {}", token.token.secret())
-                .parse()
-                .map_err(|e| CrosstacheError::azure_api(format!("Invalid token format: {e}")))?,
-        );
-        headers.insert(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-
-        // Make the REST API call to restore the secret from the backup.
         let restore_body = build_restore_request_body!(backup_data);
-        let response = client
-            .post(&restore_url)
-            .headers(headers)
-            .json(&restore_body)
-            .send()
-            .await
-            .map_err(|e| classify_network_error(&e, &restore_url))?;
-
+        let response = self
+            .rest
+            .send(reqwest::Method::POST, &restore_url, Some(&restore_body))
+            .await?;
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = read_error_body(response).await;
-            return Err(CrosstacheError::azure_api(format!(
-                "Failed to restore secret from backup: HTTP {status} - {error_text}"
-            )));
+            return Err(
+                classify_http_response(response, "Failed to restore secret from backup").await,
+            );
         }
 
         // Parse the response to get the restored secret properties
-        let json: serde_json::Value =
-            read_json_body(response, crate::utils::MAX_RESPONSE_BYTES).await?;
-        parse_restored_secret_properties!(&json)
+        parse_restored_secret_properties!(read_json(response).await?)
     }
 }
 
@@ -1713,6 +1314,14 @@ impl SecretOperations for AzureSecretOperations {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_groups_tag_is_exact_comma_separated_match() {
+        assert!(groups_tag_contains(Some("prod, infra"), "prod"));
+        assert!(groups_tag_contains(Some("prod, infra"), "infra"));
+        assert!(!groups_tag_contains(Some("production"), "prod"));
+        assert!(!groups_tag_contains(None, "prod"));
+    }
+
     #[test]
     fn test_field_update_from_flags() {
         assert_eq!(
@@ -1740,6 +1349,14 @@ mod tests {
         assert_eq!(FieldUpdate::Clear.apply(Some(1)), None);
         assert_eq!(FieldUpdate::<i32>::Clear.apply(None), None);
     }
+    fn bundle(json: serde_json::Value) -> SecretBundle {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn deleted(json: serde_json::Value) -> DeletedSecretBundle {
+        serde_json::from_value(json).unwrap()
+    }
+
     fn test_ops() -> AzureSecretOperations {
         AzureSecretOperations::new(Arc::new(
             crate::auth::provider::DefaultAzureCredentialProvider::new().unwrap(),
@@ -1831,17 +1448,20 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_deleted_secret_summary_full() {
+    fn test_deleted_secret_summary_full() {
         let item = serde_json::json!({
             "id": "https://myvault.vault.azure.net/deletedsecrets/my-secret",
             "deletedDate": 1_700_000_100,
             "scheduledPurgeDate": 1_707_776_100,
+            "attributes": {
+                "recoveryLevel": "Recoverable+Purgeable"
+            },
             "tags": {
                 "original_name": "My Secret"
             }
         });
 
-        let summary = parse_deleted_secret_summary(&item).unwrap();
+        let summary = deleted_secret_summary(&deleted(item)).unwrap();
         assert_eq!(summary.name, "my-secret");
         assert_eq!(summary.original_name, "My Secret");
         assert_eq!(
@@ -1860,38 +1480,89 @@ mod tests {
                     .to_string()
             )
         );
+        assert_eq!(
+            summary.recovery_level.as_deref(),
+            Some("Recoverable+Purgeable")
+        );
+        assert!(summary.is_recoverable());
+        assert!(summary.is_purgeable());
+    }
+
+    #[test]
+    fn test_deleted_secret_purgeable_follows_recovery_level() {
+        let mut deleted = DeletedSecretSummary {
+            name: "db".into(),
+            original_name: "db".into(),
+            deleted_on: None,
+            scheduled_purge_on: None,
+            recovery_level: Some("Recoverable".into()),
+            tags: HashMap::new(),
+        };
+        assert!(!deleted.is_purgeable());
+        deleted.recovery_level = Some("CustomizedRecoverable+ProtectedSubscription".into());
+        assert!(!deleted.is_purgeable());
+        deleted.recovery_level = Some("CustomizedRecoverable+Purgeable".into());
+        assert!(deleted.is_purgeable());
+        deleted.recovery_level = None;
+        assert!(deleted.is_purgeable());
+    }
+
+    #[test]
+    fn test_deleted_name_conflict_message_names_purge_date() {
+        let deleted = DeletedSecretSummary {
+            name: "db".into(),
+            original_name: "db".into(),
+            deleted_on: None,
+            scheduled_purge_on: Some("2024-09-01 12:00:00 UTC".into()),
+            recovery_level: Some("Recoverable+Purgeable".into()),
+            tags: HashMap::new(),
+        };
+        let message = deleted_name_conflict_message("db", Some(&deleted));
+        assert!(
+            message.contains("cannot be reused until 2024-09-01."),
+            "{message}"
+        );
+        assert!(message.contains("recovery level: Recoverable+Purgeable"));
+        assert!(message.contains("xv restore db") && message.contains("xv purge db"));
+
+        let message = deleted_name_conflict_message("db", None);
+        assert!(message.contains("cannot be reused."), "{message}");
     }
 
     #[test]
-    fn test_parse_deleted_secret_summary_minimal() {
+    fn test_deleted_secret_summary_minimal() {
         let item = serde_json::json!({
             "id": "https://myvault.vault.azure.net/deletedsecrets/bare-secret"
         });
 
-        let summary = parse_deleted_secret_summary(&item).unwrap();
+        let summary = deleted_secret_summary(&deleted(item)).unwrap();
         assert_eq!(summary.name, "bare-secret");
         assert_eq!(summary.original_name, "bare-secret");
         assert_eq!(summary.deleted_on, None);
         assert_eq!(summary.scheduled_purge_on, None);
+        assert_eq!(summary.recovery_level, None);
+        assert!(summary.is_recoverable());
     }
 
     #[test]
-    fn test_parse_deleted_secret_summary_legacy_name_tag_and_empty_groups() {
+    fn test_deleted_secret_summary_legacy_name_tag_and_empty_groups() {
         let item = serde_json::json!({
             "id": "https://myvault.vault.azure.net/deletedsecrets/legacy",
             "tags": { "name": "Legacy Name", "groups": "   " }
         });
 
-        let summary = parse_deleted_secret_summary(&item).unwrap();
+        let summary = deleted_secret_summary(&deleted(item)).unwrap();
         assert_eq!(summary.name, "legacy");
         assert_eq!(summary.original_name, "Legacy Name");
     }
 
     #[test]
-    fn test_parse_deleted_secret_summary_missing_id() {
-        assert!(parse_deleted_secret_summary(&serde_json::json!({})).is_none());
-        assert!(parse_deleted_secret_summary(&serde_json::json!({ "id": 42 })).is_none());
-        assert!(parse_deleted_secret_summary(&serde_json::json!({ "id": "" })).is_none());
+    fn test_deleted_secret_summary_missing_id() {
+        assert!(deleted_secret_summary(&deleted(serde_json::json!({}))).is_none());
+        assert!(deleted_secret_summary(&deleted(serde_json::json!({ "id": "" }))).is_none());
+        assert!(
+            serde_json::from_value::<DeletedSecretBundle>(serde_json::json!({ "id": 42 })).is_err()
+        );
     }
 
     #[test]
@@ -1899,21 +1570,27 @@ mod tests {
         let original: Vec<u8> = (0u8..=255).collect();
         let body = build_restore_request_body!(&original);
         assert_eq!(decode_backup_value!(&body).unwrap(), original);
-        let encoded = body.get("value").unwrap().as_str().unwrap();
+        let encoded = body.value.as_deref().unwrap();
         assert!(!encoded.contains('+') && !encoded.contains('/') && !encoded.contains('='));
 
-        let unpadded = serde_json::json!({ "value": "AAECAw" });
-        let padded = serde_json::json!({ "value": "AAECAw==" });
+        let blob = |value: &str| BackupBlob {
+            value: Some(value.to_string()),
+        };
+        let unpadded = blob("AAECAw");
+        let padded = blob("AAECAw==");
         assert_eq!(decode_backup_value!(&unpadded).unwrap(), vec![0, 1, 2, 3]);
         assert_eq!(decode_backup_value!(&padded).unwrap(), vec![0, 1, 2, 3]);
     }
 
     #[test]
     fn test_backup_value_codec_rejects_missing_and_invalid_payloads() {
-        let missing = decode_backup_value!(&serde_json::json!({})).unwrap_err();
+        let missing = decode_backup_value!(&BackupBlob::default()).unwrap_err();
         assert!(missing.to_string().contains("missing 'value'"), "{missing}");
 
-        let invalid = decode_backup_value!(&serde_json::json!({ "value": "!!!" })).unwrap_err();
+        let invalid = decode_backup_value!(&BackupBlob {
+            value: Some("!!!".to_string())
+        })
+        .unwrap_err();
         assert!(
             invalid.to_string().contains("Failed to decode"),
             "{invalid}"
@@ -1922,14 +1599,15 @@ mod tests {
 
     #[test]
     fn test_restored_secret_parser_rejects_malformed_ids() {
-        let missing = parse_restored_secret_properties!(&serde_json::json!({})).unwrap_err();
+        let missing = parse_restored_secret_properties!(SecretBundle::default()).unwrap_err();
         assert!(
             missing.to_string().contains("missing secret 'id'"),
             "{missing}"
         );
 
         let malformed =
-            parse_restored_secret_properties!(&serde_json::json!({ "id": "abc" })).unwrap_err();
+            parse_restored_secret_properties!(bundle(serde_json::json!({ "id": "abc" })))
+                .unwrap_err();
         assert!(
             malformed.to_string().contains("unexpected secret id"),
             "{malformed}"
@@ -1937,7 +1615,7 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_secret_properties_bundle_full_without_value() {
+    fn test_secret_properties_from_bundle_full_without_value() {
         let json = serde_json::json!({
             "id": "https://myvault.vault.azure.net/secrets/my-secret/abc123def456",
             "contentType": "application/json",
@@ -1955,10 +1633,14 @@ mod tests {
             }
         });
 
-        let props = parse_secret_properties_bundle(&json, "", false, "").unwrap();
+        let props = secret_properties_from_bundle(bundle(json), "", false, "");
         assert_eq!(props.name, "my-secret");
         assert_eq!(props.original_name, "My Secret");
         assert_eq!(props.version, "abc123def456");
+        assert_eq!(
+            props.id.as_deref(),
+            Some("https://myvault.vault.azure.net/secrets/my-secret/abc123def456")
+        );
         assert!(props.value.is_none());
         assert!(props.enabled);
         assert_eq!(props.created_timestamp, 1_700_000_000);
@@ -1976,12 +1658,12 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_secret_properties_bundle_minimal_without_value() {
+    fn test_secret_properties_from_bundle_minimal_without_value() {
         let json = serde_json::json!({
             "id": "https://myvault.vault.azure.net/secrets/plain/v1"
         });
 
-        let props = parse_secret_properties_bundle(&json, "", false, "").unwrap();
+        let props = secret_properties_from_bundle(bundle(json), "", false, "");
         assert_eq!(props.name, "plain");
         assert_eq!(props.original_name, "plain");
         assert_eq!(props.version, "v1");
@@ -1990,6 +1672,52 @@ mod tests {
         assert_eq!(props.updated_on, "Unknown");
         assert_eq!(props.expires_on, None);
         assert_eq!(props.not_before, None);
-        assert_eq!(props.content_type, "text/plain");
+        assert_eq!(props.content_type, "");
+    }
+
+    #[test]
+    fn test_confirm_written_version_accepts_versioned_id() {
+        let json = serde_json::json!({
+            "id": "https://myvault.vault.azure.net/secrets/My-Secret/0123abcd"
+        });
+        assert!(confirm_written_version(&bundle(json), "my-secret").is_ok());
+    }
+
+    #[test]
+    fn test_confirm_written_version_rejects_unversioned_or_foreign_ids() {
+        for json in [
+            serde_json::json!({}),
+            serde_json::json!({ "id": "https://myvault.vault.azure.net/secrets/my-secret/" }),
+            serde_json::json!({ "id": "https://myvault.vault.azure.net/secrets/other/0123abcd" }),
+        ] {
+            let err = confirm_written_version(&bundle(json), "my-secret").unwrap_err();
+            assert!(
+                err.to_string().contains("did not confirm the new version"),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_secret_list_page_counts_items_and_follows_next_link() {
+        let mut page: Page<SecretBundle> = serde_json::from_value(serde_json::json!({
+            "value": [
+                { "id": "https://myvault.vault.azure.net/secrets/a" },
+                { "id": "https://myvault.vault.azure.net/secrets/b" },
+                { "attributes": {} }
+            ],
+            "nextLink": "https://myvault.vault.azure.net/secrets?$skiptoken=x"
+        }))
+        .unwrap();
+        assert_eq!(count_secret_list_page(&page), 2);
+        assert_eq!(
+            page.take_next_link(),
+            Some("https://myvault.vault.azure.net/secrets?$skiptoken=x".to_string())
+        );
+
+        let mut last: Page<SecretBundle> =
+            serde_json::from_value(serde_json::json!({ "value": [], "nextLink": null })).unwrap();
+        assert_eq!(count_secret_list_page(&last), 0);
+        assert_eq!(last.take_next_link(), None);
     }
 }
@@ -4,6 +4,12 @@
 //! including name sanitization, group management, and advanced secret operations.
 
 pub mod attachments;
+pub mod bundle;
+pub mod diff;
+pub mod expiry;
+pub mod generate;
 pub mod manager;
 pub mod models;
 pub mod name_manager;
+pub mod rotate;
+pub mod scaffold;
@@ -59,6 +59,26 @@ pub struct SecretInfo {
 
     /// Number of versions (if available)
     pub version_count: Option<usize>,
+
+    /// Value size in bytes (when the value was fetched)
+    #[serde(default)]
+    pub value_size: Option<usize>,
+
+    /// Backend's maximum value size in bytes (if it enforces one)
+    #[serde(default)]
+    pub value_size_limit: Option<usize>,
+}
+
+/// Share of a backend's value-size limit at which a secret is flagged as
+/// close to it (`xv info`, `xv ls --size`).
+pub const VALUE_SIZE_WARN_RATIO: f64 = 0.8;
+
+/// Percentage of `limit` a `size`-byte value uses, when that is at or above
+/// [`VALUE_SIZE_WARN_RATIO`]. `None` when comfortably under or unlimited.
+pub fn value_size_pressure(size: usize, limit: Option<usize>) -> Option<u64> {
+    let limit = limit.filter(|l| *l > 0)?;
+    let ratio = size as f64 / limit as f64;
+    (ratio >= VALUE_SIZE_WARN_RATIO).then(|| (ratio * 100.0).round() as u64)
 }
 
 impl SecretInfo {
@@ -112,6 +132,8 @@ impl SecretInfo {
             note: None,
             vault_uri: vault_uri.to_string(),
             version_count: None,
+            value_size: None,
+            value_size_limit: None,
         }
     }
 }
@@ -147,7 +169,17 @@ impl std::fmt::Display for SecretInfo {
         }
 
         if let Some(expires) = self.expires {
-            writeln!(f, "  Expires: {}", expires.format("%Y-%m-%d %H:%M:%S UTC"))?;
+            let (label, urgency) = crate::utils::datetime::describe_expiry(expires, Utc::now());
+            writeln!(
+                f,
+                "  Expires: {} ({})",
+                expires.format("%Y-%m-%d %H:%M:%S UTC"),
+                crate::utils::output::paint_expiry(
+                    &label,
+                    urgency,
+                    crate::utils::output::should_use_rich_stdout()
+                )
+            )?;
         }
 
         if let Some(not_before) = self.not_before {
@@ -162,6 +194,21 @@ impl std::fmt::Display for SecretInfo {
             writeln!(f, "  Content Type: {content_type}")?;
         }
 
+        if let Some(size) = self.value_size {
+            let formatted = crate::utils::format::format_size(size as u64);
+            match (
+                value_size_pressure(size, self.value_size_limit),
+                self.value_size_limit,
+            ) {
+                (Some(pct), Some(limit)) => writeln!(
+                    f,
+                    "  Value Size: {formatted} ({size} bytes, {pct}% of the {} limit)",
+                    crate::utils::format::format_size(limit as u64)
+                )?,
+                _ => writeln!(f, "  Value Size: {formatted} ({size} bytes)")?,
+            }
+        }
+
         if let Some(recovery_level) = &self.recovery_level {
             writeln!(f, "  Recovery Level: {recovery_level}")?;
         }
@@ -387,4 +434,38 @@ mod tests {
             "system tags should not appear in additional: {output}"
         );
     }
+
+    // --- value size ---
+
+    #[test]
+    fn test_value_size_pressure_flags_values_near_the_limit() {
+        assert_eq!(value_size_pressure(1024, Some(25 * 1024)), None);
+        assert_eq!(value_size_pressure(20 * 1024, Some(25 * 1024)), Some(80));
+        assert_eq!(value_size_pressure(25 * 1024, Some(25 * 1024)), Some(100));
+        assert_eq!(value_size_pressure(25 * 1024, None), None);
+    }
+
+    #[test]
+    fn test_display_shows_value_size() {
+        let mut info = SecretInfo::test_minimal("my-secret", "https://myvault.vault.azure.net/");
+        info.value_size = Some(2048);
+        info.value_size_limit = Some(25 * 1024);
+        let output = info.to_string();
+        assert!(
+            output.contains("Value Size: 2.00 KB (2048 bytes)\n"),
+            "output: {output}"
+        );
+    }
+
+    #[test]
+    fn test_display_flags_value_size_near_limit() {
+        let mut info = SecretInfo::test_minimal("my-secret", "https://myvault.vault.azure.net/");
+        info.value_size = Some(24 * 1024);
+        info.value_size_limit = Some(25 * 1024);
+        let output = info.to_string();
+        assert!(
+            output.contains("(24576 bytes, 96% of the 25.00 KB limit)"),
+            "output: {output}"
+        );
+    }
 }
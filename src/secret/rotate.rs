@@ -0,0 +1,323 @@
+//! Secret rotation for `xv rotate`
+//!
+//! A [`Rotator`] provisions the next value of a secret: random characters,
+//! the output of a generator script or shell command, or the response of a
+//! webhook that provisions the credential upstream. The caller writes the
+//! value as a new version and stamps it with [`rotation_tags`], which record
+//! when it was rotated, by what, and which version it replaced, so
+//! `xv expiry-report --max-age` can flag secrets that are overdue.
+
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use zeroize::Zeroizing;
+
+use crate::cli::commands::CharsetType;
+use crate::cli::helpers::{generate_random_value, run_generator_script};
+use crate::error::{CrosstacheError, Result};
+
+/// Tag holding the RFC 3339 time of the last rotation.
+pub const TAG_ROTATED_AT: &str = "rotated_at";
+/// Tag holding the version the last rotation replaced.
+pub const TAG_PREVIOUS: &str = "previous";
+/// Tag holding the [`Rotator::kind`] of the last rotation.
+pub const TAG_ROTATED_BY: &str = "rotated_by";
+
+/// The secret being rotated, as passed to a [`Rotator`].
+#[derive(Debug, Clone, Copy)]
+pub struct RotationContext<'a> {
+    pub vault: &'a str,
+    pub name: &'a str,
+    /// Version being replaced; empty on backends without version ids.
+    pub current_version: &'a str,
+}
+
+/// Provisions the next value of a secret.
+#[async_trait]
+pub trait Rotator: Send + Sync {
+    /// Short name recorded in the `rotated_by` tag, e.g. `generate`.
+    fn kind(&self) -> &'static str;
+
+    /// One line describing where the value comes from.
+    fn describe(&self) -> String;
+
+    /// Produce the new value.
+    async fn provision(&self, ctx: &RotationContext<'_>) -> Result<Zeroizing<String>>;
+}
+
+/// `length` random characters from `charset`, as `xv gen` makes them.
+pub struct GenerateRotator {
+    pub length: usize,
+    pub charset: CharsetType,
+}
+
+#[async_trait]
+impl Rotator for GenerateRotator {
+    fn kind(&self) -> &'static str {
+        "generate"
+    }
+
+    fn describe(&self) -> String {
+        format!("{} random {} characters", self.length, self.charset)
+    }
+
+    async fn provision(&self, _ctx: &RotationContext<'_>) -> Result<Zeroizing<String>> {
+        generate_random_value(self.length, self.charset, None)
+    }
+}
+
+/// The stdout of a generator script, run directly with `XV_SECRET_LENGTH`
+/// set (`--generator`).
+pub struct ScriptRotator {
+    pub path: String,
+    pub length: usize,
+}
+
+#[async_trait]
+impl Rotator for ScriptRotator {
+    fn kind(&self) -> &'static str {
+        "script"
+    }
+
+    fn describe(&self) -> String {
+        format!("generator script {} (length: {})", self.path, self.length)
+    }
+
+    async fn provision(&self, _ctx: &RotationContext<'_>) -> Result<Zeroizing<String>> {
+        run_generator_script(&self.path, self.length).map(Zeroizing::new)
+    }
+}
+
+/// The stdout of a shell command (`--command`). The command sees the secret
+/// in `XV_SECRET_NAME`, `XV_VAULT` and `XV_SECRET_VERSION`.
+pub struct CommandRotator {
+    pub command: String,
+}
+
+#[async_trait]
+impl Rotator for CommandRotator {
+    fn kind(&self) -> &'static str {
+        "command"
+    }
+
+    fn describe(&self) -> String {
+        format!("command `{}`", self.command)
+    }
+
+    async fn provision(&self, ctx: &RotationContext<'_>) -> Result<Zeroizing<String>> {
+        let mut cmd = if cfg!(windows) {
+            let mut cmd = Command::new("cmd");
+            cmd.arg("/C");
+            cmd
+        } else {
+            let mut cmd = Command::new("sh");
+            cmd.arg("-c");
+            cmd
+        };
+        cmd.arg(&self.command)
+            .env("XV_SECRET_NAME", ctx.name)
+            .env("XV_VAULT", ctx.vault)
+            .env("XV_SECRET_VERSION", ctx.current_version)
+            .stdin(Stdio::null());
+        let output = tokio::task::spawn_blocking(move || cmd.output())
+            .await
+            .map_err(|e| CrosstacheError::unknown(format!("Rotation command panicked: {e}")))?
+            .map_err(|e| CrosstacheError::config(format!("Failed to run rotation command: {e}")))?;
+        let stdout = Zeroizing::new(output.stdout);
+        if !output.status.success() {
+            return Err(CrosstacheError::config(format!(
+                "Rotation command failed with exit code {}: {}",
+                output.status.code().unwrap_or(-1),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let value = std::str::from_utf8(&stdout).map_err(|e| {
+            CrosstacheError::config(format!("Rotation command output is not valid UTF-8: {e}"))
+        })?;
+        non_empty(value.trim(), "Rotation command produced empty output")
+    }
+}
+
+/// The value returned by a webhook that provisions the new credential
+/// (`--webhook`). The webhook receives a JSON POST of
+/// `{"vault", "name", "current_version"}` and answers with either
+/// `{"value": "..."}` or the value as a plain-text body.
+pub struct WebhookRotator {
+    pub url: String,
+}
+
+impl WebhookRotator {
+    /// Reject URLs that would send the request, and receive the new value,
+    /// in clear text. Plain `http://` is allowed for loopback only.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let url = url.into();
+        let parsed = url::Url::parse(&url).map_err(|e| {
+            CrosstacheError::invalid_argument(format!("Invalid webhook URL '{url}': {e}"))
+        })?;
+        let loopback = matches!(parsed.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+        match parsed.scheme() {
+            "https" => Ok(Self { url }),
+            "http" if loopback => Ok(Self { url }),
+            _ => Err(CrosstacheError::invalid_argument(format!(
+                "Webhook URL '{url}' must use https:// (plain http:// is only allowed for \
+                 localhost)"
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl Rotator for WebhookRotator {
+    fn kind(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn describe(&self) -> String {
+        format!("webhook {}", self.url)
+    }
+
+    async fn provision(&self, ctx: &RotationContext<'_>) -> Result<Zeroizing<String>> {
+        let client = crate::utils::network::create_http_client(
+            &crate::utils::network::NetworkConfig::default(),
+        )?;
+        let response = client
+            .post(&self.url)
+            .json(&serde_json::json!({
+                "vault": ctx.vault,
+                "name": ctx.name,
+                "current_version": ctx.current_version,
+            }))
+            .send()
+            .await
+            .map_err(|e| crate::utils::network::classify_network_error(&e, &self.url))?;
+        let status = response.status();
+        let body = Zeroizing::new(response.text().await.map_err(|e| {
+            CrosstacheError::network(format!("Failed to read the webhook response: {e}"))
+        })?);
+        if !status.is_success() {
+            return Err(CrosstacheError::config(format!(
+                "Rotation webhook returned {status}"
+            )));
+        }
+        webhook_value(&body)
+    }
+}
+
+/// Pull the value out of a webhook response body.
+fn webhook_value(body: &str) -> Result<Zeroizing<String>> {
+    let trimmed = body.trim();
+    if trimmed.starts_with('{') {
+        let json: serde_json::Value = serde_json::from_str(trimmed).map_err(|e| {
+            CrosstacheError::config(format!("Rotation webhook returned invalid JSON: {e}"))
+        })?;
+        let value = json.get("value").and_then(|v| v.as_str()).ok_or_else(|| {
+            CrosstacheError::config("Rotation webhook JSON has no string \"value\" field")
+        })?;
+        return non_empty(value, "Rotation webhook returned an empty value");
+    }
+    non_empty(trimmed, "Rotation webhook returned an empty body")
+}
+
+fn non_empty(value: &str, message: &str) -> Result<Zeroizing<String>> {
+    if value.is_empty() {
+        return Err(CrosstacheError::config(message));
+    }
+    Ok(Zeroizing::new(value.to_string()))
+}
+
+/// Tags written on the new version: the rotation time, the rotator and the
+/// version it replaced.
+pub fn rotation_tags(
+    rotator: &dyn Rotator,
+    previous_version: &str,
+    now: DateTime<Utc>,
+) -> HashMap<String, String> {
+    let mut tags = HashMap::from([
+        (TAG_ROTATED_AT.to_string(), now.to_rfc3339()),
+        (TAG_ROTATED_BY.to_string(), rotator.kind().to_string()),
+    ]);
+    if !previous_version.is_empty() {
+        tags.insert(TAG_PREVIOUS.to_string(), previous_version.to_string());
+    }
+    tags
+}
+
+/// When the secret carrying `tags` was last rotated by xv.
+pub fn last_rotated(tags: &HashMap<String, String>) -> Option<DateTime<Utc>> {
+    tags.get(TAG_ROTATED_AT)
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+        .map(|at| at.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CTX: RotationContext<'static> = RotationContext {
+        vault: "kv",
+        name: "db-password",
+        current_version: "v1",
+    };
+
+    #[tokio::test]
+    async fn generate_rotator_uses_charset_and_length() {
+        let rotator = GenerateRotator {
+            length: 40,
+            charset: CharsetType::Numeric,
+        };
+        let value = rotator.provision(&CTX).await.unwrap();
+        assert_eq!(value.len(), 40);
+        assert!(value.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn command_rotator_sees_secret_and_returns_trimmed_stdout() {
+        let rotator = CommandRotator {
+            command:
+                "printf '%s:%s:%s\\n' \"$XV_VAULT\" \"$XV_SECRET_NAME\" \"$XV_SECRET_VERSION\""
+                    .to_string(),
+        };
+        let value = rotator.provision(&CTX).await.unwrap();
+        assert_eq!(value.as_str(), "kv:db-password:v1");
+
+        let failing = CommandRotator {
+            command: "echo nope >&2; exit 3".to_string(),
+        };
+        let err = failing.provision(&CTX).await.unwrap_err().to_string();
+        assert!(err.contains("exit code 3") && err.contains("nope"), "{err}");
+    }
+
+    #[test]
+    fn webhook_value_accepts_json_or_plain_text() {
+        assert_eq!(
+            webhook_value("{\"value\": \"s3cret\"}").unwrap().as_str(),
+            "s3cret"
+        );
+        assert_eq!(webhook_value("s3cret\n").unwrap().as_str(), "s3cret");
+        assert!(webhook_value("{\"token\": \"x\"}").is_err());
+        assert!(webhook_value("  ").is_err());
+    }
+
+    #[test]
+    fn webhook_requires_https_outside_loopback() {
+        assert!(WebhookRotator::new("https://rotate.example.com/hook").is_ok());
+        assert!(WebhookRotator::new("http://127.0.0.1:8080/hook").is_ok());
+        assert!(WebhookRotator::new("http://rotate.example.com/hook").is_err());
+    }
+
+    #[test]
+    fn rotation_tags_round_trip_through_last_rotated() {
+        let rotator = CommandRotator {
+            command: "true".to_string(),
+        };
+        let now = Utc::now();
+        let tags = rotation_tags(&rotator, "v1", now);
+        assert_eq!(tags[TAG_PREVIOUS], "v1");
+        assert_eq!(tags[TAG_ROTATED_BY], "command");
+        assert_eq!(last_rotated(&tags).unwrap().timestamp(), now.timestamp());
+        assert!(!rotation_tags(&rotator, "", now).contains_key(TAG_PREVIOUS));
+    }
+}
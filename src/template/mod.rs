@@ -0,0 +1,100 @@
+//! `{{ secret:NAME }}` placeholders, shared by `xv inject` and `xv render`.
+//!
+//! Whitespace inside the braces is allowed. NAME runs to the closing braces
+//! and may not contain whitespace or `}`; it is a secret name, a
+//! `name.field` record field, a `folder/name` path or an `alias:name`
+//! workspace reference, which each command resolves in its own way. Other
+//! text in braces, such as a templating engine's own `{{ .Values.x }}`, is
+//! not a placeholder and passes through untouched.
+
+use regex::Regex;
+use std::sync::LazyLock;
+use zeroize::Zeroizing;
+
+static PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*secret:([^}\s]+)\s*\}\}").unwrap());
+
+/// Every placeholder name in `template`, once each, in order of first use.
+pub fn placeholders(template: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for captures in PLACEHOLDER.captures_iter(template) {
+        let name = &captures[1];
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// A rendered template and the placeholders that had no value.
+pub struct Rendered {
+    pub output: Zeroizing<String>,
+    pub missing: Vec<String>,
+}
+
+/// Substitute each placeholder with `resolve(name)`, in one pass, so a value
+/// is inserted verbatim even when it looks like a placeholder itself. A
+/// placeholder with no value is left as written and listed in
+/// [`Rendered::missing`] so the caller can warn or fail.
+pub fn render<'a>(template: &str, resolve: impl Fn(&str) -> Option<&'a str>) -> Rendered {
+    let mut output = Zeroizing::new(String::with_capacity(template.len()));
+    let mut missing: Vec<String> = Vec::new();
+    let mut last = 0;
+    for captures in PLACEHOLDER.captures_iter(template) {
+        let whole = captures.get(0).expect("group 0 always matches");
+        output.push_str(&template[last..whole.start()]);
+        last = whole.end();
+        let name = &captures[1];
+        match resolve(name) {
+            Some(value) => output.push_str(value),
+            None => {
+                output.push_str(whole.as_str());
+                if !missing.iter().any(|n| n == name) {
+                    missing.push(name.to_string());
+                }
+            }
+        }
+    }
+    output.push_str(&template[last..]);
+    Rendered { output, missing }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn placeholders_are_listed_once_in_order() {
+        let template =
+            "url={{ secret:db-host }}:{{secret:db-port}}\nuser={{ secret:db-host }}\n{{ other }}";
+        assert_eq!(placeholders(template), vec!["db-host", "db-port"]);
+    }
+
+    #[test]
+    fn render_substitutes_and_reports_missing() {
+        let values: HashMap<&str, &str> = [
+            ("db-host", "db.internal"),
+            ("db.port", "$1 {{ secret:db-host }}"),
+        ]
+        .into();
+        let rendered = render(
+            "host={{ secret:db-host }} port={{secret:db.port}} pw={{ secret:db-password }} pw2={{ secret:db-password }}",
+            |name| values.get(name).copied(),
+        );
+        assert_eq!(
+            rendered.output.as_str(),
+            "host=db.internal port=$1 {{ secret:db-host }} pw={{ secret:db-password }} pw2={{ secret:db-password }}"
+        );
+        assert_eq!(rendered.missing, vec!["db-password"]);
+    }
+
+    #[test]
+    fn foreign_braces_pass_through() {
+        let template = "{{ .Values.image }} {{db-host}} {{}} {{ secret: }}";
+        assert!(placeholders(template).is_empty());
+        let rendered = render(template, |_| Some("VALUE"));
+        assert_eq!(rendered.output.as_str(), template);
+        assert!(rendered.missing.is_empty());
+    }
+}
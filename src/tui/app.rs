@@ -258,8 +258,10 @@ impl App {
                 name: e.alias.clone(),
                 location: String::new(),
                 resource_group: String::new(),
+                subscription: None,
                 status: "Attached".to_string(),
                 created_at: String::new(),
+                secret_count: None,
             })
             .collect();
         self.workspace_vault_names = ws
@@ -0,0 +1,166 @@
+//! Local audit logs: append-only JSON lines, one entry per access.
+//!
+//! The break-glass cache (`break-glass/audit.jsonl`), `xv dev-proxy
+//! --audit-log` and the QR/paste transfer log beside the config file all
+//! write [`AuditEntry`] lines through [`append`], so one reader handles
+//! every log. Files are created owner-only on Unix.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+
+/// Log of `xv get --qr` and `xv file paste`, beside `xv.conf`. Kept out of
+/// the cache directory so clearing the cache does not erase it.
+pub const TRANSFER_AUDIT_FILE: &str = "transfer-audit.jsonl";
+
+/// What happened, as recorded in an audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditEvent {
+    /// `xv vault cache warm` sealed a break-glass bundle.
+    Warm,
+    /// `xv get --offline` read a value from a bundle.
+    OfflineRead,
+    /// An offline read was refused (expired or unreadable bundle).
+    Refused,
+    /// `xv vault cache clear` removed a bundle.
+    Clear,
+    /// `xv get --qr` drew a value on the terminal.
+    QrReveal,
+    /// `xv file paste` uploaded the clipboard.
+    ClipboardPaste,
+    /// `xv dev-proxy` served a value.
+    ProxyRead,
+    /// `xv dev-proxy` refused a request.
+    ProxyDenied,
+    /// `xv dev-proxy` could not read from the backend.
+    ProxyError,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub at: DateTime<Utc>,
+    pub event: AuditEvent,
+    pub backend: String,
+    pub vault: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl AuditEntry {
+    pub fn new(event: AuditEvent, backend: &str, vault: &str) -> Self {
+        Self {
+            at: Utc::now(),
+            event,
+            backend: backend.to_string(),
+            vault: vault.to_string(),
+            secret: None,
+            user: std::env::var("USER")
+                .or_else(|_| std::env::var("USERNAME"))
+                .ok(),
+            detail: None,
+        }
+    }
+
+    pub fn secret(mut self, name: &str) -> Self {
+        self.secret = Some(name.to_string());
+        self
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Append `entry` to the log at `path`, creating it (and its directory)
+/// owner-only. Callers treat a failure as fatal for the access being
+/// recorded: an access that cannot be audited does not happen.
+pub fn append(path: &Path, entry: &AuditEntry) -> Result<()> {
+    let cannot_write = |e: &dyn std::fmt::Display| {
+        CrosstacheError::unknown(format!(
+            "Cannot write the audit log {}: {e}",
+            path.display()
+        ))
+    };
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|e| cannot_write(&e))?;
+    }
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    let mut options = std::fs::OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| cannot_write(&e))?;
+    file.write_all(line.as_bytes())
+        .map_err(|e| cannot_write(&e))?;
+    Ok(())
+}
+
+/// Append `entry` to [`TRANSFER_AUDIT_FILE`] beside the config file.
+pub fn append_transfer(entry: &AuditEntry) -> Result<()> {
+    append(
+        &Config::get_config_path()?.with_file_name(TRANSFER_AUDIT_FILE),
+        entry,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_append_as_owner_only_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("audit.jsonl");
+        append(
+            &path,
+            &AuditEntry::new(AuditEvent::ProxyRead, "local", "default").secret("db-url"),
+        )
+        .unwrap();
+        append(
+            &path,
+            &AuditEntry::new(AuditEvent::ProxyDenied, "local", "default").detail("403 denied"),
+        )
+        .unwrap();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let entries: Vec<AuditEntry> = log
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].event, AuditEvent::ProxyRead);
+        assert!(log.contains("\"proxy-denied\""));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn an_unwritable_log_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        // A directory where the file should be.
+        let err = append(
+            dir.path(),
+            &AuditEntry::new(AuditEvent::ProxyRead, "local", "default"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Cannot write the audit log"));
+    }
+}
@@ -0,0 +1,194 @@
+//! Resume checkpoints for bulk operations
+//!
+//! When a bulk command is interrupted or fails part-way, the names of the
+//! items it already finished are saved under `<config dir>/checkpoints/`.
+//! Re-running the same command with `--resume` skips those items. A
+//! checkpoint is keyed by the operation and its scope (source, target, and
+//! anything else that decides which items the run covers), and is removed
+//! once a run completes cleanly. Only item names are stored, never values.
+
+use crate::config::Config;
+use crate::error::{CrosstacheError, Result};
+use crate::utils::output;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Completed items of one interrupted or failed run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub operation: String,
+    pub scope: String,
+    pub completed: BTreeSet<String>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip)]
+    dir: Option<PathBuf>,
+}
+
+impl Checkpoint {
+    /// Start tracking a run. With `resume`, items recorded by an earlier run
+    /// with the same operation and scope are treated as already done;
+    /// without it any stale checkpoint is discarded.
+    pub fn open(operation: &str, scope: &str, resume: bool) -> Result<Self> {
+        Self::open_in(checkpoint_dir(), operation, scope, resume)
+    }
+
+    fn open_in(dir: Option<PathBuf>, operation: &str, scope: &str, resume: bool) -> Result<Self> {
+        let mut checkpoint = Self {
+            operation: operation.to_string(),
+            scope: scope.to_string(),
+            completed: BTreeSet::new(),
+            updated_at: Utc::now(),
+            dir,
+        };
+        let Some(path) = checkpoint.path() else {
+            return Ok(checkpoint);
+        };
+        if !resume {
+            let _ = std::fs::remove_file(&path);
+            return Ok(checkpoint);
+        }
+        match std::fs::read_to_string(&path) {
+            Ok(json) => {
+                let saved: Checkpoint = serde_json::from_str(&json).map_err(|e| {
+                    CrosstacheError::serialization(format!(
+                        "Failed to parse checkpoint '{}': {e}",
+                        path.display()
+                    ))
+                })?;
+                checkpoint.completed = saved.completed;
+                output::info(&format!(
+                    "Resuming {operation}: {} item(s) already completed",
+                    checkpoint.completed.len()
+                ));
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                output::warn(&format!(
+                    "No checkpoint found for this {operation}; starting from the beginning"
+                ));
+            }
+            Err(e) => {
+                return Err(CrosstacheError::unknown(format!(
+                    "Failed to read checkpoint '{}': {e}",
+                    path.display()
+                )));
+            }
+        }
+        Ok(checkpoint)
+    }
+
+    /// Whether an earlier run already finished `name`.
+    pub fn is_done(&self, name: &str) -> bool {
+        self.completed.contains(name)
+    }
+
+    pub fn mark_done(&mut self, name: impl Into<String>) {
+        self.completed.insert(name.into());
+    }
+
+    /// Persist or clear the checkpoint according to how the run ended: a
+    /// clean run removes it, a failed or interrupted one saves the completed
+    /// items so `--resume` can pick up from there.
+    pub fn finish<T>(mut self, outcome: &Result<T>) {
+        let Some(path) = self.path() else {
+            return;
+        };
+        if outcome.is_ok() || self.completed.is_empty() {
+            let _ = std::fs::remove_file(&path);
+            return;
+        }
+        self.updated_at = Utc::now();
+        match self.save(&path) {
+            Ok(()) => output::hint(&format!(
+                "Progress saved; re-run with --resume to skip the {} completed item(s)",
+                self.completed.len()
+            )),
+            Err(e) => output::warn(&e.to_string()),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                CrosstacheError::unknown(format!(
+                    "Failed to create checkpoint directory '{}': {e}",
+                    parent.display()
+                ))
+            })?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            CrosstacheError::serialization(format!("Failed to serialize checkpoint: {e}"))
+        })?;
+        std::fs::write(path, json + "\n").map_err(|e| {
+            CrosstacheError::unknown(format!(
+                "Failed to write checkpoint '{}': {e}",
+                path.display()
+            ))
+        })
+    }
+
+    /// `<dir>/<operation>-<hash of scope>.json`
+    fn path(&self) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        let digest = hex::encode(Sha256::digest(self.scope.as_bytes()));
+        let operation = self.operation.replace(' ', "-");
+        Some(dir.join(format!("{operation}-{}.json", &digest[..16])))
+    }
+}
+
+fn checkpoint_dir() -> Option<PathBuf> {
+    Config::get_config_path()
+        .ok()
+        .and_then(|path| path.parent().map(|dir| dir.join("checkpoints")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_run_saves_completed_items_for_resume() {
+        let dir = tempfile::tempdir().unwrap();
+        let open = |resume| {
+            Checkpoint::open_in(
+                Some(dir.path().to_path_buf()),
+                "vault import",
+                "kv-app\nsecrets.json",
+                resume,
+            )
+            .unwrap()
+        };
+
+        let mut checkpoint = open(false);
+        checkpoint.mark_done("a");
+        checkpoint.mark_done("b");
+        checkpoint.finish::<()>(&Err(CrosstacheError::interrupted(2, 1)));
+
+        let resumed = open(true);
+        assert!(resumed.is_done("a") && resumed.is_done("b"));
+        assert!(!resumed.is_done("c"));
+
+        // A clean run clears the checkpoint.
+        resumed.finish(&Ok(()));
+        assert!(open(true).completed.is_empty());
+    }
+
+    #[test]
+    fn scope_isolates_checkpoints_and_fresh_runs_discard_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let open = |scope: &str, resume| {
+            Checkpoint::open_in(Some(dir.path().to_path_buf()), "migrate", scope, resume).unwrap()
+        };
+
+        let mut checkpoint = open("local:a -> local:b", false);
+        checkpoint.mark_done("x");
+        checkpoint.finish::<()>(&Err(CrosstacheError::unknown("boom")));
+
+        assert!(open("local:a -> local:c", true).completed.is_empty());
+        // Starting without --resume throws the old progress away.
+        open("local:a -> local:b", false);
+        assert!(open("local:a -> local:b", true).completed.is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+//! Per-command correlation and client request ids
+//!
+//! Every Azure request an `xv` invocation makes — Key Vault, ARM and
+//! Storage — carries the same `x-ms-correlation-request-id`, so one command
+//! can be traced end to end in the Activity Log and in Microsoft's service
+//! logs. Requests also carry an `x-ms-client-request-id`, which the service
+//! echoes back and records against the individual call. The correlation id is
+//! logged under `--debug` and printed with error output once a request has
+//! been sent, so it can be quoted in a support case.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+/// Header carrying the per-command correlation id.
+pub const CORRELATION_REQUEST_ID: &str = "x-ms-correlation-request-id";
+/// Header carrying the caller-chosen id for a single request.
+pub const CLIENT_REQUEST_ID: &str = "x-ms-client-request-id";
+/// Header asking the service to echo the client request id in its response.
+pub const RETURN_CLIENT_REQUEST_ID: &str = "x-ms-return-client-request-id";
+
+static CORRELATION_ID: OnceLock<String> = OnceLock::new();
+static SENT: AtomicBool = AtomicBool::new(false);
+
+/// The correlation id shared by every request this process sends.
+pub fn correlation_id() -> &'static str {
+    CORRELATION_ID.get_or_init(|| uuid::Uuid::new_v4().to_string())
+}
+
+/// A fresh id for one request (or one short-lived client).
+pub fn new_client_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// The correlation id, but only once an Azure request has carried it —
+/// commands that never reached Azure have nothing to quote.
+pub fn sent_correlation_id() -> Option<&'static str> {
+    SENT.load(Ordering::Relaxed).then(correlation_id)
+}
+
+/// Record that the ids are about to go out on the wire, logging the
+/// correlation id the first time and the client request id every time.
+fn mark_sent(client_request_id: &str) {
+    if !SENT.swap(true, Ordering::Relaxed) {
+        tracing::debug!("Azure correlation id: {}", correlation_id());
+    }
+    tracing::debug!("Azure client request id: {client_request_id}");
+}
+
+/// Default headers for `reqwest` clients: the correlation id, and the ask
+/// to echo client request ids. Clients are long-lived and shared, so the
+/// client request id is added per request by [`with_client_request_id`].
+pub fn default_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in [
+        (CORRELATION_REQUEST_ID, correlation_id()),
+        (RETURN_CLIENT_REQUEST_ID, "true"),
+    ] {
+        if let Ok(value) = HeaderValue::from_str(value) {
+            headers.insert(name, value);
+        }
+    }
+    headers
+}
+
+/// Tag one `reqwest` request with a fresh client request id.
+pub fn with_client_request_id(request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    let client_request_id = new_client_request_id();
+    mark_sent(&client_request_id);
+    request.header(CLIENT_REQUEST_ID, client_request_id)
+}
+
+/// Pipeline policy adding the same headers to Azure SDK clients that accept
+/// client options (Storage), with a fresh client request id per request.
+#[derive(Debug, Default)]
+pub struct CorrelationPolicy;
+
+#[async_trait]
+impl azure_core::Policy for CorrelationPolicy {
+    async fn send(
+        &self,
+        ctx: &azure_core::Context,
+        request: &mut azure_core::Request,
+        next: &[Arc<dyn azure_core::Policy>],
+    ) -> azure_core::PolicyResult {
+        let client_request_id = new_client_request_id();
+        mark_sent(&client_request_id);
+        request.insert_header(CORRELATION_REQUEST_ID, correlation_id().to_string());
+        request.insert_header(CLIENT_REQUEST_ID, client_request_id);
+        request.insert_header(RETURN_CLIENT_REQUEST_ID, "true");
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
+
+/// Client options carrying [`CorrelationPolicy`].
+pub fn sdk_client_options() -> azure_core::ClientOptions {
+    azure_core::ClientOptions::default().per_call_policies(vec![
+        Arc::new(CorrelationPolicy) as Arc<dyn azure_core::Policy>
+    ])
+}
+
+/// Operation context for Azure SDK clients whose pipeline cannot be
+/// configured (Key Vault): the SDK's custom-headers policy applies these.
+pub fn sdk_context() -> azure_core::Context {
+    let client_request_id = new_client_request_id();
+    mark_sent(&client_request_id);
+
+    let mut headers = azure_core::headers::Headers::new();
+    headers.insert(CORRELATION_REQUEST_ID, correlation_id().to_string());
+    headers.insert(CLIENT_REQUEST_ID, client_request_id);
+    headers.insert(RETURN_CLIENT_REQUEST_ID, "true");
+
+    let mut ctx = azure_core::Context::new();
+    ctx.insert(azure_core::CustomHeaders::from(headers));
+    ctx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correlation_id_is_stable_and_client_ids_are_fresh() {
+        assert_eq!(correlation_id(), correlation_id());
+        assert!(uuid::Uuid::parse_str(correlation_id()).is_ok());
+        assert_ne!(new_client_request_id(), new_client_request_id());
+    }
+
+    #[test]
+    fn requests_get_their_own_client_request_id() {
+        let client = reqwest::Client::builder()
+            .default_headers(default_headers())
+            .build()
+            .unwrap();
+        let request = |client: &reqwest::Client| {
+            with_client_request_id(client.get("https://example.invalid/"))
+                .build()
+                .unwrap()
+        };
+        let (first, second) = (request(&client), request(&client));
+
+        assert_eq!(
+            default_headers().get(CORRELATION_REQUEST_ID).unwrap(),
+            correlation_id()
+        );
+        assert_eq!(
+            default_headers().get(RETURN_CLIENT_REQUEST_ID).unwrap(),
+            "true"
+        );
+        assert!(default_headers().get(CLIENT_REQUEST_ID).is_none());
+        assert_ne!(
+            first.headers().get(CLIENT_REQUEST_ID),
+            second.headers().get(CLIENT_REQUEST_ID)
+        );
+        assert_eq!(sent_correlation_id(), Some(correlation_id()));
+    }
+}
@@ -111,6 +111,63 @@ pub fn parse_iso_datetime(input: &str) -> Result<DateTime<Utc>> {
     )))
 }
 
+/// Days before expiry at which indicators switch to the warning color.
+pub const EXPIRY_WARNING_DAYS: i64 = 30;
+
+/// How urgent a secret's expiry is, used to color-code indicators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryUrgency {
+    /// Already past its expiry date
+    Expired,
+    /// Expires within [`EXPIRY_WARNING_DAYS`]
+    Soon,
+    /// Expires further out
+    Later,
+}
+
+/// Describe how far `expiry` is from `now`, e.g. "expires in 12 days",
+/// "expires today" or "expired 3 days ago", along with its urgency.
+pub fn describe_expiry(expiry: DateTime<Utc>, now: DateTime<Utc>) -> (String, ExpiryUrgency) {
+    let plural = |n: i64| if n == 1 { "day" } else { "days" };
+    if expiry <= now {
+        let days = (now - expiry).num_days();
+        let label = if days == 0 {
+            "expired today".to_string()
+        } else {
+            format!("expired {days} {} ago", plural(days))
+        };
+        return (label, ExpiryUrgency::Expired);
+    }
+    let days = (expiry - now).num_days();
+    let label = if days == 0 {
+        "expires today".to_string()
+    } else {
+        format!("expires in {days} {}", plural(days))
+    };
+    let urgency = if days <= EXPIRY_WARNING_DAYS {
+        ExpiryUrgency::Soon
+    } else {
+        ExpiryUrgency::Later
+    };
+    (label, urgency)
+}
+
+/// How long ago `then` was, coarsely: "just now", "5 minutes ago",
+/// "3 hours ago", "2 days ago".
+pub fn describe_age(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let age = now - then;
+    let (n, unit) = if age.num_days() > 0 {
+        (age.num_days(), "day")
+    } else if age.num_hours() > 0 {
+        (age.num_hours(), "hour")
+    } else if age.num_minutes() > 0 {
+        (age.num_minutes(), "minute")
+    } else {
+        return "just now".to_string();
+    };
+    format!("{n} {unit}{} ago", if n == 1 { "" } else { "s" })
+}
+
 /// Check if a secret has expired based on its expiry date
 pub fn is_expired(expires_on: Option<DateTime<Utc>>) -> bool {
     match expires_on {
@@ -204,6 +261,45 @@ mod tests {
         assert!(!is_expired(None));
     }
 
+    #[test]
+    fn test_describe_expiry() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            describe_expiry(now + Duration::days(90), now),
+            ("expires in 90 days".to_string(), ExpiryUrgency::Later)
+        );
+        assert_eq!(
+            describe_expiry(now + Duration::days(1), now),
+            ("expires in 1 day".to_string(), ExpiryUrgency::Soon)
+        );
+        assert_eq!(
+            describe_expiry(now + Duration::hours(3), now),
+            ("expires today".to_string(), ExpiryUrgency::Soon)
+        );
+        assert_eq!(
+            describe_expiry(now - Duration::hours(3), now),
+            ("expired today".to_string(), ExpiryUrgency::Expired)
+        );
+        assert_eq!(
+            describe_expiry(now - Duration::days(3), now),
+            ("expired 3 days ago".to_string(), ExpiryUrgency::Expired)
+        );
+    }
+
+    #[test]
+    fn test_describe_age() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(describe_age(now - Duration::seconds(20), now), "just now");
+        assert_eq!(
+            describe_age(now - Duration::minutes(1), now),
+            "1 minute ago"
+        );
+        assert_eq!(describe_age(now - Duration::hours(5), now), "5 hours ago");
+        assert_eq!(describe_age(now - Duration::days(2), now), "2 days ago");
+    }
+
     #[test]
     fn test_datetime_or_duration() {
         // Test relative duration
@@ -0,0 +1,172 @@
+//! Reading dotenv (`.env`) files.
+//!
+//! `xv env push` and `--map` files read through [`parse`]. It accepts what
+//! `xv env pull` writes, plus the common hand-written forms: an optional
+//! `export ` prefix, unquoted values with a trailing ` # comment`,
+//! single-quoted values (literal, joined POSIX-style as in `'it'\''s'`), and
+//! double-quoted values with `\n`, `\t`, `\"`, `\\` and `\$` escapes. Quoted
+//! values may span several lines.
+
+use zeroize::Zeroizing;
+
+use crate::error::{CrosstacheError, Result};
+
+/// Parse dotenv `content` into `(name, value)` pairs in file order.
+/// Errors name the offending line.
+pub fn parse(content: &str) -> Result<Vec<(String, Zeroizing<String>)>> {
+    let mut entries = Vec::new();
+    let mut rest = content;
+    let mut line = 1;
+    while !rest.is_empty() {
+        let (statement, consumed) = next_statement(rest, line)?;
+        line += rest[..consumed].matches('\n').count();
+        rest = &rest[consumed..];
+        if let Some(entry) = statement {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+type Statement = Option<(String, Zeroizing<String>)>;
+
+/// Parse one assignment (or blank/comment line) at the start of `input`,
+/// returning it and the number of bytes consumed, through the newline.
+fn next_statement(input: &str, line: usize) -> Result<(Statement, usize)> {
+    let err = |msg: &str| CrosstacheError::invalid_argument(format!("line {line}: {msg}"));
+    let line_end = input.find('\n').map_or(input.len(), |i| i + 1);
+    let trimmed = input[..line_end].trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok((None, line_end));
+    }
+
+    let start = input.len() - input.trim_start().len();
+    let mut pos = start;
+    if input[pos..].starts_with("export ") {
+        pos += "export ".len();
+        pos += input[pos..].len() - input[pos..].trim_start_matches([' ', '\t']).len();
+    }
+    let Some(eq) = input[pos..line_end].find('=') else {
+        return Err(err("expected NAME=value"));
+    };
+    let name = input[pos..pos + eq].trim_end();
+    if !crate::cli::config_ops::is_posix_assignment_name(name) {
+        return Err(err(&format!("'{name}' is not a valid variable name")));
+    }
+    pos += eq + 1;
+    pos += input[pos..].len() - input[pos..].trim_start_matches([' ', '\t']).len();
+
+    let mut value = Zeroizing::new(String::new());
+    let mut chars = input[pos..].char_indices().peekable();
+    let mut end = input.len();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' => loop {
+                match chars.next() {
+                    Some((_, '\'')) => break,
+                    Some((_, c)) => value.push(c),
+                    None => return Err(err("unterminated single quote")),
+                }
+            },
+            '"' => loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, 'n')) => value.push('\n'),
+                        Some((_, 't')) => value.push('\t'),
+                        Some((_, 'r')) => value.push('\r'),
+                        Some((_, c @ ('"' | '\\' | '$' | '`'))) => value.push(c),
+                        Some((_, '\n')) => {}
+                        Some((_, c)) => {
+                            value.push('\\');
+                            value.push(c);
+                        }
+                        None => return Err(err("unterminated double quote")),
+                    },
+                    Some((_, c)) => value.push(c),
+                    None => return Err(err("unterminated double quote")),
+                }
+            },
+            '\\' => match chars.next() {
+                Some((_, '\n')) | None => {}
+                Some((_, c)) => value.push(c),
+            },
+            '\n' => {
+                end = pos + i + 1;
+                break;
+            }
+            ' ' | '\t' | '\r' => {
+                // Only a comment may follow the value on its line.
+                let after = &input[pos + i..];
+                let after_end = after.find('\n').map_or(after.len(), |n| n + 1);
+                let tail = after[..after_end].trim();
+                if !tail.is_empty() && !tail.starts_with('#') {
+                    return Err(err(&format!(
+                        "unexpected text after the value of {name}; quote values containing spaces"
+                    )));
+                }
+                end = pos + i + after_end;
+                break;
+            }
+            c => value.push(c),
+        }
+    }
+    Ok((Some((name.to_string(), value)), end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(content: &str) -> Vec<(String, String)> {
+        parse(content)
+            .unwrap()
+            .into_iter()
+            .map(|(k, v)| (k, v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn reads_common_forms() {
+        let content = "# comment\n\
+                       PLAIN=abc\n\
+                       export EXPORTED=1\n\
+                       SPACED = value # trailing comment\n\
+                       EMPTY=\n\
+                       SINGLE='a b $c'\n\
+                       DOUBLE=\"line1\\nline2 \\\"q\\\"\"\n";
+        assert_eq!(
+            pairs(content),
+            vec![
+                ("PLAIN".into(), "abc".into()),
+                ("EXPORTED".into(), "1".into()),
+                ("SPACED".into(), "value".into()),
+                ("EMPTY".into(), "".into()),
+                ("SINGLE".into(), "a b $c".into()),
+                ("DOUBLE".into(), "line1\nline2 \"q\"".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trips_posix_single_quoting() {
+        let quoted = crate::cli::config_ops::quote_posix_shell_value("it's\nmulti line");
+        let content = format!("A={quoted}\nB=2\n");
+        assert_eq!(
+            pairs(&content),
+            vec![
+                ("A".into(), "it's\nmulti line".into()),
+                ("B".into(), "2".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let err = parse("A=1\nnot an assignment\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{err}");
+        assert!(parse("A='open\n").is_err());
+        assert!(parse("A=two words\n").is_err());
+        assert!(parse("1A=x\n").is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//! Host environment detection
+//!
+//! WSL and container shells usually have no browser to finish an interactive
+//! sign-in, no clipboard, often no `az` CLI, and sometimes no `$HOME`. This
+//! module detects that once per process so the rest of xv can adapt instead
+//! of failing: Azure auth falls back to the device-code flow (or managed
+//! identity in a non-interactive container), `xv get`/`xv gen` print the
+//! value instead of copying it, and the config directory resolves even
+//! without `$HOME`.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::config::settings::AzureCredentialType;
+
+/// Overrides the directory holding `xv.conf` and the global context.
+pub const CONFIG_DIR_ENV: &str = "XV_CONFIG_DIR";
+
+/// What this process can rely on from the machine it runs on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostEnvironment {
+    /// Running under Windows Subsystem for Linux.
+    pub wsl: bool,
+    /// Running inside a container (Docker, Podman, Kubernetes, ...).
+    pub container: bool,
+    /// A graphical session is available (always true off Linux).
+    pub display: bool,
+    /// The `az` CLI is on `PATH`.
+    pub az_cli: bool,
+    /// stdin and stderr are terminals, so the user can answer a prompt.
+    pub interactive: bool,
+}
+
+static CURRENT: OnceLock<HostEnvironment> = OnceLock::new();
+
+/// The environment of this process, detected on first use.
+pub fn current() -> &'static HostEnvironment {
+    CURRENT.get_or_init(HostEnvironment::detect)
+}
+
+impl HostEnvironment {
+    fn detect() -> Self {
+        use std::io::IsTerminal;
+
+        let proc_version = std::fs::read_to_string("/proc/sys/kernel/osrelease")
+            .or_else(|_| std::fs::read_to_string("/proc/version"))
+            .unwrap_or_default();
+        let cgroup = std::fs::read_to_string("/proc/1/cgroup").unwrap_or_default();
+        let env_set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+
+        Self {
+            wsl: is_wsl(&proc_version, env_set("WSL_DISTRO_NAME")),
+            container: is_container(
+                Path::new("/.dockerenv").exists() || Path::new("/run/.containerenv").exists(),
+                &cgroup,
+                env_set("container") || env_set("KUBERNETES_SERVICE_HOST"),
+            ),
+            display: !cfg!(target_os = "linux") || env_set("DISPLAY") || env_set("WAYLAND_DISPLAY"),
+            az_cli: on_path(if cfg!(windows) { "az.cmd" } else { "az" }),
+            interactive: std::io::stdin().is_terminal() && std::io::stderr().is_terminal(),
+        }
+    }
+
+    /// A browser can be opened for an interactive sign-in.
+    pub fn has_browser(&self) -> bool {
+        self.display && !self.wsl && !self.container
+    }
+
+    /// Copying to the clipboard can work. WSLg sets `WAYLAND_DISPLAY`, so
+    /// WSL counts as having a clipboard only under WSLg.
+    pub fn has_clipboard(&self) -> bool {
+        self.display && !self.container
+    }
+
+    /// The credential to use instead of the default chain, when the default
+    /// chain cannot work here: no `az` CLI and no browser, and no
+    /// service-principal or workload-identity variables for the chain to
+    /// pick up. A user at a terminal gets the device-code flow; anything
+    /// else in a container gets managed identity.
+    pub fn adapted_credential(&self, env_credentials: bool) -> Option<AzureCredentialType> {
+        if env_credentials || self.az_cli || self.has_browser() {
+            return None;
+        }
+        if self.interactive {
+            Some(AzureCredentialType::DeviceCode)
+        } else if self.container {
+            Some(AzureCredentialType::ManagedIdentity)
+        } else {
+            None
+        }
+    }
+
+    /// One-line description for `xv whoami` and `xv doctor`.
+    pub fn describe(&self) -> String {
+        let mut traits = Vec::new();
+        if self.wsl {
+            traits.push("WSL");
+        }
+        if self.container {
+            traits.push("container");
+        }
+        if !self.display {
+            traits.push("no display");
+        }
+        if !self.az_cli {
+            traits.push("no az CLI");
+        }
+        if traits.is_empty() {
+            "desktop".to_string()
+        } else {
+            traits.join(", ")
+        }
+    }
+}
+
+/// True when the environment already carries credentials the default Azure
+/// chain reads on its own: a service principal or a workload identity.
+pub fn has_env_azure_credentials() -> bool {
+    let set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+    set("AZURE_CLIENT_ID")
+        && (set("AZURE_CLIENT_SECRET")
+            || set("AZURE_CLIENT_CERTIFICATE_PATH")
+            || set("AZURE_FEDERATED_TOKEN_FILE"))
+}
+
+/// The directory holding `xv.conf` and the global context:
+/// `$XV_CONFIG_DIR`, else `$XDG_CONFIG_HOME/xv`, else `~/.config/xv`, where
+/// the home directory falls back to the passwd entry when `$HOME` is unset
+/// (common in containers).
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn config_dir() -> Option<PathBuf> {
+    config_dir_from(
+        std::env::var_os(CONFIG_DIR_ENV),
+        std::env::var_os("XDG_CONFIG_HOME"),
+        std::env::var_os("HOME").or_else(|| dirs::home_dir().map(PathBuf::into_os_string)),
+    )
+}
+
+/// The directory holding `xv.conf` and the global context:
+/// `%XV_CONFIG_DIR%`, else the platform config directory.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CONFIG_DIR_ENV).filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    #[cfg(all(windows, feature = "windows-compat"))]
+    if let Some(dir) = crate::utils::windows::appdata_config_dir(std::env::var_os("APPDATA")) {
+        return Some(dir);
+    }
+    dirs::config_dir().map(|dir| dir.join("xv"))
+}
+
+#[cfg_attr(not(any(target_os = "linux", target_os = "macos")), allow(dead_code))]
+fn config_dir_from(
+    override_dir: Option<std::ffi::OsString>,
+    xdg_config_home: Option<std::ffi::OsString>,
+    home: Option<std::ffi::OsString>,
+) -> Option<PathBuf> {
+    let non_empty = |value: Option<std::ffi::OsString>| value.filter(|v| !v.is_empty());
+    if let Some(dir) = non_empty(override_dir) {
+        return Some(PathBuf::from(dir));
+    }
+    if let Some(dir) = non_empty(xdg_config_home) {
+        return Some(PathBuf::from(dir).join("xv"));
+    }
+    non_empty(home).map(|home| PathBuf::from(home).join(".config").join("xv"))
+}
+
+/// `/proc/sys/kernel/osrelease` reads like `5.15.90.1-microsoft-standard-WSL2`
+/// under WSL; `WSL_DISTRO_NAME` is set in WSL shells.
+fn is_wsl(kernel_release: &str, distro_env: bool) -> bool {
+    distro_env || kernel_release.to_ascii_lowercase().contains("microsoft")
+}
+
+/// Docker and Podman drop a marker file; PID 1's cgroup names the runtime
+/// on cgroup v1; Podman and systemd-nspawn set `container`, Kubernetes sets
+/// `KUBERNETES_SERVICE_HOST`.
+fn is_container(marker_file: bool, pid1_cgroup: &str, env_marker: bool) -> bool {
+    marker_file
+        || env_marker
+        || ["docker", "kubepods", "containerd", "libpod", "lxc"]
+            .iter()
+            .any(|runtime| pid1_cgroup.contains(runtime))
+}
+
+fn on_path(program: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join(program).is_file()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(wsl: bool, container: bool, display: bool, az_cli: bool) -> HostEnvironment {
+        HostEnvironment {
+            wsl,
+            container,
+            display,
+            az_cli,
+            interactive: true,
+        }
+    }
+
+    #[test]
+    fn detects_wsl_and_containers() {
+        assert!(is_wsl("5.15.90.1-microsoft-standard-WSL2", false));
+        assert!(is_wsl("", true));
+        assert!(!is_wsl("6.8.0-45-generic", false));
+
+        assert!(is_container(true, "", false));
+        assert!(is_container(
+            false,
+            "12:pids:/kubepods/besteffort/pod1",
+            false
+        ));
+        assert!(is_container(false, "", true));
+        assert!(!is_container(false, "0::/init.scope", false));
+    }
+
+    #[test]
+    fn adapted_credential_only_when_default_chain_cannot_work() {
+        // Desktop, or anywhere with az or env credentials: keep the chain.
+        assert_eq!(
+            host(false, false, true, false).adapted_credential(false),
+            None
+        );
+        assert_eq!(
+            host(true, false, false, true).adapted_credential(false),
+            None
+        );
+        assert_eq!(
+            host(true, false, false, false).adapted_credential(true),
+            None
+        );
+
+        // WSL or a container without az: device code at a terminal.
+        assert_eq!(
+            host(true, false, true, false).adapted_credential(false),
+            Some(AzureCredentialType::DeviceCode)
+        );
+        let mut headless = host(false, true, false, false);
+        assert_eq!(
+            headless.adapted_credential(false),
+            Some(AzureCredentialType::DeviceCode)
+        );
+        // ...and managed identity when nobody can answer the prompt.
+        headless.interactive = false;
+        assert_eq!(
+            headless.adapted_credential(false),
+            Some(AzureCredentialType::ManagedIdentity)
+        );
+    }
+
+    #[test]
+    fn clipboard_needs_a_display_outside_containers() {
+        assert!(host(false, false, true, true).has_clipboard());
+        assert!(host(true, false, true, true).has_clipboard());
+        assert!(!host(true, false, false, true).has_clipboard());
+        assert!(!host(false, true, true, true).has_clipboard());
+    }
+
+    #[test]
+    fn config_dir_falls_back_from_override_to_xdg_to_home() {
+        assert_eq!(
+            config_dir_from(Some("/etc/xv".into()), Some("/x".into()), Some("/h".into())),
+            Some(PathBuf::from("/etc/xv"))
+        );
+        assert_eq!(
+            config_dir_from(Some("".into()), Some("/x".into()), Some("/h".into())),
+            Some(PathBuf::from("/x/xv"))
+        );
+        assert_eq!(
+            config_dir_from(None, None, Some("/h".into())),
+            Some(PathBuf::from("/h/.config/xv"))
+        );
+        assert_eq!(config_dir_from(None, None, None), None);
+    }
+}
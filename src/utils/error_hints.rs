@@ -10,11 +10,18 @@ pub fn hint_for(code: &str) -> Option<&'static str> {
         "xv-secret-not-found" => "Run 'xv list' to see secrets in the active vault.",
         "xv-invalid-secret-name" => "Names must be alphanumeric + hyphens; see 'xv help set'.",
         "xv-permission-denied" => "Check your role with 'xv whoami'; see 'xv vault share list'.",
+        "xv-forbidden" => {
+            "Check your role with 'xv whoami'; grant the required permission, then retry."
+        }
+        "xv-not-found" => "Check the name and the active vault or storage container.",
         "xv-auth-failed" => {
             "Try 'az login' or set AZURE_CLIENT_ID / AZURE_CLIENT_SECRET / AZURE_TENANT_ID."
         }
         "xv-network-dns" => "Check the vault name and your DNS settings.",
-        "xv-network-timeout" => "Check your network connection or proxy settings.",
+        "xv-network" => "Run 'xv doctor' to test connectivity to each Azure endpoint.",
+        "xv-network-timeout" => {
+            "Run 'xv doctor' to test each endpoint; behind a proxy, set HTTPS_PROXY."
+        }
         "xv-network-refused" => "Verify the vault exists and is reachable from this network.",
         "xv-network-ssl" => {
             "Check TLS configuration and any corporate proxy with TLS interception."
@@ -23,6 +30,9 @@ pub fn hint_for(code: &str) -> Option<&'static str> {
         "xv-env-not-defined" => "Run 'xv env list' to see defined environments.",
         "xv-azure-api" => "Check Azure service status and your subscription quotas.",
         "xv-scan-leak-detected" => "Findings printed to stderr; review and remove the leak before committing. Use 'xv scan --hook' for CI integration.",
+        "xv-backup-incomplete" => "The archive was kept but lacks the failed secrets, and older backups were not pruned. Fix read access and run the backup again.",
+        "xv-reference-check-failed" => "Fix each reference marked broken (rename the setting's secret, re-enable it, or grant the app identity 'Key Vault Secrets User') and run 'xv appservice check' again.",
+        "xv-lint-failed" => "Create the missing secrets (or drop the stale references) and run 'xv lint' again.",
         _ => return None,
     })
 }
@@ -36,6 +46,8 @@ mod tests {
         assert!(hint_for("xv-vault-not-found").is_some());
         assert!(hint_for("xv-secret-not-found").is_some());
         assert!(hint_for("xv-permission-denied").is_some());
+        assert!(hint_for("xv-forbidden").is_some());
+        assert!(hint_for("xv-not-found").is_some());
         assert!(hint_for("xv-network-dns").is_some());
         assert!(hint_for("xv-config-invalid").is_some());
         assert!(hint_for("xv-env-not-defined").is_some());
@@ -11,6 +11,9 @@ use nucleo::{Config, Matcher, Utf32Str};
 #[derive(Debug, Clone)]
 pub struct CandidateItem {
     pub name: String,
+    /// The stored (sanitized) name when it differs from `name`; searched
+    /// along with `name`.
+    pub stored_name: Option<String>,
     pub folder: Option<String>,
     pub groups: Option<String>,
     pub note: Option<String>,
@@ -30,6 +33,17 @@ pub enum FuzzyField {
     Tags,
 }
 
+impl FuzzyField {
+    /// Every searchable field, for `--in all`.
+    pub const ALL: [FuzzyField; 5] = [
+        FuzzyField::Name,
+        FuzzyField::Folder,
+        FuzzyField::Groups,
+        FuzzyField::Note,
+        FuzzyField::Tags,
+    ];
+}
+
 /// One scored result.
 #[derive(Debug, Clone)]
 pub struct Match<'a> {
@@ -67,15 +81,7 @@ pub fn score_matches<'a>(
         let mut best: Option<u32> = None;
 
         for field in fields {
-            // Collect the string(s) to score for this field.
-            let field_strings: Vec<&str> = match field {
-                FuzzyField::Name => vec![item.name.as_str()],
-                FuzzyField::Folder => item.folder.as_deref().map(|s| vec![s]).unwrap_or_default(),
-                FuzzyField::Groups => item.groups.as_deref().map(|s| vec![s]).unwrap_or_default(),
-                FuzzyField::Note => item.note.as_deref().map(|s| vec![s]).unwrap_or_default(),
-                FuzzyField::Tags => item.tags.iter().map(String::as_str).collect(),
-            };
-            for hay_str in field_strings {
+            for hay_str in item.field_values(*field) {
                 let mut hay_buf = Vec::new();
                 let hay = Utf32Str::new(hay_str, &mut hay_buf);
                 if let Some(s) = matcher.fuzzy_match(hay, pattern_utf32) {
@@ -98,24 +104,67 @@ pub fn score_matches<'a>(
     out
 }
 
+/// Keep the items for which `is_match` accepts at least one of the
+/// requested fields, with score `0`, sorted by name (case-insensitive).
+/// The exact-match counterpart of [`score_matches`], used by
+/// `xv find --mode substring|regex`.
+pub fn filter_matches<'a>(
+    items: &'a [CandidateItem],
+    fields: &[FuzzyField],
+    is_match: impl Fn(&str) -> bool,
+) -> Vec<Match<'a>> {
+    let mut out: Vec<Match<'a>> = items
+        .iter()
+        .filter(|item| {
+            fields
+                .iter()
+                .any(|field| item.field_values(*field).into_iter().any(&is_match))
+        })
+        .map(|item| Match { item, score: 0 })
+        .collect();
+    out.sort_by_key(|m| m.item.name.to_lowercase());
+    out
+}
+
 impl CandidateItem {
+    /// The string(s) `field` searches; empty when the field is unset.
+    pub fn field_values(&self, field: FuzzyField) -> Vec<&str> {
+        match field {
+            FuzzyField::Name => std::iter::once(self.name.as_str())
+                .chain(self.stored_name.as_deref())
+                .collect(),
+            FuzzyField::Folder => self.folder.as_deref().into_iter().collect(),
+            FuzzyField::Groups => self.groups.as_deref().into_iter().collect(),
+            FuzzyField::Note => self.note.as_deref().into_iter().collect(),
+            FuzzyField::Tags => self.tags.iter().map(String::as_str).collect(),
+        }
+    }
+
     /// Adapt a `SecretSummary` to a `CandidateItem`. Prefers
     /// `original_name` over the sanitized `name` since users search
     /// against what they typed, not against post-sanitization forms.
-    /// Empty `original_name` falls back to `name`.
+    /// Empty `original_name` falls back to `name`. Tags are the groups
+    /// plus each user tag as `key=value`.
     pub fn from_secret_summary(s: &crate::secret::manager::SecretSummary) -> Self {
         let name = if s.original_name.is_empty() {
             s.name.clone()
         } else {
             s.original_name.clone()
         };
-        let tags: Vec<String> = s
+        let stored_name = (s.name != name).then(|| s.name.clone());
+        let mut tags: Vec<String> = s
             .groups
             .as_deref()
             .map(|g| g.split(',').map(|t| t.trim().to_string()).collect())
             .unwrap_or_default();
+        tags.extend(
+            crate::cli::secret_ops::user_tags_of(&s.tags)
+                .into_iter()
+                .map(|(k, v)| format!("{k}={v}")),
+        );
         Self {
             name,
+            stored_name,
             folder: s.folder.clone(),
             groups: s.groups.clone(),
             note: s.note.clone(),
@@ -132,6 +181,7 @@ mod tests {
     fn item(name: &str) -> CandidateItem {
         CandidateItem {
             name: name.to_string(),
+            stored_name: None,
             folder: None,
             groups: None,
             note: None,
@@ -195,6 +245,7 @@ mod tests {
     fn name_only_does_not_match_folder_text() {
         let items = vec![CandidateItem {
             name: "FOO".to_string(),
+            stored_name: None,
             folder: Some("database".to_string()),
             groups: None,
             note: None,
@@ -221,7 +272,10 @@ mod tests {
             enabled: true,
             expires_on: None,
             content_type: String::new(),
-            tags: std::collections::HashMap::new(),
+            tags: std::collections::HashMap::from([
+                ("owner".to_string(), "payments".to_string()),
+                ("groups".to_string(), "backend,prod".to_string()),
+            ]),
         };
         let item = CandidateItem::from_secret_summary(&summary);
         // Prefer original_name over sanitized name (matches the user-typed form).
@@ -229,10 +283,49 @@ mod tests {
         assert_eq!(item.folder.as_deref(), Some("backend/database"));
         assert_eq!(item.groups.as_deref(), Some("backend,prod"));
         assert_eq!(item.note.as_deref(), Some("primary db"));
-        // Tags come from groups (comma-separated). v0.6.1 has no separate
-        // tags field on SecretSummary; we map groups to tags so `--in tags`
-        // still works on existing data.
+        assert_eq!(item.stored_name, None);
+        // Tags are the groups plus user tags as key=value; the reserved
+        // `groups` tag is not repeated.
         assert!(item.tags.contains(&"backend".to_string()));
         assert!(item.tags.contains(&"prod".to_string()));
+        assert!(item.tags.contains(&"owner=payments".to_string()));
+        assert_eq!(item.tags.len(), 3);
+    }
+
+    #[test]
+    fn filter_matches_checks_every_requested_field() {
+        let items = vec![
+            CandidateItem {
+                name: "db.password".to_string(),
+                stored_name: Some("db-password".to_string()),
+                folder: None,
+                groups: None,
+                note: None,
+                tags: vec![],
+            },
+            CandidateItem {
+                note: Some("rotated by ops".to_string()),
+                ..item("API_TOKEN")
+            },
+            item("zzz"),
+        ];
+        let names = |matches: Vec<Match<'_>>| {
+            matches
+                .iter()
+                .map(|m| m.item.name.clone())
+                .collect::<Vec<_>>()
+        };
+        // The stored name is searched along with the display name.
+        assert_eq!(
+            names(filter_matches(&items, &[FuzzyField::Name], |v| v.contains("db-pass"))),
+            vec!["db.password"]
+        );
+        assert!(filter_matches(&items, &[FuzzyField::Name], |v| v.contains("ops")).is_empty());
+        assert_eq!(
+            names(filter_matches(&items, &FuzzyField::ALL, |v| v
+                .contains("ops")
+                || v.starts_with("zz"))),
+            vec!["API_TOKEN", "zzz"]
+        );
     }
 }
@@ -320,6 +320,8 @@ fn write_file_no_follow_with_mode(
                 })?
                 .join(path)
         };
+        // Deeply nested downloads can exceed MAX_PATH.
+        let absolute = crate::utils::windows::long_path_safe(&absolute);
         if let Some(parent) = absolute.parent() {
             let mut current = PathBuf::new();
             for component in parent.components() {
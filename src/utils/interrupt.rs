@@ -0,0 +1,68 @@
+//! Graceful Ctrl+C handling for bulk operations
+//!
+//! Bulk commands (`xv vault import`, `xv migrate`, multi-file
+//! `xv file download`) call [`watch`] before their main loop. While the
+//! returned guard is alive, the first Ctrl+C only sets a flag that the loop
+//! polls between items: in-flight items finish, the partial report and
+//! checkpoint are written, and a completed/remaining summary is printed. A
+//! second Ctrl+C exits immediately with code 130.
+//!
+//! Outside a guarded section Ctrl+C keeps its usual meaning and exits at once.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static WATCHING: AtomicBool = AtomicBool::new(false);
+static LISTENER: Once = Once::new();
+
+/// Exit code for an immediate (second) Ctrl+C: 128 + SIGINT.
+const FORCED_EXIT_CODE: i32 = 130;
+
+/// Keeps graceful interruption enabled until dropped.
+#[must_use = "interruption is only graceful while the guard is alive"]
+pub struct InterruptGuard(());
+
+impl Drop for InterruptGuard {
+    fn drop(&mut self) {
+        WATCHING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Enable graceful Ctrl+C handling for the current bulk operation. Must be
+/// called from within the tokio runtime.
+pub fn watch() -> InterruptGuard {
+    INTERRUPTED.store(false, Ordering::SeqCst);
+    WATCHING.store(true, Ordering::SeqCst);
+    LISTENER.call_once(|| {
+        tokio::spawn(async {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                if !WATCHING.load(Ordering::SeqCst) || INTERRUPTED.swap(true, Ordering::SeqCst) {
+                    std::process::exit(FORCED_EXIT_CODE);
+                }
+                crate::utils::output::warn(
+                    "Interrupt received: finishing in-flight items (press Ctrl+C again to abort immediately)",
+                );
+            }
+        });
+    });
+    InterruptGuard(())
+}
+
+/// Whether the user has asked the current bulk operation to stop.
+pub fn requested() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Print the completed/remaining summary for an interrupted run and build
+/// the matching error.
+pub fn stopped(completed: usize, remaining: &[String]) -> crate::error::CrosstacheError {
+    crate::utils::output::warn(&format!(
+        "Interrupted: {completed} item(s) completed, {} not processed",
+        remaining.len()
+    ));
+    for name in remaining {
+        eprintln!("  - {name}");
+    }
+    crate::error::CrosstacheError::interrupted(completed, remaining.len())
+}
@@ -0,0 +1,76 @@
+//! Request checks for the loopback HTTP servers (`xv ui` and `xv dev-proxy`).
+//!
+//! Threat model: the network is loopback-only; the real attacker is another
+//! web page in the user's browser issuing requests to 127.0.0.1 (CSRF / DNS
+//! rebinding). The bearer token is the gate; Host/Origin checks are a free
+//! second layer that specifically kills DNS rebinding (attacker-controlled
+//! hostname in Host).
+
+use sha2::{Digest, Sha256};
+
+/// Why a request was turned away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// `Host` is missing or not a loopback name.
+    Host,
+    /// `Origin` is present and not a loopback `http://` origin.
+    Origin,
+    /// The bearer token is missing or wrong.
+    Token,
+}
+
+impl Rejection {
+    /// HTTP status to answer with.
+    pub fn status(self) -> u16 {
+        match self {
+            Rejection::Host | Rejection::Origin => 403,
+            Rejection::Token => 401,
+        }
+    }
+
+    /// Short reason to send as the response body.
+    pub fn reason(self) -> &'static str {
+        match self {
+            Rejection::Host => "invalid Host header",
+            Rejection::Origin => "invalid Origin header",
+            Rejection::Token => "missing or invalid token",
+        }
+    }
+}
+
+/// `127.0.0.1` or `localhost`, with an optional numeric port.
+pub fn is_loopback_name(host_port: &str) -> bool {
+    // Only strip a real numeric port; "localhost:evil.example.com" must not
+    // parse as name "localhost".
+    let name = match host_port.rsplit_once(':') {
+        Some((h, p)) if !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()) => h,
+        _ => host_port,
+    };
+    name == "127.0.0.1" || name == "localhost"
+}
+
+/// Check the `Host`, `Origin` and bearer token of a request against `token`.
+///
+/// `bearer` is the `Authorization` value with the `Bearer ` prefix already
+/// removed.
+pub fn check_request(
+    host: Option<&str>,
+    origin: Option<&str>,
+    bearer: Option<&str>,
+    token: &str,
+) -> Result<(), Rejection> {
+    if !host.is_some_and(is_loopback_name) {
+        return Err(Rejection::Host);
+    }
+    if let Some(origin) = origin {
+        if !origin.strip_prefix("http://").is_some_and(is_loopback_name) {
+            return Err(Rejection::Origin);
+        }
+    }
+    // Digest comparison makes timing differences useless to an attacker.
+    let provided = bearer.unwrap_or("");
+    if Sha256::digest(provided.as_bytes()) != Sha256::digest(token.as_bytes()) {
+        return Err(Rejection::Token);
+    }
+    Ok(())
+}
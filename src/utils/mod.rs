@@ -14,20 +14,29 @@ pub const MAX_RESPONSE_BYTES: usize = 10 * 1024 * 1024; // 10 MB
 pub mod azure_detect {
     pub use crate::backend::azure::detect::*;
 }
+pub mod audit_log;
+pub mod checkpoint;
+pub mod correlation;
 pub mod datetime;
+pub mod dotenv;
+pub mod environment;
 pub mod error_hints;
 pub mod format;
 pub mod fuzzy;
 pub mod helpers;
 pub mod interactive;
+pub mod interrupt;
 pub mod list_output;
+pub mod local_auth;
 pub mod network;
 pub mod output;
 pub mod pager;
 pub mod pagination;
 pub mod progress;
+pub mod report;
 pub mod resource_detector;
 pub mod retry;
 pub mod sanitizer;
 pub mod suggestions;
 pub mod url_helpers;
+pub mod windows;
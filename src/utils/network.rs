@@ -19,12 +19,15 @@ impl Default for NetworkConfig {
     }
 }
 
-/// Create a properly configured HTTP client with timeouts
+/// Create a properly configured HTTP client with timeouts. Every request it
+/// sends carries the command's correlation id; Azure callers add a client
+/// request id per request (see [`crate::utils::correlation`]).
 pub fn create_http_client(config: &NetworkConfig) -> Result<Client> {
     Client::builder()
         .connect_timeout(config.connect_timeout)
         .timeout(config.request_timeout)
         .user_agent(&config.user_agent)
+        .default_headers(crate::utils::correlation::default_headers())
         .build()
         .map_err(|e| CrosstacheError::network(format!("Failed to create HTTP client: {e}")))
 }
@@ -33,11 +36,12 @@ pub fn create_http_client(config: &NetworkConfig) -> Result<Client> {
 pub fn classify_network_error(error: &reqwest::Error, url: &str) -> CrosstacheError {
     // Extract vault name from URL for better error messages
     let vault_name = extract_vault_name_from_url(url);
+    let via_proxy = proxy_note(url);
 
     // Check for timeout errors
     if error.is_timeout() {
         return CrosstacheError::connection_timeout(format!(
-            "Connection to Azure Key Vault '{vault_name}' timed out. This might be due to network issues or the vault being unreachable."
+            "Connection to Azure Key Vault '{vault_name}'{via_proxy} timed out. This might be due to network issues or the vault being unreachable."
         ));
     }
 
@@ -60,12 +64,12 @@ pub fn classify_network_error(error: &reqwest::Error, url: &str) -> CrosstacheEr
             .contains("connection refused")
         {
             return CrosstacheError::connection_refused(format!(
-                "Connection to Azure Key Vault '{vault_name}' was refused. The service may be temporarily unavailable."
+                "Connection to Azure Key Vault '{vault_name}'{via_proxy} was refused. The service may be temporarily unavailable."
             ));
         }
 
         return CrosstacheError::network(format!(
-            "Failed to connect to Azure Key Vault '{vault_name}'. Please check your network connection and verify the vault name."
+            "Failed to connect to Azure Key Vault '{vault_name}'{via_proxy}. Please check your network connection and verify the vault name."
         ));
     }
 
@@ -109,6 +113,99 @@ pub fn classify_network_error(error: &reqwest::Error, url: &str) -> CrosstacheEr
     ))
 }
 
+/// The environment proxy reqwest will use for `url`, if any.
+///
+/// Mirrors reqwest's resolution: `HTTPS_PROXY`, then `ALL_PROXY` (either
+/// case), skipped when the host matches `NO_PROXY`. Credentials embedded in
+/// the proxy URL are removed so the result is safe to print.
+pub fn env_proxy_for(url: &str) -> Option<String> {
+    proxy_for_url(url, |name| {
+        std::env::var(name)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+    })
+}
+
+fn proxy_for_url(url: &str, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let host = url::Url::parse(url).ok()?.host_str()?.to_ascii_lowercase();
+    let pick = |names: &[&str]| names.iter().find_map(|name| env(name));
+
+    let no_proxy = pick(&["NO_PROXY", "no_proxy"]).unwrap_or_default();
+    let bypassed = no_proxy.split(',').map(str::trim).any(|entry| {
+        let entry = entry.trim_start_matches('.').to_ascii_lowercase();
+        entry == "*"
+            || (!entry.is_empty() && (host == entry || host.ends_with(&format!(".{entry}"))))
+    });
+    if bypassed {
+        return None;
+    }
+
+    let proxy = pick(&["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"])?;
+    Some(redact_proxy_url(proxy.trim()))
+}
+
+fn redact_proxy_url(proxy: &str) -> String {
+    match url::Url::parse(proxy) {
+        Ok(mut parsed) => {
+            // Both setters only fail for URLs that cannot carry credentials.
+            let _ = parsed.set_username("");
+            let _ = parsed.set_password(None);
+            parsed.as_str().trim_end_matches('/').to_string()
+        }
+        Err(_) => "(unparseable proxy setting)".to_string(),
+    }
+}
+
+/// " via proxy <url>" when an environment proxy applies to `url`, for
+/// appending to connection failure messages.
+fn proxy_note(url: &str) -> String {
+    env_proxy_for(url)
+        .map(|proxy| format!(" via proxy {proxy}"))
+        .unwrap_or_default()
+}
+
+/// Result of a single reachability probe.
+#[derive(Debug)]
+pub struct EndpointProbe {
+    pub name: String,
+    pub url: String,
+    /// Round-trip time on success; a short failure description otherwise.
+    pub outcome: std::result::Result<Duration, String>,
+}
+
+/// Check that `url` answers at all. Any HTTP response, including 401 or
+/// 404, proves the network path works; only transport failures count.
+pub async fn probe_endpoint(client: &Client, name: &str, url: &str) -> EndpointProbe {
+    let started = std::time::Instant::now();
+    let outcome = match client.get(url).send().await {
+        Ok(_) => Ok(started.elapsed()),
+        Err(error) => Err(describe_probe_failure(&error, url)),
+    };
+    EndpointProbe {
+        name: name.to_string(),
+        url: url.to_string(),
+        outcome,
+    }
+}
+
+fn describe_probe_failure(error: &reqwest::Error, url: &str) -> String {
+    let via_proxy = proxy_note(url);
+    let lower = error.to_string().to_lowercase();
+    if error.is_timeout() {
+        format!("timed out{via_proxy}")
+    } else if lower.contains("certificate") || lower.contains("tls") || lower.contains("ssl") {
+        format!("TLS handshake failed{via_proxy} (a TLS-intercepting proxy needs its CA trusted)")
+    } else if error.is_connect() && via_proxy.is_empty() && is_dns_resolution_error(error) {
+        "DNS lookup failed".to_string()
+    } else if error.is_connect() && lower.contains("connection refused") {
+        format!("connection refused{via_proxy}")
+    } else if error.is_connect() {
+        format!("could not connect{via_proxy}")
+    } else {
+        format!("request failed{via_proxy}: {error}")
+    }
+}
+
 /// Enhanced DNS error detection
 fn is_dns_resolution_error(error: &reqwest::Error) -> bool {
     let error_msg = error.to_string().to_lowercase();
@@ -160,10 +257,194 @@ fn extract_vault_name_from_url(url: &str) -> String {
     )
 }
 
+/// Turn a non-success HTTP response into a typed error.
+///
+/// Shared by the secret and vault REST paths so 401/403/409/429 and gateway
+/// timeouts surface as their own variants, with the exit codes and retry
+/// behavior to match, instead of a generic Azure API error. `context` is the
+/// failed operation, e.g. "Failed to set secret". A 404 names the requested
+/// host and path, without the query string.
+pub async fn classify_http_response(response: reqwest::Response, context: &str) -> CrosstacheError {
+    let status = response.status();
+    let retry_after = parse_retry_after(response.headers());
+    let url = response.url();
+    let resource = format!(
+        "{}{}",
+        url.host_str().unwrap_or_default(),
+        url.path().trim_end_matches('/')
+    );
+    let body = response
+        .text()
+        .await
+        .unwrap_or_else(|e| format!("(failed to read error body: {e})"));
+    classify_http_error(status, retry_after, &body, &resource, context)
+}
+
+/// [`classify_http_response`] for a body the caller has already read, e.g.
+/// to inspect it for a more specific error first.
+pub fn classify_http_error(
+    status: reqwest::StatusCode,
+    retry_after: Option<u64>,
+    body: &str,
+    resource: &str,
+    context: &str,
+) -> CrosstacheError {
+    if status == reqwest::StatusCode::FORBIDDEN {
+        if let Some(denial) = detect_firewall_denial(body) {
+            return CrosstacheError::permission_denied(format!("{context}: {}", denial.explain()));
+        }
+    }
+    CrosstacheError::from_http_status(
+        status.as_u16(),
+        retry_after,
+        resource,
+        format!("{context}: HTTP {status} - {}", azure_error_detail(body)),
+    )
+}
+
+/// Whether an Azure error body is Key Vault's `ObjectIsDeletedButRecoverable`
+/// conflict: the name belongs to a soft-deleted item and cannot be reused
+/// until that item is recovered or purged.
+pub fn is_deleted_but_recoverable(body: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|json| {
+            json.get("error")?
+                .get("innererror")?
+                .get("code")?
+                .as_str()
+                .map(|code| code == "ObjectIsDeletedButRecoverable")
+        })
+        .unwrap_or(false)
+}
+
+/// Read a `Retry-After` header given in delta-seconds. Azure Key Vault and
+/// ARM never send the HTTP-date form, so it is ignored.
+pub fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Prefer the `error.message` of an Azure JSON error envelope; fall back to
+/// the raw body for anything else.
+pub fn azure_error_detail(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|json| {
+            json.get("error")?
+                .get("message")?
+                .as_str()
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// A 403 from Key Vault's network layer rather than from RBAC.
+///
+/// With private link, the vault name can still resolve to a public IP, so the
+/// request reaches the vault and is refused with a 403 that reads like a
+/// permissions problem. Role assignments won't fix these; network access will.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FirewallDenial {
+    /// `ForbiddenByConnection`: public network access is disabled, so only
+    /// private-endpoint traffic is accepted.
+    PublicAccessDisabled { vault: Option<String> },
+    /// `ForbiddenByFirewall`: the caller's address isn't in the vault's
+    /// network allow list.
+    AddressNotAllowed {
+        vault: Option<String>,
+        client_address: Option<String>,
+    },
+}
+
+impl FirewallDenial {
+    /// User-facing explanation with the next step to take.
+    pub fn explain(&self) -> String {
+        match self {
+            Self::PublicAccessDisabled { vault } => format!(
+                "the vault{} has public network access disabled and only accepts traffic \
+                 through its private endpoint. Connect over VPN or from a network peered \
+                 with the vault's private endpoint, or ask a vault admin to allow public access \
+                 from selected networks.",
+                vault_label(vault)
+            ),
+            Self::AddressNotAllowed {
+                vault,
+                client_address,
+            } => {
+                let vault_arg = vault.as_deref().unwrap_or("<vault>");
+                let address = client_address.as_deref().unwrap_or("<your-ip>");
+                format!(
+                    "the vault{} firewall does not allow client address {address}. \
+                     Connect over VPN, or ask a vault admin to allow this address: \
+                     `az keyvault network-rule add --name {vault_arg} --ip-address {address}`",
+                    vault_label(vault)
+                )
+            }
+        }
+    }
+}
+
+fn vault_label(vault: &Option<String>) -> String {
+    vault
+        .as_deref()
+        .map(|name| format!(" '{name}'"))
+        .unwrap_or_default()
+}
+
+/// Recognize Key Vault's firewall and private-link 403 payloads.
+///
+/// Matches the `innererror.code` when present and falls back to the
+/// message text, which older API versions send without an inner error.
+pub fn detect_firewall_denial(body: &str) -> Option<FirewallDenial> {
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    let error = json.get("error")?;
+    let inner_code = error
+        .get("innererror")
+        .and_then(|inner| inner.get("code"))
+        .and_then(|code| code.as_str())
+        .unwrap_or("");
+    let message = error.get("message").and_then(|m| m.as_str()).unwrap_or("");
+    let message_lower = message.to_lowercase();
+
+    // Key Vault reports context as "Label: value" lines in the message, e.g.
+    // "Client address: 203.0.113.7" and "Vault: my-vault;location=eastus".
+    let field = |label: &str| {
+        message.lines().find_map(|line| {
+            line.trim()
+                .strip_prefix(label)
+                .map(|value| value.split(';').next().unwrap_or("").trim().to_string())
+                .filter(|value| !value.is_empty())
+        })
+    };
+    let vault = field("Vault:");
+
+    if inner_code == "ForbiddenByConnection"
+        || message_lower.contains("public network access is disabled")
+    {
+        Some(FirewallDenial::PublicAccessDisabled { vault })
+    } else if inner_code == "ForbiddenByFirewall"
+        || message_lower.contains("client address is not authorized")
+    {
+        Some(FirewallDenial::AddressNotAllowed {
+            vault,
+            client_address: field("Client address:"),
+        })
+    } else {
+        None
+    }
+}
+
 /// Check if a network error is retryable
 pub fn is_retryable_error(error: &CrosstacheError) -> bool {
     match error {
         CrosstacheError::ConnectionTimeout(_) => true,
+        CrosstacheError::RateLimited { .. } => true,
         CrosstacheError::NetworkError(msg) => {
             // Retry on temporary network issues
             let msg_lower = msg.to_lowercase();
@@ -192,10 +473,119 @@ pub fn is_retryable_error(error: &CrosstacheError) -> bool {
     }
 }
 
+/// True when `error` means the backend could not be reached at all (DNS,
+/// timeout, refused, TLS or a transport failure), as opposed to the backend
+/// answering with an error. Used to decide when `xv get --offline` may fall
+/// back to the break-glass cache.
+pub fn is_unreachable_error(error: &CrosstacheError) -> bool {
+    match error {
+        CrosstacheError::NetworkError(_)
+        | CrosstacheError::DnsResolutionError { .. }
+        | CrosstacheError::ConnectionTimeout(_)
+        | CrosstacheError::ConnectionRefused(_)
+        | CrosstacheError::SslError(_) => true,
+        CrosstacheError::HttpError(e) => e.is_connect() || e.is_timeout(),
+        _ => false,
+    }
+}
+
+/// Endpoints the offline check tries. Reaching either one proves the
+/// machine is online, whichever cloud it normally talks to.
+const OFFLINE_PROBE_URLS: [(&str, &str); 2] = [
+    ("Microsoft Entra ID", "https://login.microsoftonline.com/"),
+    ("AWS STS", "https://sts.amazonaws.com/"),
+];
+
+/// Connect and response budget for each offline-check probe.
+const OFFLINE_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Outcome of the once-per-process offline check: `Some(reason)` offline.
+static OFFLINE_STATE: std::sync::OnceLock<Option<String>> = std::sync::OnceLock::new();
+
+/// Why the machine looks offline, or `None` when it can reach the internet.
+///
+/// Meant for after an [`is_unreachable_error`], to tell "no network" apart
+/// from "this one host is down". The probes run in parallel with a short
+/// timeout (through any configured proxy) and only once per process; later
+/// calls return the first answer.
+pub async fn offline_reason() -> Option<String> {
+    if let Some(state) = OFFLINE_STATE.get() {
+        return state.clone();
+    }
+    let reason = match create_http_client(&NetworkConfig {
+        connect_timeout: OFFLINE_PROBE_TIMEOUT,
+        request_timeout: OFFLINE_PROBE_TIMEOUT,
+        ..NetworkConfig::default()
+    }) {
+        Ok(client) => summarize_offline_probes(
+            &futures::future::join_all(
+                OFFLINE_PROBE_URLS
+                    .iter()
+                    .map(|(name, url)| probe_endpoint(&client, name, url)),
+            )
+            .await,
+        ),
+        Err(_) => None,
+    };
+    OFFLINE_STATE.get_or_init(|| reason).clone()
+}
+
+/// Whether an earlier [`offline_reason`] call found the machine offline.
+pub fn known_offline() -> bool {
+    OFFLINE_STATE.get().is_some_and(Option::is_some)
+}
+
+/// `None` when any probe got through; otherwise every probe's failure.
+fn summarize_offline_probes(probes: &[EndpointProbe]) -> Option<String> {
+    let mut failures = Vec::with_capacity(probes.len());
+    for probe in probes {
+        match &probe.outcome {
+            Ok(_) => return None,
+            Err(reason) => failures.push(format!("{} {reason}", probe.name)),
+        }
+    }
+    (!failures.is_empty()).then(|| failures.join("; "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn offline_only_when_every_probe_fails() {
+        let probe = |name: &str, outcome: std::result::Result<Duration, String>| EndpointProbe {
+            name: name.to_string(),
+            url: String::new(),
+            outcome,
+        };
+        assert_eq!(
+            summarize_offline_probes(&[
+                probe("Microsoft Entra ID", Err("DNS lookup failed".to_string())),
+                probe("AWS STS", Err("timed out".to_string())),
+            ]),
+            Some("Microsoft Entra ID DNS lookup failed; AWS STS timed out".to_string())
+        );
+        assert_eq!(
+            summarize_offline_probes(&[
+                probe("Microsoft Entra ID", Err("DNS lookup failed".to_string())),
+                probe("AWS STS", Ok(Duration::from_millis(40))),
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn unreachable_errors_are_transport_failures_only() {
+        assert!(is_unreachable_error(&CrosstacheError::network("reset")));
+        assert!(is_unreachable_error(&CrosstacheError::connection_timeout(
+            "no answer"
+        )));
+        assert!(!is_unreachable_error(&CrosstacheError::authentication(
+            "bad token"
+        )));
+        assert!(!is_unreachable_error(&CrosstacheError::invalid_url("x")));
+    }
+
     #[test]
     fn test_extract_vault_name_from_url() {
         let url = "https://test-vault.vault.azure.net/secrets/test-secret";
@@ -209,5 +599,118 @@ mod tests {
 
         let dns_error = CrosstacheError::dns_resolution("vault", "DNS failed");
         assert!(!is_retryable_error(&dns_error));
+
+        let throttled = CrosstacheError::from_http_status(429, Some(5), "kv", "busy".into());
+        assert!(is_retryable_error(&throttled));
+
+        let forbidden = CrosstacheError::from_http_status(403, None, "kv", "denied".into());
+        assert!(!is_retryable_error(&forbidden));
+    }
+
+    #[test]
+    fn test_proxy_for_url_honors_https_all_and_no_proxy() {
+        let vars = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let url = "https://kv-prod.vault.azure.net/secrets";
+
+        assert_eq!(proxy_for_url(url, vars(&[])), None);
+        assert_eq!(
+            proxy_for_url(
+                url,
+                vars(&[("HTTPS_PROXY", "http://user:pw@proxy.corp:8080")])
+            ),
+            Some("http://proxy.corp:8080".to_string())
+        );
+        assert_eq!(
+            proxy_for_url(url, vars(&[("all_proxy", "http://proxy.corp:3128")])),
+            Some("http://proxy.corp:3128".to_string())
+        );
+        assert_eq!(
+            proxy_for_url(
+                url,
+                vars(&[
+                    ("HTTPS_PROXY", "http://proxy.corp:8080"),
+                    ("NO_PROXY", "localhost, .vault.azure.net"),
+                ])
+            ),
+            None
+        );
+        assert_eq!(
+            proxy_for_url(
+                url,
+                vars(&[
+                    ("HTTPS_PROXY", "http://proxy.corp:8080"),
+                    ("NO_PROXY", "azure.com"),
+                ])
+            ),
+            Some("http://proxy.corp:8080".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(reqwest::header::RETRY_AFTER, "12".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), Some(12));
+
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_is_deleted_but_recoverable() {
+        let conflict = r#"{"error":{"code":"Conflict","message":"Secret db is currently in a deleted but recoverable state, and its name cannot be reused; in this state, the secret can only be recovered or purged.","innererror":{"code":"ObjectIsDeletedButRecoverable"}}}"#;
+        assert!(is_deleted_but_recoverable(conflict));
+        assert!(!is_deleted_but_recoverable(
+            r#"{"error":{"code":"Conflict","message":"busy"}}"#
+        ));
+        assert!(!is_deleted_but_recoverable("not json"));
+    }
+
+    #[test]
+    fn test_detect_firewall_denial() {
+        let ip_denied = r#"{"error":{"code":"Forbidden","message":"Client address is not authorized and caller is not a trusted service.\r\nClient address: 203.0.113.7\r\nCaller: appid=abc\r\nVault: kv-prod;location=eastus","innererror":{"code":"ForbiddenByFirewall"}}}"#;
+        let denial = detect_firewall_denial(ip_denied).unwrap();
+        assert_eq!(
+            denial,
+            FirewallDenial::AddressNotAllowed {
+                vault: Some("kv-prod".to_string()),
+                client_address: Some("203.0.113.7".to_string()),
+            }
+        );
+        assert!(denial
+            .explain()
+            .contains("az keyvault network-rule add --name kv-prod --ip-address 203.0.113.7"));
+
+        let private_only = r#"{"error":{"code":"Forbidden","message":"Public network access is disabled and request is not from a trusted service nor via an approved private link.\r\nCaller: appid=abc\r\nVault: kv-prod;location=eastus","innererror":{"code":"ForbiddenByConnection"}}}"#;
+        assert_eq!(
+            detect_firewall_denial(private_only),
+            Some(FirewallDenial::PublicAccessDisabled {
+                vault: Some("kv-prod".to_string())
+            })
+        );
+
+        // An RBAC denial is a real permissions problem, not a network one.
+        let rbac = r#"{"error":{"code":"Forbidden","message":"Caller is not authorized to perform action on resource.","innererror":{"code":"ForbiddenByRbac"}}}"#;
+        assert_eq!(detect_firewall_denial(rbac), None);
+        assert_eq!(detect_firewall_denial("not json"), None);
+    }
+
+    #[test]
+    fn test_azure_error_detail_prefers_envelope_message() {
+        let body = r#"{"error":{"code":"Forbidden","message":"Caller is not authorized"}}"#;
+        assert_eq!(azure_error_detail(body), "Caller is not authorized");
+        assert_eq!(azure_error_detail("plain text"), "plain text");
     }
 }
@@ -86,12 +86,39 @@ pub fn format_line(level: Level, msg: &str, rich: bool) -> String {
     }
 }
 
+/// Color an expiry indicator by urgency (red once expired, yellow inside the
+/// warning window, green otherwise). Plain text when `rich` is false.
+pub fn paint_expiry(
+    label: &str,
+    urgency: crate::utils::datetime::ExpiryUrgency,
+    rich: bool,
+) -> String {
+    use crate::utils::datetime::ExpiryUrgency;
+    if !rich {
+        return label.to_string();
+    }
+    let color = match urgency {
+        ExpiryUrgency::Expired => CrosstermColor::Red,
+        ExpiryUrgency::Soon => CrosstermColor::Yellow,
+        ExpiryUrgency::Later => CrosstermColor::Green,
+    };
+    label.with(color).to_string()
+}
+
 // NOTE: All decorative log helpers below write to STDERR, never stdout.
 // stdout is reserved for machine-consumable data (secret values, JSON/YAML/
 // CSV/table payloads) so that `xv get X > file` or `xv ... | jq` is never
 // polluted by `[ok]`/`::`/`[info]` chrome. Only `error` previously went to
 // stderr; `success`/`warn`/`info`/`hint`/`step` were moved here to match.
 
+/// Print a message at `level` to stderr
+pub fn print(level: Level, msg: &str) {
+    eprintln!(
+        "{}",
+        format_line(level, msg, should_use_rich(is_tty_stderr()))
+    );
+}
+
 /// Print a success message to stderr
 pub fn success(msg: &str) {
     eprintln!(
@@ -144,6 +171,18 @@ pub fn step(msg: &str) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_paint_expiry_plain_and_rich() {
+        use crate::utils::datetime::ExpiryUrgency;
+        assert_eq!(
+            paint_expiry("expires in 3 days", ExpiryUrgency::Soon, false),
+            "expires in 3 days"
+        );
+        let rich = paint_expiry("expired today", ExpiryUrgency::Expired, true);
+        assert!(rich.contains("expired today"));
+        assert!(rich.contains('\x1b'));
+    }
+
     #[test]
     fn test_format_success_no_tty() {
         let msg = format_line(Level::Success, "done", false);
@@ -1,12 +1,68 @@
 //! Interactive TTY pager for long human-facing CLI output.
+//!
+//! Output that fits on the screen is printed directly. Longer output goes
+//! through the configured pager command (`XV_PAGER`, the `pager` config
+//! key, then `PAGER`), falling back to a built-in `--More--` pager.
 
 use crate::error::Result;
 use crossterm::event::{read, Event, KeyCode, KeyEventKind};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode, size};
 use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
 
 const MORE_PROMPT: &str = "--More-- (space=next, q=quit)";
 
+/// Pager command from `XV_PAGER` or the `pager` config key, set once per
+/// run by `Cli::execute`.
+static PAGER_COMMAND: OnceLock<Option<String>> = OnceLock::new();
+
+/// Record the configured pager command (`None` = use `PAGER`).
+pub fn set_command(command: Option<String>) {
+    let _ = PAGER_COMMAND.set(command);
+}
+
+/// The external pager to run, or `None` for the built-in one. An empty
+/// command or `cat` means "do not page", like git.
+fn external_command() -> Option<String> {
+    PAGER_COMMAND
+        .get()
+        .cloned()
+        .flatten()
+        .or_else(|| std::env::var("PAGER").ok())
+        .map(|command| command.trim().to_string())
+}
+
+/// Pipe `text` into `command` run by the shell. `LESS=FRX` (unless already
+/// set) keeps colors and leaves the text on screen on exit, as git does.
+fn page_with_command(command: &str, text: &str) -> io::Result<()> {
+    #[cfg(windows)]
+    let mut shell = {
+        let mut c = Command::new("cmd");
+        c.arg("/C").arg(command);
+        c
+    };
+    #[cfg(not(windows))]
+    let mut shell = {
+        let mut c = Command::new("sh");
+        c.arg("-c").arg(command);
+        c
+    };
+    if std::env::var_os("LESS").is_none() {
+        shell.env("LESS", "FRX");
+    }
+    let mut child = shell.stdin(Stdio::piped()).spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user quitting the pager early closes the pipe; not an error.
+        match writeln!(stdin, "{text}") {
+            Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+            _ => {}
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
 struct RawModeGuard;
 
 impl RawModeGuard {
@@ -77,13 +133,33 @@ fn page_text(text: &str) -> Result<()> {
     Ok(())
 }
 
-/// Print text directly, or page it interactively when `pager` is enabled.
+/// True when `text` has more lines than fit on a screen `height` rows tall
+/// (one row is left for the prompt).
+fn exceeds_screen(text: &str, height: usize) -> bool {
+    text.lines().count() > height.saturating_sub(1)
+}
+
+/// Print text directly, or page it when `pager` is enabled, stdout and
+/// stdin are terminals, and the text is taller than the screen.
 pub fn print_output(text: &str, pager: bool) -> Result<()> {
-    if pager && can_page() {
-        page_text(text)
-    } else {
+    let height = size().map(|(_, h)| h as usize).unwrap_or(24);
+    if !(pager && can_page() && exceeds_screen(text, height)) {
         println!("{text}");
-        Ok(())
+        return Ok(());
+    }
+    match external_command() {
+        Some(command) if command.is_empty() || command == "cat" => {
+            println!("{text}");
+            Ok(())
+        }
+        Some(command) => match page_with_command(&command, text) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                tracing::debug!("pager '{command}' failed ({e}); using the built-in pager");
+                page_text(text)
+            }
+        },
+        None => page_text(text),
     }
 }
 
@@ -98,6 +174,13 @@ mod tests {
         let _ = can_page();
     }
 
+    #[test]
+    fn only_output_taller_than_the_screen_pages() {
+        assert!(!exceeds_screen("a\nb\nc", 4));
+        assert!(exceeds_screen("a\nb\nc\nd", 4));
+        assert!(!exceeds_screen("", 1));
+    }
+
     #[test]
     fn direct_print_helper_is_callable() {
         let _ = print_output("hello\nworld", false);
@@ -3,7 +3,9 @@
 //! Provides a trait-based abstraction over `indicatif` so the blob manager
 //! stays UI-free while the CLI layer controls progress rendering.
 
+use crate::utils::output::{self, Level};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 // ---------------------------------------------------------------------------
 // Private style helpers
@@ -224,6 +226,77 @@ impl MultiProgressContext {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ItemProgress
+// ---------------------------------------------------------------------------
+
+/// Item-count progress for bulk secret operations (`vault import`,
+/// `vault export`): completed/total, throughput and failures on one line.
+/// Without a TTY the bar is hidden and per-item lines are printed instead, so
+/// CI logs keep a record of every secret.
+pub struct ItemProgress {
+    bar: ProgressBar,
+    failed: AtomicU64,
+}
+
+impl ItemProgress {
+    pub fn new(total: u64, is_tty: bool) -> Self {
+        let bar = if is_tty {
+            let bar = ProgressBar::new(total);
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template(
+                        "{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} secrets ({per_sec}, {eta})  {msg}",
+                    )
+                    .expect("valid template")
+                    .progress_chars("#>-"),
+            );
+            bar.enable_steady_tick(std::time::Duration::from_millis(100));
+            bar
+        } else {
+            ProgressBar::hidden()
+        };
+        Self {
+            bar,
+            failed: AtomicU64::new(0),
+        }
+    }
+
+    /// Print a line that matters even with the bar showing (a failure or a
+    /// refused item), above the bar.
+    pub fn notice(&self, level: Level, msg: &str) {
+        let line = output::format_line(level, msg, output::should_use_rich_stderr());
+        if self.bar.is_hidden() {
+            eprintln!("{line}");
+        } else {
+            self.bar.println(line);
+        }
+    }
+
+    /// Print a routine per-item line; the bar replaces these on a TTY.
+    pub fn item(&self, level: Level, msg: &str) {
+        if self.bar.is_hidden() {
+            output::print(level, msg);
+        }
+    }
+
+    /// Count one finished item.
+    pub fn inc(&self) {
+        self.bar.inc(1);
+    }
+
+    /// Count one failed item.
+    pub fn inc_failed(&self) {
+        let failed = self.failed.fetch_add(1, Ordering::Relaxed) + 1;
+        self.bar.set_message(format!("{failed} failed"));
+        self.bar.inc(1);
+    }
+
+    pub fn finish(&self) {
+        self.bar.finish_and_clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,4 +346,14 @@ mod tests {
         ctx.log("done: test.txt");
         ctx.finish();
     }
+
+    #[test]
+    fn item_progress_hidden_when_not_tty() {
+        let progress = ItemProgress::new(3, false);
+        progress.inc();
+        progress.inc_failed();
+        progress.notice(Level::Warn, "skipped");
+        progress.finish();
+        assert_eq!(progress.failed.load(Ordering::Relaxed), 1);
+    }
 }
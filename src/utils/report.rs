@@ -0,0 +1,290 @@
+//! Per-run operation transcripts
+//!
+//! Bulk commands (`xv vault import`, `xv vault export`, `xv migrate`) accept
+//! `--report <path>` and write a JSON transcript with one entry per secret:
+//! its outcome, how long it took, and the error if it failed. The file is
+//! meant to be kept as a CI artifact, so it is written even when the run
+//! fails part-way. Secret values never appear in it.
+
+use crate::error::{CrosstacheError, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Outcome of a single item in a transcript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    Succeeded,
+    Skipped,
+    Failed,
+}
+
+/// One processed secret.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportItem {
+    pub name: String,
+    pub status: ItemStatus,
+    pub duration_ms: u64,
+    /// Why the item was skipped or failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Item counts by status.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReportSummary {
+    pub succeeded: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+/// Items that ended the same way (same status and detail), for the summary
+/// printed at the end of a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemGroup {
+    pub status: ItemStatus,
+    pub detail: Option<String>,
+    pub names: Vec<String>,
+}
+
+/// Serialized transcript of one run.
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationReport {
+    pub operation: String,
+    pub source: Option<String>,
+    pub target: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    /// `false` when the run aborted or any item failed.
+    pub success: bool,
+    /// Run-level error that stopped the operation, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub summary: ReportSummary,
+    pub items: Vec<ReportItem>,
+}
+
+/// Collects items while a bulk operation runs.
+#[derive(Debug)]
+pub struct ReportRecorder {
+    operation: String,
+    source: Option<String>,
+    target: Option<String>,
+    started_at: DateTime<Utc>,
+    started: Instant,
+    items: Vec<ReportItem>,
+}
+
+impl ReportRecorder {
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            source: None,
+            target: None,
+            started_at: Utc::now(),
+            started: Instant::now(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Describe where items were read from and written to (a vault, a file,
+    /// or `backend:vault`).
+    pub fn with_endpoints(mut self, source: Option<String>, target: Option<String>) -> Self {
+        self.source = source;
+        self.target = target;
+        self
+    }
+
+    pub fn record(
+        &mut self,
+        name: impl Into<String>,
+        status: ItemStatus,
+        elapsed: Duration,
+        detail: Option<String>,
+    ) {
+        self.items.push(ReportItem {
+            name: name.into(),
+            status,
+            duration_ms: elapsed.as_millis() as u64,
+            detail,
+        });
+    }
+
+    /// Time since the recorder was created.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Recorded items grouped by status and detail: succeeded, then skipped,
+    /// then failed, with the largest group first within each status.
+    pub fn groups(&self) -> Vec<ItemGroup> {
+        let rank = |status: ItemStatus| match status {
+            ItemStatus::Succeeded => 0,
+            ItemStatus::Skipped => 1,
+            ItemStatus::Failed => 2,
+        };
+        let mut groups: Vec<ItemGroup> = Vec::new();
+        for item in &self.items {
+            match groups
+                .iter_mut()
+                .find(|g| g.status == item.status && g.detail == item.detail)
+            {
+                Some(group) => group.names.push(item.name.clone()),
+                None => groups.push(ItemGroup {
+                    status: item.status,
+                    detail: item.detail.clone(),
+                    names: vec![item.name.clone()],
+                }),
+            }
+        }
+        groups.sort_by_key(|g| (rank(g.status), std::cmp::Reverse(g.names.len())));
+        groups
+    }
+
+    /// Freeze the transcript. `error` is the run-level failure, if any.
+    pub fn finish(&self, error: Option<&CrosstacheError>) -> OperationReport {
+        let mut summary = ReportSummary::default();
+        for item in &self.items {
+            match item.status {
+                ItemStatus::Succeeded => summary.succeeded += 1,
+                ItemStatus::Skipped => summary.skipped += 1,
+                ItemStatus::Failed => summary.failed += 1,
+            }
+        }
+        OperationReport {
+            operation: self.operation.clone(),
+            source: self.source.clone(),
+            target: self.target.clone(),
+            started_at: self.started_at,
+            finished_at: Utc::now(),
+            duration_ms: self.started.elapsed().as_millis() as u64,
+            success: error.is_none() && summary.failed == 0,
+            error: error.map(|e| e.to_string()),
+            summary,
+            items: self.items.clone(),
+        }
+    }
+
+    /// Write the transcript for a run that ended with `outcome` to `path`.
+    /// The run's own error takes precedence over a failure to write the
+    /// report, which is then only reported as a warning.
+    pub fn write_for<T>(&self, path: &Path, outcome: Result<T>) -> Result<T> {
+        let report = self.finish(outcome.as_ref().err());
+        let written = serde_json::to_string_pretty(&report)
+            .map_err(|e| CrosstacheError::serialization(format!("Failed to serialize report: {e}")))
+            .and_then(|json| {
+                std::fs::write(path, json + "\n").map_err(|e| {
+                    CrosstacheError::unknown(format!(
+                        "Failed to write report to '{}': {e}",
+                        path.display()
+                    ))
+                })
+            });
+        match (outcome, written) {
+            (Ok(value), Ok(())) => Ok(value),
+            (Ok(_), Err(e)) => Err(e),
+            (Err(e), Ok(())) => Err(e),
+            (Err(e), Err(write_err)) => {
+                crate::utils::output::warn(&write_err.to_string());
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finish_counts_items_and_flags_failures() {
+        let mut recorder = ReportRecorder::new("vault import")
+            .with_endpoints(Some("secrets.json".into()), Some("kv-app".into()));
+        recorder.record("a", ItemStatus::Succeeded, Duration::from_millis(12), None);
+        recorder.record(
+            "b",
+            ItemStatus::Skipped,
+            Duration::ZERO,
+            Some("already exists".into()),
+        );
+        recorder.record(
+            "c",
+            ItemStatus::Failed,
+            Duration::from_millis(3),
+            Some("403 Forbidden".into()),
+        );
+
+        let report = recorder.finish(None);
+        assert_eq!(
+            report.summary,
+            ReportSummary {
+                succeeded: 1,
+                skipped: 1,
+                failed: 1
+            }
+        );
+        assert!(!report.success);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["operation"], "vault import");
+        assert_eq!(json["target"], "kv-app");
+        assert_eq!(json["items"][0]["status"], "succeeded");
+        assert_eq!(json["items"][0]["duration_ms"], 12);
+        assert!(json["items"][0].get("detail").is_none());
+        assert_eq!(json["items"][2]["detail"], "403 Forbidden");
+    }
+
+    #[test]
+    fn write_for_persists_report_and_keeps_run_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.json");
+        let recorder = ReportRecorder::new("migrate");
+
+        let result: Result<()> =
+            recorder.write_for(&path, Err(CrosstacheError::config("target unreachable")));
+        assert!(result.is_err());
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["success"], false);
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .contains("target unreachable"));
+    }
+
+    #[test]
+    fn groups_collect_items_with_the_same_outcome() {
+        let mut recorder = ReportRecorder::new("vault import");
+        let skip = || Some("already exists".to_string());
+        recorder.record("a", ItemStatus::Failed, Duration::ZERO, Some("403".into()));
+        recorder.record("b", ItemStatus::Skipped, Duration::ZERO, skip());
+        recorder.record("c", ItemStatus::Succeeded, Duration::ZERO, None);
+        recorder.record("d", ItemStatus::Skipped, Duration::ZERO, skip());
+        recorder.record(
+            "e",
+            ItemStatus::Skipped,
+            Duration::ZERO,
+            Some("dry run".into()),
+        );
+
+        let groups = recorder.groups();
+        let shape: Vec<(ItemStatus, Option<&str>, usize)> = groups
+            .iter()
+            .map(|g| (g.status, g.detail.as_deref(), g.names.len()))
+            .collect();
+        assert_eq!(
+            shape,
+            [
+                (ItemStatus::Succeeded, None, 1),
+                (ItemStatus::Skipped, Some("already exists"), 2),
+                (ItemStatus::Skipped, Some("dry run"), 1),
+                (ItemStatus::Failed, Some("403"), 1),
+            ]
+        );
+        assert_eq!(groups[1].names, ["b", "d"]);
+    }
+}
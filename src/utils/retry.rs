@@ -4,7 +4,9 @@
 //! exponential backoff for handling transient failures.
 
 use crate::error::{CrosstacheError, Result};
-use crate::utils::network::is_retryable_error;
+use crate::utils::network::{
+    is_retryable_error, is_unreachable_error, known_offline, offline_reason,
+};
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -27,42 +29,244 @@ impl Default for RetryOptions {
     }
 }
 
+impl RetryOptions {
+    /// Per-block/part/range retries for blob transfers: `retries` further
+    /// attempts after the first, with a short backoff so one slow block
+    /// does not stall the rest of the transfer for long.
+    pub fn transfer(retries: usize) -> Self {
+        Self {
+            max_retries: retries,
+            initial_interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(8),
+            multiplier: 2.0,
+        }
+    }
+}
+
+/// Retries stop early once the machine is found to be offline: the first
+/// transport failure triggers [`offline_reason`], and backing off cannot
+/// bring a network back.
 pub async fn retry_with_backoff<T, F, Fut>(mut operation: F, options: RetryOptions) -> Result<T>
 where
     F: FnMut() -> Fut,
     Fut: std::future::Future<Output = Result<T>>,
+{
+    retry_loop(
+        move || {
+            let attempt = operation();
+            async move {
+                let result = attempt.await;
+                if let Err(error) = &result {
+                    if is_unreachable_error(error) {
+                        offline_reason().await;
+                    }
+                }
+                result
+            }
+        },
+        options,
+        |error| is_retryable_error(error) && !known_offline(),
+        CrosstacheError::retry_after_secs,
+    )
+    .await
+}
+
+/// [`retry_with_backoff`] for any error type, with the caller deciding which
+/// errors are worth another attempt. Retry-After hints are not consulted.
+pub async fn retry_with_backoff_if<T, E, F, Fut, P>(
+    operation: F,
+    options: RetryOptions,
+    should_retry: P,
+) -> std::result::Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, E>>,
+    P: Fn(&E) -> bool,
+{
+    retry_loop(operation, options, should_retry, |_| None).await
+}
+
+async fn retry_loop<T, E, F, Fut, P, A>(
+    mut operation: F,
+    options: RetryOptions,
+    should_retry: P,
+    retry_after: A,
+) -> std::result::Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, E>>,
+    P: Fn(&E) -> bool,
+    A: Fn(&E) -> Option<u64>,
 {
     let mut interval = options.initial_interval;
-    let mut last_error = None;
-
-    for attempt in 0..=options.max_retries {
-        if attempt > 0 {
-            sleep(interval).await;
-            interval = std::cmp::min(
-                Duration::from_secs_f64(interval.as_secs_f64() * options.multiplier),
-                options.max_interval,
-            );
-        }
+    let mut attempt = 0;
 
-        match operation().await {
+    loop {
+        let error = match operation().await {
             Ok(result) => return Ok(result),
-            Err(error) => {
-                // Check if the error is retryable before continuing
-                if !is_retryable_error(&error) {
-                    return Err(error);
-                }
+            Err(error) => error,
+        };
+        if attempt == options.max_retries || !should_retry(&error) {
+            return Err(error);
+        }
+        attempt += 1;
 
-                last_error = Some(error);
-                if attempt == options.max_retries {
-                    break;
-                }
-            }
+        // A throttling response's Retry-After wins over the backoff
+        // schedule, capped so a hostile header cannot stall the CLI.
+        let requested = retry_after(&error)
+            .map(|secs| std::cmp::min(Duration::from_secs(secs), options.max_interval));
+        sleep(requested.map_or(interval, |wait| wait.max(interval))).await;
+        interval = std::cmp::min(
+            Duration::from_secs_f64(interval.as_secs_f64() * options.multiplier),
+            options.max_interval,
+        );
+    }
+}
+
+/// Whether a failed block, part or range of a blob transfer may succeed on
+/// another attempt: anything [`is_retryable_error`] accepts, plus dropped
+/// connections and server-side (5xx) failures, which are transient for a
+/// single transfer request even when a management call would not be.
+pub fn is_retryable_transfer_error(error: &CrosstacheError) -> bool {
+    match error {
+        CrosstacheError::NetworkError(_) => true,
+        CrosstacheError::AzureApiError(msg) => {
+            let msg = msg.to_lowercase();
+            ["500", "502", "503", "internal", "unavailable"]
+                .iter()
+                .any(|needle| msg.contains(needle))
         }
+        other => is_retryable_error(other),
     }
+}
 
-    Err(last_error.unwrap_or_else(|| CrosstacheError::unknown("Retry failed with no error")))
+/// A block, part or byte range that still failed after every attempt.
+#[derive(Debug, Clone)]
+pub struct FailedRange {
+    /// `"block 3"`, `"part 7"`, ...
+    pub label: String,
+    /// First byte of the range.
+    pub offset: u64,
+    /// Length of the range in bytes.
+    pub len: u64,
+    pub error: String,
 }
 
-// TODO: Add context-aware cancellation support
-// TODO: Add configurable retry conditions
-// TODO: Add retry policy abstraction
+/// One-line report of the pieces of a transfer that failed, e.g.
+/// `2 of 12 blocks failed after 4 attempts: block 3 (bytes 8388608-12582911): ...`.
+/// At most five pieces are listed.
+pub fn describe_failed_ranges(
+    noun: &str,
+    total: usize,
+    attempts: usize,
+    failures: &[FailedRange],
+) -> String {
+    const LISTED: usize = 5;
+    let listed: Vec<String> = failures
+        .iter()
+        .take(LISTED)
+        .map(|f| {
+            format!(
+                "{} (bytes {}-{}): {}",
+                f.label,
+                f.offset,
+                f.offset + f.len.saturating_sub(1),
+                f.error
+            )
+        })
+        .collect();
+    let mut summary = format!(
+        "{} of {total} {noun} failed after {attempts} attempt{}: {}",
+        failures.len(),
+        if attempts == 1 { "" } else { "s" },
+        listed.join("; ")
+    );
+    if failures.len() > LISTED {
+        summary.push_str(&format!("; and {} more", failures.len() - LISTED));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn fast(retries: usize) -> RetryOptions {
+        RetryOptions {
+            max_retries: retries,
+            initial_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(1),
+            multiplier: 1.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried_until_success() {
+        let calls = AtomicUsize::new(0);
+        let result = retry_with_backoff_if(
+            || async {
+                if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(CrosstacheError::network("connection reset"))
+                } else {
+                    Ok(7)
+                }
+            },
+            fast(3),
+            is_retryable_transfer_error,
+        )
+        .await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_the_configured_attempts() {
+        let calls = AtomicUsize::new(0);
+        let result: Result<()> = retry_with_backoff_if(
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(CrosstacheError::network("connection reset"))
+            },
+            fast(2),
+            is_retryable_transfer_error,
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn permanent_failures_are_not_retried() {
+        let calls = AtomicUsize::new(0);
+        let result: Result<()> = retry_with_backoff_if(
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(CrosstacheError::permission_denied("no data role"))
+            },
+            fast(3),
+            is_retryable_transfer_error,
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn failed_ranges_name_each_piece() {
+        let failures: Vec<FailedRange> = (0..7)
+            .map(|i| FailedRange {
+                label: format!("block {i}"),
+                offset: i * 4,
+                len: 4,
+                error: "timeout".into(),
+            })
+            .collect();
+        let summary = describe_failed_ranges("blocks", 12, 4, &failures);
+        assert!(summary
+            .starts_with("7 of 12 blocks failed after 4 attempts: block 0 (bytes 0-3): timeout;"));
+        assert!(summary.contains("block 4 (bytes 16-19)"));
+        assert!(!summary.contains("block 5 ("));
+        assert!(summary.ends_with("; and 2 more"));
+    }
+}
@@ -30,6 +30,21 @@ pub fn closest_match<'a>(target: &str, candidates: &'a [String]) -> Option<&'a s
     best.map(|(_, name)| name)
 }
 
+/// Every candidate within `MAX_DISTANCE` edits of `target`, closest first
+/// (ties keep `candidates` order). Empty when `target` is empty.
+pub fn close_matches<'a>(target: &str, candidates: &[&'a str]) -> Vec<&'a str> {
+    if target.is_empty() {
+        return Vec::new();
+    }
+    let mut scored: Vec<(usize, &str)> = candidates
+        .iter()
+        .map(|c| (strsim::levenshtein(target, c), *c))
+        .filter(|(d, _)| *d <= MAX_DISTANCE)
+        .collect();
+    scored.sort_by_key(|(d, _)| *d);
+    scored.into_iter().map(|(_, c)| c).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,4 +90,18 @@ mod tests {
         let candidates = vec!["abcde".to_string()];
         assert_eq!(closest_match("axcyf", &candidates), None);
     }
+
+    #[test]
+    fn close_matches_lists_every_near_miss_closest_first() {
+        let candidates = ["storage_account", "storage_container", "debug"];
+        assert_eq!(
+            close_matches("storage_acount", &candidates),
+            vec!["storage_account"]
+        );
+        assert_eq!(
+            close_matches("storage_x", &["storage_a", "storage_xy", "other"]),
+            vec!["storage_a", "storage_xy"]
+        );
+        assert!(close_matches("", &candidates).is_empty());
+    }
 }
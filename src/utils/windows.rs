@@ -0,0 +1,95 @@
+//! Windows path and console support
+//!
+//! The `windows-compat` feature (on by default) enables the Windows-specific
+//! behavior built from these helpers: the config file lives under
+//! `%APPDATA%\xv`, download destinations longer than `MAX_PATH` get the
+//! `\\?\` extended-length prefix, and ANSI escape processing is switched on
+//! for the console at startup. The string transforms themselves are
+//! platform-independent so they are exercised on every CI runner.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Longest path the classic Win32 file APIs accept without the `\\?\` prefix.
+#[cfg_attr(not(all(windows, feature = "windows-compat")), allow(dead_code))]
+pub const MAX_PATH: usize = 260;
+
+/// Config directory from the value of `%APPDATA%`, or `None` when it is
+/// unset or empty.
+#[cfg_attr(not(all(windows, feature = "windows-compat")), allow(dead_code))]
+pub fn appdata_config_dir(appdata: Option<OsString>) -> Option<PathBuf> {
+    appdata
+        .filter(|value| !value.is_empty())
+        .map(|value| PathBuf::from(value).join("xv"))
+}
+
+/// Extended-length form of an absolute Windows path that is too long for
+/// `MAX_PATH`: `C:\...` becomes `\\?\C:\...` and `\\server\share\...`
+/// becomes `\\?\UNC\server\share\...`. Forward slashes are converted, since
+/// the prefix disables path normalization.
+///
+/// Returns `None` when the path is short enough, already prefixed, relative,
+/// or contains `.`/`..` components (which the prefix would no longer
+/// resolve).
+#[cfg_attr(not(all(windows, feature = "windows-compat")), allow(dead_code))]
+pub fn extended_length_path(path: &str) -> Option<String> {
+    if path.len() < MAX_PATH || path.starts_with(r"\\?\") {
+        return None;
+    }
+    let normalized = path.replace('/', "\\");
+    if normalized
+        .split('\\')
+        .any(|component| component == "." || component == "..")
+    {
+        return None;
+    }
+    let bytes = normalized.as_bytes();
+    if let Some(unc) = normalized.strip_prefix(r"\\") {
+        return Some(format!(r"\\?\UNC\{unc}"));
+    }
+    if bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && bytes[2] == b'\\' {
+        return Some(format!(r"\\?\{normalized}"));
+    }
+    None
+}
+
+/// `path` in a form the Windows file APIs accept regardless of length.
+/// Unchanged on other platforms or without the `windows-compat` feature.
+#[cfg_attr(not(windows), allow(dead_code))]
+pub fn long_path_safe(path: &Path) -> PathBuf {
+    #[cfg(all(windows, feature = "windows-compat"))]
+    {
+        if let Some(extended) = path.to_str().and_then(extended_length_path) {
+            return PathBuf::from(extended);
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Quote `value` as a PowerShell single-quoted string literal. Nothing is
+/// expanded inside single quotes; embedded quotes — including the typographic
+/// ones PowerShell also treats as delimiters — are escaped by doubling.
+pub fn quote_powershell(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        if matches!(c, '\'' | '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}') {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Turn on ANSI escape processing for the Windows console so colored output
+/// renders instead of printing raw escape codes. Consoles that cannot
+/// process them fall back to plain output. No-op elsewhere.
+pub fn enable_ansi() {
+    #[cfg(all(windows, feature = "windows-compat"))]
+    {
+        if !crossterm::ansi_support::supports_ansi() {
+            crate::utils::output::disable_color();
+        }
+    }
+}
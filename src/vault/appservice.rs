@@ -0,0 +1,543 @@
+//! App Service Key Vault reference checks
+//!
+//! App Service and Function apps can point a setting at a vault secret with
+//! `@Microsoft.KeyVault(...)`. When the secret is renamed, disabled, or the
+//! app's identity loses access, the platform quietly hands the app the
+//! literal reference string instead. `xv appservice check` reads the app's
+//! settings and identity through ARM and checks every reference against its
+//! vault.
+
+use crate::auth::provider::AzureAuthProvider;
+use crate::backend::azure::arm::ArmClient;
+use crate::error::{CrosstacheError, Result};
+use crate::vault::models::DataActionGrant;
+use crate::vault::operations::enc;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+const WEB_API_VERSION: &str = "2023-01-01";
+
+/// Prefix under which connection strings are reported, matching how .NET
+/// configuration exposes them to the app.
+const CONNECTION_STRING_PREFIX: &str = "ConnectionStrings:";
+
+/// The secret a `@Microsoft.KeyVault(...)` setting points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyVaultReference {
+    pub vault: String,
+    pub secret: String,
+    /// Pinned version; `None` follows the latest.
+    pub version: Option<String>,
+}
+
+/// Parse a setting value. `None` when it is not a Key Vault reference at
+/// all; `Some(Err(reason))` when it looks like one but App Service could not
+/// resolve it either.
+pub fn parse_key_vault_reference(
+    value: &str,
+) -> Option<std::result::Result<KeyVaultReference, String>> {
+    const PREFIX: &str = "@microsoft.keyvault(";
+    let value = value.trim();
+    if !value.to_ascii_lowercase().starts_with(PREFIX) {
+        return None;
+    }
+    let Some(inner) = value[PREFIX.len()..].strip_suffix(')') else {
+        return Some(Err("missing closing ')'".to_string()));
+    };
+
+    let mut vault = None;
+    let mut secret = None;
+    let mut version = None;
+    let mut uri = None;
+    for part in inner.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        let Some((key, val)) = part.split_once('=') else {
+            return Some(Err(format!("expected Key=Value, found '{part}'")));
+        };
+        let val = val.trim().to_string();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "secreturi" => uri = Some(val),
+            "vaultname" => vault = Some(val),
+            "secretname" => secret = Some(val),
+            "secretversion" => version = Some(val),
+            other => return Some(Err(format!("unknown property '{other}'"))),
+        }
+    }
+    if let Some(uri) = uri {
+        return Some(parse_secret_uri(&uri));
+    }
+    Some(match (vault, secret) {
+        (Some(vault), Some(secret)) if !vault.is_empty() && !secret.is_empty() => {
+            Ok(KeyVaultReference {
+                vault,
+                secret,
+                version: version.filter(|v| !v.is_empty()),
+            })
+        }
+        _ => Err("needs SecretUri, or VaultName and SecretName".to_string()),
+    })
+}
+
+/// `https://<vault>.vault.azure.net/secrets/<name>[/<version>]`, in any
+/// Azure cloud.
+fn parse_secret_uri(uri: &str) -> std::result::Result<KeyVaultReference, String> {
+    let parsed = url::Url::parse(uri).map_err(|e| format!("invalid SecretUri '{uri}': {e}"))?;
+    let vault = parsed
+        .host_str()
+        .and_then(|host| host.split('.').next())
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| format!("SecretUri '{uri}' has no vault host"))?;
+    let segments: Vec<&str> = parsed
+        .path_segments()
+        .into_iter()
+        .flatten()
+        .filter(|s| !s.is_empty())
+        .collect();
+    match segments.as_slice() {
+        ["secrets", name] => Ok(KeyVaultReference {
+            vault: vault.to_string(),
+            secret: name.to_string(),
+            version: None,
+        }),
+        ["secrets", name, version] => Ok(KeyVaultReference {
+            vault: vault.to_string(),
+            secret: name.to_string(),
+            version: Some(version.to_string()),
+        }),
+        _ => Err(format!("SecretUri '{uri}' is not a secret URI")),
+    }
+}
+
+/// The identity App Service uses to resolve references.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceIdentity {
+    Principal {
+        principal_id: String,
+        /// `system-assigned`, or the user-assigned identity's name
+        label: String,
+    },
+    /// No usable identity; every reference fails.
+    Missing(String),
+}
+
+/// Read the reference identity off a site resource: the system-assigned
+/// identity unless `keyVaultReferenceIdentity` names a user-assigned one.
+pub fn reference_identity(site: &Value) -> ReferenceIdentity {
+    let configured = site
+        .pointer("/properties/keyVaultReferenceIdentity")
+        .and_then(Value::as_str)
+        .filter(|v| !v.is_empty())
+        .unwrap_or("SystemAssigned");
+    let identity = site.get("identity").unwrap_or(&Value::Null);
+
+    if configured.eq_ignore_ascii_case("SystemAssigned") {
+        let has_system = identity
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|t| t.to_ascii_lowercase().contains("systemassigned"));
+        return match identity.get("principalId").and_then(Value::as_str) {
+            Some(id) if has_system => ReferenceIdentity::Principal {
+                principal_id: id.to_string(),
+                label: "system-assigned".to_string(),
+            },
+            _ => ReferenceIdentity::Missing(
+                "the app has no system-assigned identity; enable one or set \
+                 keyVaultReferenceIdentity to a user-assigned identity"
+                    .to_string(),
+            ),
+        };
+    }
+
+    let name = configured.rsplit('/').next().unwrap_or(configured);
+    identity
+        .get("userAssignedIdentities")
+        .and_then(Value::as_object)
+        .and_then(|assigned| {
+            assigned
+                .iter()
+                .find(|(id, _)| id.eq_ignore_ascii_case(configured))
+        })
+        .and_then(|(_, details)| details.get("principalId").and_then(Value::as_str))
+        .map_or_else(
+            || {
+                ReferenceIdentity::Missing(format!(
+                    "keyVaultReferenceIdentity '{name}' is not assigned to the app"
+                ))
+            },
+            |id| ReferenceIdentity::Principal {
+                principal_id: id.to_string(),
+                label: name.to_string(),
+            },
+        )
+}
+
+/// Whether an access-policy vault lets `principal_id` read secrets.
+pub fn access_policy_grants_get(vault: &Value, principal_id: &str) -> bool {
+    vault
+        .pointer("/properties/accessPolicies")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|policy| {
+            policy
+                .get("objectId")
+                .and_then(Value::as_str)
+                .is_some_and(|id| id.eq_ignore_ascii_case(principal_id))
+        })
+        .flat_map(|policy| {
+            policy
+                .pointer("/permissions/secrets")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default()
+        })
+        .any(|permission| {
+            permission
+                .as_str()
+                .is_some_and(|p| p.eq_ignore_ascii_case("get") || p.eq_ignore_ascii_case("all"))
+        })
+}
+
+/// Outcome of checking one reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReferenceStatus {
+    Ok,
+    /// Could not be fully checked (e.g. the caller may not read the vault);
+    /// not counted as broken.
+    Unverified,
+    Malformed,
+    VaultNotFound,
+    SecretNotFound,
+    SecretDisabled,
+    SecretExpired,
+    NoIdentity,
+    AccessDenied,
+}
+
+impl ReferenceStatus {
+    /// Whether the app will see the literal reference instead of the secret.
+    pub fn is_broken(self) -> bool {
+        !matches!(self, Self::Ok | Self::Unverified)
+    }
+}
+
+impl fmt::Display for ReferenceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ok => "ok",
+            Self::Unverified => "unverified",
+            Self::Malformed => "malformed",
+            Self::VaultNotFound => "vault not found",
+            Self::SecretNotFound => "secret not found",
+            Self::SecretDisabled => "secret disabled",
+            Self::SecretExpired => "secret expired",
+            Self::NoIdentity => "no identity",
+            Self::AccessDenied => "access denied",
+        })
+    }
+}
+
+/// One row of `xv appservice check`.
+#[derive(Debug, Clone, serde::Serialize, tabled::Tabled)]
+pub struct ReferenceCheck {
+    #[tabled(rename = "Setting")]
+    pub setting: String,
+    #[tabled(rename = "Vault")]
+    pub vault: String,
+    #[tabled(rename = "Secret")]
+    pub secret: String,
+    #[tabled(rename = "Status")]
+    pub status: ReferenceStatus,
+    #[tabled(rename = "Detail")]
+    pub detail: String,
+}
+
+/// ARM client for one App Service or Function app (optionally a slot).
+pub struct AppServiceClient {
+    arm: ArmClient,
+    subscription_id: String,
+    site_path: String,
+}
+
+impl AppServiceClient {
+    pub fn new(
+        auth_provider: Arc<dyn AzureAuthProvider>,
+        subscription_id: &str,
+        resource_group: &str,
+        app: &str,
+        slot: Option<&str>,
+    ) -> Result<Self> {
+        let mut site_path = format!(
+            "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Web/sites/{}",
+            enc(subscription_id),
+            enc(resource_group),
+            enc(app)
+        );
+        if let Some(slot) = slot {
+            site_path.push_str(&format!("/slots/{}", enc(slot)));
+        }
+        Ok(Self {
+            arm: ArmClient::new(auth_provider)?,
+            subscription_id: subscription_id.to_string(),
+            site_path,
+        })
+    }
+
+    /// The site resource, for its identity.
+    pub async fn site(&self) -> Result<Value> {
+        let url = self
+            .arm
+            .url(&format!("{}?api-version={WEB_API_VERSION}", self.site_path));
+        self.arm
+            .get(&url, "Failed to read app")
+            .await?
+            .ok_or_else(|| CrosstacheError::azure_api(format!("App not found: {}", self.site_path)))
+    }
+
+    /// App settings and connection strings by name, connection strings
+    /// under `ConnectionStrings:`.
+    pub async fn settings(&self) -> Result<BTreeMap<String, String>> {
+        let mut settings = BTreeMap::new();
+        let url = self.arm.url(&format!(
+            "{}/config/appsettings/list?api-version={WEB_API_VERSION}",
+            self.site_path
+        ));
+        let body: Value = self
+            .arm
+            .post::<(), _>(&url, None, "Failed to read app settings")
+            .await?;
+        for (name, value) in body
+            .get("properties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(value) = value.as_str() {
+                settings.insert(name.clone(), value.to_string());
+            }
+        }
+
+        let url = self.arm.url(&format!(
+            "{}/config/connectionstrings/list?api-version={WEB_API_VERSION}",
+            self.site_path
+        ));
+        let body: Value = self
+            .arm
+            .post::<(), _>(&url, None, "Failed to read connection strings")
+            .await?;
+        for (name, entry) in body
+            .get("properties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(value) = entry.get("value").and_then(Value::as_str) {
+                settings.insert(
+                    format!("{CONNECTION_STRING_PREFIX}{name}"),
+                    value.to_string(),
+                );
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Every vault in the subscription, as full ARM resources.
+    pub async fn vaults(&self) -> Result<Vec<Value>> {
+        let url = self.arm.url(&format!(
+            "/subscriptions/{}/providers/Microsoft.KeyVault/vaults?api-version=2023-07-01",
+            enc(&self.subscription_id)
+        ));
+        self.arm.list(url, "Failed to list vaults").await
+    }
+
+    /// The data actions `principal_id` holds on `scope` through role
+    /// assignments made directly to it (at the scope or inherited from
+    /// above). Assignments to groups it belongs to are not visible here.
+    pub async fn principal_grants(
+        &self,
+        scope: &str,
+        principal_id: &str,
+    ) -> Result<Vec<DataActionGrant>> {
+        let url = self.arm.url(&format!(
+            "{scope}/providers/Microsoft.Authorization/roleAssignments?api-version=2022-04-01&$filter=principalId%20eq%20'{}'",
+            enc(principal_id)
+        ));
+        let assignments: Vec<Value> = self
+            .arm
+            .list(url, "Failed to list role assignments")
+            .await?;
+        let role_definitions: HashSet<String> = assignments
+            .iter()
+            .filter_map(|a| a.pointer("/properties/roleDefinitionId")?.as_str())
+            .map(str::to_string)
+            .collect();
+
+        let mut grants = Vec::new();
+        for definition in role_definitions {
+            let url = self
+                .arm
+                .url(&format!("{definition}?api-version=2022-04-01"));
+            // A deleted custom role grants nothing.
+            if let Some(role) = self
+                .arm
+                .get::<Value>(&url, "Failed to read role definition")
+                .await?
+            {
+                grants.extend(role_grants(&role));
+            }
+        }
+        Ok(grants)
+    }
+}
+
+/// The data-action grants in a role definition resource.
+fn role_grants(role: &Value) -> Vec<DataActionGrant> {
+    let strings = |permission: &Value, field: &str| -> Vec<String> {
+        permission
+            .get(field)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect()
+    };
+    role.pointer("/properties/permissions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|permission| DataActionGrant {
+            data_actions: strings(permission, "dataActions"),
+            not_data_actions: strings(permission, "notDataActions"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::models::VaultPermission;
+    use serde_json::json;
+
+    #[test]
+    fn references_parse_in_both_forms() {
+        assert_eq!(
+            parse_key_vault_reference(
+                "@Microsoft.KeyVault(SecretUri=https://kv-prod.vault.azure.net/secrets/db-password/)"
+            ),
+            Some(Ok(KeyVaultReference {
+                vault: "kv-prod".into(),
+                secret: "db-password".into(),
+                version: None,
+            }))
+        );
+        assert_eq!(
+            parse_key_vault_reference(
+                "@Microsoft.KeyVault(SecretUri=https://kv.vault.usgovcloudapi.net/secrets/api/abc123)"
+            )
+            .unwrap()
+            .unwrap()
+            .version
+            .as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            parse_key_vault_reference("@Microsoft.KeyVault(VaultName=kv; SecretName=api-key)"),
+            Some(Ok(KeyVaultReference {
+                vault: "kv".into(),
+                secret: "api-key".into(),
+                version: None,
+            }))
+        );
+        assert_eq!(parse_key_vault_reference("plain value"), None);
+    }
+
+    #[test]
+    fn malformed_references_are_reported() {
+        for value in [
+            "@Microsoft.KeyVault(VaultName=kv",
+            "@Microsoft.KeyVault(VaultName=kv)",
+            "@Microsoft.KeyVault(SecretUri=https://kv.vault.azure.net/keys/k)",
+            "@Microsoft.KeyVault(Secret=db)",
+        ] {
+            assert!(
+                matches!(parse_key_vault_reference(value), Some(Err(_))),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn reference_identity_follows_key_vault_reference_identity() {
+        let system = json!({
+            "identity": { "type": "SystemAssigned", "principalId": "p-sys" },
+            "properties": {}
+        });
+        assert_eq!(
+            reference_identity(&system),
+            ReferenceIdentity::Principal {
+                principal_id: "p-sys".into(),
+                label: "system-assigned".into(),
+            }
+        );
+
+        let uami = "/subscriptions/s/resourceGroups/rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/app-id";
+        let user = json!({
+            "identity": {
+                "type": "UserAssigned",
+                "userAssignedIdentities": { uami: { "principalId": "p-user" } }
+            },
+            "properties": { "keyVaultReferenceIdentity": uami }
+        });
+        assert_eq!(
+            reference_identity(&user),
+            ReferenceIdentity::Principal {
+                principal_id: "p-user".into(),
+                label: "app-id".into(),
+            }
+        );
+
+        // keyVaultReferenceIdentity defaults to SystemAssigned, which this
+        // app does not have.
+        let user_only = json!({
+            "identity": user["identity"].clone(),
+            "properties": {}
+        });
+        assert!(matches!(
+            reference_identity(&user_only),
+            ReferenceIdentity::Missing(_)
+        ));
+        assert!(matches!(
+            reference_identity(&json!({ "properties": {} })),
+            ReferenceIdentity::Missing(_)
+        ));
+    }
+
+    #[test]
+    fn access_policies_need_secret_get() {
+        let vault = json!({ "properties": { "accessPolicies": [
+            { "objectId": "reader", "permissions": { "secrets": ["Get", "List"] } },
+            { "objectId": "lister", "permissions": { "secrets": ["list"] } },
+        ] } });
+        assert!(access_policy_grants_get(&vault, "reader"));
+        assert!(!access_policy_grants_get(&vault, "lister"));
+        assert!(!access_policy_grants_get(&vault, "stranger"));
+    }
+
+    #[test]
+    fn role_definitions_yield_data_action_grants() {
+        let role = json!({ "properties": { "permissions": [{
+            "actions": [],
+            "dataActions": ["Microsoft.KeyVault/vaults/secrets/getSecret/action"],
+            "notDataActions": []
+        }] } });
+        let grants = role_grants(&role);
+        assert!(grants
+            .iter()
+            .any(|g| g.permits(VaultPermission::Read.azure_data_action())));
+        assert!(ReferenceStatus::AccessDenied.is_broken());
+        assert!(!ReferenceStatus::Unverified.is_broken());
+    }
+}
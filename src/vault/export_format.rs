@@ -0,0 +1,304 @@
+//! The `xv vault export --fmt json` document and its import-side validation
+//!
+//! Exports carry a `format_version`. Documents without one predate
+//! versioning (version 0) and share version 1's shape, so they import
+//! unchanged; versioned documents are checked strictly, and a version newer
+//! than [`EXPORT_FORMAT_VERSION`] is refused rather than half-read. The
+//! schema is documented in `docs/export-format.md`.
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::error::{CrosstacheError, Result};
+
+/// Version written by this build. Bump it (and teach [`parse_export`] the
+/// old shape) whenever the document changes incompatibly.
+pub const EXPORT_FORMAT_VERSION: u64 = 1;
+
+/// `manifest` is added by `xv backup vault`; import ignores it.
+const TOP_LEVEL_FIELDS: &[&str] = &[
+    "format_version",
+    "vault",
+    "exported_at",
+    "manifest",
+    "secrets",
+];
+const SECRET_FIELDS: &[&str] = &[
+    "name",
+    "value",
+    "enabled",
+    "content_type",
+    "tags",
+    "expires_on",
+    "not_before",
+];
+
+/// A validated export document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultExport {
+    /// 0 for documents written before versioning.
+    pub format_version: u64,
+    pub vault: Option<String>,
+    pub exported_at: Option<String>,
+    pub secrets: Vec<ExportedSecret>,
+}
+
+/// One entry of the `secrets` array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedSecret {
+    pub name: String,
+    /// `None` when exported without `--include-values`.
+    pub value: Option<String>,
+    pub enabled: Option<bool>,
+    pub content_type: Option<String>,
+    /// The backend's full tag map, xv's own metadata tags included.
+    pub tags: BTreeMap<String, String>,
+    pub expires_on: Option<DateTime<Utc>>,
+    /// Only written when the export read values.
+    pub not_before: Option<DateTime<Utc>>,
+}
+
+fn invalid(location: &str, problem: impl std::fmt::Display) -> CrosstacheError {
+    CrosstacheError::serialization(format!("Invalid export file: {location}: {problem}"))
+}
+
+/// Reject fields the schema doesn't define; only versioned documents are
+/// held to this, hand-edited legacy files may carry extras.
+fn check_fields(object: &Map<String, Value>, allowed: &[&str], location: &str) -> Result<()> {
+    match object.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) => Err(invalid(
+            location,
+            format!(
+                "unknown field '{key}' (expected one of: {})",
+                allowed.join(", ")
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn optional_string(
+    object: &Map<String, Value>,
+    field: &str,
+    location: &str,
+) -> Result<Option<String>> {
+    match object.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(other) => Err(invalid(
+            location,
+            format!("'{field}' must be a string, found {}", type_name(other)),
+        )),
+    }
+}
+
+fn optional_datetime(
+    object: &Map<String, Value>,
+    field: &str,
+    location: &str,
+) -> Result<Option<DateTime<Utc>>> {
+    optional_string(object, field, location)?
+        .map(|raw| {
+            DateTime::parse_from_rfc3339(&raw)
+                .map(|at| at.with_timezone(&Utc))
+                .map_err(|e| {
+                    invalid(
+                        location,
+                        format!("'{field}' must be an RFC 3339 timestamp ({e})"),
+                    )
+                })
+        })
+        .transpose()
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// Parse and validate an export document.
+pub fn parse_export(text: &str) -> Result<VaultExport> {
+    let document: Value = serde_json::from_str(text)
+        .map_err(|e| CrosstacheError::serialization(format!("Failed to parse JSON: {e}")))?;
+    let Value::Object(top) = document else {
+        return Err(invalid(
+            "document",
+            format!("expected an object, found {}", type_name(&document)),
+        ));
+    };
+
+    let format_version = match top.get("format_version") {
+        None => 0,
+        Some(version) => match version.as_u64() {
+            Some(0) | None => {
+                return Err(invalid(
+                    "format_version",
+                    format!("expected a positive integer, found {version}"),
+                ))
+            }
+            Some(v) if v > EXPORT_FORMAT_VERSION => {
+                return Err(invalid(
+                    "format_version",
+                    format!(
+                        "version {v} is newer than this xv supports ({EXPORT_FORMAT_VERSION}); \
+                         upgrade xv to import it"
+                    ),
+                ))
+            }
+            Some(v) => v,
+        },
+    };
+    let strict = format_version > 0;
+    if strict {
+        check_fields(&top, TOP_LEVEL_FIELDS, "document")?;
+    }
+
+    let entries = match top.get("secrets") {
+        Some(Value::Array(entries)) => entries,
+        Some(other) => {
+            return Err(invalid(
+                "secrets",
+                format!("expected an array, found {}", type_name(other)),
+            ))
+        }
+        None => return Err(invalid("document", "missing 'secrets' array")),
+    };
+
+    let mut secrets = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let Value::Object(object) = entry else {
+            return Err(invalid(
+                &format!("secrets[{i}]"),
+                format!("expected an object, found {}", type_name(entry)),
+            ));
+        };
+        let name = match object.get("name") {
+            Some(Value::String(name)) if !name.is_empty() => name.clone(),
+            Some(other) => {
+                return Err(invalid(
+                    &format!("secrets[{i}]"),
+                    format!("'name' must be a non-empty string, found {other}"),
+                ))
+            }
+            None => return Err(invalid(&format!("secrets[{i}]"), "missing 'name'")),
+        };
+        let location = format!("secrets[{i}] ('{name}')");
+        if strict {
+            check_fields(object, SECRET_FIELDS, &location)?;
+        }
+
+        let enabled = match object.get("enabled") {
+            None | Some(Value::Null) => None,
+            Some(Value::Bool(enabled)) => Some(*enabled),
+            Some(other) => {
+                return Err(invalid(
+                    &location,
+                    format!(
+                        "'enabled' must be true or false, found {}",
+                        type_name(other)
+                    ),
+                ))
+            }
+        };
+        let tags = match object.get("tags") {
+            None | Some(Value::Null) => BTreeMap::new(),
+            Some(Value::Object(tags)) => {
+                let mut parsed = BTreeMap::new();
+                for (key, value) in tags {
+                    let Value::String(value) = value else {
+                        return Err(invalid(
+                            &location,
+                            format!("tag '{key}' must be a string, found {}", type_name(value)),
+                        ));
+                    };
+                    parsed.insert(key.clone(), value.clone());
+                }
+                parsed
+            }
+            Some(other) => {
+                return Err(invalid(
+                    &location,
+                    format!("'tags' must be an object, found {}", type_name(other)),
+                ))
+            }
+        };
+
+        secrets.push(ExportedSecret {
+            value: optional_string(object, "value", &location)?,
+            enabled,
+            content_type: optional_string(object, "content_type", &location)?
+                .filter(|ct| !ct.is_empty()),
+            tags,
+            expires_on: optional_datetime(object, "expires_on", &location)?,
+            not_before: optional_datetime(object, "not_before", &location)?,
+            name,
+        });
+    }
+
+    Ok(VaultExport {
+        format_version,
+        vault: optional_string(&top, "vault", "document")?,
+        exported_at: optional_string(&top, "exported_at", "document")?,
+        secrets,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_and_current_documents_parse() {
+        let legacy = r#"{"vault":"kv","exported_at":"2025-01-01T00:00:00Z","note":"hand edit",
+            "secrets":[{"name":"db","value":"s3cret","enabled":true,"content_type":""}]}"#;
+        let parsed = parse_export(legacy).unwrap();
+        assert_eq!(parsed.format_version, 0);
+        assert_eq!(parsed.secrets[0].value.as_deref(), Some("s3cret"));
+        assert_eq!(parsed.secrets[0].content_type, None);
+
+        let current = r#"{"format_version":1,"vault":"kv","secrets":[
+            {"name":"api","enabled":false,"tags":{"owner":"web"},
+             "expires_on":"2030-01-01T00:00:00+00:00"}]}"#;
+        let parsed = parse_export(current).unwrap();
+        assert_eq!(parsed.format_version, 1);
+        assert_eq!(parsed.secrets[0].value, None);
+        assert_eq!(parsed.secrets[0].enabled, Some(false));
+        assert_eq!(parsed.secrets[0].tags["owner"], "web");
+        assert_eq!(
+            parsed.secrets[0].expires_on.map(|at| at.to_rfc3339()),
+            Some("2030-01-01T00:00:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn errors_name_the_offending_entry_and_field() {
+        let err = |text: &str| parse_export(text).unwrap_err().to_string();
+
+        assert!(err(
+            r#"{"format_version":1,"secrets":[{"name":"a"},{"name":"b","enabled":"yes"}]}"#
+        )
+        .contains("secrets[1] ('b'): 'enabled' must be true or false, found a string"));
+        assert!(
+            err(r#"{"format_version":1,"secrets":[{"name":"a","valeu":"x"}]}"#)
+                .contains("secrets[0] ('a'): unknown field 'valeu'")
+        );
+        assert!(err(r#"{"format_version":1,"secrets":[{"value":"x"}]}"#)
+            .contains("secrets[0]: missing 'name'"));
+        assert!(err(r#"{"format_version":2,"secrets":[]}"#).contains("newer than this xv"));
+        assert!(err(r#"{"format_version":"1","secrets":[]}"#).contains("positive integer"));
+        assert!(err(r#"{"format_version":1}"#).contains("missing 'secrets' array"));
+        assert!(
+            err(r#"{"format_version":1,"secrets":[{"name":"a","expires_on":"soon"}]}"#)
+                .contains("secrets[0] ('a'): 'expires_on' must be an RFC 3339 timestamp")
+        );
+        assert!(err(r#"{"secrets":[{"name":"a","tags":{"n":1}}]}"#)
+            .contains("secrets[0] ('a'): tag 'n' must be a string, found a number"));
+    }
+}
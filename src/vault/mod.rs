@@ -3,8 +3,12 @@
 //! This module provides functionality for managing Azure Key Vaults,
 //! including creation, deletion, access control, and metadata management.
 
+pub mod appservice;
+pub mod export_format;
 pub mod manager;
 pub mod models;
 pub mod operations;
+pub mod sync;
+pub mod template;
 
 pub use models::*;
@@ -54,6 +54,50 @@ pub struct VaultProperties {
     #[tabled(skip)]
     #[serde(default)]
     pub enable_rbac_authorization: Option<bool>,
+    /// `publicNetworkAccess` as reported by ARM (`Enabled` / `Disabled`)
+    #[tabled(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_network_access: Option<String>,
+    /// Firewall configuration, when the vault reports one
+    #[tabled(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_acls: Option<NetworkAclSummary>,
+}
+
+/// Summary of a vault's `networkAcls` firewall configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkAclSummary {
+    /// `Allow` or `Deny` for traffic matching no rule
+    pub default_action: String,
+    /// Trusted-service bypass (`AzureServices` or `None`)
+    pub bypass: String,
+    pub ip_rules: usize,
+    pub virtual_network_rules: usize,
+}
+
+impl NetworkAclSummary {
+    /// One-line description for the vault info view.
+    pub fn describe(&self) -> String {
+        format!(
+            "default {}, {} IP rule(s), {} VNet rule(s), bypass {}",
+            self.default_action, self.ip_rules, self.virtual_network_rules, self.bypass
+        )
+    }
+}
+
+/// `xv vault info` view: the vault's properties plus counts that take extra
+/// calls to gather. A count that could not be read is omitted.
+#[derive(Debug, Clone, Serialize)]
+pub struct VaultInfo {
+    #[serde(flatten)]
+    pub vault: VaultProperties,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_count: Option<usize>,
+    /// Role assignments for RBAC vaults, access policies otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostics_enabled: Option<bool>,
 }
 
 /// Access policy for a Key Vault
@@ -173,6 +217,10 @@ pub struct VaultRole {
     pub principal_name: String,
     #[tabled(rename = "Email")]
     pub email: String,
+    /// Number of (transitive) members when the principal is a group.
+    #[tabled(rename = "Members", display_with = "display_or_blank")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_count: Option<usize>,
     #[tabled(skip)]
     pub principal_type: String,
     #[tabled(skip)]
@@ -240,10 +288,187 @@ pub struct VaultSummary {
     pub location: String,
     #[tabled(rename = "Resource Group")]
     pub resource_group: String,
+    /// Owning subscription's display name, filled in only by
+    /// `xv vault list --all-subscriptions`.
+    #[tabled(rename = "Subscription", display_with = "display_or_blank")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<String>,
     #[tabled(rename = "Status")]
     pub status: String,
     #[tabled(rename = "Created")]
     pub created_at: String,
+    /// Number of secrets, filled in only by `xv vault list --with-counts`.
+    #[tabled(rename = "Secrets", display_with = "display_or_blank")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_count: Option<usize>,
+}
+
+/// Blank rather than "-" so the table formatter drops an opt-in column
+/// entirely when no row filled it.
+fn display_or_blank<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+/// A soft-deleted vault that still reserves its name until purged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeletedVaultSummary {
+    pub name: String,
+    /// Region the vault was deleted in; restore and purge need it.
+    pub location: String,
+    pub scheduled_purge_date: Option<String>,
+    /// Whether the vault had purge protection, when Azure reports it; a
+    /// protected vault can't be purged before its scheduled date.
+    #[serde(default)]
+    pub purge_protection: Option<bool>,
+}
+
+/// Result of a vault name availability check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum VaultNameStatus {
+    Available,
+    /// In use by a live vault, here or anywhere else in Azure.
+    Taken {
+        message: Option<String>,
+    },
+    /// Reserved by a soft-deleted vault in the current subscription.
+    SoftDeleted(DeletedVaultSummary),
+    /// Not a valid vault name.
+    Invalid {
+        message: Option<String>,
+    },
+}
+
+/// One Azure Policy finding from a vault-creation pre-check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyFinding {
+    /// Policy effect or restriction: `Deny`, `Modify`, `Append`, `Audit`,
+    /// `Required` (the field will be set to a policy-chosen value) or
+    /// `Removed`.
+    pub effect: String,
+    /// Resource field the finding is about, when the policy names one.
+    pub field: Option<String>,
+    /// Display name (or definition id) of the policy responsible.
+    pub policy: Option<String>,
+    /// The value the policy requires or sets, or its stated reason.
+    pub detail: Option<String>,
+}
+
+impl PolicyFinding {
+    /// Whether the policy would reject the create outright.
+    pub fn is_denial(&self) -> bool {
+        self.effect.eq_ignore_ascii_case("deny")
+    }
+
+    /// Whether the policy would change the vault's settings on the way in.
+    pub fn is_modification(&self) -> bool {
+        ["modify", "append", "required", "removed"]
+            .iter()
+            .any(|effect| self.effect.eq_ignore_ascii_case(effect))
+    }
+}
+
+/// A data-plane permission a bulk operation needs on a vault, checked up
+/// front so the operation fails before its first item rather than part-way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultPermission {
+    /// Read secret values (`vault export --include-values`).
+    Read,
+    /// Create and update secrets (`vault import`).
+    Write,
+    /// Delete secrets (`xv delete --group`).
+    Delete,
+}
+
+impl VaultPermission {
+    /// What the permission allows, for error messages.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Read => "read secret values",
+            Self::Write => "write secrets",
+            Self::Delete => "delete secrets",
+        }
+    }
+
+    /// The Key Vault RBAC data action the permission corresponds to.
+    pub fn azure_data_action(self) -> &'static str {
+        match self {
+            Self::Read => "Microsoft.KeyVault/vaults/secrets/getSecret/action",
+            Self::Write => "Microsoft.KeyVault/vaults/secrets/setSecret/action",
+            Self::Delete => "Microsoft.KeyVault/vaults/secrets/delete",
+        }
+    }
+
+    /// The least-privileged built-in Key Vault role that grants it.
+    pub fn azure_role(self) -> &'static str {
+        match self {
+            Self::Read => "Key Vault Secrets User",
+            Self::Write | Self::Delete => "Key Vault Secrets Officer",
+        }
+    }
+}
+
+/// The data actions one role assignment grants the caller on a resource, as
+/// reported by the ARM permissions API. Patterns may end in (or contain) `*`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataActionGrant {
+    pub data_actions: Vec<String>,
+    pub not_data_actions: Vec<String>,
+}
+
+impl DataActionGrant {
+    /// Whether this grant allows `action`: some data action matches it and
+    /// none of the grant's exclusions do.
+    pub fn permits(&self, action: &str) -> bool {
+        self.data_actions
+            .iter()
+            .any(|pattern| action_matches(pattern, action))
+            && !self
+                .not_data_actions
+                .iter()
+                .any(|pattern| action_matches(pattern, action))
+    }
+}
+
+/// Case-insensitive match of an RBAC action pattern, where `*` stands for
+/// any run of characters.
+fn action_matches(pattern: &str, action: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let action = action.to_ascii_lowercase();
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = action.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// A B2B guest invitation, as returned by the directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuestInvitation {
+    /// Object id of the (new or existing) guest user.
+    pub user_id: String,
+    /// Link the guest opens to accept the invitation.
+    pub redeem_url: Option<String>,
+    /// `PendingAcceptance`, or `Completed` for users already in the tenant.
+    pub status: String,
+}
+
+/// An Azure subscription the credential can see.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionSummary {
+    pub id: String,
+    pub display_name: String,
 }
 
 impl VaultProperties {
@@ -253,8 +478,10 @@ impl VaultProperties {
             name: self.name.clone(),
             location: self.location.clone(),
             resource_group: self.resource_group.clone(),
+            subscription: None,
             status: "Active".to_string(),
             created_at: self.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            secret_count: None,
         }
     }
 
@@ -332,6 +559,8 @@ mod tests {
             created_at: chrono::Utc::now(),
             tags: HashMap::new(),
             enable_rbac_authorization: None,
+            public_network_access: None,
+            network_acls: None,
         }
     }
 
@@ -427,6 +656,40 @@ mod tests {
         assert_eq!(summary.resource_group, "rg");
     }
 
+    #[test]
+    fn test_summary_secret_count_is_omitted_until_counted() {
+        let vp = make_vault_properties("myvault", "https://myvault.vault.azure.net/", false, 90);
+        let mut summary = vp.to_summary();
+        let json = serde_json::to_value(&summary).unwrap();
+        assert!(json.get("secret_count").is_none());
+
+        summary.secret_count = Some(12);
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["secret_count"], 12);
+
+        // Cached summaries written before the field existed still load.
+        let legacy = r#"{"name":"v","location":"l","resource_group":"rg","status":"Active","created_at":""}"#;
+        let parsed: VaultSummary = serde_json::from_str(legacy).unwrap();
+        assert_eq!(parsed.secret_count, None);
+    }
+
+    #[test]
+    fn test_vault_info_flattens_properties_and_omits_unread_counts() {
+        let vault = make_vault_properties("myvault", "https://myvault.vault.azure.net/", true, 90);
+        let info = VaultInfo {
+            vault,
+            secret_count: Some(3),
+            access_count: None,
+            diagnostics_enabled: Some(false),
+        };
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["name"], "myvault");
+        assert_eq!(json["secret_count"], 3);
+        assert_eq!(json["diagnostics_enabled"], false);
+        assert!(json.get("access_count").is_none());
+        assert!(json.get("network_acls").is_none());
+    }
+
     // --- VaultCreateRequest::default ---
 
     #[test]
@@ -493,4 +756,41 @@ mod tests {
         assert_ne!(AccessLevel::Reader, AccessLevel::Admin);
         assert_ne!(AccessLevel::Contributor, AccessLevel::Admin);
     }
+
+    // --- DataActionGrant ---
+
+    fn grant(actions: &[&str], not_actions: &[&str]) -> DataActionGrant {
+        DataActionGrant {
+            data_actions: actions.iter().map(|a| a.to_string()).collect(),
+            not_data_actions: not_actions.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn grant_matches_wildcards_case_insensitively() {
+        let officer = grant(&["Microsoft.KeyVault/vaults/secrets/*"], &[]);
+        for permission in [
+            VaultPermission::Read,
+            VaultPermission::Write,
+            VaultPermission::Delete,
+        ] {
+            assert!(officer.permits(permission.azure_data_action()));
+        }
+        let user = grant(&["microsoft.keyvault/vaults/secrets/getsecret/action"], &[]);
+        assert!(user.permits(VaultPermission::Read.azure_data_action()));
+        assert!(!user.permits(VaultPermission::Write.azure_data_action()));
+        assert!(grant(&["*"], &[]).permits("Microsoft.KeyVault/vaults/secrets/delete"));
+        assert!(grant(&["Microsoft.KeyVault/*/delete"], &[])
+            .permits("Microsoft.KeyVault/vaults/secrets/delete"));
+    }
+
+    #[test]
+    fn grant_exclusions_win() {
+        let no_delete = grant(
+            &["Microsoft.KeyVault/vaults/secrets/*"],
+            &["Microsoft.KeyVault/vaults/secrets/delete"],
+        );
+        assert!(no_delete.permits(VaultPermission::Write.azure_data_action()));
+        assert!(!no_delete.permits(VaultPermission::Delete.azure_data_action()));
+    }
 }
@@ -5,12 +5,18 @@
 
 use async_trait::async_trait;
 use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
-use reqwest::{header::HeaderMap, Client};
+use reqwest::header::HeaderMap;
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// `Microsoft.KeyVault` management API version.
+const VAULT_API_VERSION: &str = "2023-07-01";
+
+/// `Microsoft.Authorization` role assignment and definition API version.
+const RBAC_API_VERSION: &str = "2022-04-01";
+
 /// RFC 3986 path-segment encoding: encodes everything except unreserved chars.
 /// `/` and `%` must be encoded to prevent path injection and double-encoding.
 const PATH_SEGMENT: &AsciiSet = &CONTROLS
@@ -26,7 +32,7 @@ const PATH_SEGMENT: &AsciiSet = &CONTROLS
     .add(b'/')
     .add(b'%');
 
-fn enc(s: &str) -> impl std::fmt::Display + '_ {
+pub(crate) fn enc(s: &str) -> impl std::fmt::Display + '_ {
     utf8_percent_encode(s, PATH_SEGMENT)
 }
 
@@ -55,14 +61,15 @@ fn key_vault_role_definition_id(access_level: &AccessLevel) -> &'static str {
 }
 
 use super::models::{
-    AccessLevel, AccessPolicy, VaultCreateRequest, VaultProperties, VaultRole, VaultSummary,
-    VaultUpdateRequest,
+    AccessLevel, AccessPolicy, DataActionGrant, DeletedVaultSummary, GuestInvitation,
+    NetworkAclSummary, PolicyFinding, SubscriptionSummary, VaultCreateRequest, VaultNameStatus,
+    VaultProperties, VaultRole, VaultSummary, VaultUpdateRequest,
 };
 use crate::auth::provider::AzureAuthProvider;
+use crate::backend::azure::arm::ArmClient;
 use crate::backend::azure::types::AzureVaultName;
 use crate::error::{CrosstacheError, Result};
-use crate::utils::network::{classify_network_error, create_http_client, NetworkConfig};
-use crate::utils::retry::retry_with_backoff;
+use crate::utils::network::{azure_error_detail, classify_network_error, parse_retry_after};
 
 /// Trait for vault operations
 #[async_trait]
@@ -73,6 +80,13 @@ pub trait VaultOperations: Send + Sync {
     /// Get vault details
     async fn get_vault(&self, vault_name: &str, resource_group: &str) -> Result<VaultProperties>;
 
+    /// Get the vault's raw ARM resource document
+    async fn get_vault_resource(&self, _vault_name: &str, _resource_group: &str) -> Result<Value> {
+        Err(CrosstacheError::config(
+            "raw vault resources are not supported by this backend",
+        ))
+    }
+
     /// List vaults in subscription
     async fn list_vaults(
         &self,
@@ -162,54 +176,441 @@ pub trait VaultOperations: Send + Sync {
             "principal resolution is not supported by this backend",
         ))
     }
+
+    /// Resolve a group display name (or object id) to the object id of an
+    /// Entra ID security group, for use as the principal of a role
+    /// assignment. Graph-backed on Azure; unsupported by default.
+    async fn resolve_group_to_object_id(&self, _group: &str) -> Result<String> {
+        Err(CrosstacheError::config(
+            "group resolution is not supported by this backend",
+        ))
+    }
+
+    /// Invite `email` into the directory as a B2B guest. Inviting someone
+    /// who is already a guest returns their existing user.
+    async fn invite_guest(
+        &self,
+        _email: &str,
+        _redirect_url: &str,
+        _send_email: bool,
+    ) -> Result<GuestInvitation> {
+        Err(CrosstacheError::config(
+            "guest invitations are not supported by this backend",
+        ))
+    }
+
+    /// Whether a directory object with this id can be read yet. Fresh
+    /// invitations take a while to replicate.
+    async fn principal_exists(&self, _object_id: &str) -> Result<bool> {
+        Err(CrosstacheError::config(
+            "principal lookups are not supported by this backend",
+        ))
+    }
+
+    /// Transitive member counts of the given groups, keyed by object id.
+    /// Groups that can't be counted are absent; the default counts nothing.
+    async fn group_member_counts(&self, _group_ids: &[String]) -> HashMap<String, usize> {
+        HashMap::new()
+    }
+
+    /// List the enabled subscriptions visible to the credential.
+    async fn list_subscriptions(&self) -> Result<Vec<SubscriptionSummary>> {
+        Err(CrosstacheError::config(
+            "subscription listing is not supported by this backend",
+        ))
+    }
+
+    /// Find a soft-deleted vault with this name in the subscription.
+    async fn find_deleted_vault(&self, _vault_name: &str) -> Result<Option<DeletedVaultSummary>> {
+        Ok(None)
+    }
+
+    /// Ask the provider whether a vault name can be created. A soft-deleted
+    /// reservation comes back as taken; see [`Self::find_deleted_vault`].
+    async fn check_name_availability(&self, _vault_name: &str) -> Result<VaultNameStatus> {
+        Err(CrosstacheError::config(
+            "vault name checks are not supported by this backend",
+        ))
+    }
+
+    /// Whether the vault has at least one diagnostic setting.
+    async fn diagnostics_enabled(&self, _vault_name: &str, _resource_group: &str) -> Result<bool> {
+        Err(CrosstacheError::config(
+            "diagnostic settings are not supported by this backend",
+        ))
+    }
+
+    /// The data actions the caller's role assignments grant on the vault.
+    async fn caller_data_actions(
+        &self,
+        _vault_name: &str,
+        _resource_group: &str,
+    ) -> Result<Vec<DataActionGrant>> {
+        Err(CrosstacheError::config(
+            "permission checks are not supported by this backend",
+        ))
+    }
+
+    /// Evaluate the policy assignments covering the request's resource group
+    /// against the vault it would create, without creating anything.
+    async fn check_create_policy(
+        &self,
+        _request: &VaultCreateRequest,
+    ) -> Result<Vec<PolicyFinding>> {
+        Err(CrosstacheError::config(
+            "policy checks are not supported by this backend",
+        ))
+    }
+}
+
+/// Summarize `GET /subscriptions` results, keeping subscriptions whose vaults
+/// can be listed: `Disabled` and `Deleted` ones answer every call with an error.
+fn parse_subscriptions(subscriptions: &[Value]) -> Vec<SubscriptionSummary> {
+    subscriptions
+        .iter()
+        .filter(|sub| {
+            !matches!(
+                sub.get("state").and_then(|v| v.as_str()),
+                Some("Disabled") | Some("Deleted")
+            )
+        })
+        .filter_map(|sub| {
+            let id = sub.get("subscriptionId")?.as_str()?.to_string();
+            let display_name = sub
+                .get("displayName")
+                .and_then(|v| v.as_str())
+                .unwrap_or(&id)
+                .to_string();
+            Some(SubscriptionSummary { id, display_name })
+        })
+        .collect()
+}
+
+/// Find `vault_name` (case-insensitively, as Azure treats vault names) on one
+/// page of `GET .../deletedVaults`.
+fn find_deleted_vault_in(page: &[Value], vault_name: &str) -> Option<DeletedVaultSummary> {
+    page.iter()
+        .find(|vault| {
+            vault
+                .get("name")
+                .and_then(Value::as_str)
+                .is_some_and(|name| name.eq_ignore_ascii_case(vault_name))
+        })
+        .map(|vault| {
+            let properties = vault.get("properties").unwrap_or(&Value::Null);
+            DeletedVaultSummary {
+                name: vault_name.to_string(),
+                location: properties
+                    .get("location")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                scheduled_purge_date: properties
+                    .get("scheduledPurgeDate")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                purge_protection: properties
+                    .get("purgeProtectionEnabled")
+                    .and_then(Value::as_bool),
+            }
+        })
+}
+
+/// Pick the one security group a `GET /groups?$filter=displayName eq ...`
+/// page matched for `group`.
+fn pick_security_group(page: &Value, group: &str) -> Result<String> {
+    let matches: Vec<&Value> = page
+        .get("value")
+        .and_then(Value::as_array)
+        .map(|groups| groups.iter().collect())
+        .unwrap_or_default();
+    let id = |g: &Value| {
+        g.get("id")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    match matches.as_slice() {
+        [] => Err(CrosstacheError::invalid_argument(format!(
+            "No Entra ID group named '{group}'"
+        ))),
+        [only] => {
+            if only.get("securityEnabled").and_then(Value::as_bool) == Some(false) {
+                return Err(CrosstacheError::invalid_argument(format!(
+                    "Group '{group}' is not security-enabled; Azure RBAC roles can only be \
+                     assigned to security groups"
+                )));
+            }
+            Ok(id(only))
+        }
+        several => Err(CrosstacheError::invalid_argument(format!(
+            "{} groups are named '{group}' ({}); use group:<object-id> to pick one",
+            several.len(),
+            several.iter().map(|g| id(g)).collect::<Vec<_>>().join(", ")
+        ))),
+    }
+}
+
+/// Read the guest user out of a `POST /invitations` response.
+fn parse_invitation(body: &Value) -> Result<GuestInvitation> {
+    let user_id = body
+        .pointer("/invitedUser/id")
+        .and_then(Value::as_str)
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| {
+            CrosstacheError::serialization("Invitation response has no invited user id")
+        })?;
+    Ok(GuestInvitation {
+        user_id: user_id.to_string(),
+        redeem_url: body
+            .get("inviteRedeemUrl")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        status: body
+            .get("status")
+            .and_then(Value::as_str)
+            .unwrap_or("PendingAcceptance")
+            .to_string(),
+    })
+}
+
+/// Interpret a `checkNameAvailability` response body.
+fn parse_name_availability(body: &Value) -> VaultNameStatus {
+    if body
+        .get("nameAvailable")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+    {
+        return VaultNameStatus::Available;
+    }
+    let message = body
+        .get("message")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    match body.get("reason").and_then(Value::as_str) {
+        Some("AccountNameInvalid") => VaultNameStatus::Invalid { message },
+        _ => VaultNameStatus::Taken { message },
+    }
+}
+
+/// The resource `create_vault` would PUT, as Policy Insights wants it: the
+/// same settings, with the defaults Azure fills in (public network access)
+/// spelled out so policies on them are evaluated. Access policies are left
+/// out; vault policies do not look at them and they need a token to build.
+fn policy_check_content(request: &VaultCreateRequest) -> Value {
+    json!({
+        "type": "Microsoft.KeyVault/vaults",
+        "name": request.name,
+        "location": request.location,
+        "tags": request.tags.as_ref().unwrap_or(&HashMap::new()),
+        "properties": {
+            "sku": {
+                "family": "A",
+                "name": request.sku.as_deref().unwrap_or("standard")
+            },
+            "enabledForDeployment": request.enabled_for_deployment.unwrap_or(false),
+            "enabledForDiskEncryption": request.enabled_for_disk_encryption.unwrap_or(false),
+            "enabledForTemplateDeployment": request.enabled_for_template_deployment.unwrap_or(false),
+            "enableSoftDelete": true,
+            "softDeleteRetentionInDays": request.soft_delete_retention_in_days.unwrap_or(90),
+            "enablePurgeProtection": request.purge_protection.unwrap_or(true),
+            "publicNetworkAccess": "Enabled"
+        }
+    })
+}
+
+/// Short label for the policy behind a restriction or evaluation: its display
+/// name when reported, otherwise the last segment of its definition id.
+fn policy_label(info: &Value) -> Option<String> {
+    info.get("policyDefinitionDisplayName")
+        .and_then(Value::as_str)
+        .filter(|name| !name.is_empty())
+        .or_else(|| {
+            info.get("policyDefinitionId")
+                .and_then(Value::as_str)
+                .and_then(|id| id.rsplit('/').next())
+        })
+        .map(str::to_string)
+}
+
+/// Interpret a `checkPolicyRestrictions` response body: field restrictions
+/// (values a policy requires, removes or denies) plus non-compliant
+/// evaluations of the resource content as a whole.
+fn parse_policy_restrictions(body: &Value) -> Vec<PolicyFinding> {
+    let mut findings = Vec::new();
+
+    for field_restriction in body
+        .get("fieldRestrictions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let field = field_restriction
+            .get("field")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        for restriction in field_restriction
+            .get("restrictions")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(effect) = restriction.get("result").and_then(Value::as_str) else {
+                continue;
+            };
+            let values = restriction
+                .get("values")
+                .and_then(Value::as_array)
+                .map(|values| {
+                    values
+                        .iter()
+                        .map(|v| v.as_str().map_or_else(|| v.to_string(), str::to_string))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .filter(|values| !values.is_empty());
+            let detail = restriction
+                .get("defaultValue")
+                .map(|v| v.as_str().map_or_else(|| v.to_string(), str::to_string))
+                .or(values)
+                .or_else(|| {
+                    restriction
+                        .get("reason")
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                });
+            findings.push(PolicyFinding {
+                effect: effect.to_string(),
+                field: field.clone(),
+                policy: restriction.get("policy").and_then(policy_label),
+                detail,
+            });
+        }
+    }
+
+    for evaluation in body
+        .pointer("/contentEvaluationResult/policyEvaluations")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let non_compliant = evaluation
+            .get("evaluationResult")
+            .and_then(Value::as_str)
+            .is_some_and(|result| result.eq_ignore_ascii_case("NonCompliant"));
+        let Some(effect) = evaluation
+            .pointer("/effectDetails/policyEffect")
+            .and_then(Value::as_str)
+        else {
+            continue;
+        };
+        if !non_compliant {
+            continue;
+        }
+        let field = evaluation
+            .pointer("/evaluationDetails/evaluatedExpressions/0/path")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        findings.push(PolicyFinding {
+            effect: effect.to_string(),
+            field,
+            policy: evaluation.get("policyInfo").and_then(policy_label),
+            detail: None,
+        });
+    }
+
+    findings
+}
+
+/// Parse the entries of an ARM `.../providers/Microsoft.Authorization/permissions` list.
+fn parse_data_action_grants(permissions: &[Value]) -> Vec<DataActionGrant> {
+    let strings = |permission: &Value, field: &str| -> Vec<String> {
+        permission
+            .get(field)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect()
+    };
+    permissions
+        .iter()
+        .map(|permission| DataActionGrant {
+            data_actions: strings(permission, "dataActions"),
+            not_data_actions: strings(permission, "notDataActions"),
+        })
+        .collect()
+}
+
+/// Summarize a vault's `properties.networkAcls` object.
+fn parse_network_acls(acls: &Value) -> NetworkAclSummary {
+    let text = |field: &str, default: &str| {
+        acls.get(field)
+            .and_then(|v| v.as_str())
+            .unwrap_or(default)
+            .to_string()
+    };
+    let rule_count = |field: &str| {
+        acls.get(field)
+            .and_then(|v| v.as_array())
+            .map_or(0, Vec::len)
+    };
+    NetworkAclSummary {
+        default_action: text("defaultAction", "Allow"),
+        bypass: text("bypass", "AzureServices"),
+        ip_rules: rule_count("ipRules"),
+        virtual_network_rules: rule_count("virtualNetworkRules"),
+    }
 }
 
 /// Azure vault operations implementation
 pub struct AzureVaultOperations {
     auth_provider: Arc<dyn AzureAuthProvider>,
-    http_client: Client,
+    arm: ArmClient,
     subscription_id: String,
 }
 
 impl AzureVaultOperations {
     /// Create a new Azure vault operations instance
     pub fn new(auth_provider: Arc<dyn AzureAuthProvider>, subscription_id: String) -> Result<Self> {
-        let network_config = NetworkConfig::default();
-        let http_client = create_http_client(&network_config)?;
-
         Ok(Self {
+            arm: ArmClient::new(auth_provider.clone())?,
             auth_provider,
-            http_client,
             subscription_id,
         })
     }
 
-    /// Get access token for Azure Resource Manager
-    async fn get_management_token(&self) -> Result<String> {
+    /// Create authorized headers for Microsoft Graph
+    async fn create_graph_headers(&self) -> Result<HeaderMap> {
         let token = self
             .auth_provider
-            .get_token(&["https://management.azure.com/.default"])
+            .get_token(&["https://graph.microsoft.com/.default"])
             .await?;
-        Ok(token.token.secret().to_string())
-    }
-
-    /// Create authorized headers for Azure REST API
-    async fn create_headers(&self) -> Result<HeaderMap> {
-        let token = self.get_management_token().await?;
         let mut headers = HeaderMap::new();
         headers.insert(
             "Authorization",
-            format!("Bearer {token}").parse().map_err(|e| {
-                CrosstacheError::authentication(format!("Invalid token format: {e}"))
-            })?,
+            format!("Bearer {}", token.token.secret())
+                .parse()
+                .map_err(|e| {
+                    CrosstacheError::authentication(format!("Invalid token format: {e}"))
+                })?,
         );
-        headers.insert("Content-Type", "application/json".parse().unwrap());
         Ok(headers)
     }
 
-    /// Build Azure Resource Manager URL
-    fn build_arm_url(&self, path: &str) -> String {
-        format!("https://management.azure.com{path}")
+    /// The data actions the caller holds on any ARM resource, e.g. a
+    /// storage account, from the permissions API.
+    pub async fn caller_data_actions_on(
+        &self,
+        resource_id: &str,
+        context: &str,
+    ) -> Result<Vec<DataActionGrant>> {
+        let url = self.arm.url(&format!(
+            "{resource_id}/providers/Microsoft.Authorization/permissions?api-version={RBAC_API_VERSION}"
+        ));
+        let permissions: Vec<Value> = self.arm.list(url, context).await?;
+        Ok(parse_data_action_grants(&permissions))
     }
 
     fn validated_vault_name(&self, vault_name: &str) -> Result<AzureVaultName> {
@@ -226,175 +627,214 @@ impl AzureVaultOperations {
         )
     }
 
-    /// Parse Azure error response
-    fn parse_azure_error(&self, status: u16, body: &str) -> CrosstacheError {
-        if let Ok(error_json) = serde_json::from_str::<Value>(body) {
-            if let Some(error) = error_json.get("error") {
-                if let Some(message) = error.get("message").and_then(|m| m.as_str()) {
-                    return CrosstacheError::azure_api(format!("HTTP {status}: {message}"));
-                }
+    /// ARM resource ID of a secret, the scope of per-secret role assignments
+    fn get_secret_resource_id(
+        &self,
+        vault_name: &AzureVaultName,
+        resource_group: &str,
+        secret_name: &str,
+    ) -> String {
+        format!(
+            "{}/secrets/{}",
+            self.get_vault_resource_id(vault_name, resource_group),
+            enc(secret_name)
+        )
+    }
+
+    /// The vault's ARM resource URL
+    fn vault_url(&self, vault_name: &AzureVaultName, resource_group: &str) -> String {
+        let resource_id = self.get_vault_resource_id(vault_name, resource_group);
+        self.arm
+            .url(&format!("{resource_id}?api-version={VAULT_API_VERSION}"))
+    }
+
+    /// Parse a Microsoft Graph error response
+    fn parse_graph_error(
+        &self,
+        status: u16,
+        retry_after: Option<u64>,
+        resource: &str,
+        body: &str,
+    ) -> CrosstacheError {
+        CrosstacheError::from_http_status(
+            status,
+            retry_after,
+            resource,
+            format!("HTTP {status}: {}", azure_error_detail(body)),
+        )
+    }
+
+    /// Create a role assignment of `access_level` for `principal_id` at `scope`
+    async fn assign_role(
+        &self,
+        scope: &str,
+        principal_id: &str,
+        access_level: &AccessLevel,
+        context: &str,
+    ) -> Result<()> {
+        // The id is fixed before the first attempt, so a retried PUT cannot
+        // create a second assignment.
+        let url = self.arm.url(&format!(
+            "{scope}/providers/Microsoft.Authorization/roleAssignments/{}?api-version={RBAC_API_VERSION}",
+            Uuid::new_v4()
+        ));
+        let body = json!({
+            "properties": {
+                "roleDefinitionId": format!(
+                    "/subscriptions/{}/providers/Microsoft.Authorization/roleDefinitions/{}",
+                    enc(&self.subscription_id),
+                    key_vault_role_definition_id(access_level)
+                ),
+                "principalId": principal_id
             }
-        }
-        CrosstacheError::azure_api(format!("HTTP {status}: {body}"))
+        });
+        self.arm.put::<_, Value>(&url, &body, context).await?;
+        Ok(())
     }
 
-    /// Retry wrapper for Azure operations
-    async fn execute_with_retry<F, Fut, T>(&self, operation: F) -> Result<T>
-    where
-        F: Fn() -> Fut,
-        Fut: std::future::Future<Output = Result<T>>,
-    {
-        let retry_options = crate::utils::retry::RetryOptions {
-            max_retries: 3,
-            initial_interval: std::time::Duration::from_millis(1000),
-            max_interval: std::time::Duration::from_millis(10000),
-            multiplier: 2.0,
-        };
-        retry_with_backoff(operation, retry_options).await
+    /// Role assignments at `scope` matching `filter` (an OData expression)
+    async fn role_assignments(&self, scope: &str, filter: &str) -> Result<Vec<Value>> {
+        let url = self.arm.url(&format!(
+            "{scope}/providers/Microsoft.Authorization/roleAssignments?api-version={RBAC_API_VERSION}&$filter={filter}"
+        ));
+        self.arm.list(url, "Failed to list role assignments").await
+    }
+
+    /// Delete `principal_id`'s assignments made exactly at `scope`;
+    /// inherited ones are left alone. `what` names the scope in errors.
+    async fn remove_exact_assignments(
+        &self,
+        scope: &str,
+        principal_id: &str,
+        what: &str,
+    ) -> Result<()> {
+        let filter = crate::utils::url_helpers::odata_eq("principalId", principal_id);
+        let assignments = self.role_assignments(scope, &filter).await?;
+        let exact_assignments: Vec<_> = assignments
+            .iter()
+            .filter(|assignment| assignment_has_exact_scope(assignment, scope))
+            .collect();
+        if exact_assignments.is_empty() {
+            return Err(CrosstacheError::permission_denied(format!(
+                "User does not have an exact-scope role assignment on this {what}"
+            )));
+        }
+        for assignment in exact_assignments {
+            let assignment_id = assignment
+                .get("id")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    CrosstacheError::serialization(
+                        "Exact-scope role assignment is missing its resource ID",
+                    )
+                })?;
+            let url = self
+                .arm
+                .url(&format!("{assignment_id}?api-version={RBAC_API_VERSION}"));
+            self.arm
+                .delete(&url, "Failed to delete role assignment")
+                .await?;
+        }
+        Ok(())
     }
 }
 
 #[async_trait]
 impl VaultOperations for AzureVaultOperations {
     async fn create_vault(&self, request: &VaultCreateRequest) -> Result<VaultProperties> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(&request.name)?;
-            let headers = self.create_headers().await?;
-            let resource_id = self.get_vault_resource_id(&vault_name, &request.resource_group);
-            let url = self.build_arm_url(&format!("{resource_id}?api-version=2023-07-01"));
-
-            let tenant_id = self.auth_provider.get_tenant_id().await?;
-            let current_user_object_id = self.auth_provider.get_object_id().await?;
-
-            // Build access policies including current user as admin
-            let mut access_policies = request.access_policies.clone().unwrap_or_default();
-            if !access_policies
-                .iter()
-                .any(|p| p.object_id == current_user_object_id)
-            {
-                access_policies.push(AccessPolicy::new(
-                    tenant_id.clone(),
-                    current_user_object_id,
-                    AccessLevel::Admin,
-                    None,
-                    None,
-                ));
-            }
-
-            // Convert access policies to Azure API format
-            let access_policies_json: Vec<Value> = access_policies
-                .iter()
-                .map(|policy| {
-                    json!({
-                        "tenantId": policy.tenant_id,
-                        "objectId": policy.object_id,
-                        "applicationId": policy.application_id,
-                        "permissions": {
-                            "keys": policy.permissions.keys,
-                            "secrets": policy.permissions.secrets,
-                            "certificates": policy.permissions.certificates,
-                            "storage": policy.permissions.storage
-                        }
-                    })
-                })
-                .collect();
+        let vault_name = self.validated_vault_name(&request.name)?;
+        let url = self.vault_url(&vault_name, &request.resource_group);
 
-            let body = json!({
-                "location": request.location,
-                "properties": {
-                    "tenantId": tenant_id,
-                    "sku": {
-                        "family": "A",
-                        "name": request.sku.as_ref().unwrap_or(&"standard".to_string())
-                    },
-                    "accessPolicies": access_policies_json,
-                    "enabledForDeployment": request.enabled_for_deployment.unwrap_or(false),
-                    "enabledForDiskEncryption": request.enabled_for_disk_encryption.unwrap_or(false),
-                    "enabledForTemplateDeployment": request.enabled_for_template_deployment.unwrap_or(false),
-                    "enableSoftDelete": true,
-                    "softDeleteRetentionInDays": request.soft_delete_retention_in_days.unwrap_or(90),
-                    "enablePurgeProtection": request.purge_protection.unwrap_or(true)
-                },
-                "tags": request.tags.as_ref().unwrap_or(&HashMap::new())
-            });
-
-            let response = self
-                .http_client
-                .put(&url)
-                .headers(headers)
-                .json(&body)
-                .send()
-                .await
-                .map_err(|e| classify_network_error(&e, &url))?;
+        let tenant_id = self.auth_provider.get_tenant_id().await?;
+        let current_user_object_id = self.auth_provider.get_object_id().await?;
 
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
+        // Build access policies including current user as admin
+        let mut access_policies = request.access_policies.clone().unwrap_or_default();
+        if !access_policies
+            .iter()
+            .any(|p| p.object_id == current_user_object_id)
+        {
+            access_policies.push(AccessPolicy::new(
+                tenant_id.clone(),
+                current_user_object_id,
+                AccessLevel::Admin,
+                None,
+                None,
+            ));
+        }
 
-            let vault_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse vault response: {e}"))
-            })?;
+        // Convert access policies to Azure API format
+        let access_policies_json: Vec<Value> = access_policies
+            .iter()
+            .map(|policy| {
+                json!({
+                    "tenantId": policy.tenant_id,
+                    "objectId": policy.object_id,
+                    "applicationId": policy.application_id,
+                    "permissions": {
+                        "keys": policy.permissions.keys,
+                        "secrets": policy.permissions.secrets,
+                        "certificates": policy.permissions.certificates,
+                        "storage": policy.permissions.storage
+                    }
+                })
+            })
+            .collect();
 
-            self.parse_vault_properties(&vault_data)
-        };
+        let body = json!({
+            "location": request.location,
+            "properties": {
+                "tenantId": tenant_id,
+                "sku": {
+                    "family": "A",
+                    "name": request.sku.as_ref().unwrap_or(&"standard".to_string())
+                },
+                "accessPolicies": access_policies_json,
+                "enabledForDeployment": request.enabled_for_deployment.unwrap_or(false),
+                "enabledForDiskEncryption": request.enabled_for_disk_encryption.unwrap_or(false),
+                "enabledForTemplateDeployment": request.enabled_for_template_deployment.unwrap_or(false),
+                "enableSoftDelete": true,
+                "softDeleteRetentionInDays": request.soft_delete_retention_in_days.unwrap_or(90),
+                "enablePurgeProtection": request.purge_protection.unwrap_or(true)
+            },
+            "tags": request.tags.as_ref().unwrap_or(&HashMap::new())
+        });
 
-        self.execute_with_retry(operation).await
+        let vault_data: Value = self.arm.put(&url, &body, "Failed to create vault").await?;
+        self.parse_vault_properties(&vault_data)
     }
 
     async fn get_vault(&self, vault_name: &str, resource_group: &str) -> Result<VaultProperties> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let resource_id = self.get_vault_resource_id(&vault_name, resource_group);
-            let url = self.build_arm_url(&format!("{resource_id}?api-version=2023-07-01"));
-
-            let response = self
-                .http_client
-                .get(&url)
-                .headers(headers)
-                .send()
-                .await
-                .map_err(|e| classify_network_error(&e, &url))?;
-
-            if response.status().as_u16() == 404 {
-                return Err(CrosstacheError::vault_not_found(vault_name.as_str()));
-            }
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            let vault_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse vault response: {e}"))
-            })?;
-
-            let mut vault = self.parse_vault_properties(&vault_data)?;
-
-            // Resolve user emails for access policies via Graph API
-            let object_ids: Vec<String> = vault
-                .access_policies
-                .iter()
-                .map(|p| p.object_id.clone())
-                .collect();
-            if !object_ids.is_empty() {
-                let resolved = self.resolve_principal_ids(&object_ids).await;
-                for policy in vault.access_policies.iter_mut() {
-                    if let Some((_name, email)) = resolved.get(&policy.object_id) {
-                        if !email.is_empty() {
-                            policy.user_email = Some(email.clone());
-                        }
+        let vault_data = self.get_vault_resource(vault_name, resource_group).await?;
+        let mut vault = self.parse_vault_properties(&vault_data)?;
+
+        // Resolve user emails for access policies via Graph API
+        let object_ids: Vec<String> = vault
+            .access_policies
+            .iter()
+            .map(|p| p.object_id.clone())
+            .collect();
+        if !object_ids.is_empty() {
+            let resolved = self.resolve_principal_ids(&object_ids).await;
+            for policy in vault.access_policies.iter_mut() {
+                if let Some((_name, email)) = resolved.get(&policy.object_id) {
+                    if !email.is_empty() {
+                        policy.user_email = Some(email.clone());
                     }
                 }
             }
+        }
 
-            Ok(vault)
-        };
+        Ok(vault)
+    }
 
-        self.execute_with_retry(operation).await
+    async fn get_vault_resource(&self, vault_name: &str, resource_group: &str) -> Result<Value> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let url = self.vault_url(&vault_name, resource_group);
+        self.arm
+            .get(&url, "Failed to read vault")
+            .await?
+            .ok_or_else(|| CrosstacheError::vault_not_found(vault_name.as_str()))
     }
 
     async fn list_vaults(
@@ -402,80 +842,26 @@ impl VaultOperations for AzureVaultOperations {
         subscription_id: Option<&str>,
         resource_group: Option<&str>,
     ) -> Result<Vec<VaultSummary>> {
-        let headers = self.create_headers().await?;
         let sub_id = subscription_id.unwrap_or(&self.subscription_id);
-
-        let first_url = if let Some(rg) = resource_group {
-            self.build_arm_url(&format!(
-                "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults?api-version=2023-07-01",
+        let url = if let Some(rg) = resource_group {
+            self.arm.url(&format!(
+                "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults?api-version={VAULT_API_VERSION}",
                 enc(sub_id),
                 enc(rg)
             ))
         } else {
-            self.build_arm_url(&format!(
-                "/subscriptions/{}/providers/Microsoft.KeyVault/vaults?api-version=2023-07-01",
+            self.arm.url(&format!(
+                "/subscriptions/{}/providers/Microsoft.KeyVault/vaults?api-version={VAULT_API_VERSION}",
                 enc(sub_id)
             ))
         };
 
-        let mut vaults = Vec::new();
-        let mut next_url: Option<String> = Some(first_url);
-        let mut page_count: usize = 0;
-
-        while let Some(current_url) = next_url.take() {
-            page_count += 1;
-            if page_count > crate::utils::MAX_PAGES {
-                return Err(CrosstacheError::azure_api(format!(
-                    "Pagination exceeded maximum of {} pages",
-                    crate::utils::MAX_PAGES
-                )));
-            }
-
-            let response = self
-                .http_client
-                .get(&current_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| CrosstacheError::network(format!("Failed to list vaults: {e}")))?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            let response_data: Value = {
-                let bytes = response.bytes().await.map_err(|e| {
-                    CrosstacheError::serialization(format!("Failed to read vaults response: {e}"))
-                })?;
-                if bytes.len() > crate::utils::MAX_RESPONSE_BYTES {
-                    return Err(CrosstacheError::serialization(format!(
-                        "Vaults response too large: {} bytes (max {})",
-                        bytes.len(),
-                        crate::utils::MAX_RESPONSE_BYTES,
-                    )));
-                }
-                serde_json::from_slice(&bytes).map_err(|e| {
-                    CrosstacheError::serialization(format!("Failed to parse vaults response: {e}"))
-                })?
-            };
-
-            if let Some(vault_array) = response_data.get("value").and_then(|v| v.as_array()) {
-                for vault_value in vault_array {
-                    if let Ok(vault_props) = self.parse_vault_properties(vault_value) {
-                        vaults.push(vault_props.to_summary());
-                    }
-                }
-            }
-
-            next_url = response_data
-                .get("nextLink")
-                .and_then(|v| v.as_str())
-                .map(str::to_owned);
-        }
-
-        Ok(vaults)
+        let vaults: Vec<Value> = self.arm.list(url, "Failed to list vaults").await?;
+        Ok(vaults
+            .iter()
+            .filter_map(|vault| self.parse_vault_properties(vault).ok())
+            .map(|vault| vault.to_summary())
+            .collect())
     }
 
     async fn update_vault(
@@ -484,720 +870,345 @@ impl VaultOperations for AzureVaultOperations {
         resource_group: &str,
         request: &VaultUpdateRequest,
     ) -> Result<VaultProperties> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            // First get the current vault to merge properties
-            let current_vault = self.get_vault(vault_name.as_str(), resource_group).await?;
-
-            let headers = self.create_headers().await?;
-            let resource_id = self.get_vault_resource_id(&vault_name, resource_group);
-            let url = self.build_arm_url(&format!("{resource_id}?api-version=2023-07-01"));
-
-            let properties = json!({
-                "tenantId": current_vault.tenant_id,
-                "sku": {
-                    "family": "A",
-                    "name": current_vault.sku
-                },
-                "accessPolicies": request.access_policies.as_ref().unwrap_or(&current_vault.access_policies),
-                "enabledForDeployment": request.enabled_for_deployment.unwrap_or(current_vault.enabled_for_deployment),
-                "enabledForDiskEncryption": request.enabled_for_disk_encryption.unwrap_or(current_vault.enabled_for_disk_encryption),
-                "enabledForTemplateDeployment": request.enabled_for_template_deployment.unwrap_or(current_vault.enabled_for_template_deployment),
-                "enableSoftDelete": true,
-                "softDeleteRetentionInDays": request.soft_delete_retention_in_days.unwrap_or(current_vault.soft_delete_retention_in_days),
-                "enablePurgeProtection": request.purge_protection.unwrap_or(current_vault.purge_protection)
-            });
-
-            let body = json!({
-                "location": current_vault.location,
-                "properties": properties,
-                "tags": request.tags.as_ref().unwrap_or(&current_vault.tags)
-            });
-
-            let response = self
-                .http_client
-                .put(&url)
-                .headers(headers)
-                .json(&body)
-                .send()
-                .await
-                .map_err(|e| CrosstacheError::network(format!("Failed to update vault: {e}")))?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            let vault_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse vault response: {e}"))
-            })?;
+        let vault_name = self.validated_vault_name(vault_name)?;
+        // First get the current vault to merge properties
+        let current_vault = self.get_vault(vault_name.as_str(), resource_group).await?;
+        let url = self.vault_url(&vault_name, resource_group);
+
+        let properties = json!({
+            "tenantId": current_vault.tenant_id,
+            "sku": {
+                "family": "A",
+                "name": current_vault.sku
+            },
+            "accessPolicies": request.access_policies.as_ref().unwrap_or(&current_vault.access_policies),
+            "enabledForDeployment": request.enabled_for_deployment.unwrap_or(current_vault.enabled_for_deployment),
+            "enabledForDiskEncryption": request.enabled_for_disk_encryption.unwrap_or(current_vault.enabled_for_disk_encryption),
+            "enabledForTemplateDeployment": request.enabled_for_template_deployment.unwrap_or(current_vault.enabled_for_template_deployment),
+            "enableSoftDelete": true,
+            "softDeleteRetentionInDays": request.soft_delete_retention_in_days.unwrap_or(current_vault.soft_delete_retention_in_days),
+            "enablePurgeProtection": request.purge_protection.unwrap_or(current_vault.purge_protection)
+        });
 
-            self.parse_vault_properties(&vault_data)
-        };
+        let body = json!({
+            "location": current_vault.location,
+            "properties": properties,
+            "tags": request.tags.as_ref().unwrap_or(&current_vault.tags)
+        });
 
-        self.execute_with_retry(operation).await
+        let vault_data: Value = self.arm.put(&url, &body, "Failed to update vault").await?;
+        self.parse_vault_properties(&vault_data)
     }
 
     async fn delete_vault(&self, vault_name: &str, resource_group: &str) -> Result<()> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let resource_id = self.get_vault_resource_id(&vault_name, resource_group);
-            let url = self.build_arm_url(&format!("{resource_id}?api-version=2023-07-01"));
-
-            let response = self
-                .http_client
-                .delete(&url)
-                .headers(headers)
-                .send()
-                .await
-                .map_err(|e| CrosstacheError::network(format!("Failed to delete vault: {e}")))?;
-
-            if response.status().as_u16() == 404 {
-                return Err(CrosstacheError::vault_not_found(vault_name.as_str()));
-            }
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            Ok(())
-        };
-
-        self.execute_with_retry(operation).await
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let url = self.vault_url(&vault_name, resource_group);
+        if !self.arm.delete(&url, "Failed to delete vault").await? {
+            return Err(CrosstacheError::vault_not_found(vault_name.as_str()));
+        }
+        Ok(())
     }
 
     async fn restore_vault(&self, vault_name: &str, location: &str) -> Result<VaultProperties> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let url = self.build_arm_url(&format!(
-                "/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}/recover?api-version=2023-07-01",
-                enc(&self.subscription_id),
-                enc(location),
-                enc(vault_name.as_str())
-            ));
-
-            let response = self
-                .http_client
-                .post(&url)
-                .headers(headers)
-                .send()
-                .await
-                .map_err(|e| CrosstacheError::network(format!("Failed to restore vault: {e}")))?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            // Azure returns the vault object in the body for a successful recover (200 OK).
-            // Parse it if present; fall back to fabricated properties if the body is empty (202 Accepted).
-            let response_body = response.text().await.unwrap_or_default();
-            if !response_body.is_empty() {
-                if let Ok(vault_data) = serde_json::from_str::<Value>(&response_body) {
-                    if let Ok(props) = self.parse_vault_properties(&vault_data) {
-                        return Ok(props);
-                    }
-                }
-            }
-
-            // Fall back: wait briefly then return best-effort properties
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-            Ok(VaultProperties {
-                id: format!(
-                    "/subscriptions/{}/providers/Microsoft.KeyVault/vaults/{}",
-                    enc(&self.subscription_id),
-                    enc(vault_name.as_str())
-                ),
-                name: vault_name.as_str().to_string(),
-                location: location.to_string(),
-                resource_group: String::new(), // unknown without a separate lookup
-                subscription_id: self.subscription_id.clone(),
-                tenant_id: self.auth_provider.get_tenant_id().await?,
-                uri: vault_name.key_vault_url()?.to_string(),
-                enabled_for_deployment: false,
-                enabled_for_disk_encryption: false,
-                enabled_for_template_deployment: false,
-                soft_delete_retention_in_days: 90,
-                purge_protection: false,
-                sku: "standard".to_string(),
-                access_policies: Vec::new(),
-                created_at: chrono::Utc::now(),
-                tags: HashMap::new(),
-                enable_rbac_authorization: None,
-            })
-        };
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let url = self.arm.url(&format!(
+            "/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}/recover?api-version={VAULT_API_VERSION}",
+            enc(&self.subscription_id),
+            enc(location),
+            enc(vault_name.as_str())
+        ));
 
-        self.execute_with_retry(operation).await
-    }
+        // Azure returns the vault object in the body for a successful recover (200 OK).
+        // Parse it if present; fall back to fabricated properties if the body is empty (202 Accepted).
+        let vault_data: Value = self
+            .arm
+            .post::<(), _>(&url, None, "Failed to restore vault")
+            .await?;
+        if let Ok(props) = self.parse_vault_properties(&vault_data) {
+            return Ok(props);
+        }
 
-    async fn purge_vault(&self, vault_name: &str, location: &str) -> Result<()> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let url = self.build_arm_url(&format!(
-                "/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}/purge?api-version=2023-07-01",
+        // Fall back: wait briefly then return best-effort properties
+        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+        Ok(VaultProperties {
+            id: format!(
+                "/subscriptions/{}/providers/Microsoft.KeyVault/vaults/{}",
                 enc(&self.subscription_id),
-                enc(location),
                 enc(vault_name.as_str())
-            ));
-
-            let response = self
-                .http_client
-                .post(&url)
-                .headers(headers)
-                .send()
-                .await
-                .map_err(|e| CrosstacheError::network(format!("Failed to purge vault: {e}")))?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            Ok(())
-        };
-
-        self.execute_with_retry(operation).await
-    }
-
-    async fn grant_access(
-        &self,
-        vault_name: &str,
-        resource_group: &str,
-        user_object_id: &str,
-        access_level: AccessLevel,
-    ) -> Result<()> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let scope = self.get_vault_resource_id(&vault_name, resource_group);
-            let assignment_id = Uuid::new_v4();
-            let url = self.build_arm_url(&format!(
-                "{scope}/providers/Microsoft.Authorization/roleAssignments/{assignment_id}?api-version=2022-04-01"
-            ));
-            let role_definition_id = key_vault_role_definition_id(&access_level);
-            let body = json!({
-                "properties": {
-                    "roleDefinitionId": format!(
-                        "/subscriptions/{}/providers/Microsoft.Authorization/roleDefinitions/{role_definition_id}",
-                        enc(&self.subscription_id)
-                    ),
-                    "principalId": user_object_id
-                }
-            });
-
-            let response = self
-                .http_client
-                .put(&url)
-                .headers(headers)
-                .json(&body)
-                .send()
-                .await
-                .map_err(|e| {
-                    CrosstacheError::network(format!("Failed to grant vault access: {e}"))
-                })?;
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-            Ok(())
-        };
-        self.execute_with_retry(operation).await
-    }
-
-    async fn revoke_access(
-        &self,
-        vault_name: &str,
-        resource_group: &str,
-        user_object_id: &str,
-    ) -> Result<()> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let scope = self.get_vault_resource_id(&vault_name, resource_group);
-            let list_url = self.build_arm_url(&format!(
-                "{scope}/providers/Microsoft.Authorization/roleAssignments?api-version=2022-04-01&$filter={}",
-                crate::utils::url_helpers::odata_eq("principalId", user_object_id)
-            ));
-            let response = self
-                .http_client
-                .get(&list_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| {
-                    CrosstacheError::network(format!("Failed to list vault role assignments: {e}"))
-                })?;
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-            let response_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse role assignments: {e}"))
-            })?;
-            let assignments = response_data
-                .get("value")
-                .and_then(Value::as_array)
-                .cloned()
-                .unwrap_or_default();
-            let exact_assignments: Vec<_> = assignments
-                .iter()
-                .filter(|assignment| assignment_has_exact_scope(assignment, &scope))
-                .collect();
-            if exact_assignments.is_empty() {
-                return Err(CrosstacheError::permission_denied(
-                    "User does not have an exact-scope role assignment on this vault",
-                ));
-            }
-            for assignment in exact_assignments {
-                let assignment_id =
-                    assignment
-                        .get("id")
-                        .and_then(Value::as_str)
-                        .ok_or_else(|| {
-                            CrosstacheError::serialization(
-                                "Exact-scope role assignment is missing its resource ID",
-                            )
-                        })?;
-                let delete_url =
-                    self.build_arm_url(&format!("{assignment_id}?api-version=2022-04-01"));
-                let response = self
-                    .http_client
-                    .delete(&delete_url)
-                    .headers(headers.clone())
-                    .send()
-                    .await
-                    .map_err(|e| {
-                        CrosstacheError::network(format!(
-                            "Failed to delete vault role assignment: {e}"
-                        ))
-                    })?;
-                if !response.status().is_success() {
-                    let status_code = response.status().as_u16();
-                    let error_body = response.text().await.unwrap_or_default();
-                    return Err(self.parse_azure_error(status_code, &error_body));
-                }
-            }
-            Ok(())
-        };
-        self.execute_with_retry(operation).await
-    }
-
-    async fn list_access(&self, vault_name: &str, resource_group: &str) -> Result<Vec<VaultRole>> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let scope = self.get_vault_resource_id(&vault_name, resource_group);
-            let url = self.build_arm_url(&format!(
-                "{scope}/providers/Microsoft.Authorization/roleAssignments?api-version=2022-04-01&$filter=atScope()"
-            ));
-            let response = self
-                .http_client
-                .get(&url)
-                .headers(headers)
-                .send()
-                .await
-                .map_err(|e| {
-                    CrosstacheError::network(format!("Failed to list vault access: {e}"))
-                })?;
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-            let response_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse role assignments: {e}"))
-            })?;
-            let assignments = response_data
-                .get("value")
-                .and_then(Value::as_array)
-                .cloned()
-                .unwrap_or_default();
-            let now = chrono::Utc::now;
-            Ok(assignments
-                .iter()
-                .filter(|assignment| assignment_has_exact_scope(assignment, &scope))
-                .map(|assignment| {
-                    let props = assignment.get("properties").unwrap_or(assignment);
-                    let role_definition_id = props
-                        .get("roleDefinitionId")
-                        .and_then(Value::as_str)
-                        .unwrap_or_default();
-                    VaultRole {
-                        assignment_id: assignment
-                            .get("id")
-                            .and_then(Value::as_str)
-                            .unwrap_or_default()
-                            .to_string(),
-                        role_id: role_definition_id.to_string(),
-                        role_name: role_definition_id
-                            .rsplit('/')
-                            .next()
-                            .unwrap_or("Unknown Role")
-                            .to_string(),
-                        role_description: "Azure RBAC role assignment".to_string(),
-                        principal_id: props
-                            .get("principalId")
-                            .and_then(Value::as_str)
-                            .unwrap_or_default()
-                            .to_string(),
-                        principal_name: props
-                            .get("principalId")
-                            .and_then(Value::as_str)
-                            .unwrap_or_default()
-                            .to_string(),
-                        email: String::new(),
-                        principal_type: props
-                            .get("principalType")
-                            .and_then(Value::as_str)
-                            .unwrap_or("Unknown")
-                            .to_string(),
-                        scope: props
-                            .get("scope")
-                            .and_then(Value::as_str)
-                            .unwrap_or_default()
-                            .to_string(),
-                        created_on: props
-                            .get("createdOn")
-                            .and_then(Value::as_str)
-                            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                            .map(|dt| dt.with_timezone(&chrono::Utc))
-                            .unwrap_or_else(now),
-                        updated_on: props
-                            .get("updatedOn")
-                            .and_then(Value::as_str)
-                            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                            .map(|dt| dt.with_timezone(&chrono::Utc))
-                            .unwrap_or_else(now),
-                    }
-                })
-                .collect())
-        };
-        self.execute_with_retry(operation).await
-    }
-
-    async fn grant_secret_access(
-        &self,
-        vault_name: &str,
-        resource_group: &str,
-        secret_name: &str,
-        user_object_id: &str,
-        access_level: AccessLevel,
-    ) -> Result<()> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let scope = format!(
-                "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/secrets/{}",
-                enc(&self.subscription_id),
-                enc(resource_group),
-                enc(vault_name.as_str()),
-                enc(secret_name)
-            );
-
-            let role_definition_id = key_vault_role_definition_id(&access_level);
-
-            let assignment_id = Uuid::new_v4();
-            let url = self.build_arm_url(&format!(
-                "{scope}/providers/Microsoft.Authorization/roleAssignments/{assignment_id}?api-version=2022-04-01"
-            ));
-
-            let body = json!({
-                "properties": {
-                    "roleDefinitionId": format!(
-                        "/subscriptions/{}/providers/Microsoft.Authorization/roleDefinitions/{role_definition_id}",
-                        enc(&self.subscription_id)
-                    ),
-                    "principalId": user_object_id
-                }
-            });
-
-            let response = self
-                .http_client
-                .put(&url)
-                .headers(headers)
-                .json(&body)
-                .send()
-                .await
-                .map_err(|e| {
-                    CrosstacheError::network(format!("Failed to grant secret access: {e}"))
-                })?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            Ok(())
-        };
-
-        self.execute_with_retry(operation).await
-    }
-
-    async fn revoke_secret_access(
-        &self,
-        vault_name: &str,
-        resource_group: &str,
-        secret_name: &str,
-        user_object_id: &str,
-    ) -> Result<()> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let scope = format!(
-                "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/secrets/{}",
-                enc(&self.subscription_id),
-                enc(resource_group),
-                enc(vault_name.as_str()),
-                enc(secret_name)
-            );
-
-            // List role assignments for this scope
-            let list_url = self.build_arm_url(&format!(
-                "{scope}/providers/Microsoft.Authorization/roleAssignments?api-version=2022-04-01&$filter={}",
-                crate::utils::url_helpers::odata_eq("principalId", user_object_id)
-            ));
-
-            let response = self
-                .http_client
-                .get(&list_url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| {
-                    CrosstacheError::network(format!("Failed to list secret role assignments: {e}"))
-                })?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            let response_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse role assignments: {e}"))
-            })?;
-
-            let assignments = response_data
-                .get("value")
-                .and_then(|v| v.as_array())
-                .cloned()
-                .unwrap_or_default();
-
-            if assignments.is_empty() {
-                return Err(CrosstacheError::permission_denied(
-                    "User does not have any role assignments on this secret",
-                ));
-            }
-
-            let exact_assignments: Vec<_> = assignments
-                .iter()
-                .filter(|assignment| assignment_has_exact_scope(assignment, &scope))
-                .collect();
-            if exact_assignments.is_empty() {
-                return Err(CrosstacheError::permission_denied(
-                    "User does not have an exact-scope role assignment on this secret",
-                ));
-            }
-
-            // Delete only assignments whose ARM properties confirm the exact
-            // secret scope; inherited vault/resource-group assignments remain.
-            for assignment in exact_assignments {
-                let assignment_id =
-                    assignment
-                        .get("id")
-                        .and_then(|v| v.as_str())
-                        .ok_or_else(|| {
-                            CrosstacheError::serialization(
-                                "Exact-scope role assignment is missing its resource ID",
-                            )
-                        })?;
-                let delete_url =
-                    self.build_arm_url(&format!("{assignment_id}?api-version=2022-04-01"));
-
-                let del_response = self
-                    .http_client
-                    .delete(&delete_url)
-                    .headers(headers.clone())
-                    .send()
-                    .await
-                    .map_err(|e| {
-                        CrosstacheError::network(format!("Failed to delete role assignment: {e}"))
-                    })?;
-
-                if !del_response.status().is_success() {
-                    let status_code = del_response.status().as_u16();
-                    let error_body = del_response.text().await.unwrap_or_default();
-                    return Err(self.parse_azure_error(status_code, &error_body));
-                }
-            }
-
-            Ok(())
-        };
-
-        self.execute_with_retry(operation).await
+            ),
+            name: vault_name.as_str().to_string(),
+            location: location.to_string(),
+            resource_group: String::new(), // unknown without a separate lookup
+            subscription_id: self.subscription_id.clone(),
+            tenant_id: self.auth_provider.get_tenant_id().await?,
+            uri: vault_name.key_vault_url()?.to_string(),
+            enabled_for_deployment: false,
+            enabled_for_disk_encryption: false,
+            enabled_for_template_deployment: false,
+            soft_delete_retention_in_days: 90,
+            purge_protection: false,
+            sku: "standard".to_string(),
+            access_policies: Vec::new(),
+            created_at: chrono::Utc::now(),
+            tags: HashMap::new(),
+            enable_rbac_authorization: None,
+            public_network_access: None,
+            network_acls: None,
+        })
     }
 
-    async fn list_secret_access(
-        &self,
-        vault_name: &str,
-        resource_group: &str,
-        secret_name: &str,
-    ) -> Result<Vec<VaultRole>> {
-        let operation = || async {
-            let vault_name = self.validated_vault_name(vault_name)?;
-            let headers = self.create_headers().await?;
-            let scope = format!(
-                "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/secrets/{}",
-                enc(&self.subscription_id),
-                enc(resource_group),
-                enc(vault_name.as_str()),
-                enc(secret_name)
-            );
-
-            let url = self.build_arm_url(&format!(
-                "{scope}/providers/Microsoft.Authorization/roleAssignments?api-version=2022-04-01&$filter=atScope()"
-            ));
-
-            let response = self
-                .http_client
-                .get(&url)
-                .headers(headers.clone())
-                .send()
-                .await
-                .map_err(|e| {
-                    CrosstacheError::network(format!("Failed to list secret access: {e}"))
-                })?;
-
-            if !response.status().is_success() {
-                let status_code = response.status().as_u16();
-                let error_body = response.text().await.unwrap_or_default();
-                return Err(self.parse_azure_error(status_code, &error_body));
-            }
-
-            let response_data: Value = response.json().await.map_err(|e| {
-                CrosstacheError::serialization(format!("Failed to parse role assignments: {e}"))
-            })?;
-
-            let assignments = response_data
-                .get("value")
-                .and_then(|v| v.as_array())
-                .cloned()
-                .unwrap_or_default();
+    async fn purge_vault(&self, vault_name: &str, location: &str) -> Result<()> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let url = self.arm.url(&format!(
+            "/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}/purge?api-version={VAULT_API_VERSION}",
+            enc(&self.subscription_id),
+            enc(location),
+            enc(vault_name.as_str())
+        ));
+        self.arm
+            .post::<(), Value>(&url, None, "Failed to purge vault")
+            .await?;
+        Ok(())
+    }
 
-            // Collect unique role definition IDs and resolve their names via ARM API
-            let unique_role_def_ids: Vec<String> = {
-                let mut seen = std::collections::HashSet::new();
-                assignments
-                    .iter()
-                    .filter_map(|a| {
-                        let props = a.get("properties").unwrap_or(a);
-                        props
-                            .get("roleDefinitionId")
-                            .and_then(|v| v.as_str())
-                            .map(|s| s.to_string())
-                    })
-                    .filter(|id| seen.insert(id.clone()))
-                    .collect()
-            };
+    async fn grant_access(
+        &self,
+        vault_name: &str,
+        resource_group: &str,
+        user_object_id: &str,
+        access_level: AccessLevel,
+    ) -> Result<()> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let scope = self.get_vault_resource_id(&vault_name, resource_group);
+        self.assign_role(
+            &scope,
+            user_object_id,
+            &access_level,
+            "Failed to grant vault access",
+        )
+        .await
+    }
 
-            let mut role_name_map: HashMap<String, String> = HashMap::new();
-            for role_def_id in &unique_role_def_ids {
-                let url = self.build_arm_url(&format!("{role_def_id}?api-version=2022-04-01"));
-                if let Ok(resp) = self
-                    .http_client
-                    .get(&url)
-                    .headers(headers.clone())
-                    .send()
-                    .await
-                {
-                    if resp.status().is_success() {
-                        if let Ok(data) = resp.json::<Value>().await {
-                            if let Some(name) = data
-                                .get("properties")
-                                .and_then(|p| p.get("roleName"))
-                                .and_then(|v| v.as_str())
-                            {
-                                role_name_map.insert(role_def_id.clone(), name.to_string());
-                            }
-                        }
-                    }
-                }
-            }
+    async fn revoke_access(
+        &self,
+        vault_name: &str,
+        resource_group: &str,
+        user_object_id: &str,
+    ) -> Result<()> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let scope = self.get_vault_resource_id(&vault_name, resource_group);
+        self.remove_exact_assignments(&scope, user_object_id, "vault")
+            .await
+    }
 
-            let mut roles = Vec::new();
-            for assignment in &assignments {
+    async fn list_access(&self, vault_name: &str, resource_group: &str) -> Result<Vec<VaultRole>> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let scope = self.get_vault_resource_id(&vault_name, resource_group);
+        let assignments = self.role_assignments(&scope, "atScope()").await?;
+        let now = chrono::Utc::now;
+        Ok(assignments
+            .iter()
+            .filter(|assignment| assignment_has_exact_scope(assignment, &scope))
+            .map(|assignment| {
                 let props = assignment.get("properties").unwrap_or(assignment);
-                let role_def_id = props
+                let role_definition_id = props
                     .get("roleDefinitionId")
-                    .and_then(|v| v.as_str())
+                    .and_then(Value::as_str)
                     .unwrap_or_default();
-
-                let role_name = role_name_map
-                    .get(role_def_id)
-                    .cloned()
-                    .unwrap_or_else(|| "Unknown Role".to_string());
-
-                let role = VaultRole {
+                VaultRole {
                     assignment_id: assignment
                         .get("id")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .unwrap_or_default()
                         .to_string(),
-                    role_id: role_def_id.to_string(),
-                    role_name: role_name.clone(),
-                    role_description: role_name,
+                    role_id: role_definition_id.to_string(),
+                    role_name: role_definition_id
+                        .rsplit('/')
+                        .next()
+                        .unwrap_or("Unknown Role")
+                        .to_string(),
+                    role_description: "Azure RBAC role assignment".to_string(),
                     principal_id: props
                         .get("principalId")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .unwrap_or_default()
                         .to_string(),
                     principal_name: props
                         .get("principalId")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .unwrap_or_default()
                         .to_string(),
                     email: String::new(),
+                    member_count: None,
                     principal_type: props
                         .get("principalType")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .unwrap_or("Unknown")
                         .to_string(),
                     scope: props
                         .get("scope")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .unwrap_or_default()
                         .to_string(),
                     created_on: props
                         .get("createdOn")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
                         .map(|dt| dt.with_timezone(&chrono::Utc))
-                        .unwrap_or_else(chrono::Utc::now),
+                        .unwrap_or_else(now),
                     updated_on: props
                         .get("updatedOn")
-                        .and_then(|v| v.as_str())
+                        .and_then(Value::as_str)
                         .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
                         .map(|dt| dt.with_timezone(&chrono::Utc))
-                        .unwrap_or_else(chrono::Utc::now),
-                };
-                roles.push(role);
-            }
+                        .unwrap_or_else(now),
+                }
+            })
+            .collect())
+    }
+
+    async fn grant_secret_access(
+        &self,
+        vault_name: &str,
+        resource_group: &str,
+        secret_name: &str,
+        user_object_id: &str,
+        access_level: AccessLevel,
+    ) -> Result<()> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let scope = self.get_secret_resource_id(&vault_name, resource_group, secret_name);
+        self.assign_role(
+            &scope,
+            user_object_id,
+            &access_level,
+            "Failed to grant secret access",
+        )
+        .await
+    }
+
+    async fn revoke_secret_access(
+        &self,
+        vault_name: &str,
+        resource_group: &str,
+        secret_name: &str,
+        user_object_id: &str,
+    ) -> Result<()> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let scope = self.get_secret_resource_id(&vault_name, resource_group, secret_name);
+        // Only assignments whose ARM properties confirm the exact secret
+        // scope are deleted; inherited vault/resource-group ones remain.
+        self.remove_exact_assignments(&scope, user_object_id, "secret")
+            .await
+    }
 
-            Ok(roles)
+    async fn list_secret_access(
+        &self,
+        vault_name: &str,
+        resource_group: &str,
+        secret_name: &str,
+    ) -> Result<Vec<VaultRole>> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let scope = self.get_secret_resource_id(&vault_name, resource_group, secret_name);
+        let assignments = self.role_assignments(&scope, "atScope()").await?;
+
+        // Collect unique role definition IDs and resolve their names via ARM API
+        let unique_role_def_ids: Vec<String> = {
+            let mut seen = std::collections::HashSet::new();
+            assignments
+                .iter()
+                .filter_map(|a| {
+                    let props = a.get("properties").unwrap_or(a);
+                    props
+                        .get("roleDefinitionId")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                })
+                .filter(|id| seen.insert(id.clone()))
+                .collect()
         };
 
-        self.execute_with_retry(operation).await
+        let mut role_name_map: HashMap<String, String> = HashMap::new();
+        for role_def_id in &unique_role_def_ids {
+            let url = self
+                .arm
+                .url(&format!("{role_def_id}?api-version={RBAC_API_VERSION}"));
+            // Best effort: an unreadable definition shows as "Unknown Role".
+            if let Ok(Some(data)) = self
+                .arm
+                .get::<Value>(&url, "Failed to read role definition")
+                .await
+            {
+                if let Some(name) = data
+                    .get("properties")
+                    .and_then(|p| p.get("roleName"))
+                    .and_then(|v| v.as_str())
+                {
+                    role_name_map.insert(role_def_id.clone(), name.to_string());
+                }
+            }
+        }
+
+        let mut roles = Vec::new();
+        for assignment in &assignments {
+            let props = assignment.get("properties").unwrap_or(assignment);
+            let role_def_id = props
+                .get("roleDefinitionId")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+
+            let role_name = role_name_map
+                .get(role_def_id)
+                .cloned()
+                .unwrap_or_else(|| "Unknown Role".to_string());
+
+            let role = VaultRole {
+                assignment_id: assignment
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                role_id: role_def_id.to_string(),
+                role_name: role_name.clone(),
+                role_description: role_name,
+                principal_id: props
+                    .get("principalId")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                principal_name: props
+                    .get("principalId")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                email: String::new(),
+                member_count: None,
+                principal_type: props
+                    .get("principalType")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown")
+                    .to_string(),
+                scope: props
+                    .get("scope")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                created_on: props
+                    .get("createdOn")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc))
+                    .unwrap_or_else(chrono::Utc::now),
+                updated_on: props
+                    .get("updatedOn")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc))
+                    .unwrap_or_else(chrono::Utc::now),
+            };
+            roles.push(role);
+        }
+
+        Ok(roles)
     }
 
     /// Resolve a user identifier to an AAD object id, delegating to the auth
@@ -1207,6 +1218,225 @@ impl VaultOperations for AzureVaultOperations {
         self.auth_provider.resolve_user_to_object_id(user).await
     }
 
+    async fn resolve_group_to_object_id(&self, group: &str) -> Result<String> {
+        if Uuid::parse_str(group).is_ok() {
+            return Ok(group.to_string());
+        }
+        let filter = crate::utils::url_helpers::odata_eq("displayName", group);
+        let url = format!(
+            "https://graph.microsoft.com/v1.0/groups?$filter={}&$select=id,displayName,securityEnabled",
+            urlencoding::encode(&filter)
+        );
+        let response = self
+            .arm
+            .http_client()
+            .get(&url)
+            .headers(self.create_graph_headers().await?)
+            .send()
+            .await
+            .map_err(|e| classify_network_error(&e, &url))?;
+        if !response.status().is_success() {
+            let status_code = response.status().as_u16();
+            let retry_after = parse_retry_after(response.headers());
+            let error_body = response.text().await.unwrap_or_default();
+            return Err(self.parse_graph_error(status_code, retry_after, &url, &error_body));
+        }
+        let page: Value = response.json().await.map_err(|e| {
+            CrosstacheError::serialization(format!("Failed to parse group lookup: {e}"))
+        })?;
+        pick_security_group(&page, group)
+    }
+
+    async fn invite_guest(
+        &self,
+        email: &str,
+        redirect_url: &str,
+        send_email: bool,
+    ) -> Result<GuestInvitation> {
+        let url = "https://graph.microsoft.com/v1.0/invitations";
+        let body = json!({
+            "invitedUserEmailAddress": email,
+            "inviteRedirectUrl": redirect_url,
+            "sendInvitationMessage": send_email,
+        });
+        let response = self
+            .arm
+            .http_client()
+            .post(url)
+            .headers(self.create_graph_headers().await?)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| classify_network_error(&e, url))?;
+        if !response.status().is_success() {
+            let status_code = response.status().as_u16();
+            let retry_after = parse_retry_after(response.headers());
+            let error_body = response.text().await.unwrap_or_default();
+            return Err(self.parse_graph_error(status_code, retry_after, url, &error_body));
+        }
+        let data: Value = response.json().await.map_err(|e| {
+            CrosstacheError::serialization(format!("Failed to parse invitation: {e}"))
+        })?;
+        parse_invitation(&data)
+    }
+
+    async fn principal_exists(&self, object_id: &str) -> Result<bool> {
+        let url = crate::utils::url_helpers::graph_url(
+            "https://graph.microsoft.com/v1.0/directoryObjects",
+            &[object_id],
+        );
+        let response = self
+            .arm
+            .http_client()
+            .get(&url)
+            .headers(self.create_graph_headers().await?)
+            .send()
+            .await
+            .map_err(|e| classify_network_error(&e, &url))?;
+        match response.status() {
+            status if status.is_success() => Ok(true),
+            reqwest::StatusCode::NOT_FOUND => Ok(false),
+            status => {
+                let retry_after = parse_retry_after(response.headers());
+                let error_body = response.text().await.unwrap_or_default();
+                Err(self.parse_graph_error(status.as_u16(), retry_after, &url, &error_body))
+            }
+        }
+    }
+
+    async fn group_member_counts(&self, group_ids: &[String]) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        if group_ids.is_empty() {
+            return counts;
+        }
+        let mut headers = match self.create_graph_headers().await {
+            Ok(headers) => headers,
+            Err(e) => {
+                tracing::debug!("Skipping group member counts: {e}");
+                return counts;
+            }
+        };
+        // `$count` needs advanced query support.
+        headers.insert("ConsistencyLevel", "eventual".parse().unwrap());
+        for id in group_ids {
+            let url = format!(
+                "{}/transitiveMembers/$count",
+                crate::utils::url_helpers::graph_url(
+                    "https://graph.microsoft.com/v1.0/groups",
+                    &[id]
+                )
+            );
+            let count = match self
+                .arm
+                .http_client()
+                .get(&url)
+                .headers(headers.clone())
+                .send()
+                .await
+            {
+                Ok(response) if response.status().is_success() => response
+                    .text()
+                    .await
+                    .ok()
+                    .and_then(|body| body.trim().parse::<usize>().ok()),
+                Ok(response) => {
+                    tracing::debug!("Group '{id}' member count: HTTP {}", response.status());
+                    None
+                }
+                Err(e) => {
+                    tracing::debug!("Group '{id}' member count: {e}");
+                    None
+                }
+            };
+            if let Some(count) = count {
+                counts.insert(id.clone(), count);
+            }
+        }
+        counts
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<SubscriptionSummary>> {
+        let url = self.arm.url("/subscriptions?api-version=2022-12-01");
+        let subscriptions: Vec<Value> = self.arm.list(url, "Failed to list subscriptions").await?;
+        Ok(parse_subscriptions(&subscriptions))
+    }
+
+    async fn check_name_availability(&self, vault_name: &str) -> Result<VaultNameStatus> {
+        let url = self.arm.url(&format!(
+            "/subscriptions/{}/providers/Microsoft.KeyVault/checkNameAvailability?api-version={VAULT_API_VERSION}",
+            enc(&self.subscription_id)
+        ));
+        let body = json!({
+            "name": vault_name,
+            "type": "Microsoft.KeyVault/vaults"
+        });
+        let response: Value = self
+            .arm
+            .post(&url, Some(&body), "Failed to check vault name")
+            .await?;
+        Ok(parse_name_availability(&response))
+    }
+
+    async fn find_deleted_vault(&self, vault_name: &str) -> Result<Option<DeletedVaultSummary>> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let url = self.arm.url(&format!(
+            "/subscriptions/{}/providers/Microsoft.KeyVault/deletedVaults?api-version={VAULT_API_VERSION}",
+            enc(&self.subscription_id)
+        ));
+        self.arm
+            .find_in_pages(url, "Failed to list deleted vaults", |page: Vec<Value>| {
+                find_deleted_vault_in(&page, vault_name.as_str())
+            })
+            .await
+    }
+
+    async fn diagnostics_enabled(&self, vault_name: &str, resource_group: &str) -> Result<bool> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let resource_id = self.get_vault_resource_id(&vault_name, resource_group);
+        let url = self.arm.url(&format!(
+            "{resource_id}/providers/Microsoft.Insights/diagnosticSettings?api-version=2021-05-01-preview"
+        ));
+        let settings: Vec<Value> = self
+            .arm
+            .list(url, "Failed to read diagnostic settings")
+            .await?;
+        Ok(!settings.is_empty())
+    }
+
+    async fn caller_data_actions(
+        &self,
+        vault_name: &str,
+        resource_group: &str,
+    ) -> Result<Vec<DataActionGrant>> {
+        let vault_name = self.validated_vault_name(vault_name)?;
+        let resource_id = self.get_vault_resource_id(&vault_name, resource_group);
+        self.caller_data_actions_on(&resource_id, "Failed to read vault permissions")
+            .await
+    }
+
+    async fn check_create_policy(
+        &self,
+        request: &VaultCreateRequest,
+    ) -> Result<Vec<PolicyFinding>> {
+        let url = self.arm.url(&format!(
+            "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.PolicyInsights/checkPolicyRestrictions?api-version=2022-03-01",
+            enc(&self.subscription_id),
+            enc(&request.resource_group)
+        ));
+        let body = json!({
+            "resourceDetails": {
+                "resourceContent": policy_check_content(request),
+                "apiVersion": VAULT_API_VERSION
+            },
+            "includeAuditEffect": true
+        });
+        let response: Value = self
+            .arm
+            .post(&url, Some(&body), "Failed to check policy restrictions")
+            .await?;
+        Ok(parse_policy_restrictions(&response))
+    }
+
     /// Resolve principal IDs to display names and emails using Microsoft Graph API.
     /// Returns a map of principal_id -> (display_name, email).
     /// Falls back gracefully if the Graph API call fails.
@@ -1245,7 +1475,8 @@ impl VaultOperations for AzureVaultOperations {
         });
 
         let response = match self
-            .http_client
+            .arm
+            .http_client()
             .post("https://graph.microsoft.com/v1.0/directoryObjects/getByIds")
             .headers(headers)
             .json(&body)
@@ -1429,6 +1660,11 @@ impl AzureVaultOperations {
             enable_rbac_authorization: properties
                 .get("enableRbacAuthorization")
                 .and_then(|v| v.as_bool()),
+            public_network_access: properties
+                .get("publicNetworkAccess")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            network_acls: properties.get("networkAcls").map(parse_network_acls),
         })
     }
 
@@ -1548,6 +1784,249 @@ mod tests {
         assert_eq!(enc("abc-123_XYZ").to_string(), "abc-123_XYZ");
     }
 
+    #[test]
+    fn invitation_response_yields_the_guest_user() {
+        let invitation = parse_invitation(&json!({
+            "invitedUserEmailAddress": "guest@partner.com",
+            "inviteRedeemUrl": "https://login.microsoftonline.com/redeem?x=1",
+            "invitedUser": { "id": "u-guest" },
+            "status": "PendingAcceptance"
+        }))
+        .unwrap();
+        assert_eq!(invitation.user_id, "u-guest");
+        assert_eq!(invitation.status, "PendingAcceptance");
+        assert!(invitation.redeem_url.is_some());
+        assert!(parse_invitation(&json!({ "status": "Error" })).is_err());
+    }
+
+    #[test]
+    fn group_lookup_needs_exactly_one_security_group() {
+        let page = |groups: Value| json!({ "value": groups });
+        assert_eq!(
+            pick_security_group(
+                &page(json!([{ "id": "g-1", "displayName": "Platform-Team", "securityEnabled": true }])),
+                "Platform-Team"
+            )
+            .unwrap(),
+            "g-1"
+        );
+        assert!(pick_security_group(&page(json!([])), "Nobody")
+            .unwrap_err()
+            .to_string()
+            .contains("No Entra ID group"));
+        let m365 = page(json!([{ "id": "g-2", "securityEnabled": false }]));
+        assert!(pick_security_group(&m365, "Chat")
+            .unwrap_err()
+            .to_string()
+            .contains("not security-enabled"));
+        let twins = page(json!([{ "id": "g-3" }, { "id": "g-4" }]));
+        let err = pick_security_group(&twins, "Ops").unwrap_err().to_string();
+        assert!(err.contains("g-3") && err.contains("g-4"));
+    }
+
+    #[test]
+    fn policy_restrictions_report_denials_and_modifications() {
+        let findings = parse_policy_restrictions(&json!({
+            "fieldRestrictions": [{
+                "field": "properties.publicNetworkAccess",
+                "restrictions": [{
+                    "result": "Required",
+                    "defaultValue": "Disabled",
+                    "policy": {
+                        "policyDefinitionId": "/providers/Microsoft.Authorization/policyDefinitions/kv-private-only"
+                    }
+                }]
+            }],
+            "contentEvaluationResult": {
+                "policyEvaluations": [
+                    {
+                        "policyInfo": {
+                            "policyDefinitionId": "/providers/Microsoft.Authorization/policyDefinitions/abc",
+                            "policyDefinitionDisplayName": "Key vaults should have a retention of 90 days"
+                        },
+                        "evaluationResult": "NonCompliant",
+                        "evaluationDetails": {
+                            "evaluatedExpressions": [{"path": "properties.softDeleteRetentionInDays"}]
+                        },
+                        "effectDetails": {"policyEffect": "Deny"}
+                    },
+                    {
+                        "policyInfo": {"policyDefinitionId": "/x/compliant"},
+                        "evaluationResult": "Compliant",
+                        "effectDetails": {"policyEffect": "Deny"}
+                    }
+                ]
+            }
+        }));
+
+        assert_eq!(findings.len(), 2);
+        assert!(findings[0].is_modification());
+        assert_eq!(
+            findings[0].field.as_deref(),
+            Some("properties.publicNetworkAccess")
+        );
+        assert_eq!(findings[0].policy.as_deref(), Some("kv-private-only"));
+        assert_eq!(findings[0].detail.as_deref(), Some("Disabled"));
+        assert!(findings[1].is_denial());
+        assert_eq!(
+            findings[1].policy.as_deref(),
+            Some("Key vaults should have a retention of 90 days")
+        );
+        assert!(parse_policy_restrictions(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn data_action_grants_parse_from_permissions_page() {
+        let grants = parse_data_action_grants(&[
+            json!({
+                "actions": [],
+                "notActions": [],
+                "dataActions": ["Microsoft.KeyVault/vaults/secrets/*"],
+                "notDataActions": ["Microsoft.KeyVault/vaults/secrets/delete"]
+            }),
+            json!({"actions": ["*/read"]}),
+        ]);
+        assert_eq!(grants.len(), 2);
+        assert_eq!(
+            grants[0].data_actions,
+            ["Microsoft.KeyVault/vaults/secrets/*"]
+        );
+        assert_eq!(
+            grants[0].not_data_actions,
+            ["Microsoft.KeyVault/vaults/secrets/delete"]
+        );
+        assert!(grants[1].data_actions.is_empty());
+        assert!(parse_data_action_grants(&[]).is_empty());
+    }
+
+    #[test]
+    fn policy_check_content_spells_out_public_access_and_retention() {
+        let request = VaultCreateRequest {
+            name: "kv".into(),
+            location: "eastus".into(),
+            resource_group: "rg".into(),
+            subscription_id: "sub".into(),
+            sku: None,
+            enabled_for_deployment: None,
+            enabled_for_disk_encryption: None,
+            enabled_for_template_deployment: None,
+            soft_delete_retention_in_days: Some(30),
+            purge_protection: None,
+            tags: None,
+            access_policies: None,
+        };
+        let content = policy_check_content(&request);
+        assert_eq!(content["type"], "Microsoft.KeyVault/vaults");
+        assert_eq!(content["properties"]["publicNetworkAccess"], "Enabled");
+        assert_eq!(content["properties"]["softDeleteRetentionInDays"], 30);
+    }
+
+    #[test]
+    fn name_availability_distinguishes_free_taken_and_invalid() {
+        assert_eq!(
+            parse_name_availability(&json!({"nameAvailable": true})),
+            VaultNameStatus::Available
+        );
+        assert_eq!(
+            parse_name_availability(&json!({
+                "nameAvailable": false,
+                "reason": "AlreadyExists",
+                "message": "The vault name 'kv' is already in use."
+            })),
+            VaultNameStatus::Taken {
+                message: Some("The vault name 'kv' is already in use.".into())
+            }
+        );
+        assert!(matches!(
+            parse_name_availability(&json!({
+                "nameAvailable": false,
+                "reason": "AccountNameInvalid"
+            })),
+            VaultNameStatus::Invalid { message: None }
+        ));
+        let soft_deleted = VaultNameStatus::SoftDeleted(DeletedVaultSummary {
+            name: "kv".into(),
+            location: "eastus".into(),
+            scheduled_purge_date: None,
+            purge_protection: None,
+        });
+        assert_eq!(
+            serde_json::to_value(&soft_deleted).unwrap()["status"],
+            "soft_deleted"
+        );
+    }
+
+    #[test]
+    fn deleted_vault_page_matches_name_case_insensitively() {
+        let page = [
+            json!({"name": "other", "properties": {"location": "westus"}}),
+            json!({
+                "name": "MyVault",
+                "properties": {
+                    "location": "eastus",
+                    "scheduledPurgeDate": "2026-11-01T00:00:00Z",
+                    "purgeProtectionEnabled": true
+                }
+            }),
+        ];
+        assert_eq!(
+            find_deleted_vault_in(&page, "myvault"),
+            Some(DeletedVaultSummary {
+                name: "myvault".into(),
+                location: "eastus".into(),
+                scheduled_purge_date: Some("2026-11-01T00:00:00Z".into()),
+                purge_protection: Some(true),
+            })
+        );
+        assert_eq!(find_deleted_vault_in(&page, "missing"), None);
+    }
+
+    #[test]
+    fn network_acls_summary_counts_rules_and_defaults_missing_fields() {
+        let acls = json!({
+            "defaultAction": "Deny",
+            "bypass": "None",
+            "ipRules": [{"value": "203.0.113.0/24"}, {"value": "198.51.100.7"}],
+            "virtualNetworkRules": [{"id": "/subscriptions/s/.../subnets/a"}]
+        });
+        assert_eq!(
+            parse_network_acls(&acls),
+            NetworkAclSummary {
+                default_action: "Deny".into(),
+                bypass: "None".into(),
+                ip_rules: 2,
+                virtual_network_rules: 1,
+            }
+        );
+        assert_eq!(
+            parse_network_acls(&json!({})).describe(),
+            "default Allow, 0 IP rule(s), 0 VNet rule(s), bypass AzureServices"
+        );
+    }
+
+    #[test]
+    fn subscription_page_skips_disabled_and_falls_back_to_id() {
+        let subscriptions = [
+            json!({"subscriptionId": "sub-a", "displayName": "Production", "state": "Enabled"}),
+            json!({"subscriptionId": "sub-b", "displayName": "Old", "state": "Disabled"}),
+            json!({"subscriptionId": "sub-c", "state": "Warned"}),
+            json!({"displayName": "no id"}),
+        ];
+        assert_eq!(
+            parse_subscriptions(&subscriptions),
+            vec![
+                SubscriptionSummary {
+                    id: "sub-a".into(),
+                    display_name: "Production".into()
+                },
+                SubscriptionSummary {
+                    id: "sub-c".into(),
+                    display_name: "sub-c".into()
+                },
+            ]
+        );
+    }
+
     #[test]
     fn exact_scope_check_rejects_inherited_assignments() {
         let secret_scope =
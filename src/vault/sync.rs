@@ -0,0 +1,192 @@
+//! Vault-to-vault sync planning
+//!
+//! `xv vault sync` mirrors one vault into another (typically a DR vault in a
+//! second region). This module only decides what has to change: secrets are
+//! matched by their display name, and a destination secret is stale when its
+//! value or any user-visible metadata (tags, groups, note, folder, content
+//! type, enabled state, validity window) differs from the source. Bookkeeping
+//! tags the backends write themselves never count as a difference.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::backend::secret::split_denormalized_tags;
+use crate::secret::manager::SecretProperties;
+
+/// One change that brings the destination in line with the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncAction {
+    /// Missing in the destination.
+    Create,
+    /// Present in both but different; lists what differs.
+    Update(Vec<&'static str>),
+    /// Only in the destination (planned only with `--delete`).
+    Delete,
+}
+
+/// A planned change for one secret, by display name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncItem {
+    pub name: String,
+    pub action: SyncAction,
+}
+
+/// Compare `source` and `dest` (both read with values) and return the
+/// changes in name order. Secrets already in sync produce no item.
+pub fn plan_sync(
+    source: &[SecretProperties],
+    dest: &[SecretProperties],
+    delete: bool,
+) -> Vec<SyncItem> {
+    let dest_by_name: HashMap<&str, &SecretProperties> =
+        dest.iter().map(|s| (s.original_name.as_str(), s)).collect();
+    let mut plan: BTreeMap<String, SyncAction> = BTreeMap::new();
+
+    for secret in source {
+        match dest_by_name.get(secret.original_name.as_str()) {
+            None => {
+                plan.insert(secret.original_name.clone(), SyncAction::Create);
+            }
+            Some(existing) => {
+                let fields = differing_fields(secret, existing);
+                if !fields.is_empty() {
+                    plan.insert(secret.original_name.clone(), SyncAction::Update(fields));
+                }
+            }
+        }
+    }
+
+    if delete {
+        let source_names: std::collections::HashSet<&str> =
+            source.iter().map(|s| s.original_name.as_str()).collect();
+        for secret in dest {
+            if !source_names.contains(secret.original_name.as_str()) {
+                plan.insert(secret.original_name.clone(), SyncAction::Delete);
+            }
+        }
+    }
+
+    plan.into_iter()
+        .map(|(name, action)| SyncItem { name, action })
+        .collect()
+}
+
+/// The fields of `dest` that a sync would change (`value`, `tags`,
+/// `content type`, `enabled`, `validity`); `xv diff` reports the same. An
+/// empty source content type means "unset" and leaves the destination's
+/// alone.
+pub fn differing_fields(source: &SecretProperties, dest: &SecretProperties) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if source.value.as_deref() != dest.value.as_deref() {
+        fields.push("value");
+    }
+    if comparable_tags(source) != comparable_tags(dest) {
+        fields.push("tags");
+    }
+    if !source.content_type.is_empty() && source.content_type != dest.content_type {
+        fields.push("content type");
+    }
+    if source.enabled != dest.enabled {
+        fields.push("enabled");
+    }
+    if source.expires_on != dest.expires_on || source.not_before != dest.not_before {
+        fields.push("validity");
+    }
+    fields
+}
+
+type ComparableTags = (
+    BTreeMap<String, String>,
+    Option<Vec<String>>,
+    Option<String>,
+    Option<String>,
+);
+
+/// User tags plus the denormalized groups/note/folder, parsed so that
+/// backends encoding them differently still compare equal.
+fn comparable_tags(secret: &SecretProperties) -> ComparableTags {
+    let mut tags = secret.tags.clone();
+    let (groups, note, folder) = split_denormalized_tags(&mut tags);
+    let groups = groups.map(|mut g| {
+        g.sort();
+        g
+    });
+    (tags.into_iter().collect(), groups, note, folder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroize::Zeroizing;
+
+    fn secret(name: &str, value: &str, tags: &[(&str, &str)]) -> SecretProperties {
+        SecretProperties {
+            name: name.to_string(),
+            original_name: name.to_string(),
+            value: Some(Zeroizing::new(value.to_string())),
+            version: "1".to_string(),
+            id: None,
+            version_number: None,
+            created_timestamp: 0,
+            created_on: String::new(),
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            not_before: None,
+            tags: tags
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            content_type: String::new(),
+            recovery_level: None,
+        }
+    }
+
+    #[test]
+    fn plans_creates_updates_and_optional_deletes() {
+        let source = vec![
+            secret("db-url", "a", &[("env", "prod")]),
+            secret("api-key", "new", &[]),
+            secret("same", "x", &[]),
+        ];
+        let dest = vec![
+            secret("api-key", "old", &[("env", "prod")]),
+            secret("same", "x", &[]),
+            secret("extra", "y", &[]),
+        ];
+
+        let plan = plan_sync(&source, &dest, false);
+        assert_eq!(
+            plan,
+            vec![
+                SyncItem {
+                    name: "api-key".to_string(),
+                    action: SyncAction::Update(vec!["value", "tags"]),
+                },
+                SyncItem {
+                    name: "db-url".to_string(),
+                    action: SyncAction::Create,
+                },
+            ]
+        );
+
+        let plan = plan_sync(&source, &dest, true);
+        assert_eq!(plan.len(), 3);
+        assert_eq!(plan[2].name, "extra");
+        assert_eq!(plan[2].action, SyncAction::Delete);
+    }
+
+    #[test]
+    fn bookkeeping_tags_and_group_order_are_not_differences() {
+        let source = vec![secret(
+            "s",
+            "v",
+            &[("groups", "b,a"), ("created_by", "crosstache")],
+        )];
+        let dest = vec![secret(
+            "s",
+            "v",
+            &[("groups", "a, b"), ("original_name", "s")],
+        )];
+        assert!(plan_sync(&source, &dest, true).is_empty());
+    }
+}
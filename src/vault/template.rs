@@ -0,0 +1,537 @@
+//! Infrastructure-as-code export for existing vaults
+//!
+//! Turns a vault's ARM resource document into an ARM deployment template or
+//! a Bicep file that recreates its configuration. Secrets are never part of
+//! the vault resource, so nothing sensitive is emitted. Read-only and
+//! service-managed properties are dropped, and the tenant is taken from the
+//! deploying subscription rather than hard-coded.
+//!
+//! `xv vault export --fmt bicep|terraform` also lives here: one secret
+//! resource stub per secret, with the value left to a secure parameter (or
+//! sensitive variable) so the generated file never contains a secret.
+
+use crate::secret::manager::SecretSummary;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashSet};
+
+/// ARM API version the generated templates target.
+const TEMPLATE_API_VERSION: &str = "2023-07-01";
+
+/// Properties the service reports but rejects or ignores on deployment.
+const READ_ONLY_PROPERTIES: &[&str] = &[
+    "vaultUri",
+    "provisioningState",
+    "hsmPoolResourceId",
+    "privateEndpointConnections",
+];
+
+/// ARM expression for the deploying subscription's tenant.
+const TENANT_EXPRESSION: &str = "[subscription().tenantId]";
+
+/// Output flavour for `xv vault export-template`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TemplateFormat {
+    /// ARM deployment template (JSON)
+    Arm,
+    /// Bicep
+    Bicep,
+}
+
+impl TemplateFormat {
+    /// Infer the format from an output path: `.bicep` means Bicep, anything
+    /// else ARM JSON.
+    pub fn from_path(path: &str) -> Self {
+        if path.to_ascii_lowercase().ends_with(".bicep") {
+            Self::Bicep
+        } else {
+            Self::Arm
+        }
+    }
+}
+
+/// Render `resource` (a `GET .../vaults/{name}` response) in `format`.
+pub fn render_template(resource: &Value, format: TemplateFormat) -> String {
+    match format {
+        TemplateFormat::Arm => {
+            // A `Value` built from JSON always serializes.
+            serde_json::to_string_pretty(&arm_template(resource)).unwrap_or_default() + "\n"
+        }
+        TemplateFormat::Bicep => bicep_template(resource),
+    }
+}
+
+/// ARM deployment template with `vaultName` and `location` parameters
+/// defaulting to the exported vault's values.
+pub fn arm_template(resource: &Value) -> Value {
+    json!({
+        "$schema": "https://schema.management.azure.com/schemas/2019-04-01/deploymentTemplate.json#",
+        "contentVersion": "1.0.0.0",
+        "parameters": {
+            "vaultName": {
+                "type": "string",
+                "defaultValue": escape_arm_literal(text(resource, "name")),
+            },
+            "location": {
+                "type": "string",
+                "defaultValue": escape_arm_literal(text(resource, "location")),
+            },
+        },
+        "resources": [{
+            "type": "Microsoft.KeyVault/vaults",
+            "apiVersion": TEMPLATE_API_VERSION,
+            "name": "[parameters('vaultName')]",
+            "location": "[parameters('location')]",
+            "tags": escape_arm_literals(resource.get("tags").unwrap_or(&json!({}))),
+            "properties": deployable_properties(resource),
+        }],
+    })
+}
+
+/// Bicep equivalent of [`arm_template`].
+pub fn bicep_template(resource: &Value) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "param vaultName string = {}\n",
+        bicep_string(text(resource, "name"))
+    ));
+    out.push_str(&format!(
+        "param location string = {}\n\n",
+        bicep_string(text(resource, "location"))
+    ));
+    out.push_str(&format!(
+        "resource vault 'Microsoft.KeyVault/vaults@{TEMPLATE_API_VERSION}' = {{\n"
+    ));
+    out.push_str("  name: vaultName\n");
+    out.push_str("  location: location\n");
+    let tags = escape_arm_literals(resource.get("tags").unwrap_or(&json!({})));
+    if tags.as_object().is_some_and(|t| !t.is_empty()) {
+        out.push_str(&format!("  tags: {}\n", to_bicep(&tags, 1)));
+    }
+    out.push_str(&format!(
+        "  properties: {}\n",
+        to_bicep(&deployable_properties(resource), 1)
+    ));
+    out.push_str("}\n");
+    out
+}
+
+/// Bicep stubs for `secrets` in the existing vault `vault_name`: a
+/// `Microsoft.KeyVault/vaults/secrets` resource per secret whose value comes
+/// from a `@secure()` parameter, keeping the name, content type, tags and
+/// enabled state.
+pub fn secrets_bicep(vault_name: &str, secrets: &[SecretSummary]) -> String {
+    let idents = secret_identifiers(secrets);
+    let mut out = String::new();
+    out.push_str(&format!(
+        "param vaultName string = {}\n",
+        bicep_string(vault_name)
+    ));
+    for ident in &idents {
+        out.push_str(&format!("\n@secure()\nparam {ident}_value string\n"));
+    }
+    out.push_str(&format!(
+        "\nresource vault 'Microsoft.KeyVault/vaults@{TEMPLATE_API_VERSION}' existing = {{\n"
+    ));
+    out.push_str("  name: vaultName\n}\n");
+    for (secret, ident) in secrets.iter().zip(&idents) {
+        out.push_str(&format!(
+            "\nresource secret_{ident} 'Microsoft.KeyVault/vaults/secrets@{TEMPLATE_API_VERSION}' = {{\n"
+        ));
+        out.push_str("  parent: vault\n");
+        out.push_str(&format!("  name: {}\n", bicep_string(&secret.name)));
+        let tags = sorted_tags(secret);
+        if !tags.is_empty() {
+            out.push_str("  tags: {\n");
+            for (key, value) in tags {
+                out.push_str(&format!(
+                    "    {}: {}\n",
+                    bicep_key(key),
+                    bicep_string(value)
+                ));
+            }
+            out.push_str("  }\n");
+        }
+        out.push_str("  properties: {\n");
+        out.push_str(&format!("    value: {ident}_value\n"));
+        if !secret.content_type.is_empty() {
+            out.push_str(&format!(
+                "    contentType: {}\n",
+                bicep_string(&secret.content_type)
+            ));
+        }
+        if !secret.enabled {
+            out.push_str("    attributes: {\n      enabled: false\n    }\n");
+        }
+        out.push_str("  }\n}\n");
+    }
+    out
+}
+
+/// Terraform stubs for `secrets` in the vault `vault_name`: an
+/// `azurerm_key_vault_secret` per secret whose value comes from a sensitive
+/// variable, keeping the name, content type and tags. The vault itself is
+/// referenced through a `key_vault_id` variable.
+pub fn secrets_terraform(vault_name: &str, secrets: &[SecretSummary]) -> String {
+    let idents = secret_identifiers(secrets);
+    let mut out = String::new();
+    out.push_str("variable \"key_vault_id\" {\n");
+    out.push_str(&format!(
+        "  description = {}\n",
+        hcl_string(&format!("Resource ID of the Key Vault '{vault_name}'"))
+    ));
+    out.push_str("  type        = string\n}\n");
+    for ident in &idents {
+        out.push_str(&format!(
+            "\nvariable \"{ident}_value\" {{\n  type      = string\n  sensitive = true\n}}\n"
+        ));
+    }
+    for (secret, ident) in secrets.iter().zip(&idents) {
+        out.push_str(&format!(
+            "\nresource \"azurerm_key_vault_secret\" \"{ident}\" {{\n"
+        ));
+        out.push_str(&format!("  name         = {}\n", hcl_string(&secret.name)));
+        out.push_str(&format!("  value        = var.{ident}_value\n"));
+        out.push_str("  key_vault_id = var.key_vault_id\n");
+        if !secret.content_type.is_empty() {
+            out.push_str(&format!(
+                "  content_type = {}\n",
+                hcl_string(&secret.content_type)
+            ));
+        }
+        let tags = sorted_tags(secret);
+        if !tags.is_empty() {
+            out.push_str("\n  tags = {\n");
+            for (key, value) in tags {
+                out.push_str(&format!(
+                    "    {} = {}\n",
+                    hcl_string(key),
+                    hcl_string(value)
+                ));
+            }
+            out.push_str("  }\n");
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+/// One IaC identifier per secret: the name lowercased with every other
+/// character folded to `_`, prefixed when it would start with a digit, and
+/// suffixed `_2`, `_3`, ... when two names fold to the same identifier.
+fn secret_identifiers(secrets: &[SecretSummary]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    secrets
+        .iter()
+        .map(|secret| {
+            let mut base: String = secret
+                .name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            if !base.starts_with(|c: char| c.is_ascii_lowercase()) {
+                base.insert_str(0, "s_");
+            }
+            let mut ident = base.clone();
+            let mut n = 2;
+            while !seen.insert(ident.clone()) {
+                ident = format!("{base}_{n}");
+                n += 1;
+            }
+            ident
+        })
+        .collect()
+}
+
+fn sorted_tags(secret: &SecretSummary) -> BTreeMap<&str, &str> {
+    secret
+        .tags
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect()
+}
+
+/// HCL string literal. `${` and `%{` start template sequences, so they are
+/// doubled to stay literal.
+fn hcl_string(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+        .replace("${", "$${")
+        .replace("%{", "%%{");
+    format!("\"{escaped}\"")
+}
+
+fn text<'a>(value: &'a Value, field: &str) -> &'a str {
+    value.get(field).and_then(Value::as_str).unwrap_or_default()
+}
+
+/// The vault's `properties`, minus read-only fields, with literal strings
+/// ARM-escaped and the vault's own tenant replaced by [`TENANT_EXPRESSION`].
+fn deployable_properties(resource: &Value) -> Value {
+    let properties = resource.get("properties").unwrap_or(&Value::Null);
+    let tenant_id = text(properties, "tenantId");
+
+    let mut out = Map::new();
+    for (key, value) in properties.as_object().into_iter().flatten() {
+        if READ_ONLY_PROPERTIES.contains(&key.as_str()) {
+            continue;
+        }
+        let value = if key == "tenantId" {
+            json!(TENANT_EXPRESSION)
+        } else if key == "accessPolicies" {
+            access_policies_for_template(value, tenant_id)
+        } else {
+            escape_arm_literals(value)
+        };
+        out.insert(key.clone(), value);
+    }
+    Value::Object(out)
+}
+
+fn access_policies_for_template(policies: &Value, tenant_id: &str) -> Value {
+    let Some(policies) = policies.as_array() else {
+        return escape_arm_literals(policies);
+    };
+    Value::Array(
+        policies
+            .iter()
+            .map(|policy| {
+                let mut policy = escape_arm_literals(policy);
+                if let Some(obj) = policy.as_object_mut() {
+                    if !tenant_id.is_empty() && text_of(obj.get("tenantId")) == tenant_id {
+                        obj.insert("tenantId".into(), json!(TENANT_EXPRESSION));
+                    }
+                }
+                policy
+            })
+            .collect(),
+    )
+}
+
+fn text_of(value: Option<&Value>) -> &str {
+    value.and_then(Value::as_str).unwrap_or_default()
+}
+
+/// ARM evaluates strings wrapped in `[...]` as expressions; a literal that
+/// starts with `[` is escaped by doubling it.
+fn escape_arm_literal(s: &str) -> String {
+    if s.starts_with('[') {
+        format!("[{s}")
+    } else {
+        s.to_string()
+    }
+}
+
+fn escape_arm_literals(value: &Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(escape_arm_literal(s)),
+        Value::Array(items) => Value::Array(items.iter().map(escape_arm_literals).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), escape_arm_literals(v)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Render an (ARM-escaped) JSON value as a Bicep expression at `indent`
+/// levels. ARM expressions become raw Bicep expressions; escaped literals are
+/// unescaped back to plain strings.
+fn to_bicep(value: &Value, indent: usize) -> String {
+    let pad = "  ".repeat(indent + 1);
+    let close = "  ".repeat(indent);
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => {
+            if let Some(literal) = s.strip_prefix("[[") {
+                bicep_string(&format!("[{literal}"))
+            } else if s.starts_with('[') && s.ends_with(']') {
+                s[1..s.len() - 1].to_string()
+            } else {
+                bicep_string(s)
+            }
+        }
+        Value::Array(items) if items.is_empty() => "[]".to_string(),
+        Value::Array(items) => {
+            let body: String = items
+                .iter()
+                .map(|item| format!("{pad}{}\n", to_bicep(item, indent + 1)))
+                .collect();
+            format!("[\n{body}{close}]")
+        }
+        Value::Object(map) if map.is_empty() => "{}".to_string(),
+        Value::Object(map) => {
+            let body: String = map
+                .iter()
+                .map(|(k, v)| format!("{pad}{}: {}\n", bicep_key(k), to_bicep(v, indent + 1)))
+                .collect();
+            format!("{{\n{body}{close}}}")
+        }
+    }
+}
+
+fn bicep_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        key.to_string()
+    } else {
+        bicep_string(key)
+    }
+}
+
+fn bicep_string(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace("${", "\\${")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("'{escaped}'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_vault() -> Value {
+        json!({
+            "id": "/subscriptions/sub/resourceGroups/rg/providers/Microsoft.KeyVault/vaults/kv-app",
+            "name": "kv-app",
+            "location": "eastus",
+            "tags": { "team": "payments", "note": "[draft]" },
+            "properties": {
+                "tenantId": "tenant-1",
+                "sku": { "family": "A", "name": "standard" },
+                "vaultUri": "https://kv-app.vault.azure.net/",
+                "provisioningState": "Succeeded",
+                "enableRbacAuthorization": false,
+                "softDeleteRetentionInDays": 90,
+                "accessPolicies": [{
+                    "tenantId": "tenant-1",
+                    "objectId": "obj-1",
+                    "permissions": { "secrets": ["get", "list"] }
+                }],
+                "networkAcls": { "defaultAction": "Deny", "bypass": "AzureServices", "ipRules": [] }
+            }
+        })
+    }
+
+    #[test]
+    fn arm_template_drops_read_only_properties_and_parameterizes_tenant() {
+        let template = arm_template(&sample_vault());
+        assert_eq!(
+            template["parameters"]["vaultName"]["defaultValue"],
+            "kv-app"
+        );
+        let resource = &template["resources"][0];
+        assert_eq!(resource["name"], "[parameters('vaultName')]");
+        let properties = &resource["properties"];
+        assert!(properties.get("vaultUri").is_none());
+        assert!(properties.get("provisioningState").is_none());
+        assert_eq!(properties["tenantId"], TENANT_EXPRESSION);
+        assert_eq!(
+            properties["accessPolicies"][0]["tenantId"],
+            TENANT_EXPRESSION
+        );
+        assert_eq!(properties["softDeleteRetentionInDays"], 90);
+        // Literal strings that look like expressions are escaped.
+        assert_eq!(resource["tags"]["note"], "[[draft]");
+    }
+
+    #[test]
+    fn bicep_template_renders_expressions_raw_and_literals_quoted() {
+        let bicep = bicep_template(&sample_vault());
+        assert!(bicep.contains("param vaultName string = 'kv-app'"));
+        assert!(bicep.contains("resource vault 'Microsoft.KeyVault/vaults@2023-07-01' = {"));
+        assert!(bicep.contains("    tenantId: subscription().tenantId\n"));
+        assert!(bicep.contains("    note: '[draft]'\n"));
+        assert!(bicep.contains("    ipRules: []\n"));
+        assert!(!bicep.contains("vaultUri"));
+    }
+
+    fn secret(name: &str, tags: &[(&str, &str)]) -> SecretSummary {
+        SecretSummary {
+            name: name.to_string(),
+            original_name: name.to_string(),
+            note: None,
+            folder: None,
+            groups: None,
+            updated_on: String::new(),
+            enabled: true,
+            expires_on: None,
+            content_type: String::new(),
+            tags: tags
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn secrets_bicep_uses_secure_params_for_values() {
+        let mut disabled = secret("db-password", &[("groups", "db")]);
+        disabled.enabled = false;
+        disabled.content_type = "text/plain".to_string();
+        let bicep = secrets_bicep("kv-app", &[disabled, secret("9lives", &[])]);
+        assert!(bicep.contains("param vaultName string = 'kv-app'\n"));
+        assert!(bicep.contains("@secure()\nparam db_password_value string\n"));
+        assert!(
+            bicep.contains("resource vault 'Microsoft.KeyVault/vaults@2023-07-01' existing = {")
+        );
+        assert!(bicep.contains(
+            "resource secret_db_password 'Microsoft.KeyVault/vaults/secrets@2023-07-01' = {\n  parent: vault\n  name: 'db-password'\n"
+        ));
+        assert!(bicep.contains("    groups: 'db'\n"));
+        assert!(bicep.contains("    value: db_password_value\n"));
+        assert!(bicep.contains("    contentType: 'text/plain'\n"));
+        assert!(bicep.contains("      enabled: false\n"));
+        assert!(bicep.contains("resource secret_s_9lives "));
+    }
+
+    #[test]
+    fn secrets_terraform_uses_sensitive_variables_for_values() {
+        let tf = secrets_terraform("kv-app", &[secret("api-key", &[("note", "uses ${env}")])]);
+        assert!(tf
+            .contains("variable \"api_key_value\" {\n  type      = string\n  sensitive = true\n}"));
+        assert!(tf.contains("resource \"azurerm_key_vault_secret\" \"api_key\" {"));
+        assert!(tf.contains("  name         = \"api-key\"\n"));
+        assert!(tf.contains("  value        = var.api_key_value\n"));
+        assert!(tf.contains("  key_vault_id = var.key_vault_id\n"));
+        assert!(tf.contains("    \"note\" = \"uses $${env}\"\n"));
+    }
+
+    #[test]
+    fn secret_identifiers_are_unique() {
+        let idents =
+            secret_identifiers(&[secret("a-b", &[]), secret("a_b", &[]), secret("A-B", &[])]);
+        assert_eq!(idents, vec!["a_b", "a_b_2", "a_b_3"]);
+    }
+
+    #[test]
+    fn format_is_inferred_from_output_extension() {
+        assert_eq!(
+            TemplateFormat::from_path("vault.bicep"),
+            TemplateFormat::Bicep
+        );
+        assert_eq!(
+            TemplateFormat::from_path("VAULT.BICEP"),
+            TemplateFormat::Bicep
+        );
+        assert_eq!(TemplateFormat::from_path("vault.json"), TemplateFormat::Arm);
+    }
+}
@@ -1,10 +1,7 @@
 //! Request authentication for the web UI API.
 //!
-//! Threat model: the network is loopback-only; the real attacker is another
-//! web page in the user's browser issuing requests to 127.0.0.1 (CSRF / DNS
-//! rebinding). The bearer token is the gate; Host/Origin checks are a free
-//! second layer that specifically kills DNS rebinding (attacker-controlled
-//! hostname in Host).
+//! The Host/Origin and bearer-token checks live in
+//! [`crate::utils::local_auth`], shared with `xv dev-proxy`.
 
 use std::sync::Arc;
 
@@ -12,57 +9,26 @@ use axum::extract::{Request, State};
 use axum::http::{header, HeaderValue, StatusCode};
 use axum::middleware::Next;
 use axum::response::Response;
-use sha2::{Digest, Sha256};
 
 use super::WebState;
-
-fn is_loopback_name(host_port: &str) -> bool {
-    // Only strip a real numeric port; "localhost:evil.example.com" must not
-    // parse as name "localhost".
-    let name = match host_port.rsplit_once(':') {
-        Some((h, p)) if !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()) => h,
-        _ => host_port,
-    };
-    name == "127.0.0.1" || name == "localhost"
-}
+use crate::utils::local_auth;
 
 pub(crate) async fn require_auth(
     State(state): State<Arc<WebState>>,
     req: Request,
     next: Next,
 ) -> Result<Response, (StatusCode, &'static str)> {
-    let host = req
-        .headers()
-        .get(header::HOST)
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("");
-    if !is_loopback_name(host) {
-        return Err((StatusCode::FORBIDDEN, "invalid Host header"));
-    }
-
-    if let Some(origin) = req
-        .headers()
-        .get(header::ORIGIN)
-        .and_then(|h| h.to_str().ok())
-    {
-        let ok = origin
-            .strip_prefix("http://")
-            .map(is_loopback_name)
-            .unwrap_or(false);
-        if !ok {
-            return Err((StatusCode::FORBIDDEN, "invalid Origin header"));
-        }
-    }
-
-    let provided = req
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|h| h.to_str().ok())
-        .and_then(|v| v.strip_prefix("Bearer "))
-        .unwrap_or("");
-    // Digest comparison makes timing differences useless to an attacker.
-    if Sha256::digest(provided.as_bytes()) != Sha256::digest(state.token.as_bytes()) {
-        return Err((StatusCode::UNAUTHORIZED, "missing or invalid token"));
+    let headers = req.headers();
+    let value = |name| headers.get(name).and_then(|h| h.to_str().ok());
+    let checked = local_auth::check_request(
+        value(header::HOST),
+        value(header::ORIGIN),
+        value(header::AUTHORIZATION).and_then(|v| v.strip_prefix("Bearer ")),
+        &state.token,
+    );
+    if let Err(rejection) = checked {
+        let status = StatusCode::from_u16(rejection.status()).unwrap_or(StatusCode::FORBIDDEN);
+        return Err((status, rejection.reason()));
     }
 
     Ok(next.run(req).await)
@@ -119,11 +119,11 @@ pub(crate) fn crosstache_error(error: CrosstacheError) -> (StatusCode, ApiErrorB
 
     let code = error.code();
     let status = match &error {
-        SecretNotFound { .. } | VaultNotFound { .. } => StatusCode::NOT_FOUND,
-        PermissionDenied(_) => StatusCode::FORBIDDEN,
+        SecretNotFound { .. } | VaultNotFound { .. } | NotFound { .. } => StatusCode::NOT_FOUND,
+        PermissionDenied(_) | Forbidden { .. } => StatusCode::FORBIDDEN,
         AuthenticationError(_) => StatusCode::UNAUTHORIZED,
         Conflict(_) => StatusCode::CONFLICT,
-        RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+        RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
         InvalidArgument(_) | InvalidUrl(_) => StatusCode::BAD_REQUEST,
         BackendUnavailable { .. }
         | NetworkError(_)
@@ -147,7 +147,7 @@ pub(crate) fn crosstache_error(error: CrosstacheError) -> (StatusCode, ApiErrorB
             "The requested change conflicts with existing data.".into(),
             "Refresh the vault and choose a different name or retry the change.",
         ),
-        RateLimited(_) => (
+        RateLimited { .. } => (
             "The backend is rate limiting requests.".into(),
             "Wait a moment, then try again.",
         ),
@@ -175,6 +175,17 @@ pub(crate) fn crosstache_error(error: CrosstacheError) -> (StatusCode, ApiErrorB
             "You do not have permission for this action.".into(),
             "Check your backend role or choose a permitted vault.",
         ),
+        Forbidden {
+            required_permission,
+            ..
+        } => (
+            forbidden_message(required_permission.as_deref()),
+            "Check your backend role or choose a permitted vault.",
+        ),
+        NotFound { .. } => (
+            "The requested resource was not found.".into(),
+            "Refresh and try again.",
+        ),
         NetworkError(_)
         | DnsResolutionError { .. }
         | ConnectionTimeout(_)
@@ -207,6 +218,22 @@ pub(crate) fn crosstache_error(error: CrosstacheError) -> (StatusCode, ApiErrorB
             "The operation was blocked by a security finding.".into(),
             "Review the finding and remove the sensitive value before retrying.",
         ),
+        LintFailed { .. } => (
+            "Some referenced secrets are missing from the vault.".into(),
+            "Create the missing secrets or remove the references, then try again.",
+        ),
+        ReferenceCheckFailed { .. } => (
+            "Some app settings reference secrets the app cannot read.".into(),
+            "Fix the listed references or the app identity's vault access, then try again.",
+        ),
+        Interrupted { .. } => (
+            "The operation was cancelled before it finished.".into(),
+            "Run it again to process the remaining items.",
+        ),
+        BackupIncomplete { .. } => (
+            "Some secrets could not be included in the backup.".into(),
+            "Check read access to the listed secrets, then run the backup again.",
+        ),
         RenameIncomplete { .. } => (
             "The secret was renamed, but the original could not be removed.".into(),
             "Refresh the vault and verify both secrets before retrying deletion.",
@@ -265,6 +292,25 @@ pub(crate) fn backend_error(error: BackendError) -> (StatusCode, ApiErrorBody) {
             "You do not have permission for this action.",
             "Check your backend role or choose a permitted vault.",
         ),
+        Forbidden {
+            required_permission,
+            ..
+        } => (
+            StatusCode::FORBIDDEN,
+            ApiErrorBody {
+                code: "xv-forbidden",
+                message: forbidden_message(required_permission.as_deref()),
+                hint: "Check your backend role or choose a permitted vault.",
+                field: None,
+                details: None,
+            },
+        ),
+        ResourceNotFound { .. } => generic(
+            StatusCode::NOT_FOUND,
+            "xv-not-found",
+            "The requested resource was not found.",
+            "Refresh and try again.",
+        ),
         Unsupported(_) => generic(
             StatusCode::NOT_IMPLEMENTED,
             "xv-operation-unsupported",
@@ -328,6 +374,16 @@ pub(crate) fn backend_error(error: BackendError) -> (StatusCode, ApiErrorBody) {
     }
 }
 
+/// A refusal message naming the missing permission when the backend said.
+fn forbidden_message(required_permission: Option<&str>) -> String {
+    match required_permission {
+        Some(permission) => {
+            format!("You do not have permission for this action (needs {permission}).")
+        }
+        None => "You do not have permission for this action.".into(),
+    }
+}
+
 fn generic(
     status: StatusCode,
     code: &'static str,
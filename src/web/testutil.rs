@@ -339,6 +339,7 @@ pub(crate) mod stub {
             not_before: req.not_before,
             tags,
             content_type: req.content_type.clone().unwrap_or_default(),
+            id: None,
             recovery_level: None,
         }
     }
@@ -551,6 +552,8 @@ pub(crate) mod stub {
                     original_name: request.name.clone(),
                     deleted_on: Some("2026-07-22T00:00:00Z".to_string()),
                     scheduled_purge_on: None,
+                    recovery_level: None,
+                    tags: Default::default(),
                 })
                 .collect())
         }
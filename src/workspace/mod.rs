@@ -495,9 +495,7 @@ async fn degenerate_default_vault(
             return Ok(config.default_vault.clone());
         }
 
-        Err(CrosstacheError::config(
-            "No vault specified. Use --vault, set context with 'xv context use', or configure default_vault",
-        ))
+        Err(CrosstacheError::no_vault_context())
     }
     .await;
 
@@ -765,6 +763,7 @@ mod tests {
             .await
             .expect_err("Azure + no vault must be an Err, not Ok(None) or a 'default' vault");
         assert!(err.to_string().contains("No vault specified"), "{err}");
+        assert!(err.is_no_vault_context());
     }
 
     /// `is_configured()` distinguishes the degenerate workspace from a real
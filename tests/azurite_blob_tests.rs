@@ -0,0 +1,92 @@
+//! Azurite integration tests for the Azure file backend. Skipped silently
+//! when Azurite is unavailable.
+//!
+//! Enable with: AZURITE_INTEGRATION_TESTS=1 (and Azurite's blob service at
+//! AZURITE_BLOB_ENDPOINT, default `http://127.0.0.1:10000/devstoreaccount1`).
+//!
+//! Azurite docker quickstart:
+//!   docker run -d --rm --name azurite -p 10000:10000 \
+//!     mcr.microsoft.com/azure-storage/azurite azurite-blob --blobHost 0.0.0.0
+//!   az storage container create -n crosstache-files \
+//!     --connection-string "UseDevelopmentStorage=true"
+//!   export AZURITE_INTEGRATION_TESTS=1
+
+#![cfg(feature = "file-ops")]
+
+use crosstache::auth::provider::DefaultAzureCredentialProvider;
+use crosstache::backend::azure::AzureBackend;
+use crosstache::backend::Backend;
+use crosstache::blob::models::{FileListRequest, FileUploadRequest};
+use crosstache::config::{BlobConfig, Config};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn skip_unless_enabled() -> bool {
+    if std::env::var("AZURITE_INTEGRATION_TESTS").is_err() {
+        eprintln!("AZURITE_INTEGRATION_TESTS not set — skipping");
+        return true;
+    }
+    false
+}
+
+fn build_backend() -> AzureBackend {
+    let endpoint = std::env::var("AZURITE_BLOB_ENDPOINT")
+        .unwrap_or_else(|_| "http://127.0.0.1:10000/devstoreaccount1".to_string());
+    let config = Config {
+        blob_config: Some(BlobConfig {
+            storage_account: "devstoreaccount1".to_string(),
+            endpoint: Some(endpoint),
+            ..BlobConfig::default()
+        }),
+        ..Config::default()
+    };
+    let provider = DefaultAzureCredentialProvider::with_credential_priority(
+        config.azure_credential_priority.clone(),
+    )
+    .unwrap();
+    AzureBackend::new(&config, Arc::new(provider)).unwrap()
+}
+
+#[tokio::test]
+async fn azurite_upload_list_download_delete() {
+    if skip_unless_enabled() {
+        return;
+    }
+    let backend = build_backend();
+    let files = backend.files().expect("file backend configured");
+    let name = format!("xv-test-{}/hello.txt", uuid::Uuid::new_v4());
+
+    let request = FileUploadRequest {
+        name: name.clone(),
+        content: b"hello from azurite".to_vec(),
+        content_type: None,
+        groups: vec!["test".to_string()],
+        metadata: HashMap::new(),
+        tags: HashMap::new(),
+    };
+    let info = files.upload_file("", request, None).await.unwrap();
+    assert_eq!(info.size, 18);
+
+    let prefix = name.split_once('/').unwrap().0.to_string();
+    let listed = files
+        .list_files(
+            "",
+            FileListRequest {
+                prefix: Some(prefix),
+                groups: None,
+                limit: None,
+                delimiter: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(listed
+        .iter()
+        .any(|f| f.name == name && f.groups == vec!["test".to_string()]));
+
+    let content = files.download_file("", &name, None).await.unwrap();
+    assert_eq!(content, b"hello from azurite");
+
+    files.delete_file("", &name).await.unwrap();
+    assert!(files.get_file_info("", &name).await.is_err());
+}
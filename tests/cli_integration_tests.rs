@@ -328,6 +328,15 @@ fn every_top_level_command_supports_help() {
         &["list"],
         &["delete"],
         &["update"],
+        &["edit"],
+        &["bundle"],
+        &["dotenv"],
+        &["ssh"],
+        &["folder"],
+        &["index"],
+        &["dev-proxy"],
+        &["name"],
+        &["direnv"],
         &["restore"],
         &["purge"],
         &["history"],
@@ -336,6 +345,7 @@ fn every_top_level_command_supports_help() {
         &["run"],
         &["inject"],
         &["whoami"],
+        &["doctor"],
         &["info"],
         &["audit"],
         &["share"],
@@ -356,6 +366,8 @@ fn every_top_level_command_supports_help() {
         &["vault", "list"],
         &["vault", "delete"],
         &["vault", "info"],
+        &["vault", "check-name"],
+        &["vault", "export-template"],
         &["vault", "share"],
         &["config"],
         &["config", "show"],
@@ -363,11 +375,16 @@ fn every_top_level_command_supports_help() {
         &["completion"],
         &["scan"],
         &["scan", "install"],
+        &["expiry-report"],
         &["scan", "uninstall"],
         &["init"],
+        &["bench"],
+        &["selftest"],
         &["upgrade"],
         &["version"],
         &["gen"],
+        &["generate"],
+        &["scaffold"],
         &["copy"],
         &["move"],
         &["diff"],
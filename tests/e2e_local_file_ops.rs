@@ -530,3 +530,374 @@ default_vault = "testvault"
         "expected the AWS sync gate message, got: {stderr}"
     );
 }
+
+/// Lifecycle policies are an Azure Storage feature; on local storage the
+/// command is refused with exit 2 instead of attempting an ARM call.
+#[test]
+fn file_lifecycle_gated_to_azure() {
+    let env = FileEnv::new();
+    let out = env.run(&[
+        "file",
+        "lifecycle",
+        "set",
+        "--prefix",
+        "logs/",
+        "--delete-after",
+        "90d",
+    ]);
+
+    assert_eq!(
+        out.status.code(),
+        Some(2),
+        "lifecycle on local must exit 2\nstderr:{}",
+        String::from_utf8_lossy(&out.stderr),
+    );
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("Azure Storage lifecycle policies"),
+        "expected the lifecycle gate message, got: {stderr}"
+    );
+}
+
+#[test]
+fn file_list_continue_pages_through_every_file() {
+    let env = FileEnv::new();
+    std::fs::write(env.path().join("payload.bin"), b"x").unwrap();
+    for name in ["a.txt", "b.txt", "c.txt"] {
+        env.ok(&["file", "upload", "payload.bin", "--name", name]);
+    }
+
+    let first = env.run(&[
+        "file",
+        "list",
+        "--continue",
+        "--page-size",
+        "2",
+        "--names-only",
+    ]);
+    assert!(first.status.success());
+    assert_eq!(String::from_utf8_lossy(&first.stdout), "a.txt\nb.txt\n");
+    let hint = String::from_utf8_lossy(&first.stderr);
+    let token = hint
+        .split("--continue '")
+        .nth(1)
+        .and_then(|rest| rest.split('\'').next())
+        .unwrap_or_else(|| panic!("expected a continuation token, got: {hint}"));
+
+    let last = env.run(&[
+        "file",
+        "list",
+        "--continue",
+        token,
+        "--page-size",
+        "2",
+        "--names-only",
+    ]);
+    assert!(last.status.success());
+    assert_eq!(String::from_utf8_lossy(&last.stdout), "c.txt\n");
+    assert!(
+        !String::from_utf8_lossy(&last.stderr).contains("--continue"),
+        "the final page must not offer another token"
+    );
+}
+
+/// An env profile's `file_prefix` namespaces every `xv file` verb: uploads
+/// land under it, listings only see it, and names stay relative to it.
+#[test]
+fn env_file_prefix_namespaces_file_commands() {
+    let env = FileEnv::new();
+    std::fs::write(env.path().join("payload.bin"), b"prod-bytes").unwrap();
+    env.ok(&["file", "upload", "payload.bin", "--name", "shared.txt"]);
+
+    let xv_toml = env.path().join(".xv.toml");
+    std::fs::write(
+        &xv_toml,
+        "default_env = \"prod\"\n\n[env.prod]\nfile_prefix = \"prod\"\n",
+    )
+    .unwrap();
+    env.ok(&["file", "upload", "payload.bin", "--name", "report.txt"]);
+    assert_eq!(
+        env.ok(&["file", "list", "--names-only"]),
+        "report.txt\n",
+        "the prod namespace must hide files outside prod/"
+    );
+    env.ok(&["file", "download", "report.txt", "-o", "out.bin"]);
+    assert_eq!(
+        std::fs::read(env.path().join("out.bin")).unwrap(),
+        b"prod-bytes"
+    );
+
+    std::fs::remove_file(&xv_toml).unwrap();
+    assert_eq!(
+        env.ok(&["file", "list", "--names-only"]),
+        "prod/report.txt\nshared.txt\n",
+        "without the profile the upload must sit under prod/"
+    );
+}
+
+#[test]
+fn file_update_merges_and_replaces_properties_in_place() {
+    let env = FileEnv::new();
+    std::fs::write(env.path().join("payload.bin"), b"x").unwrap();
+    env.ok(&[
+        "file",
+        "upload",
+        "payload.bin",
+        "--name",
+        "doc.txt",
+        "--group",
+        "a",
+        "--tag",
+        "env=dev",
+    ]);
+    let update = |args: &[&str]| -> serde_json::Value {
+        let out = env.ok(&[&["file", "update", "doc.txt"], args].concat());
+        serde_json::from_str(&out).expect("update prints the file as JSON when piped")
+    };
+
+    let merged = update(&["--group", "b", "--metadata", "owner=ops"]);
+    assert_eq!(merged["groups"], serde_json::json!(["a", "b"]));
+    assert_eq!(merged["metadata"]["owner"], "ops");
+    assert_eq!(merged["tags"]["env"], "dev", "tags were not given");
+
+    let replaced = update(&["--group", "c", "--replace"]);
+    assert_eq!(replaced["groups"], serde_json::json!(["c"]));
+    assert_eq!(
+        replaced["metadata"]["owner"], "ops",
+        "metadata was not given"
+    );
+
+    let nothing = env.run(&["file", "update", "doc.txt"]);
+    assert!(!nothing.status.success(), "an empty update must be refused");
+}
+
+#[test]
+fn file_copy_and_move_keep_properties() {
+    let env = FileEnv::new();
+    std::fs::write(env.path().join("payload.bin"), b"contents").unwrap();
+    env.ok(&[
+        "file",
+        "upload",
+        "payload.bin",
+        "--name",
+        "a.txt",
+        "--group",
+        "ops",
+        "--metadata",
+        "owner=me",
+        "--tag",
+        "env=dev",
+    ]);
+    let info = |name: &str| -> serde_json::Value {
+        serde_json::from_str(&env.ok(&["file", "info", name])).unwrap()
+    };
+
+    env.ok(&["file", "cp", "a.txt", "b.txt"]);
+    let copy = info("b.txt");
+    assert_eq!(copy["groups"], serde_json::json!(["ops"]));
+    assert_eq!(copy["metadata"]["owner"], "me");
+    assert_eq!(copy["tags"]["env"], "dev");
+    info("a.txt");
+
+    let clash = env.run(&["file", "cp", "a.txt", "b.txt"]);
+    assert!(
+        !clash.status.success(),
+        "an existing destination needs --force"
+    );
+    env.ok(&["file", "cp", "a.txt", "b.txt", "--force"]);
+
+    env.ok(&["file", "mv", "b.txt", "docs/c.txt"]);
+    assert_eq!(info("docs/c.txt")["groups"], serde_json::json!(["ops"]));
+    assert!(!env.run(&["file", "info", "b.txt"]).status.success());
+    env.ok(&["file", "download", "docs/c.txt", "--output", "out.bin"]);
+    assert_eq!(
+        std::fs::read(env.path().join("out.bin")).unwrap(),
+        b"contents"
+    );
+
+    let other_container = env.run(&["file", "cp", "a.txt", "a.txt", "--container", "x"]);
+    assert!(!other_container.status.success());
+}
+
+#[test]
+fn file_archive_bundles_a_prefix() {
+    use std::io::Read;
+
+    let env = FileEnv::new();
+    std::fs::write(env.path().join("q1.csv"), b"a,b").unwrap();
+    std::fs::write(env.path().join("other.txt"), b"x").unwrap();
+    env.ok(&["file", "upload", "q1.csv", "--name", "reports/2024/q1.csv"]);
+    env.ok(&["file", "upload", "q1.csv", "--name", "reports/2024/q2.csv"]);
+    env.ok(&["file", "upload", "other.txt"]);
+
+    env.ok(&[
+        "file",
+        "archive",
+        "--prefix",
+        "reports/2024",
+        "--output",
+        "reports.zip",
+    ]);
+    let zip_file = std::fs::File::open(env.path().join("reports.zip")).unwrap();
+    let mut archive = zip::ZipArchive::new(zip_file).unwrap();
+    let mut names: Vec<String> = archive.file_names().map(str::to_string).collect();
+    names.sort();
+    assert_eq!(names, ["reports/2024/q1.csv", "reports/2024/q2.csv"]);
+    let mut content = String::new();
+    archive
+        .by_name("reports/2024/q1.csv")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(content, "a,b", "content is stored decrypted");
+
+    let again = env.run(&[
+        "file",
+        "archive",
+        "--prefix",
+        "reports/2024",
+        "--output",
+        "reports.zip",
+    ]);
+    assert!(!again.status.success(), "an existing archive needs --force");
+
+    env.ok(&["file", "archive", "--output", "all.tgz"]);
+    let tgz = std::fs::File::open(env.path().join("all.tgz")).unwrap();
+    let mut tar = tar::Archive::new(flate2::read::GzDecoder::new(tgz));
+    assert_eq!(tar.entries().unwrap().count(), 3);
+
+    let unknown = env.run(&["file", "archive", "--output", "bundle.rar"]);
+    assert!(!unknown.status.success());
+}
+
+#[test]
+fn file_paste_is_off_until_allowed() {
+    let env = FileEnv::new();
+    let out = env.run(&["file", "paste", "a.txt"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("allow_qr_and_paste"));
+}
+
+#[test]
+fn file_paste_refuses_to_replace_without_force() {
+    let env = FileEnv::new();
+    env.ok(&["config", "set", "allow_qr_and_paste", "true"]);
+    std::fs::write(env.path().join("a.txt"), b"x").unwrap();
+    env.ok(&["file", "upload", "a.txt"]);
+    let out = env.run(&["file", "paste", "a.txt"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("already exists"));
+}
+
+#[test]
+fn upload_dash_streams_stdin() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let env = FileEnv::new();
+    let payload: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+    let mut child = env
+        .cmd()
+        .args(["up", "-", "--name", "logs/output.log"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn xv");
+    child.stdin.take().unwrap().write_all(&payload).unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(
+        out.status.success(),
+        "stdin upload failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    env.ok(&[
+        "file",
+        "download",
+        "logs/output.log",
+        "--output",
+        "copy.log",
+    ]);
+    assert_eq!(std::fs::read(env.path().join("copy.log")).unwrap(), payload);
+
+    let unnamed = env.run(&["file", "upload", "-"]);
+    assert!(!unnamed.status.success());
+    assert!(String::from_utf8_lossy(&unnamed.stderr).contains("--name"));
+}
+
+#[test]
+fn download_dash_streams_to_stdout() {
+    let env = FileEnv::new();
+    let payload: Vec<u8> = (0..64 * 1024).map(|i| (i % 253) as u8).collect();
+    std::fs::write(env.path().join("big.bin"), &payload).unwrap();
+    std::fs::write(env.path().join("secret.txt"), b"classified").unwrap();
+    env.ok(&["file", "upload", "big.bin"]);
+    env.ok(&["file", "upload", "secret.txt", "--encrypt"]);
+
+    let out = env.run(&["down", "big.bin", "--output", "-"]);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(out.stdout, payload, "stdout carries only the file content");
+    assert!(String::from_utf8_lossy(&out.stderr).contains("big.bin"));
+
+    let decrypted = env.run(&["file", "download", "secret.txt", "-o", "-"]);
+    assert!(decrypted.status.success());
+    assert_eq!(decrypted.stdout, b"classified");
+
+    let several = env.run(&["file", "download", "big.bin", "secret.txt", "-o", "-"]);
+    assert!(!several.status.success());
+}
+
+#[test]
+fn upload_skips_identical_content() {
+    let env = FileEnv::new();
+    let etag = || -> String {
+        let out = env.ok(&["file", "info", "a.txt"]);
+        let info: serde_json::Value = serde_json::from_str(&out).expect("info JSON");
+        info["etag"].as_str().unwrap().to_string()
+    };
+    std::fs::write(env.path().join("a.txt"), b"v1").unwrap();
+    env.ok(&["file", "upload", "a.txt"]);
+    let first = etag();
+
+    let again = env.run(&["file", "upload", "a.txt"]);
+    assert!(again.status.success());
+    assert!(String::from_utf8_lossy(&again.stderr).contains("unchanged"));
+    assert_eq!(etag(), first, "identical content is not re-sent");
+
+    env.ok(&["file", "upload", "a.txt", "--group", "ops"]);
+    let regrouped = etag();
+    assert_ne!(regrouped, first, "a new group still uploads");
+
+    env.ok(&["file", "upload", "a.txt", "--force"]);
+    assert_ne!(etag(), regrouped, "--force always uploads");
+
+    std::fs::write(env.path().join("a.txt"), b"v2").unwrap();
+    let changed = env.run(&["file", "upload", "a.txt"]);
+    assert!(!String::from_utf8_lossy(&changed.stderr).contains("unchanged"));
+}
+
+#[test]
+fn file_download_resume_skips_files_from_the_failed_run() {
+    let env = FileEnv::new();
+    std::fs::write(env.path().join("a.txt"), b"a").unwrap();
+    env.ok(&["file", "upload", "a.txt"]);
+
+    let failed = env.run(&["file", "download", "a.txt", "b.txt", "-o", "out"]);
+    assert!(!failed.status.success());
+    assert_eq!(std::fs::read(env.path().join("out/a.txt")).unwrap(), b"a");
+
+    std::fs::write(env.path().join("b.txt"), b"b").unwrap();
+    env.ok(&["file", "upload", "b.txt"]);
+    // a.txt is already in out/; without the checkpoint this would refuse
+    // to overwrite it.
+    let resumed = env.ok(&[
+        "file", "download", "a.txt", "b.txt", "-o", "out", "--resume",
+    ]);
+    assert!(resumed.contains("a.txt: downloaded by an earlier run"));
+    assert_eq!(std::fs::read(env.path().join("out/b.txt")).unwrap(), b"b");
+}
@@ -26,6 +26,8 @@ fn create_test_config() -> Config {
             chunk_size_mb: 4,
             max_concurrent_uploads: 3,
             progress_threshold_mb: 5,
+            transfer_retries: 3,
+            key_secret: None,
         }),
         ..Default::default()
     }
@@ -54,6 +56,7 @@ async fn test_file_upload_command_basic() -> Result<()> {
         content_type: Some("text/plain".to_string()),
         continue_on_error: false,
         encrypt: false,
+        force: false,
     };
 
     match upload_command {
@@ -69,6 +72,7 @@ async fn test_file_upload_command_basic() -> Result<()> {
             content_type,
             continue_on_error,
             encrypt: _,
+            force: _,
         } => {
             assert_eq!(files, vec![temp_file.path().to_string_lossy().to_string()]);
             assert_eq!(name, Some("test-file.txt".to_string()));
@@ -113,6 +117,7 @@ async fn test_file_upload_command_with_multiple_groups() -> Result<()> {
         content_type: None,
         continue_on_error: false,
         encrypt: false,
+        force: false,
     };
 
     match upload_command {
@@ -128,6 +133,7 @@ async fn test_file_upload_command_with_multiple_groups() -> Result<()> {
             content_type,
             continue_on_error: _,
             encrypt: _,
+            force: _,
         } => {
             assert_eq!(files, vec![temp_file.path().to_string_lossy().to_string()]);
             assert!(name.is_none());
@@ -158,6 +164,7 @@ async fn test_file_download_command_basic() -> Result<()> {
         flatten: false,
         force: false,
         continue_on_error: false,
+        resume: false,
     };
 
     match download_command {
@@ -169,6 +176,7 @@ async fn test_file_download_command_basic() -> Result<()> {
             flatten: _,
             force,
             continue_on_error: _,
+            resume: _,
         } => {
             assert_eq!(files, vec!["test-file.txt"]);
             assert_eq!(output, Some(output_path.to_string_lossy().to_string()));
@@ -191,6 +199,7 @@ async fn test_file_download_command_force_overwrite() -> Result<()> {
         flatten: false,
         force: true,
         continue_on_error: false,
+        resume: false,
     };
 
     match download_command {
@@ -202,6 +211,7 @@ async fn test_file_download_command_force_overwrite() -> Result<()> {
             flatten: _,
             force,
             continue_on_error: _,
+            resume: _,
         } => {
             assert_eq!(files, vec!["large-file.bin"]);
             assert!(output.is_none());
@@ -305,6 +315,7 @@ async fn test_file_upload_validation() -> Result<()> {
         content_type: None,
         continue_on_error: false,
         encrypt: false,
+        force: false,
     };
 
     match upload_command {
@@ -320,6 +331,7 @@ async fn test_file_upload_validation() -> Result<()> {
             content_type,
             continue_on_error,
             encrypt: _,
+            force: _,
         } => {
             assert_eq!(files, vec![non_existent_file]);
             assert!(name.is_none());
@@ -359,6 +371,7 @@ async fn test_metadata_and_tag_parsing() -> Result<()> {
         content_type: Some("text/plain".to_string()),
         continue_on_error: false,
         encrypt: false,
+        force: false,
     };
 
     match upload_command {
@@ -374,6 +387,7 @@ async fn test_metadata_and_tag_parsing() -> Result<()> {
             content_type: _,
             continue_on_error: _,
             encrypt: _,
+            force: _,
         } => {
             assert_eq!(metadata.len(), 2);
             assert!(metadata.contains(&("author".to_string(), "John Doe".to_string())));
@@ -401,6 +415,7 @@ async fn test_file_list_command() -> Result<()> {
         recursive: false,
         names_only: false,
         no_cache: false,
+        continue_from: None,
     };
 
     match list_command {
@@ -414,6 +429,7 @@ async fn test_file_list_command() -> Result<()> {
             recursive: _,
             names_only: _,
             no_cache: _,
+            continue_from,
         } => {
             assert_eq!(prefix, Some("config/".to_string()));
             assert_eq!(group, Some("production".to_string()));
@@ -421,6 +437,7 @@ async fn test_file_list_command() -> Result<()> {
             assert_eq!(page, None);
             assert_eq!(page_size, None);
             assert!(pager.is_none());
+            assert!(continue_from.is_none());
         }
         _ => panic!("Expected List command"),
     }
@@ -455,11 +472,15 @@ async fn test_file_delete_command() -> Result<()> {
 async fn test_file_info_command() -> Result<()> {
     let info_command = FileCommands::Info {
         name: "info-file.txt".to_string(),
+        url: true,
+        url_ttl: 60,
     };
 
     match info_command {
-        FileCommands::Info { name } => {
+        FileCommands::Info { name, url, url_ttl } => {
             assert_eq!(name, "info-file.txt");
+            assert!(url);
+            assert_eq!(url_ttl, 60);
         }
         _ => panic!("Expected Info command"),
     }
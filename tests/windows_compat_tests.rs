@@ -0,0 +1,106 @@
+//! Tests for the `windows-compat` helpers.
+//!
+//! The path and quoting transforms are platform-independent and run on every
+//! CI runner; the `#[cfg(windows)]` tests additionally check the behavior
+//! that only Windows builds switch on.
+//!
+//! Run with:
+//!   cargo test --test windows_compat_tests
+
+use crosstache::utils::windows::{
+    appdata_config_dir, extended_length_path, long_path_safe, quote_powershell, MAX_PATH,
+};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+fn long_tail() -> String {
+    "nested-directory\\".repeat(MAX_PATH / 16)
+}
+
+#[test]
+fn appdata_config_dir_uses_appdata_and_ignores_empty_values() {
+    assert_eq!(
+        appdata_config_dir(Some(OsString::from("C:\\Users\\dev\\AppData\\Roaming"))),
+        Some(PathBuf::from("C:\\Users\\dev\\AppData\\Roaming").join("xv"))
+    );
+    assert_eq!(appdata_config_dir(Some(OsString::new())), None);
+    assert_eq!(appdata_config_dir(None), None);
+}
+
+#[test]
+fn short_paths_are_left_alone() {
+    assert_eq!(extended_length_path("C:\\Users\\dev\\report.pdf"), None);
+}
+
+#[test]
+fn long_drive_paths_get_the_extended_prefix() {
+    let path = format!("C:\\downloads\\{}file.bin", long_tail());
+    let extended = extended_length_path(&path).expect("long path is prefixed");
+    assert_eq!(extended, format!("\\\\?\\{path}"));
+}
+
+#[test]
+fn long_paths_with_forward_slashes_are_normalized() {
+    let path = format!("C:/downloads/{}file.bin", long_tail().replace('\\', "/"));
+    let extended = extended_length_path(&path).expect("long path is prefixed");
+    assert!(extended.starts_with("\\\\?\\C:\\downloads\\"));
+    assert!(!extended.contains('/'));
+}
+
+#[test]
+fn long_unc_paths_use_the_unc_form() {
+    let path = format!("\\\\fileserver\\share\\{}file.bin", long_tail());
+    let extended = extended_length_path(&path).expect("long UNC path is prefixed");
+    assert!(extended.starts_with("\\\\?\\UNC\\fileserver\\share\\"));
+}
+
+#[test]
+fn prefixed_relative_and_dotted_paths_are_not_rewritten() {
+    let prefixed = format!("\\\\?\\C:\\{}file.bin", long_tail());
+    assert_eq!(extended_length_path(&prefixed), None);
+
+    let relative = format!("downloads\\{}file.bin", long_tail());
+    assert_eq!(extended_length_path(&relative), None);
+
+    let dotted = format!("C:\\downloads\\..\\{}file.bin", long_tail());
+    assert_eq!(extended_length_path(&dotted), None);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn long_path_safe_is_a_no_op_off_windows() {
+    let path = format!("/tmp/{}", "a/".repeat(MAX_PATH));
+    assert_eq!(long_path_safe(Path::new(&path)), PathBuf::from(&path));
+}
+
+#[cfg(all(windows, feature = "windows-compat"))]
+#[test]
+fn long_path_safe_prefixes_long_paths_on_windows() {
+    let path = format!("C:\\downloads\\{}file.bin", long_tail());
+    let safe = long_path_safe(Path::new(&path));
+    assert!(safe.to_string_lossy().starts_with("\\\\?\\C:\\"));
+}
+
+#[cfg(all(windows, feature = "windows-compat"))]
+#[test]
+fn long_path_safe_allows_writing_past_max_path() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let mut dir = tmp.path().to_path_buf();
+    while dir.as_os_str().len() < MAX_PATH + 20 {
+        dir.push("nested-directory");
+    }
+    let file = dir.join("file.bin");
+    std::fs::create_dir_all(long_path_safe(&dir)).unwrap();
+    std::fs::write(long_path_safe(&file), b"content").unwrap();
+    assert_eq!(std::fs::read(long_path_safe(&file)).unwrap(), b"content");
+}
+
+#[test]
+fn powershell_quoting_keeps_values_literal() {
+    assert_eq!(quote_powershell("plain"), "'plain'");
+    assert_eq!(quote_powershell(""), "''");
+    assert_eq!(quote_powershell("$(Remove-Item x)"), "'$(Remove-Item x)'");
+    assert_eq!(quote_powershell("it's"), "'it''s'");
+    assert_eq!(quote_powershell("a\u{2019}b"), "'a\u{2019}\u{2019}b'");
+    assert_eq!(quote_powershell("line1\nline2"), "'line1\nline2'");
+}